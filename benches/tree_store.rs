@@ -0,0 +1,61 @@
+//! Measure incremental view updates when expanding/collapsing tree nodes.
+//!
+//! Run with `cargo bench --bench tree_store`.
+
+use std::time::{Duration, Instant};
+
+use yew::virtual_dom::Key;
+
+use pwt::state::TreeStore;
+
+// 100 + 100*100 + 100*100*9 = 100100 nodes
+fn large_store() -> TreeStore<String> {
+    let store = TreeStore::with_extract_key(|s: &String| Key::from(s.clone()));
+    {
+        let mut tree = store.write();
+        let mut root = tree.set_root(String::from("root"));
+        root.set_expanded(true);
+        for i in 0..100 {
+            let mut a = root.append(format!("{i}"));
+            for j in 0..100 {
+                let mut b = a.append(format!("{i}/{j}"));
+                for k in 0..9 {
+                    b.append(format!("{i}/{j}/{k}"));
+                }
+            }
+        }
+        tree.expand_all(None);
+    }
+    store
+}
+
+fn toggle(store: &TreeStore<String>, key: &str) {
+    let mut tree = store.write();
+    let mut node = tree.lookup_node_mut(&Key::from(key)).unwrap();
+    let expanded = node.expanded();
+    node.set_expanded(!expanded);
+}
+
+fn bench(name: &str, keys: &[&str], rounds: u32) {
+    let store = large_store();
+    store.filtered_data_len();
+
+    let mut elapsed = Duration::ZERO;
+    for _ in 0..rounds {
+        for key in keys {
+            toggle(&store, key);
+            let start = Instant::now();
+            store.filtered_data_len();
+            elapsed += start.elapsed();
+        }
+    }
+
+    let count = rounds * keys.len() as u32;
+    println!("{name}: {:?} per update ({count} updates)", elapsed / count);
+}
+
+fn main() {
+    bench("toggle first", &["0"], 1000);
+    bench("toggle last", &["99/99"], 1000);
+    bench("toggle mixed", &["5", "7/3", "50", "99/99", "0/0"], 200);
+}
@@ -0,0 +1,31 @@
+//! Render the widget gallery to static HTML files.
+//!
+//! ```text
+//! cargo run --example gallery --features gallery -- <output-dir>
+//! ```
+//!
+//! Each registered widget state is written to `<output-dir>/<name>--<state>.html`,
+//! so CI jobs can diff the generated markup between releases.
+
+use std::path::PathBuf;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), std::io::Error> {
+    let out_dir = PathBuf::from(
+        std::env::args()
+            .nth(1)
+            .unwrap_or_else(|| String::from("gallery-snapshots")),
+    );
+    std::fs::create_dir_all(&out_dir)?;
+
+    let local = tokio::task::LocalSet::new();
+    let snapshots = local.run_until(pwt::gallery::render_all()).await;
+
+    for snapshot in snapshots {
+        let path = out_dir.join(snapshot.file_name());
+        std::fs::write(&path, format!("{}\n", snapshot.html))?;
+        println!("{}", path.display());
+    }
+
+    Ok(())
+}
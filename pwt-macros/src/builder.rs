@@ -130,6 +130,13 @@ impl Parse for CallbackOptions {
     }
 }
 
+// options from the additional field attributes
+struct ExtraOptions {
+    deprecated: Option<syn::Attribute>,
+    validate: Option<syn::Expr>,
+    conditional: bool,
+}
+
 fn derive_builder(builder: DeriveInput) -> Result<proc_macro2::TokenStream> {
     let DeriveInput {
         attrs,
@@ -190,48 +197,83 @@ fn derive_builder(builder: DeriveInput) -> Result<proc_macro2::TokenStream> {
             .iter()
             .find(|a| a.path().is_ident("deprecated"))
             .cloned();
+        let validate = field
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident("builder_validate"))
+            .map(|a| a.parse_args::<syn::Expr>())
+            .transpose()?;
+        let conditional = field.attrs.iter().any(|a| a.path().is_ident("builder_if"));
         field.attrs.retain(|a| {
             !a.path().is_ident("builder")
                 && !a.path().is_ident("builder_cb")
+                && !a.path().is_ident("builder_validate")
+                && !a.path().is_ident("builder_if")
                 && !a.path().is_ident("deprecated")
         });
-        builder.push((field.clone(), attr, builder_type, deprecated));
+        let options = ExtraOptions {
+            deprecated,
+            validate,
+            conditional,
+        };
+        builder.push((field.clone(), attr, builder_type, options));
+    }
+
+    // the extra attributes are only valid together with a builder attribute
+    for field in fields.iter() {
+        if let Some(attr) = field
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident("builder_validate") || a.path().is_ident("builder_if"))
+        {
+            return Err(Error::new(
+                attr.path().span(),
+                "missing 'builder' or 'builder_cb' attribute",
+            ));
+        }
     }
 
     let mut quotes = quote! {};
 
-    for (field, attr, builder_type, deprecated) in builder {
+    for (field, attr, builder_type, options) in builder {
         let field_ident = field.ident.unwrap();
         let field_name = field_ident.to_string();
         let field_type = field.ty;
         let setter = format_ident!("set_{}", field_name);
+        let conditional_builder = format_ident!("with_{}_if", field_name);
+
+        // the setter inherits the documentation of the field
+        let mut doc = String::new();
+        for attr in field.attrs {
+            if attr.path().is_ident("doc") {
+                if let Ok(syn::Expr::Lit(literal)) =
+                    attr.meta.require_name_value().map(|n| &n.value)
+                {
+                    if let syn::Lit::Str(text) = &literal.lit {
+                        doc.push_str(&text.value());
+                        doc.push('\n');
+                    }
+                }
+            }
+        }
+
         let (builder_doc, setter_doc) = match field.vis {
             syn::Visibility::Public(_) => {
                 let link =
                     format!("[`{field_name}`](struct.{ident}.html#structfield.{field_name})");
-                let setter_doc = format!("Set {link}");
+                let setter_doc = format!("Set {link}\n\n{doc}");
                 let builder_doc = format!("Builder style method to set {link}");
                 (builder_doc, setter_doc)
             }
             _ => {
-                let mut doc = String::new();
-                for attr in field.attrs {
-                    if attr.path().is_ident("doc") {
-                        if let Ok(syn::Expr::Lit(literal)) =
-                            attr.meta.require_name_value().map(|n| &n.value)
-                        {
-                            if let syn::Lit::Str(text) = &literal.lit {
-                                doc.push_str(&text.value());
-                                doc.push('\n');
-                            }
-                        }
-                    }
-                }
                 let setter_doc = format!("Set {field_name}\n\n{doc}");
                 let builder_doc = format!("Builder style method for [`{setter}`](Self::{setter})");
                 (builder_doc, setter_doc)
             }
         };
+        let conditional_doc = format!(
+            "Builder style method for [`{setter}`](Self::{setter}), only applied if `condition` is true"
+        );
 
         let attr_span = attr.path().span();
 
@@ -281,15 +323,37 @@ fn derive_builder(builder: DeriveInput) -> Result<proc_macro2::TokenStream> {
             }
         };
 
+        let ExtraOptions {
+            deprecated,
+            validate,
+            conditional,
+        } = options;
+
         let deprecated = match deprecated {
             Some(attr) => quote! { #attr },
             None => quote! {},
         };
 
+        // validation only runs in debug builds
+        let validate = match validate {
+            Some(validate) => {
+                let msg = format!("{ident}: invalid value for '{field_name}'");
+                quote_spanned! { validate.span() =>
+                    #[cfg(debug_assertions)]
+                    {
+                        let validate: fn(&#field_type) -> bool = #validate;
+                        assert!(validate(&self.#field_ident), #msg);
+                    }
+                }
+            }
+            None => quote! {},
+        };
+
         quotes.extend(quote_spanned! { attr_span =>
             #[doc = #setter_doc]
             pub fn #setter(&mut self, #field_ident: #param_type) {
                 self.#field_ident = #convert;
+                #validate
             }
 
             #[doc = #builder_doc]
@@ -299,6 +363,19 @@ fn derive_builder(builder: DeriveInput) -> Result<proc_macro2::TokenStream> {
                 self
             }
         });
+
+        if conditional {
+            quotes.extend(quote_spanned! { attr_span =>
+                #[doc = #conditional_doc]
+                #deprecated
+                pub fn #conditional_builder(mut self, condition: bool, #field_ident: #param_type) -> Self {
+                    if condition {
+                        self.#setter(#field_ident);
+                    }
+                    self
+                }
+            });
+        }
     }
 
     Ok(quote! {
@@ -42,6 +42,12 @@ use builder::*;
 ///   implement `EventSubscriber`
 /// * `svg` - this prevents the implemntation of `pwt::props::AsClassesMut` and the
 ///   `Css*Builder` traits.
+/// * `default` - this implements `Default`. For yew properties, this uses the
+///   property defaults (`yew::props!(Self {})`), so all fields need a default value.
+///   Otherwise, all fields must implement `Default`.
+///
+/// The macro also appends a documentation section to the struct, listing the
+/// injected properties and implemented traits.
 ///
 /// # Syntax
 ///
@@ -49,6 +55,9 @@ use builder::*;
 ///
 /// * `crate=foo` is optional and designates where to find the `pwt` crate
 /// * `comp=bar` is also optional and describes the `Component` to use
+/// * `tag="div"` is optional and implements `pwt::props::IntoVTag` for plain
+///   widgets, rendering the given tag with the standard properties (and listeners
+///   and children, if available). This cannot be combined with `comp`.
 /// * The desired types are prefixed with `@` and simply appended as a comma seperated list
 ///
 /// # Examples:
@@ -418,6 +427,68 @@ pub fn widget(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Additional field attributes
+///
+/// The documentation of a field is copied to the generated setter method.
+///
+/// A `#[deprecated]` attribute on the field is moved to the generated builder
+/// method, so that callers get a warning.
+///
+/// With `#[builder_validate(...)]` you can give a closure or function which
+/// checks the value after it was set. It gets a reference to the field value and
+/// returns `false` for invalid values, which panics. The check only runs in
+/// debug builds.
+///
+/// `#[builder_if]` generates an additional `with_<field>_if(condition, value)`
+/// method, which only sets the value if `condition` is true.
+///
+/// ```
+/// # use pwt_macros::builder;
+/// #[builder]
+/// struct Foo {
+///     #[builder]
+///     #[builder_validate(|value| *value <= 100)]
+///     #[builder_if]
+///     /// percentage, must be between 0 and 100
+///     percent: u32,
+/// }
+/// ```
+///
+/// which will generate code like this:
+///
+/// ```
+/// # struct Foo {
+/// #   percent: u32,
+/// # }
+/// impl Foo {
+///     /// Set `percent`
+///     ///
+///     /// percentage, must be between 0 and 100
+///     pub fn set_percent(&mut self, percent: u32) {
+///         self.percent = percent;
+///         #[cfg(debug_assertions)]
+///         {
+///             let validate: fn(&u32) -> bool = |value| *value <= 100;
+///             assert!(validate(&self.percent), "Foo: invalid value for 'percent'");
+///         }
+///     }
+///
+///     /// Builder style method for [`set_percent`](Self::set_percent)
+///     pub fn percent(mut self, percent: u32) -> Self {
+///         self.set_percent(percent);
+///         self
+///     }
+///
+///     /// Builder style method for [`set_percent`](Self::set_percent), only applied if `condition` is true
+///     pub fn with_percent_if(mut self, condition: bool, percent: u32) -> Self {
+///         if condition {
+///             self.set_percent(percent);
+///         }
+///         self
+///     }
+/// }
+/// ```
+///
 #[proc_macro_attribute]
 pub fn builder(_attr: TokenStream, item: TokenStream) -> TokenStream {
     handle_builder_struct(item)
@@ -3,27 +3,31 @@ use quote::{format_ident, quote};
 use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Attribute, Error, Fields, Ident, Path, Result, Token};
+use syn::{Attribute, Error, Fields, Ident, LitStr, Path, Result, Token};
 use syn::{Data, DeriveInput, parse_macro_input};
 
 #[derive(Debug)]
 pub(crate) struct WidgetSetup {
     pwt_crate_name: Option<Ident>,
     component_name: Option<Path>,
+    tag: Option<LitStr>,
     is_input: bool,
     is_container: bool,
     is_element: bool,
     is_svg: bool,
+    is_default: bool,
 }
 
 impl Parse for WidgetSetup {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut pwt_crate_name = None;
         let mut component_name = None;
+        let mut tag = None;
         let mut is_input = false;
         let mut is_container = false;
         let mut is_element = false;
         let mut is_svg = false;
+        let mut is_default = false;
 
         loop {
             if input.is_empty() {
@@ -41,6 +45,8 @@ impl Parse for WidgetSetup {
                     is_element = true;
                 } else if mixin == "svg" {
                     is_svg = true;
+                } else if mixin == "default" {
+                    is_default = true;
                 } else {
                     return Err(Error::new(mixin.span(), "no such widget mixin"));
                 }
@@ -68,6 +74,13 @@ impl Parse for WidgetSetup {
                         }
                         component_name = Some(path);
                     }
+                    "tag" => {
+                        let lit: LitStr = input.parse()?;
+                        if tag.is_some() {
+                            return Err(Error::new(lit.span(), "multiple tag definitions"));
+                        }
+                        tag = Some(lit);
+                    }
                     _ => {
                         return Err(Error::new(opt.span(), "unknown widget option"));
                     }
@@ -82,13 +95,22 @@ impl Parse for WidgetSetup {
             }
         }
 
+        if let (Some(_), Some(tag)) = (&component_name, &tag) {
+            return Err(Error::new(
+                tag.span(),
+                "options 'comp' and 'tag' are mutually exclusive",
+            ));
+        }
+
         Ok(WidgetSetup {
             pwt_crate_name,
             component_name,
+            tag,
             is_input,
             is_container,
             is_element,
             is_svg,
+            is_default,
         })
     }
 }
@@ -141,6 +163,8 @@ fn derive_widget(setup: &WidgetSetup, widget: DeriveInput) -> Result<proc_macro2
 
     let pwt: Ident = setup.pwt_crate_name.clone().unwrap_or(format_ident!("pwt"));
 
+    let doc_section = widget_doc_section(setup, &pwt);
+
     let fields = match data {
         Data::Struct(data) => match data.fields {
             Fields::Named(fields) => fields,
@@ -195,6 +219,7 @@ fn derive_widget(setup: &WidgetSetup, widget: DeriveInput) -> Result<proc_macro2
 
     let mut output = quote! {
         #(#attrs)*
+        #(#[doc = #doc_section])*
         #vis struct #ident #generics {
 
             #[doc(hidden)]
@@ -275,10 +300,64 @@ fn derive_widget(setup: &WidgetSetup, widget: DeriveInput) -> Result<proc_macro2
         });
     }
 
+    if let Some(tag) = &setup.tag {
+        let listeners = if setup.is_element {
+            quote! { Some(self.listeners) }
+        } else {
+            quote! { None }
+        };
+        let children = if setup.is_container {
+            quote! { Some(self.children) }
+        } else {
+            quote! { None }
+        };
+        output.extend(quote! {
+            impl #impl_generics #pwt::props::IntoVTag for #ident #ty_generics #where_clause {
+                fn into_vtag_with_ref(self, node_ref: ::yew::NodeRef) -> ::yew::virtual_dom::VTag {
+                    self.std_props.into_vtag(
+                        ::std::borrow::Cow::Borrowed(#tag),
+                        node_ref,
+                        None::<&str>,
+                        #listeners,
+                        #children,
+                    )
+                }
+            }
+        });
+    }
+
+    if setup.is_default {
+        // use the property defaults (prop_or, prop_or_else) for yew properties
+        let default_impl = if has_property_derive {
+            quote! { ::yew::props!(Self {}) }
+        } else {
+            let mut names = vec![format_ident!("std_props")];
+            if setup.is_input {
+                names.push(format_ident!("input_props"));
+            }
+            if setup.is_container {
+                names.push(format_ident!("children"));
+            }
+            if setup.is_element {
+                names.push(format_ident!("listeners"));
+            }
+            names.extend(fields.iter().filter_map(|field| field.ident.clone()));
+            quote! { Self { #(#names: ::std::default::Default::default(),)* } }
+        };
+        output.extend(quote! {
+            impl #impl_generics ::std::default::Default for #ident #ty_generics #where_clause {
+                fn default() -> Self {
+                    #default_impl
+                }
+            }
+        });
+    }
+
     if let Some(component_name) = &setup.component_name {
         output.extend(quote!{
             impl #impl_generics From<#ident #ty_generics> for ::yew::virtual_dom::VNode #where_clause {
-                fn from(value: #ident #ty_generics) -> Self {
+                fn from(mut value: #ident #ty_generics) -> Self {
+                    value.std_props.annotate_widget(stringify!(#ident));
                     let key = value.std_props.key.clone();
                     let comp = ::yew::virtual_dom::VComp::new::<#component_name>(
                         ::std::rc::Rc::new(value), key,
@@ -290,7 +369,8 @@ fn derive_widget(setup: &WidgetSetup, widget: DeriveInput) -> Result<proc_macro2
     } else {
         output.extend(quote!{
             impl #impl_generics From<#ident #ty_generics> for ::yew::virtual_dom::VNode #where_clause {
-                fn from(value: #ident #ty_generics) -> Self {
+                fn from(mut value: #ident #ty_generics) -> Self {
+                    value.std_props.annotate_widget(stringify!(#ident));
                     let vtag: ::yew::virtual_dom::VTag = #pwt::props::IntoVTag::into_vtag(value);
                     ::yew::virtual_dom::VNode::from(vtag)
                 }
@@ -315,3 +395,46 @@ fn derive_widget(setup: &WidgetSetup, widget: DeriveInput) -> Result<proc_macro2
     //eprintln!("TEST {}", output);
     Ok(output)
 }
+
+// Documentation section listing the injected properties and traits.
+fn widget_doc_section(setup: &WidgetSetup, pwt: &Ident) -> Vec<String> {
+    let link = |name: &str| format!("[{name}]({pwt}::props::{name})");
+
+    let mut traits = vec![link("WidgetBuilder"), link("WidgetStyleBuilder")];
+    if !setup.is_svg {
+        traits.push(link("CssMarginBuilder"));
+        traits.push(link("CssPaddingBuilder"));
+        traits.push(link("CssBorderBuilder"));
+    }
+    if setup.is_element {
+        traits.push(link("EventSubscriber"));
+    }
+    if setup.is_container {
+        traits.push(link("ContainerBuilder"));
+    }
+    if setup.is_input {
+        traits.push(link("FieldBuilder"));
+    }
+
+    let mut props = vec![format!("`std_props` ({})", link("WidgetStdProps"))];
+    if setup.is_input {
+        props.push(format!("`input_props` ({})", link("FieldStdProps")));
+    }
+    if setup.is_container {
+        props.push("`children`".into());
+    }
+    if setup.is_element {
+        props.push(format!("`listeners` ({})", link("ListenersWrapper")));
+    }
+
+    vec![
+        String::new(),
+        "# Standard Properties".into(),
+        String::new(),
+        format!(
+            "Generated by the widget macro. Includes the properties {}, and implements {}.",
+            props.join(", "),
+            traits.join(", ")
+        ),
+    ]
+}
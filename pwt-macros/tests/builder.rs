@@ -59,6 +59,12 @@ struct Foo {
     #[builder_cb(IntoCallback, into_callback, Option<i32>)]
     /// callback with option type
     cb_with_option: Option<Callback<Option<i32>>>,
+
+    #[builder]
+    #[builder_validate(|v| *v <= 100)]
+    #[builder_if]
+    /// validated and conditional field
+    percent: u32,
 }
 
 impl Foo {
@@ -70,6 +76,7 @@ impl Foo {
             into_with_default: 0,
             cb: None,
             cb_with_option: None,
+            percent: 0,
         }
     }
 }
@@ -108,6 +115,22 @@ fn builder_test() {
     assert_eq!(element.cb_with_option, Some(Callback::from(|_| ())));
 }
 
+#[test]
+fn builder_extra_options_test() {
+    let element = Foo::new().percent(50).with_percent_if(false, 100);
+    assert_eq!(element.percent, 50);
+
+    let element = element.with_percent_if(true, 100);
+    assert_eq!(element.percent, 100);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Foo: invalid value for 'percent'")]
+fn builder_validate_test() {
+    let _ = Foo::new().percent(101);
+}
+
 #[test]
 fn compile_fail_tests() {
     let t = trybuild::TestCases::new();
@@ -0,0 +1,9 @@
+use pwt_macros::builder;
+
+#[builder]
+struct MissingBuilder {
+    #[builder_validate(|v| *v > 0)]
+    field: i32,
+}
+
+fn main() {}
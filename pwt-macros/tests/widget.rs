@@ -47,6 +47,28 @@ mod props {
     }
     #[derive(PartialEq, Default, Clone)]
     pub struct ListenersWrapper {}
+
+    pub trait IntoVTag: Sized {
+        fn into_vtag_with_ref(self, node_ref: yew::NodeRef) -> yew::virtual_dom::VTag;
+        fn into_vtag(self) -> yew::virtual_dom::VTag {
+            self.into_vtag_with_ref(yew::NodeRef::default())
+        }
+    }
+
+    impl WidgetStdProps {
+        pub fn annotate_widget(&mut self, _widget_type: &'static str) {}
+
+        pub fn into_vtag(
+            self,
+            tag: std::borrow::Cow<'static, str>,
+            _node_ref: yew::NodeRef,
+            _additional_class: Option<impl Into<Classes>>,
+            _listeners: Option<ListenersWrapper>,
+            _children: Option<Vec<yew::virtual_dom::VNode>>,
+        ) -> yew::virtual_dom::VTag {
+            yew::virtual_dom::VTag::new(tag)
+        }
+    }
 }
 
 use props::WidgetBuilder;
@@ -84,6 +106,22 @@ fn builder_test() {
     let _res = html! {<div>{text}</div>};
 }
 
+#[widget(pwt=crate, tag="span", @element, @default)]
+#[derive(Properties, PartialEq, Clone)]
+struct Bar {
+    #[prop_or(3)]
+    count: u32,
+}
+
+#[test]
+fn default_and_tag_test() {
+    let bar = Bar::default();
+    assert_eq!(bar.count, 3);
+
+    let vtag = props::IntoVTag::into_vtag(bar);
+    assert_eq!(vtag.tag(), "span");
+}
+
 #[test]
 fn compile_fail_tests() {
     let t = trybuild::TestCases::new();
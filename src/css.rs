@@ -33,7 +33,10 @@
 //! ```
 //!
 
-use yew::Classes;
+use std::fmt;
+
+use yew::html::IntoPropValue;
+use yew::{AttrValue, Classes};
 
 #[cfg(doc)]
 use crate::prelude::*;
@@ -285,7 +288,10 @@ impl From<TextAlign> for Classes {
 ///    .class(Display::InlineBlock)
 /// # ;
 /// ```
+///
+/// New variants may be added as the theme gains utility classes.
 #[derive(Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Display {
     None,
     Inline,
@@ -297,6 +303,8 @@ pub enum Display {
     TableCell,
     Flex,
     InlineFlex,
+    /// `display: contents` (theme class `pwt-d-contents`).
+    Contents,
 }
 
 impl From<Display> for Classes {
@@ -312,6 +320,38 @@ impl From<Display> for Classes {
             Display::TableCell => "pwt-d-table-cell".into(),
             Display::Flex => "pwt-d-flex".into(),
             Display::InlineFlex => "pwt-d-inline-flex".into(),
+            Display::Contents => "pwt-d-contents".into(),
+        }
+    }
+}
+
+/// Wrapper type to specify CSS property `position`.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .class(Position::Relative)
+/// # ;
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Position {
+    Static,
+    Relative,
+    Absolute,
+    Fixed,
+    Sticky,
+}
+
+impl From<Position> for Classes {
+    fn from(value: Position) -> Self {
+        match value {
+            Position::Static => "pwt-position-static".into(),
+            Position::Relative => "pwt-position-relative".into(),
+            Position::Absolute => "pwt-position-absolute".into(),
+            Position::Fixed => "pwt-position-fixed".into(),
+            Position::Sticky => "pwt-position-sticky".into(),
         }
     }
 }
@@ -483,6 +523,40 @@ impl From<FlexFillFirstChild> for Classes {
     }
 }
 
+/// CSS utility type to use the full width of the parent (`width: 100%`).
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .class(FullWidth)
+/// # ;
+/// ```
+pub struct FullWidth;
+impl From<FullWidth> for Classes {
+    fn from(_value: FullWidth) -> Self {
+        "pwt-w-100".into()
+    }
+}
+
+/// CSS utility type to use the full height of the parent (`height: 100%`).
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .class(FullHeight)
+/// # ;
+/// ```
+pub struct FullHeight;
+impl From<FullHeight> for Classes {
+    fn from(_value: FullHeight) -> Self {
+        "pwt-h-100".into()
+    }
+}
+
 /// CSS utility type to fit into viewport (use all visible space)
 ///
 /// ```
@@ -528,6 +602,28 @@ impl From<Flex> for Classes {
     }
 }
 
+/// CSS utility type to set the gap between flex or grid children.
+///
+/// The factor is multiplied with the spacer base width. The CSS template
+/// defines classes for factors 0 to 4 (`pwt-gap-0` - `pwt-gap-4`).
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .class(Display::Flex)
+///    .class(Gap(2))
+/// # ;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gap(pub usize);
+impl From<Gap> for Classes {
+    fn from(value: Gap) -> Self {
+        format!("pwt-gap-{}", value.0).into()
+    }
+}
+
 /// Wrapper type to specify CSS color scheme class.
 ///
 /// Color schemes defines color/background-color combinations.
@@ -725,13 +821,18 @@ impl From<FontColor> for Classes {
 ///    .class(Opacity::Quarter)
 /// # ;
 /// ```
+///
+/// New variants may be added as the theme gains utility classes.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Opacity {
     Zero,
     Quarter,
     Half,
     ThreeQuarters,
     Full,
+    /// Opacity used for disabled elements (theme class `pwt-opacity-disabled`).
+    Disabled,
 }
 
 impl From<Opacity> for Classes {
@@ -742,6 +843,7 @@ impl From<Opacity> for Classes {
             Opacity::Half => "pwt-opacity-50".into(),
             Opacity::ThreeQuarters => "pwt-opacity-75".into(),
             Opacity::Full => "pwt-opacity-100".into(),
+            Opacity::Disabled => "pwt-opacity-disabled".into(),
         }
     }
 }
@@ -811,3 +913,266 @@ impl From<FontStyle> for Classes {
         }
     }
 }
+
+/// Wrapper type to specify CSS margin classes.
+///
+/// The factor is multiplied with the spacer base width. The CSS template
+/// defines classes for factors 0 to 4 (`pwt-m-{factor}`, `pwt-mt-{factor}`, ...).
+///
+/// Please use the [CssMarginBuilder] methods if you need other sizes.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .class(Margin::Top(2))
+/// # ;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Margin {
+    All(usize),
+    X(usize),
+    Y(usize),
+    Top(usize),
+    Bottom(usize),
+    Start(usize),
+    End(usize),
+}
+
+impl From<Margin> for Classes {
+    fn from(value: Margin) -> Self {
+        match value {
+            Margin::All(factor) => format!("pwt-m-{factor}").into(),
+            Margin::X(factor) => format!("pwt-mx-{factor}").into(),
+            Margin::Y(factor) => format!("pwt-my-{factor}").into(),
+            Margin::Top(factor) => format!("pwt-mt-{factor}").into(),
+            Margin::Bottom(factor) => format!("pwt-mb-{factor}").into(),
+            Margin::Start(factor) => format!("pwt-ms-{factor}").into(),
+            Margin::End(factor) => format!("pwt-me-{factor}").into(),
+        }
+    }
+}
+
+/// Wrapper type to specify CSS padding classes.
+///
+/// The factor is multiplied with the spacer base width. The CSS template
+/// defines classes for factors 0 to 4 (`pwt-p-{factor}`, `pwt-pt-{factor}`, ...).
+///
+/// Please use the [CssPaddingBuilder] methods if you need other sizes.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .class(Padding::X(2))
+/// # ;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Padding {
+    All(usize),
+    X(usize),
+    Y(usize),
+    Top(usize),
+    Bottom(usize),
+    Start(usize),
+    End(usize),
+}
+
+impl From<Padding> for Classes {
+    fn from(value: Padding) -> Self {
+        match value {
+            Padding::All(factor) => format!("pwt-p-{factor}").into(),
+            Padding::X(factor) => format!("pwt-px-{factor}").into(),
+            Padding::Y(factor) => format!("pwt-py-{factor}").into(),
+            Padding::Top(factor) => format!("pwt-pt-{factor}").into(),
+            Padding::Bottom(factor) => format!("pwt-pb-{factor}").into(),
+            Padding::Start(factor) => format!("pwt-ps-{factor}").into(),
+            Padding::End(factor) => format!("pwt-pe-{factor}").into(),
+        }
+    }
+}
+
+/// Border side, used by [Border] and [NoBorder].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BorderSide {
+    All,
+    Top,
+    Bottom,
+    Start,
+    End,
+    Left,
+    Right,
+}
+
+impl BorderSide {
+    fn suffix(self) -> &'static str {
+        match self {
+            BorderSide::All => "",
+            BorderSide::Top => "-top",
+            BorderSide::Bottom => "-bottom",
+            BorderSide::Start => "-start",
+            BorderSide::End => "-end",
+            BorderSide::Left => "-left",
+            BorderSide::Right => "-right",
+        }
+    }
+}
+
+/// CSS utility type to add a border (`pwt-border`, `pwt-border-top`, ...).
+///
+/// See also [CssBorderBuilder].
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .class(Border(BorderSide::Bottom))
+/// # ;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Border(pub BorderSide);
+impl From<Border> for Classes {
+    fn from(value: Border) -> Self {
+        format!("pwt-border{}", value.0.suffix()).into()
+    }
+}
+
+/// CSS utility type to remove a border (`pwt-no-border`, `pwt-no-border-top`, ...).
+///
+/// See also [CssBorderBuilder].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NoBorder(pub BorderSide);
+impl From<NoBorder> for Classes {
+    fn from(value: NoBorder) -> Self {
+        format!("pwt-no-border{}", value.0.suffix()).into()
+    }
+}
+
+/// Number of `z-index` values reserved for each [Layer].
+pub const LAYER_STEP: i32 = 100;
+
+/// Stacking layers (CSS `z-index` scale).
+///
+/// Widgets use these named layers instead of arbitrary `z-index` values, so
+/// that elements always stack in the expected order. Each layer reserves
+/// [LAYER_STEP] values, so custom layers created with [Layer::above] always
+/// slot between the built-in ones.
+///
+/// Note: Elements shown in the browser top layer (modal `<dialog>` and
+/// `popover` elements) are always above other content. The `z-index` only
+/// orders elements inside the same stacking context.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// use pwt::css::*;
+/// Container::new()
+///    .style("z-index", Layer::Sticky)
+/// # ;
+/// // slightly above other overlays, but below modal content
+/// Container::new()
+///    .style("z-index", Layer::Overlay.above(1))
+/// # ;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// Normal page content.
+    Content,
+    /// Sticky headers, footers and toolbars.
+    Sticky,
+    /// Load masks, drawers and other overlays.
+    Overlay,
+    /// Modal dialogs.
+    Modal,
+    /// Snackbars and notifications.
+    Toast,
+    /// Tooltips.
+    Tooltip,
+    /// Development tools like the [WidgetInspector](crate::widget::WidgetInspector).
+    Inspector,
+}
+
+impl Layer {
+    /// Returns the base `z-index` of the layer.
+    pub const fn z_index(self) -> i32 {
+        self as i32 * LAYER_STEP
+    }
+
+    /// Returns a custom `z-index` above this layer.
+    ///
+    /// The offset is limited, so that the result is always below the
+    /// next built-in layer.
+    pub fn above(self, offset: u16) -> ZIndex {
+        ZIndex(self.z_index() + (offset as i32).min(LAYER_STEP - 1))
+    }
+}
+
+/// A CSS `z-index` value (see [Layer]).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZIndex(pub i32);
+
+impl From<Layer> for ZIndex {
+    fn from(layer: Layer) -> Self {
+        ZIndex(layer.z_index())
+    }
+}
+
+impl fmt::Display for ZIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<ZIndex> for AttrValue {
+    fn from(value: ZIndex) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for ZIndex {
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Some(self.into())
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for Layer {
+    fn into_prop_value(self) -> Option<AttrValue> {
+        ZIndex::from(self).into_prop_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_spacing_classes() {
+        assert_eq!(Classes::from(Margin::Top(2)), Classes::from("pwt-mt-2"));
+        assert_eq!(Classes::from(Padding::X(1)), Classes::from("pwt-px-1"));
+        assert_eq!(Classes::from(Gap(4)), Classes::from("pwt-gap-4"));
+    }
+
+    #[test]
+    fn test_layers() {
+        assert!(Layer::Sticky.z_index() < Layer::Overlay.z_index());
+        assert!(Layer::Overlay.above(1) > ZIndex::from(Layer::Overlay));
+        assert!(Layer::Overlay.above(u16::MAX) < ZIndex::from(Layer::Modal));
+        assert_eq!(ZIndex::from(Layer::Toast).to_string(), "400");
+        assert!(Layer::Tooltip.above(u16::MAX) < ZIndex::from(Layer::Inspector));
+    }
+
+    #[test]
+    fn test_border_classes() {
+        assert_eq!(
+            Classes::from(Border(BorderSide::All)),
+            Classes::from("pwt-border")
+        );
+        assert_eq!(
+            Classes::from(NoBorder(BorderSide::End)),
+            Classes::from("pwt-no-border-end")
+        );
+    }
+}
@@ -92,6 +92,7 @@ pub struct AlignOptions {
     offset: (f64, f64),
     viewport_padding: f64,
     align_width: bool,
+    shift: bool,
 }
 
 impl Default for AlignOptions {
@@ -105,6 +106,7 @@ impl Default for AlignOptions {
             offset: (0.0, 0.0),
             viewport_padding: 0.0,
             align_width: false,
+            shift: false,
         }
     }
 }
@@ -122,6 +124,7 @@ impl AlignOptions {
             offset: (0.0, 0.0),
             viewport_padding: 0.0,
             align_width: false,
+            shift: false,
         }
     }
 
@@ -196,6 +199,18 @@ impl AlignOptions {
     pub fn set_align_width(&mut self, align_width: bool) {
         self.align_width = align_width;
     }
+
+    /// Builder style method to set `shift`
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.set_shift(shift);
+        self
+    }
+
+    /// Sets if the element should be shifted into the viewport if none of
+    /// the placements fits.
+    pub fn set_shift(&mut self, shift: bool) {
+        self.shift = shift;
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -222,6 +237,22 @@ impl Rect {
         self.y_end += y;
     }
 
+    // move inside the constraint (as far as possible)
+    fn shift_into(&mut self, constraint: &Rect) {
+        if self.x_end > constraint.x_end {
+            self.shift(constraint.x_end - self.x_end, 0.0);
+        }
+        if self.x < constraint.x {
+            self.shift(constraint.x - self.x, 0.0);
+        }
+        if self.y_end > constraint.y_end {
+            self.shift(0.0, constraint.y_end - self.y_end);
+        }
+        if self.y < constraint.y {
+            self.shift(0.0, constraint.y - self.y);
+        }
+    }
+
     fn get_position(&self, point: Point) -> (f64, f64) {
         let x = match &point {
             Point::TopStart | Point::Start | Point::BottomStart => self.x,
@@ -499,6 +530,10 @@ where
         }
     }
 
+    if options.shift && !fits(&rect, &window_rect, &GrowDirection::None) {
+        rect.shift_into(&window_rect);
+    }
+
     // since the node has 'position: fixed', we must correct the position for nodes which have a
     // transformed parent, see https://developer.mozilla.org/en-US/docs/Web/CSS/position
     if let Some(offset_parent) = get_containing_block(&element) {
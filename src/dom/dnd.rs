@@ -0,0 +1,212 @@
+//! Drag and drop helpers.
+//!
+//! HTML5 drag and drop can only transfer strings, and the transferred data
+//! is not readable before the drop. So we keep the payload of the current
+//! drag operation in a (thread local) shared state, and only put a marker
+//! type ([DND_MIME_TYPE]) into the [web_sys::DataTransfer] object. Drop
+//! targets use [accept_drag] inside their `dragover` and `drop` handlers to
+//! check if they can handle the dragged items.
+//!
+//! The same state is used for keyboard driven drag and drop, where a widget
+//! picks up items with [start_keyboard_drag], and the drop target gets them
+//! with [keyboard_drag_payload] (e.g. "cut and paste" with `Ctrl+X` and
+//! `Ctrl+V`).
+//!
+//! Widgets can use a [DragObserver] to update the visual feedback (for
+//! example to mark picked up items).
+
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+use crate::state::{SharedState, SharedStateObserver};
+
+/// Data type set on the [web_sys::DataTransfer] object for drag operations
+/// started with [start_drag].
+pub const DND_MIME_TYPE: &str = "application/x-pwt-dnd";
+
+/// Describes the dragged items.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DragPayload {
+    /// The kind of the dragged items (e.g. `"datatable-row"`).
+    ///
+    /// Drop targets only accept payloads of the kind they know about.
+    pub kind: AttrValue,
+    /// The ID of the drag source widget.
+    pub source_id: AttrValue,
+    /// The keys of the dragged items.
+    pub keys: Vec<Key>,
+}
+
+impl DragPayload {
+    /// Create a new instance.
+    pub fn new(
+        kind: impl Into<AttrValue>,
+        source_id: impl Into<AttrValue>,
+        keys: impl IntoIterator<Item = Key>,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            source_id: source_id.into(),
+            keys: keys.into_iter().collect(),
+        }
+    }
+}
+
+/// Drop position relative to the target item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropPosition {
+    /// Insert before the target.
+    Before,
+    /// Insert after the target.
+    After,
+    /// Insert into the target (e.g. a tree node or folder).
+    Inside,
+}
+
+#[derive(Clone, PartialEq)]
+struct DragState {
+    payload: DragPayload,
+    keyboard: bool,
+}
+
+// this `thread_local!` definition should be fine as this crate is essentially WASM only (see
+// `state::language`).
+thread_local! {
+    static DRAG_STATE: SharedState<Option<DragState>> = SharedState::new(None);
+}
+
+fn set_drag_state(state: Option<DragState>) {
+    if DRAG_STATE.with(|s| **s.read() == state) {
+        return; // nothing changed
+    }
+    DRAG_STATE.with(|s| **s.write() = state);
+}
+
+/// Start a pointer drag operation.
+///
+/// Call this inside the `dragstart` handler of the drag source. The keys are
+/// also set as plain text (one per line), so that dropping on other
+/// applications works as expected.
+pub fn start_drag(event: &DragEvent, payload: DragPayload) {
+    if let Some(data_transfer) = event.data_transfer() {
+        data_transfer.set_effect_allowed("move");
+        let _ = data_transfer.set_data(DND_MIME_TYPE, &payload.source_id);
+        let text: Vec<String> = payload.keys.iter().map(|key| key.to_string()).collect();
+        let _ = data_transfer.set_data("text/plain", &text.join("\n"));
+    }
+    set_drag_state(Some(DragState {
+        payload,
+        keyboard: false,
+    }));
+}
+
+/// Start a keyboard drag operation (pick up the items).
+pub fn start_keyboard_drag(payload: DragPayload) {
+    set_drag_state(Some(DragState {
+        payload,
+        keyboard: true,
+    }));
+}
+
+/// Finish or cancel the current drag operation.
+///
+/// The drag source should call this inside its `dragend` handler, and the
+/// drop target after handling a drop.
+pub fn end_drag() {
+    set_drag_state(None);
+}
+
+/// Returns the payload of the current drag operation (pointer or keyboard).
+pub fn drag_payload() -> Option<DragPayload> {
+    DRAG_STATE.with(|s| s.read().as_ref().map(|state| state.payload.clone()))
+}
+
+/// Returns the payload of a pending keyboard drag operation if its kind
+/// matches.
+pub fn keyboard_drag_payload(kind: &str) -> Option<DragPayload> {
+    DRAG_STATE.with(|s| {
+        s.read()
+            .as_ref()
+            .filter(|state| state.keyboard && state.payload.kind == kind)
+            .map(|state| state.payload.clone())
+    })
+}
+
+/// Check if a `dragover` (or `drop`) event carries an acceptable payload.
+///
+/// The `accept` function decides about the payload (usually by checking
+/// [DragPayload::kind] and [DragPayload::source_id]). If accepted, we prevent
+/// the default action (which allows the drop), set the drop effect and
+/// return the payload. Drags from other sources (files, text, ...) are
+/// ignored.
+pub fn accept_drag(
+    event: &DragEvent,
+    accept: impl Fn(&DragPayload) -> bool,
+) -> Option<DragPayload> {
+    let data_transfer = event.data_transfer()?;
+    let has_type = data_transfer
+        .types()
+        .iter()
+        .any(|ty| ty.as_string().as_deref() == Some(DND_MIME_TYPE));
+    if !has_type {
+        return None;
+    }
+
+    let payload = DRAG_STATE.with(|s| {
+        s.read()
+            .as_ref()
+            .filter(|state| !state.keyboard && accept(&state.payload))
+            .map(|state| state.payload.clone())
+    })?;
+
+    event.prevent_default();
+    data_transfer.set_drop_effect("move");
+
+    Some(payload)
+}
+
+/// Compute the drop position from the pointer position inside the target
+/// element.
+///
+/// The upper half of the element means [DropPosition::Before], the lower
+/// half [DropPosition::After]. If `allow_inside` is set, the middle third
+/// returns [DropPosition::Inside].
+pub fn drop_position(event: &DragEvent, el: &web_sys::Element, allow_inside: bool) -> DropPosition {
+    let rect = el.get_bounding_client_rect();
+    let offset = event.client_y() as f64 - rect.y();
+    let height = rect.height();
+
+    if allow_inside {
+        if offset < height / 3.0 {
+            DropPosition::Before
+        } else if offset > height * 2.0 / 3.0 {
+            DropPosition::After
+        } else {
+            DropPosition::Inside
+        }
+    } else if offset < height / 2.0 {
+        DropPosition::Before
+    } else {
+        DropPosition::After
+    }
+}
+
+/// Listen to drag state changes.
+///
+/// The callback is called when a drag operation starts or ends.
+pub struct DragObserver {
+    _observer: SharedStateObserver<Option<DragState>>,
+}
+
+impl DragObserver {
+    /// Create a new instance.
+    pub fn new(on_change: Callback<Option<DragPayload>>) -> Self {
+        let _observer = DRAG_STATE.with(|state| {
+            state.add_listener(move |state: SharedState<Option<DragState>>| {
+                let payload = state.read().as_ref().map(|state| state.payload.clone());
+                on_change.emit(payload);
+            })
+        });
+        Self { _observer }
+    }
+}
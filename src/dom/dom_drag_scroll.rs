@@ -0,0 +1,225 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gloo_events::{EventListener, EventListenerOptions};
+use gloo_timers::callback::Interval;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, PointerEvent};
+
+// Pointer movement (in pixels) needed to start dragging.
+const DRAG_THRESHOLD: f64 = 5.0;
+// Momentum animation interval (ms).
+const MOMENTUM_INTERVAL: u32 = 16;
+// Velocity decay per momentum step.
+const MOMENTUM_DECAY: f64 = 0.95;
+// Stop the momentum animation below this velocity (pixels/ms).
+const MOMENTUM_MIN_VELOCITY: f64 = 0.02;
+
+struct DragScrollState {
+    el: HtmlElement,
+    pointer_id: Option<i32>,
+    dragging: bool,
+    last_x: i32,
+    last_y: i32,
+    last_time: f64,
+    distance: f64,
+    velocity_x: f64,
+    velocity_y: f64,
+    momentum: Option<Interval>,
+    // suppress the click event after a drag
+    suppress_click: bool,
+}
+
+impl DragScrollState {
+    fn set_user_select(&self, enabled: bool) {
+        let style = self.el.style();
+        if enabled {
+            let _ = style.remove_property("user-select");
+        } else {
+            let _ = style.set_property("user-select", "none");
+        }
+    }
+
+    fn scroll_by(&self, dx: f64, dy: f64) {
+        self.el
+            .set_scroll_left(self.el.scroll_left() - dx.round() as i32);
+        self.el
+            .set_scroll_top(self.el.scroll_top() - dy.round() as i32);
+    }
+}
+
+/// Kinetic drag-to-scroll behavior.
+///
+/// Allows to scroll an element by dragging its content with the mouse. The
+/// content keeps scrolling with decreasing speed (momentum) after releasing
+/// the mouse button. Text selection is suppressed while dragging, and so is
+/// the click event at the end of a drag.
+///
+/// Touch and pen input is ignored, because browsers already provide native
+/// touch scrolling.
+///
+/// The behavior is removed when this struct is dropped.
+pub struct DomDragScroll {
+    state: Rc<RefCell<DragScrollState>>,
+    _listeners: Vec<EventListener>,
+}
+
+impl DomDragScroll {
+    /// Creates a new instance.
+    pub fn new(el: &HtmlElement) -> Self {
+        let state = Rc::new(RefCell::new(DragScrollState {
+            el: el.clone(),
+            pointer_id: None,
+            dragging: false,
+            last_x: 0,
+            last_y: 0,
+            last_time: 0.0,
+            distance: 0.0,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            momentum: None,
+            suppress_click: false,
+        }));
+
+        let document = gloo_utils::document();
+
+        let pointerdown = EventListener::new(el, "pointerdown", {
+            let state = state.clone();
+            move |event| {
+                let event = match event.dyn_ref::<PointerEvent>() {
+                    Some(event) => event,
+                    None => return,
+                };
+                if event.pointer_type() != "mouse" || event.button() != 0 {
+                    return;
+                }
+                let mut state = state.borrow_mut();
+                state.momentum = None;
+                state.pointer_id = Some(event.pointer_id());
+                state.dragging = false;
+                state.last_x = event.client_x();
+                state.last_y = event.client_y();
+                state.last_time = js_sys::Date::now();
+                state.distance = 0.0;
+                state.velocity_x = 0.0;
+                state.velocity_y = 0.0;
+                state.suppress_click = false;
+            }
+        });
+
+        let options = EventListenerOptions::enable_prevent_default();
+
+        let pointermove = EventListener::new_with_options(&document, "pointermove", options, {
+            let state = state.clone();
+            move |event| {
+                let event = match event.dyn_ref::<PointerEvent>() {
+                    Some(event) => event,
+                    None => return,
+                };
+                let mut state = state.borrow_mut();
+                if state.pointer_id != Some(event.pointer_id()) {
+                    return;
+                }
+
+                let dx = (event.client_x() - state.last_x) as f64;
+                let dy = (event.client_y() - state.last_y) as f64;
+                let now = js_sys::Date::now();
+                let elapsed = (now - state.last_time).max(1.0);
+
+                state.last_x = event.client_x();
+                state.last_y = event.client_y();
+                state.last_time = now;
+                state.distance += dx.abs() + dy.abs();
+
+                if !state.dragging {
+                    if state.distance < DRAG_THRESHOLD {
+                        return;
+                    }
+                    state.dragging = true;
+                    state.set_user_select(false);
+                }
+
+                event.prevent_default();
+                state.scroll_by(dx, dy);
+
+                // smooth out the velocity
+                state.velocity_x = 0.8 * (dx / elapsed) + 0.2 * state.velocity_x;
+                state.velocity_y = 0.8 * (dy / elapsed) + 0.2 * state.velocity_y;
+            }
+        });
+
+        let pointerup = EventListener::new(&document, "pointerup", {
+            let state = state.clone();
+            move |event| {
+                let event = match event.dyn_ref::<PointerEvent>() {
+                    Some(event) => event,
+                    None => return,
+                };
+                let mut state_guard = state.borrow_mut();
+                if state_guard.pointer_id != Some(event.pointer_id()) {
+                    return;
+                }
+                state_guard.pointer_id = None;
+                if !state_guard.dragging {
+                    return;
+                }
+                state_guard.dragging = false;
+                state_guard.suppress_click = true;
+                state_guard.set_user_select(true);
+
+                // ignore the velocity if the pointer rested before release
+                if js_sys::Date::now() - state_guard.last_time > 100.0 {
+                    return;
+                }
+
+                let state = state.clone();
+                state_guard.momentum = Some(Interval::new(MOMENTUM_INTERVAL, move || {
+                    let mut state = state.borrow_mut();
+                    state.velocity_x *= MOMENTUM_DECAY;
+                    state.velocity_y *= MOMENTUM_DECAY;
+                    if state.velocity_x.abs() < MOMENTUM_MIN_VELOCITY
+                        && state.velocity_y.abs() < MOMENTUM_MIN_VELOCITY
+                    {
+                        state.momentum = None;
+                        return;
+                    }
+                    let step = MOMENTUM_INTERVAL as f64;
+                    state.scroll_by(state.velocity_x * step, state.velocity_y * step);
+                }));
+            }
+        });
+
+        let click = EventListener::new_with_options(
+            el,
+            "click",
+            EventListenerOptions::run_in_capture_phase(),
+            {
+                let state = state.clone();
+                move |event| {
+                    let mut state = state.borrow_mut();
+                    if state.suppress_click {
+                        state.suppress_click = false;
+                        event.stop_propagation();
+                    }
+                }
+            },
+        );
+
+        Self {
+            state,
+            _listeners: vec![pointerdown, pointermove, pointerup, click],
+        }
+    }
+}
+
+impl Drop for DomDragScroll {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.state.try_borrow_mut() {
+            // the momentum interval holds a reference to the state
+            state.momentum = None;
+            if state.dragging {
+                state.set_user_select(true);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Input modality tracking (keyboard vs. pointer).
+//!
+//! Browsers only show focus rings for `:focus-visible`, but their heuristic
+//! differs between elements (e.g. focusable `div`s show a ring after mouse
+//! clicks in some browsers). So we track the last used input device
+//! globally, and mark the focused element with the
+//! [FOCUS_VISIBLE_ATTRIBUTE] only if the focus was moved with the keyboard.
+//! The document root (`<html>`) gets either the `pwt-keyboard-modality` or
+//! the `pwt-pointer-modality` class.
+//!
+//! Tracking starts automatically when the first widget gets rendered (see
+//! [WidgetStdProps::into_vtag](crate::props::WidgetStdProps::into_vtag)),
+//! so this works for all widgets, including custom ones. Widgets can use an
+//! [InputModalityObserver] (or the context provided by
+//! [DesktopApp](crate::widget::DesktopApp)) to react on changes.
+//!
+//! Note: We use a data attribute instead of a class, because yew overwrites
+//! the `class` attribute whenever a widget changes its classes.
+
+use std::cell::Cell;
+
+use gloo_events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::state::{SharedState, SharedStateObserver};
+
+/// Attribute set on the focused element if focus was moved with the
+/// keyboard.
+///
+/// Use `[data-pwt-focus-visible]` instead of `:focus-visible` in CSS rules.
+pub const FOCUS_VISIBLE_ATTRIBUTE: &str = "data-pwt-focus-visible";
+
+/// The last used input device.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputModality {
+    /// Mouse, pen or touch.
+    #[default]
+    Pointer,
+    /// Keyboard.
+    Keyboard,
+}
+
+// this `thread_local!` definition should be fine as this crate is essentially WASM only (see
+// `state::language`).
+thread_local! {
+    static INPUT_MODALITY: SharedState<InputModality> = SharedState::new(InputModality::default());
+    static TRACKING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns the last used input device.
+pub fn input_modality() -> InputModality {
+    INPUT_MODALITY.with(|s| **s.read())
+}
+
+fn set_input_modality(modality: InputModality) {
+    if input_modality() == modality {
+        return; // nothing changed
+    }
+
+    if let Some(root) = gloo_utils::document().document_element() {
+        let class_list = root.class_list();
+        let _ = class_list.remove_2("pwt-keyboard-modality", "pwt-pointer-modality");
+        let _ = class_list.add_1(match modality {
+            InputModality::Keyboard => "pwt-keyboard-modality",
+            InputModality::Pointer => "pwt-pointer-modality",
+        });
+    }
+
+    if let Some(el) = gloo_utils::document().active_element() {
+        set_focus_visible(&el, modality == InputModality::Keyboard);
+    }
+
+    INPUT_MODALITY.with(|s| **s.write() = modality);
+}
+
+fn set_focus_visible(el: &web_sys::Element, visible: bool) {
+    if visible {
+        let _ = el.set_attribute(FOCUS_VISIBLE_ATTRIBUTE, "");
+    } else {
+        let _ = el.remove_attribute(FOCUS_VISIBLE_ATTRIBUTE);
+    }
+}
+
+/// Start tracking the input modality.
+///
+/// This installs global (capturing) event listeners on the document. It is
+/// safe to call this multiple times.
+pub fn init_input_modality_tracking() {
+    // widgets are also created in (native) tests, where we have no document
+    if !cfg!(target_arch = "wasm32") {
+        return;
+    }
+    if TRACKING.with(|tracking| tracking.replace(true)) {
+        return;
+    }
+
+    let document = gloo_utils::document();
+    let options = EventListenerOptions::run_in_capture_phase();
+
+    EventListener::new_with_options(&document, "keydown", options, |event| {
+        let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+            return;
+        };
+        // ignore shortcuts like Ctrl+C
+        if event.meta_key() || event.ctrl_key() || event.alt_key() {
+            return;
+        }
+        set_input_modality(InputModality::Keyboard);
+    })
+    .forget();
+
+    EventListener::new_with_options(&document, "pointerdown", options, |_| {
+        set_input_modality(InputModality::Pointer);
+    })
+    .forget();
+
+    EventListener::new_with_options(&document, "focusin", options, |event| {
+        if let Some(el) = event
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        {
+            set_focus_visible(&el, input_modality() == InputModality::Keyboard);
+        }
+    })
+    .forget();
+
+    EventListener::new_with_options(&document, "focusout", options, |event| {
+        if let Some(el) = event
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        {
+            set_focus_visible(&el, false);
+        }
+    })
+    .forget();
+}
+
+/// Listen to input modality changes.
+pub struct InputModalityObserver {
+    _observer: SharedStateObserver<InputModality>,
+}
+
+impl InputModalityObserver {
+    /// Create a new instance.
+    ///
+    /// This also starts the tracking (see [init_input_modality_tracking]).
+    pub fn new(on_change: Callback<InputModality>) -> Self {
+        init_input_modality_tracking();
+        let _observer = INPUT_MODALITY.with(|state| {
+            state.add_listener(move |state: SharedState<InputModality>| {
+                on_change.emit(**state.read());
+            })
+        });
+        Self { _observer }
+    }
+}
@@ -2,8 +2,16 @@
 
 pub mod align;
 
+pub mod dnd;
+
 pub mod focus;
 
+mod input_modality;
+pub use input_modality::{
+    FOCUS_VISIBLE_ATTRIBUTE, InputModality, InputModalityObserver, init_input_modality_tracking,
+    input_modality,
+};
+
 mod number_format;
 pub use number_format::{LocaleInfo, format_float, parse_float};
 
@@ -13,6 +21,15 @@ pub use dom_size_observer::{DomSizeObserver, IntoSizeCallback, SizeCallback};
 mod dom_visibility_observer;
 pub use dom_visibility_observer::DomVisibilityObserver;
 
+mod dom_drag_scroll;
+pub use dom_drag_scroll::DomDragScroll;
+
+mod safe_triangle;
+pub use safe_triangle::pointer_moves_towards;
+
+mod text_highlight;
+pub use text_highlight::DomTextHighlighter;
+
 use web_sys::Node;
 use yew::prelude::*;
 
@@ -67,6 +84,26 @@ pub fn element_direction_rtl<T: IntoHtmlElement>(node: T) -> Option<bool> {
     None
 }
 
+/// Capture the pointer to the element referenced by `node_ref`.
+///
+/// All further events of that pointer get targeted at the element (using
+/// `setPointerCapture()`), even if the pointer leaves it. This is useful for drag
+/// operations. Returns `false` if the capture failed.
+pub fn set_pointer_capture(node_ref: &NodeRef, pointer_id: i32) -> bool {
+    match node_ref.cast::<web_sys::Element>() {
+        Some(el) => el.set_pointer_capture(pointer_id).is_ok(),
+        None => false,
+    }
+}
+
+/// Release a pointer captured with [set_pointer_capture].
+pub fn release_pointer_capture(node_ref: &NodeRef, pointer_id: i32) -> bool {
+    match node_ref.cast::<web_sys::Element>() {
+        Some(el) => el.release_pointer_capture(pointer_id).is_ok(),
+        None => false,
+    }
+}
+
 /// Returns if the system prefers dark mode
 pub fn get_system_prefer_dark_mode() -> bool {
     if let Ok(Some(list)) = gloo_utils::window().match_media("(prefers-color-scheme: dark)") {
@@ -75,3 +112,12 @@ pub fn get_system_prefer_dark_mode() -> bool {
         false
     }
 }
+
+/// Returns if the system prefers reduced motion (no animations)
+pub fn get_system_prefer_reduced_motion() -> bool {
+    if let Ok(Some(list)) = gloo_utils::window().match_media("(prefers-reduced-motion: reduce)") {
+        list.matches()
+    } else {
+        false
+    }
+}
@@ -11,6 +11,8 @@ pub struct LocaleInfo {
     pub decimal: String,
     /// Digit group separator.
     pub group: String,
+    // Numeric date format (PHP style tokens `Y`, `m` and `d`, e.g. "d.m.Y").
+    date_format: String,
 }
 
 fn test_browser_locale() -> Option<LocaleInfo> {
@@ -30,7 +32,55 @@ fn test_browser_locale() -> Option<LocaleInfo> {
         .map(|i| i.value.clone());
 
     if let (Some(decimal), Some(group)) = (decimal, group) {
-        Some(LocaleInfo { decimal, group })
+        let date_format = test_browser_date_format().unwrap_or_else(|| "Y-m-d".into());
+        Some(LocaleInfo {
+            decimal,
+            group,
+            date_format,
+        })
+    } else {
+        None
+    }
+}
+
+fn test_browser_date_format() -> Option<String> {
+    let options = js_sys::Object::new();
+    for (key, value) in [
+        ("year", "numeric"),
+        ("month", "2-digit"),
+        ("day", "2-digit"),
+    ] {
+        js_sys::Reflect::set(&options, &key.into(), &value.into()).ok()?;
+    }
+    let dtf = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &options);
+
+    let date = js_sys::Date::new_with_year_month_day(2001, 10, 22);
+    let info = dtf.format_to_parts(&date);
+
+    let parts: Vec<NumberPartInfo> = serde_wasm_bindgen::from_value(info.into()).ok()?;
+    date_format_from_parts(&parts)
+}
+
+// Convert Intl.DateTimeFormat parts into a PHP style format string.
+fn date_format_from_parts(parts: &[NumberPartInfo]) -> Option<String> {
+    let mut format = String::new();
+    for part in parts {
+        match part.ty.as_str() {
+            "year" => format.push('Y'),
+            "month" => format.push('m'),
+            "day" => format.push('d'),
+            "literal" => {
+                // Avoid format tokens inside literals
+                if part.value.contains(['Y', 'y', 'm', 'n', 'd', 'j']) {
+                    return None;
+                }
+                format.push_str(&part.value);
+            }
+            _ => return None,
+        }
+    }
+    if format.contains('Y') && format.contains('m') && format.contains('d') {
+        Some(format)
     } else {
         None
     }
@@ -70,6 +120,7 @@ impl Default for LocaleInfo {
         Self {
             decimal: ".".into(),
             group: ",".into(),
+            date_format: "Y-m-d".into(),
         }
     }
 }
@@ -80,6 +131,11 @@ impl LocaleInfo {
         get_browser_locale_info()
     }
 
+    /// Numeric date format (PHP style tokens `Y`, `m` and `d`, e.g. "d.m.Y").
+    pub fn date_format(&self) -> &str {
+        &self.date_format
+    }
+
     /// Rust f64 float format, but replaces decimal point from browser locale settings.
     pub fn format_float(&self, value: f64) -> String {
         let mut text = value.to_string();
@@ -119,6 +175,94 @@ impl LocaleInfo {
 
         Ok(number)
     }
+
+    /// Convert a language-sensitive number representation into canonical format.
+    ///
+    /// Replaces the decimal separator with '.', so that the result can be
+    /// parsed with Rust's `FromStr` implementation. This does not
+    /// validate the number itself.
+    ///
+    /// Digit group separators are rejected, unless `grouping` is set. In
+    /// that case, they are removed, but only if they are at valid
+    /// positions (every 3 digits of the integer part). This avoids
+    /// misinterpreting input like "1.5" in a German locale.
+    pub fn canonicalize(&self, text: &str, grouping: bool) -> Result<String, String> {
+        let mut text = text.trim().to_string();
+        // allow normal spaces if the group separator is some kind of space (i.e. U+202F)
+        if !self.group.is_empty() && self.group.chars().all(char::is_whitespace) {
+            text = text.replace(' ', &self.group);
+        }
+
+        let mut text = text.replace(&self.decimal, "{D}");
+        if !self.group.is_empty() {
+            text = text.replace(&self.group, "{G}");
+        }
+        if text.contains(['.', ',']) {
+            return Err(tr!("invalid float literal (wrong decimal separator)"));
+        }
+
+        if text.contains("{G}") {
+            if !grouping {
+                return Err(tr!("invalid float literal"));
+            }
+            let (int_part, frac_part) = match text.split_once("{D}") {
+                Some((int_part, frac_part)) => (int_part, frac_part),
+                None => (text.as_str(), ""),
+            };
+            if !valid_digit_groups(int_part) || frac_part.contains("{G}") {
+                return Err(tr!("invalid float literal (wrong digit grouping)"));
+            }
+            text = text.replace("{G}", "");
+        }
+
+        Ok(text.replace("{D}", "."))
+    }
+
+    /// Format a canonical number representation (as returned by
+    /// `to_string()`) using the locale decimal separator, optionally
+    /// adding digit group separators.
+    pub fn format_canonical(&self, text: &str, grouping: bool) -> String {
+        let (sign, rest) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text),
+        };
+        let (int_part, frac_part) = match rest.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (rest, None),
+        };
+
+        let mut result = String::from(sign);
+        if grouping && !self.group.is_empty() && int_part.chars().all(|c| c.is_ascii_digit()) {
+            let len = int_part.len();
+            for (i, c) in int_part.chars().enumerate() {
+                if i > 0 && (len - i) % 3 == 0 {
+                    result.push_str(&self.group);
+                }
+                result.push(c);
+            }
+        } else {
+            result.push_str(int_part);
+        }
+        if let Some(frac_part) = frac_part {
+            result.push_str(&self.decimal);
+            result.push_str(frac_part);
+        }
+        result
+    }
+}
+
+// Check that an integer part with group separators (`{G}`) has a group
+// separator every 3 digits.
+fn valid_digit_groups(int_part: &str) -> bool {
+    let int_part = int_part.strip_prefix(['-', '+']).unwrap_or(int_part);
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+    let mut groups = int_part.split("{G}");
+    let first_ok = groups
+        .next()
+        .is_some_and(|first| (1..=3).contains(&first.len()) && is_digits(first));
+
+    first_ok && groups.all(|group| group.len() == 3 && is_digits(group))
 }
 
 // result from js_sys::Intl::NumberFormat::format_to_parts
@@ -128,3 +272,94 @@ struct NumberPartInfo {
     ty: String,
     value: String,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{LocaleInfo, NumberPartInfo, date_format_from_parts};
+
+    fn german() -> LocaleInfo {
+        LocaleInfo {
+            decimal: ",".into(),
+            group: ".".into(),
+            date_format: "d.m.Y".into(),
+        }
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let en = LocaleInfo::default();
+        assert_eq!(en.canonicalize("1,234.5", true).unwrap(), "1234.5");
+        assert_eq!(en.canonicalize("-1,234,567", true).unwrap(), "-1234567");
+        assert_eq!(en.canonicalize(" -12 ", false).unwrap(), "-12");
+        assert_eq!(en.canonicalize("1.5", false).unwrap(), "1.5");
+        assert!(en.canonicalize("1,234.5", false).is_err());
+        assert!(en.canonicalize("1,5", false).is_err());
+        assert!(en.canonicalize("1,5", true).is_err());
+        assert!(en.canonicalize("1234,567", true).is_err());
+        assert!(en.canonicalize("1,234.567,8", true).is_err());
+
+        let de = german();
+        assert_eq!(de.canonicalize("1.234,5", true).unwrap(), "1234.5");
+        assert_eq!(de.canonicalize("0,5e3", false).unwrap(), "0.5e3");
+        assert_eq!(de.canonicalize("1,5", false).unwrap(), "1.5");
+        assert!(de.canonicalize("1.5", false).is_err());
+        assert!(de.canonicalize("1.5", true).is_err());
+        assert!(de.canonicalize("1.234,5", false).is_err());
+
+        let fr = LocaleInfo {
+            decimal: ",".into(),
+            group: "\u{202f}".into(),
+            ..LocaleInfo::default()
+        };
+        assert_eq!(fr.canonicalize("1 234,5", true).unwrap(), "1234.5");
+        assert_eq!(fr.canonicalize("1\u{202f}234,5", true).unwrap(), "1234.5");
+        assert!(fr.canonicalize("1 234,5", false).is_err());
+        assert!(fr.canonicalize("1.5", true).is_err());
+    }
+
+    #[test]
+    fn test_format_canonical() {
+        let en = LocaleInfo::default();
+        assert_eq!(en.format_canonical("1234567.25", true), "1,234,567.25");
+        assert_eq!(en.format_canonical("1234567.25", false), "1234567.25");
+        assert_eq!(en.format_canonical("-123", true), "-123");
+        assert_eq!(en.format_canonical("-1234", true), "-1,234");
+
+        let de = german();
+        assert_eq!(de.format_canonical("1234.5", true), "1.234,5");
+        assert_eq!(de.format_canonical("1e21", true), "1e21");
+    }
+
+    #[test]
+    fn test_date_format_from_parts() {
+        let parts = |list: &[(&str, &str)]| -> Vec<NumberPartInfo> {
+            list.iter()
+                .map(|(ty, value)| NumberPartInfo {
+                    ty: ty.to_string(),
+                    value: value.to_string(),
+                })
+                .collect()
+        };
+
+        let de = parts(&[
+            ("day", "22"),
+            ("literal", "."),
+            ("month", "11"),
+            ("literal", "."),
+            ("year", "2001"),
+        ]);
+        assert_eq!(date_format_from_parts(&de).as_deref(), Some("d.m.Y"));
+
+        let us = parts(&[
+            ("month", "11"),
+            ("literal", "/"),
+            ("day", "22"),
+            ("literal", "/"),
+            ("year", "2001"),
+        ]);
+        assert_eq!(date_format_from_parts(&us).as_deref(), Some("m/d/Y"));
+
+        let invalid = parts(&[("month", "11"), ("literal", "/"), ("year", "2001")]);
+        assert_eq!(date_format_from_parts(&invalid), None);
+    }
+}
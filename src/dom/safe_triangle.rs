@@ -0,0 +1,79 @@
+use web_sys::{Element, MouseEvent};
+
+/// Test if the pointer moves towards an element (safe triangle).
+///
+/// Hover menus and tooltips use this to avoid closing a popup while the
+/// user moves the pointer diagonally towards it. The triangle spans from the
+/// previous pointer position (computed from the event movement) to the
+/// nearest edge of the target element.
+pub fn pointer_moves_towards(event: &MouseEvent, target: &Element) -> bool {
+    let x = event.client_x() as f64;
+    let y = event.client_y() as f64;
+    let origin = (x - event.movement_x() as f64, y - event.movement_y() as f64);
+
+    if origin == (x, y) {
+        return false; // no movement information
+    }
+
+    let rect = target.get_bounding_client_rect();
+    in_safe_triangle(
+        origin,
+        (x, y),
+        (rect.left(), rect.top(), rect.right(), rect.bottom()),
+    )
+}
+
+/// Test if `point` is inside the triangle spanned by `origin` and the
+/// nearest edge of `rect` (`(left, top, right, bottom)`).
+fn in_safe_triangle(origin: (f64, f64), point: (f64, f64), rect: (f64, f64, f64, f64)) -> bool {
+    let (left, top, right, bottom) = rect;
+    let (ox, oy) = origin;
+
+    let (b, c) = if ox <= left {
+        ((left, top), (left, bottom))
+    } else if ox >= right {
+        ((right, top), (right, bottom))
+    } else if oy <= top {
+        ((left, top), (right, top))
+    } else if oy >= bottom {
+        ((left, bottom), (right, bottom))
+    } else {
+        return true; // origin inside rect
+    };
+
+    let cross = |p1: (f64, f64), p2: (f64, f64), p: (f64, f64)| {
+        (p2.0 - p1.0) * (p.1 - p1.1) - (p2.1 - p1.1) * (p.0 - p1.0)
+    };
+
+    let d1 = cross(origin, b, point);
+    let d2 = cross(b, c, point);
+    let d3 = cross(c, origin, point);
+
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+
+    !(has_neg && has_pos)
+}
+
+#[cfg(test)]
+mod test {
+    use super::in_safe_triangle;
+
+    const RECT: (f64, f64, f64, f64) = (100.0, 0.0, 200.0, 100.0);
+
+    #[test]
+    fn test_safe_triangle() {
+        // moving right, towards the rect
+        assert!(in_safe_triangle((50.0, 50.0), (60.0, 50.0), RECT));
+        // moving diagonally, towards the rect
+        assert!(in_safe_triangle((50.0, 90.0), (60.0, 80.0), RECT));
+        // moving away from the rect
+        assert!(!in_safe_triangle((50.0, 50.0), (40.0, 50.0), RECT));
+        // moving down, passing the rect
+        assert!(!in_safe_triangle((50.0, 90.0), (50.0, 110.0), RECT));
+        // rect on the left side
+        assert!(in_safe_triangle((250.0, 50.0), (240.0, 45.0), RECT));
+        // origin inside rect
+        assert!(in_safe_triangle((150.0, 50.0), (0.0, 0.0), RECT));
+    }
+}
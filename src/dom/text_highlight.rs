@@ -0,0 +1,277 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, Node, Range, ScrollIntoViewOptions, ScrollLogicalPosition, Text};
+
+use crate::web_sys_ext::{Highlight, css_highlights};
+
+// Elements we never search inside.
+const SKIP_TAGS: &[&str] = &["SCRIPT", "STYLE", "TEXTAREA", "INPUT", "SELECT"];
+
+// Highlight names, styled with `::highlight(<name>)`.
+const MATCH_HIGHLIGHT: &str = "pwt-find-match";
+const CURRENT_HIGHLIGHT: &str = "pwt-find-current";
+
+/// Highlight text matches inside a DOM subtree.
+///
+/// Matches are highlighted using the CSS Custom Highlight API, so the DOM
+/// is never modified and the virtual DOM can re-render the content at any
+/// time. The theme styles all matches with `::highlight(pwt-find-match)`,
+/// and the selected match with `::highlight(pwt-find-current)`.
+///
+/// Browsers without highlight support can still navigate the matches
+/// (the current match is scrolled into view).
+///
+/// # Note
+///
+/// Matches refer to the text nodes found by [highlight](Self::highlight),
+/// so please call it again after the content changed. Highlights are
+/// removed automatically when the object is dropped.
+pub struct DomTextHighlighter {
+    root: Element,
+    matches: Vec<Range>,
+    current: Option<usize>,
+    // None if the browser does not support highlights
+    highlights: Option<(Highlight, Highlight)>,
+}
+
+impl DomTextHighlighter {
+    /// Create a new instance for the subtree below `root`.
+    pub fn new(root: &Element) -> Self {
+        let highlights = match (Highlight::new(), Highlight::new()) {
+            (Ok(all), Ok(current)) if css_highlights().is_some() => Some((all, current)),
+            _ => None,
+        };
+        Self {
+            root: root.clone(),
+            matches: Vec::new(),
+            current: None,
+            highlights,
+        }
+    }
+
+    /// Highlight all occurrences of `query`.
+    ///
+    /// Previous highlights are removed. Returns the number of matches.
+    pub fn highlight(&mut self, query: &str, case_sensitive: bool) -> usize {
+        self.clear();
+        if query.is_empty() {
+            return 0;
+        }
+
+        let mut text_nodes = Vec::new();
+        collect_text_nodes(&self.root, &mut text_nodes);
+
+        let document = gloo_utils::document();
+
+        for text in text_nodes {
+            for (start, end) in find_text_matches(&text.data(), query, case_sensitive) {
+                let Ok(range) = document.create_range() else {
+                    continue;
+                };
+                if range.set_start(&text, start).is_err() || range.set_end(&text, end).is_err() {
+                    continue;
+                }
+                self.matches.push(range);
+            }
+        }
+
+        if let Some((all, current)) = &self.highlights {
+            for range in &self.matches {
+                all.add(range);
+            }
+            if let Some(registry) = css_highlights() {
+                registry.set(MATCH_HIGHLIGHT, all);
+                registry.set(CURRENT_HIGHLIGHT, current);
+            }
+        }
+
+        self.matches.len()
+    }
+
+    /// Remove all highlights.
+    pub fn clear(&mut self) {
+        self.matches.clear();
+        self.current = None;
+
+        if let Some((all, current)) = &self.highlights {
+            all.clear();
+            current.clear();
+            // Note: other instances may have registered their highlights
+            if let Some(registry) = css_highlights() {
+                for (name, highlight) in [(MATCH_HIGHLIGHT, all), (CURRENT_HIGHLIGHT, current)] {
+                    let highlight: &JsValue = highlight.as_ref();
+                    if registry.get(name) == *highlight {
+                        registry.delete(name);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the number of matches.
+    pub fn count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Returns the index of the current match.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Select a match (highlighted as `pwt-find-current`) and scroll it into view.
+    pub fn select(&mut self, index: usize) -> Option<usize> {
+        let range = self.matches.get(index)?;
+
+        if let Some((_, current)) = &self.highlights {
+            current.clear();
+            current.add(range);
+        }
+
+        let element = range
+            .start_container()
+            .ok()
+            .and_then(|node| node.parent_element());
+        if let Some(element) = element {
+            let options = ScrollIntoViewOptions::new();
+            options.set_block(ScrollLogicalPosition::Center);
+            options.set_inline(ScrollLogicalPosition::Nearest);
+            element.scroll_into_view_with_scroll_into_view_options(&options);
+        }
+
+        self.current = Some(index);
+        self.current
+    }
+
+    /// Select the next match (wraps around).
+    pub fn next(&mut self) -> Option<usize> {
+        let count = self.matches.len();
+        if count == 0 {
+            return None;
+        }
+        let index = match self.current {
+            Some(current) => (current + 1) % count,
+            None => 0,
+        };
+        self.select(index)
+    }
+
+    /// Select the previous match (wraps around).
+    pub fn previous(&mut self) -> Option<usize> {
+        let count = self.matches.len();
+        if count == 0 {
+            return None;
+        }
+        let index = match self.current {
+            Some(0) | None => count - 1,
+            Some(current) => current - 1,
+        };
+        self.select(index)
+    }
+}
+
+impl Drop for DomTextHighlighter {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+fn collect_text_nodes(node: &Node, list: &mut Vec<Text>) {
+    let children = node.child_nodes();
+    for i in 0..children.length() {
+        let Some(child) = children.item(i) else {
+            continue;
+        };
+        match child.node_type() {
+            Node::TEXT_NODE => {
+                if let Ok(text) = child.dyn_into::<Text>() {
+                    list.push(text);
+                }
+            }
+            Node::ELEMENT_NODE => {
+                if SKIP_TAGS.contains(&child.node_name().as_str()) {
+                    continue;
+                }
+                if let Some(el) = child.dyn_ref::<Element>() {
+                    if el.class_list().contains("pwt-find-bar") {
+                        continue;
+                    }
+                }
+                collect_text_nodes(&child, list);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Find all (non-overlapping) occurrences of `query` inside `text`.
+///
+/// Returns the match ranges as UTF-16 offsets (as used by the DOM).
+pub(crate) fn find_text_matches(text: &str, query: &str, case_sensitive: bool) -> Vec<(u32, u32)> {
+    let chars: Vec<char> = text.chars().collect();
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let char_eq = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a == b || a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+
+    // UTF-16 offset for each char position
+    let mut offsets = Vec::with_capacity(chars.len() + 1);
+    let mut offset = 0u32;
+    for c in &chars {
+        offsets.push(offset);
+        offset += c.len_utf16() as u32;
+    }
+    offsets.push(offset);
+
+    let mut result = Vec::new();
+    let mut pos = 0;
+    while pos + query.len() <= chars.len() {
+        if query
+            .iter()
+            .enumerate()
+            .all(|(i, q)| char_eq(chars[pos + i], *q))
+        {
+            result.push((offsets[pos], offsets[pos + query.len()]));
+            pos += query.len();
+        } else {
+            pos += 1;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::find_text_matches;
+
+    #[test]
+    fn test_find_text_matches() {
+        assert_eq!(
+            find_text_matches("abcabc", "bc", true),
+            vec![(1, 3), (4, 6)]
+        );
+        assert_eq!(find_text_matches("aaaa", "aa", true), vec![(0, 2), (2, 4)]);
+        assert_eq!(
+            find_text_matches("Error error", "error", true),
+            vec![(6, 11)]
+        );
+        assert_eq!(
+            find_text_matches("Error error", "error", false),
+            vec![(0, 5), (6, 11)]
+        );
+        assert_eq!(find_text_matches("abc", "", false), vec![]);
+    }
+
+    #[test]
+    fn test_find_text_matches_utf16() {
+        // U+1F600 uses two UTF-16 code units
+        assert_eq!(find_text_matches("\u{1F600}ab", "ab", true), vec![(2, 4)]);
+        assert_eq!(find_text_matches("äÄ", "ä", false), vec![(0, 1), (1, 2)]);
+    }
+}
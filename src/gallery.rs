@@ -0,0 +1,235 @@
+//! Widget gallery for markup snapshot tests.
+//!
+//! The gallery is a registry of widgets in defined states. [render_all]
+//! renders each entry to a static HTML string (using the yew server side
+//! rendering mode, so no DOM is required). CI jobs can store those
+//! snapshots and diff the markup between releases.
+//!
+//! The toolkit registers its basic widgets automatically. Products can
+//! add their own composite widgets using [register]:
+//!
+//! ```
+//! # use pwt::prelude::*;
+//! # use pwt::widget::Button;
+//! pwt::gallery::register("MyToolbarButton", "default", || {
+//!     Button::new("Reload").icon_class("fa fa-refresh").into()
+//! });
+//! ```
+//!
+//! Only widgets which do not access the DOM in `create()` or `view()` can be
+//! rendered this way.
+//!
+//! This module requires the `gallery` feature.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use yew::LocalServerRenderer;
+
+use crate::prelude::*;
+use crate::widget::{
+    ActionIcon, BarSegment, Button, Card, Column, Container, Fa, ListTile, Meter, Progress, Row,
+};
+
+/// A registered gallery entry.
+#[derive(Clone)]
+pub struct GalleryEntry {
+    /// Widget name.
+    pub name: AttrValue,
+    /// Name of the rendered state (i.e. "disabled").
+    pub state: AttrValue,
+    render: Rc<dyn Fn() -> Html>,
+}
+
+impl GalleryEntry {
+    /// Render the widget.
+    pub fn render(&self) -> Html {
+        (self.render)()
+    }
+}
+
+/// Rendered gallery entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GallerySnapshot {
+    /// Widget name.
+    pub name: AttrValue,
+    /// Name of the rendered state.
+    pub state: AttrValue,
+    /// The rendered markup.
+    pub html: String,
+}
+
+impl GallerySnapshot {
+    /// Returns a file name suitable to store the snapshot (`<name>--<state>.html`).
+    pub fn file_name(&self) -> String {
+        let sanitize = |s: &str| -> String {
+            s.chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                .collect()
+        };
+        format!("{}--{}.html", sanitize(&self.name), sanitize(&self.state))
+    }
+}
+
+thread_local! {
+    static GALLERY: RefCell<Vec<GalleryEntry>> = RefCell::new(builtin_entries());
+}
+
+/// Register a widget state.
+///
+/// Entries with the same `name` and `state` are replaced.
+pub fn register(
+    name: impl Into<AttrValue>,
+    state: impl Into<AttrValue>,
+    render: impl Fn() -> Html + 'static,
+) {
+    let entry = GalleryEntry {
+        name: name.into(),
+        state: state.into(),
+        render: Rc::new(render),
+    };
+    GALLERY.with(|gallery| {
+        let mut gallery = gallery.borrow_mut();
+        match gallery
+            .iter_mut()
+            .find(|e| e.name == entry.name && e.state == entry.state)
+        {
+            Some(existing) => *existing = entry,
+            None => gallery.push(entry),
+        }
+    });
+}
+
+/// Returns all registered entries (in registration order).
+pub fn entries() -> Vec<GalleryEntry> {
+    GALLERY.with(|gallery| gallery.borrow().clone())
+}
+
+#[derive(Properties, Clone)]
+struct GalleryFrameProps {
+    entry: GalleryEntry,
+}
+
+impl PartialEq for GalleryFrameProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.entry.render, &other.entry.render)
+    }
+}
+
+#[function_component]
+fn GalleryFrame(props: &GalleryFrameProps) -> Html {
+    props.entry.render()
+}
+
+/// Render a single entry to a static HTML string.
+pub async fn render_entry(entry: &GalleryEntry) -> GallerySnapshot {
+    let html = LocalServerRenderer::<GalleryFrame>::with_props(GalleryFrameProps {
+        entry: entry.clone(),
+    })
+    .hydratable(false)
+    .render()
+    .await;
+
+    GallerySnapshot {
+        name: entry.name.clone(),
+        state: entry.state.clone(),
+        html,
+    }
+}
+
+/// Render all registered entries to static HTML strings.
+///
+/// # Note
+///
+/// On non-wasm targets, this must run inside a tokio `LocalSet` (see the
+/// `gallery` example).
+pub async fn render_all() -> Vec<GallerySnapshot> {
+    let mut list = Vec::new();
+    for entry in entries() {
+        list.push(render_entry(&entry).await);
+    }
+    list
+}
+
+fn builtin_entries() -> Vec<GalleryEntry> {
+    let entry = |name: &'static str, state: &'static str, render: fn() -> Html| GalleryEntry {
+        name: AttrValue::Static(name),
+        state: AttrValue::Static(state),
+        render: Rc::new(render),
+    };
+
+    vec![
+        entry("Button", "default", || Button::new("Button").into()),
+        entry("Button", "icon", || {
+            Button::new("Button").icon_class("fa fa-check").into()
+        }),
+        entry("Button", "icon-only", || {
+            Button::new_icon("fa fa-check").into()
+        }),
+        entry("Button", "disabled", || {
+            Button::new("Button").disabled(true).into()
+        }),
+        entry("Button", "pressed", || {
+            Button::new("Button").pressed(true).into()
+        }),
+        entry("ActionIcon", "default", || {
+            ActionIcon::new("fa fa-pencil").into()
+        }),
+        entry("Fa", "default", || Fa::new("check").into()),
+        entry("Fa", "spin", || Fa::new("spinner").spin().into()),
+        entry("Meter", "default", || Meter::new().value(0.5).into()),
+        entry("Meter", "thresholds", || {
+            Meter::new().low(0.5).high(0.8).value(0.9).into()
+        }),
+        entry("Meter", "segments", || {
+            Meter::new()
+                .value(0.7)
+                .with_segment(BarSegment::new(0.5).tip("Used"))
+                .with_segment(BarSegment::new(0.2).color("orange").tip("Reserved"))
+                .into()
+        }),
+        entry("Progress", "indeterminate", || Progress::new().into()),
+        entry("Progress", "striped", || {
+            Progress::new().striped(true).into()
+        }),
+        entry("Progress", "value", || Progress::new().value(0.3).into()),
+        entry("Progress", "buffer", || {
+            Progress::new().value(0.3).buffer(0.6).into()
+        }),
+        entry("Container", "default", || {
+            Container::new().with_child("Content").into()
+        }),
+        entry("Row", "default", || {
+            Row::new()
+                .gap(2)
+                .with_child("Left")
+                .with_child("Right")
+                .into()
+        }),
+        entry("Column", "default", || {
+            Column::new()
+                .gap(2)
+                .with_child("Top")
+                .with_child("Bottom")
+                .into()
+        }),
+        entry("Card", "default", || Card::new().with_child("Card").into()),
+        entry("ListTile", "default", || {
+            ListTile::new().with_child("List tile").into()
+        }),
+        entry("ListTile", "slots", || {
+            ListTile::new()
+                .leading(html! {<i class="fa fa-user"/>})
+                .title("Title")
+                .subtitle("Subtitle")
+                .into()
+        }),
+        entry("ListTile", "disabled", || {
+            ListTile::new()
+                .interactive(true)
+                .disabled(true)
+                .with_child("List tile")
+                .into()
+        }),
+    ]
+}
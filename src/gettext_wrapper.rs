@@ -6,10 +6,35 @@ use yew::html::IntoEventCallback;
 
 static CATALOG: RwLock<Option<Catalog>> = RwLock::new(None);
 
+// Source URL of a preloaded catalog (see init_i18n_preloaded).
+static CATALOG_URL: RwLock<Option<String>> = RwLock::new(None);
+
 /// Intitialize the global translation catalog.
 pub fn init_i18n(catalog: Catalog) {
     let mut guard = CATALOG.write().expect("could not write catalog");
     *guard = Some(catalog);
+    drop(guard);
+    set_catalog_url(None);
+}
+
+fn set_catalog_url(url: Option<String>) {
+    let mut guard = CATALOG_URL.write().expect("could not write catalog url");
+    *guard = url;
+}
+
+// Install a catalog downloaded from `url`, so that the CatalogLoader can
+// skip downloading it again.
+pub(crate) fn init_i18n_preloaded(url: &str, catalog: Catalog) {
+    init_i18n(catalog);
+    set_catalog_url(Some(url.to_string()));
+}
+
+// Returns the source URL if the installed catalog was preloaded.
+pub(crate) fn preloaded_catalog_url() -> Option<String> {
+    match CATALOG_URL.read() {
+        Ok(guard) => guard.clone(),
+        Err(_) => None,
+    }
 }
 
 fn get_catalog() -> Option<Catalog> {
@@ -122,6 +147,15 @@ fn convert_js_error(js_err: ::wasm_bindgen::JsValue) -> String {
 }
 
 async fn fetch_catalog(url: &str) -> Result<(), String> {
+    let catalog = load_catalog(url).await?;
+    init_i18n(catalog);
+    Ok(())
+}
+
+/// Download a translation catalog from url, without installing it.
+///
+/// Use [init_i18n] to install the returned catalog.
+pub async fn load_catalog(url: &str) -> Result<Catalog, String> {
     let abort = crate::WebSysAbortGuard::new()
         .map_err(|err| format!("unable to create abort guard: {err}"))?;
 
@@ -153,9 +187,7 @@ async fn fetch_catalog(url: &str) -> Result<(), String> {
     let body = js_fut.await.map_err(convert_js_error)?;
     let body = js_sys::Uint8Array::new(&body).to_vec();
 
-    init_i18n_from_blob(body)?;
-
-    Ok(())
+    Catalog::parse(&mut &body[..]).map_err(|err| err.to_string())
 }
 
 /// This is an implementation detail for replacing arguments in the gettext macros.
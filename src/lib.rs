@@ -125,6 +125,7 @@
 //! - [widget::form::Combobox]: Select value from a list of options.
 //! - [widget::form::DisplayField]: Just display values (not editable).
 //! - [widget::form::Field]: Wrapper around standard Html fields.
+//! - [widget::form::FieldArray]: Repeatable group of fields.
 //! - [widget::form::Hidden]: Hidden fields.
 //! - [widget::form::Number]: Number input element for common Rust number types,
 //!   which stores values a number (not string).
@@ -135,6 +136,9 @@
 //! There are also special buttons for [reset](widget::form::ResetButton)
 //! and [submit](widget::form::SubmitButton).
 //!
+//! The [AdvancedOptions](widget::form::AdvancedOptions) container hides
+//! rarely used fields in an expandable section.
+//!
 //! The [ManagedField](widget::form::ManagedField) trait is used as base
 //! for input elements, because it simplifies the interaction with the
 //! [context](widget::form::FormContext).
@@ -150,9 +154,14 @@
 //! [Selector](widget::form::Selector) widgets.
 //!
 //! The [SearchDropdown](widget::SearchDropdown) also uses the [Dropdown](widget::Dropdown)
-//! to represent asynchrounous search results to the user.
+//! to represent asynchrounous search results to the user. The
+//! [FederatedSearchDropdown](widget::FederatedSearchDropdown) does the same
+//! for multiple search sources.
 //!
 //! The [Trigger](widget::Trigger) is used to toggle a dropdown or action.
+//!
+//! The [EditInPlace](widget::EditInPlace) widget displays text which can be
+//! edited inline, for example to rename items.
 
 //! ### Buttons
 //!
@@ -183,6 +192,8 @@
 //! using a html grid column layout.
 //!
 //! - [widget::SelectionView]: View for selection.
+//! - [widget::Timeline]: Time ordered entries, i.e. task history or audit logs.
+//! - [widget::FindBar]: Find and highlight text inside a container (i.e. log viewers).
 
 //! ### Drawing Canvas
 //!
@@ -206,6 +217,7 @@
 //! - [touch::Scaffold]: Material Design visual page layout.
 //! - [touch::SideDialog]: Modal Dialog with slide in/out animations.
 //! - [touch::Slidable]: Slidable widget with directional slide actions that can be dismissed.
+//! - [touch::SlidableList]: List with slidable rows.
 //! - [touch::SnackBar]: Display lightweight messages with an optional action button.
 
 //! ## Router
@@ -224,6 +236,7 @@
 //! - [widget::DesktopApp]: Application scaffold for desktop applications.
 //! - [touch::MaterialApp]: Helper to implement applications that uses material design guidelines.
 //! - [widget::CatalogLoader]: Loader for catalogs.
+//! - [widget::Bootstrap]: Startup helper (theme, catalog and init tasks with splash screen).
 //!
 
 //! ## DOM Helpers
@@ -249,6 +262,9 @@ pub use async_pool::AsyncPool;
 
 pub mod css;
 pub mod dom;
+#[cfg(feature = "gallery")]
+pub mod gallery;
+pub mod log;
 pub mod props;
 pub mod state;
 pub mod touch;
@@ -263,8 +279,8 @@ pub mod gettext_runtime_format;
 
 mod gettext_wrapper;
 pub use gettext_wrapper::{
-    gettext, gettext_noop, init_i18n, init_i18n_from_blob, init_i18n_from_url, ngettext, npgettext,
-    pgettext,
+    gettext, gettext_noop, init_i18n, init_i18n_from_blob, init_i18n_from_url, load_catalog,
+    ngettext, npgettext, pgettext,
 };
 
 mod tr;
@@ -0,0 +1,381 @@
+//! Client side logging.
+//!
+//! Implements the [log](::log) crate facade for browser applications:
+//!
+//! - Records are written to the browser console.
+//! - Log levels are configurable per module at runtime (see
+//!   [set_level]), and saved in the browser local storage.
+//! - Recent records are kept in an in-memory ring buffer (see
+//!   [recent_entries]), which can be viewed with the
+//!   [LogViewer](crate::widget::LogViewer) debug panel.
+//! - Warnings and errors can be forwarded to an application provided
+//!   sink (see [set_sink]).
+//!
+//! ```no_run
+//! use pwt::log::LevelFilter;
+//!
+//! pwt::log::init(LevelFilter::Info);
+//! // enable debug output for a single module
+//! pwt::log::set_level("pwt::widget::data_table", Some(LevelFilter::Debug));
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, VecDeque};
+use std::str::FromStr;
+
+use yew::Callback;
+use yew::html::IntoEventCallback;
+
+use crate::state::{PersistentState, SharedState, SharedStateObserver};
+
+pub use ::log::{Level, LevelFilter};
+
+// Local storage key for the level configuration.
+const LEVEL_CONFIG_KEY: &str = "pwt-log-levels";
+
+/// Default size of the ring buffer.
+pub const DEFAULT_BUFFER_SIZE: usize = 1000;
+
+/// Log record stored in the ring buffer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry {
+    /// Time in milliseconds since the UNIX epoch.
+    pub time: f64,
+    /// Log level.
+    pub level: Level,
+    /// Log target (module path by default).
+    pub target: String,
+    /// The formatted message.
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Returns the entry as single text line (with ISO 8601 time).
+    pub fn to_text(&self) -> String {
+        let time = js_sys::Date::new(&self.time.into()).to_iso_string();
+        format!(
+            "{} {:<5} {}: {}",
+            String::from(time),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Log level configuration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogLevelConfig {
+    /// Level used for targets without module level.
+    pub default: LevelFilter,
+    /// Module levels (by target prefix).
+    pub modules: BTreeMap<String, LevelFilter>,
+}
+
+impl LogLevelConfig {
+    /// Create a new instance.
+    pub fn new(default: LevelFilter) -> Self {
+        Self {
+            default,
+            modules: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the level for a log target.
+    ///
+    /// Uses the longest matching module path (i.e. `"pwt::widget"`
+    /// matches `"pwt::widget::form"`, but not `"pwt::widgets"`).
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .filter(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+
+    /// Returns the maximum of all levels.
+    pub fn max_level(&self) -> LevelFilter {
+        self.modules
+            .values()
+            .copied()
+            .fold(self.default, LevelFilter::max)
+    }
+
+    // Saved as map, using an empty key for the default level.
+    fn to_storage(&self) -> BTreeMap<String, String> {
+        let mut map: BTreeMap<String, String> = self
+            .modules
+            .iter()
+            .map(|(module, level)| (module.clone(), level.to_string()))
+            .collect();
+        map.insert(String::new(), self.default.to_string());
+        map
+    }
+
+    fn apply_storage(&mut self, map: &BTreeMap<String, String>) {
+        for (module, level) in map {
+            let Ok(level) = LevelFilter::from_str(level) else {
+                continue;
+            };
+            if module.is_empty() {
+                self.default = level;
+            } else {
+                self.modules.insert(module.clone(), level);
+            }
+        }
+    }
+}
+
+struct LogBuffer {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+}
+
+thread_local! {
+    static LEVELS: RefCell<Option<(LogLevelConfig, LevelFilter)>> = const { RefCell::new(None) };
+    static BUFFER: RefCell<LogBuffer> = const {
+        RefCell::new(LogBuffer {
+            entries: VecDeque::new(),
+            capacity: DEFAULT_BUFFER_SIZE,
+        })
+    };
+    static SINK: RefCell<Option<Callback<LogEntry>>> = const { RefCell::new(None) };
+    // avoid recursion if the sink logs itself
+    static IN_SINK: Cell<bool> = const { Cell::new(false) };
+    static CHANGE: SharedState<()> = SharedState::new(());
+}
+
+struct PwtLogger;
+
+impl ::log::Log for PwtLogger {
+    fn enabled(&self, metadata: &::log::Metadata) -> bool {
+        LEVELS.with(|levels| match levels.try_borrow() {
+            Ok(levels) => levels
+                .as_ref()
+                .is_some_and(|(config, _)| metadata.level() <= config.level_for(metadata.target())),
+            Err(_) => false,
+        })
+    }
+
+    fn log(&self, record: &::log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let entry = LogEntry {
+            time: js_sys::Date::now(),
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        };
+
+        let console_text = format!("{} {}: {}", entry.level, entry.target, entry.message);
+        let console_text = wasm_bindgen::JsValue::from_str(&console_text);
+        match entry.level {
+            Level::Error => web_sys::console::error_1(&console_text),
+            Level::Warn => web_sys::console::warn_1(&console_text),
+            Level::Info => web_sys::console::info_1(&console_text),
+            Level::Debug | Level::Trace => web_sys::console::debug_1(&console_text),
+        }
+
+        if entry.level <= Level::Warn && !IN_SINK.with(Cell::get) {
+            let sink = SINK.with(|sink| sink.borrow().clone());
+            if let Some(sink) = sink {
+                IN_SINK.with(|in_sink| in_sink.set(true));
+                sink.emit(entry.clone());
+                IN_SINK.with(|in_sink| in_sink.set(false));
+            }
+        }
+
+        let added = BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+            Ok(mut buffer) => {
+                if buffer.capacity == 0 {
+                    return false;
+                }
+                while buffer.entries.len() >= buffer.capacity {
+                    buffer.entries.pop_front();
+                }
+                buffer.entries.push_back(entry);
+                true
+            }
+            Err(_) => false,
+        });
+
+        if added {
+            notify_change();
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn notify_change() {
+    CHANGE.with(|change| drop(change.write()));
+}
+
+fn update_levels(update: impl FnOnce(&mut LogLevelConfig, LevelFilter)) {
+    let config = LEVELS.with(|levels| {
+        let mut levels = levels.borrow_mut();
+        let (config, init_level) = levels.as_mut()?;
+        update(config, *init_level);
+        Some(config.clone())
+    });
+    if let Some(config) = config {
+        ::log::set_max_level(config.max_level());
+        PersistentState::<BTreeMap<String, String>>::new(LEVEL_CONFIG_KEY)
+            .update(config.to_storage());
+        notify_change();
+    }
+}
+
+/// Install the logger.
+///
+/// Saved level settings (see [set_level]) override the `default_level`.
+pub fn init(default_level: LevelFilter) {
+    let mut config = LogLevelConfig::new(default_level);
+    let saved = PersistentState::<BTreeMap<String, String>>::new(LEVEL_CONFIG_KEY);
+    config.apply_storage(&saved);
+
+    let max_level = config.max_level();
+    LEVELS.with(|levels| *levels.borrow_mut() = Some((config, default_level)));
+
+    match ::log::set_boxed_logger(Box::new(PwtLogger)) {
+        Ok(()) => ::log::set_max_level(max_level),
+        Err(err) => web_sys::console::error_1(&format!("pwt::log::init failed: {err}").into()),
+    }
+}
+
+/// Returns the level configuration (`None` if the logger is not installed).
+pub fn level_config() -> Option<LogLevelConfig> {
+    LEVELS.with(|levels| levels.borrow().as_ref().map(|(config, _)| config.clone()))
+}
+
+/// Set the level for a module (target prefix).
+///
+/// An empty `module` sets the default level. Use `None` to remove the
+/// module level (or reset the default level to the [init] value). The
+/// configuration is saved in the browser local storage.
+pub fn set_level(module: &str, level: Option<LevelFilter>) {
+    update_levels(|config, init_level| match (module.is_empty(), level) {
+        (true, level) => config.default = level.unwrap_or(init_level),
+        (false, Some(level)) => {
+            config.modules.insert(module.to_string(), level);
+        }
+        (false, None) => {
+            config.modules.remove(module);
+        }
+    });
+}
+
+/// Remove all module levels and reset the default level.
+pub fn reset_levels() {
+    update_levels(|config, init_level| *config = LogLevelConfig::new(init_level));
+}
+
+/// Set the ring buffer size (default is [DEFAULT_BUFFER_SIZE]).
+///
+/// A size of zero disables the buffer.
+pub fn set_buffer_size(size: usize) {
+    BUFFER.with(|buffer| {
+        let mut buffer = buffer.borrow_mut();
+        buffer.capacity = size;
+        while buffer.entries.len() > size {
+            buffer.entries.pop_front();
+        }
+    });
+    notify_change();
+}
+
+/// Returns all entries from the ring buffer (oldest first).
+pub fn recent_entries() -> Vec<LogEntry> {
+    BUFFER.with(|buffer| buffer.borrow().entries.iter().cloned().collect())
+}
+
+/// Returns the last `max` entries as text lines (oldest first).
+pub fn recent_lines(max: usize) -> Vec<String> {
+    BUFFER.with(|buffer| {
+        let buffer = buffer.borrow();
+        let skip = buffer.entries.len().saturating_sub(max);
+        buffer
+            .entries
+            .iter()
+            .skip(skip)
+            .map(LogEntry::to_text)
+            .collect()
+    })
+}
+
+/// Remove all entries from the ring buffer.
+pub fn clear() {
+    BUFFER.with(|buffer| buffer.borrow_mut().entries.clear());
+    notify_change();
+}
+
+/// Set the sink for warnings and errors (i.e. to send them to the server).
+pub fn set_sink(sink: impl IntoEventCallback<LogEntry>) {
+    let sink = sink.into_event_callback();
+    SINK.with(|current| *current.borrow_mut() = sink);
+}
+
+/// Listen to ring buffer and level configuration changes.
+pub struct LogObserver {
+    _observer: SharedStateObserver<()>,
+}
+
+impl LogObserver {
+    /// Create a new instance.
+    pub fn new(on_change: Callback<()>) -> Self {
+        let _observer = CHANGE.with(|change| change.add_listener(move |_| on_change.emit(())));
+        Self { _observer }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use super::{LevelFilter, LogLevelConfig};
+
+    #[test]
+    fn test_level_for() {
+        let mut config = LogLevelConfig::new(LevelFilter::Warn);
+        config
+            .modules
+            .insert("pwt::widget".into(), LevelFilter::Debug);
+        config
+            .modules
+            .insert("pwt::widget::form".into(), LevelFilter::Error);
+
+        assert_eq!(config.level_for("app"), LevelFilter::Warn);
+        assert_eq!(config.level_for("pwt::widget"), LevelFilter::Debug);
+        assert_eq!(config.level_for("pwt::widget::menu"), LevelFilter::Debug);
+        assert_eq!(config.level_for("pwt::widgets"), LevelFilter::Warn);
+        assert_eq!(
+            config.level_for("pwt::widget::form::field"),
+            LevelFilter::Error
+        );
+        assert_eq!(config.max_level(), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn test_storage() {
+        let mut config = LogLevelConfig::new(LevelFilter::Info);
+        config.modules.insert("app".into(), LevelFilter::Trace);
+        let map = config.to_storage();
+
+        let mut loaded = LogLevelConfig::new(LevelFilter::Warn);
+        loaded.apply_storage(&map);
+        assert_eq!(loaded, config);
+
+        let mut invalid = BTreeMap::new();
+        invalid.insert(String::from("app"), String::from("verbose"));
+        let mut loaded = LogLevelConfig::new(LevelFilter::Warn);
+        loaded.apply_storage(&invalid);
+        assert_eq!(loaded, LogLevelConfig::new(LevelFilter::Warn));
+    }
+}
@@ -1,4 +1,5 @@
 use super::AsClassesMut;
+use crate::css::{Border, BorderSide, NoBorder};
 use yew::Classes;
 use yew::html::IntoPropValue;
 
@@ -34,6 +35,23 @@ use yew::html::IntoPropValue;
 /// # ;
 /// ```
 pub trait CssBorderBuilder: AsClassesMut + Sized {
+    /// Builder style method to add a border class for the specified side.
+    fn border_side(mut self, side: BorderSide, border: impl IntoPropValue<Option<bool>>) -> Self {
+        self.add_border_side(side, border);
+        self
+    }
+
+    /// Method to add a border class for the specified side.
+    ///
+    /// `true` adds [Border], `false` adds [NoBorder], and `None` does nothing.
+    fn add_border_side(&mut self, side: BorderSide, border: impl IntoPropValue<Option<bool>>) {
+        match border.into_prop_value() {
+            Some(true) => self.as_classes_mut().push(Border(side)),
+            Some(false) => self.as_classes_mut().push(NoBorder(side)),
+            None => {}
+        }
+    }
+
     /// Builder style method to add a box border class.
     fn border(mut self, border: impl IntoPropValue<Option<bool>>) -> Self {
         self.add_border(border);
@@ -42,14 +60,7 @@ pub trait CssBorderBuilder: AsClassesMut + Sized {
 
     /// Method to add a box border class.
     fn add_border(&mut self, border: impl IntoPropValue<Option<bool>>) {
-        let border = border.into_prop_value();
-        if let Some(border) = border {
-            self.as_classes_mut().push(if border {
-                "pwt-border"
-            } else {
-                "pwt-no-border"
-            });
-        };
+        self.add_border_side(BorderSide::All, border);
     }
 
     /// Builder style method to add a top border class.
@@ -60,14 +71,7 @@ pub trait CssBorderBuilder: AsClassesMut + Sized {
 
     /// Method to add a top border class.
     fn add_border_top(&mut self, border: impl IntoPropValue<Option<bool>>) {
-        let border = border.into_prop_value();
-        if let Some(border) = border {
-            self.as_classes_mut().push(if border {
-                "pwt-border-top"
-            } else {
-                "pwt-no-border-top"
-            });
-        };
+        self.add_border_side(BorderSide::Top, border);
     }
 
     /// Builder style method to add a bottom border class.
@@ -78,14 +82,7 @@ pub trait CssBorderBuilder: AsClassesMut + Sized {
 
     /// Method to add a bottom border class.
     fn add_border_bottom(&mut self, border: impl IntoPropValue<Option<bool>>) {
-        let border = border.into_prop_value();
-        if let Some(border) = border {
-            self.as_classes_mut().push(if border {
-                "pwt-border-bottom"
-            } else {
-                "pwt-no-border-bottom"
-            });
-        };
+        self.add_border_side(BorderSide::Bottom, border);
     }
 
     /// Builder style method to add a start border class.
@@ -96,14 +93,7 @@ pub trait CssBorderBuilder: AsClassesMut + Sized {
 
     /// Method to add a start border class.
     fn add_border_start(&mut self, border: impl IntoPropValue<Option<bool>>) {
-        let border = border.into_prop_value();
-        if let Some(border) = border {
-            self.as_classes_mut().push(if border {
-                "pwt-border-start"
-            } else {
-                "pwt-no-border-start"
-            });
-        };
+        self.add_border_side(BorderSide::Start, border);
     }
 
     /// Builder style method to add an end border class.
@@ -114,14 +104,7 @@ pub trait CssBorderBuilder: AsClassesMut + Sized {
 
     /// Method to add an end border class.
     fn add_border_end(&mut self, border: impl IntoPropValue<Option<bool>>) {
-        let border = border.into_prop_value();
-        if let Some(border) = border {
-            self.as_classes_mut().push(if border {
-                "pwt-border-end"
-            } else {
-                "pwt-no-border-end"
-            });
-        };
+        self.add_border_side(BorderSide::End, border);
     }
 
     /// Builder style method to add a left border class.
@@ -132,14 +115,7 @@ pub trait CssBorderBuilder: AsClassesMut + Sized {
 
     /// Method to add a left border class.
     fn add_border_left(&mut self, border: impl IntoPropValue<Option<bool>>) {
-        let border = border.into_prop_value();
-        if let Some(border) = border {
-            self.as_classes_mut().push(if border {
-                "pwt-border-left"
-            } else {
-                "pwt-no-border-left"
-            });
-        };
+        self.add_border_side(BorderSide::Left, border);
     }
 
     /// Builder style method to add a right border class.
@@ -150,14 +126,7 @@ pub trait CssBorderBuilder: AsClassesMut + Sized {
 
     /// Method to add a right border class.
     fn add_border_right(&mut self, border: impl IntoPropValue<Option<bool>>) {
-        let border = border.into_prop_value();
-        if let Some(border) = border {
-            self.as_classes_mut().push(if border {
-                "pwt-border-right"
-            } else {
-                "pwt-no-border-right"
-            });
-        };
+        self.add_border_side(BorderSide::Right, border);
     }
 }
 
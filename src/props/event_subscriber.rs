@@ -1,8 +1,12 @@
+use std::borrow::Cow;
 use std::rc::Rc;
 
-use yew::virtual_dom::{Listener, VNode};
+use wasm_bindgen::JsCast;
+use web_sys::CompositionEvent;
+
+use yew::virtual_dom::{Listener, ListenerKind, VNode};
 use yew::{
-    AnimationEvent, DragEvent, Event, FocusEvent, InputEvent, KeyboardEvent, MouseEvent,
+    AnimationEvent, Callback, DragEvent, Event, FocusEvent, InputEvent, KeyboardEvent, MouseEvent,
     PointerEvent, ProgressEvent, SubmitEvent, TouchEvent, TransitionEvent, WheelEvent,
 };
 
@@ -41,6 +45,28 @@ impl PartialEq for ListenersWrapper {
     }
 }
 
+/// Listener for events not covered by the yew listener types, or
+/// where we need to control the `passive` flag.
+struct CustomListener {
+    kind: ListenerKind,
+    passive: bool,
+    callback: Callback<Event>,
+}
+
+impl Listener for CustomListener {
+    fn kind(&self) -> ListenerKind {
+        self.kind.clone()
+    }
+
+    fn handle(&self, event: Event) {
+        self.callback.emit(event);
+    }
+
+    fn passive(&self) -> bool {
+        self.passive
+    }
+}
+
 macro_rules! handler {
     ($id:ident, $add_id:ident, $et:ty) => {
         /// Builder style method to set the callback
@@ -59,6 +85,27 @@ macro_rules! handler {
         }
     };
 }
+macro_rules! custom_handler {
+    ($id:ident, $add_id:ident, $name:literal, $et:ty) => {
+        /// Builder style method to set the callback
+        fn $id(mut self, cb: impl ::yew::html::IntoEventCallback<$et>) -> Self {
+            self.$add_id(cb);
+            self
+        }
+
+        /// Method to set the callback
+        fn $add_id(&mut self, cb: impl ::yew::html::IntoEventCallback<$et>) {
+            if let Some(cb) = cb.into_event_callback() {
+                self.add_on_event(
+                    $name,
+                    cb.reform(|event: Event| event.unchecked_into()),
+                    false,
+                );
+            }
+        }
+    };
+}
+
 /// Defines builder methods on [ListenersWrapper].
 ///
 /// This trait defines builder method for all Html events.
@@ -187,4 +234,80 @@ pub trait EventSubscriber: Into<VNode> {
     handler!(ontransitionstart, add_ontransitionstart, TransitionEvent);
 
     handler!(onwheel, add_onwheel, WheelEvent);
+
+    /// Builder style method to set a wheel callback with explicit `passive` flag.
+    ///
+    /// The [onwheel](Self::onwheel) listener is passive, so it cannot
+    /// prevent the default action (scrolling). Use `passive = false` if you
+    /// need to call `prevent_default()`.
+    fn onwheel_with_passive(
+        mut self,
+        cb: impl ::yew::html::IntoEventCallback<WheelEvent>,
+        passive: bool,
+    ) -> Self {
+        self.add_onwheel_with_passive(cb, passive);
+        self
+    }
+
+    /// Method to set a wheel callback with explicit `passive` flag.
+    fn add_onwheel_with_passive(
+        &mut self,
+        cb: impl ::yew::html::IntoEventCallback<WheelEvent>,
+        passive: bool,
+    ) {
+        if let Some(cb) = cb.into_event_callback() {
+            self.add_on_event(
+                "wheel",
+                cb.reform(|event: Event| event.unchecked_into()),
+                passive,
+            );
+        }
+    }
+
+    custom_handler!(
+        oncompositionstart,
+        add_oncompositionstart,
+        "compositionstart",
+        CompositionEvent
+    );
+    custom_handler!(
+        oncompositionupdate,
+        add_oncompositionupdate,
+        "compositionupdate",
+        CompositionEvent
+    );
+    custom_handler!(
+        oncompositionend,
+        add_oncompositionend,
+        "compositionend",
+        CompositionEvent
+    );
+
+    /// Builder style method to listen to an arbitrary event type.
+    fn on_event(
+        mut self,
+        event_type: impl Into<Cow<'static, str>>,
+        cb: impl ::yew::html::IntoEventCallback<Event>,
+        passive: bool,
+    ) -> Self {
+        self.add_on_event(event_type, cb, passive);
+        self
+    }
+
+    /// Method to listen to an arbitrary event type (e.g. `"wheel"`).
+    fn add_on_event(
+        &mut self,
+        event_type: impl Into<Cow<'static, str>>,
+        cb: impl ::yew::html::IntoEventCallback<Event>,
+        passive: bool,
+    ) {
+        if let Some(callback) = cb.into_event_callback() {
+            let listener: Rc<dyn Listener> = Rc::new(CustomListener {
+                kind: ListenerKind::other(event_type.into()),
+                passive,
+                callback,
+            });
+            self.as_listeners_mut().listeners.push(Some(listener));
+        }
+    }
 }
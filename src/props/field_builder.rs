@@ -52,7 +52,22 @@ pub trait FieldBuilder: Into<VNode> {
         self.as_input_props_mut().tabindex = index.into_prop_value();
     }
 
+    /// Builder style method to set the focus order (see [FieldStdProps::tab_order])
+    fn tab_order(mut self, order: impl IntoPropValue<Option<i32>>) -> Self {
+        self.set_tab_order(order);
+        self
+    }
+
+    /// Method to set the focus order (see [FieldStdProps::tab_order])
+    fn set_tab_order(&mut self, order: impl IntoPropValue<Option<i32>>) {
+        self.as_input_props_mut().tab_order = order.into_prop_value();
+    }
+
     /// Builder style method to set the autofocus flag
+    ///
+    /// Managed fields focus themselves after the first render (or as soon
+    /// as they get enabled), after the surrounding dialog did its own
+    /// focus handling.
     fn autofocus(mut self, autofocus: bool) -> Self {
         self.set_autofocus(autofocus);
         self
@@ -19,6 +19,13 @@ pub struct FieldStdProps {
     /// Html tabindex attriute
     #[prop_or_default]
     pub tabindex: Option<i32>,
+
+    /// Explicit focus order.
+    ///
+    /// Used as `tabindex` (if not set explicitly), and by
+    /// [FormContext::focus_first_field](crate::widget::form::FormContext::focus_first_field).
+    #[prop_or_default]
+    pub tab_order: Option<i32>,
     /// ARIA label.
     #[prop_or_default]
     pub aria_label: Option<AttrValue>,
@@ -115,7 +122,7 @@ impl FieldStdProps {
             );
         }
 
-        if let Some(ref tabindex) = self.tabindex {
+        if let Some(ref tabindex) = self.tabindex.or(self.tab_order) {
             attr_map.insert(
                 AttrValue::Static("tabindex"),
                 (tabindex.to_string().into(), ApplyAttributeAs::Attribute),
@@ -1,6 +1,7 @@
 use crate::props::{AsClassesMut, AsCssStylesMut};
 
 use super::PwtSpace;
+use crate::css::Margin;
 
 /// Defines methods to use CSS margin classes.
 ///
@@ -32,22 +33,22 @@ use super::PwtSpace;
 /// # ;
 /// ```
 pub trait CssMarginBuilder: AsClassesMut + AsCssStylesMut + Sized {
-    generate_padding_trait_fn!(add_margin, margin, "margin", "pwt-m-{}");
-    generate_padding_trait_fn!(add_margin_x, margin_x, "margin-inline", "pwt-mx-{}");
+    generate_padding_trait_fn!(add_margin, margin, "margin", Margin::All);
+    generate_padding_trait_fn!(add_margin_x, margin_x, "margin-inline", Margin::X);
     generate_padding_trait_fn!(
         add_margin_start,
         margin_start,
         "margin-inline-start",
-        "pwt-ms-{}"
+        Margin::Start
     );
-    generate_padding_trait_fn!(add_margin_end, margin_end, "margin-inline-end", "pwt-me-{}");
+    generate_padding_trait_fn!(add_margin_end, margin_end, "margin-inline-end", Margin::End);
 
-    generate_padding_trait_fn!(add_margin_y, margin_y, "margin-block", "pwt-my-{}");
-    generate_padding_trait_fn!(add_margin_top, margin_top, "margin-top", "pwt-mt-{}");
+    generate_padding_trait_fn!(add_margin_y, margin_y, "margin-block", Margin::Y);
+    generate_padding_trait_fn!(add_margin_top, margin_top, "margin-top", Margin::Top);
     generate_padding_trait_fn!(
         add_margin_bottom,
         margin_bottom,
         "margin-bottom",
-        "pwt-mb-{}"
+        Margin::Bottom
     );
 }
@@ -178,7 +178,7 @@ mod css_styles;
 pub use css_styles::{AsCssStylesMut, CssStyles};
 
 mod widget_style_builder;
-pub use widget_style_builder::{CssLength, WidgetStyleBuilder};
+pub use widget_style_builder::{CssLength, CssToken, WidgetStyleBuilder};
 
 mod container_builder;
 pub use container_builder::ContainerBuilder;
@@ -1,6 +1,7 @@
 use crate::props::{AsClassesMut, AsCssStylesMut};
 
 use super::PwtSpace;
+use crate::css::Padding;
 
 /// Defines methods to use CSS padding classes.use yew::{html::IntoPropValue, AttrValue};
 ///
@@ -34,28 +35,28 @@ use super::PwtSpace;
 ///
 ///
 pub trait CssPaddingBuilder: AsClassesMut + AsCssStylesMut + Sized {
-    generate_padding_trait_fn!(add_padding, padding, "padding", "pwt-p-{}");
+    generate_padding_trait_fn!(add_padding, padding, "padding", Padding::All);
 
-    generate_padding_trait_fn!(add_padding_y, padding_y, "padding-block", "pwt-py-{}");
-    generate_padding_trait_fn!(add_padding_top, padding_top, "padding-top", "pwt-pt-{}");
+    generate_padding_trait_fn!(add_padding_y, padding_y, "padding-block", Padding::Y);
+    generate_padding_trait_fn!(add_padding_top, padding_top, "padding-top", Padding::Top);
     generate_padding_trait_fn!(
         add_padding_bottom,
         padding_bottom,
         "padding-bottom",
-        "pwt-pb-{}"
+        Padding::Bottom
     );
 
-    generate_padding_trait_fn!(add_padding_x, padding_x, "padding-inline", "pwt-px-{}");
+    generate_padding_trait_fn!(add_padding_x, padding_x, "padding-inline", Padding::X);
     generate_padding_trait_fn!(
         add_padding_start,
         padding_start,
         "padding-inline-start",
-        "pwt-ps-{}"
+        Padding::Start
     );
     generate_padding_trait_fn!(
         add_padding_end,
         padding_end,
         "padding-inline-end",
-        "pwt-pe-{}"
+        Padding::End
     );
 }
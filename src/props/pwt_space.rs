@@ -58,7 +58,7 @@ impl IntoPropValue<Option<AttrValue>> for PwtSpace {
 // macro to generate the trait functions
 
 macro_rules! generate_padding_trait_fn {
-    ($func:ident, $builder:ident, $name:literal, $class:literal) => {
+    ($func:ident, $builder:ident, $name:literal, $class:path) => {
         /// Builder style method to set $name CSS property.
         fn $builder(mut self, padding: impl Into<PwtSpace>) -> Self {
             self.$func(padding);
@@ -70,7 +70,7 @@ macro_rules! generate_padding_trait_fn {
             match space.into() {
                 PwtSpace::None => {}
                 PwtSpace::Pwt(factor) if factor <= 4 => {
-                    self.as_classes_mut().push(format!($class, factor));
+                    self.as_classes_mut().push($class(factor));
                 }
                 space => self.as_css_styles_mut().set_style($name, space.to_string()),
             }
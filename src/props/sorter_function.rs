@@ -25,6 +25,14 @@ impl<T> SorterFn<T> {
     }
 }
 
+impl<T: 'static> SorterFn<T> {
+    /// Chain another sorter, which is used for records this sorter
+    /// considers equal.
+    pub fn then(self, next: SorterFn<T>) -> Self {
+        Self::new(move |a: &T, b: &T| self.cmp(a, b).then_with(|| next.cmp(a, b)))
+    }
+}
+
 /// Helper trait to create an optional [SorterFn] property.
 pub trait IntoSorterFn<T> {
     fn into_sorter_fn(self) -> Option<SorterFn<T>>;
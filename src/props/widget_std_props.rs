@@ -4,7 +4,9 @@ use yew::html::IntoPropValue;
 use yew::prelude::*;
 use yew::virtual_dom::{ApplyAttributeAs, Attributes, Key, Listeners, VList, VNode, VTag};
 
+use crate::dom::init_input_modality_tracking;
 use crate::props::{CssStyles, ListenersWrapper};
+use crate::widget::inspector_enabled;
 
 /// Standard widget properties.
 #[derive(PartialEq, Debug, Default, Clone)]
@@ -67,6 +69,31 @@ impl WidgetStdProps {
         }
     }
 
+    /// Add the widget type and key as data attributes.
+    ///
+    /// Called by the widget macro, and only used if the
+    /// [WidgetInspector](crate::widget::WidgetInspector) is enabled. Existing
+    /// values are kept, so the outermost widget wins.
+    #[doc(hidden)]
+    pub fn annotate_widget(&mut self, widget_type: &'static str) {
+        if !inspector_enabled() {
+            return;
+        }
+        let key = self
+            .key
+            .as_ref()
+            .map(|key| AttrValue::from(key.to_string()));
+        let attrs = self.attributes.get_mut_index_map();
+        attrs
+            .entry(AttrValue::Static("data-pwt-widget"))
+            .or_insert((AttrValue::Static(widget_type), ApplyAttributeAs::Attribute));
+        if let Some(key) = key {
+            attrs
+                .entry(AttrValue::Static("data-pwt-key"))
+                .or_insert((key, ApplyAttributeAs::Attribute));
+        }
+    }
+
     /// Helper to gather all attributes into a single [Attributes]
     /// map.
     pub fn cumulate_attributes(&self, additional_class: Option<impl Into<Classes>>) -> Attributes {
@@ -98,6 +125,10 @@ impl WidgetStdProps {
     }
 
     /// Helper to create a VTag from [WidgetStdProps].
+    ///
+    /// This also starts the input modality tracking (see
+    /// [init_input_modality_tracking]), so that focus rings work for all
+    /// widgets.
     pub fn into_vtag(
         self,
         tag: Cow<'static, str>,
@@ -106,6 +137,8 @@ impl WidgetStdProps {
         listeners: Option<ListenersWrapper>,
         children: Option<Vec<VNode>>,
     ) -> VTag {
+        init_input_modality_tracking();
+
         let attributes = self.cumulate_attributes(additional_class);
 
         let listeners = match listeners {
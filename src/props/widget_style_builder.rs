@@ -77,6 +77,60 @@ impl IntoPropValue<Option<AttrValue>> for CssLength {
     }
 }
 
+/// Known theme tokens (CSS custom properties), to be used with
+/// [WidgetStyleBuilder::css_token].
+///
+/// Custom properties are inherited, so setting a token on a container
+/// changes the look of the whole subtree.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CssToken {
+    ColorPrimary,
+    ColorSecondary,
+    ColorTertiary,
+    ColorError,
+    ColorWarning,
+    ColorNeutral,
+    ColorOnNeutral,
+    ColorSurface,
+    ColorOnSurface,
+    ColorBackground,
+    /// Base width used by the spacing classes (padding, margin, gap).
+    SpacerBaseWidth,
+    /// Minimum height of [ListTile](crate::widget::ListTile)s.
+    ListTileMinHeight,
+}
+
+impl CssToken {
+    /// Returns the custom property name (including the leading `--`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            CssToken::ColorPrimary => "--pwt-color-primary",
+            CssToken::ColorSecondary => "--pwt-color-secondary",
+            CssToken::ColorTertiary => "--pwt-color-tertiary",
+            CssToken::ColorError => "--pwt-color-error",
+            CssToken::ColorWarning => "--pwt-color-warning",
+            CssToken::ColorNeutral => "--pwt-color-neutral",
+            CssToken::ColorOnNeutral => "--pwt-color-on-neutral",
+            CssToken::ColorSurface => "--pwt-color-surface",
+            CssToken::ColorOnSurface => "--pwt-color-on-surface",
+            CssToken::ColorBackground => "--pwt-color-background",
+            CssToken::SpacerBaseWidth => "--pwt-spacer-base-width",
+            CssToken::ListTileMinHeight => "--pwt-list-tile-min-height",
+        }
+    }
+
+    /// Returns the `var()` expression to reference the token in other styles.
+    pub fn var(&self) -> String {
+        format!("var({})", self.name())
+    }
+}
+
+impl Display for CssToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.write_str(self.name())
+    }
+}
+
 // macro to generate the trait functions
 
 macro_rules! generate_style_trait_fn {
@@ -176,6 +230,72 @@ pub trait WidgetStyleBuilder: AsCssStylesMut + Sized {
         self
     }
 
+    /// Method to set a CSS custom property (i.e. `--pwt-color-primary`).
+    ///
+    /// Custom properties are inherited, so this can be used to tweak the
+    /// theme for a single widget and its children.
+    ///
+    /// Note: Value 'None' removes the property.
+    /// Note: Names not starting with `--` are logged and ignored (debug
+    /// builds panic).
+    fn set_css_var(
+        &mut self,
+        name: impl Into<AttrValue>,
+        value: impl IntoPropValue<Option<AttrValue>>,
+    ) {
+        let name = name.into();
+        let valid = name.starts_with("--");
+        debug_assert!(valid, "invalid css custom property name: '{name}'");
+        if !valid {
+            log::error!("invalid css custom property name: '{name}'");
+            return;
+        }
+        self.as_css_styles_mut().set_style(name, value)
+    }
+
+    /// Builder style method to set a CSS custom property (i.e. `--pwt-color-primary`).
+    ///
+    /// ```
+    /// # use pwt::prelude::*;
+    /// # use pwt::widget::Container;
+    /// Container::new()
+    ///     .css_var("--my-app-accent", "#ff8000")
+    ///     .style("color", "var(--my-app-accent)");
+    /// ```
+    ///
+    /// Note: Value 'None' removes the property.
+    fn css_var(
+        mut self,
+        name: impl Into<AttrValue>,
+        value: impl IntoPropValue<Option<AttrValue>>,
+    ) -> Self {
+        self.set_css_var(name, value);
+        self
+    }
+
+    /// Method to set a known theme token.
+    ///
+    /// This is equivalent to `.set_css_var(token.name(), value)`
+    fn set_css_token(&mut self, token: CssToken, value: impl IntoPropValue<Option<AttrValue>>) {
+        self.as_css_styles_mut().set_style(token.name(), value)
+    }
+
+    /// Builder style method to set a known theme token.
+    ///
+    /// ```
+    /// # use pwt::prelude::*;
+    /// # use pwt::props::CssToken;
+    /// # use pwt::widget::{Button, Row};
+    /// // all buttons inside the row use the error color
+    /// Row::new()
+    ///     .css_token(CssToken::ColorPrimary, CssToken::ColorError.var())
+    ///     .with_child(Button::new("Delete").class("pwt-button-filled"));
+    /// ```
+    fn css_token(mut self, token: CssToken, value: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.set_css_token(token, value);
+        self
+    }
+
     generate_style_trait_fn!(set_width, width, "width");
     generate_style_trait_fn!(set_min_width, min_width, "min-width");
     generate_style_trait_fn!(set_max_width, max_width, "max-width");
@@ -0,0 +1,128 @@
+use yew::AttrValue;
+use yew::html::IntoPropValue;
+
+/// Application metadata.
+///
+/// The application provides this as yew context (or sets
+/// [DesktopApp::app_info](crate::widget::DesktopApp::app_info)). Widgets
+/// use it to generate links to the online documentation (see
+/// [HelpButton](crate::widget::HelpButton)), and to show product details
+/// (see [AboutPanel](crate::widget::AboutPanel)).
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::state::AppInfo;
+/// # use pwt::widget::Panel;
+/// fn view() -> Html {
+///     let info = AppInfo::new("Proxmox Backup Server")
+///         .version("4.0.1")
+///         .docs_url("/docs");
+///     html! {
+///         <ContextProvider<AppInfo> context={info}>
+///             {Panel::new().title("Datastores").help_id("storage.html#datastore-intro")}
+///         </ContextProvider<AppInfo>>
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AppInfo {
+    /// Product name.
+    pub product_name: AttrValue,
+    /// Product version.
+    pub version: Option<AttrValue>,
+    /// Build date (free form text, e.g. `"2025-07-08"`).
+    pub build_date: Option<AttrValue>,
+    /// Base URL of the online documentation.
+    pub docs_url: Option<AttrValue>,
+    /// Support or bug tracker URL.
+    pub support_url: Option<AttrValue>,
+}
+
+impl AppInfo {
+    /// Create a new instance.
+    pub fn new(product_name: impl Into<AttrValue>) -> Self {
+        Self {
+            product_name: product_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Builder style method to set the product version.
+    pub fn version(mut self, version: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.version = version.into_prop_value();
+        self
+    }
+
+    /// Builder style method to set the build date.
+    pub fn build_date(mut self, build_date: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.build_date = build_date.into_prop_value();
+        self
+    }
+
+    /// Builder style method to set the documentation base URL.
+    pub fn docs_url(mut self, docs_url: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.docs_url = docs_url.into_prop_value();
+        self
+    }
+
+    /// Builder style method to set the support URL.
+    pub fn support_url(mut self, support_url: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.support_url = support_url.into_prop_value();
+        self
+    }
+
+    /// Returns the product name including the version (if set).
+    pub fn full_name(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{} {}", self.product_name, version),
+            None => self.product_name.to_string(),
+        }
+    }
+
+    /// Returns the documentation URL for a help ID.
+    ///
+    /// The help ID is appended to the [docs_url](Self::docs_url), so it
+    /// can contain a page and an anchor (e.g. `"chapter-qm.html#qm_cpu"`).
+    /// An ID starting with `#` just adds an anchor to the base URL.
+    pub fn help_url(&self, help_id: &str) -> Option<String> {
+        let base = self.docs_url.as_deref()?;
+        let url = if help_id.is_empty() {
+            base.to_string()
+        } else if help_id.starts_with('#') {
+            format!("{base}{help_id}")
+        } else {
+            format!(
+                "{}/{}",
+                base.trim_end_matches('/'),
+                help_id.trim_start_matches('/')
+            )
+        };
+        Some(url)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AppInfo;
+
+    #[test]
+    fn test_help_url() {
+        let info = AppInfo::new("Test");
+        assert_eq!(info.help_url("index.html"), None);
+
+        let info = info.docs_url("/docs/");
+        assert_eq!(
+            info.help_url("chapter-qm.html#qm_cpu").as_deref(),
+            Some("/docs/chapter-qm.html#qm_cpu")
+        );
+        assert_eq!(info.help_url("#intro").as_deref(), Some("/docs/#intro"));
+        assert_eq!(info.help_url("").as_deref(), Some("/docs/"));
+    }
+
+    #[test]
+    fn test_full_name() {
+        let info = AppInfo::new("Test");
+        assert_eq!(info.full_name(), "Test");
+        assert_eq!(info.version("1.2").full_name(), "Test 1.2");
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use yew::AttrValue;
+
+/// Set of capabilities (privileges) granted to the current user.
+///
+/// The application provides this set as yew context, and widgets with
+/// required capabilities (see [RequiredCaps]) are automatically hidden
+/// or disabled if a capability is missing.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::state::Capabilities;
+/// # use pwt::widget::Button;
+/// fn view(caps: Capabilities) -> Html {
+///     html! {
+///         <ContextProvider<Capabilities> context={caps}>
+///             {Button::new("Resize Disk").require_cap("VM.Config.Disk")}
+///         </ContextProvider<Capabilities>>
+///     }
+/// }
+/// ```
+///
+/// Widgets without a [Capabilities] context are not restricted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Capabilities {
+    caps: Rc<HashSet<String>>,
+}
+
+impl Capabilities {
+    /// Create a new instance.
+    pub fn new<S: Into<String>>(caps: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            caps: Rc::new(caps.into_iter().map(|cap| cap.into()).collect()),
+        }
+    }
+
+    /// Returns true if the capability is granted.
+    pub fn has(&self, cap: &str) -> bool {
+        self.caps.contains(cap)
+    }
+
+    /// Returns true if all capabilities are granted.
+    pub fn has_all<'a>(&self, caps: impl IntoIterator<Item = &'a str>) -> bool {
+        caps.into_iter().all(|cap| self.has(cap))
+    }
+}
+
+/// What to do with a widget if a required capability is missing.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MissingCapPolicy {
+    /// Disable the widget and show a tooltip with the missing capabilities.
+    #[default]
+    Disable,
+    /// Do not render the widget.
+    Hide,
+}
+
+/// Result of a [RequiredCaps] check.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CapCheck {
+    /// All capabilities are granted (or nothing is required).
+    Granted,
+    /// The widget should be disabled. Contains a tooltip text.
+    Disabled(AttrValue),
+    /// The widget should be hidden.
+    Hidden,
+}
+
+impl CapCheck {
+    /// Returns true if the capabilities are granted.
+    pub fn granted(&self) -> bool {
+        matches!(self, CapCheck::Granted)
+    }
+
+    /// Returns true if the widget should be hidden.
+    pub fn hidden(&self) -> bool {
+        matches!(self, CapCheck::Hidden)
+    }
+
+    /// Returns the tooltip text for disabled widgets.
+    pub fn tip(&self) -> Option<AttrValue> {
+        match self {
+            CapCheck::Disabled(tip) => Some(tip.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Capabilities required by a widget.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequiredCaps {
+    caps: Vec<AttrValue>,
+    policy: MissingCapPolicy,
+}
+
+impl RequiredCaps {
+    /// Add a required capability.
+    pub fn add(&mut self, cap: impl Into<AttrValue>) {
+        self.caps.push(cap.into());
+    }
+
+    /// Set the policy for missing capabilities.
+    pub fn set_policy(&mut self, policy: MissingCapPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns true if no capability is required.
+    pub fn is_empty(&self) -> bool {
+        self.caps.is_empty()
+    }
+
+    /// Check the required capabilities against the granted ones.
+    ///
+    /// Everything is granted if there is no [Capabilities] context.
+    pub fn check(&self, granted: Option<&Capabilities>) -> CapCheck {
+        let granted = match granted {
+            Some(granted) => granted,
+            None => return CapCheck::Granted,
+        };
+
+        let missing: Vec<&str> = self
+            .caps
+            .iter()
+            .map(|cap| cap.as_str())
+            .filter(|cap| !granted.has(cap))
+            .collect();
+
+        if missing.is_empty() {
+            return CapCheck::Granted;
+        }
+
+        match self.policy {
+            MissingCapPolicy::Hide => CapCheck::Hidden,
+            MissingCapPolicy::Disable => {
+                let tip = crate::tr!("Missing privilege: {0}", missing.join(", "));
+                CapCheck::Disabled(tip.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn required(caps: &[&'static str], policy: MissingCapPolicy) -> RequiredCaps {
+        let mut required = RequiredCaps::default();
+        for cap in caps {
+            required.add(*cap);
+        }
+        required.set_policy(policy);
+        required
+    }
+
+    #[test]
+    fn test_capability_check() {
+        let granted = Capabilities::new(["VM.Audit", "VM.Config.Disk"]);
+
+        assert!(granted.has_all(["VM.Audit", "VM.Config.Disk"]));
+        assert!(!granted.has("Sys.Modify"));
+
+        let req = required(&["VM.Config.Disk"], MissingCapPolicy::Hide);
+        assert_eq!(req.check(Some(&granted)), CapCheck::Granted);
+
+        let req = required(&["VM.Audit", "Sys.Modify"], MissingCapPolicy::Hide);
+        assert_eq!(req.check(Some(&granted)), CapCheck::Hidden);
+        // no context - no restrictions
+        assert_eq!(req.check(None), CapCheck::Granted);
+
+        let req = required(&["Sys.Modify", "Sys.Audit"], MissingCapPolicy::Disable);
+        let check = req.check(Some(&granted));
+        assert!(!check.granted() && !check.hidden());
+        let tip = check.tip().unwrap();
+        assert!(tip.contains("Sys.Modify, Sys.Audit"));
+
+        assert!(RequiredCaps::default().check(Some(&granted)).granted());
+    }
+}
@@ -3,7 +3,7 @@ use std::ops::{Deref, Range};
 use yew::Callback;
 use yew::virtual_dom::Key;
 
-use crate::props::{ExtractKeyFn, IntoFilterFn, IntoSorterFn};
+use crate::props::{ExtractKeyFn, FilterFn, IntoFilterFn, IntoSorterFn};
 
 #[doc(hidden)]
 pub trait DataNode<T> {
@@ -52,6 +52,14 @@ pub trait DataStore: Clone + PartialEq {
         self.data_len() == 0
     }
 
+    /// Returns true for hierarchical stores.
+    ///
+    /// For trees, [data_len](Self::data_len) also counts collapsed
+    /// nodes, so it cannot be compared with the number of visible rows.
+    fn is_tree(&self) -> bool {
+        false
+    }
+
     /// Method to add a change observer.
     ///
     /// The returned observer owns the listener callback. When dropped, the
@@ -60,10 +68,28 @@ pub trait DataStore: Clone + PartialEq {
 
     fn set_sorter(&self, sorter: impl IntoSorterFn<Self::Record>);
     fn set_filter(&self, filter: impl IntoFilterFn<Self::Record>);
+
+    /// Returns the filter function (`None` for stores without local filters).
+    fn get_filter(&self) -> Option<FilterFn<Self::Record>> {
+        None
+    }
     fn lookup_filtered_record_key(&self, cursor: usize) -> Option<Key>;
     fn filtered_record_pos(&self, key: &Key) -> Option<usize>;
     fn filtered_data_len(&self) -> usize;
 
+    /// Returns the keys of all expanded nodes (empty for non-tree stores).
+    fn expanded_keys(&self) -> Vec<Key> {
+        Vec::new()
+    }
+
+    /// Expand all nodes with the given keys (ignored by non-tree stores).
+    fn apply_expanded_keys(&self, _keys: &[Key]) {}
+
+    /// Notify the store about the currently visible rows.
+    ///
+    /// Stores which load data on demand use this to fetch missing records.
+    fn request_range(&self, _range: Range<usize>) {}
+
     #[allow(clippy::type_complexity)]
     fn filtered_data<'a>(
         &'a self,
@@ -0,0 +1,143 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use derivative::Derivative;
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use yew::AttrValue;
+use yew::virtual_dom::Key;
+
+use crate::props::{ExtractKeyFn, FilterFn, RenderFn, SorterFn};
+
+struct DerivedFieldsState<T> {
+    extract_key: ExtractKeyFn<T>,
+    fields: IndexMap<AttrValue, RenderFn<T, Value>>,
+    cache: HashMap<(AttrValue, Key), Value>,
+}
+
+/// Named, computed record fields.
+///
+/// Derived fields are registered on a [Store](super::Store) (see
+/// [Store::derive](super::Store::derive)). Computed values are cached
+/// per record, so the computation is done once, even if the value is
+/// used in cell renderers, sorters and filters. Store modifications
+/// only invalidate the values of the changed records (or the whole cache
+/// if the changed records are unknown).
+///
+/// This is a cheap to clone handle, so it can be moved into sorter and
+/// filter functions (which can't access the store itself).
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+pub struct DerivedFields<T: 'static> {
+    #[derivative(PartialEq(compare_with = "Rc::ptr_eq"))]
+    inner: Rc<RefCell<DerivedFieldsState<T>>>,
+}
+
+impl<T: 'static> DerivedFields<T> {
+    pub(crate) fn new(extract_key: ExtractKeyFn<T>) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(DerivedFieldsState {
+                extract_key,
+                fields: IndexMap::new(),
+                cache: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Register a derived field (replaces existing fields with the same name).
+    pub fn register(&self, name: impl Into<AttrValue>, derive_fn: impl Into<RenderFn<T, Value>>) {
+        let name = name.into();
+        let mut state = self.inner.borrow_mut();
+        state.cache.retain(|(field, _), _| field != &name);
+        state.fields.insert(name, derive_fn.into());
+    }
+
+    /// Returns the names of all registered fields (in registration order).
+    pub fn names(&self) -> Vec<AttrValue> {
+        self.inner.borrow().fields.keys().cloned().collect()
+    }
+
+    /// Returns the (cached) field value for a record.
+    ///
+    /// Returns `None` if there is no field with that name.
+    pub fn get(&self, name: &str, record: &T) -> Option<Value> {
+        let (derive_fn, cache_key) = {
+            let state = self.inner.borrow();
+            let (name, derive_fn) = state.fields.get_key_value(name)?;
+            let cache_key = (name.clone(), state.extract_key.apply(record));
+            if let Some(value) = state.cache.get(&cache_key) {
+                return Some(value.clone());
+            }
+            (derive_fn.clone(), cache_key)
+        };
+
+        // Note: do not hold the borrow while calling the derive function.
+        let value = derive_fn.apply(record);
+        self.inner
+            .borrow_mut()
+            .cache
+            .insert(cache_key, value.clone());
+        Some(value)
+    }
+
+    /// Returns a sorter function comparing the values of a derived field.
+    ///
+    /// Numbers are compared numerically, strings lexicographically, and
+    /// null values sort first.
+    pub fn sorter(&self, name: impl Into<AttrValue>) -> SorterFn<T> {
+        let fields = self.clone();
+        let name = name.into();
+        SorterFn::new(move |a: &T, b: &T| {
+            compare_values(
+                &fields.get(&name, a).unwrap_or(Value::Null),
+                &fields.get(&name, b).unwrap_or(Value::Null),
+            )
+        })
+    }
+
+    /// Returns a filter function using the value of a derived field.
+    pub fn filter(
+        &self,
+        name: impl Into<AttrValue>,
+        filter: impl 'static + Fn(&Value) -> bool,
+    ) -> FilterFn<T> {
+        let fields = self.clone();
+        let name = name.into();
+        FilterFn::new(move |record: &T| filter(&fields.get(&name, record).unwrap_or(Value::Null)))
+    }
+
+    /// Clear the cache.
+    ///
+    /// This is called automatically when the store data changes.
+    pub fn invalidate(&self) {
+        self.inner.borrow_mut().cache.clear();
+    }
+
+    /// Remove the cached values of the specified records.
+    pub(crate) fn invalidate_keys(&self, keys: &HashSet<Key>) {
+        self.inner
+            .borrow_mut()
+            .cache
+            .retain(|(_, key), _| !keys.contains(key));
+    }
+}
+
+/// Compare two JSON values (used to sort derived fields).
+pub(crate) fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Less,
+        (_, Value::Null) => Ordering::Greater,
+        (Value::Number(a), Value::Number(b)) => {
+            let a = a.as_f64().unwrap_or(0.0);
+            let b = b.as_f64().unwrap_or(0.0);
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        }
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (a, b) => a.to_string().cmp(&b.to_string()),
+    }
+}
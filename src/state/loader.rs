@@ -20,10 +20,23 @@ pub struct LoaderState<T> {
     loading: u64,
     storage_location: Option<StorageLocation>,
     async_abort_guard: Option<AsyncAbortGuard>,
+    load_when_visible: bool,
+    cancel_when_hidden: bool,
+    visible: bool,
+    pending_load: bool,
+    last_update: Option<i64>,
     pub loader: Option<LoadCallback<T>>,
     pub data: Option<Result<Rc<T>, Error>>,
 }
 
+impl<T> LoaderState<T> {
+    // abort the running load, returns true if there was one.
+    fn abort_load(&mut self) -> bool {
+        self.loading = 0;
+        self.async_abort_guard.take().is_some()
+    }
+}
+
 impl<T: 'static + DeserializeOwned + Serialize> LoaderState<T> {
     fn load_from_cache(&mut self) {
         let storage_location = match &self.storage_location {
@@ -60,7 +73,23 @@ impl<T: 'static + DeserializeOwned + Serialize> LoaderState<T> {
 /// - tracks load state `self.loading()`.
 /// - ability to cache result in local (default) or session storage by setting `state_id`.
 /// - helper to simplify renderering `self.render`.
+/// - optionally defer loading until the widget is visible (`load_when_visible`).
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::state::Loader;
+/// # use pwt::widget::{Panel, VisibilityObserver};
+/// # fn test(loader: Loader<String>) -> Html {
+/// let loader = loader.load_when_visible(true).cancel_when_hidden(true);
+/// loader.load(); // deferred until the panel gets visible
 ///
+/// VisibilityObserver::new(
+///     Panel::new().with_child(loader.render(|data| data.to_string())),
+///     loader.visibility_callback(),
+/// )
+/// .into()
+/// # }
+/// ```
 #[derive(Derivative)]
 #[derivative(Clone(bound = ""), PartialEq(bound = ""))]
 pub struct Loader<T>(SharedState<LoaderState<T>>);
@@ -80,6 +109,11 @@ impl<T: 'static + DeserializeOwned + Serialize> Loader<T> {
             loader: None,
             storage_location: None,
             async_abort_guard: None,
+            load_when_visible: false,
+            cancel_when_hidden: false,
+            visible: true,
+            pending_load: false,
+            last_update: None,
         };
         Self(SharedState::new(state))
     }
@@ -97,6 +131,81 @@ impl<T: 'static + DeserializeOwned + Serialize> Loader<T> {
         me.load_from_cache();
     }
 
+    /// Builder style method to defer loading until the widget is visible.
+    ///
+    /// If enabled, [Self::load] only marks the data as outdated while the
+    /// widget is hidden. The load is then started as soon as the widget
+    /// gets visible. Visibility changes are reported with [Self::set_visible],
+    /// usually via [Self::visibility_callback] and a
+    /// [VisibilityObserver](crate::widget::VisibilityObserver).
+    pub fn load_when_visible(mut self, load_when_visible: bool) -> Self {
+        self.set_load_when_visible(load_when_visible);
+        self
+    }
+
+    /// Method to set the `load_when_visible` flag.
+    ///
+    /// Enabling it assumes that the widget is hidden until the first
+    /// visibility change is reported.
+    pub fn set_load_when_visible(&mut self, load_when_visible: bool) {
+        let mut me = self.write();
+        me.notify = false;
+        me.load_when_visible = load_when_visible;
+        me.visible = !load_when_visible;
+    }
+
+    /// Builder style method to abort running loads when the widget gets hidden.
+    ///
+    /// The aborted load is restarted when the widget is visible again. This is
+    /// only used together with [Self::load_when_visible].
+    pub fn cancel_when_hidden(mut self, cancel_when_hidden: bool) -> Self {
+        self.set_cancel_when_hidden(cancel_when_hidden);
+        self
+    }
+
+    /// Method to set the `cancel_when_hidden` flag.
+    pub fn set_cancel_when_hidden(&mut self, cancel_when_hidden: bool) {
+        let mut me = self.write();
+        me.notify = false;
+        me.cancel_when_hidden = cancel_when_hidden;
+    }
+
+    /// Update the visibility state.
+    ///
+    /// Starts deferred loads if the widget gets visible, and aborts running
+    /// loads on hide if `cancel_when_hidden` is set.
+    pub fn set_visible(&self, visible: bool) {
+        let mut state = self.write();
+        if !state.load_when_visible || state.visible == visible {
+            state.notify = false;
+            return;
+        }
+        state.visible = visible;
+
+        if visible {
+            let pending_load = std::mem::take(&mut state.pending_load);
+            state.notify = false;
+            drop(state);
+            if pending_load {
+                self.load();
+            }
+        } else if state.cancel_when_hidden && state.abort_load() {
+            // restart when visible again
+            state.pending_load = true;
+        } else {
+            state.notify = false;
+        }
+    }
+
+    /// Returns a callback which calls [Self::set_visible].
+    ///
+    /// Useful as `on_visibility_change` callback for a
+    /// [VisibilityObserver](crate::widget::VisibilityObserver).
+    pub fn visibility_callback(&self) -> Callback<bool> {
+        let me = self.clone();
+        Callback::from(move |visible| me.set_visible(visible))
+    }
+
     pub fn on_change(mut self, cb: impl IntoEventCallback<Loader<T>>) -> Self {
         let me = self.clone();
         match cb.into_event_callback() {
@@ -141,6 +250,13 @@ impl<T: 'static + DeserializeOwned + Serialize> Loader<T> {
         self.read().loading > 0
     }
 
+    /// Returns the time of the last successful load (unix epoch, seconds).
+    ///
+    /// Data loaded from the cache does not count as update.
+    pub fn last_update(&self) -> Option<i64> {
+        self.read().last_update
+    }
+
     pub fn has_valid_data(&self) -> bool {
         matches!(self.read().data, Some(Ok(_)))
     }
@@ -158,6 +274,10 @@ impl<T: 'static + DeserializeOwned + Serialize> Loader<T> {
         }
     }
 
+    /// Start loading the data.
+    ///
+    /// If `load_when_visible` is set and the widget is hidden, the load is
+    /// deferred until the widget gets visible.
     pub fn load(&self) {
         let loader = match &self.read().loader {
             Some(loader) => loader.clone(),
@@ -167,12 +287,20 @@ impl<T: 'static + DeserializeOwned + Serialize> Loader<T> {
         let me = self.clone();
 
         let mut state = self.write();
+        if state.load_when_visible && !state.visible {
+            state.pending_load = true;
+            state.notify = false;
+            return;
+        }
         state.loading += 1;
         state.async_abort_guard = Some(AsyncAbortGuard::spawn(async move {
             let res = loader.apply().await;
             let mut me = me.write();
             me.async_abort_guard = None;
             me.loading -= 1;
+            if res.is_ok() {
+                me.last_update = Some((js_sys::Date::now() / 1000.0) as i64);
+            }
             me.data = Some(res.map(|data| Rc::new(data)));
             me.store_to_cache();
         }));
@@ -184,7 +312,7 @@ impl<T: 'static + DeserializeOwned + Serialize> Loader<T> {
 
     /// Abort any currently running load.
     pub fn abort(&mut self) {
-        self.write().async_abort_guard = None;
+        self.write().abort_load();
     }
 
     pub fn reload_button(&self) -> Button {
@@ -4,9 +4,18 @@ use std::rc::Rc;
 
 use serde::{Serialize, de::DeserializeOwned};
 
+mod app_info;
+pub use app_info::AppInfo;
+
+mod capabilities;
+pub use capabilities::{CapCheck, Capabilities, MissingCapPolicy, RequiredCaps};
+
 mod data_store;
 pub use data_store::{DataNode, DataNodeDerefGuard, DataStore};
 
+mod derived_fields;
+pub use derived_fields::DerivedFields;
+
 mod loader;
 pub use loader::{Loader, LoaderState};
 
@@ -15,6 +24,9 @@ pub use navigation_container::{
     NavigationContainer, NavigationContext, NavigationContextExt, PwtNavigationContainer,
 };
 
+mod paged_store;
+pub use paged_store::{Page, PageLoadFn, PageRequest, PagedStore, PagedStoreObserver};
+
 mod persistent_state;
 pub use persistent_state::PersistentState;
 
@@ -23,12 +35,18 @@ pub use selection::{Selection, SelectionObserver, use_selection};
 
 mod shared_state;
 pub use shared_state::{
-    SharedState, SharedStateInner, SharedStateObserver, SharedStateReadGuard, SharedStateWriteGuard,
+    SharedState, SharedStateInner, SharedStateObserver, SharedStateReadGuard,
+    SharedStateWriteGuard, shared_state_observer_count,
 };
 
 mod store;
 pub use store::*;
 
+mod tab_channel;
+pub use tab_channel::{TabChannel, TabChannelSubscription, TabNotification, tab_id};
+
+mod store_journal;
+
 mod tree_store;
 pub use tree_store::*;
 
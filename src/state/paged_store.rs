@@ -0,0 +1,636 @@
+use std::cell::{Ref, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::ops::{Deref, Range};
+use std::pin::Pin;
+use std::rc::Rc;
+
+use anyhow::Error;
+use derivative::Derivative;
+use slab::Slab;
+
+use yew::html::IntoPropValue;
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+use crate::props::{ExtractKeyFn, ExtractPrimaryKey, IntoFilterFn, IntoSorterFn};
+use crate::state::{DataNode, DataNodeDerefGuard, DataStore};
+
+/// Parameters passed to the [PagedStore] load function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PageRequest {
+    /// Position of the first record.
+    pub offset: usize,
+    /// Maximum number of records to return.
+    pub limit: usize,
+    /// Remote sort parameter (see [PagedStore::set_remote_sort]).
+    pub sort: Option<AttrValue>,
+    /// Remote filter parameter (see [PagedStore::set_remote_filter]).
+    pub filter: Option<AttrValue>,
+}
+
+/// A page returned by the [PagedStore] load function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page<T> {
+    /// Total number of records (all pages).
+    pub total: usize,
+    /// The records, starting at [PageRequest::offset].
+    pub data: Vec<T>,
+}
+
+/// Load function for a [PagedStore].
+///
+/// We use an [Rc] to store the callback, and [Rc::ptr_eq] to implement
+/// [PartialEq].
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+pub struct PageLoadFn<T> {
+    #[allow(clippy::type_complexity)]
+    #[derivative(PartialEq(compare_with = "Rc::ptr_eq"))]
+    callback: Rc<dyn Fn(PageRequest) -> Pin<Box<dyn Future<Output = Result<Page<T>, Error>>>>>,
+}
+
+impl<T> PageLoadFn<T> {
+    /// Create a new instance.
+    pub fn new<F, R>(callback: F) -> Self
+    where
+        F: 'static + Fn(PageRequest) -> R,
+        R: 'static + Future<Output = Result<Page<T>, Error>>,
+    {
+        Self {
+            callback: Rc::new(move |request| Box::pin(callback(request))),
+        }
+    }
+
+    /// Load a page.
+    pub async fn apply(&self, request: PageRequest) -> Result<Page<T>, Error> {
+        (self.callback)(request).await
+    }
+}
+
+impl<T, F, R> From<F> for PageLoadFn<T>
+where
+    F: 'static + Fn(PageRequest) -> R,
+    R: 'static + Future<Output = Result<Page<T>, Error>>,
+{
+    fn from(callback: F) -> Self {
+        PageLoadFn::new(callback)
+    }
+}
+
+/// Shared store for remote data, loaded page by page.
+///
+/// Only the total record count and the pages requested so far are held
+/// in memory. A [DataTable](crate::widget::data_table::DataTable) notifies
+/// the store about the visible rows, so scrolling automatically loads the
+/// missing pages (the first page is loaded when the table is rendered).
+///
+/// Sorting and filtering is done by the server. Use
+/// [set_remote_sort](Self::set_remote_sort) and
+/// [set_remote_filter](Self::set_remote_filter) to set the parameters
+/// passed with each [PageRequest]. Local sorter and filter functions are
+/// ignored.
+///
+/// Use [loading](Self::loading) and [error](Self::error) to display a
+/// loading mask or error message.
+///
+/// # Note
+///
+/// Rows of pages which are not loaded yet are rendered as empty
+/// placeholder rows (CSS class `pwt-datatable-placeholder-row`), so the
+/// scroll position stays stable while pages arrive.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+pub struct PagedStore<T: 'static> {
+    #[derivative(PartialEq(compare_with = "Rc::ptr_eq"))]
+    inner: Rc<RefCell<PagedStoreState<T>>>,
+}
+
+/// Owns the listener callback. When dropped, the
+/// listener callback will be removed from the [PagedStore].
+pub struct PagedStoreObserver<T: 'static> {
+    key: usize,
+    inner: Rc<RefCell<PagedStoreState<T>>>,
+}
+
+impl<T: 'static> Drop for PagedStoreObserver<T> {
+    fn drop(&mut self) {
+        self.inner.borrow_mut().listeners.remove(self.key);
+    }
+}
+
+struct PagedStoreState<T> {
+    extract_key: ExtractKeyFn<T>,
+    loader: PageLoadFn<T>,
+    page_size: usize,
+    total: Option<usize>,
+    pages: HashMap<usize, Vec<T>>,
+    pending: HashSet<usize>,
+    // Pages which failed to load (not requested again until reload).
+    failed: HashSet<usize>,
+    // Incremented on reset, to ignore outdated responses.
+    generation: usize,
+    error: Option<String>,
+    sort: Option<AttrValue>,
+    filter: Option<AttrValue>,
+    listeners: Slab<Callback<()>>,
+}
+
+impl<T> PagedStoreState<T> {
+    fn get(&self, pos: usize) -> Option<&T> {
+        self.pages
+            .get(&(pos / self.page_size))
+            .and_then(|page| page.get(pos % self.page_size))
+    }
+
+    fn reset(&mut self) {
+        self.generation += 1;
+        self.total = None;
+        self.pages.clear();
+        self.pending.clear();
+        self.failed.clear();
+        self.error = None;
+    }
+
+    // Mark the page as pending and return the request (with the current
+    // generation), or `None` if the page needs no load.
+    fn begin_load(&mut self, page: usize) -> Option<(PageRequest, usize)> {
+        if self.pages.contains_key(&page)
+            || self.pending.contains(&page)
+            || self.failed.contains(&page)
+        {
+            return None;
+        }
+        if let Some(total) = self.total {
+            if page * self.page_size >= total {
+                return None;
+            }
+        }
+        self.pending.insert(page);
+        let request = PageRequest {
+            offset: page * self.page_size,
+            limit: self.page_size,
+            sort: self.sort.clone(),
+            filter: self.filter.clone(),
+        };
+        Some((request, self.generation))
+    }
+
+    // Store the load result. Returns false if the response is outdated.
+    fn finish_load(
+        &mut self,
+        page: usize,
+        generation: usize,
+        result: Result<Page<T>, Error>,
+    ) -> bool {
+        if self.generation != generation {
+            return false;
+        }
+        self.pending.remove(&page);
+        match result {
+            Ok(data) => {
+                self.total = Some(data.total);
+                self.pages.insert(page, data.data);
+                self.error = None;
+            }
+            Err(err) => {
+                log::error!("PagedStore: load page {page} failed - {err}");
+                self.failed.insert(page);
+                self.error = Some(err.to_string());
+            }
+        }
+        true
+    }
+
+    fn loaded_positions(&self, range: Range<usize>) -> Vec<usize> {
+        let end = range.end.min(self.total.unwrap_or(0));
+        (range.start..end)
+            .filter(|pos| self.get(*pos).is_some())
+            .collect()
+    }
+}
+
+impl<T: ExtractPrimaryKey + 'static> PagedStore<T> {
+    /// Creates a new instance for types implementing [ExtractPrimaryKey].
+    pub fn new(loader: impl Into<PageLoadFn<T>>) -> Self {
+        let extract_key = ExtractKeyFn::new(|data: &T| data.extract_key());
+        Self::with_extract_key(extract_key, loader)
+    }
+}
+
+impl<T: 'static> PagedStore<T> {
+    /// Creates a new instance with the specified extract key function.
+    pub fn with_extract_key(
+        extract_key: impl Into<ExtractKeyFn<T>>,
+        loader: impl Into<PageLoadFn<T>>,
+    ) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(PagedStoreState {
+                extract_key: extract_key.into(),
+                loader: loader.into(),
+                page_size: 100,
+                total: None,
+                pages: HashMap::new(),
+                pending: HashSet::new(),
+                failed: HashSet::new(),
+                generation: 0,
+                error: None,
+                sort: None,
+                filter: None,
+                listeners: Slab::new(),
+            })),
+        }
+    }
+
+    /// Builder style method to set the page size (default 100).
+    pub fn page_size(self, page_size: usize) -> Self {
+        self.set_page_size(page_size);
+        self
+    }
+
+    /// Set the page size (clears all loaded pages).
+    pub fn set_page_size(&self, page_size: usize) {
+        let mut state = self.inner.borrow_mut();
+        state.page_size = page_size.max(1);
+        state.reset();
+    }
+
+    /// Set the remote sort parameter and reload.
+    pub fn set_remote_sort(&self, sort: impl IntoPropValue<Option<AttrValue>>) {
+        self.inner.borrow_mut().sort = sort.into_prop_value();
+        self.reload();
+    }
+
+    /// Set the remote filter parameter and reload.
+    pub fn set_remote_filter(&self, filter: impl IntoPropValue<Option<AttrValue>>) {
+        self.inner.borrow_mut().filter = filter.into_prop_value();
+        self.reload();
+    }
+
+    /// Drop all loaded pages and load the first page again.
+    pub fn reload(&self) {
+        self.inner.borrow_mut().reset();
+        self.load_page(0);
+        self.notify_listeners();
+    }
+
+    /// Returns true while a page request is pending.
+    pub fn loading(&self) -> bool {
+        !self.inner.borrow().pending.is_empty()
+    }
+
+    /// Returns the error message of the last failed request.
+    ///
+    /// Failed pages are not requested again until [reload](Self::reload).
+    pub fn error(&self) -> Option<String> {
+        self.inner.borrow().error.clone()
+    }
+
+    /// Returns the total number of records (if known).
+    pub fn total(&self) -> Option<usize> {
+        self.inner.borrow().total
+    }
+
+    /// Returns a clone of the record at `pos` (if loaded).
+    pub fn get(&self, pos: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.inner.borrow().get(pos).cloned()
+    }
+
+    /// Load all pages overlapping `range` (if not already loaded).
+    pub fn load_range(&self, range: Range<usize>) {
+        let (pages, unknown_total) = {
+            let state = self.inner.borrow();
+            let first = range.start / state.page_size;
+            let last = range.end.saturating_sub(1) / state.page_size;
+            (first..=last, state.total.is_none())
+        };
+
+        if unknown_total {
+            self.load_page(0);
+            return;
+        }
+        for page in pages {
+            self.load_page(page);
+        }
+    }
+
+    fn load_page(&self, page: usize) {
+        let (request, generation, loader) = {
+            let mut state = self.inner.borrow_mut();
+            let Some((request, generation)) = state.begin_load(page) else {
+                return;
+            };
+            (request, generation, state.loader.clone())
+        };
+
+        let inner = self.inner.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = loader.apply(request).await;
+            let updated = inner.borrow_mut().finish_load(page, generation, result);
+            if updated {
+                notify_listeners(&inner);
+            }
+        });
+    }
+
+    fn notify_listeners(&self) {
+        notify_listeners(&self.inner);
+    }
+
+    /// Returns the unique record key.
+    pub fn extract_key(&self, data: &T) -> Key {
+        self.inner.borrow().extract_key.apply(data)
+    }
+
+    /// Method to add an change observer.
+    pub fn add_listener(&self, cb: impl Into<Callback<()>>) -> PagedStoreObserver<T> {
+        let key = self.inner.borrow_mut().listeners.insert(cb.into());
+        PagedStoreObserver {
+            key,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+// Note: emit callbacks without holding the borrow
+fn notify_listeners<T>(inner: &Rc<RefCell<PagedStoreState<T>>>) {
+    let listeners: Vec<Callback<()>> = inner
+        .borrow()
+        .listeners
+        .iter()
+        .map(|(_, cb)| cb.clone())
+        .collect();
+    for listener in listeners {
+        listener.emit(());
+    }
+}
+
+#[doc(hidden)]
+pub struct PagedStoreNodeRef<'a, T> {
+    state: Ref<'a, PagedStoreState<T>>,
+    pos: usize,
+}
+
+impl<T: 'static> DataNode<T> for PagedStoreNodeRef<'_, T> {
+    fn record(&self) -> DataNodeDerefGuard<'_, T> {
+        // Note: we only create nodes for loaded records
+        let data = self.state.get(self.pos).unwrap();
+        let guard: Box<dyn Deref<Target = T>> = Box::new(data);
+        DataNodeDerefGuard { guard }
+    }
+    fn level(&self) -> usize {
+        0
+    }
+    fn is_leaf(&self) -> bool {
+        true
+    }
+    fn is_root(&self) -> bool {
+        false
+    }
+    fn expanded(&self) -> bool {
+        false
+    }
+    fn parent(&self) -> Option<Box<dyn DataNode<T> + '_>> {
+        None
+    }
+    fn key(&self) -> Key {
+        let record = self.state.get(self.pos).unwrap();
+        self.state.extract_key.apply(record)
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> DataStore for PagedStore<T> {
+    type Observer = PagedStoreObserver<T>;
+    type Record = T;
+    type Collection = Vec<T>;
+
+    fn extract_key(&self, data: &T) -> Key {
+        self.extract_key(data)
+    }
+
+    fn get_extract_key_fn(&self) -> ExtractKeyFn<T> {
+        self.inner.borrow().extract_key.clone()
+    }
+
+    fn add_listener(&self, cb: impl Into<Callback<()>>) -> Self::Observer {
+        self.add_listener(cb)
+    }
+
+    /// Set all data (as if all pages were loaded).
+    fn set_data(&self, data: Vec<T>) {
+        {
+            let mut state = self.inner.borrow_mut();
+            state.reset();
+            state.total = Some(data.len());
+            let page_size = state.page_size;
+            let mut data = data.into_iter().peekable();
+            let mut page = 0;
+            while data.peek().is_some() {
+                let records: Vec<T> = data.by_ref().take(page_size).collect();
+                state.pages.insert(page, records);
+                page += 1;
+            }
+        }
+        self.notify_listeners();
+    }
+
+    fn clear(&self) {
+        {
+            let mut state = self.inner.borrow_mut();
+            state.reset();
+            state.total = Some(0);
+        }
+        self.notify_listeners();
+    }
+
+    fn data_len(&self) -> usize {
+        self.inner.borrow().total.unwrap_or(0)
+    }
+
+    fn set_sorter(&self, _sorter: impl IntoSorterFn<T>) {
+        log::warn!("PagedStore: local sorters are not supported - use set_remote_sort");
+    }
+
+    fn set_filter(&self, _filter: impl IntoFilterFn<T>) {
+        log::warn!("PagedStore: local filters are not supported - use set_remote_filter");
+    }
+
+    fn lookup_filtered_record_key(&self, cursor: usize) -> Option<Key> {
+        let state = self.inner.borrow();
+        state
+            .get(cursor)
+            .map(|record| state.extract_key.apply(record))
+    }
+
+    fn filtered_record_pos(&self, key: &Key) -> Option<usize> {
+        let state = self.inner.borrow();
+        state.pages.iter().find_map(|(page, records)| {
+            records
+                .iter()
+                .position(|record| &state.extract_key.apply(record) == key)
+                .map(|n| page * state.page_size + n)
+        })
+    }
+
+    fn filtered_data_len(&self) -> usize {
+        self.data_len()
+    }
+
+    fn request_range(&self, range: Range<usize>) {
+        self.load_range(range);
+    }
+
+    fn filtered_data<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = (usize, Box<dyn DataNode<T> + 'a>)> + 'a> {
+        self.filtered_data_range(0..self.data_len())
+    }
+
+    fn filtered_data_range<'a>(
+        &'a self,
+        range: Range<usize>,
+    ) -> Box<dyn Iterator<Item = (usize, Box<dyn DataNode<T> + 'a>)> + 'a> {
+        let state = self.inner.borrow();
+        let positions = state.loaded_positions(range);
+        Box::new(positions.into_iter().map(move |pos| {
+            let node: Box<dyn DataNode<T> + 'a> = Box::new(PagedStoreNodeRef {
+                state: Ref::clone(&state),
+                pos,
+            });
+            (pos, node)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_store() -> PagedStore<usize> {
+        PagedStore::with_extract_key(
+            |n: &usize| Key::from(*n),
+            |_request: PageRequest| async {
+                Ok(Page::<usize> {
+                    total: 0,
+                    data: Vec::new(),
+                })
+            },
+        )
+        .page_size(3)
+    }
+
+    #[test]
+    fn test_set_data_pages() {
+        let store = test_store();
+        store.set_data((0..8).collect());
+
+        assert_eq!(store.total(), Some(8));
+        assert_eq!(store.inner.borrow().pages.len(), 3);
+        assert_eq!(store.get(7), Some(7));
+        assert_eq!(store.get(8), None);
+        assert_eq!(store.filtered_record_pos(&Key::from(5usize)), Some(5));
+
+        let positions: Vec<usize> = store
+            .filtered_data_range(2..5)
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(positions, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_sparse_pages() {
+        let store = test_store();
+        {
+            let mut state = store.inner.borrow_mut();
+            state.total = Some(9);
+            state.pages.insert(2, vec![6, 7, 8]);
+        }
+        let positions: Vec<usize> = store
+            .filtered_data_range(4..9)
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(positions, vec![6, 7, 8]);
+        assert_eq!(store.lookup_filtered_record_key(0), None);
+        assert_eq!(store.lookup_filtered_record_key(7), Some(Key::from(7usize)));
+    }
+
+    fn page(total: usize, data: &[usize]) -> Result<Page<usize>, Error> {
+        Ok(Page {
+            total,
+            data: data.to_vec(),
+        })
+    }
+
+    #[test]
+    fn test_load_requests() {
+        let store = test_store();
+        let mut state = store.inner.borrow_mut();
+        state.filter = Some("name=a".into());
+
+        let (request, generation) = state.begin_load(1).unwrap();
+        assert_eq!(
+            request,
+            PageRequest {
+                offset: 3,
+                limit: 3,
+                sort: None,
+                filter: Some("name=a".into()),
+            }
+        );
+        // already pending
+        assert!(state.begin_load(1).is_none());
+
+        assert!(state.finish_load(1, generation, page(5, &[3, 4])));
+        assert_eq!(state.total, Some(5));
+        assert!(state.pending.is_empty());
+        assert_eq!(state.get(4), Some(&4));
+
+        // already loaded, or beyond the total
+        assert!(state.begin_load(1).is_none());
+        assert!(state.begin_load(2).is_none());
+        assert!(state.begin_load(0).is_some());
+    }
+
+    #[test]
+    fn test_load_generation() {
+        let store = test_store();
+        let mut state = store.inner.borrow_mut();
+
+        let (_, generation) = state.begin_load(0).unwrap();
+        state.reset();
+        let (_, new_generation) = state.begin_load(0).unwrap();
+        assert_ne!(generation, new_generation);
+
+        // outdated responses are ignored
+        assert!(!state.finish_load(0, generation, page(9, &[0, 1, 2])));
+        assert_eq!(state.total, None);
+        assert!(state.pending.contains(&0));
+
+        assert!(state.finish_load(0, new_generation, page(3, &[0, 1, 2])));
+        assert_eq!(state.total, Some(3));
+    }
+
+    #[test]
+    fn test_failed_page() {
+        let store = test_store();
+        let (_, generation) = store.inner.borrow_mut().begin_load(0).unwrap();
+        let updated = store.inner.borrow_mut().finish_load(
+            0,
+            generation,
+            Err(anyhow::format_err!("connection refused")),
+        );
+        assert!(updated);
+        assert!(!store.loading());
+        assert_eq!(store.error().as_deref(), Some("connection refused"));
+
+        // no retry until reload
+        let mut state = store.inner.borrow_mut();
+        assert!(state.begin_load(0).is_none());
+        state.reset();
+        assert!(state.begin_load(0).is_some());
+        assert_eq!(state.error, None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::cell::{Ref, RefCell, RefMut};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
@@ -61,9 +61,22 @@ pub struct SharedStateObserver<T> {
 impl<T> Drop for SharedStateObserver<T> {
     fn drop(&mut self) {
         self.inner.borrow_mut().remove_listener(self.key);
+        ACTIVE_OBSERVERS.with(|count| count.set(count.get().saturating_sub(1)));
     }
 }
 
+thread_local! {
+    static ACTIVE_OBSERVERS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Returns the number of active [SharedStateObserver]s (all types).
+///
+/// Useful to detect leaking observers (see
+/// [WidgetInspector](crate::widget::WidgetInspector)).
+pub fn shared_state_observer_count() -> usize {
+    ACTIVE_OBSERVERS.with(Cell::get)
+}
+
 impl<T> SharedState<T> {
     /// Create a new instance.
     pub fn new(data: T) -> Self {
@@ -99,6 +112,7 @@ impl<T> SharedState<T> {
     /// observer inside the [SharedState] object.
     pub fn add_listener(&self, cb: impl Into<Callback<SharedState<T>>>) -> SharedStateObserver<T> {
         let key = self.inner.borrow_mut().add_listener(cb.into());
+        ACTIVE_OBSERVERS.with(|count| count.set(count.get() + 1));
         SharedStateObserver {
             key,
             inner: self.inner.clone(),
@@ -1,20 +1,33 @@
 use std::cell::{Ref, RefCell, RefMut};
+use std::collections::{HashMap, HashSet};
 use std::ops::Range;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 
 use anyhow::Error;
 use derivative::Derivative;
+use gloo_timers::callback::Timeout;
 use slab::Slab;
 
+use serde_json::Value;
+
 use yew::html::IntoEventCallback;
 use yew::prelude::*;
 use yew::virtual_dom::Key;
 
 use crate::props::{
-    ExtractKeyFn, ExtractPrimaryKey, FilterFn, IntoFilterFn, IntoSorterFn, SorterFn,
+    ExtractKeyFn, ExtractPrimaryKey, FilterFn, IntoFilterFn, IntoSorterFn, RenderFn, SorterFn,
+};
+use crate::state::{
+    DataNode, DataNodeDerefGuard, DataStore, DerivedFields, Selection, optional_rc_ptr_eq,
 };
-use crate::state::{DataNode, DataNodeDerefGuard, DataStore, Selection, optional_rc_ptr_eq};
+
+use crate::touch::{SnackBar, SnackBarController};
+use crate::tr;
+use crate::widget::get_unique_element_id;
+
+pub use super::store_journal::StoreChange;
+use super::store_journal::StoreJournal;
 
 /// Hook to use a [Store] with functional components.
 ///
@@ -125,6 +138,189 @@ impl<T: Clone> Store<T> {
     }
 }
 
+impl<T: Clone + 'static> Store<T> {
+    /// Enable the change journal.
+    ///
+    /// The journal records [insert](Self::insert_record), [update](Self::update_record)
+    /// and [remove](Self::remove_record) operations (up to `limit` entries). Direct
+    /// modifications using [Self::write] are not recorded, and
+    /// [Self::set_data] or [Self::clear] reset the journal.
+    ///
+    /// ```
+    /// # use pwt::state::{Store, StoreChange};
+    /// # #[derive(Clone, PartialEq, Debug)]
+    /// # struct User { name: String, active: bool }
+    /// let store = Store::with_extract_key(|u: &User| u.name.as_str().into());
+    /// store.enable_journal(50);
+    /// store.insert_record(User { name: "root".into(), active: true });
+    /// store.update_record(User { name: "root".into(), active: false });
+    /// assert_eq!(store.read().changes().count(), 2);
+    ///
+    /// store.undo_last();
+    /// assert!(store.read()[0].active);
+    /// ```
+    pub fn enable_journal(&self, limit: usize) {
+        self.inner.borrow_mut().journal = Some(StoreJournal::new(limit));
+    }
+
+    /// Builder style method to enable the change journal.
+    pub fn with_journal(self, limit: usize) -> Self {
+        self.enable_journal(limit);
+        self
+    }
+
+    /// Disable the change journal (discards all recorded changes).
+    pub fn disable_journal(&self) {
+        self.inner.borrow_mut().journal = None;
+    }
+
+    /// Append a record (recorded in the journal).
+    pub fn insert_record(&self, record: T) {
+        self.write().insert_record(record);
+    }
+
+    /// Replace the record with the same key (recorded in the journal).
+    ///
+    /// Returns false if there is no such record.
+    pub fn update_record(&self, record: T) -> bool {
+        let mut guard = self.write();
+        let updated = guard.update_record(record);
+        if !updated {
+            guard.skip_update();
+        }
+        updated
+    }
+
+    /// Remove the record with the specified key (recorded in the journal).
+    pub fn remove_record(&self, key: &Key) -> Option<T> {
+        let mut guard = self.write();
+        let record = guard.remove_record(key);
+        if record.is_none() {
+            guard.skip_update();
+        }
+        record
+    }
+
+    /// Revert the last recorded change.
+    ///
+    /// Returns the reverted change, or `None` if the journal is empty (or disabled).
+    pub fn undo_last(&self) -> Option<StoreChange<T>> {
+        let mut guard = self.write();
+        let change = guard.undo_last();
+        if change.is_none() {
+            guard.skip_update();
+        }
+        change
+    }
+
+    /// Discard all recorded changes, i.e. after a successful submit.
+    pub fn clear_journal(&self) {
+        self.inner.borrow_mut().clear_journal();
+    }
+
+    /// Remove a record, and show a snackbar with an "Undo" action.
+    ///
+    /// The record is removed immediately (optimistic update). If the user
+    /// presses "Undo" within `timeout` milliseconds, the record is
+    /// restored at its old position. Else `on_delete` is called with the
+    /// removed record, so that the caller can do the real deletion (i.e.
+    /// call the API).
+    ///
+    /// Returns the snackbar ID, or `None` if there is no such record.
+    ///
+    /// Note: This modification is not recorded in the journal.
+    ///
+    /// ```
+    /// # use pwt::prelude::*;
+    /// # use pwt::state::Store;
+    /// # use pwt::touch::SnackBarController;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct User { name: String }
+    /// # fn delete_user(store: &Store<User>, controller: &SnackBarController) {
+    /// store.remove_with_undo(
+    ///     &Key::from("root"),
+    ///     controller,
+    ///     tr!("Deleted user {0}", "root"),
+    ///     5000,
+    ///     |user: User| { /* call the delete API */ },
+    /// );
+    /// # }
+    /// ```
+    pub fn remove_with_undo(
+        &self,
+        key: &Key,
+        controller: &SnackBarController,
+        message: impl Into<AttrValue>,
+        timeout: u32,
+        on_delete: impl Into<Callback<T>>,
+    ) -> Option<AttrValue> {
+        let (pos, record) = {
+            let mut guard = self.write();
+            let Some(pos) = guard.record_pos(key) else {
+                guard.skip_update();
+                return None;
+            };
+            (pos, guard.remove(pos))
+        };
+
+        // the removed record, until undo or timeout
+        let pending = Rc::new(RefCell::new(Some(record)));
+        let id = AttrValue::from(get_unique_element_id());
+
+        let snackbar = SnackBar::new()
+            .id(id.clone())
+            .message(message.into())
+            .action_label(tr!("Undo"))
+            .duration(timeout)
+            .on_action({
+                let store = self.clone();
+                let pending = Rc::clone(&pending);
+                let controller = controller.clone();
+                let id = id.clone();
+                move |_| {
+                    store.undo_remove(pos, &pending);
+                    controller.dismiss(id.clone());
+                }
+            });
+
+        controller.show_snackbar(snackbar);
+
+        // Note: the snackbar may be queued behind others, so we dismiss
+        // it when the record gets deleted.
+        let on_delete = on_delete.into();
+        Timeout::new(timeout, {
+            let controller = controller.clone();
+            let id = id.clone();
+            move || {
+                if let Some(record) = pending.borrow_mut().take() {
+                    on_delete.emit(record);
+                }
+                controller.dismiss(id);
+            }
+        })
+        .forget();
+
+        Some(id)
+    }
+
+    // Re-insert a record removed by [Self::remove_with_undo], unless it
+    // was already deleted or reloaded in the meantime.
+    fn undo_remove(&self, pos: usize, pending: &RefCell<Option<T>>) -> bool {
+        let Some(record) = pending.borrow_mut().take() else {
+            return false;
+        };
+        let mut guard = self.write();
+        let key = guard.extract_key(&record);
+        if guard.record_pos(&key).is_some() {
+            guard.skip_update();
+            return false;
+        }
+        let pos = pos.min(guard.len());
+        guard.insert(pos, record);
+        true
+    }
+}
+
 impl<T: 'static> Store<T> {
     /// Creates a new instance with the specifies extract key function.
     pub fn with_extract_key(extract_key: impl Into<ExtractKeyFn<T>>) -> Self {
@@ -147,6 +343,48 @@ impl<T: 'static> Store<T> {
         self
     }
 
+    /// Register a named derived field.
+    ///
+    /// Derived values are computed on demand and cached until the
+    /// record changes. Use [Self::derived_fields] to access them
+    /// inside sorter/filter functions.
+    ///
+    /// ```
+    /// # use serde_json::{json, Value};
+    /// # use pwt::state::Store;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Storage { name: String, used: u64, total: u64 }
+    /// let store = Store::with_extract_key(|s: &Storage| s.name.as_str().into());
+    /// store.derive("usage_pct", |s: &Storage| -> Value {
+    ///     json!(s.used as f64 * 100.0 / s.total.max(1) as f64)
+    /// });
+    /// let fields = store.derived_fields();
+    /// store.set_sorter(fields.sorter("usage_pct"));
+    /// ```
+    pub fn derive(&self, name: impl Into<AttrValue>, derive_fn: impl Into<RenderFn<T, Value>>) {
+        self.derived_fields().register(name, derive_fn);
+    }
+
+    /// Builder style method to register a named derived field.
+    pub fn with_derived(
+        self,
+        name: impl Into<AttrValue>,
+        derive_fn: impl Into<RenderFn<T, Value>>,
+    ) -> Self {
+        self.derive(name, derive_fn);
+        self
+    }
+
+    /// Returns the [DerivedFields] handle.
+    pub fn derived_fields(&self) -> DerivedFields<T> {
+        self.inner.borrow().derived.clone()
+    }
+
+    /// Returns the (cached) value of a derived field.
+    pub fn derived_value(&self, name: &str, record: &T) -> Option<Value> {
+        self.derived_fields().get(name, record)
+    }
+
     /// Lock this store for read access.
     ///
     /// # Panics
@@ -215,15 +453,77 @@ impl<T: 'static> Store<T> {
         self.write().set_sorter(sorter);
     }
 
+    /// Add a sorter to the sorter chain.
+    ///
+    /// The new sorter is used for records the existing sorters consider
+    /// equal (i.e. sort by "node", then by "name").
+    ///
+    /// ```
+    /// # use pwt::state::Store;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Guest { node: String, name: String }
+    /// let store = Store::with_extract_key(|g: &Guest| g.name.as_str().into());
+    /// store.add_sorter(|a: &Guest, b: &Guest| a.node.cmp(&b.node));
+    /// store.add_sorter(|a: &Guest, b: &Guest| a.name.cmp(&b.name));
+    /// ```
+    pub fn add_sorter(&self, sorter: impl IntoSorterFn<T>) {
+        self.write().add_sorter(sorter);
+    }
+
+    /// Remove all sorters.
+    pub fn clear_sorters(&self) {
+        self.write().set_sorter(None);
+    }
+
     /// Set the filter function.
     pub fn set_filter(&self, filter: impl IntoFilterFn<T>) {
         self.write().set_filter(filter);
     }
 
+    /// Returns the filter function.
+    pub fn get_filter(&self) -> Option<FilterFn<T>> {
+        self.inner.borrow().filter.clone()
+    }
+
     pub fn set_data(&self, data: Vec<T>) {
         self.write().set_data(data);
     }
 
+    /// Synchronize the store with a new list of records.
+    ///
+    /// Unlike [Self::set_data], this compares the new records with the
+    /// existing ones (using the record key), and only replaces records
+    /// which actually changed. If nothing changed, listeners are not
+    /// notified, so periodically reloaded data does not trigger useless
+    /// redraws. Selection and cursor are key based, so they stay on the
+    /// same records.
+    ///
+    /// ```
+    /// # use pwt::state::Store;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Node { name: String, cpu: f64 }
+    /// let store = Store::with_extract_key(|n: &Node| n.name.as_str().into());
+    /// let node = |name: &str, cpu| Node { name: name.into(), cpu };
+    /// store.set_data(vec![node("a", 0.1), node("b", 0.2)]);
+    ///
+    /// let result = store.sync_data(vec![node("a", 0.1), node("b", 0.5), node("c", 0.0)]);
+    /// assert_eq!((result.inserted, result.updated, result.removed), (1, 1, 0));
+    ///
+    /// let result = store.sync_data(vec![node("a", 0.1), node("b", 0.5), node("c", 0.0)]);
+    /// assert!(!result.changed());
+    /// ```
+    pub fn sync_data(&self, data: Vec<T>) -> StoreSyncResult
+    where
+        T: PartialEq,
+    {
+        let mut guard = self.write();
+        let result = guard.sync_data(data);
+        if !result.changed() {
+            guard.skip_update();
+        }
+        result
+    }
+
     pub fn clear(&self) {
         self.write().clear();
     }
@@ -308,6 +608,11 @@ impl<T: 'static> Drop for StoreWriteGuard<'_, T> {
     fn drop(&mut self) {
         if self.update {
             self.version += 1;
+            match std::mem::take(&mut self.state.derived_changes) {
+                DerivedChanges::None => {}
+                DerivedChanges::Keys(keys) => self.state.derived.invalidate_keys(&keys),
+                DerivedChanges::All => self.state.derived.invalidate(),
+            }
             self.state.notify_listeners();
         }
     }
@@ -403,6 +708,10 @@ impl<T: Clone + PartialEq + 'static> DataStore for Store<T> {
         self.set_filter(filter);
     }
 
+    fn get_filter(&self) -> Option<FilterFn<T>> {
+        self.get_filter()
+    }
+
     fn lookup_filtered_record_key(&self, cursor: usize) -> Option<Key> {
         self.lookup_filtered_record_key(cursor)
     }
@@ -429,6 +738,48 @@ impl<T: Clone + PartialEq + 'static> DataStore for Store<T> {
     }
 }
 
+/// Summary of the changes applied by [Store::sync_data].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StoreSyncResult {
+    /// Number of new records.
+    pub inserted: usize,
+    /// Number of replaced (modified) records.
+    pub updated: usize,
+    /// Number of removed records.
+    pub removed: usize,
+    /// Set if the order of the remaining records changed.
+    pub moved: bool,
+}
+
+impl StoreSyncResult {
+    /// Returns true if the store data changed.
+    pub fn changed(&self) -> bool {
+        self.inserted > 0 || self.updated > 0 || self.removed > 0 || self.moved
+    }
+}
+
+// Records changed since the last derived field cache invalidation.
+#[derive(Default)]
+enum DerivedChanges {
+    #[default]
+    None,
+    Keys(HashSet<Key>),
+    // unknown changes (i.e. direct access to the data vector)
+    All,
+}
+
+impl DerivedChanges {
+    fn add_key(&mut self, key: Key) {
+        match self {
+            DerivedChanges::None => *self = DerivedChanges::Keys(HashSet::from([key])),
+            DerivedChanges::Keys(keys) => {
+                keys.insert(key);
+            }
+            DerivedChanges::All => {}
+        }
+    }
+}
+
 /// Implements the [Store] for lists of records (`Vec<T>`).
 ///
 /// This class provides the actual [Store] implementation, and is
@@ -447,6 +798,11 @@ pub struct StoreState<T> {
     filter: Option<FilterFn<T>>,
 
     listeners: Slab<Callback<()>>,
+
+    derived: DerivedFields<T>,
+    derived_changes: DerivedChanges,
+
+    journal: Option<StoreJournal<T>>,
 }
 
 impl<T> Deref for StoreState<T> {
@@ -459,6 +815,8 @@ impl<T> Deref for StoreState<T> {
 
 impl<T> DerefMut for StoreState<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        // we do not know which records get modified
+        self.derived_changes = DerivedChanges::All;
         &mut self.data
     }
 }
@@ -468,12 +826,15 @@ impl<T: 'static> StoreState<T> {
         Self {
             version: 0,
             data: Vec::new(),
+            derived: DerivedFields::new(extract_key.clone()),
+            derived_changes: DerivedChanges::None,
             extract_key,
             filtered_data: Vec::new(),
             last_view_version: 0,
             sorter: None,
             filter: None,
             listeners: Slab::new(),
+            journal: None,
         }
     }
 
@@ -505,6 +866,16 @@ impl<T: 'static> StoreState<T> {
         self.sorter = sorter.into_sorter_fn();
     }
 
+    fn add_sorter(&mut self, sorter: impl IntoSorterFn<T>) {
+        let Some(sorter) = sorter.into_sorter_fn() else {
+            return;
+        };
+        self.sorter = Some(match self.sorter.take() {
+            Some(first) => first.then(sorter),
+            None => sorter,
+        });
+    }
+
     fn set_filter(&mut self, filter: impl IntoFilterFn<T>) {
         self.filter = filter.into_filter_fn();
     }
@@ -515,10 +886,99 @@ impl<T: 'static> StoreState<T> {
 
     pub fn set_data(&mut self, data: Vec<T>) {
         self.data = data;
+        self.derived_changes = DerivedChanges::All;
+        self.clear_journal();
+    }
+
+    /// Synchronize the data with a new list of records (see [Store::sync_data]).
+    ///
+    /// Unchanged records are kept, and the record order is taken from
+    /// `data`. The journal is reset if something changed.
+    pub fn sync_data(&mut self, data: Vec<T>) -> StoreSyncResult
+    where
+        T: PartialEq,
+    {
+        let mut result = StoreSyncResult::default();
+
+        let old_data = std::mem::take(&mut self.data);
+        let mut old_records: HashMap<Key, (usize, T)> = HashMap::with_capacity(old_data.len());
+        for (pos, record) in old_data.into_iter().enumerate() {
+            old_records.insert(self.extract_key(&record), (pos, record));
+        }
+
+        let mut last_pos = None;
+        let mut new_data = Vec::with_capacity(data.len());
+        for record in data {
+            let key = self.extract_key(&record);
+            match old_records.remove(&key) {
+                Some((pos, old_record)) => {
+                    if last_pos.is_some_and(|last_pos| pos < last_pos) {
+                        result.moved = true;
+                    }
+                    last_pos = Some(pos);
+                    if old_record == record {
+                        new_data.push(old_record);
+                    } else {
+                        result.updated += 1;
+                        self.derived_changes.add_key(key);
+                        new_data.push(record);
+                    }
+                }
+                None => {
+                    result.inserted += 1;
+                    self.derived_changes.add_key(key);
+                    new_data.push(record);
+                }
+            }
+        }
+        result.removed = old_records.len();
+        for key in old_records.into_keys() {
+            self.derived_changes.add_key(key);
+        }
+
+        self.data = new_data;
+        if result.changed() {
+            self.clear_journal();
+        }
+        result
     }
 
     pub fn clear(&mut self) {
         self.data = Vec::new();
+        self.derived_changes = DerivedChanges::All;
+        self.clear_journal();
+    }
+
+    fn clear_journal(&mut self) {
+        if let Some(journal) = &mut self.journal {
+            journal.clear();
+        }
+    }
+
+    fn record_change(&mut self, change: impl FnOnce() -> StoreChange<T>) {
+        if let Some(journal) = &mut self.journal {
+            journal.record(change());
+        }
+    }
+
+    /// Returns true if the change journal is enabled.
+    pub fn journal_enabled(&self) -> bool {
+        self.journal.is_some()
+    }
+
+    /// Iterate over the recorded changes (oldest first).
+    ///
+    /// This is useful to generate a summary of pending changes before submit.
+    pub fn changes(&self) -> impl Iterator<Item = &StoreChange<T>> {
+        self.journal.iter().flat_map(|journal| journal.iter())
+    }
+
+    /// Returns the number of recorded changes.
+    pub fn change_count(&self) -> usize {
+        self.journal
+            .as_ref()
+            .map(|journal| journal.len())
+            .unwrap_or(0)
     }
 
     fn update_filtered_data(&mut self) {
@@ -565,6 +1025,71 @@ impl<T: 'static> StoreState<T> {
         self.filtered_data.len()
     }
 
+    /// Append a record (recorded in the journal).
+    pub fn insert_record(&mut self, record: T)
+    where
+        T: Clone,
+    {
+        let pos = self.data.len();
+        let key = self.extract_key(&record);
+        self.derived_changes.add_key(key);
+        self.record_change(|| StoreChange::Insert {
+            pos,
+            record: record.clone(),
+        });
+        self.data.push(record);
+    }
+
+    /// Replace the record with the same key (recorded in the journal).
+    ///
+    /// Returns false if there is no such record.
+    pub fn update_record(&mut self, record: T) -> bool
+    where
+        T: Clone,
+    {
+        let key = self.extract_key(&record);
+        let Some(pos) = self.record_pos(&key) else {
+            return false;
+        };
+        self.derived_changes.add_key(key);
+        let before = std::mem::replace(&mut self.data[pos], record);
+        if let Some(journal) = &mut self.journal {
+            journal.record(StoreChange::Update {
+                pos,
+                before,
+                after: self.data[pos].clone(),
+            });
+        }
+        true
+    }
+
+    /// Remove the record with the specified key (recorded in the journal).
+    pub fn remove_record(&mut self, key: &Key) -> Option<T>
+    where
+        T: Clone,
+    {
+        let pos = self.record_pos(key)?;
+        self.derived_changes.add_key(key.clone());
+        let record = self.data.remove(pos);
+        self.record_change(|| StoreChange::Remove {
+            pos,
+            record: record.clone(),
+        });
+        Some(record)
+    }
+
+    /// Revert the last recorded change.
+    pub fn undo_last(&mut self) -> Option<StoreChange<T>>
+    where
+        T: Clone,
+    {
+        let change = self.journal.as_mut()?.pop()?;
+        self.derived_changes = DerivedChanges::All;
+        let extract_key = self.extract_key.clone();
+        change.revert(&mut self.data, |record| extract_key.apply(record));
+        Some(change)
+    }
+
     /// Find a record position by its key.
     pub fn record_pos(&self, key: &Key) -> Option<usize> {
         self.data
@@ -579,7 +1104,9 @@ impl<T: 'static> StoreState<T> {
 
     /// Find a record by its key (mutable).
     pub fn lookup_record_mut(&mut self, key: &Key) -> Option<&mut T> {
-        self.record_pos(key).map(|n| &mut self.data[n])
+        let pos = self.record_pos(key)?;
+        self.derived_changes.add_key(key.clone());
+        Some(&mut self.data[pos])
     }
 
     /// Find the record that has the same key as is currently selected by `selection`.
@@ -643,3 +1170,176 @@ where
         Some((pos, node))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_store(data: &[(u32, &str)]) -> Store<(u32, String)> {
+        let store = Store::with_extract_key(|(id, _): &(u32, String)| Key::from(*id as usize));
+        store.set_data(data.iter().map(|(id, v)| (*id, v.to_string())).collect());
+        store
+    }
+
+    fn sync(store: &Store<(u32, String)>, data: &[(u32, &str)]) -> StoreSyncResult {
+        store.sync_data(data.iter().map(|(id, v)| (*id, v.to_string())).collect())
+    }
+
+    #[test]
+    fn test_undo_remove() {
+        let store = create_store(&[(1, "a"), (2, "b"), (3, "c")]);
+        let ids = |store: &Store<(u32, String)>| -> Vec<u32> {
+            store.read().iter().map(|(id, _)| *id).collect()
+        };
+
+        let record = store.write().remove(1);
+        let pending = RefCell::new(Some(record));
+        assert!(store.undo_remove(1, &pending));
+        assert_eq!(ids(&store), vec![1, 2, 3]);
+        assert!(!store.undo_remove(1, &pending));
+
+        // record already deleted by the timeout
+        let record = store.write().remove(1);
+        let pending = RefCell::new(Some(record));
+        assert!(pending.borrow_mut().take().is_some());
+        assert!(!store.undo_remove(1, &pending));
+        assert_eq!(ids(&store), vec![1, 3]);
+
+        // record reloaded in the meantime
+        let record = store.write().remove(0);
+        let pending = RefCell::new(Some(record));
+        sync(&store, &[(1, "a"), (3, "c")]);
+        assert!(!store.undo_remove(0, &pending));
+        assert_eq!(ids(&store), vec![1, 3]);
+
+        // position clamped to the current length
+        let record = store.write().remove(1);
+        store.write().remove(0);
+        let pending = RefCell::new(Some(record));
+        assert!(store.undo_remove(1, &pending));
+        assert_eq!(ids(&store), vec![3]);
+    }
+
+    #[test]
+    fn test_sync_data() {
+        let store = create_store(&[(1, "a"), (2, "b"), (3, "c")]);
+        let version = store.read().version;
+
+        let result = sync(&store, &[(1, "a"), (2, "b"), (3, "c")]);
+        assert!(!result.changed());
+        assert_eq!(store.read().version, version);
+
+        let result = sync(&store, &[(1, "a"), (3, "x"), (4, "d")]);
+        assert_eq!(
+            result,
+            StoreSyncResult {
+                inserted: 1,
+                updated: 1,
+                removed: 1,
+                moved: false
+            }
+        );
+        assert_eq!(store.read().version, version + 1);
+
+        let result = sync(&store, &[(4, "d"), (1, "a"), (3, "x")]);
+        assert!(result.moved);
+        assert_eq!(result.inserted + result.updated + result.removed, 0);
+        let ids: Vec<u32> = store.read().iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![4, 1, 3]);
+    }
+
+    #[test]
+    fn test_sorter_chain() {
+        let store = create_store(&[(1, "b"), (2, "a"), (3, "b"), (4, "a")]);
+        let filtered_ids = || -> Vec<u32> {
+            store
+                .filtered_data()
+                .map(|(_, node)| node.record().0)
+                .collect()
+        };
+
+        store.add_sorter(|a: &(u32, String), b: &(u32, String)| a.1.cmp(&b.1));
+        store.add_sorter(|a: &(u32, String), b: &(u32, String)| b.0.cmp(&a.0));
+        assert_eq!(filtered_ids(), vec![4, 2, 3, 1]);
+
+        store.clear_sorters();
+        assert_eq!(filtered_ids(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_journal_undo() {
+        let store = create_store(&[(1, "a"), (2, "b"), (3, "c")]);
+        store.enable_journal(10);
+        let ids = || -> Vec<u32> { store.read().iter().map(|(id, _)| *id).collect() };
+
+        store.insert_record((4, "d".into()));
+        assert!(store.update_record((1, "x".into())));
+        assert!(store.remove_record(&Key::from(2usize)).is_some());
+        assert!(store.remove_record(&Key::from(9usize)).is_none());
+        assert_eq!(store.change_count(), 3);
+
+        // unrecorded modification (moves all records)
+        store.write().reverse();
+        assert_eq!(ids(), vec![4, 3, 1]);
+
+        assert!(matches!(
+            store.undo_last(),
+            Some(StoreChange::Remove { .. })
+        ));
+        assert_eq!(ids(), vec![4, 2, 3, 1]);
+
+        assert!(matches!(
+            store.undo_last(),
+            Some(StoreChange::Update { .. })
+        ));
+        assert_eq!(
+            store.read().lookup_record(&Key::from(1usize)).unwrap().1,
+            "a"
+        );
+
+        assert!(matches!(
+            store.undo_last(),
+            Some(StoreChange::Insert { .. })
+        ));
+        assert_eq!(ids(), vec![2, 3, 1]);
+
+        assert!(store.undo_last().is_none());
+        assert_eq!(store.change_count(), 0);
+    }
+
+    #[test]
+    fn test_derived_invalidation() {
+        let store = create_store(&[(1, "a"), (2, "b"), (3, "c")]);
+        let calls = Rc::new(std::cell::Cell::new(0));
+        store.derive("upper", {
+            let calls = calls.clone();
+            move |(_, v): &(u32, String)| -> Value {
+                calls.set(calls.get() + 1);
+                Value::from(v.to_uppercase())
+            }
+        });
+        let values = || -> Vec<Value> {
+            let data: Vec<_> = store.read().data().to_vec();
+            data.iter()
+                .map(|record| store.derived_value("upper", record).unwrap())
+                .collect()
+        };
+
+        assert_eq!(values(), vec!["A", "B", "C"]);
+        assert_eq!(calls.get(), 3);
+
+        // only the changed records are computed again
+        store.write().update_record((2, String::from("x")));
+        assert_eq!(values(), vec!["A", "X", "C"]);
+        assert_eq!(calls.get(), 4);
+
+        sync(&store, &[(1, "a"), (3, "y"), (4, "d")]);
+        assert_eq!(values(), vec!["A", "Y", "D"]);
+        assert_eq!(calls.get(), 6);
+
+        // direct data access invalidates everything
+        store.write().reverse();
+        assert_eq!(values(), vec!["D", "Y", "A"]);
+        assert_eq!(calls.get(), 9);
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::VecDeque;
+
+use yew::virtual_dom::Key;
+
+/// A single recorded [Store](super::Store) modification.
+///
+/// Each entry contains snapshots of the affected record, so it can be
+/// reverted (see [Store::undo_last](super::Store::undo_last)).
+#[derive(Clone, Debug, PartialEq)]
+pub enum StoreChange<T> {
+    /// Record inserted at `pos`.
+    Insert { pos: usize, record: T },
+    /// Record at `pos` replaced.
+    Update { pos: usize, before: T, after: T },
+    /// Record removed from `pos`.
+    Remove { pos: usize, record: T },
+}
+
+impl<T> StoreChange<T> {
+    /// Returns the record position affected by this change.
+    pub fn pos(&self) -> usize {
+        match self {
+            StoreChange::Insert { pos, .. }
+            | StoreChange::Update { pos, .. }
+            | StoreChange::Remove { pos, .. } => *pos,
+        }
+    }
+
+    /// Returns the record state before the change (if any).
+    pub fn before(&self) -> Option<&T> {
+        match self {
+            StoreChange::Insert { .. } => None,
+            StoreChange::Update { before, .. } => Some(before),
+            StoreChange::Remove { record, .. } => Some(record),
+        }
+    }
+
+    /// Returns the record state after the change (if any).
+    pub fn after(&self) -> Option<&T> {
+        match self {
+            StoreChange::Insert { record, .. } => Some(record),
+            StoreChange::Update { after, .. } => Some(after),
+            StoreChange::Remove { .. } => None,
+        }
+    }
+
+    // Revert this change on `data`.
+    //
+    // Records are looked up by key, because positions may have changed
+    // (i.e. by unrecorded modifications). The position is only used to
+    // re-insert removed records.
+    pub(crate) fn revert(&self, data: &mut Vec<T>, extract_key: impl Fn(&T) -> Key)
+    where
+        T: Clone,
+    {
+        let find = |data: &[T], record: &T| {
+            let key = extract_key(record);
+            data.iter().position(|item| extract_key(item) == key)
+        };
+        match self {
+            StoreChange::Insert { record, .. } => {
+                if let Some(pos) = find(data, record) {
+                    data.remove(pos);
+                }
+            }
+            StoreChange::Update { before, .. } => {
+                if let Some(pos) = find(data, before) {
+                    data[pos] = before.clone();
+                }
+            }
+            StoreChange::Remove { pos, record } => {
+                if find(data, record).is_none() {
+                    data.insert((*pos).min(data.len()), record.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Bounded list of [StoreChange] entries (oldest first).
+pub(crate) struct StoreJournal<T> {
+    limit: usize,
+    changes: VecDeque<StoreChange<T>>,
+}
+
+impl<T> StoreJournal<T> {
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            changes: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, change: StoreChange<T>) {
+        if self.limit == 0 {
+            return;
+        }
+        while self.changes.len() >= self.limit {
+            self.changes.pop_front();
+        }
+        self.changes.push_back(change);
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<StoreChange<T>> {
+        self.changes.pop_back()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.changes.clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &StoreChange<T>> {
+        self.changes.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_journal_limit() {
+        let mut journal = StoreJournal::new(2);
+        for pos in 0..3 {
+            journal.record(StoreChange::Insert { pos, record: pos });
+        }
+        let positions: Vec<usize> = journal.iter().map(|c| c.pos()).collect();
+        assert_eq!(positions, vec![1, 2]);
+    }
+
+    // (key, value) records
+    fn key(record: &(u32, &str)) -> Key {
+        Key::from(record.0 as usize)
+    }
+
+    #[test]
+    fn test_revert() {
+        let mut data = vec![(1, "a"), (2, "b"), (3, "c")];
+
+        let changes = [
+            StoreChange::Insert {
+                pos: 3,
+                record: (4, "d"),
+            },
+            StoreChange::Update {
+                pos: 0,
+                before: (1, "a"),
+                after: (1, "x"),
+            },
+            StoreChange::Remove {
+                pos: 1,
+                record: (2, "b"),
+            },
+        ];
+
+        let mut current = vec![(1, "x"), (3, "c"), (4, "d")];
+        for change in changes.iter().rev() {
+            change.revert(&mut current, key);
+        }
+        assert_eq!(current, data);
+
+        StoreChange::Remove {
+            pos: 10,
+            record: (5, "e"),
+        }
+        .revert(&mut data, key);
+        assert_eq!(data, vec![(1, "a"), (2, "b"), (3, "c"), (5, "e")]);
+    }
+
+    #[test]
+    fn test_revert_moved_records() {
+        // positions changed by unrecorded modifications
+        let mut data = vec![(3, "c"), (4, "d"), (1, "x")];
+
+        StoreChange::Insert {
+            pos: 0,
+            record: (4, "d"),
+        }
+        .revert(&mut data, key);
+        assert_eq!(data, vec![(3, "c"), (1, "x")]);
+
+        StoreChange::Update {
+            pos: 0,
+            before: (1, "a"),
+            after: (1, "x"),
+        }
+        .revert(&mut data, key);
+        assert_eq!(data, vec![(3, "c"), (1, "a")]);
+
+        // records which are gone are not touched
+        StoreChange::Update {
+            pos: 1,
+            before: (7, "g"),
+            after: (7, "y"),
+        }
+        .revert(&mut data, key);
+        StoreChange::Insert {
+            pos: 1,
+            record: (8, "h"),
+        }
+        .revert(&mut data, key);
+        assert_eq!(data, vec![(3, "c"), (1, "a")]);
+
+        // removed records are re-inserted at the old position, unless they
+        // were added again
+        StoreChange::Remove {
+            pos: 1,
+            record: (2, "b"),
+        }
+        .revert(&mut data, key);
+        assert_eq!(data, vec![(3, "c"), (2, "b"), (1, "a")]);
+        StoreChange::Remove {
+            pos: 0,
+            record: (3, "old"),
+        }
+        .revert(&mut data, key);
+        assert_eq!(data, vec![(3, "c"), (2, "b"), (1, "a")]);
+    }
+}
@@ -0,0 +1,235 @@
+use std::marker::PhantomData;
+
+use anyhow::Error;
+use gloo_events::EventListener;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use wasm_bindgen::JsCast;
+
+use yew::prelude::*;
+
+// this `thread_local!` definition should be fine as this crate is essentially WASM only (see
+// `state::language`).
+thread_local! {
+    static TAB_ID: String = format!(
+        "{:x}-{:x}",
+        js_sys::Date::now() as u64,
+        (js_sys::Math::random() * u32::MAX as f64) as u32
+    );
+}
+
+/// Returns a random ID which identifies this browser tab (window).
+pub fn tab_id() -> String {
+    TAB_ID.with(|id| id.clone())
+}
+
+// Message envelope sent over the broadcast channel.
+#[derive(Serialize, Deserialize)]
+struct TabMessageEnvelope<T> {
+    sender: String,
+    message: T,
+}
+
+fn encode_message<T: Serialize>(sender: &str, message: &T) -> Result<String, Error> {
+    let envelope = TabMessageEnvelope {
+        sender: sender.to_string(),
+        message,
+    };
+    Ok(serde_json::to_string(&envelope)?)
+}
+
+fn decode_message<T: DeserializeOwned>(data: &str) -> Result<(String, T), Error> {
+    let envelope: TabMessageEnvelope<T> = serde_json::from_str(data)?;
+    Ok((envelope.sender, envelope.message))
+}
+
+fn open_channel(name: &str) -> Option<web_sys::BroadcastChannel> {
+    match web_sys::BroadcastChannel::new(name) {
+        Ok(channel) => Some(channel),
+        Err(err) => {
+            log::error!("unable to open broadcast channel '{name}' - {err:?}");
+            None
+        }
+    }
+}
+
+/// Typed publish/subscribe between browser tabs of the same app.
+///
+/// This is a thin wrapper around a
+/// [BroadcastChannel](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel).
+/// Messages are serialized as JSON, together with the [tab_id] of the
+/// sender. Subscribers only receive messages published by other tabs.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use pwt::state::TabChannel;
+/// #[derive(Serialize, Deserialize)]
+/// enum AppEvent {
+///     Logout,
+///     TaskFinished { upid: String },
+/// }
+///
+/// # fn test() {
+/// let channel = TabChannel::<AppEvent>::new("my-app-events");
+///
+/// // Note: the subscription is active until the guard is dropped.
+/// let _subscription = channel.subscribe(|event: AppEvent| {
+///     if let AppEvent::Logout = event {
+///         log::info!("logged out in another tab");
+///     }
+/// });
+///
+/// channel.publish(&AppEvent::Logout);
+/// # }
+/// ```
+pub struct TabChannel<T> {
+    name: AttrValue,
+    channel: Option<web_sys::BroadcastChannel>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> Drop for TabChannel<T> {
+    fn drop(&mut self) {
+        if let Some(channel) = &self.channel {
+            channel.close();
+        }
+    }
+}
+
+impl<T: 'static + Serialize + DeserializeOwned> TabChannel<T> {
+    /// Create a new instance.
+    ///
+    /// Please use an app specific channel name.
+    pub fn new(name: impl Into<AttrValue>) -> Self {
+        let name = name.into();
+        Self {
+            channel: open_channel(&name),
+            name,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the channel name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Send a message to all other tabs.
+    pub fn publish(&self, message: &T) {
+        let Some(channel) = &self.channel else {
+            return;
+        };
+        let data = match encode_message(&tab_id(), message) {
+            Ok(data) => data,
+            Err(err) => {
+                log::error!(
+                    "TabChannel '{}': unable to encode message - {err}",
+                    self.name
+                );
+                return;
+            }
+        };
+        if let Err(err) = channel.post_message(&data.into()) {
+            log::error!("TabChannel '{}': post message failed - {err:?}", self.name);
+        }
+    }
+
+    /// Subscribe to messages from other tabs.
+    ///
+    /// The returned guard unsubscribes when dropped.
+    pub fn subscribe(&self, callback: impl Into<Callback<T>>) -> TabChannelSubscription {
+        let callback = callback.into();
+        let channel = open_channel(&self.name);
+
+        let _listener = channel.as_ref().map(|channel| {
+            let name = self.name.clone();
+            let own_id = tab_id();
+            EventListener::new(channel, "message", move |event| {
+                let Some(data) = event
+                    .dyn_ref::<web_sys::MessageEvent>()
+                    .and_then(|event| event.data().as_string())
+                else {
+                    return;
+                };
+                match decode_message::<T>(&data) {
+                    Ok((sender, _)) if sender == own_id => { /* ignore own messages */ }
+                    Ok((_, message)) => callback.emit(message),
+                    Err(err) => log::warn!("TabChannel '{name}': unable to decode message - {err}"),
+                }
+            })
+        });
+
+        TabChannelSubscription { channel, _listener }
+    }
+}
+
+/// Subscription guard returned by [TabChannel::subscribe].
+///
+/// Closes the underlying channel when dropped.
+pub struct TabChannelSubscription {
+    channel: Option<web_sys::BroadcastChannel>,
+    _listener: Option<EventListener>,
+}
+
+impl Drop for TabChannelSubscription {
+    fn drop(&mut self) {
+        if let Some(channel) = &self.channel {
+            channel.close();
+        }
+    }
+}
+
+/// Notification message, i.e. to show a
+/// [SnackBar](crate::touch::SnackBar) in other tabs.
+///
+/// See [SnackBarManager::tab_channel](crate::touch::SnackBarManager::tab_channel).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TabNotification {
+    /// The text message.
+    pub message: String,
+    /// Display duration in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+}
+
+impl TabNotification {
+    /// Create a new instance.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            duration: None,
+        }
+    }
+
+    /// Builder style method to set the display duration.
+    pub fn duration(mut self, duration: impl Into<Option<u32>>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_message_envelope() {
+        let notification = TabNotification::new("Task finished").duration(2000);
+
+        let data = encode_message("tab1", &notification).unwrap();
+        assert_eq!(
+            data,
+            r#"{"sender":"tab1","message":{"message":"Task finished","duration":2000}}"#
+        );
+
+        let (sender, message) = decode_message::<TabNotification>(&data).unwrap();
+        assert_eq!(sender, "tab1");
+        assert_eq!(message, notification);
+
+        let (_, message) =
+            decode_message::<TabNotification>(r#"{"sender":"tab2","message":{"message":"x"}}"#)
+                .unwrap();
+        assert_eq!(message, TabNotification::new("x"));
+
+        assert!(decode_message::<TabNotification>(r#"{"message":"x"}"#).is_err());
+    }
+}
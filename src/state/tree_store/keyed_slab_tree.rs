@@ -21,7 +21,12 @@ use super::{SlabTree, SlabTreeEntry};
 pub struct KeyedSlabTree<T> {
     pub(crate) tree: SlabTree<T>,
     pub(crate) linear_view: Vec<usize>, // node_id list
+    // node_id => linear_view position (only valid below view_index_len)
+    view_index: HashMap<usize, usize>,
+    view_index_len: usize,
     last_view_version: usize,
+    // expand/collapse changes since last view update (applied incrementally)
+    expand_changes: Vec<usize>,
 
     pub(crate) extract_key: ExtractKeyFn<T>,
 
@@ -237,7 +242,10 @@ impl<T> KeyedSlabTree<T> {
             extract_key: extract_key.into(),
             tree: SlabTree::new(),
             linear_view: Vec::new(),
+            view_index: HashMap::new(),
+            view_index_len: 0,
             last_view_version: 0,
+            expand_changes: Vec::new(),
             sorter: None,
             filter: None,
             listeners: Slab::new(),
@@ -261,6 +269,16 @@ impl<T> KeyedSlabTree<T> {
         self.tree.record_data_change();
     }
 
+    // Record an expand/collapse change, so that we can update the
+    // linear view incrementally.
+    pub(crate) fn record_expand_change(&mut self, node_id: usize) {
+        // only track changes if there are no other pending changes
+        if self.tree.version() == self.last_view_version + self.expand_changes.len() {
+            self.expand_changes.push(node_id);
+        }
+        self.record_data_change();
+    }
+
     /// Returns the unique record key.
     pub fn extract_key(&self, data: &T) -> Key {
         self.extract_key.apply(data)
@@ -290,11 +308,29 @@ impl<T> KeyedSlabTree<T> {
         self.tree.record_data_change();
     }
 
+    pub(crate) fn add_sorter(&mut self, sorter: impl IntoSorterFn<T>)
+    where
+        T: 'static,
+    {
+        let Some(sorter) = sorter.into_sorter_fn() else {
+            return;
+        };
+        self.sorter = Some(match self.sorter.take() {
+            Some(first) => first.then(sorter),
+            None => sorter,
+        });
+        self.tree.record_data_change();
+    }
+
     pub(crate) fn set_filter(&mut self, filter: impl IntoFilterFn<T>) {
         self.filter = filter.into_filter_fn();
         self.tree.record_data_change();
     }
 
+    pub(crate) fn get_filter(&self) -> Option<FilterFn<T>> {
+        self.filter.clone()
+    }
+
     fn flatten_tree_children(&self, list: &mut Vec<usize>, children: &[usize]) {
         let mut children: Vec<usize> = match &self.filter {
             Some(filter) => children
@@ -329,10 +365,100 @@ impl<T> KeyedSlabTree<T> {
     }
 
     pub(crate) fn update_filtered_data(&mut self) {
-        if self.tree.version() == self.last_view_version {
+        let version = self.tree.version();
+        if version == self.last_view_version {
             return;
         }
 
+        let expand_changes = std::mem::take(&mut self.expand_changes);
+        let incremental =
+            !expand_changes.is_empty() && version == self.last_view_version + expand_changes.len();
+
+        if !(incremental && self.apply_expand_changes(&expand_changes)) {
+            self.linear_view = self.build_linear_view();
+            self.view_index.clear();
+            self.view_index_len = 0;
+        }
+        self.last_view_version = version;
+    }
+
+    // Returns the position of a node inside the linear view.
+    //
+    // The index is updated lazily, so we only need to index the part
+    // of the view which changed since the last lookup.
+    fn view_position(&mut self, node_id: usize) -> Option<usize> {
+        let is_valid = |this: &Self, pos: usize| {
+            pos < this.view_index_len && this.linear_view.get(pos) == Some(&node_id)
+        };
+
+        if let Some(pos) = self.view_index.get(&node_id).copied() {
+            if is_valid(self, pos) {
+                return Some(pos);
+            }
+        }
+
+        if self.view_index_len >= self.linear_view.len() {
+            return None;
+        }
+
+        for pos in self.view_index_len..self.linear_view.len() {
+            self.view_index.insert(self.linear_view[pos], pos);
+        }
+        self.view_index_len = self.linear_view.len();
+
+        self.view_index
+            .get(&node_id)
+            .copied()
+            .filter(|pos| is_valid(self, *pos))
+    }
+
+    // Update the linear view after nodes were expanded or collapsed.
+    //
+    // Returns false if a full rebuild is required.
+    fn apply_expand_changes(&mut self, changes: &[usize]) -> bool {
+        let mut done = HashSet::new();
+        for node_id in changes {
+            let node_id = *node_id;
+            if !done.insert(node_id) {
+                continue;
+            }
+            if !self.view_root && Some(node_id) == self.tree.root_id {
+                return false;
+            }
+            if self.get(node_id).is_none() {
+                return false;
+            }
+
+            // nothing to do if the node is not visible
+            let pos = match self.view_position(node_id) {
+                Some(pos) => pos,
+                None => continue,
+            };
+
+            let entry = self.get(node_id).unwrap();
+
+            // descendants follow the node, and have a higher level
+            let end = self.linear_view[(pos + 1)..]
+                .iter()
+                .position(|id| self.get(*id).unwrap().level <= entry.level)
+                .map(|n| pos + 1 + n)
+                .unwrap_or(self.linear_view.len());
+
+            let mut descendants = Vec::new();
+            if entry.expanded {
+                if let Some(children) = &entry.children {
+                    self.flatten_tree_children(&mut descendants, children);
+                }
+            }
+
+            self.linear_view.splice((pos + 1)..end, descendants);
+            // positions after the node changed
+            self.view_index_len = self.view_index_len.min(pos + 1);
+        }
+        true
+    }
+
+    fn build_linear_view(&self) -> Vec<usize> {
         let mut view = Vec::new();
 
         if let Some(root_id) = self.tree.root_id {
@@ -347,8 +473,7 @@ impl<T> KeyedSlabTree<T> {
             }
         }
 
-        self.linear_view = view;
-        self.last_view_version = self.tree.version();
+        view
     }
 
     pub(crate) fn lookup_filtered_record_key(&self, cursor: usize) -> Option<Key> {
@@ -511,7 +636,10 @@ impl<T> KeyedSlabTree<T> {
                 extract_key: self.extract_key.clone(),
                 tree,
                 linear_view: Vec::new(),
+                view_index: HashMap::new(),
+                view_index_len: 0,
                 last_view_version: 0,
+                expand_changes: Vec::new(),
                 sorter: None,
                 filter: None,
                 listeners: Slab::new(),
@@ -584,6 +712,140 @@ impl<T> KeyedSlabTree<T> {
                 tree: self,
             })
     }
+
+    // Returns the node_id of the node at `path`.
+    fn find_path(&self, path: &[Key]) -> Option<usize> {
+        let root_id = self.tree.root_id?;
+        let (first, rest) = path.split_first()?;
+
+        let root = self.get(root_id)?;
+        let mut node_id = if &self.extract_key(&root.record) == first {
+            root_id
+        } else {
+            self.find_child_by_key(root_id, first)?
+        };
+
+        for key in rest {
+            node_id = self.find_child_by_key(node_id, key)?;
+        }
+        Some(node_id)
+    }
+
+    fn find_child_by_key(&self, node_id: usize, key: &Key) -> Option<usize> {
+        let children = self.get(node_id)?.children.as_ref()?;
+        children
+            .iter()
+            .find(|child_id| {
+                let entry = self.get(**child_id).unwrap();
+                &self.extract_key(&entry.record) == key
+            })
+            .copied()
+    }
+
+    /// Find a node by its key path.
+    ///
+    /// The path starts with the root key, or with the key of a root child
+    /// (useful if the root node is hidden).
+    pub fn lookup_path(&self, path: &[Key]) -> Option<KeyedSlabTreeNodeRef<'_, T>> {
+        self.find_path(path)
+            .map(|node_id| KeyedSlabTreeNodeRef::new(self, node_id))
+    }
+
+    /// Find a node by its key path (mutable).
+    pub fn lookup_path_mut(&mut self, path: &[Key]) -> Option<KeyedSlabTreeNodeMut<'_, T>> {
+        self.find_path(path)
+            .map(|node_id| KeyedSlabTreeNodeMut::new(self, node_id))
+    }
+
+    fn expand_ancestors(&mut self, node_id: usize) {
+        let mut parent_id = self.get(node_id).and_then(|entry| entry.parent_id);
+        while let Some(id) = parent_id {
+            let entry = self.get_mut(id).unwrap();
+            let changed = !entry.expanded;
+            entry.expanded = true;
+            parent_id = entry.parent_id;
+            if changed {
+                self.record_expand_change(id);
+            }
+        }
+    }
+
+    /// Expand all ancestors of the node at `path`, so that the node gets visible.
+    ///
+    /// Returns `false` if there is no such node.
+    pub fn expand_path(&mut self, path: &[Key]) -> bool {
+        match self.find_path(path) {
+            Some(node_id) => {
+                self.expand_ancestors(node_id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Expand all ancestors of the node with the specified key.
+    ///
+    /// Returns `false` if there is no such node.
+    pub fn expand_to(&mut self, key: &Key) -> bool {
+        match self.find_node_by_key(key) {
+            Some(node_id) => {
+                self.expand_ancestors(node_id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn set_expanded_by_level(&mut self, expanded: bool, filter: impl Fn(usize) -> bool) {
+        let mut changed = false;
+        for (_, entry) in self.tree.slab.iter_mut() {
+            if entry.children.is_some() && filter(entry.level) && entry.expanded != expanded {
+                entry.expanded = expanded;
+                changed = true;
+            }
+        }
+        if changed {
+            self.record_data_change();
+        }
+    }
+
+    /// Expand all nodes.
+    ///
+    /// If `max_depth` is set, only nodes with level < `max_depth` are
+    /// expanded (the root node has level 0).
+    pub fn expand_all(&mut self, max_depth: Option<usize>) {
+        self.set_expanded_by_level(true, |level| match max_depth {
+            Some(max_depth) => level < max_depth,
+            None => true,
+        });
+    }
+
+    /// Collapse all nodes.
+    ///
+    /// If `min_depth` is set, only nodes with level >= `min_depth` are
+    /// collapsed, so the upper levels keep their state.
+    pub fn collapse_all(&mut self, min_depth: Option<usize>) {
+        self.set_expanded_by_level(false, |level| level >= min_depth.unwrap_or(0));
+    }
+
+    /// Returns the keys of all visible descendants of a node.
+    ///
+    /// This uses the same order as the linear view, i.e. applies the
+    /// filter and sorter, and only includes children of expanded nodes.
+    pub fn visible_descendants(&self, key: &Key) -> Vec<Key> {
+        let mut list = Vec::new();
+        if let Some(node_id) = self.find_node_by_key(key) {
+            let entry = self.get(node_id).unwrap();
+            if entry.expanded {
+                if let Some(children) = &entry.children {
+                    self.flatten_tree_children(&mut list, children);
+                }
+            }
+        }
+        list.into_iter()
+            .map(|node_id| self.extract_key(&self.get(node_id).unwrap().record))
+            .collect()
+    }
 }
 
 /// [KeyedSlabTree] iterator over a node`s children.
@@ -603,3 +865,140 @@ pub struct KeyedSlabTreeChildrenMut<'a, T> {
 }
 
 impl_slab_tree_child_iter_mut!(KeyedSlabTreeChildrenMut, KeyedSlabTreeNodeMut);
+
+#[cfg(test)]
+mod test {
+    use yew::virtual_dom::Key;
+
+    use super::KeyedSlabTree;
+
+    fn test_tree() -> KeyedSlabTree<&'static str> {
+        let mut tree = KeyedSlabTree::with_extract_key(|s: &&'static str| Key::from(*s));
+        let mut root = tree.set_root("root");
+        let mut a = root.append("a");
+        a.append("a1").append("a1x");
+        a.append("a2");
+        root.append("b").append("b1");
+        tree
+    }
+
+    fn path(list: &[&str]) -> Vec<Key> {
+        list.iter().map(|s| Key::from(*s)).collect()
+    }
+
+    #[test]
+    fn test_lookup_path() {
+        let tree = test_tree();
+        let node = tree
+            .lookup_path(&path(&["root", "a", "a1", "a1x"]))
+            .unwrap();
+        assert_eq!(*node.record(), "a1x");
+        // root key is optional
+        let node = tree.lookup_path(&path(&["b", "b1"])).unwrap();
+        assert_eq!(*node.record(), "b1");
+
+        assert!(tree.lookup_path(&path(&["root", "b", "a1"])).is_none());
+        assert!(tree.lookup_path(&[]).is_none());
+    }
+
+    #[test]
+    fn test_expand_collapse() {
+        let mut tree = test_tree();
+        assert!(tree.expand_path(&path(&["root", "a", "a1", "a1x"])));
+        assert_eq!(
+            tree.visible_descendants(&Key::from("root")),
+            path(&["a", "a1", "a1x", "a2", "b"]),
+        );
+
+        tree.collapse_all(Some(1));
+        assert_eq!(
+            tree.visible_descendants(&Key::from("root")),
+            path(&["a", "b"])
+        );
+
+        tree.expand_all(Some(2));
+        assert_eq!(
+            tree.visible_descendants(&Key::from("root")),
+            path(&["a", "a1", "a2", "b", "b1"]),
+        );
+
+        tree.collapse_all(None);
+        assert!(tree.visible_descendants(&Key::from("root")).is_empty());
+
+        assert!(tree.expand_to(&Key::from("b1")));
+        assert_eq!(tree.visible_descendants(&Key::from("b")), path(&["b1"]));
+    }
+
+    fn large_tree() -> KeyedSlabTree<String> {
+        // 100 + 100*100 + 100*100*9 = 100100 nodes
+        let mut tree = KeyedSlabTree::with_extract_key(|s: &String| Key::from(s.clone()));
+        let mut root = tree.set_root(String::from("root"));
+        root.set_expanded(true);
+        for i in 0..100 {
+            let mut a = root.append(format!("{i}"));
+            for j in 0..100 {
+                let mut b = a.append(format!("{i}/{j}"));
+                for k in 0..9 {
+                    b.append(format!("{i}/{j}/{k}"));
+                }
+            }
+        }
+        tree
+    }
+
+    fn toggle(tree: &mut KeyedSlabTree<String>, key: &str) {
+        let mut node = tree.lookup_node_mut(&Key::from(key)).unwrap();
+        let expanded = node.expanded();
+        node.set_expanded(!expanded);
+    }
+
+    #[test]
+    fn test_incremental_view_update() {
+        let mut tree = large_tree();
+        tree.expand_all(None);
+        tree.update_filtered_data();
+        assert_eq!(tree.filtered_data_len(), 100101);
+
+        for key in ["5", "5", "7/3", "7", "7/3", "99/99", "0", "7"] {
+            toggle(&mut tree, key);
+            assert!(!tree.expand_changes.is_empty());
+            tree.update_filtered_data();
+            assert!(tree.expand_changes.is_empty());
+            assert_eq!(tree.linear_view, tree.build_linear_view());
+        }
+
+        // multiple changes between updates
+        tree.collapse_all(Some(1));
+        tree.update_filtered_data();
+        for key in ["3", "3/4", "3/5", "3/4", "42"] {
+            toggle(&mut tree, key);
+        }
+        assert!(tree.expand_to(&Key::from("8/8/8")));
+        tree.update_filtered_data();
+        assert_eq!(tree.linear_view, tree.build_linear_view());
+        assert_eq!(tree.filtered_data_len(), 1 + 100 + 100 + 9 + 100 + 100 + 9);
+    }
+
+    #[test]
+    fn test_incremental_view_update_with_filter() {
+        let mut tree = large_tree();
+        tree.set_view_root(false);
+        tree.set_filter(|s: &String| !s.ends_with('1'));
+        tree.set_sorter(|a: &String, b: &String| b.cmp(a));
+        tree.update_filtered_data();
+
+        for key in ["2", "2/2", "11", "root", "2/3", "root", "2"] {
+            toggle(&mut tree, key);
+            tree.update_filtered_data();
+            assert_eq!(tree.linear_view, tree.build_linear_view());
+        }
+
+        // mixed with other changes
+        toggle(&mut tree, "4");
+        tree.lookup_node_mut(&Key::from("4"))
+            .unwrap()
+            .append(String::from("4/x"));
+        tree.update_filtered_data();
+        assert_eq!(tree.linear_view, tree.build_linear_view());
+    }
+}
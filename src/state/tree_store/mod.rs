@@ -12,6 +12,7 @@ pub use keyed_slab_tree::{
 };
 
 mod slab_tree_serde;
+pub use slab_tree_serde::{CompactSlabTree, CompactTreeNode, SlabTreeLoader, load_compact_tree};
 
 use std::cell::{Ref, RefCell, RefMut};
 use std::ops::{Deref, DerefMut, Range};
@@ -23,7 +24,7 @@ use yew::html::IntoEventCallback;
 use yew::prelude::*;
 use yew::virtual_dom::Key;
 
-use crate::props::{ExtractKeyFn, ExtractPrimaryKey, IntoFilterFn, IntoSorterFn};
+use crate::props::{ExtractKeyFn, ExtractPrimaryKey, FilterFn, IntoFilterFn, IntoSorterFn};
 use crate::state::{DataNode, DataNodeDerefGuard, DataStore, optional_rc_ptr_eq};
 
 /// Hook to use a [TreeStore] with functional components.
@@ -176,11 +177,29 @@ impl<T: 'static> TreeStore<T> {
         self.write().set_sorter(sorter);
     }
 
+    /// Add a sorter to the sorter chain.
+    ///
+    /// The new sorter is used for nodes the existing sorters consider
+    /// equal.
+    pub fn add_sorter(&self, sorter: impl IntoSorterFn<T>) {
+        self.write().add_sorter(sorter);
+    }
+
+    /// Remove all sorters.
+    pub fn clear_sorters(&self) {
+        self.write().set_sorter(None);
+    }
+
     /// Set the filter function.
     pub fn set_filter(&self, filter: impl IntoFilterFn<T>) {
         self.write().set_filter(filter);
     }
 
+    /// Returns the filter function.
+    pub fn get_filter(&self) -> Option<FilterFn<T>> {
+        self.inner.borrow().get_filter()
+    }
+
     fn lookup_filtered_record_key(&self, cursor: usize) -> Option<Key> {
         let mut tree = self.inner.borrow_mut();
         tree.update_filtered_data();
@@ -199,6 +218,22 @@ impl<T: 'static> TreeStore<T> {
         tree.filtered_data_len()
     }
 
+    /// Returns the keys of all expanded nodes.
+    pub fn expanded_keys(&self) -> Vec<Key> {
+        match self.read().root() {
+            Some(root) => root.extract_expanded_state().into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Expand all nodes with the given keys.
+    pub fn apply_expanded_keys(&self, keys: &[Key]) {
+        let expanded_state = keys.iter().cloned().collect();
+        if let Some(mut root) = self.write().root_mut() {
+            root.apply_expanded_state(&expanded_state);
+        }
+    }
+
     pub fn filtered_data<'a>(
         &'a self,
     ) -> Box<dyn Iterator<Item = (usize, Box<dyn DataNode<T> + 'a>)> + 'a> {
@@ -275,6 +310,10 @@ impl<T: Clone + PartialEq + 'static> DataStore for TreeStore<T> {
         self.set_filter(filter);
     }
 
+    fn get_filter(&self) -> Option<FilterFn<T>> {
+        self.get_filter()
+    }
+
     fn lookup_filtered_record_key(&self, cursor: usize) -> Option<Key> {
         self.lookup_filtered_record_key(cursor)
     }
@@ -287,6 +326,18 @@ impl<T: Clone + PartialEq + 'static> DataStore for TreeStore<T> {
         self.filtered_data_len()
     }
 
+    fn is_tree(&self) -> bool {
+        true
+    }
+
+    fn expanded_keys(&self) -> Vec<Key> {
+        self.expanded_keys()
+    }
+
+    fn apply_expanded_keys(&self, keys: &[Key]) {
+        self.apply_expanded_keys(keys);
+    }
+
     fn filtered_data<'a>(
         &'a self,
     ) -> Box<dyn Iterator<Item = (usize, Box<dyn DataNode<T> + 'a>)> + 'a> {
@@ -173,7 +173,7 @@ macro_rules! impl_slab_node_mut {
         /// Set the expanded flag
         pub fn set_expanded(&mut self, expanded: bool) {
             if self.expanded() != expanded {
-                self.tree.record_data_change();
+                self.tree.record_expand_change(self.node_id);
                 let entry = self.tree.get_mut(self.node_id).unwrap();
                 entry.expanded = expanded;
             }
@@ -369,6 +369,10 @@ impl<T> SlabTree<T> {
         self.version += 1;
     }
 
+    pub(crate) fn record_expand_change(&mut self, _node_id: usize) {
+        self.record_data_change();
+    }
+
     /// Tree version number (incread by any modification).
     pub fn version(&self) -> usize {
         self.version
@@ -1,8 +1,12 @@
 use std::borrow::Cow;
 
+use anyhow::bail;
+use serde_json::Value;
 use slab::Slab;
 
-use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use yew::html::IntoEventCallback;
+
+use serde::de::{DeserializeOwned, DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
 use serde::ser::{SerializeSeq, SerializeStruct};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -234,3 +238,272 @@ impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for TreeNodeVisitor<'_, T> {
         deserializer.deserialize_struct("TreeNode", KNOWN_FIELDS, self)
     }
 }
+
+/// Compact tree node representation.
+///
+/// The compact format is a flat list of nodes in pre-order (depth first),
+/// where each node is serialized as `[level, flags, record]`. Flag bit 0
+/// marks expanded nodes, bit 1 marks nodes with a children list (non-leaf
+/// nodes). This format is much smaller than the nested format for large
+/// trees, and it can be deserialized incrementally (see [SlabTreeLoader]).
+pub struct CompactTreeNode<T> {
+    /// Node level (the root node has level 0).
+    pub level: usize,
+    /// Node is expanded.
+    pub expanded: bool,
+    /// Node has no children list.
+    pub leaf: bool,
+    /// The node data.
+    pub record: T,
+}
+
+const COMPACT_FLAG_EXPANDED: u8 = 1;
+const COMPACT_FLAG_CHILDREN: u8 = 2;
+
+impl<T: Serialize> Serialize for CompactTreeNode<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        compact_node_flags(self.expanded, self.leaf, self.level, &self.record).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for CompactTreeNode<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (level, flags, record): (usize, u8, T) = Deserialize::deserialize(deserializer)?;
+        Ok(CompactTreeNode {
+            level,
+            expanded: flags & COMPACT_FLAG_EXPANDED != 0,
+            leaf: flags & COMPACT_FLAG_CHILDREN == 0,
+            record,
+        })
+    }
+}
+
+fn compact_node_flags<T>(expanded: bool, leaf: bool, level: usize, record: &T) -> (usize, u8, &T) {
+    let mut flags = 0;
+    if expanded {
+        flags |= COMPACT_FLAG_EXPANDED;
+    }
+    if !leaf {
+        flags |= COMPACT_FLAG_CHILDREN;
+    }
+    (level, flags, record)
+}
+
+/// Serialize a [SlabTree] using the compact format (see [CompactTreeNode]).
+///
+/// Returned by [SlabTree::compact] and [KeyedSlabTree::compact].
+pub struct CompactSlabTree<'a, T> {
+    tree: &'a SlabTree<T>,
+}
+
+impl<T: Serialize> Serialize for CompactSlabTree<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut list = Vec::new();
+        let mut stack: Vec<usize> = self.tree.root_id.into_iter().collect();
+        while let Some(node_id) = stack.pop() {
+            list.push(node_id);
+            let entry = self.tree.get(node_id).unwrap();
+            if let Some(children) = &entry.children {
+                stack.extend(children.iter().rev());
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(Some(list.len()))?;
+        for node_id in list {
+            let entry = self.tree.get(node_id).unwrap();
+            seq.serialize_element(&compact_node_flags(
+                entry.expanded,
+                entry.children.is_none(),
+                entry.level,
+                &entry.record,
+            ))?;
+        }
+        seq.end()
+    }
+}
+
+impl<T> SlabTree<T> {
+    /// Returns a wrapper to serialize the tree using the compact format.
+    pub fn compact(&self) -> CompactSlabTree<'_, T> {
+        CompactSlabTree { tree: self }
+    }
+}
+
+impl<T> KeyedSlabTree<T> {
+    /// Returns a wrapper to serialize the tree using the compact format.
+    pub fn compact(&self) -> CompactSlabTree<'_, T> {
+        CompactSlabTree { tree: &self.tree }
+    }
+}
+
+/// Incremental [SlabTree] builder.
+///
+/// Builds a tree from a list of nodes in the compact format (see
+/// [CompactTreeNode]). Nodes can be fed in batches, so that huge trees
+/// can be loaded without blocking the UI (see [load_compact_tree]).
+pub struct SlabTreeLoader<T> {
+    tree: SlabTree<T>,
+    // node ids of the current node and its ancestors
+    stack: Vec<usize>,
+}
+
+impl<T> Default for SlabTreeLoader<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlabTreeLoader<T> {
+    /// Create a new loader (starts with an empty tree).
+    pub fn new() -> Self {
+        Self {
+            tree: SlabTree::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns the number of nodes loaded so far.
+    pub fn node_count(&self) -> usize {
+        self.tree.slab.len()
+    }
+
+    /// Append the next node (nodes must be pushed in pre-order).
+    pub fn push(&mut self, node: CompactTreeNode<T>) -> Result<(), anyhow::Error> {
+        if node.level == 0 {
+            if self.tree.root_id.is_some() {
+                bail!("tree loader: got multiple root nodes");
+            }
+        } else if node.level > self.stack.len() {
+            bail!("tree loader: unexpected node level {}", node.level);
+        }
+
+        self.stack.truncate(node.level);
+        let parent_id = self.stack.last().copied();
+
+        if let Some(parent_id) = parent_id {
+            if self.tree.get(parent_id).unwrap().children.is_none() {
+                bail!("tree loader: got child of leaf node");
+            }
+        }
+
+        let node_id = self.tree.insert_record(node.record, parent_id);
+        let entry = self.tree.get_mut(node_id).unwrap();
+        entry.expanded = node.expanded;
+        if !node.leaf {
+            entry.children = Some(Vec::new());
+        }
+
+        match parent_id {
+            Some(parent_id) => {
+                let parent = self.tree.get_mut(parent_id).unwrap();
+                parent.children.as_mut().unwrap().push(node_id);
+            }
+            None => self.tree.root_id = Some(node_id),
+        }
+
+        self.stack.push(node_id);
+
+        Ok(())
+    }
+
+    /// Append a batch of nodes from a JSON array (compact format).
+    ///
+    /// Returns the number of nodes added.
+    pub fn feed(&mut self, nodes: Value) -> Result<usize, anyhow::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let nodes: Vec<CompactTreeNode<T>> = serde_json::from_value(nodes)?;
+        let count = nodes.len();
+        for node in nodes {
+            self.push(node)?;
+        }
+        Ok(count)
+    }
+
+    /// Returns the loaded tree.
+    pub fn finish(self) -> SlabTree<T> {
+        self.tree
+    }
+}
+
+/// Load a tree from a list of nodes (compact format) without blocking the UI.
+///
+/// Nodes are deserialized in batches of `batch_size`, yielding to the
+/// event loop between batches. `on_progress` is called after each batch with
+/// the number of loaded nodes and the total number of nodes.
+pub async fn load_compact_tree<T: DeserializeOwned>(
+    nodes: Vec<Value>,
+    batch_size: usize,
+    on_progress: impl IntoEventCallback<(usize, usize)>,
+) -> Result<SlabTree<T>, anyhow::Error> {
+    let on_progress = on_progress.into_event_callback();
+    let total = nodes.len();
+    let mut loader = SlabTreeLoader::new();
+
+    let mut nodes = nodes.into_iter();
+    loop {
+        let batch: Vec<Value> = nodes.by_ref().take(batch_size.max(1)).collect();
+        if batch.is_empty() {
+            break;
+        }
+        loader.feed(Value::Array(batch))?;
+        if let Some(on_progress) = &on_progress {
+            on_progress.emit((loader.node_count(), total));
+        }
+        // Note: use a (macro) task, so that the browser can render and handle events
+        gloo_timers::future::TimeoutFuture::new(0).await;
+    }
+
+    Ok(loader.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_compact_roundtrip() {
+        let mut tree = SlabTree::new();
+        let mut root = tree.set_root(0);
+        root.set_expanded(true);
+        let mut child = root.append(1);
+        child.append(2);
+        root.append(3).set_leaf_node(false);
+        root.append(4);
+
+        let data = serde_json::to_value(tree.compact()).unwrap();
+        assert_eq!(
+            data,
+            json!([[0, 3, 0], [1, 2, 1], [2, 0, 2], [1, 2, 3], [1, 0, 4]])
+        );
+
+        let mut loader = SlabTreeLoader::<usize>::new();
+        loader.feed(json!([[0, 3, 0], [1, 2, 1]])).unwrap();
+        loader
+            .feed(json!([[2, 0, 2], [1, 2, 3], [1, 0, 4]]))
+            .unwrap();
+        assert_eq!(loader.node_count(), 5);
+
+        let loaded = loader.finish();
+        assert_eq!(serde_json::to_value(loaded.compact()).unwrap(), data);
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&tree).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_loader_errors() {
+        let mut loader = SlabTreeLoader::<usize>::new();
+        assert!(loader.feed(json!([[1, 0, 1]])).is_err());
+
+        let mut loader = SlabTreeLoader::<usize>::new();
+        assert!(loader.feed(json!([[0, 0, 0], [1, 0, 1]])).is_err());
+
+        let mut loader = SlabTreeLoader::<usize>::new();
+        assert!(loader.feed(json!([[0, 2, 0], [0, 0, 1]])).is_err());
+    }
+}
@@ -3,6 +3,7 @@ use std::rc::Rc;
 use yew::html::IntoPropValue;
 use yew::virtual_dom::{Key, VComp, VNode};
 
+use crate::css::Layer;
 use crate::prelude::*;
 use crate::widget::{ActionIcon, Column, Row};
 
@@ -156,7 +157,7 @@ impl Component for PwtApplicationBar {
             .with_child(actions);
 
         Column::new()
-            .style("z-index", "1") // make shadow (if any) visible
+            .style("z-index", Layer::Sticky) // make shadow (if any) visible
             .attribute("role", "banner")
             .class("pwt-application-bar")
             .with_child(row1)
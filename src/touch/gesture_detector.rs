@@ -10,6 +10,7 @@ use yew::html::IntoEventCallback;
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
+use crate::dom::set_pointer_capture;
 use crate::impl_to_html;
 use crate::props::{
     ContainerBuilder, EventSubscriber, IntoVTag, WidgetBuilder, WidgetStyleBuilder,
@@ -489,12 +490,6 @@ impl PwtGestureDetector {
         self.pointers.remove(&id)
     }
 
-    fn capture_pointer(&self, pointer_id: i32) {
-        if let Some(el) = self.node_ref.cast::<web_sys::Element>() {
-            let _ = el.set_pointer_capture(pointer_id);
-        }
-    }
-
     fn get_pinch_points(&self) -> (PinchPoint, PinchPoint) {
         let mut points: Vec<_> = self
             .pointers
@@ -606,7 +601,7 @@ impl PwtGestureDetector {
                     if distance < props.tap_tolerance {
                         //log::info!("LONG PRESS");
                         // supress further (click) events on children
-                        self.capture_pointer(id);
+                        set_pointer_capture(&self.node_ref, id);
 
                         self.state = DetectionState::Done;
                         if let Some(on_long_press) = &props.on_long_press {
@@ -693,7 +688,7 @@ impl PwtGestureDetector {
                     if distance >= props.tap_tolerance {
                         //log::info!("DRAG START {} {}", event.x(), event.y());
                         self.state = DetectionState::Drag;
-                        self.capture_pointer(event.pointer_id());
+                        set_pointer_capture(&self.node_ref, event.pointer_id());
                         if let Some(on_drag) = &props.on_drag {
                             on_drag.emit(GestureDragEvent::new(event.into(), GesturePhase::Start));
                         }
@@ -1123,7 +1118,7 @@ impl Component for PwtGestureDetector {
         let props = ctx.props();
 
         let mut container = Container::new()
-            .class("pwt-d-contents")
+            .class(crate::css::Display::Contents)
             .style("touch-action", "none")
             .with_child(props.content.clone());
 
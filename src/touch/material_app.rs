@@ -13,9 +13,11 @@ use pwt_macros::builder;
 
 use crate::prelude::*;
 use crate::props::{IntoOptionalRenderFn, RenderFn};
-use crate::state::{NavigationContainer, SharedState, SharedStateObserver};
+use crate::state::{AppInfo, NavigationContainer, SharedState, SharedStateObserver};
 use crate::touch::{PageAnimationStyle, SnackBarController, SnackBarManager};
-use crate::widget::{CatalogLoader, Container, ThemeLoader};
+use crate::widget::{
+    CatalogLoader, Container, ThemeLoader, WidgetInspector, inspector_enabled, set_document_title,
+};
 
 use super::{PageStack, SideDialog, SideDialogController, SideDialogLocation};
 
@@ -132,6 +134,8 @@ impl PageController {
 /// - Provides a [SnackBarController], and display snackbars using [SnackBarManager]
 /// - Uses [PageStack] to dislay/animate overlapping pages.
 /// - Provides a [PageController] to navigate and control the [PageStack].
+/// - Provides the [AppInfo] (if set) using a [yew::ContextProvider].
+/// - Shows the [WidgetInspector] (if [enabled](inspector_enabled)).
 ///
 /// You need to provide a simple rendering function that translates routes into HTML pages.
 /// More specifically, each route can reeturn a stack of pages.
@@ -229,6 +233,13 @@ pub struct MaterialApp {
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or(AttrValue::Static("en"))]
     pub default_lang: AttrValue,
+
+    /// Application metadata, provided as [AppInfo] context.
+    ///
+    /// The product name is also used as document title.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub app_info: Option<AppInfo>,
 }
 
 impl MaterialApp {
@@ -344,6 +355,8 @@ impl Component for PwtMaterialApp {
 
         let page_stack = Vec::new();
 
+        set_document_title(props.app_info.as_ref());
+
         let mut me = Self {
             snackbar_controller,
             page_controller,
@@ -379,6 +392,14 @@ impl Component for PwtMaterialApp {
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.app_info != old_props.app_info {
+            set_document_title(props.app_info.as_ref());
+        }
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
@@ -407,12 +428,19 @@ impl Component for PwtMaterialApp {
         let body = CatalogLoader::new(body)
             .default_lang(props.default_lang.clone())
             .url_builder(props.catalog_url_builder.clone());
+        let body = match &props.app_info {
+            Some(app_info) => html! {
+                <ContextProvider<AppInfo> context={app_info.clone()}>{body}</ContextProvider<AppInfo>>
+            },
+            None => body.into(),
+        };
 
         html! {
             <Router history={self.history.clone()} basename={props.basename.clone()}>
                 <ContextProvider<SnackBarController> context={self.snackbar_controller.clone()}>
                     <ContextProvider<PageController> context={self.page_controller.clone()}>
                     { body }
+                    { for inspector_enabled().then(WidgetInspector::new) }
                     </ContextProvider<PageController>>
                 </ContextProvider<SnackBarController>>
             </Router>
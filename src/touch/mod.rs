@@ -26,13 +26,13 @@ pub use material_app::PageController;
 pub use material_app::{MaterialApp, MaterialAppScopeExt, PwtMaterialApp};
 
 mod navigation_bar;
-pub use navigation_bar::NavigationBar;
+pub use navigation_bar::{NavigationBar, NavigationLabels};
 
 mod navigation_rail;
 pub use navigation_rail::NavigationRail;
 
 mod page_view;
-pub use page_view::{PageView, PwtPageView};
+pub use page_view::{PageIndicator, PageScrollEvent, PageView, PwtPageIndicator, PwtPageView};
 
 mod page_stack;
 pub use page_stack::{PageAnimationStyle, PageStack, PwtPageStack};
@@ -41,12 +41,12 @@ mod side_dialog;
 pub use side_dialog::{PwtSideDialog, SideDialog, SideDialogController, SideDialogLocation};
 
 mod scaffold;
-pub use scaffold::{PwtScaffold, Scaffold};
+pub use scaffold::{PwtScaffold, Scaffold, ScaffoldScrollContext};
 
 mod slidable;
 pub use slidable::{
-    PwtSlidable, PwtSlidableAction, Slidable, SlidableAction, SlidableActionMouseEvent,
-    SlidableController,
+    PwtSlidable, PwtSlidableAction, PwtSlidableList, Slidable, SlidableAction,
+    SlidableActionMouseEvent, SlidableController, SlidableList,
 };
 
 mod snack_bar;
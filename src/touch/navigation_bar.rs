@@ -16,6 +16,30 @@ use crate::widget::TabBarItem;
 
 use pwt_macros::builder;
 
+use super::ScaffoldScrollContext;
+
+/// Label visibility policy for [NavigationBar] and [NavigationRail](super::NavigationRail).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum NavigationLabels {
+    /// Always show labels.
+    #[default]
+    Show,
+    /// Never show labels (icons only).
+    Hide,
+    /// Only show the label of the selected item.
+    SelectedOnly,
+}
+
+impl NavigationLabels {
+    pub(crate) fn visible(&self, is_active: bool) -> bool {
+        match self {
+            NavigationLabels::Show => true,
+            NavigationLabels::Hide => false,
+            NavigationLabels::SelectedOnly => is_active,
+        }
+    }
+}
+
 /// Navigation bar (bottom)
 ///
 /// # Automatic routing.
@@ -65,6 +89,26 @@ pub struct NavigationBar {
     #[builder]
     #[prop_or_default]
     router: bool,
+
+    /// Label visibility policy.
+    #[builder]
+    #[prop_or_default]
+    pub labels: NavigationLabels,
+
+    /// Floating action button, placed in a slot at the center of the bar.
+    #[prop_or_default]
+    pub fab: Option<VNode>,
+
+    /// Hide the bar when the user scrolls down, and show it again on scroll up.
+    ///
+    /// The hidden state is indicated with the `pwt-navigation-bar-hidden`
+    /// class, which slides the bar out (using a transform transition).
+    ///
+    /// This requires a parent [Scaffold](super::Scaffold), which provides the
+    /// scroll state.
+    #[builder]
+    #[prop_or_default]
+    pub auto_hide: bool,
 }
 
 impl NavigationBar {
@@ -98,6 +142,17 @@ impl NavigationBar {
         self.default_active = default_active.into_optional_key();
     }
 
+    /// Builder style method to set the floating action button.
+    pub fn fab(mut self, fab: impl Into<VNode>) -> Self {
+        self.set_fab(fab);
+        self
+    }
+
+    /// Method to set the floating action button.
+    pub fn set_fab(&mut self, fab: impl Into<VNode>) {
+        self.fab = Some(fab.into());
+    }
+
     fn get_default_active(&self) -> Option<Key> {
         if self.default_active.is_some() {
             return self.default_active.clone();
@@ -116,6 +171,7 @@ impl NavigationBar {
 pub enum Msg {
     Select(Option<Key>, bool),
     SelectionChange(Selection),
+    ScrollContext(ScaffoldScrollContext),
 }
 
 #[doc(hidden)]
@@ -123,6 +179,8 @@ pub struct PwtNavigationBar {
     active: Option<Key>,
     selection: Selection,
     _nav_ctx_handle: Option<ContextHandle<NavigationContext>>,
+    scroll_context: ScaffoldScrollContext,
+    _scroll_ctx_handle: Option<ContextHandle<ScaffoldScrollContext>>,
 }
 
 fn get_active_or_default(props: &NavigationBar, active: &Option<Key>) -> Option<Key> {
@@ -195,10 +253,20 @@ impl Component for PwtNavigationBar {
             on_select.emit(active.clone());
         }
 
+        let (scroll_context, _scroll_ctx_handle) = match ctx
+            .link()
+            .context::<ScaffoldScrollContext>(ctx.link().callback(Msg::ScrollContext))
+        {
+            Some((scroll_context, handle)) => (scroll_context, Some(handle)),
+            None => (ScaffoldScrollContext::default(), None),
+        };
+
         Self {
             selection,
             active,
             _nav_ctx_handle,
+            scroll_context,
+            _scroll_ctx_handle,
         }
     }
 
@@ -256,6 +324,10 @@ impl Component for PwtNavigationBar {
 
                 true
             }
+            Msg::ScrollContext(scroll_context) => {
+                self.scroll_context = scroll_context;
+                props.auto_hide
+            }
         }
     }
 
@@ -295,11 +367,15 @@ impl Component for PwtNavigationBar {
                         "pwt-navigation-bar-icon-container",
                         is_active.then_some("active"),
                     );
-                    Some(html! {<div {class}><i class={icon_class}/></div>})
+                    let badge = item.badge.as_ref().map(|badge| {
+                        html! {<div class="pwt-navigation-bar-badge">{badge.clone()}</div>}
+                    });
+                    Some(html! {<div {class}><i class={icon_class}/>{badge}</div>})
                 }
                 None => None,
             };
-            let label = item.label.as_ref().map(|label| {
+            let label_visible = props.labels.visible(is_active);
+            let label = item.label.as_ref().filter(|_| label_visible).map(|label| {
                 html! {
                     <div class="pwt-navigation-bar-label">{label}</div>
                 }
@@ -307,6 +383,8 @@ impl Component for PwtNavigationBar {
 
             Container::new()
                 .class("pwt-navigation-bar-item")
+                // keep the label accessible if it is not shown
+                .attribute("aria-label", item.label.clone().filter(|_| !label_visible))
                 .with_optional_child(icon)
                 .with_optional_child(label)
                 .onclick(ctx.link().callback({
@@ -321,10 +399,24 @@ impl Component for PwtNavigationBar {
                 }))
                 .into()
         });
+        let mut children: Vec<Html> = children.collect();
+
+        if let Some(fab) = &props.fab {
+            let fab_slot = Container::new()
+                .class("pwt-navigation-bar-fab-slot")
+                .with_child(fab.clone());
+            children.insert(children.len().div_ceil(2), fab_slot.into());
+        }
+
+        let hidden = props.auto_hide && self.scroll_context.scrolled_down;
 
         Container::new()
             .class("pwt-navigation-bar")
+            .class(props.fab.is_some().then_some("pwt-navigation-bar-with-fab"))
+            .class(hidden.then_some("pwt-navigation-bar-hidden"))
             .class(props.class.clone())
+            .attribute("aria-hidden", hidden.then_some("true"))
+            .attribute("inert", hidden.then_some(""))
             .children(children)
             .into()
     }
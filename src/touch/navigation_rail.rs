@@ -15,6 +15,8 @@ use crate::widget::TabBarItem;
 
 use pwt_macros::builder;
 
+use super::NavigationLabels;
+
 /// Navigation rail
 ///
 /// # Automatic routing.
@@ -64,6 +66,11 @@ pub struct NavigationRail {
     #[builder]
     #[prop_or_default]
     router: bool,
+
+    /// Label visibility policy.
+    #[builder]
+    #[prop_or_default]
+    pub labels: NavigationLabels,
 }
 
 impl NavigationRail {
@@ -299,11 +306,15 @@ impl Component for PwtNavigationRail {
                         "pwt-navigation-rail-icon-container",
                         is_active.then_some("active"),
                     );
-                    Some(html! {<div {class}><i class={icon_class}/></div>})
+                    let badge = item.badge.as_ref().map(|badge| {
+                        html! {<div class="pwt-navigation-rail-badge">{badge.clone()}</div>}
+                    });
+                    Some(html! {<div {class}><i class={icon_class}/>{badge}</div>})
                 }
                 None => None,
             };
-            let label = item.label.as_ref().map(|label| {
+            let label_visible = props.labels.visible(is_active);
+            let label = item.label.as_ref().filter(|_| label_visible).map(|label| {
                 html! {
                     <div class="pwt-navigation-rail-label">{label}</div>
                 }
@@ -311,6 +322,7 @@ impl Component for PwtNavigationRail {
 
             Container::new()
                 .class("pwt-navigation-rail-item")
+                .attribute("aria-label", item.label.clone().filter(|_| !label_visible))
                 .with_optional_child(icon)
                 .with_optional_child(label)
                 .onclick(ctx.link().callback({
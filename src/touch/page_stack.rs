@@ -216,8 +216,8 @@ impl Component for PwtPageStack {
         }
 
         Container::new()
-            .class("pwt-position-relative")
-            .class("pwt-overflow-hidden")
+            .class(crate::css::Position::Relative)
+            .class(crate::css::Overflow::Hidden)
             .class("pwt-page-outer")
             .width(CssLength::Fraction(1.0))
             .height(CssLength::Fraction(1.0))
@@ -5,13 +5,35 @@ use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
 use crate::impl_to_html;
-use crate::props::{ContainerBuilder, CssLength, WidgetBuilder, WidgetStyleBuilder};
+use crate::props::{
+    ContainerBuilder, CssLength, EventSubscriber, WidgetBuilder, WidgetStyleBuilder,
+};
 use crate::touch::GestureDetector;
-use crate::widget::Container;
+use crate::widget::{Container, Row};
 
-use super::GestureSwipeEvent;
+use super::{GestureDragEvent, GesturePhase, GestureSwipeEvent};
+
+/// Page scroll event, emitted while the user drags a [PageView].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageScrollEvent {
+    /// The currently active page.
+    pub page: usize,
+    /// The (fractional) scroll position, i.e. `1.5` means half way
+    /// between page 1 and page 2.
+    pub position: f64,
+}
+
+impl PageScrollEvent {
+    /// Returns the scroll progress relative to the active page (from -1.0 to 1.0).
+    pub fn progress(&self) -> f64 {
+        self.position - self.page as f64
+    }
+}
 
 /// A scrollable list that works page by page.
+///
+/// Only the active page and its neighbors (see [PageView::overscan]) are
+/// mounted. Pages are destroyed when they move out of that range.
 #[derive(Properties, Clone, PartialEq)]
 pub struct PageView {
     /// The yew component key.
@@ -25,9 +47,21 @@ pub struct PageView {
     #[prop_or(0)]
     pub view_page: usize,
 
+    /// Number of pages mounted on each side of the active page (default 1).
+    #[prop_or(1)]
+    pub overscan: usize,
+
+    /// Show a page indicator (dots) at the bottom.
+    #[prop_or_default]
+    pub show_indicator: bool,
+
     /// This callback is called when the user swipes to the next/previous page.
     #[prop_or_default]
     pub on_page_change: Option<Callback<usize>>,
+
+    /// This callback is called when the scroll position changes (while dragging).
+    #[prop_or_default]
+    pub on_page_scroll: Option<Callback<PageScrollEvent>>,
 }
 
 impl Default for PageView {
@@ -53,11 +87,39 @@ impl PageView {
         self.view_page = page_num;
     }
 
+    /// Builder style method to set the number of pages mounted on each side of the active page.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.set_overscan(overscan);
+        self
+    }
+
+    /// Method to set the number of pages mounted on each side of the active page.
+    pub fn set_overscan(&mut self, overscan: usize) {
+        self.overscan = overscan;
+    }
+
+    /// Builder style method to show the page indicator.
+    pub fn show_indicator(mut self, show_indicator: bool) -> Self {
+        self.set_show_indicator(show_indicator);
+        self
+    }
+
+    /// Method to show the page indicator.
+    pub fn set_show_indicator(&mut self, show_indicator: bool) {
+        self.show_indicator = show_indicator;
+    }
+
     /// Builder style method to set the `on_page_change` callback.
     pub fn on_page_change(mut self, cb: impl IntoEventCallback<usize>) -> Self {
         self.on_page_change = cb.into_event_callback();
         self
     }
+
+    /// Builder style method to set the `on_page_scroll` callback.
+    pub fn on_page_scroll(mut self, cb: impl IntoEventCallback<PageScrollEvent>) -> Self {
+        self.on_page_scroll = cb.into_event_callback();
+        self
+    }
 }
 
 impl ContainerBuilder for PageView {
@@ -67,11 +129,38 @@ impl ContainerBuilder for PageView {
 }
 
 #[doc(hidden)]
-pub struct PwtPageView {}
+pub struct PwtPageView {
+    node_ref: NodeRef,
+    drag_start: Option<(i32, f64)>, // start x and page width
+    drag_offset: f64,
+    page_change_sent: bool,
+}
 
 pub enum Msg {
     NextLeft,
     NextRight,
+    Jump(usize),
+    Drag(GestureDragEvent),
+}
+
+impl PwtPageView {
+    fn change_page(&mut self, props: &PageView, page: usize) {
+        if page == props.view_page || page >= props.children.len() {
+            return;
+        }
+        if let Some(on_page_change) = &props.on_page_change {
+            on_page_change.emit(page);
+        }
+    }
+
+    fn emit_scroll(&self, props: &PageView, position: f64) {
+        if let Some(on_page_scroll) = &props.on_page_scroll {
+            on_page_scroll.emit(PageScrollEvent {
+                page: props.view_page,
+                position,
+            });
+        }
+    }
 }
 
 impl Component for PwtPageView {
@@ -79,63 +168,134 @@ impl Component for PwtPageView {
     type Properties = PageView;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+        Self {
+            node_ref: NodeRef::default(),
+            drag_start: None,
+            drag_offset: 0.0,
+            page_change_sent: false,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         let props = ctx.props();
         match msg {
             Msg::NextLeft => {
-                if props.view_page > 0 {
-                    if let Some(on_page_change) = &props.on_page_change {
-                        on_page_change.emit(props.view_page - 1)
-                    }
+                // swipe and drag end can both trigger a page change
+                if props.view_page > 0 && !self.page_change_sent {
+                    self.page_change_sent = true;
+                    self.change_page(props, props.view_page - 1);
                 }
                 false
             }
             Msg::NextRight => {
-                if props.view_page < (props.children.len() - 1) {
-                    if let Some(on_page_change) = &props.on_page_change {
-                        on_page_change.emit(props.view_page + 1)
-                    }
+                if !self.page_change_sent {
+                    self.page_change_sent = true;
+                    self.change_page(props, props.view_page + 1);
                 }
                 false
             }
+            Msg::Jump(page) => {
+                self.change_page(props, page);
+                false
+            }
+            Msg::Drag(event) => match event.phase {
+                GesturePhase::Start => {
+                    let width = self
+                        .node_ref
+                        .cast::<web_sys::Element>()
+                        .map(|el| el.client_width() as f64)
+                        .unwrap_or(0.0);
+                    self.drag_start = Some((event.x(), width));
+                    self.drag_offset = 0.0;
+                    self.page_change_sent = false;
+                    true
+                }
+                GesturePhase::Update => {
+                    let (start_x, width) = match self.drag_start {
+                        Some(start) => start,
+                        None => return false,
+                    };
+                    let last_page = props.children.len().saturating_sub(1) as f64;
+                    let page = props.view_page as f64;
+                    let mut offset = (event.x() - start_x) as f64;
+                    if width > 0.0 {
+                        // do not allow to drag beyond the first/last page
+                        offset = offset.clamp((page - last_page) * width, page * width);
+                        self.emit_scroll(props, page - offset / width);
+                    }
+                    self.drag_offset = offset;
+                    true
+                }
+                GesturePhase::End => {
+                    if let Some((_, width)) = self.drag_start {
+                        if width > 0.0 && self.drag_offset.abs() > width / 2.0 {
+                            if self.drag_offset > 0.0 {
+                                ctx.link().send_message(Msg::NextLeft);
+                            } else {
+                                ctx.link().send_message(Msg::NextRight);
+                            }
+                        }
+                    }
+                    self.drag_start = None;
+                    self.drag_offset = 0.0;
+                    self.emit_scroll(props, props.view_page as f64);
+                    true
+                }
+            },
         }
     }
 
+    fn changed(&mut self, _ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        // the parent handled (or ignored) our page change request
+        self.page_change_sent = false;
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
+        let dragging = self.drag_start.is_some();
+
         let pages: Vec<Html> = props
             .children
             .iter()
             .enumerate()
+            .filter(|(i, _)| i.abs_diff(props.view_page) <= props.overscan)
             .map(|(i, child)| {
-                let pos = i.cmp(&props.view_page) as i8;
+                let pos = i as i64 - props.view_page as i64;
 
                 Container::new()
+                    .key(Key::from(i))
                     .width(CssLength::Fraction(1.0))
                     .height(CssLength::Fraction(1.0))
                     .style("position", "absolute")
                     .style("top", "0")
-                    .style("transition", "all ease 0.5s")
-                    .style("left", format!("calc({pos}*100%)"))
+                    .style("transition", (!dragging).then_some("all ease 0.5s"))
+                    .style("left", format!("calc({pos}*100% + {}px)", self.drag_offset))
                     .with_child(child.clone())
                     .into()
             })
             .collect();
 
+        let indicator = props.show_indicator.then(|| {
+            PageIndicator::new(props.children.len(), props.view_page)
+                .on_select(ctx.link().callback(Msg::Jump))
+        });
+
         GestureDetector::new(
             Container::new()
-                .class("pwt-position-relative")
-                .class("pwt-overflow-hidden")
+                .class(crate::css::Position::Relative)
+                .class(crate::css::Overflow::Hidden)
                 .width(CssLength::Fraction(1.0))
                 .height(CssLength::Fraction(1.0))
-                .children(pages),
+                .children(pages)
+                .with_optional_child(indicator)
+                .into_html_with_ref(self.node_ref.clone()),
         )
+        .on_drag(ctx.link().callback(Msg::Drag))
         .on_swipe({
             let link = ctx.link().clone();
+            // Note: page_change_sent is reset with the next drag start
             move |event: GestureSwipeEvent| {
                 if event.direction.abs() < 45.0 {
                     link.send_message(Msg::NextLeft)
@@ -157,3 +317,94 @@ impl From<PageView> for VNode {
 }
 
 impl_to_html!(PageView);
+
+/// Page indicator (dots), used by [PageView].
+///
+/// Tapping a dot emits the `on_select` callback with the page number. The
+/// dots are styled by the theme (`pwt-page-indicator-dot` class).
+#[derive(Properties, Clone, PartialEq)]
+pub struct PageIndicator {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// Number of pages.
+    pub page_count: usize,
+
+    /// The active page.
+    pub active_page: usize,
+
+    /// This callback is called when the user taps a dot.
+    #[prop_or_default]
+    pub on_select: Option<Callback<usize>>,
+}
+
+impl PageIndicator {
+    /// Creates a new instance.
+    pub fn new(page_count: usize, active_page: usize) -> Self {
+        yew::props!(Self {
+            page_count,
+            active_page
+        })
+    }
+
+    /// Builder style method to set the `on_select` callback.
+    pub fn on_select(mut self, cb: impl IntoEventCallback<usize>) -> Self {
+        self.on_select = cb.into_event_callback();
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct PwtPageIndicator {}
+
+impl Component for PwtPageIndicator {
+    type Message = ();
+    type Properties = PageIndicator;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {}
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let dots = (0..props.page_count).map(|page| {
+            let active = page == props.active_page;
+            Container::from_tag("span")
+                .key(Key::from(page))
+                .class("pwt-page-indicator-dot")
+                .class(active.then_some("active"))
+                .attribute("role", "tab")
+                .attribute("aria-selected", active.then_some("true"))
+                .attribute("aria-label", format!("{}", page + 1))
+                .onclick({
+                    let on_select = props.on_select.clone();
+                    move |event: MouseEvent| {
+                        event.stop_propagation();
+                        if let Some(on_select) = &on_select {
+                            on_select.emit(page);
+                        }
+                    }
+                })
+        });
+
+        Row::new()
+            .class("pwt-page-indicator")
+            .class(crate::css::Position::Absolute)
+            .class(crate::css::JustifyContent::Center)
+            .gap(1)
+            .attribute("role", "tablist")
+            .children(dots)
+            .into()
+    }
+}
+
+impl From<PageIndicator> for VNode {
+    fn from(val: PageIndicator) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtPageIndicator>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
+
+impl_to_html!(PageIndicator);
@@ -1,5 +1,7 @@
 use std::rc::Rc;
 
+use gloo_events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
 use yew::html::IntoPropValue;
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
@@ -12,7 +14,23 @@ use super::{NavigationBar, NavigationRail};
 
 use pwt_macros::builder;
 
+/// Scroll state provided by [Scaffold] using a [yew::ContextProvider].
+///
+/// Used by [NavigationBar::auto_hide] to hide the navigation bar when the
+/// user scrolls the body down, and show it again on scroll up.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScaffoldScrollContext {
+    /// Set when the last body scroll movement was downwards.
+    pub scrolled_down: bool,
+}
+
+// Ignore small scroll movements.
+const SCROLL_THRESHOLD: i32 = 8;
+
 /// Implements the basic Material Design visual page layout structure.
+///
+/// The scaffold tracks scrolling inside the body, and provides a
+/// [ScaffoldScrollContext] to its children.
 #[derive(Properties, Clone, PartialEq)]
 #[builder]
 pub struct Scaffold {
@@ -92,15 +110,80 @@ impl Scaffold {
     }
 }
 
+pub enum Msg {
+    Scroll(web_sys::Element),
+}
+
 #[doc(hidden)]
-pub struct PwtScaffold {}
+pub struct PwtScaffold {
+    body_ref: NodeRef,
+    // last scroll target and its scroll offset
+    last_scroll: Option<(web_sys::Element, i32)>,
+    scroll_context: ScaffoldScrollContext,
+    _scroll_listener: Option<EventListener>,
+}
 
 impl Component for PwtScaffold {
-    type Message = ();
+    type Message = Msg;
     type Properties = Scaffold;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+        Self {
+            body_ref: NodeRef::default(),
+            last_scroll: None,
+            scroll_context: ScaffoldScrollContext::default(),
+            _scroll_listener: None,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Scroll(target) => {
+                let top = target.scroll_top();
+                let diff = match &self.last_scroll {
+                    Some((last_target, last_top)) if *last_target == target => top - last_top,
+                    _ => {
+                        // another (nested) scroller - start tracking its offset
+                        self.last_scroll = Some((target, top));
+                        return false;
+                    }
+                };
+                if diff.abs() < SCROLL_THRESHOLD {
+                    return false;
+                }
+                self.last_scroll = Some((target, top));
+
+                // always show when scrolled to the top
+                let scrolled_down = diff > 0 && top > 0;
+                if self.scroll_context.scrolled_down == scrolled_down {
+                    return false;
+                }
+                self.scroll_context.scrolled_down = scrolled_down;
+                true
+            }
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(el) = self.body_ref.cast::<web_sys::Element>() {
+                // Note: scroll events do not bubble, so we listen in the capture phase.
+                let link = ctx.link().clone();
+                self._scroll_listener = Some(EventListener::new_with_options(
+                    &el,
+                    "scroll",
+                    EventListenerOptions::run_in_capture_phase(),
+                    move |event| {
+                        let target = event
+                            .target()
+                            .and_then(|t| t.dyn_into::<web_sys::Element>().ok());
+                        if let Some(target) = target {
+                            link.send_message(Msg::Scroll(target));
+                        }
+                    },
+                ));
+            }
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -108,24 +191,25 @@ impl Component for PwtScaffold {
 
         let positioned_fab = props.favorite_action_button.clone().map(|fab| {
             Container::new()
-                .class("pwt-position-absolute")
+                .class(crate::css::Position::Absolute)
                 .style("inset-inline-end", PwtSpace::Pwt(2))
                 .style("inset-block-end", PwtSpace::Pwt(2))
                 .with_child(fab)
         });
 
         let body = Column::new()
-            .class("pwt-position-relative")
+            .class(crate::css::Position::Relative)
             .class(FlexFit)
             .with_optional_child(props.body.clone())
-            .with_optional_child(positioned_fab);
+            .with_optional_child(positioned_fab)
+            .into_html_with_ref(self.body_ref.clone());
 
         // A navigation rail anchors to the inline-start side with the application bar and body to
         // its right; a bottom navigation bar instead spans the full width below the body.
-        if let Some(rail) = props.navigation_rail.clone() {
+        let content: Html = if let Some(rail) = props.navigation_rail.clone() {
             Row::new()
                 .class("pwt-viewport")
-                .class("pwt-position-relative")
+                .class(crate::css::Position::Relative)
                 .with_child(rail)
                 .with_child(
                     Column::new()
@@ -137,11 +221,17 @@ impl Component for PwtScaffold {
         } else {
             Column::new()
                 .class("pwt-viewport")
-                .class("pwt-position-relative")
+                .class(crate::css::Position::Relative)
                 .with_optional_child(props.application_bar.clone())
                 .with_child(body)
                 .with_optional_child(props.navigation_bar.clone())
                 .into()
+        };
+
+        html! {
+            <ContextProvider<ScaffoldScrollContext> context={self.scroll_context}>
+                {content}
+            </ContextProvider<ScaffoldScrollContext>>
         }
     }
 }
@@ -80,6 +80,21 @@ pub struct SideDialog {
     #[prop_or_default]
     pub on_close: Option<Callback<()>>,
 
+    /// Controlled open state.
+    ///
+    /// By default, the dialog slides in when it gets created. If set, the
+    /// dialog visibility follows this property, and a dismiss request
+    /// (swipe, tap outside, Escape or controller) only emits
+    /// [on_open_change](Self::on_open_change).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub open: Option<bool>,
+
+    /// Called when the user wants to dismiss the dialog (with `false`).
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    #[prop_or_default]
+    pub on_open_change: Option<Callback<bool>>,
+
     #[prop_or_default]
     pub children: Vec<VNode>,
 
@@ -124,7 +139,8 @@ impl SideDialog {
 pub enum Msg {
     Open,
     Close,
-    Dismiss, // Slide out, then close
+    Dismiss,  // Slide out, then close (or ask the parent in controlled mode)
+    SlideOut, // Slide out, then close
     SliderAnimationEnd,
     Drag(GestureDragEvent),
     Swipe(GestureSwipeEvent),
@@ -158,6 +174,15 @@ impl PwtSideDialog {
             let _ = el.focus();
         }
     }
+
+    fn slide_out(&mut self) -> bool {
+        if self.slider_state == SliderState::Visible || self.slider_state == SliderState::SlideIn {
+            self.slider_state = SliderState::SlideOut;
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl PwtSideDialog {
@@ -216,6 +241,12 @@ impl Component for PwtSideDialog {
                 if !self.open {
                     self.slider_state = SliderState::SlideIn;
 
+                    if self.last_active.is_none() {
+                        self.last_active = gloo_utils::document()
+                            .active_element()
+                            .and_then(|el| el.dyn_into::<HtmlElement>().ok());
+                    }
+
                     if let Some(dialog_node) = self.node_ref.get() {
                         crate::show_modal_dialog(dialog_node);
                         self.open = true;
@@ -224,17 +255,17 @@ impl Component for PwtSideDialog {
                 true
             }
             Msg::Close => {
-                if self.open {
-                    if let Some(on_close) = &props.on_close {
-                        if let Some(dialog_node) = self.node_ref.get() {
-                            crate::close_dialog(dialog_node);
-                        }
+                if self.open && (props.on_close.is_some() || props.open.is_some()) {
+                    if let Some(dialog_node) = self.node_ref.get() {
+                        crate::close_dialog(dialog_node);
+                    }
 
+                    if let Some(on_close) = &props.on_close {
                         on_close.emit(());
-                        self.open = false;
-
-                        self.restore_focus();
                     }
+                    self.open = false;
+
+                    self.restore_focus();
                 }
                 false
             }
@@ -243,13 +274,25 @@ impl Component for PwtSideDialog {
                 false
             }
             Msg::Dismiss => {
+                if props.open.is_some() {
+                    if self.slider_state == SliderState::Visible {
+                        if let Some(on_open_change) = &props.on_open_change {
+                            on_open_change.emit(false);
+                        }
+                    }
+                    return false;
+                }
                 if self.slider_state == SliderState::Visible {
+                    if let Some(on_open_change) = &props.on_open_change {
+                        on_open_change.emit(false);
+                    }
                     self.slider_state = SliderState::SlideOut;
                     true
                 } else {
                     false
                 }
             }
+            Msg::SlideOut => self.slide_out(),
             Msg::SliderAnimationEnd => {
                 self.slider_state = match self.slider_state {
                     SliderState::SlideIn => SliderState::Visible,
@@ -333,6 +376,18 @@ impl Component for PwtSideDialog {
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.open != old_props.open {
+            match props.open {
+                Some(true) if !self.open => ctx.link().send_message(Msg::Open),
+                Some(false) if self.open => ctx.link().send_message(Msg::SlideOut),
+                _ => {}
+            }
+        }
+        true
+    }
+
     fn destroy(&mut self, _ctx: &Context<Self>) {
         // always close the dialog before restoring the focus
         if let Some(dialog_node) = self.node_ref.get() {
@@ -452,7 +507,7 @@ impl Component for PwtSideDialog {
     }
 
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-        if first_render {
+        if first_render && ctx.props().open != Some(false) {
             ctx.link().send_message(Msg::Open);
         }
     }
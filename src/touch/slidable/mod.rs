@@ -7,6 +7,9 @@ pub use slidable_action_event::SlidableActionMouseEvent;
 mod slidable_action;
 pub use slidable_action::{PwtSlidableAction, SlidableAction};
 
+mod slidable_list;
+pub use slidable_list::{PwtSlidableList, SlidableList};
+
 use gloo_timers::callback::Timeout;
 use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
@@ -62,6 +65,19 @@ pub struct Slidable {
     ///
     /// Called when the main item is tapped.
     pub on_tap: Option<Callback<InputEvent>>,
+
+    /// Open callback.
+    ///
+    /// Called when the user starts to drag the slider.
+    #[prop_or_default]
+    pub on_open: Option<Callback<()>>,
+
+    /// Collapse the slider when this property changes to `true`.
+    ///
+    /// This is used to make sure only one slidable is open at a time
+    /// (see [SlidableList]).
+    #[prop_or_default]
+    pub force_collapse: bool,
 }
 
 impl Slidable {
@@ -111,6 +127,28 @@ impl Slidable {
         self.on_tap = cb.into_event_callback();
     }
 
+    /// Builder style method to set the `on_open` callback.
+    pub fn on_open(mut self, cb: impl IntoEventCallback<()>) -> Self {
+        self.set_on_open(cb);
+        self
+    }
+
+    /// Method to set the `on_open` callback.
+    pub fn set_on_open(&mut self, cb: impl IntoEventCallback<()>) {
+        self.on_open = cb.into_event_callback();
+    }
+
+    /// Builder style method to set the `force_collapse` flag.
+    pub fn force_collapse(mut self, force_collapse: bool) -> Self {
+        self.set_force_collapse(force_collapse);
+        self
+    }
+
+    /// Method to set the `force_collapse` flag.
+    pub fn set_force_collapse(&mut self, force_collapse: bool) {
+        self.force_collapse = force_collapse;
+    }
+
     /// Method to add a left hand side action
     pub fn add_left_action(&mut self, action: impl Into<SlidableAction>) {
         self.left_action_list.push(action.into());
@@ -186,6 +224,13 @@ impl PwtSlidable {
         }
     }
 
+    fn collapse(&mut self) {
+        if self.drag_pos.is_none() && self.start_pos != 0f64 {
+            self.start_pos = 0f64;
+            self.switch_back = true;
+        }
+    }
+
     fn finalize_drag(&mut self) {
         if self.start_pos > 0f64 {
             if self.left_size > 0f64 && self.start_pos >= self.left_size {
@@ -216,7 +261,8 @@ impl PwtSlidable {
         };
 
         Row::new()
-            .class("pwt-w-100 pwt-h-100")
+            .class(css::FullWidth)
+            .class(css::FullHeight)
             .with_child(
                 Container::new()
                     .height(CssLength::Fraction(1.0))
@@ -246,7 +292,8 @@ impl PwtSlidable {
         };
 
         Row::new()
-            .class("pwt-w-100 pwt-h-100")
+            .class(css::FullWidth)
+            .class(css::FullHeight)
             .with_child(html! {<div style="flex: 1 1 auto;"></div>})
             .with_child(
                 Container::new()
@@ -303,7 +350,12 @@ impl Component for PwtSlidable {
                 self.view_state = ViewState::DismissTransition;
             }
             Msg::Drag(event) => match event.phase {
-                GesturePhase::Start => self.drag_start = event.x(),
+                GesturePhase::Start => {
+                    self.drag_start = event.x();
+                    if let Some(on_open) = &props.on_open {
+                        on_open.emit(());
+                    }
+                }
                 GesturePhase::Update => self.drag_pos = Some(self.drag_start - event.x()),
                 GesturePhase::End => {
                     self.drag_start = 0;
@@ -387,10 +439,7 @@ impl Component for PwtSlidable {
                 }
                 SlidableControllerMsg::Collapse => {
                     log::info!("REquest Collapse");
-                    if self.drag_pos.is_none() && self.start_pos != 0f64 {
-                        self.start_pos = 0f64;
-                        self.switch_back = true;
-                    }
+                    self.collapse();
                 }
             },
             Msg::OnTap(pointer_event) => {
@@ -409,6 +458,14 @@ impl Component for PwtSlidable {
         true
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.force_collapse && !old_props.force_collapse {
+            self.collapse();
+        }
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
@@ -0,0 +1,231 @@
+use yew::html::{IntoEventCallback, IntoPropValue};
+
+use crate::prelude::*;
+use crate::props::{IntoStorageLocation, StorageLocation};
+use crate::widget::{List, ListTile};
+
+use super::{Slidable, SlidableAction};
+
+use pwt_macros::{builder, widget};
+
+/// [List] where each tile is wrapped into a [Slidable].
+///
+/// The action factories receive the item position and return the list of
+/// actions for that row. Only one row can be open at a time, i.e. opening a
+/// row collapses the previously opened one.
+///
+/// Dismissed rows are reported with their position. The list resets the
+/// slidable state of all rows after a dismiss, so the caller can simply
+/// remove the item from its data and update the item count (rows are
+/// keyed by position, so stale slider state would otherwise move to the
+/// next item).
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::ListTile;
+/// # use pwt::touch::{SlidableAction, SlidableList};
+/// # fn create_list() -> SlidableList {
+///     SlidableList::new(100, |pos| ListTile::new().title(format!("Item {pos}")))
+///         .right_actions(|pos| {
+///             vec![SlidableAction::new("Delete")
+///                 .icon_class("fa fa-trash")
+///                 .on_activate(move |_| log::info!("delete {pos}"))]
+///         })
+///         .on_dismiss(|pos| log::info!("dismissed {pos}"))
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtSlidableList, @element)]
+#[derive(Properties, Clone, PartialEq)]
+#[builder]
+pub struct SlidableList {
+    // Item count
+    item_count: u64,
+    // List item render function
+    renderer: Callback<u64, ListTile>,
+
+    /// Factory for the left hand side actions.
+    #[prop_or_default]
+    pub left_actions: Option<Callback<u64, Vec<SlidableAction>>>,
+
+    /// Factory for the right hand side actions.
+    #[prop_or_default]
+    pub right_actions: Option<Callback<u64, Vec<SlidableAction>>>,
+
+    /// Dismiss callback, called with the item position.
+    ///
+    /// Without a callback, dismiss is disabled on rows without actions.
+    #[builder_cb(IntoEventCallback, into_event_callback, u64)]
+    #[prop_or_default]
+    pub on_dismiss: Option<Callback<u64>>,
+
+    /// Tap callback, called with the item position.
+    #[builder_cb(IntoEventCallback, into_event_callback, u64)]
+    #[prop_or_default]
+    pub on_tap: Option<Callback<u64>>,
+
+    /// The grid-template-columns used inside each tile.
+    ///
+    /// The slider breaks the subgrid layout of the [List], so the columns
+    /// are defined per tile.
+    #[prop_or(AttrValue::Static("1fr"))]
+    #[builder(IntoPropValue, into_prop_value)]
+    pub grid_template_columns: AttrValue,
+
+    /// Virtual Scroll (see [List::virtual_scroll]).
+    #[prop_or_default]
+    #[builder]
+    pub virtual_scroll: Option<bool>,
+
+    /// Minimum row height (see [List::min_row_height]).
+    #[prop_or(22)]
+    #[builder]
+    pub min_row_height: u64,
+
+    /// Add a line as separator between list items.
+    #[prop_or_default]
+    #[builder]
+    pub separator: bool,
+
+    /// Save and restore the scroll position (see [List::scroll_state_id]).
+    #[prop_or_default]
+    pub scroll_state_id: Option<StorageLocation>,
+}
+
+impl SlidableList {
+    /// Create a new instance.
+    pub fn new(item_count: u64, renderer: impl Into<Callback<u64, ListTile>>) -> Self {
+        yew::props!(SlidableList {
+            item_count,
+            renderer: renderer.into()
+        })
+    }
+
+    /// Builder style method to set the left hand side action factory.
+    pub fn left_actions(mut self, factory: impl Into<Callback<u64, Vec<SlidableAction>>>) -> Self {
+        self.set_left_actions(factory);
+        self
+    }
+
+    /// Method to set the left hand side action factory.
+    pub fn set_left_actions(&mut self, factory: impl Into<Callback<u64, Vec<SlidableAction>>>) {
+        self.left_actions = Some(factory.into());
+    }
+
+    /// Builder style method to set the right hand side action factory.
+    pub fn right_actions(mut self, factory: impl Into<Callback<u64, Vec<SlidableAction>>>) -> Self {
+        self.set_right_actions(factory);
+        self
+    }
+
+    /// Method to set the right hand side action factory.
+    pub fn set_right_actions(&mut self, factory: impl Into<Callback<u64, Vec<SlidableAction>>>) {
+        self.right_actions = Some(factory.into());
+    }
+
+    /// Builder style method to set the scroll state storage location.
+    pub fn scroll_state_id(mut self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_scroll_state_id(state_id);
+        self
+    }
+
+    /// Method to set the scroll state storage location.
+    pub fn set_scroll_state_id(&mut self, state_id: impl IntoStorageLocation) {
+        self.scroll_state_id = state_id.into_storage_location();
+    }
+}
+
+pub enum Msg {
+    Open(u64),
+    Dismiss(u64),
+}
+
+#[doc(hidden)]
+pub struct PwtSlidableList {
+    open_pos: Option<u64>,
+    // Incremented on dismiss to reset the slider state of all rows.
+    generation: usize,
+}
+
+impl Component for PwtSlidableList {
+    type Message = Msg;
+    type Properties = SlidableList;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            open_pos: None,
+            generation: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Open(pos) => {
+                if self.open_pos == Some(pos) {
+                    return false;
+                }
+                self.open_pos = Some(pos);
+                true
+            }
+            Msg::Dismiss(pos) => {
+                self.open_pos = None;
+                self.generation += 1;
+                if let Some(on_dismiss) = &ctx.props().on_dismiss {
+                    on_dismiss.emit(pos);
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let renderer = {
+            let props = props.clone();
+            let link = ctx.link().clone();
+            let open_pos = self.open_pos;
+            let generation = self.generation;
+            move |pos: u64| {
+                let tile = props
+                    .renderer
+                    .emit(pos)
+                    .style("grid-template-columns", &props.grid_template_columns);
+
+                let mut slidable = Slidable::new(tile)
+                    .key(format!("slidable-{generation}"))
+                    .style("grid-column", "1 / -1")
+                    .force_collapse(open_pos.is_some() && open_pos != Some(pos))
+                    .on_open(link.callback(move |_| Msg::Open(pos)));
+
+                if let Some(left_actions) = &props.left_actions {
+                    slidable.left_action_list = left_actions.emit(pos);
+                }
+                if let Some(right_actions) = &props.right_actions {
+                    slidable.right_action_list = right_actions.emit(pos);
+                }
+                if props.on_dismiss.is_some() {
+                    slidable = slidable.on_dismiss(link.callback(move |_| Msg::Dismiss(pos)));
+                }
+                if let Some(on_tap) = &props.on_tap {
+                    let on_tap = on_tap.clone();
+                    slidable.set_on_tap(move |_| on_tap.emit(pos));
+                }
+
+                ListTile::new()
+                    .class("pwt-slidable-list-tile")
+                    .with_child(slidable)
+            }
+        };
+
+        let mut list = List::new(props.item_count, renderer)
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .virtual_scroll(props.virtual_scroll)
+            .min_row_height(props.min_row_height)
+            .separator(props.separator);
+
+        list.scroll_state_id = props.scroll_state_id.clone();
+
+        list.into()
+    }
+}
@@ -7,8 +7,11 @@ use yew::html::IntoPropValue;
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
+use crate::css::{Layer, ZIndex};
 use crate::props::{EventSubscriber, IntoOptionalKey, WidgetBuilder};
-use crate::state::{SharedState, SharedStateObserver};
+use crate::state::{
+    SharedState, SharedStateObserver, TabChannel, TabChannelSubscription, TabNotification,
+};
 
 use pwt_macros::builder;
 
@@ -99,6 +102,19 @@ pub struct SnackBarManager {
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or_default]
     pub bottom_offset: Option<u32>,
+
+    /// Show [TabNotification]s published by other browser tabs on this [TabChannel].
+    ///
+    /// ```
+    /// # use pwt::state::{TabChannel, TabNotification};
+    /// # fn test() {
+    /// TabChannel::new("my-app-notifications")
+    ///     .publish(&TabNotification::new("Backup job finished"));
+    /// # }
+    /// ```
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub tab_channel: Option<AttrValue>,
 }
 
 impl Default for SnackBarManager {
@@ -126,6 +142,7 @@ pub enum Msg {
     Controller, // Controller has new messages
     AnimationEnd(AnimationEvent),
     Timeout,
+    TabNotification(TabNotification),
 }
 
 #[derive(Clone, PartialEq)]
@@ -144,9 +161,17 @@ pub struct PwtSnackBarManager {
     queue: VecDeque<SnackBar>,
     view_state: ViewState, // current visible snackbar
     timeout: Option<Timeout>,
+    _tab_subscription: Option<TabChannelSubscription>,
 }
 
 impl PwtSnackBarManager {
+    fn subscribe_tab_channel(ctx: &Context<Self>) -> Option<TabChannelSubscription> {
+        let name = ctx.props().tab_channel.clone()?;
+        let subscription = TabChannel::<TabNotification>::new(name)
+            .subscribe(ctx.link().callback(Msg::TabNotification));
+        Some(subscription)
+    }
+
     fn display_next(&mut self, ctx: &Context<Self>) {
         if self.view_state != ViewState::Idle {
             return;
@@ -228,6 +253,7 @@ impl Component for PwtSnackBarManager {
             queue: VecDeque::new(),
             view_state: ViewState::Idle,
             timeout: None,
+            _tab_subscription: Self::subscribe_tab_channel(ctx),
         };
 
         me.handle_controller_messages(ctx);
@@ -284,6 +310,14 @@ impl Component for PwtSnackBarManager {
                     true
                 }
             },
+            Msg::TabNotification(notification) => {
+                self.controller.show_snackbar(
+                    SnackBar::new()
+                        .message(notification.message)
+                        .duration(notification.duration),
+                );
+                false
+            }
             Msg::Timeout => {
                 self.timeout = None;
                 match &self.view_state {
@@ -297,6 +331,13 @@ impl Component for PwtSnackBarManager {
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().tab_channel != old_props.tab_channel {
+            self._tab_subscription = Self::subscribe_tab_channel(ctx);
+        }
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
@@ -309,9 +350,13 @@ impl Component for PwtSnackBarManager {
         let snackbar = snackbar
             .attribute(
                 "style",
-                props
-                    .bottom_offset
-                    .map(|offset| format!("bottom: {offset}px;")),
+                match props.bottom_offset {
+                    Some(offset) => format!(
+                        "z-index: {};bottom: {offset}px;",
+                        ZIndex::from(Layer::Toast)
+                    ),
+                    None => format!("z-index: {};", ZIndex::from(Layer::Toast)),
+                },
             )
             .on_action(ctx.link().callback(|_| Msg::ActionButtonPress))
             .on_close(ctx.link().callback(|_| Msg::CloseButtonPress))
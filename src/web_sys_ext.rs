@@ -81,3 +81,81 @@ impl Default for ResizeObserverOptions {
         Self::new()
     }
 }
+
+#[wasm_bindgen]
+extern "C" {
+    /// Write text to the system clipboard (`navigator.clipboard.writeText()`).
+    ///
+    /// Returns an error if the clipboard API is not available (i.e. in
+    /// non-secure contexts). The returned promise rejects if the write fails.
+    # [wasm_bindgen (catch , js_namespace = ["navigator", "clipboard"] , js_name = writeText)]
+    pub fn clipboard_write_text(text: &str) -> Result<::js_sys::Promise, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Set of ranges styled with the `::highlight(name)` pseudo element (CSS Custom Highlight API).
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = Highlight , typescript_type = "Highlight")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type Highlight;
+
+    #[wasm_bindgen(catch, constructor, js_class = "Highlight")]
+    pub fn new() -> Result<Highlight, JsValue>;
+
+    # [wasm_bindgen (method , structural , js_class = "Highlight" , js_name = add)]
+    pub fn add(this: &Highlight, range: &::web_sys::Range);
+
+    # [wasm_bindgen (method , structural , js_class = "Highlight" , js_name = clear)]
+    pub fn clear(this: &Highlight);
+
+    /// Registry of the named highlights (`CSS.highlights`).
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = HighlightRegistry , typescript_type = "HighlightRegistry")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type HighlightRegistry;
+
+    # [wasm_bindgen (method , structural , js_class = "HighlightRegistry" , js_name = get)]
+    pub fn get(this: &HighlightRegistry, name: &str) -> JsValue;
+
+    # [wasm_bindgen (method , structural , js_class = "HighlightRegistry" , js_name = set)]
+    pub fn set(this: &HighlightRegistry, name: &str, highlight: &Highlight);
+
+    # [wasm_bindgen (method , structural , js_class = "HighlightRegistry" , js_name = delete)]
+    pub fn delete(this: &HighlightRegistry, name: &str) -> bool;
+}
+
+/// Returns the highlight registry (`CSS.highlights`), if supported by the browser.
+pub fn css_highlights() -> Option<HighlightRegistry> {
+    let css = js_sys::Reflect::get(&gloo_utils::window(), &JsValue::from_str("CSS")).ok()?;
+    let registry = js_sys::Reflect::get(&css, &JsValue::from_str("highlights")).ok()?;
+    if registry.is_undefined() {
+        return None;
+    }
+    Some(registry.unchecked_into())
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// Deadline passed to [request_idle_callback] callbacks.
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = IdleDeadline , typescript_type = "IdleDeadline")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type IdleDeadline;
+
+    /// Remaining time (milliseconds) of the current idle period.
+    # [wasm_bindgen (method , structural , js_class = "IdleDeadline" , js_name = timeRemaining)]
+    pub fn time_remaining(this: &IdleDeadline) -> f64;
+
+    /// Queue a function to be called during browser idle periods (`window.requestIdleCallback()`).
+    ///
+    /// Not supported by all browsers, so check [has_request_idle_callback] first.
+    # [wasm_bindgen (js_name = requestIdleCallback)]
+    pub fn request_idle_callback(callback: &::js_sys::Function, options: &JsValue) -> u32;
+}
+
+/// Returns true if the browser supports [request_idle_callback].
+pub fn has_request_idle_callback() -> bool {
+    js_sys::Reflect::has(
+        &gloo_utils::window(),
+        &JsValue::from_str("requestIdleCallback"),
+    )
+    .unwrap_or(false)
+}
@@ -0,0 +1,178 @@
+use yew::html::IntoPropValue;
+
+use crate::css::{Display, FontColor, FontStyle};
+use crate::prelude::*;
+use crate::state::{AppInfo, Language, LanguageObserver, Theme, ThemeObserver};
+use crate::widget::{Column, Container};
+
+use pwt_macros::{builder, widget};
+
+/// Panel showing product details.
+///
+/// Lists the product name, version and build date from the [AppInfo]
+/// context (or the [app_info](Self::app_info) property), the loaded
+/// translation catalog and the active theme, and links to the
+/// documentation and support pages.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{AboutPanel, Dialog};
+/// # fn create_dialog() -> Dialog {
+/// Dialog::new(tr!("About")).with_child(AboutPanel::new().padding(2))
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtAboutPanel, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct AboutPanel {
+    /// Application info (defaults to the [AppInfo] context).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub app_info: Option<AppInfo>,
+}
+
+impl Default for AboutPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AboutPanel {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+}
+
+pub enum Msg {
+    AppInfoUpdate(AppInfo),
+    LanguageChange(String),
+    ThemeChange(Theme),
+}
+
+#[doc(hidden)]
+pub struct PwtAboutPanel {
+    app_info: Option<AppInfo>,
+    _app_info_handle: Option<ContextHandle<AppInfo>>,
+    lang: String,
+    _lang_observer: LanguageObserver,
+    theme: Theme,
+    _theme_observer: ThemeObserver,
+}
+
+fn link(url: &AttrValue) -> Html {
+    html! {<a href={url.clone()} target="_blank" rel="noopener noreferrer">{url}</a>}
+}
+
+impl Component for PwtAboutPanel {
+    type Message = Msg;
+    type Properties = AboutPanel;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (app_info, _app_info_handle) = match ctx
+            .link()
+            .context::<AppInfo>(ctx.link().callback(Msg::AppInfoUpdate))
+        {
+            Some((app_info, handle)) => (Some(app_info), Some(handle)),
+            None => (None, None),
+        };
+
+        let _lang_observer = LanguageObserver::new(ctx.link().callback(Msg::LanguageChange));
+        let _theme_observer = ThemeObserver::new(
+            ctx.link()
+                .callback(|(theme, _dark_mode): (Theme, bool)| Msg::ThemeChange(theme)),
+        );
+
+        Self {
+            app_info,
+            _app_info_handle,
+            lang: Language::load(),
+            _lang_observer,
+            theme: _theme_observer.theme(),
+            _theme_observer,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::AppInfoUpdate(app_info) => {
+                self.app_info = Some(app_info);
+                true
+            }
+            Msg::LanguageChange(lang) => {
+                self.lang = lang;
+                true
+            }
+            Msg::ThemeChange(theme) => {
+                let changed = self.theme != theme;
+                self.theme = theme;
+                changed
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let app_info = props
+            .app_info
+            .clone()
+            .or_else(|| self.app_info.clone())
+            .unwrap_or_default();
+
+        let mut rows: Vec<(String, Html)> = Vec::new();
+        if let Some(version) = &app_info.version {
+            rows.push((tr!("Version"), html! {{version}}));
+        }
+        if let Some(build_date) = &app_info.build_date {
+            rows.push((tr!("Build Date"), html! {{build_date}}));
+        }
+
+        let language = if self.lang.is_empty() {
+            tr!("Default")
+        } else {
+            self.lang.clone()
+        };
+        rows.push((tr!("Language"), html! {{language}}));
+
+        let theme = format!(
+            "{} ({}, {})",
+            self.theme.name, self.theme.mode, self.theme.density
+        );
+        rows.push((tr!("Theme"), html! {{theme}}));
+
+        if let Some(docs_url) = &app_info.docs_url {
+            rows.push((tr!("Documentation"), link(docs_url)));
+        }
+        if let Some(support_url) = &app_info.support_url {
+            rows.push((tr!("Support"), link(support_url)));
+        }
+
+        let grid = Container::new()
+            .class(Display::Grid)
+            .style("grid-template-columns", "auto 1fr")
+            .style("column-gap", "1em")
+            .style("row-gap", "0.5em")
+            .children(rows.into_iter().flat_map(|(label, value)| {
+                [
+                    Container::new()
+                        .class(FontColor::NeutralAlt)
+                        .with_child(label)
+                        .into(),
+                    Container::new().with_child(value).into(),
+                ]
+            }));
+
+        Column::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-about-panel")
+            .gap(2)
+            .with_child(
+                Container::new()
+                    .class(FontStyle::HeadlineSmall)
+                    .with_child(app_info.product_name.clone()),
+            )
+            .with_child(grid)
+            .into()
+    }
+}
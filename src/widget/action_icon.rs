@@ -1,17 +1,28 @@
+use gloo_timers::callback::Timeout;
 use wasm_bindgen::JsCast;
 use web_sys::Event;
 
 use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::virtual_dom::VTag;
 
+use crate::css;
 use crate::prelude::*;
+use crate::widget::Tooltip;
 
 use pwt_macros::{builder, widget};
 
 /// A clickable icon. Like [Button](super::Button) without any decoration (inline element).
 ///
 /// This component is useful in data tables because it is visually lighter than a button.
-#[widget(pwt=crate, @element)]
+///
+/// Set [confirm](Self::confirm) to require a second activation (within
+/// [confirm_timeout](Self::confirm_timeout)) before the callback fires. The
+/// transient confirm state is indicated with the `pwt-action-icon-confirm`
+/// class, and the confirm text is shown as [Tooltip].
+///
+/// Note: The confirm state is managed by the component, so it is ignored
+/// when the icon is converted with [IntoVTag].
+#[widget(pwt=crate, comp=PwtActionIcon, @element)]
 #[builder]
 #[derive(Properties, PartialEq, Clone)]
 pub struct ActionIcon {
@@ -34,6 +45,21 @@ pub struct ActionIcon {
     #[builder_cb(IntoEventCallback, into_event_callback, Event)]
     #[prop_or_default]
     pub on_activate: Option<Callback<Event>>,
+
+    /// Require a second activation to confirm the action.
+    #[builder]
+    #[prop_or_default]
+    pub confirm: bool,
+
+    /// Tooltip text in confirm state (default "Confirm?").
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub confirm_text: Option<AttrValue>,
+
+    /// Time (in milliseconds) until the confirm state reverts (default 3000).
+    #[builder]
+    #[prop_or(3000)]
+    pub confirm_timeout: u32,
 }
 
 impl ActionIcon {
@@ -102,3 +128,81 @@ impl IntoVTag for ActionIcon {
         )
     }
 }
+
+pub enum Msg {
+    Activate(Event),
+    ConfirmTimeout,
+}
+
+#[doc(hidden)]
+pub struct PwtActionIcon {
+    confirm_timeout: Option<Timeout>,
+}
+
+impl Component for PwtActionIcon {
+    type Message = Msg;
+    type Properties = ActionIcon;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            confirm_timeout: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+
+        match msg {
+            Msg::Activate(event) => {
+                if props.confirm && self.confirm_timeout.is_none() {
+                    // replacing the Timeout cancels a pending one
+                    let link = ctx.link().clone();
+                    self.confirm_timeout = Some(Timeout::new(props.confirm_timeout, move || {
+                        link.send_message(Msg::ConfirmTimeout)
+                    }));
+                    return true;
+                }
+                let confirmed = self.confirm_timeout.take().is_some();
+                if let Some(on_activate) = &props.on_activate {
+                    on_activate.emit(event);
+                }
+                confirmed
+            }
+            Msg::ConfirmTimeout => self.confirm_timeout.take().is_some(),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if !props.confirm || props.disabled {
+            self.confirm_timeout = None;
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        if !props.confirm {
+            return props.clone().into_vtag().into();
+        }
+
+        let confirming = self.confirm_timeout.is_some();
+
+        let mut icon = props.clone();
+        icon.on_activate = Some(ctx.link().callback(Msg::Activate));
+        icon.add_class(confirming.then_some("pwt-action-icon-confirm"));
+
+        let tip = confirming.then(|| {
+            props
+                .confirm_text
+                .clone()
+                .unwrap_or_else(|| tr!("Confirm?").into())
+        });
+
+        Tooltip::new(icon.into_vtag())
+            .class(css::Display::InlineBlock)
+            .tip(tip)
+            .into()
+    }
+}
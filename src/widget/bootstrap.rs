@@ -0,0 +1,301 @@
+use std::rc::Rc;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::{VComp, VNode};
+
+use crate::css::{AlignItems, Fit, JustifyContent};
+use crate::prelude::*;
+use crate::props::{IntoOptionalRenderFn, LoadCallback, RenderFn};
+use crate::widget::{Button, CatalogLoader, Column, CssBundle, Fa, Row, ThemeLoader};
+use crate::{AsyncPool, impl_to_html};
+
+use pwt_macros::builder;
+
+/// Application bootstrap helper.
+///
+/// Orchestrates the async startup sequence of an application:
+///
+/// - load the theme CSS (and additional bundles) using a [ThemeLoader]
+/// - load the I18N catalog using a [CatalogLoader]
+/// - run the application provided init tasks (in parallel)
+///
+/// A splash screen is displayed until everything is ready. Errors from
+/// the init tasks are displayed together with a retry button. The body is
+/// only rendered after all steps finished successfully.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Bootstrap;
+/// # fn create_app() -> Html {
+/// Bootstrap::new(html! {<div>{"My App Content"}</div>})
+///     .with_init_task(|| async move {
+///         // load initial data, check login ...
+///         Ok::<(), anyhow::Error>(())
+///     })
+///     .into()
+/// # }
+/// ```
+#[derive(Properties, Clone, PartialEq)]
+#[builder]
+pub struct Bootstrap {
+    body: VNode,
+
+    /// Init tasks, executed in parallel before the body is rendered.
+    #[prop_or_default]
+    pub init_tasks: Vec<LoadCallback<()>>,
+
+    /// Splash screen, displayed until everything is loaded.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub splash: Option<Html>,
+
+    /// Convert ISO 639-1 language code to server side catalog URLs (see [CatalogLoader]).
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String, String)]
+    #[prop_or_default]
+    pub catalog_url_builder: Option<RenderFn<String, String>>,
+
+    /// Default language (skip catalog loading for this language)
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::Static("en"))]
+    pub default_lang: AttrValue,
+
+    /// Returns the server side CSS URLs (see [ThemeLoader]).
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String, String)]
+    #[prop_or_default]
+    pub theme_url_builder: Option<RenderFn<String, String>>,
+
+    /// Additional CSS bundles (see [ThemeLoader]).
+    #[prop_or_default]
+    pub css_bundles: Vec<CssBundle>,
+
+    /// Ready callback, called once after all startup steps finished.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_ready: Option<Callback<()>>,
+}
+
+impl Bootstrap {
+    /// Create a new instance.
+    pub fn new(body: impl Into<VNode>) -> Self {
+        yew::props!(Self { body: body.into() })
+    }
+
+    /// Builder style method to add an init task.
+    pub fn with_init_task(mut self, task: impl Into<LoadCallback<()>>) -> Self {
+        self.add_init_task(task);
+        self
+    }
+
+    /// Method to add an init task.
+    pub fn add_init_task(&mut self, task: impl Into<LoadCallback<()>>) {
+        self.init_tasks.push(task.into());
+    }
+
+    /// Builder style method to add a CSS bundle.
+    pub fn with_css_bundle(mut self, bundle: impl Into<CssBundle>) -> Self {
+        self.css_bundles.push(bundle.into());
+        self
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum TaskState {
+    Running,
+    Done,
+    Failed(String),
+}
+
+pub enum Msg {
+    ThemeReady,
+    CatalogLoaded,
+    TaskDone(usize, Result<(), String>),
+    Retry,
+}
+
+#[doc(hidden)]
+pub struct PwtBootstrap {
+    theme_ready: bool,
+    catalog_ready: bool,
+    tasks: Vec<TaskState>,
+    ready: bool,
+    async_pool: AsyncPool,
+}
+
+impl PwtBootstrap {
+    fn run_task(&mut self, ctx: &Context<Self>, index: usize) {
+        let task = match ctx.props().init_tasks.get(index) {
+            Some(task) => task.clone(),
+            None => return,
+        };
+        self.tasks[index] = TaskState::Running;
+        self.async_pool.send_future(ctx.link().clone(), async move {
+            let result = task.apply().await.map_err(|err| err.to_string());
+            Msg::TaskDone(index, result)
+        });
+    }
+
+    fn tasks_done(&self) -> bool {
+        self.tasks.iter().all(|state| *state == TaskState::Done)
+    }
+
+    // true if some tasks failed, and all other tasks are finished
+    fn tasks_failed(&self) -> bool {
+        !self.tasks.contains(&TaskState::Running)
+            && self
+                .tasks
+                .iter()
+                .any(|state| matches!(state, TaskState::Failed(_)))
+    }
+
+    fn check_ready(&mut self, ctx: &Context<Self>) {
+        if !self.ready && self.theme_ready && self.catalog_ready && self.tasks_done() {
+            self.ready = true;
+            if let Some(on_ready) = &ctx.props().on_ready {
+                on_ready.emit(());
+            }
+        }
+    }
+
+    fn render_splash(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        match &props.splash {
+            Some(splash) => splash.clone(),
+            None => Row::new()
+                .class("pwt-bootstrap-splash")
+                .class(Fit)
+                .class(AlignItems::Center)
+                .class(JustifyContent::Center)
+                .with_child(Fa::new("spinner").pulse().large_3x())
+                .into(),
+        }
+    }
+
+    fn render_errors(&self, ctx: &Context<Self>) -> Html {
+        let errors = self.tasks.iter().filter_map(|state| match state {
+            TaskState::Failed(err) => Some(crate::widget::error_message(err)),
+            _ => None,
+        });
+
+        Column::new()
+            .class("pwt-bootstrap-errors")
+            .padding(2)
+            .gap(2)
+            .children(errors.map(VNode::from))
+            .with_child(
+                Button::new(tr!("Retry"))
+                    .icon_class("fa fa-refresh")
+                    .on_activate(ctx.link().callback(|_| Msg::Retry)),
+            )
+            .into()
+    }
+}
+
+impl Component for PwtBootstrap {
+    type Message = Msg;
+    type Properties = Bootstrap;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+
+        let mut me = Self {
+            theme_ready: false,
+            catalog_ready: false,
+            tasks: vec![TaskState::Running; props.init_tasks.len()],
+            ready: false,
+            async_pool: AsyncPool::new(),
+        };
+
+        for index in 0..props.init_tasks.len() {
+            me.run_task(ctx, index);
+        }
+
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ThemeReady => self.theme_ready = true,
+            Msg::CatalogLoaded => self.catalog_ready = true,
+            Msg::TaskDone(index, result) => {
+                if let Some(state) = self.tasks.get_mut(index) {
+                    *state = match result {
+                        Ok(()) => TaskState::Done,
+                        Err(err) => TaskState::Failed(err),
+                    };
+                }
+            }
+            Msg::Retry => {
+                let failed: Vec<usize> = self
+                    .tasks
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, state)| matches!(state, TaskState::Failed(_)))
+                    .map(|(index, _)| index)
+                    .collect();
+                for index in failed {
+                    self.run_task(ctx, index);
+                }
+            }
+        }
+        self.check_ready(ctx);
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        let (old_len, new_len) = (old_props.init_tasks.len(), props.init_tasks.len());
+        if new_len != old_len {
+            // results of removed tasks are ignored in `Msg::TaskDone`
+            self.tasks.resize(new_len, TaskState::Running);
+            for index in old_len..new_len {
+                self.run_task(ctx, index);
+            }
+            self.check_ready(ctx);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let failed = self.tasks_failed();
+
+        let content: Html = if self.ready {
+            props.body.clone()
+        } else if failed {
+            self.render_errors(ctx)
+        } else {
+            html! {}
+        };
+
+        let body = CatalogLoader::new(content)
+            .default_lang(props.default_lang.clone())
+            .url_builder(props.catalog_url_builder.clone())
+            .on_load(ctx.link().callback(|_: String| Msg::CatalogLoaded));
+
+        let mut theme_loader = ThemeLoader::new(body)
+            .theme_url_builder(props.theme_url_builder.clone())
+            .on_ready(ctx.link().callback(|_: ()| Msg::ThemeReady));
+        theme_loader.bundles = props.css_bundles.clone();
+
+        let show_splash = !self.ready && !(failed && self.theme_ready && self.catalog_ready);
+
+        html! {
+            <>
+                {theme_loader}
+                if show_splash {
+                    {self.render_splash(ctx)}
+                }
+            </>
+        }
+    }
+}
+
+impl From<Bootstrap> for VNode {
+    fn from(val: Bootstrap) -> Self {
+        let comp = VComp::new::<PwtBootstrap>(Rc::new(val), None);
+        VNode::from(comp)
+    }
+}
+
+impl_to_html!(Bootstrap);
@@ -2,6 +2,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use anyhow::bail;
+use gloo_timers::callback::Timeout;
 use web_sys::{HtmlElement, MouseEvent};
 
 use yew::html::{IntoEventCallback, IntoPropValue};
@@ -9,7 +10,8 @@ use yew::html::{IntoEventCallback, IntoPropValue};
 use crate::dom::IntoHtmlElement;
 use crate::prelude::*;
 use crate::props::EventSubscriber;
-use crate::widget::Container;
+use crate::state::{CapCheck, Capabilities, MissingCapPolicy, RequiredCaps};
+use crate::widget::{Container, DisabledContext, Tooltip};
 
 use pwt_macros::{builder, widget};
 
@@ -47,6 +49,14 @@ impl FromStr for ButtonType {
 /// Button.
 ///
 /// Buttons can be text only, icons with text, or icons only.
+///
+/// # Confirm on click
+///
+/// Set [confirm](Self::confirm) for low-risk destructive actions. The
+/// first activation switches the button into a transient confirm state
+/// (showing [confirm_text](Self::confirm_text)), and only a second
+/// activation within [confirm_timeout](Self::confirm_timeout) fires the
+/// [on_activate](Self::on_activate) callback.
 #[widget(pwt=crate, comp=crate::widget::PwtButton, @element)]
 #[derive(Properties, PartialEq, Clone)]
 #[builder]
@@ -84,6 +94,10 @@ pub struct Button {
     #[builder]
     pub disabled: bool,
 
+    /// Required capabilities (see [Capabilities]).
+    #[prop_or_default]
+    pub required_caps: RequiredCaps,
+
     /// Draw button in pressed state (for use in Demo)
     #[prop_or_default]
     #[builder]
@@ -110,6 +124,21 @@ pub struct Button {
     #[builder_cb(IntoEventCallback, into_event_callback, MouseEvent)]
     #[prop_or_default]
     pub on_activate: Option<Callback<MouseEvent>>,
+
+    /// Require a second activation to confirm the action.
+    #[prop_or_default]
+    #[builder]
+    pub confirm: bool,
+
+    /// Button text in confirm state (default "Confirm?").
+    #[prop_or_default]
+    #[builder(IntoPropValue, into_prop_value)]
+    pub confirm_text: Option<AttrValue>,
+
+    /// Time (in milliseconds) until the confirm state reverts (default 3000).
+    #[prop_or(3000)]
+    #[builder]
+    pub confirm_timeout: u32,
 }
 
 impl Button {
@@ -147,17 +176,61 @@ impl Button {
     pub fn set_icon_class(&mut self, icon_class: impl Into<Classes>) {
         self.icon_class = Some(icon_class.into());
     }
+
+    /// Builder style method to add a required capability.
+    ///
+    /// The button is disabled (or hidden, see [Self::missing_cap_policy])
+    /// if the capability is not granted by the [Capabilities] context.
+    pub fn require_cap(mut self, cap: impl Into<AttrValue>) -> Self {
+        self.add_required_cap(cap);
+        self
+    }
+
+    /// Method to add a required capability.
+    pub fn add_required_cap(&mut self, cap: impl Into<AttrValue>) {
+        self.required_caps.add(cap);
+    }
+
+    /// Builder style method to set the missing capability policy.
+    pub fn missing_cap_policy(mut self, policy: MissingCapPolicy) -> Self {
+        self.set_missing_cap_policy(policy);
+        self
+    }
+
+    /// Method to set the missing capability policy.
+    pub fn set_missing_cap_policy(&mut self, policy: MissingCapPolicy) {
+        self.required_caps.set_policy(policy);
+    }
 }
 
 pub enum Msg {
     ShowRippleAnimation(PointerEvent),
     AnimationEnd,
+    DisabledContextUpdate(DisabledContext),
+    CapabilitiesUpdate(Capabilities),
+    Activate(MouseEvent),
+    ConfirmTimeout,
 }
 
 #[doc(hidden)]
 pub struct PwtButton {
     node_ref: NodeRef,
     ripple_pos: Option<(i32, i32, i32)>,
+    context_disabled: bool,
+    _disabled_context_handle: Option<ContextHandle<DisabledContext>>,
+    capabilities: Option<Capabilities>,
+    _capabilities_handle: Option<ContextHandle<Capabilities>>,
+    confirm_timeout: Option<Timeout>,
+}
+
+impl PwtButton {
+    fn check_caps(&self, props: &Button) -> CapCheck {
+        props.required_caps.check(self.capabilities.as_ref())
+    }
+
+    fn is_disabled(&self, props: &Button) -> bool {
+        props.disabled || self.context_disabled || !self.check_caps(props).granted()
+    }
 }
 
 impl Component for PwtButton {
@@ -170,9 +243,20 @@ impl Component for PwtButton {
             .node_ref
             .clone()
             .unwrap_or_else(|| NodeRef::default());
+        let (context_disabled, _disabled_context_handle) =
+            DisabledContext::subscribe(ctx.link(), ctx.link().callback(Msg::DisabledContextUpdate));
+        let (capabilities, _capabilities_handle) = ctx
+            .link()
+            .context::<Capabilities>(ctx.link().callback(Msg::CapabilitiesUpdate))
+            .unzip();
         Self {
             ripple_pos: None,
             node_ref,
+            context_disabled,
+            _disabled_context_handle,
+            capabilities,
+            _capabilities_handle,
+            confirm_timeout: None,
         }
     }
 
@@ -181,7 +265,7 @@ impl Component for PwtButton {
 
         match msg {
             Msg::ShowRippleAnimation(event) => {
-                if props.disabled {
+                if self.is_disabled(props) {
                     return false;
                 }
                 if let Some(element) = self.node_ref.clone().into_html_element() {
@@ -199,12 +283,44 @@ impl Component for PwtButton {
                 self.ripple_pos = None;
                 true
             }
+            Msg::DisabledContextUpdate(context) => {
+                let changed = self.context_disabled != context.disabled;
+                self.context_disabled = context.disabled;
+                changed
+            }
+            Msg::CapabilitiesUpdate(capabilities) => {
+                self.capabilities = Some(capabilities);
+                true
+            }
+            Msg::Activate(event) => {
+                if self.is_disabled(props) {
+                    return false;
+                }
+                if props.confirm && self.confirm_timeout.is_none() {
+                    event.prevent_default(); // do not submit forms
+                    let link = ctx.link().clone();
+                    self.confirm_timeout = Some(Timeout::new(props.confirm_timeout, move || {
+                        link.send_message(Msg::ConfirmTimeout)
+                    }));
+                    return true;
+                }
+                let confirmed = self.confirm_timeout.take().is_some();
+                if let Some(on_activate) = &props.on_activate {
+                    on_activate.emit(event);
+                }
+                confirmed
+            }
+            Msg::ConfirmTimeout => self.confirm_timeout.take().is_some(),
         }
     }
 
     fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
         let props = ctx.props();
 
+        if !props.confirm {
+            self.confirm_timeout = None;
+        }
+
         if let Some(new_ref) = &props.node_ref {
             if new_ref != &self.node_ref {
                 self.node_ref = new_ref.clone();
@@ -225,9 +341,16 @@ impl Component for PwtButton {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
+        let cap_check = self.check_caps(props);
+        if cap_check.hidden() {
+            return html! {};
+        }
+
+        let disabled = self.is_disabled(props);
+
         let mut children = Vec::new();
 
-        let suppress_onclick: Option<Callback<MouseEvent>> = match props.disabled {
+        let suppress_onclick: Option<Callback<MouseEvent>> = match disabled {
             true => Some(Callback::from(|event: MouseEvent| {
                 event.prevent_default();
                 event.stop_propagation();
@@ -244,7 +367,15 @@ impl Component for PwtButton {
             }
         }
 
-        if let Some(text) = &props.text {
+        let confirming = self.confirm_timeout.is_some();
+
+        if confirming {
+            let text = props
+                .confirm_text
+                .clone()
+                .unwrap_or_else(|| tr!("Confirm?").into());
+            children.push(text.into());
+        } else if let Some(text) = &props.text {
             children.push(text.into());
         }
 
@@ -270,25 +401,36 @@ impl Component for PwtButton {
             children.push(html! {<i role="none" class="fa fa-caret-down"/>});
         }
 
-        let listeners = (!props.disabled).then_some(props.listeners.clone());
-        let on_activate = if props.disabled {
+        let listeners = (!disabled).then_some(props.listeners.clone());
+        let on_activate = if disabled {
             None
+        } else if props.confirm {
+            Some(ctx.link().callback(Msg::Activate))
         } else {
             props.on_activate.clone()
         };
 
-        Container::from_widget_props(props.std_props.clone(), listeners)
+        let button = Container::from_widget_props(props.std_props.clone(), listeners)
             .children(children)
             .tag("button")
             .class("pwt-button")
             .class(props.pressed.then_some("pressed"))
+            .class(confirming.then_some("pwt-button-confirm"))
             .attribute("type", Some(props.button_type.to_string()))
-            .attribute("aria-disabled", props.disabled.then_some("true"))
+            .attribute("aria-disabled", disabled.then_some("true"))
             .attribute("autofocus", props.autofocus.then_some(""))
             .attribute("aria-label", props.aria_label.clone())
             .attribute("tabindex", props.tabindex.map(|i| i.to_string()))
             .onpointerdown(ctx.link().callback(Msg::ShowRippleAnimation))
             .onclick(on_activate)
-            .into_html_with_ref(self.node_ref.clone())
+            .into_html_with_ref(self.node_ref.clone());
+
+        match cap_check.tip() {
+            Some(tip) => Tooltip::new(button)
+                .class(crate::css::Display::InlineBlock)
+                .tip(tip)
+                .into(),
+            None => button,
+        }
     }
 }
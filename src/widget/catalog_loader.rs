@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use gettext::Catalog;
-use yew::html::IntoPropValue;
+use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::virtual_dom::{VComp, VNode};
 
 use crate::props::{IntoOptionalRenderFn, RenderFn};
@@ -43,6 +43,11 @@ pub struct CatalogLoader {
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or(AttrValue::Static("en"))]
     pub default_lang: AttrValue,
+
+    /// Load callback, called with the language code after a catalog is loaded.
+    #[builder_cb(IntoEventCallback, into_event_callback, String)]
+    #[prop_or_default]
+    pub on_load: Option<Callback<String>>,
 }
 
 impl CatalogLoader {
@@ -127,6 +132,9 @@ impl Component for PwtCatalogLoader {
                 }
                 self.loaded_once = true;
                 self.state = LoadState::Idle;
+                if let Some(on_load) = &props.on_load {
+                    on_load.emit(self.lang.clone());
+                }
                 true
             }
             Msg::LoadFinished(url) => {
@@ -172,6 +180,9 @@ impl Component for PwtCatalogLoader {
                     if self.lang == props.default_lang {
                         crate::init_i18n(Catalog::empty());
                         link.send_message(Msg::LoadFinished(url));
+                    } else if crate::gettext_wrapper::preloaded_catalog_url() == Some(url.clone()) {
+                        // already installed by the LanguageSelector
+                        link.send_message(Msg::LoadFinished(url));
                     } else {
                         crate::init_i18n_from_url(&url, move |url| {
                             link.send_message(Msg::LoadFinished(url));
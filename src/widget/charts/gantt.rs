@@ -0,0 +1,784 @@
+//! Gantt chart
+//!
+//! Displays time ranges (bars) on a horizontal time axis, grouped in
+//! rows, for example backup or maintenance windows.
+//!
+//! Includes the following features
+//! * zoomable (mouse wheel) and scrollable (drag) time scale
+//! * move and resize bars with the mouse (snapped to a time grid)
+//! * current time indicator
+
+use std::rc::Rc;
+
+use gloo_timers::callback::Interval;
+use js_sys::Date;
+use wasm_bindgen::JsValue;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::Key;
+
+use crate::css;
+use crate::dom::{DomSizeObserver, set_pointer_capture};
+use crate::prelude::*;
+use crate::widget::Container;
+use crate::widget::canvas::{Canvas, Group, Line, Rect, Text};
+
+use pwt_macros::{builder, widget};
+
+// height of the time axis
+const AXIS_HEIGHT: f64 = 24.0;
+// width of the resize handles
+const HANDLE_WIDTH: f64 = 6.0;
+// minimal distance between two axis ticks (pixels)
+const MIN_TICK_SPACING: f64 = 80.0;
+// approximate label character width (used to hide labels)
+const CHAR_WIDTH: f64 = 7.0;
+// smallest visible time range (seconds)
+const MIN_RANGE: f64 = 60.0;
+// update the current time indicator once a minute
+const TICK_INTERVAL_MS: u32 = 60_000;
+
+const TICK_STEPS: &[i64] = &[
+    60,
+    5 * 60,
+    15 * 60,
+    30 * 60,
+    3600,
+    3 * 3600,
+    6 * 3600,
+    12 * 3600,
+    86400,
+    7 * 86400,
+    30 * 86400,
+];
+
+/// A bar (time range) inside a [GanttRow].
+#[derive(Clone, PartialEq)]
+pub struct GanttBar {
+    key: Key,
+    start: i64,
+    end: i64,
+    title: Option<AttrValue>,
+    color: Option<AttrValue>,
+    editable: bool,
+}
+
+impl GanttBar {
+    /// Creates a new instance.
+    ///
+    /// `start` and `end` are unix epochs (seconds).
+    pub fn new(key: impl Into<Key>, start: i64, end: i64) -> Self {
+        Self {
+            key: key.into(),
+            start,
+            end: end.max(start),
+            title: None,
+            color: None,
+            editable: true,
+        }
+    }
+
+    /// Builder style method to set the title.
+    pub fn title(mut self, title: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.title = title.into_prop_value();
+        self
+    }
+
+    /// Builder style method to set the bar color (CSS color).
+    pub fn color(mut self, color: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.color = color.into_prop_value();
+        self
+    }
+
+    /// Builder style method to set if the bar can be moved and resized.
+    ///
+    /// This is only used if the [Gantt] chart is editable (default `true`).
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+}
+
+/// A row of the [Gantt] chart.
+#[derive(Clone, PartialEq)]
+pub struct GanttRow {
+    key: Key,
+    label: AttrValue,
+    bars: Vec<GanttBar>,
+}
+
+impl GanttRow {
+    /// Creates a new instance.
+    pub fn new(key: impl Into<Key>, label: impl Into<AttrValue>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            bars: Vec::new(),
+        }
+    }
+
+    /// Builder style method to add a bar.
+    pub fn with_bar(mut self, bar: GanttBar) -> Self {
+        self.add_bar(bar);
+        self
+    }
+
+    /// Method to add a bar.
+    pub fn add_bar(&mut self, bar: GanttBar) {
+        self.bars.push(bar);
+    }
+
+    /// Builder style method to set the bars.
+    pub fn bars(mut self, bars: impl IntoIterator<Item = GanttBar>) -> Self {
+        self.bars = bars.into_iter().collect();
+        self
+    }
+}
+
+/// Emitted when the user moves or resizes a bar.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GanttBarChange {
+    /// The row key.
+    pub row: Key,
+    /// The bar key.
+    pub bar: Key,
+    /// New start time (unix epoch).
+    pub start: i64,
+    /// New end time (unix epoch).
+    pub end: i64,
+}
+
+/// Gantt chart (time range bars on a time axis).
+///
+/// Use the mouse wheel to zoom the time scale, drag the background to
+/// scroll, and double click to reset the view.
+///
+/// If [editable](Self::editable) is set, bars can be moved and resized
+/// with the mouse. Changes are not applied automatically, instead the
+/// widget emits [on_bar_change](Self::on_bar_change), and the owner is
+/// expected to update the rows.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::charts::{Gantt, GanttBar, GanttBarChange, GanttRow};
+/// fn backup_windows(on_change: Callback<GanttBarChange>) -> Gantt {
+///     Gantt::new(vec![
+///         GanttRow::new("node1", "node1")
+///             .with_bar(GanttBar::new("job1", 1700000000, 1700007200).title("daily")),
+///         GanttRow::new("node2", "node2")
+///             .with_bar(GanttBar::new("job2", 1700003600, 1700005400).title("vm-100")),
+///     ])
+///     .editable(true)
+///     .on_bar_change(on_change)
+/// }
+/// ```
+#[widget(pwt=crate, comp=PwtGantt, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct Gantt {
+    /// The rows.
+    #[prop_or_default]
+    pub rows: Rc<Vec<GanttRow>>,
+
+    /// Initially visible time range (unix epochs).
+    ///
+    /// Defaults to the range of all bars.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub range: Option<(i64, i64)>,
+
+    /// Row height (pixels).
+    #[builder]
+    #[prop_or(32.0)]
+    pub row_height: f64,
+
+    /// Width of the row label column (pixels).
+    #[builder]
+    #[prop_or(150.0)]
+    pub label_width: f64,
+
+    /// Allow to move and resize bars.
+    #[builder]
+    #[prop_or_default]
+    pub editable: bool,
+
+    /// Time grid used for moving and resizing bars (seconds, default 5 minutes).
+    #[builder]
+    #[prop_or(300)]
+    pub snap: i64,
+
+    /// Show the current time indicator.
+    #[builder]
+    #[prop_or(true)]
+    pub show_now: bool,
+
+    /// Emitted when the user moves or resizes a bar.
+    #[builder_cb(IntoEventCallback, into_event_callback, GanttBarChange)]
+    #[prop_or_default]
+    pub on_bar_change: Option<Callback<GanttBarChange>>,
+}
+
+impl Gantt {
+    /// Create a new instance.
+    pub fn new(rows: impl Into<Rc<Vec<GanttRow>>>) -> Self {
+        yew::props!(Self { rows: rows.into() })
+    }
+
+    /// Builder style method to set the rows.
+    pub fn rows(mut self, rows: impl Into<Rc<Vec<GanttRow>>>) -> Self {
+        self.set_rows(rows);
+        self
+    }
+
+    /// Method to set the rows.
+    pub fn set_rows(&mut self, rows: impl Into<Rc<Vec<GanttRow>>>) {
+        self.rows = rows.into();
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DragMode {
+    Move,
+    ResizeStart,
+    ResizeEnd,
+}
+
+struct BarDrag {
+    row: usize,
+    bar: usize,
+    mode: DragMode,
+    origin_x: f64,
+    start: i64,
+    end: i64,
+}
+
+// Returns the new bar range, snapped to the time grid.
+fn apply_drag(mode: DragMode, start: i64, end: i64, delta: f64, snap: i64) -> (i64, i64) {
+    let snap = snap.max(1);
+    let round = |t: f64| ((t / snap as f64).round() as i64) * snap;
+    match mode {
+        DragMode::Move => {
+            let shift = round(delta);
+            (start + shift, end + shift)
+        }
+        DragMode::ResizeStart => (round(start as f64 + delta).min(end - snap), end),
+        DragMode::ResizeEnd => (start, round(end as f64 + delta).max(start + snap)),
+    }
+}
+
+// Smallest tick step with enough space between the ticks.
+fn choose_tick_step(range: f64, width: f64) -> i64 {
+    let max_ticks = (width / MIN_TICK_SPACING).max(1.0);
+    for step in TICK_STEPS {
+        if range / (*step as f64) <= max_ticks {
+            return *step;
+        }
+    }
+    *TICK_STEPS.last().unwrap()
+}
+
+// Ticks inside [start, end], aligned to the local time (`tz_offset` in seconds).
+fn compute_ticks(start: f64, end: f64, step: i64, tz_offset: i64) -> Vec<i64> {
+    let step_f = step as f64;
+    let mut tick = ((start + tz_offset as f64) / step_f).ceil() as i64 * step - tz_offset;
+    let mut ticks = Vec::new();
+    while (tick as f64) <= end {
+        ticks.push(tick);
+        tick += step;
+    }
+    ticks
+}
+
+// Default visible range (all bars, with some padding).
+fn default_range(rows: &[GanttRow], now: f64) -> (f64, f64) {
+    let mut bars = rows.iter().flat_map(|row| row.bars.iter());
+    let Some(first) = bars.next() else {
+        return (now - 12.0 * 3600.0, now + 12.0 * 3600.0);
+    };
+    let (min, max) = bars.fold((first.start, first.end), |(min, max), bar| {
+        (min.min(bar.start), max.max(bar.end))
+    });
+    let padding = ((max - min) as f64 * 0.05).max(MIN_RANGE);
+    (min as f64 - padding, max as f64 + padding)
+}
+
+fn format_tick(time: i64, step: i64) -> String {
+    let date = Date::new(&JsValue::from_f64(time as f64 * 1000.0));
+    if step >= 86400 {
+        format!(
+            "{:04}-{:02}-{:02}",
+            date.get_full_year(),
+            date.get_month() + 1,
+            date.get_date()
+        )
+    } else {
+        format!("{:02}:{:02}", date.get_hours(), date.get_minutes())
+    }
+}
+
+fn local_tz_offset() -> i64 {
+    // getTimezoneOffset() returns (UTC - local) in minutes
+    -(Date::new_0().get_timezone_offset() as i64) * 60
+}
+
+fn now_secs() -> f64 {
+    Date::now() / 1000.0
+}
+
+pub enum Msg {
+    Resize(f64, f64),
+    Tick,
+    Wheel(bool, i32),                                 // zoom in?, client x
+    BarPointerDown(usize, usize, DragMode, i32, i32), // row, bar, mode, pointer id, client x
+    PanStart(i32, i32),                               // pointer id, client x
+    PointerMove(i32),
+    PointerUp,
+    ResetView,
+}
+
+#[doc(hidden)]
+pub struct PwtGantt {
+    width: f64,
+    view_start: f64,
+    view_end: f64,
+    now: f64,
+    drag: Option<BarDrag>,
+    pan_x: Option<f64>,
+    container_ref: NodeRef,
+    svg_ref: NodeRef,
+    size_observer: Option<DomSizeObserver>,
+    _tick: Interval,
+}
+
+impl PwtGantt {
+    fn reset_view(&mut self, props: &Gantt) {
+        let (start, end) = match props.range {
+            Some((start, end)) => (start as f64, end as f64),
+            None => default_range(&props.rows, self.now),
+        };
+        self.view_start = start;
+        self.view_end = end.max(start + MIN_RANGE);
+    }
+
+    fn chart_width(&self, props: &Gantt) -> f64 {
+        (self.width - props.label_width).max(1.0)
+    }
+
+    // seconds per pixel
+    fn scale(&self, props: &Gantt) -> f64 {
+        (self.view_end - self.view_start) / self.chart_width(props)
+    }
+
+    fn time_to_x(&self, props: &Gantt, time: f64) -> f64 {
+        props.label_width + (time - self.view_start) / self.scale(props)
+    }
+
+    // client x to canvas x
+    fn local_x(&self, client_x: i32) -> f64 {
+        match self.svg_ref.cast::<web_sys::Element>() {
+            Some(el) => client_x as f64 - el.get_bounding_client_rect().left(),
+            None => client_x as f64,
+        }
+    }
+
+    fn render_axis(&self, props: &Gantt, height: f64) -> Group {
+        let step = choose_tick_step(self.view_end - self.view_start, self.chart_width(props));
+        let ticks = compute_ticks(self.view_start, self.view_end, step, local_tz_offset());
+
+        let mut axis = Group::new().class("pwt-gantt-axis");
+        for tick in ticks {
+            let x = self.time_to_x(props, tick as f64);
+            axis.add_child(
+                Line::new(x as f32, AXIS_HEIGHT as f32, x as f32, height as f32)
+                    .class("pwt-gantt-grid"),
+            );
+            axis.add_child(
+                Text::new(format_tick(tick, step))
+                    .position(x, AXIS_HEIGHT / 2.0)
+                    .attribute("text-anchor", "middle")
+                    .attribute("dominant-baseline", "middle"),
+            );
+        }
+        axis
+    }
+
+    fn render_bar(
+        &self,
+        ctx: &Context<Self>,
+        row_index: usize,
+        bar_index: usize,
+        bar: &GanttBar,
+        y: f64,
+    ) -> Option<Group> {
+        let props = ctx.props();
+
+        let (start, end) = match &self.drag {
+            Some(drag) if drag.row == row_index && drag.bar == bar_index => (drag.start, drag.end),
+            _ => (bar.start, bar.end),
+        };
+
+        let x1 = self.time_to_x(props, start as f64);
+        let x2 = self.time_to_x(props, end as f64);
+        if x2 < props.label_width || x1 > self.width {
+            return None;
+        }
+        let x1 = x1.max(props.label_width);
+        let x2 = x2.min(self.width);
+        let bar_width = (x2 - x1).max(1.0);
+        let bar_height = props.row_height * 0.6;
+        let bar_y = y + (props.row_height - bar_height) / 2.0;
+
+        let editable = props.editable && bar.editable;
+        let dragging =
+            matches!(&self.drag, Some(drag) if drag.row == row_index && drag.bar == bar_index);
+
+        let title = bar.title.clone().unwrap_or_default();
+        let time_text = format!("{} - {}", format_tick(start, 1), format_tick(end, 1));
+
+        let on_pointerdown = |mode: DragMode| {
+            ctx.link().callback(move |event: PointerEvent| {
+                event.stop_propagation();
+                Msg::BarPointerDown(
+                    row_index,
+                    bar_index,
+                    mode,
+                    event.pointer_id(),
+                    event.client_x(),
+                )
+            })
+        };
+
+        let mut rect = Rect::new()
+            .class("pwt-gantt-bar")
+            .class(dragging.then_some("dragging"))
+            .position(x1, bar_y)
+            .width(bar_width)
+            .height(bar_height)
+            .fill(
+                bar.color
+                    .clone()
+                    .unwrap_or(AttrValue::Static("var(--pwt-color-primary)")),
+            );
+        if editable {
+            rect = rect
+                .style("cursor", "move")
+                .onpointerdown(on_pointerdown(DragMode::Move));
+        }
+
+        let mut group = Group::new()
+            .key(bar.key.clone())
+            .attribute("role", "img")
+            .attribute("aria-label", format!("{title} ({time_text})"))
+            .with_child(rect);
+
+        if !title.is_empty() && bar_width > (title.len() as f64 + 1.0) * CHAR_WIDTH {
+            group.add_child(
+                Text::new(title)
+                    .class("pwt-gantt-bar-label")
+                    .position(x1 + CHAR_WIDTH / 2.0, y + props.row_height / 2.0)
+                    .attribute("dominant-baseline", "middle")
+                    .attribute("pointer-events", "none"),
+            );
+        }
+
+        if editable {
+            for (mode, x) in [
+                (DragMode::ResizeStart, x1),
+                (DragMode::ResizeEnd, x2 - HANDLE_WIDTH),
+            ] {
+                group.add_child(
+                    Rect::new()
+                        .class("pwt-gantt-bar-handle")
+                        .position(x, bar_y)
+                        .width(HANDLE_WIDTH)
+                        .height(bar_height)
+                        .fill("transparent")
+                        .style("cursor", "ew-resize")
+                        .onpointerdown(on_pointerdown(mode)),
+                );
+            }
+        }
+
+        Some(group)
+    }
+}
+
+impl Component for PwtGantt {
+    type Message = Msg;
+    type Properties = Gantt;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let link = ctx.link().clone();
+        let mut me = Self {
+            width: 0.0,
+            view_start: 0.0,
+            view_end: 0.0,
+            now: now_secs(),
+            drag: None,
+            pan_x: None,
+            container_ref: NodeRef::default(),
+            svg_ref: NodeRef::default(),
+            size_observer: None,
+            _tick: Interval::new(TICK_INTERVAL_MS, move || link.send_message(Msg::Tick)),
+        };
+        me.reset_view(ctx.props());
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Resize(width, _height) => {
+                if self.width == width {
+                    return false;
+                }
+                self.width = width;
+            }
+            Msg::Tick => {
+                self.now = now_secs();
+                return props.show_now;
+            }
+            Msg::Wheel(zoom_in, client_x) => {
+                let x = self.local_x(client_x);
+                let anchor = self.view_start + (x - props.label_width).max(0.0) * self.scale(props);
+                let factor = if zoom_in { 1.0 / 1.2 } else { 1.2 };
+                let range = ((self.view_end - self.view_start) * factor).max(MIN_RANGE);
+                let ratio = (anchor - self.view_start) / (self.view_end - self.view_start);
+                self.view_start = anchor - range * ratio;
+                self.view_end = self.view_start + range;
+            }
+            Msg::BarPointerDown(row, bar, mode, pointer_id, client_x) => {
+                let Some(data) = props.rows.get(row).and_then(|r| r.bars.get(bar)) else {
+                    return false;
+                };
+                set_pointer_capture(&self.svg_ref, pointer_id);
+                self.drag = Some(BarDrag {
+                    row,
+                    bar,
+                    mode,
+                    origin_x: self.local_x(client_x),
+                    start: data.start,
+                    end: data.end,
+                });
+            }
+            Msg::PanStart(pointer_id, client_x) => {
+                set_pointer_capture(&self.svg_ref, pointer_id);
+                self.pan_x = Some(self.local_x(client_x));
+                return false;
+            }
+            Msg::PointerMove(client_x) => {
+                let x = self.local_x(client_x);
+                let scale = self.scale(props);
+                if let Some(drag) = &mut self.drag {
+                    let Some(bar) = props.rows.get(drag.row).and_then(|r| r.bars.get(drag.bar))
+                    else {
+                        return false;
+                    };
+                    let delta = (x - drag.origin_x) * scale;
+                    let (start, end) = apply_drag(drag.mode, bar.start, bar.end, delta, props.snap);
+                    if (start, end) == (drag.start, drag.end) {
+                        return false;
+                    }
+                    drag.start = start;
+                    drag.end = end;
+                } else if let Some(last_x) = self.pan_x {
+                    let shift = (x - last_x) * scale;
+                    self.view_start -= shift;
+                    self.view_end -= shift;
+                    self.pan_x = Some(x);
+                } else {
+                    return false;
+                }
+            }
+            Msg::PointerUp => {
+                self.pan_x = None;
+                let Some(drag) = self.drag.take() else {
+                    return false;
+                };
+                if let Some(row) = props.rows.get(drag.row) {
+                    if let Some(bar) = row.bars.get(drag.bar) {
+                        if (drag.start, drag.end) != (bar.start, bar.end) {
+                            if let Some(on_bar_change) = &props.on_bar_change {
+                                on_bar_change.emit(GanttBarChange {
+                                    row: row.key.clone(),
+                                    bar: bar.key.clone(),
+                                    start: drag.start,
+                                    end: drag.end,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            Msg::ResetView => self.reset_view(props),
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.range != old_props.range
+            || (props.range.is_none() && old_props.rows.is_empty() && !props.rows.is_empty())
+        {
+            self.reset_view(props);
+        }
+        if props.rows != old_props.rows {
+            // row/bar indices may have changed
+            self.drag = None;
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let height = AXIS_HEIGHT + props.rows.len() as f64 * props.row_height;
+
+        let mut rows = Group::new().class("pwt-gantt-rows");
+        let mut bars = Group::new().class("pwt-gantt-bars");
+
+        if self.width > props.label_width {
+            for (row_index, row) in props.rows.iter().enumerate() {
+                let y = AXIS_HEIGHT + row_index as f64 * props.row_height;
+                rows.add_child(
+                    Group::new()
+                        .key(row.key.clone())
+                        .class("pwt-gantt-row")
+                        .class((row_index % 2 == 1).then_some("pwt-gantt-row-alt"))
+                        .with_child(
+                            Rect::new()
+                                .position(0, y)
+                                .width(self.width)
+                                .height(props.row_height),
+                        )
+                        .with_child(
+                            Text::new(row.label.clone())
+                                .class("pwt-gantt-row-label")
+                                .position(CHAR_WIDTH, y + props.row_height / 2.0)
+                                .attribute("dominant-baseline", "middle"),
+                        ),
+                );
+                for (bar_index, bar) in row.bars.iter().enumerate() {
+                    if let Some(bar) = self.render_bar(ctx, row_index, bar_index, bar, y) {
+                        bars.add_child(bar);
+                    }
+                }
+            }
+        }
+
+        let now_line = (props.show_now
+            && self.width > props.label_width
+            && self.now >= self.view_start
+            && self.now <= self.view_end)
+            .then(|| {
+                let x = self.time_to_x(props, self.now) as f32;
+                Line::new(x, 0.0, x, height as f32).class("pwt-gantt-now")
+            });
+
+        let canvas = Canvas::new()
+            .class("pwt-gantt-canvas")
+            .attribute("width", "100%")
+            .attribute("height", format!("{height}"))
+            .style("touch-action", "none")
+            .style("cursor", self.pan_x.is_some().then_some("grabbing"))
+            .onwheel_with_passive(
+                {
+                    let link = link.clone();
+                    move |event: WheelEvent| {
+                        event.prevent_default();
+                        link.send_message(Msg::Wheel(event.delta_y() < 0.0, event.client_x()));
+                    }
+                },
+                false,
+            )
+            .onpointerdown(link.callback(|event: PointerEvent| {
+                Msg::PanStart(event.pointer_id(), event.client_x())
+            }))
+            .onpointermove(link.callback(|event: PointerEvent| Msg::PointerMove(event.client_x())))
+            .onpointerup(link.callback(|_| Msg::PointerUp))
+            .onpointercancel(link.callback(|_| Msg::PointerUp))
+            .ondblclick(link.callback(|_| Msg::ResetView))
+            .with_child(rows)
+            .with_child(self.render_axis(props, height))
+            .with_child(bars)
+            .with_optional_child(now_line);
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-gantt")
+            .class(css::Display::Block)
+            .style("overflow", "hidden")
+            .with_child(canvas.into_html_with_ref(self.svg_ref.clone()))
+            .into_html_with_ref(self.container_ref.clone())
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(el) = self.container_ref.cast::<web_sys::Element>() {
+                let link = ctx.link().clone();
+                self.size_observer = Some(DomSizeObserver::new(&el, move |(width, height)| {
+                    link.send_message(Msg::Resize(width, height));
+                }));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_apply_drag() {
+        // move, snapped to 5 minutes
+        assert_eq!(
+            apply_drag(DragMode::Move, 3600, 7200, 320.0, 300),
+            (3900, 7500)
+        );
+        assert_eq!(
+            apply_drag(DragMode::Move, 3600, 7200, -100.0, 300),
+            (3600, 7200)
+        );
+
+        // resize keeps at least one grid step
+        assert_eq!(
+            apply_drag(DragMode::ResizeStart, 3600, 7200, 1000.0, 300),
+            (4500, 7200)
+        );
+        assert_eq!(
+            apply_drag(DragMode::ResizeStart, 3600, 7200, 10000.0, 300),
+            (6900, 7200)
+        );
+        assert_eq!(
+            apply_drag(DragMode::ResizeEnd, 3600, 7200, -10000.0, 300),
+            (3600, 3900)
+        );
+
+        // snap <= 0 is treated as 1 second
+        assert_eq!(apply_drag(DragMode::Move, 10, 20, 2.6, 0), (13, 23));
+    }
+
+    #[test]
+    fn test_ticks() {
+        assert_eq!(choose_tick_step(3600.0, 800.0), 900);
+        assert_eq!(choose_tick_step(86400.0, 800.0), 3 * 3600);
+        assert_eq!(choose_tick_step(1e9, 800.0), 30 * 86400);
+
+        assert_eq!(compute_ticks(100.0, 1000.0, 300, 0), vec![300, 600, 900]);
+        // aligned to local time (UTC+1 minute)
+        assert_eq!(compute_ticks(100.0, 1000.0, 300, 60), vec![240, 540, 840]);
+    }
+
+    #[test]
+    fn test_default_range() {
+        let rows = vec![
+            GanttRow::new("a", "a").with_bar(GanttBar::new("1", 1000, 2000)),
+            GanttRow::new("b", "b").with_bar(GanttBar::new("2", 3000, 11000)),
+        ];
+        assert_eq!(default_range(&rows, 0.0), (500.0, 11500.0));
+        assert_eq!(default_range(&[], 86400.0), (43200.0, 129600.0));
+    }
+}
@@ -0,0 +1,81 @@
+use yew::virtual_dom::Key;
+
+use crate::prelude::*;
+use crate::widget::canvas::{Circle, Group, Text};
+use crate::widget::charts::Coordinates;
+
+/// Contains the node rendered on the graph and suggested properties,
+/// such as the radius, if the node is selected and where its center is.
+#[derive(Clone)]
+pub struct GraphNodeRenderArgs<'a, N: GraphNodeData> {
+    pub record: &'a N,
+    pub center: Coordinates,
+    pub selected: bool,
+    pub suggested_radius: f64,
+}
+
+/// The default renderer for a graph node (circle with the title below).
+pub fn render_node_default<N: GraphNodeData>(args: &GraphNodeRenderArgs<N>) -> Group {
+    Group::new()
+        .class("pwt-graph-node")
+        .class(args.selected.then_some("selected"))
+        .with_child(
+            Circle::new()
+                .cx(args.center.x)
+                .cy(args.center.y)
+                .r(args.suggested_radius),
+        )
+        .with_child(
+            Text::new(args.record.render_title())
+                .position(args.center.x, args.center.y + args.suggested_radius + 14.0)
+                .attribute("text-anchor", "middle"),
+        )
+}
+
+/// The default tooltip renderer for a graph node.
+pub fn render_node_tooltip_default<N: GraphNodeData>(args: &GraphNodeRenderArgs<N>) -> Html {
+    html! {<span>{args.record.render_title()}</span>}
+}
+
+/// Node data of a [Graph](super::Graph), providing the title and the render
+/// hooks used to draw the node and its tooltip.
+pub trait GraphNodeData: 'static {
+    /// Get the title of the node.
+    fn render_title(&self) -> AttrValue;
+
+    /// Render the node.
+    ///
+    /// Uses [render_node_default] by default.
+    fn render_node(args: &GraphNodeRenderArgs<Self>) -> Group
+    where
+        Self: Sized,
+    {
+        render_node_default(args)
+    }
+
+    /// Render the tooltip for a node.
+    ///
+    /// Uses [render_node_tooltip_default] by default.
+    fn render_tooltip(args: &GraphNodeRenderArgs<Self>) -> Html
+    where
+        Self: Sized,
+    {
+        render_node_tooltip_default(args)
+    }
+}
+
+/// Edge data of a [Graph](super::Graph).
+///
+/// Edges reference their nodes by the key of the node store.
+pub trait GraphEdgeData: 'static {
+    /// The key of the source node.
+    fn source(&self) -> Key;
+
+    /// The key of the target node.
+    fn target(&self) -> Key;
+
+    /// Optional label, displayed in the middle of the edge.
+    fn render_title(&self) -> Option<AttrValue> {
+        None
+    }
+}
@@ -0,0 +1,275 @@
+use crate::widget::charts::Coordinates;
+
+// number of force layout iterations
+const FORCE_ITERATIONS: usize = 150;
+
+/// Automatic layout algorithm used by the [Graph](super::Graph).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum GraphLayout {
+    /// Force directed layout (Fruchterman-Reingold). Useful for
+    /// networks without a natural direction.
+    #[default]
+    Force,
+    /// Layered layout (top to bottom, following the edge direction).
+    /// Useful for hierarchies and directed graphs.
+    Layered,
+}
+
+/// Computes node positions inside a `width` x `height` area.
+///
+/// The edges are pairs of node indices. The result is deterministic,
+/// so the layout does not jump around on data updates.
+pub(crate) fn compute_layout(
+    layout: GraphLayout,
+    node_count: usize,
+    edges: &[(usize, usize)],
+    width: f64,
+    height: f64,
+    margin: f64,
+) -> Vec<Coordinates> {
+    let area = Area {
+        x_min: margin.min(width / 2.0),
+        y_min: margin.min(height / 2.0),
+        x_max: (width - margin).max(width / 2.0),
+        y_max: (height - margin).max(height / 2.0),
+    };
+
+    match node_count {
+        0 => Vec::new(),
+        1 => vec![area.center()],
+        _ => match layout {
+            GraphLayout::Force => force_layout(node_count, edges, &area),
+            GraphLayout::Layered => layered_layout(node_count, edges, &area),
+        },
+    }
+}
+
+struct Area {
+    x_min: f64,
+    y_min: f64,
+    x_max: f64,
+    y_max: f64,
+}
+
+impl Area {
+    fn width(&self) -> f64 {
+        self.x_max - self.x_min
+    }
+
+    fn height(&self) -> f64 {
+        self.y_max - self.y_min
+    }
+
+    fn center(&self) -> Coordinates {
+        Coordinates {
+            x: (self.x_min + self.x_max) / 2.0,
+            y: (self.y_min + self.y_max) / 2.0,
+        }
+    }
+
+    fn clamp(&self, point: Coordinates) -> Coordinates {
+        Coordinates {
+            x: point.x.clamp(self.x_min, self.x_max),
+            y: point.y.clamp(self.y_min, self.y_max),
+        }
+    }
+}
+
+fn force_layout(node_count: usize, edges: &[(usize, usize)], area: &Area) -> Vec<Coordinates> {
+    let center = area.center();
+    let radius = area.width().min(area.height()) / 2.0;
+
+    // start on a circle
+    let mut pos: Vec<Coordinates> = (0..node_count)
+        .map(|i| {
+            let angle = std::f64::consts::TAU * i as f64 / node_count as f64;
+            Coordinates {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        })
+        .collect();
+
+    let k = (area.width() * area.height() / node_count as f64).sqrt();
+    let start_temperature = area.width().max(area.height()) / 10.0;
+
+    for iteration in 0..FORCE_ITERATIONS {
+        let mut disp = vec![(0.0, 0.0); node_count];
+
+        // repulsive forces between all nodes
+        for i in 0..node_count {
+            for j in (i + 1)..node_count {
+                let dx = pos[i].x - pos[j].x;
+                let dy = pos[i].y - pos[j].y;
+                let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+                let force = k * k / dist;
+                let (fx, fy) = (dx / dist * force, dy / dist * force);
+                disp[i].0 += fx;
+                disp[i].1 += fy;
+                disp[j].0 -= fx;
+                disp[j].1 -= fy;
+            }
+        }
+
+        // attractive forces along the edges
+        for &(u, v) in edges {
+            if u == v || u >= node_count || v >= node_count {
+                continue;
+            }
+            let dx = pos[u].x - pos[v].x;
+            let dy = pos[u].y - pos[v].y;
+            let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+            let force = dist * dist / k;
+            let (fx, fy) = (dx / dist * force, dy / dist * force);
+            disp[u].0 -= fx;
+            disp[u].1 -= fy;
+            disp[v].0 += fx;
+            disp[v].1 += fy;
+        }
+
+        // move, limited by the (cooling) temperature
+        let temperature =
+            start_temperature * (1.0 - iteration as f64 / FORCE_ITERATIONS as f64).max(0.01);
+        for (point, (dx, dy)) in pos.iter_mut().zip(disp) {
+            let len = (dx * dx + dy * dy).sqrt();
+            if len > 0.0 {
+                let step = len.min(temperature);
+                *point = area.clamp(Coordinates {
+                    x: point.x + dx / len * step,
+                    y: point.y + dy / len * step,
+                });
+            }
+        }
+    }
+
+    pos
+}
+
+fn layered_layout(node_count: usize, edges: &[(usize, usize)], area: &Area) -> Vec<Coordinates> {
+    let edges: Vec<(usize, usize)> = edges
+        .iter()
+        .copied()
+        .filter(|&(u, v)| u != v && u < node_count && v < node_count)
+        .collect();
+
+    // longest path layering (bounded, so that cycles terminate)
+    let mut layer = vec![0usize; node_count];
+    for _ in 0..node_count {
+        let mut changed = false;
+        for &(u, v) in &edges {
+            if layer[v] <= layer[u] && layer[u] + 1 < node_count {
+                layer[v] = layer[u] + 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let layer_count = layer.iter().max().map(|max| max + 1).unwrap_or(1);
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+    for (node, &l) in layer.iter().enumerate() {
+        layers[l].push(node);
+    }
+
+    // order nodes by the barycenter of their predecessors to reduce crossings
+    let mut order = vec![0.0f64; node_count];
+    for nodes in layers.iter_mut() {
+        for node in nodes.iter() {
+            let preds: Vec<f64> = edges
+                .iter()
+                .filter(|&&(u, v)| v == *node && layer[u] < layer[v])
+                .map(|&(u, _)| order[u])
+                .collect();
+            if !preds.is_empty() {
+                order[*node] = preds.iter().sum::<f64>() / preds.len() as f64;
+            } else {
+                order[*node] = f64::MAX; // keep nodes without predecessors at the end
+            }
+        }
+        nodes.sort_by(|a, b| order[*a].total_cmp(&order[*b]).then(a.cmp(b)));
+        for (pos, node) in nodes.iter().enumerate() {
+            order[*node] = pos as f64 / nodes.len() as f64;
+        }
+    }
+
+    let mut pos = vec![area.center(); node_count];
+    for (l, nodes) in layers.iter().enumerate() {
+        let y = if layer_count > 1 {
+            area.y_min + area.height() * l as f64 / (layer_count - 1) as f64
+        } else {
+            area.center().y
+        };
+        for (i, node) in nodes.iter().enumerate() {
+            let x = area.x_min + area.width() * (i + 1) as f64 / (nodes.len() + 1) as f64;
+            pos[*node] = Coordinates { x, y };
+        }
+    }
+
+    pos
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn distance(a: Coordinates, b: Coordinates) -> f64 {
+        ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_layered_layout() {
+        // a -> b -> c, a -> d
+        let edges = [(0, 1), (1, 2), (0, 3)];
+        let pos = compute_layout(GraphLayout::Layered, 4, &edges, 100.0, 100.0, 10.0);
+
+        assert_eq!(pos[0].y, 10.0);
+        assert_eq!(pos[1].y, 50.0);
+        assert_eq!(pos[3].y, 50.0);
+        assert_eq!(pos[2].y, 90.0);
+        assert!(pos[1].x != pos[3].x);
+
+        // cycles terminate
+        let pos = compute_layout(
+            GraphLayout::Layered,
+            2,
+            &[(0, 1), (1, 0)],
+            100.0,
+            100.0,
+            0.0,
+        );
+        assert_eq!(pos.len(), 2);
+    }
+
+    #[test]
+    fn test_force_layout() {
+        // two connected pairs
+        let edges = [(0, 1), (2, 3)];
+        let pos = compute_layout(GraphLayout::Force, 4, &edges, 200.0, 100.0, 10.0);
+
+        for point in &pos {
+            assert!((10.0..=190.0).contains(&point.x));
+            assert!((10.0..=90.0).contains(&point.y));
+        }
+
+        // connected nodes are closer together
+        assert!(distance(pos[0], pos[1]) < distance(pos[0], pos[2]));
+        assert!(distance(pos[2], pos[3]) < distance(pos[1], pos[3]));
+
+        // deterministic
+        assert_eq!(
+            pos,
+            compute_layout(GraphLayout::Force, 4, &edges, 200.0, 100.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn test_trivial_layouts() {
+        assert!(compute_layout(GraphLayout::Force, 0, &[], 100.0, 100.0, 0.0).is_empty());
+        assert_eq!(
+            compute_layout(GraphLayout::Layered, 1, &[], 100.0, 50.0, 0.0),
+            vec![Coordinates { x: 50.0, y: 25.0 }]
+        );
+    }
+}
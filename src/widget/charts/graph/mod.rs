@@ -0,0 +1,582 @@
+//! Graph (network topology)
+//!
+//! Displays nodes and the edges between them, for example network
+//! or cluster topologies.
+//!
+//! Includes the following features
+//! * automatic layout (force directed or layered)
+//! * zooming and panning (mouse wheel, drag, pinch zoom)
+//! * node selection (mouse and keyboard)
+//! * custom node and tooltip renderers (see [GraphNodeData])
+
+use std::collections::HashMap;
+
+use derivative::Derivative;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::Key;
+
+use crate::dom::align::align_to_xy;
+use crate::prelude::*;
+use crate::state::{Store, StoreObserver};
+use crate::touch::{GestureDetector, GestureDragEvent, GesturePhase, GesturePinchZoomEvent};
+use crate::widget::canvas::{Canvas, Group, Line, Text};
+use crate::widget::charts::Coordinates;
+use crate::widget::charts::map::{ZoomAction, ZoomInfo};
+use crate::widget::{Button, Container, Row, SizeObserver, Tooltip};
+use crate::{client_to_svg_coords, css};
+
+use pwt_macros::{builder, widget};
+
+mod graph_data;
+pub use graph_data::{
+    GraphEdgeData, GraphNodeData, GraphNodeRenderArgs, render_node_default,
+    render_node_tooltip_default,
+};
+
+mod layout;
+pub use layout::GraphLayout;
+use layout::compute_layout;
+
+/// Interactive graph showing nodes and the edges between them.
+///
+/// Nodes and edges are read from a [Store]. Edges reference their source
+/// and target nodes by the store key (see [GraphEdgeData]). Edges with
+/// unknown nodes are ignored. The graph is laid out automatically, and
+/// the layout is recomputed whenever a store changes.
+///
+/// Can handle touch and mouse input.
+#[widget(pwt=crate, comp=PwtGraph<N, E>, @element)]
+#[derive(Derivative, Properties)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+#[builder]
+pub struct Graph<N: GraphNodeData, E: GraphEdgeData> {
+    // The nodes.
+    nodes: Store<N>,
+
+    // The edges.
+    edges: Store<E>,
+
+    #[prop_or_default]
+    #[builder]
+    /// The layout algorithm.
+    layout: GraphLayout,
+
+    #[prop_or(1000.0)]
+    #[builder]
+    /// The width of the layout area. Used for coordinates and scaling.
+    width: f64,
+
+    #[prop_or(600.0)]
+    #[builder]
+    /// The height of the layout area. Used for coordinates and scaling.
+    height: f64,
+
+    #[prop_or(10.0)]
+    #[builder]
+    /// The maximum zoom level that is allowed.
+    max_zoom_level: f64,
+
+    #[prop_or(12.0)]
+    #[builder]
+    /// The node radius (screen pixels).
+    node_radius: f64,
+
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    /// The selected node (key). Only used to initialize the selection.
+    default_selection: Option<Key>,
+
+    #[builder_cb(IntoEventCallback, into_event_callback, Option<Key>)]
+    #[prop_or_default]
+    /// Emitted when the user selects a node (or clears the selection).
+    on_select: Option<Callback<Option<Key>>>,
+}
+
+impl<N: GraphNodeData, E: GraphEdgeData> Graph<N, E> {
+    /// Creates a new graph with the given node and edge stores.
+    pub fn new(nodes: Store<N>, edges: Store<E>) -> Self {
+        yew::props!(Self { nodes, edges })
+    }
+}
+
+pub enum Msg {
+    DataChange,
+    WheelZoom(ZoomAction, i32, i32),
+    ButtonZoom(ZoomAction),
+    PinchZoom(GesturePinchZoomEvent),
+    Drag(GestureDragEvent),
+    Resize(f64, f64),
+    Tooltip(Option<(usize, i32, i32)>),
+    Select(Option<Key>),
+    BackgroundClick,
+}
+
+#[doc(hidden)]
+pub struct PwtGraph<N: GraphNodeData, E: GraphEdgeData> {
+    zoom: ZoomInfo,
+    pinch_start_scale: f64,
+    pinch_last_center: Coordinates,
+    fit_scale: f64,
+    svg_ref: NodeRef,
+    tooltip: Option<(usize, i32, i32)>,
+    tooltip_ref: NodeRef,
+    grab_start: Option<(f64, f64)>,
+    // set after a pan, so that the trailing click does not clear the selection
+    dragged: bool,
+    selection: Option<Key>,
+    // node keys and positions (in graph coordinates), same order as the node store
+    node_keys: Vec<Key>,
+    positions: Vec<Coordinates>,
+    // edge index, source and target node index
+    edge_list: Vec<(usize, usize, usize)>,
+    _nodes_observer: StoreObserver<N>,
+    _edges_observer: StoreObserver<E>,
+}
+
+impl<N: GraphNodeData, E: GraphEdgeData> PwtGraph<N, E> {
+    fn update_layout(&mut self, ctx: &Context<Self>) {
+        let props = ctx.props();
+        let nodes = props.nodes.read();
+        let edges = props.edges.read();
+
+        self.node_keys = nodes
+            .iter()
+            .map(|node| props.nodes.extract_key(node))
+            .collect();
+
+        let index_map: HashMap<&Key, usize> = self
+            .node_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key, i))
+            .collect();
+
+        self.edge_list = edges
+            .iter()
+            .enumerate()
+            .filter_map(|(i, edge)| {
+                let source = *index_map.get(&edge.source())?;
+                let target = *index_map.get(&edge.target())?;
+                Some((i, source, target))
+            })
+            .collect();
+
+        let edge_pairs: Vec<(usize, usize)> = self
+            .edge_list
+            .iter()
+            .map(|&(_, source, target)| (source, target))
+            .collect();
+
+        self.positions = compute_layout(
+            props.layout,
+            self.node_keys.len(),
+            &edge_pairs,
+            props.width,
+            props.height,
+            props.node_radius * 3.0,
+        );
+
+        if let Some(selection) = &self.selection {
+            if !self.node_keys.contains(selection) {
+                self.selection = None;
+                if let Some(on_select) = &props.on_select {
+                    on_select.emit(None);
+                }
+            }
+        }
+
+        self.tooltip = None;
+    }
+
+    fn create_tooltip(&self, args: &GraphNodeRenderArgs<N>) -> Html {
+        Container::new()
+            .attribute("role", "tooltip")
+            .attribute("aria-live", "polite")
+            .attribute("data-show", Some(""))
+            .class("pwt-tooltip")
+            .class("pwt-tooltip-rich")
+            .with_child(N::render_tooltip(args))
+            .into_html_with_ref(self.tooltip_ref.clone())
+    }
+
+    fn render_edges(&self, ctx: &Context<Self>) -> Group {
+        let edges = ctx.props().edges.read();
+
+        let mut group = Group::new().class("pwt-graph-edges");
+        let mut labels = Group::new().class("pwt-graph-edge-labels");
+
+        for &(index, source, target) in &self.edge_list {
+            let p1 = self.zoom.map_point(self.positions[source]);
+            let p2 = self.zoom.map_point(self.positions[target]);
+
+            if self.zoom.is_out_of_bounds(p1) && self.zoom.is_out_of_bounds(p2) {
+                continue;
+            }
+
+            group.add_child(Line::new(
+                p1.x as f32,
+                p1.y as f32,
+                p2.x as f32,
+                p2.y as f32,
+            ));
+
+            if let Some(title) = edges.get(index).and_then(|edge| edge.render_title()) {
+                labels.add_child(
+                    Text::new(title)
+                        .position((p1.x + p2.x) / 2.0, (p1.y + p2.y) / 2.0)
+                        .attribute("text-anchor", "middle"),
+                );
+            }
+        }
+
+        group.with_child(labels)
+    }
+}
+
+impl<N: GraphNodeData, E: GraphEdgeData> Component for PwtGraph<N, E> {
+    type Message = Msg;
+    type Properties = Graph<N, E>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+
+        let _nodes_observer = props
+            .nodes
+            .add_listener(ctx.link().callback(|_| Msg::DataChange));
+        let _edges_observer = props
+            .edges
+            .add_listener(ctx.link().callback(|_| Msg::DataChange));
+
+        let mut me = Self {
+            zoom: ZoomInfo::new(props.width, props.height, props.max_zoom_level),
+            pinch_start_scale: 1.0,
+            pinch_last_center: Coordinates { x: 0.0, y: 0.0 },
+            fit_scale: 1.0,
+            svg_ref: NodeRef::default(),
+            tooltip: None,
+            tooltip_ref: NodeRef::default(),
+            grab_start: None,
+            dragged: false,
+            selection: props.default_selection.clone(),
+            node_keys: Vec::new(),
+            positions: Vec::new(),
+            edge_list: Vec::new(),
+            _nodes_observer,
+            _edges_observer,
+        };
+        me.update_layout(ctx);
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::DataChange => self.update_layout(ctx),
+            Msg::WheelZoom(change, x, y) => {
+                let Some(svg) = self.svg_ref.get() else {
+                    return false;
+                };
+                let coords = client_to_svg_coords(&svg, x as f64, y as f64);
+                return self.zoom.update_zoom(change, coords[0], coords[1]);
+            }
+            Msg::ButtonZoom(change) => {
+                return self
+                    .zoom
+                    .update_zoom(change, props.width / 2.0, props.height / 2.0);
+            }
+            Msg::PinchZoom(event) => {
+                let Some(svg) = self.svg_ref.get() else {
+                    return false;
+                };
+                let coords = client_to_svg_coords(
+                    &svg,
+                    (event.point0.x + event.point1.x) as f64 / 2.0,
+                    (event.point0.y + event.point1.y) as f64 / 2.0,
+                );
+                let (x, y) = (coords[0], coords[1]);
+                match event.phase {
+                    GesturePhase::Start => {
+                        self.pinch_start_scale = self.zoom.get_zoom_level();
+                        self.pinch_last_center = Coordinates { x, y };
+                    }
+                    GesturePhase::Update => {
+                        self.zoom
+                            .move_pan(x - self.pinch_last_center.x, y - self.pinch_last_center.y);
+                        self.pinch_last_center = Coordinates { x, y };
+                        self.zoom.update_zoom(
+                            ZoomAction::Scale(self.pinch_start_scale * event.scale),
+                            x,
+                            y,
+                        );
+                    }
+                    GesturePhase::End => return false,
+                }
+            }
+            Msg::Drag(event) => match event.phase {
+                GesturePhase::Start => {
+                    self.dragged = true;
+                    self.grab_start = Some((event.x() as f64, event.y() as f64));
+                }
+                GesturePhase::Update => {
+                    if let Some((start_x, start_y)) = self.grab_start {
+                        let x = event.x() as f64;
+                        let y = event.y() as f64;
+                        self.zoom.move_pan(
+                            (x - start_x) / self.fit_scale,
+                            (y - start_y) / self.fit_scale,
+                        );
+                        self.grab_start = Some((x, y));
+                    }
+                }
+                GesturePhase::End => {
+                    self.grab_start = None;
+                }
+            },
+            Msg::Resize(real_width, real_height) => {
+                if real_width <= 0.0 || real_height <= 0.0 {
+                    return false;
+                }
+                self.fit_scale = (real_width / props.width).min(real_height / props.height);
+            }
+            Msg::Tooltip(index) => {
+                if index.is_none() && self.tooltip.is_none() {
+                    return false;
+                }
+                self.tooltip = index;
+            }
+            Msg::Select(selection) => {
+                if self.selection == selection {
+                    return false;
+                }
+                self.selection = selection.clone();
+                if let Some(on_select) = &props.on_select {
+                    on_select.emit(selection);
+                }
+            }
+            Msg::BackgroundClick => {
+                // a pan emits a trailing click; consume it instead of clearing the selection
+                if self.dragged {
+                    self.dragged = false;
+                    return false;
+                }
+                ctx.link().send_message(Msg::Select(None));
+                return false;
+            }
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+
+        if props.nodes != old_props.nodes {
+            self._nodes_observer = props
+                .nodes
+                .add_listener(ctx.link().callback(|_| Msg::DataChange));
+        }
+        if props.edges != old_props.edges {
+            self._edges_observer = props
+                .edges
+                .add_listener(ctx.link().callback(|_| Msg::DataChange));
+        }
+
+        if props.width != old_props.width
+            || props.height != old_props.height
+            || props.max_zoom_level != old_props.max_zoom_level
+        {
+            self.zoom = ZoomInfo::new(props.width, props.height, props.max_zoom_level);
+        }
+
+        if props.nodes != old_props.nodes
+            || props.edges != old_props.edges
+            || props.layout != old_props.layout
+            || props.width != old_props.width
+            || props.height != old_props.height
+            || props.node_radius != old_props.node_radius
+        {
+            self.update_layout(ctx);
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let width = props.width;
+        let height = props.height;
+
+        let effective_radius = props.node_radius / self.fit_scale;
+
+        let zoom_level = self.zoom.get_zoom_level();
+        let is_zoomed = zoom_level != 1.0;
+        let fully_zoomed = zoom_level >= props.max_zoom_level;
+
+        let svg = Canvas::new()
+            .onclick(link.callback(|_| Msg::BackgroundClick))
+            .onwheel_with_passive(
+                {
+                    let link = link.clone();
+                    move |event: WheelEvent| {
+                        event.prevent_default();
+                        let (delta, x, y) = (event.delta_y(), event.client_x(), event.client_y());
+                        let action = if delta < 0.0 {
+                            ZoomAction::In
+                        } else {
+                            ZoomAction::Out
+                        };
+                        link.send_message(Msg::WheelZoom(action, x, y));
+                    }
+                },
+                false,
+            )
+            .style(
+                "cursor",
+                match (is_zoomed, self.grab_start.is_some()) {
+                    (true, true) => Some("grabbing"),
+                    (true, false) => Some("grab"),
+                    (false, _) => None,
+                },
+            )
+            .class("pwt-graph")
+            .attribute("viewBox", format!("0 0 {width} {height}"))
+            .with_child(self.render_edges(ctx));
+
+        let nodes = props.nodes.read();
+        let mut node_group = Group::new().class("pwt-graph-nodes");
+        let mut tooltip = None;
+
+        for (index, record) in nodes.iter().enumerate() {
+            let (Some(key), Some(position)) =
+                (self.node_keys.get(index), self.positions.get(index))
+            else {
+                continue;
+            };
+
+            let center = self.zoom.map_point(*position);
+            if self.zoom.is_out_of_bounds(center) {
+                continue;
+            }
+
+            let selected = self.selection.as_ref() == Some(key);
+            let args = GraphNodeRenderArgs {
+                record,
+                center,
+                selected,
+                suggested_radius: effective_radius,
+            };
+
+            let node = N::render_node(&args)
+                .attribute("tabindex", "0")
+                .attribute("role", "button")
+                .attribute("aria-label", record.render_title())
+                .attribute("aria-pressed", selected.to_string())
+                .onpointermove(link.callback(move |event: PointerEvent| {
+                    Msg::Tooltip(Some((index, event.client_x(), event.client_y())))
+                }))
+                .onpointerleave(link.callback(move |_| Msg::Tooltip(None)))
+                .onclick({
+                    let key = key.clone();
+                    link.callback(move |event: MouseEvent| {
+                        // keep the click from reaching the background handler
+                        event.stop_propagation();
+                        Msg::Select(Some(key.clone()))
+                    })
+                })
+                .onkeydown({
+                    let key = key.clone();
+                    let link = link.clone();
+                    move |event: KeyboardEvent| match event.key().as_str() {
+                        "Enter" | " " => {
+                            event.prevent_default();
+                            event.stop_propagation();
+                            link.send_message(Msg::Select(Some(key.clone())));
+                        }
+                        _ => {}
+                    }
+                });
+
+            node_group.add_child(node);
+
+            match &self.tooltip {
+                Some((tooltip_idx, _, _)) if *tooltip_idx == index => {
+                    tooltip = Some(self.create_tooltip(&args));
+                }
+                _ => {}
+            }
+        }
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class(css::Display::Block)
+            .with_child(SizeObserver::new(
+                Container::new()
+                    .class(css::Display::Flex)
+                    .class(css::JustifyContent::Center)
+                    .width("100%")
+                    .height("100%")
+                    .with_child(
+                        GestureDetector::new(
+                            svg.with_child(node_group)
+                                .into_html_with_ref(self.svg_ref.clone()),
+                        )
+                        .on_drag(link.callback(Msg::Drag))
+                        .on_pinch_zoom(link.callback(Msg::PinchZoom)),
+                    ),
+                {
+                    let link = link.clone();
+                    move |(_, _, width, height)| {
+                        link.send_message(Msg::Resize(width, height));
+                    }
+                },
+            ))
+            .with_optional_child(tooltip)
+            .with_child(
+                Row::new()
+                    .gap(1)
+                    .class("pwt-map-interaction-panel")
+                    .with_child(
+                        Tooltip::new(
+                            Button::new_icon("fa fa-arrows-alt")
+                                .class(css::ColorScheme::Primary)
+                                .disabled(!is_zoomed)
+                                .on_activate(link.callback(|_| Msg::ButtonZoom(ZoomAction::Reset))),
+                        )
+                        .tip(tr!("Show whole graph")),
+                    )
+                    .with_child(
+                        Tooltip::new(
+                            Button::new_icon("fa fa-minus")
+                                .class(css::ColorScheme::Primary)
+                                .disabled(!is_zoomed)
+                                .on_activate(link.callback(|_| Msg::ButtonZoom(ZoomAction::Out))),
+                        )
+                        .tip(tr!("Zoom out")),
+                    )
+                    .with_child(
+                        Tooltip::new(
+                            Button::new_icon("fa fa-plus")
+                                .class(css::ColorScheme::Primary)
+                                .disabled(fully_zoomed)
+                                .on_activate(link.callback(|_| Msg::ButtonZoom(ZoomAction::In))),
+                        )
+                        .tip(tr!("Zoom in")),
+                    ),
+            )
+            .into()
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if let &Some((_, x, y)) = &self.tooltip
+            && let Some(el) = self.tooltip_ref.get()
+        {
+            let _ = align_to_xy(
+                el,
+                (x as f64 + 10.0, y as f64 + 10.0),
+                crate::dom::align::Point::TopStart,
+            );
+        }
+    }
+}
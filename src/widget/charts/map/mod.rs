@@ -10,13 +10,12 @@ pub use map_point::{
 };
 
 mod zoom_info;
-use zoom_info::ZoomInfo;
+pub(super) use zoom_info::{ZoomAction, ZoomInfo};
 
 use crate::dom::align::{AlignOptions, align_to, align_to_xy};
 use crate::prelude::*;
 use crate::touch::{GestureDetector, GestureDragEvent, GesturePhase, GesturePinchZoomEvent};
 use crate::widget::canvas::{Canvas, Circle, Group};
-use crate::widget::charts::map::zoom_info::ZoomAction;
 use crate::widget::{Button, Card, Container, Row, SizeObserver, Tooltip};
 use crate::{client_to_svg_coords, css};
 use pwt_macros::{builder, widget};
@@ -421,22 +420,25 @@ impl<T: MapPointData + 'static> yew::Component for MapComp<T> {
                 let link = link.clone();
                 move |_| link.send_message(Msg::CloseInfo)
             })
-            .onwheel({
-                let link = link.clone();
-                move |event: WheelEvent| {
-                    // don't scroll the remaining page when scrolling in map
-                    event.prevent_default();
-                    // ignore delta mode as we zoom in/out in 10% steps later anyway, only the
-                    // direction is relevant here
-                    let (delta, x, y) = (event.delta_y(), event.client_x(), event.client_y());
-                    let action = if delta < 0.0 {
-                        ZoomAction::In
-                    } else {
-                        ZoomAction::Out
-                    };
-                    link.send_message(Msg::WheelZoom(action, x, y));
-                }
-            })
+            .onwheel_with_passive(
+                {
+                    let link = link.clone();
+                    move |event: WheelEvent| {
+                        // don't scroll the remaining page when scrolling in map
+                        event.prevent_default();
+                        // ignore delta mode as we zoom in/out in 10% steps later anyway, only the
+                        // direction is relevant here
+                        let (delta, x, y) = (event.delta_y(), event.client_x(), event.client_y());
+                        let action = if delta < 0.0 {
+                            ZoomAction::In
+                        } else {
+                            ZoomAction::Out
+                        };
+                        link.send_message(Msg::WheelZoom(action, x, y));
+                    }
+                },
+                false,
+            )
             .style(
                 "cursor",
                 match (is_zoomed, self.grab_start.is_some()) {
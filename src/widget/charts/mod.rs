@@ -1,5 +1,18 @@
 //! Chart components
 
+mod gantt;
+#[doc(hidden)]
+pub use gantt::PwtGantt;
+pub use gantt::{Gantt, GanttBar, GanttBarChange, GanttRow};
+
+mod graph;
+#[doc(hidden)]
+pub use graph::PwtGraph;
+pub use graph::{
+    Graph, GraphEdgeData, GraphLayout, GraphNodeData, GraphNodeRenderArgs, render_node_default,
+    render_node_tooltip_default,
+};
+
 mod map;
 pub use map::{
     Coordinates, Map, MapPoint, MapPointData, PointsRenderArgs, render_info_default,
@@ -9,5 +22,10 @@ pub use map::{
 mod pie;
 pub use pie::{LegendPosition, PieChart};
 
+mod tree_map;
+#[doc(hidden)]
+pub use tree_map::PwtTreeMap;
+pub use tree_map::TreeMap;
+
 mod world_map;
 pub use world_map::{Location, WorldMap, WorldPoint};
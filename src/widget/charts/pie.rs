@@ -373,7 +373,7 @@ impl PwtPieChart {
             LegendPosition::Top | LegendPosition::Bottom => css::FlexDirection::Row,
         };
         let legend = Container::new()
-            .class("pwt-gap-1")
+            .class(css::Gap(1))
             .class(css::Display::Flex)
             .class(direction)
             .class(css::AlignItems::Stretch)
@@ -0,0 +1,667 @@
+//! Tree Map
+//!
+//! Visualizes hierarchical data as nested rectangles. The size of each
+//! rectangle is proportional to its value, and the color can be used to
+//! show an additional metric (for example the usage ratio).
+//!
+//! Includes the following features
+//! * squarified layout (rectangles with an aspect ratio close to 1)
+//! * colors derived from an optional metric (heat map)
+//! * rendering tooltips (with an optional custom renderer)
+//! * click on a subtree to zoom in, and a breadcrumb bar to zoom out
+
+use derivative::Derivative;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::Key;
+
+use crate::css;
+use crate::dom::align::align_to_xy;
+use crate::dom::{DomSizeObserver, format_float};
+use crate::prelude::*;
+use crate::props::{IntoOptionalRenderFn, RenderFn};
+use crate::state::{KeyedSlabTreeNodeRef, TreeStore, TreeStoreObserver};
+use crate::widget::canvas::{Canvas, Group, Rect, Text};
+use crate::widget::{Button, Container, Row};
+
+use pwt_macros::{builder, widget};
+
+// default colors (used without metric)
+const DEFAULT_COLORS: &[&str] = &[
+    "var(--pwt-color-primary)",
+    "var(--pwt-color-secondary)",
+    "var(--pwt-color-tertiary)",
+];
+
+// padding between nested rectangles
+const PADDING: f64 = 2.0;
+// height of the subtree label
+const HEADER_HEIGHT: f64 = 16.0;
+// approximate label character width (used to truncate labels)
+const CHAR_WIDTH: f64 = 7.0;
+
+#[widget(pwt=crate, comp=PwtTreeMap<T>, @element)]
+#[derive(Derivative, Properties)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+#[builder]
+/// Tree map properties
+pub struct TreeMap<T: 'static> {
+    // The tree data.
+    store: TreeStore<T>,
+
+    // Returns the size of a leaf node. The size of inner nodes is the
+    // sum of their children.
+    value: RenderFn<T, f64>,
+
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, T, String)]
+    #[prop_or_default]
+    /// Label renderer. Labels are only displayed if they fit into the rectangle.
+    render_label: Option<RenderFn<T, String>>,
+
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, T, f64)]
+    #[prop_or_default]
+    /// Color metric, expected to be between 0.0 and 1.0 (heat map).
+    ///
+    /// Leaves are colored from the 'primary' color (0.0) to the 'error' color (1.0).
+    metric: Option<RenderFn<T, f64>>,
+
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, T)]
+    #[prop_or_default]
+    /// Tooltip renderer to override the default one (label and value).
+    render_tooltip: Option<RenderFn<T>>,
+
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(true)]
+    /// Determine if tooltips are shown or not.
+    show_tooltip: bool,
+
+    #[builder]
+    #[prop_or(2)]
+    /// Number of displayed tree levels (default 2).
+    max_depth: usize,
+
+    #[builder_cb(IntoEventCallback, into_event_callback, Option<Key>)]
+    #[prop_or_default]
+    /// Emitted when the user zooms in or out. The parameter is the key of
+    /// the new top level node (or `None` for the tree root).
+    on_zoom: Option<Callback<Option<Key>>>,
+}
+
+impl<T: 'static> TreeMap<T> {
+    /// Creates a new tree map. The `value` function returns the size of a leaf node.
+    pub fn new(store: TreeStore<T>, value: impl Into<RenderFn<T, f64>>) -> Self {
+        yew::props!(Self {
+            store,
+            value: value.into(),
+        })
+    }
+}
+
+/// Layout rectangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LayoutRect {
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+}
+
+impl LayoutRect {
+    fn inset(&self, top: f64) -> Self {
+        LayoutRect {
+            x: self.x + PADDING,
+            y: self.y + top,
+            w: (self.w - 2.0 * PADDING).max(0.0),
+            h: (self.h - top - PADDING).max(0.0),
+        }
+    }
+}
+
+// Returns the worst aspect ratio of a row with the given areas.
+fn worst_ratio(row: &[f64], side: f64) -> f64 {
+    let sum: f64 = row.iter().sum();
+    let max = row.iter().cloned().fold(f64::MIN, f64::max);
+    let min = row.iter().cloned().fold(f64::MAX, f64::min);
+    let side2 = side * side;
+    let sum2 = sum * sum;
+    (side2 * max / sum2).max(sum2 / (side2 * min))
+}
+
+// Place a row of areas along the shorter side, and return the remaining rectangle.
+fn layout_row(row: &[f64], rect: LayoutRect, result: &mut Vec<LayoutRect>) -> LayoutRect {
+    let sum: f64 = row.iter().sum();
+    if rect.w >= rect.h {
+        let width = if rect.h > 0.0 { sum / rect.h } else { 0.0 };
+        let mut y = rect.y;
+        for area in row {
+            let h = if width > 0.0 { area / width } else { 0.0 };
+            result.push(LayoutRect {
+                x: rect.x,
+                y,
+                w: width,
+                h,
+            });
+            y += h;
+        }
+        LayoutRect {
+            x: rect.x + width,
+            y: rect.y,
+            w: (rect.w - width).max(0.0),
+            h: rect.h,
+        }
+    } else {
+        let height = if rect.w > 0.0 { sum / rect.w } else { 0.0 };
+        let mut x = rect.x;
+        for area in row {
+            let w = if height > 0.0 { area / height } else { 0.0 };
+            result.push(LayoutRect {
+                x,
+                y: rect.y,
+                w,
+                h: height,
+            });
+            x += w;
+        }
+        LayoutRect {
+            x: rect.x,
+            y: rect.y + height,
+            w: rect.w,
+            h: (rect.h - height).max(0.0),
+        }
+    }
+}
+
+/// Squarified tree map layout.
+///
+/// The values must be positive and sorted in descending order. Returns
+/// one rectangle for each value.
+fn squarify(values: &[f64], rect: LayoutRect) -> Vec<LayoutRect> {
+    let mut result = Vec::with_capacity(values.len());
+    let total: f64 = values.iter().sum();
+    if total <= 0.0 || values.is_empty() {
+        return values
+            .iter()
+            .map(|_| LayoutRect {
+                w: 0.0,
+                h: 0.0,
+                ..rect
+            })
+            .collect();
+    }
+
+    let scale = rect.w * rect.h / total;
+    let areas: Vec<f64> = values.iter().map(|v| v * scale).collect();
+
+    let mut rect = rect;
+    let mut row: Vec<f64> = Vec::new();
+
+    for area in areas {
+        let side = rect.w.min(rect.h);
+        if row.is_empty() {
+            row.push(area);
+            continue;
+        }
+        let current = worst_ratio(&row, side);
+        row.push(area);
+        if worst_ratio(&row, side) > current {
+            row.pop();
+            rect = layout_row(&row, rect, &mut result);
+            row.clear();
+            row.push(area);
+        }
+    }
+
+    if !row.is_empty() {
+        layout_row(&row, rect, &mut result);
+    }
+
+    result
+}
+
+fn node_size<T>(node: &KeyedSlabTreeNodeRef<T>, value: &RenderFn<T, f64>) -> f64 {
+    if node.children_count() == 0 {
+        value.apply(node.record()).max(0.0)
+    } else {
+        node.children().map(|child| node_size(&child, value)).sum()
+    }
+}
+
+fn truncate_label(label: &str, width: f64) -> Option<String> {
+    let max_chars = ((width - 2.0 * PADDING) / CHAR_WIDTH) as usize;
+    if max_chars < 3 {
+        return None;
+    }
+    if label.chars().count() <= max_chars {
+        return Some(label.to_string());
+    }
+    let mut text: String = label.chars().take(max_chars - 1).collect();
+    text.push('…');
+    Some(text)
+}
+
+pub enum Msg {
+    DataChange,
+    Resize(f64, f64),
+    Hover(Option<Key>),
+    MouseOver(Option<(i32, i32)>),
+    Zoom(Option<Key>),
+}
+
+#[doc(hidden)]
+pub struct PwtTreeMap<T: 'static> {
+    width: f64,
+    height: f64,
+    zoom_root: Option<Key>,
+    hover: Option<Key>,
+    mouse_pos: Option<(f64, f64)>,
+    container_ref: NodeRef,
+    tooltip_ref: NodeRef,
+    size_observer: Option<DomSizeObserver>,
+    _store_observer: TreeStoreObserver<T>,
+}
+
+impl<T: 'static> PwtTreeMap<T> {
+    fn label(props: &TreeMap<T>, node: &KeyedSlabTreeNodeRef<T>) -> String {
+        match &props.render_label {
+            Some(render_label) => render_label.apply(node.record()),
+            None => node.key().to_string(),
+        }
+    }
+
+    fn color(props: &TreeMap<T>, node: &KeyedSlabTreeNodeRef<T>, index: usize) -> String {
+        match &props.metric {
+            Some(metric) => {
+                let metric = metric.apply(node.record()).clamp(0.0, 1.0);
+                format!(
+                    "color-mix(in hsl, var(--pwt-color-error) {}%, var(--pwt-color-primary))",
+                    (metric * 100.0).round()
+                )
+            }
+            None => {
+                let base_color = DEFAULT_COLORS[index % DEFAULT_COLORS.len()];
+                let cycle = index / DEFAULT_COLORS.len();
+                format!(
+                    "color-mix(in hsl, {base_color} {}%, var(--pwt-color-surface))",
+                    100.0 - (20.0 * cycle as f64).min(80.0)
+                )
+            }
+        }
+    }
+
+    fn render_children(
+        &self,
+        ctx: &Context<Self>,
+        node: &KeyedSlabTreeNodeRef<T>,
+        rect: LayoutRect,
+        depth: usize,
+        color_index: Option<usize>,
+        group: &mut Group,
+    ) {
+        let props = ctx.props();
+
+        let mut children: Vec<(KeyedSlabTreeNodeRef<T>, f64)> = node
+            .children()
+            .map(|child| {
+                let size = node_size(&child, &props.value);
+                (child, size)
+            })
+            .filter(|(_, size)| *size > 0.0)
+            .collect();
+        children.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let sizes: Vec<f64> = children.iter().map(|(_, size)| *size).collect();
+        let rects = squarify(&sizes, rect);
+
+        for (index, ((child, _), rect)) in children.iter().zip(rects).enumerate() {
+            if rect.w < 1.0 || rect.h < 1.0 {
+                continue;
+            }
+
+            let key = child.key();
+            let color_index = color_index.unwrap_or(index);
+            let has_children = child.children_count() > 0;
+            let nested = has_children
+                && depth + 1 < props.max_depth
+                && rect.w > 4.0 * PADDING
+                && rect.h > HEADER_HEIGHT + 2.0 * PADDING;
+
+            let label = Self::label(props, child);
+            let hovered = self.hover.as_ref() == Some(&key);
+
+            let mut tile = Rect::new()
+                .position(rect.x, rect.y)
+                .width(rect.w)
+                .height(rect.h)
+                .class("pwt-tree-map-tile")
+                .class(hovered.then_some("hovered"))
+                .stroke("var(--pwt-color-surface)")
+                .onpointerenter({
+                    let key = key.clone();
+                    ctx.link().callback(move |_| Msg::Hover(Some(key.clone())))
+                });
+
+            if has_children {
+                tile.add_onclick({
+                    let key = key.clone();
+                    ctx.link().callback(move |event: MouseEvent| {
+                        event.stop_propagation();
+                        Msg::Zoom(Some(key.clone()))
+                    })
+                });
+                tile.set_style("cursor", "zoom-in");
+            }
+
+            if nested {
+                group.add_child(
+                    tile.fill(Self::color(props, child, color_index))
+                        .style("fill-opacity", "0.3"),
+                );
+                if let Some(text) = truncate_label(&label, rect.w) {
+                    group.add_child(
+                        Text::new(text)
+                            .position(rect.x + PADDING, rect.y + HEADER_HEIGHT / 2.0)
+                            .class("pwt-tree-map-label")
+                            .attribute("alignment-baseline", "central")
+                            .style("pointer-events", "none"),
+                    );
+                }
+                self.render_children(
+                    ctx,
+                    child,
+                    rect.inset(HEADER_HEIGHT),
+                    depth + 1,
+                    Some(color_index),
+                    group,
+                );
+            } else {
+                group.add_child(tile.fill(Self::color(props, child, color_index)));
+                if rect.h >= HEADER_HEIGHT {
+                    if let Some(text) = truncate_label(&label, rect.w) {
+                        group.add_child(
+                            Text::new(text)
+                                .position(rect.x + PADDING, rect.y + HEADER_HEIGHT / 2.0)
+                                .class("pwt-tree-map-label")
+                                .attribute("alignment-baseline", "central")
+                                .style("pointer-events", "none"),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_breadcrumbs(&self, ctx: &Context<Self>) -> Option<Html> {
+        let props = ctx.props();
+        let zoom_root = self.zoom_root.as_ref()?;
+        let tree = props.store.read();
+        let node = tree.lookup_node(zoom_root)?;
+
+        let mut path = Vec::new();
+        let mut parent = node.parent();
+        while let Some(node) = parent {
+            path.push((Some(node.key()), Self::label(props, &node)));
+            parent = node.parent();
+        }
+        // the tree root resets the zoom
+        if let Some((key, _)) = path.last_mut() {
+            *key = None;
+        }
+        path.reverse();
+
+        let mut bar = Row::new()
+            .class("pwt-tree-map-breadcrumbs")
+            .class(css::AlignItems::Center)
+            .gap(1);
+
+        for (key, label) in path {
+            bar.add_child(
+                Button::new(label)
+                    .class("pwt-button-text")
+                    .on_activate(ctx.link().callback(move |_| Msg::Zoom(key.clone()))),
+            );
+            bar.add_child(html! {<i role="none" class="fa fa-angle-right"/>});
+        }
+        bar.add_child(html! {<span aria-current="location">{Self::label(props, &node)}</span>});
+
+        Some(bar.into())
+    }
+
+    fn render_tooltip(&self, ctx: &Context<Self>) -> Option<Html> {
+        let props = ctx.props();
+        if !props.show_tooltip || self.mouse_pos.is_none() {
+            return None;
+        }
+        let key = self.hover.as_ref()?;
+        let tree = props.store.read();
+        let node = tree.lookup_node(key)?;
+
+        let content = match &props.render_tooltip {
+            Some(render_tooltip) => render_tooltip.apply(node.record()),
+            None => {
+                let value = format_float(node_size(&node, &props.value));
+                format!("{}: {value}", Self::label(props, &node)).into()
+            }
+        };
+
+        Some(
+            Container::new()
+                .attribute("role", "tooltip")
+                .attribute("aria-live", "polite")
+                .attribute("data-show", Some(""))
+                .class("pwt-tooltip")
+                .class("pwt-tooltip-rich")
+                .with_child(content)
+                .into_html_with_ref(self.tooltip_ref.clone()),
+        )
+    }
+}
+
+impl<T: 'static> Component for PwtTreeMap<T> {
+    type Message = Msg;
+    type Properties = TreeMap<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let _store_observer = props
+            .store
+            .add_listener(ctx.link().callback(|_| Msg::DataChange));
+        Self {
+            width: 0.0,
+            height: 0.0,
+            zoom_root: None,
+            hover: None,
+            mouse_pos: None,
+            container_ref: NodeRef::default(),
+            tooltip_ref: NodeRef::default(),
+            size_observer: None,
+            _store_observer,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.store != old_props.store {
+            self._store_observer = props
+                .store
+                .add_listener(ctx.link().callback(|_| Msg::DataChange));
+            self.zoom_root = None;
+            self.hover = None;
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::DataChange => {
+                // reset zoom if the node vanished
+                if let Some(key) = &self.zoom_root {
+                    if props.store.read().lookup_node(key).is_none() {
+                        self.zoom_root = None;
+                    }
+                }
+            }
+            Msg::Resize(width, height) => {
+                self.width = width;
+                self.height = height;
+            }
+            Msg::Hover(key) => self.hover = key,
+            Msg::MouseOver(pos) => {
+                self.mouse_pos = pos.map(|(x, y)| (x as f64, y as f64));
+            }
+            Msg::Zoom(key) => {
+                if self.zoom_root == key {
+                    return false;
+                }
+                self.zoom_root = key.clone();
+                self.hover = None;
+                if let Some(on_zoom) = &props.on_zoom {
+                    on_zoom.emit(key);
+                }
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let mut group = Group::new();
+        {
+            let tree = props.store.read();
+            let root = match &self.zoom_root {
+                Some(key) => tree.lookup_node(key),
+                None => tree.root(),
+            };
+            if let Some(root) = root {
+                let rect = LayoutRect {
+                    x: 0.0,
+                    y: 0.0,
+                    w: self.width,
+                    h: self.height,
+                };
+                self.render_children(ctx, &root, rect, 0, None, &mut group);
+            }
+        }
+
+        let canvas = Canvas::new()
+            .class("pwt-tree-map-canvas")
+            .attribute("role", "img")
+            .attribute("width", "100%")
+            .attribute("height", "100%")
+            .onpointermove(ctx.link().callback(|event: PointerEvent| {
+                Msg::MouseOver(Some((event.client_x(), event.client_y())))
+            }))
+            .onpointerleave(
+                ctx.link()
+                    .batch_callback(|_| vec![Msg::MouseOver(None), Msg::Hover(None)]),
+            )
+            .with_child(group);
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-tree-map")
+            .class(css::Display::Flex)
+            .class(css::FlexDirection::Column)
+            .with_optional_child(self.render_breadcrumbs(ctx))
+            .with_child(
+                Container::new()
+                    .class(css::Flex::Fill)
+                    .style("position", "relative")
+                    .style("overflow", "hidden")
+                    .with_child(
+                        Container::new()
+                            .style("position", "absolute")
+                            .style("inset", "0")
+                            .with_child(canvas),
+                    )
+                    .into_html_with_ref(self.container_ref.clone()),
+            )
+            .with_optional_child(self.render_tooltip(ctx))
+            .into()
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(el) = self.container_ref.cast::<web_sys::Element>() {
+                let link = ctx.link().clone();
+                self.size_observer = Some(DomSizeObserver::new(&el, move |(width, height)| {
+                    link.send_message(Msg::Resize(width, height));
+                }));
+            }
+        }
+
+        if let Some((x, y)) = self.mouse_pos {
+            if let Some(tooltip_ref) = self.tooltip_ref.get() {
+                let _ = align_to_xy(
+                    tooltip_ref,
+                    (x + 20.0, y + 20.0),
+                    crate::dom::align::Point::TopStart,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn area(rect: &LayoutRect) -> f64 {
+        rect.w * rect.h
+    }
+
+    #[test]
+    fn test_squarify() {
+        let rect = LayoutRect {
+            x: 0.0,
+            y: 0.0,
+            w: 6.0,
+            h: 4.0,
+        };
+
+        // example from the squarified tree map paper (Bruls et al.)
+        let values = [6.0, 6.0, 4.0, 3.0, 2.0, 2.0, 1.0];
+        let rects = squarify(&values, rect);
+        assert_eq!(rects.len(), values.len());
+
+        for (value, rect) in values.iter().zip(rects.iter()) {
+            assert!((area(rect) - value).abs() < 1e-9);
+            assert!(rect.x >= 0.0 && rect.x + rect.w <= 6.0 + 1e-9);
+            assert!(rect.y >= 0.0 && rect.y + rect.h <= 4.0 + 1e-9);
+        }
+
+        // the first two values fill the left column
+        assert_eq!(
+            rects[0],
+            LayoutRect {
+                x: 0.0,
+                y: 0.0,
+                w: 3.0,
+                h: 2.0
+            }
+        );
+        assert_eq!(
+            rects[1],
+            LayoutRect {
+                x: 0.0,
+                y: 2.0,
+                w: 3.0,
+                h: 2.0
+            }
+        );
+
+        assert!(squarify(&[], rect).is_empty());
+    }
+
+    #[test]
+    fn test_truncate_label() {
+        assert_eq!(truncate_label("vm-100", 100.0).as_deref(), Some("vm-100"));
+        assert_eq!(
+            truncate_label("vm-100-disk-0", 50.0).as_deref(),
+            Some("vm-10…")
+        );
+        assert_eq!(truncate_label("vm-100", 10.0), None);
+    }
+}
@@ -0,0 +1,160 @@
+use yew::html::IntoEventCallback;
+
+use crate::dom::get_system_prefer_reduced_motion;
+use crate::prelude::*;
+use crate::widget::{Container, SizeObserver};
+
+use pwt_macros::{builder, widget};
+
+/// Show/hide children with an animated height.
+///
+/// The content height is measured with a [SizeObserver], so the
+/// animation also works for content with dynamic size. Hidden content is
+/// `inert` and marked as `aria-hidden`. If the user prefers reduced
+/// motion, the content is shown/hidden without animation.
+///
+/// By default, hidden children stay mounted (i.e. form fields keep their
+/// state). Set [unmount_on_close](Self::unmount_on_close) to remove them
+/// after the close animation finished.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{Collapse, Container};
+/// # fn create_details(open: bool) -> Collapse {
+/// Collapse::new(open)
+///     .on_animation_end(|open: bool| log::info!("collapse animation finished: {open}"))
+///     .with_child(Container::new().padding(2).with_child("Details"))
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtCollapse, @element, @container)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct Collapse {
+    /// Show the children.
+    #[builder]
+    pub open: bool,
+
+    /// Animation duration in milliseconds.
+    #[builder]
+    #[prop_or(200)]
+    pub duration: u32,
+
+    /// Unmount the children when closed.
+    #[builder]
+    #[prop_or_default]
+    pub unmount_on_close: bool,
+
+    /// Called with the [open](Self::open) state when the animation finished.
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    #[prop_or_default]
+    pub on_animation_end: Option<Callback<bool>>,
+}
+
+impl Collapse {
+    /// Create a new instance.
+    pub fn new(open: bool) -> Self {
+        yew::props!(Self { open })
+    }
+}
+
+pub enum Msg {
+    Resize(f64),
+    TransitionEnd(TransitionEvent),
+}
+
+#[doc(hidden)]
+pub struct PwtCollapse {
+    content_height: Option<f64>,
+    animating: bool,
+    on_resize: Callback<(f64, f64)>,
+}
+
+impl PwtCollapse {
+    fn animation_end(&mut self, props: &Collapse) {
+        self.animating = false;
+        if let Some(on_animation_end) = &props.on_animation_end {
+            on_animation_end.emit(props.open);
+        }
+    }
+}
+
+impl Component for PwtCollapse {
+    type Message = Msg;
+    type Properties = Collapse;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            content_height: None,
+            animating: false,
+            on_resize: ctx.link().callback(|(_width, height)| Msg::Resize(height)),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Resize(height) => {
+                if self.content_height == Some(height) {
+                    return false;
+                }
+                self.content_height = Some(height);
+                true
+            }
+            Msg::TransitionEnd(event) => {
+                // ignore bubbled events from children
+                if event.property_name() != "height" || event.target() != event.current_target() {
+                    return false;
+                }
+                self.animation_end(props);
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.open != old_props.open {
+            if self.content_height.is_none() || get_system_prefer_reduced_motion() {
+                self.animation_end(props);
+            } else {
+                self.animating = true;
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let height = match (props.open, self.content_height) {
+            (false, _) => Some(String::from("0")),
+            (true, Some(height)) => Some(format!("{height}px")),
+            (true, None) => None,
+        };
+
+        let transition = (!get_system_prefer_reduced_motion())
+            .then(|| format!("height {}ms ease-out", props.duration));
+
+        let show_children = props.open || self.animating || !props.unmount_on_close;
+
+        let content = Container::new().class("pwt-collapse-content").children(
+            show_children
+                .then(|| props.children.clone())
+                .unwrap_or_default(),
+        );
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-collapse")
+            .class(props.open.then_some("open"))
+            .style("overflow", "hidden")
+            .style("height", height)
+            .style("transition", transition)
+            .attribute("inert", (!props.open).then_some(""))
+            .attribute("aria-hidden", (!props.open).then_some("true"))
+            .ontransitionend(ctx.link().callback(Msg::TransitionEnd))
+            .with_child(SizeObserver::new(content, self.on_resize.clone()))
+            .into()
+    }
+}
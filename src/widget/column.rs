@@ -1,16 +1,14 @@
-use std::borrow::Cow;
-
 use yew::prelude::*;
-use yew::virtual_dom::{Listeners, VList, VTag};
 
 use pwt_macros::widget;
 
-use crate::css::{Display, FlexDirection};
+use crate::css::{Display, FlexDirection, Gap};
 use crate::prelude::*;
 use crate::props::{AsClassesMut, AsCssStylesMut, PwtSpace};
+use crate::widget::Sticky;
 
 /// Vertical container with flex layout.
-#[widget(pwt=crate, @element, @container)]
+#[widget(pwt=crate, tag="div", @element, @container)]
 #[derive(Debug, Clone, PartialEq, Properties)]
 pub struct Column {}
 
@@ -41,7 +39,7 @@ impl Column {
         match gap.into() {
             PwtSpace::None => {}
             PwtSpace::Pwt(factor) if factor <= 4 => {
-                self.as_classes_mut().push(format!("pwt-gap-{}", factor));
+                self.as_classes_mut().push(Gap(factor));
             }
             space => self.as_css_styles_mut().set_style("gap", space.to_string()),
         }
@@ -59,23 +57,30 @@ impl Column {
     pub fn add_flex_spacer(&mut self) {
         self.add_child(html! {<div class="pwt-flex-fill"/>});
     }
-}
 
-impl IntoVTag for Column {
-    fn into_vtag_with_ref(self, node_ref: NodeRef) -> VTag {
-        let attributes = self.std_props.cumulate_attributes(None::<&str>);
+    /// Builder style method to add a child pinned to the top of the scrolling ancestor.
+    ///
+    /// The child is wrapped into a [Sticky] widget.
+    pub fn with_sticky_child(mut self, child: impl Into<Html>) -> Self {
+        self.add_sticky_child(child);
+        self
+    }
 
-        let listeners = Listeners::Pending(self.listeners.listeners.into_boxed_slice());
+    /// Method to add a child pinned to the top of the scrolling ancestor.
+    pub fn add_sticky_child(&mut self, child: impl Into<Html>) {
+        self.add_child(Sticky::top(child));
+    }
 
-        let children = VList::with_children(self.children, None);
+    /// Builder style method to add a child pinned to the bottom of the scrolling ancestor.
+    ///
+    /// The child is wrapped into a [Sticky] widget.
+    pub fn with_sticky_footer(mut self, child: impl Into<Html>) -> Self {
+        self.add_sticky_footer(child);
+        self
+    }
 
-        VTag::__new_other(
-            Cow::Borrowed("div"),
-            node_ref,
-            self.std_props.key,
-            attributes,
-            listeners,
-            children.into(),
-        )
+    /// Method to add a child pinned to the bottom of the scrolling ancestor.
+    pub fn add_sticky_footer(&mut self, child: impl Into<Html>) {
+        self.add_child(Sticky::bottom(child));
     }
 }
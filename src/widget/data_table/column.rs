@@ -1,27 +1,114 @@
 use std::rc::Rc;
 
 use derivative::Derivative;
+use wasm_bindgen::JsCast;
 
-use yew::html::IntoPropValue;
+use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
 
 use yew::virtual_dom::Key;
 
+use crate::css::TextAlign;
 use crate::props::{CallbackMut, IntoEventCallbackMut, IntoSorterFn, RenderFn, SorterFn};
-use crate::state::TreeStore;
+use crate::state::{DerivedFields, TreeStore};
+use crate::tr;
+use crate::widget::menu::Menu;
 
 use super::{
-    DataTableCellRenderArgs, DataTableCellRenderer, DataTableHeaderKeyboardEvent,
-    DataTableHeaderRenderer, DataTableKeyboardEvent, DataTableMouseEvent,
+    DataTableCellEditEvent, DataTableCellRenderArgs, DataTableCellRenderer, DataTableColumnFilter,
+    DataTableHeaderKeyboardEvent, DataTableHeaderRenderArgs, DataTableHeaderRenderer,
+    DataTableKeyboardEvent, DataTableMouseEvent, DataTableRowActions, ROW_ACTIONS_CLASS,
 };
 
+/// Column width setting.
+///
+/// The header computes the actual column sizes: fixed and percentage
+/// widths are applied first, then the remaining space is distributed to
+/// flex columns by weight. Columns resized by the user keep their new
+/// width until reset (double-click on the resize handle).
+///
+/// Strings are parsed, so `"100px"`, `"30%"` and `"2fr"` result in the
+/// corresponding typed variant. All other values are passed to CSS as
+/// grid track size.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnWidth {
+    /// Fixed width in pixels.
+    Fixed(f64),
+    /// Share of the remaining space (weight).
+    Flex(f64),
+    /// Percentage of the table width.
+    Percent(f64),
+    /// CSS grid track size (e.g. `auto` or `max-content`).
+    Css(AttrValue),
+}
+
+impl ColumnWidth {
+    /// Minimum width for flex columns in pixels.
+    pub const MIN_FLEX_WIDTH: f64 = 50.0;
+
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if let Some(px) = text.strip_suffix("px") {
+            return px.trim().parse().ok().map(Self::Fixed);
+        }
+        if let Some(weight) = text.strip_suffix("fr") {
+            return weight.trim().parse().ok().map(Self::Flex);
+        }
+        if let Some(percent) = text.strip_suffix('%') {
+            return percent.trim().parse().ok().map(Self::Percent);
+        }
+        None
+    }
+}
+
+impl Default for ColumnWidth {
+    fn default() -> Self {
+        Self::Css(AttrValue::Static("auto"))
+    }
+}
+
+impl std::fmt::Display for ColumnWidth {
+    /// Formats the width as CSS size (used if the table width is unknown).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed(px) => write!(f, "{px}px"),
+            Self::Flex(weight) => write!(f, "minmax({}px, {weight}fr)", Self::MIN_FLEX_WIDTH),
+            Self::Percent(percent) => write!(f, "{percent}%"),
+            Self::Css(css) => f.write_str(css),
+        }
+    }
+}
+
+impl From<AttrValue> for ColumnWidth {
+    fn from(width: AttrValue) -> Self {
+        Self::parse(&width).unwrap_or(Self::Css(width))
+    }
+}
+
+impl From<&'static str> for ColumnWidth {
+    fn from(width: &'static str) -> Self {
+        AttrValue::Static(width).into()
+    }
+}
+
+impl From<String> for ColumnWidth {
+    fn from(width: String) -> Self {
+        AttrValue::from(width).into()
+    }
+}
+
 /// DataTable column properties.
 #[derive(Properties, Derivative)]
 #[derivative(Clone(bound = ""), PartialEq(bound = ""))]
 pub struct DataTableColumn<T: 'static> {
-    /// Width passed to CSS grid-template-columns.
-    #[prop_or(AttrValue::Static("auto"))]
-    pub width: AttrValue,
+    /// Column width (see [ColumnWidth]).
+    ///
+    /// Note: This field used to be an [AttrValue]. The builder methods
+    /// still accept strings, but code accessing the field directly needs
+    /// to use [ColumnWidth] (its [Display](std::fmt::Display) impl
+    /// returns the CSS value).
+    #[prop_or_default]
+    pub width: ColumnWidth,
     /// The name dispayed in the header (Also used as aria-label).
     pub name: AttrValue,
     /// Unique Column Key
@@ -33,6 +120,17 @@ pub struct DataTableColumn<T: 'static> {
     /// Vertical table cell align ("top", "bottom", "middle", "baseline").
     #[prop_or_default]
     pub vertical_align: Option<AttrValue>,
+    /// Render cell content on a single line and truncate it with ellipsis.
+    ///
+    /// The full cell content is shown as tooltip if the text is truncated.
+    /// Default is to wrap the content.
+    #[prop_or_default]
+    pub ellipsis: bool,
+    /// Highlight changed cells (see [DataTable::flash_changes](super::DataTable::flash_changes)).
+    ///
+    /// Enabled by default.
+    #[prop_or(true)]
+    pub flash_on_change: bool,
 
     // only internal, use `apply_render` instead
     render_cell: DataTableCellRenderer<T>,
@@ -55,6 +153,9 @@ pub struct DataTableColumn<T: 'static> {
     /// Hide column
     #[prop_or_default]
     pub hidden: bool,
+    /// Allow to hide the column using the header menu.
+    #[prop_or(true)]
+    pub hideable: bool,
     /// Resizable flag.
     #[prop_or(true)]
     pub resizable: bool,
@@ -82,8 +183,24 @@ pub struct DataTableColumn<T: 'static> {
     #[prop_or_default]
     pub on_header_keydown: Option<CallbackMut<DataTableHeaderKeyboardEvent<T>>>,
 
+    /// Cell editor render function (see [editor](Self::editor)).
+    #[prop_or_default]
+    pub editor: Option<RenderFn<T>>,
+
+    /// Cell edit callback
+    #[prop_or_default]
+    pub on_cell_edit: Option<Callback<DataTableCellEditEvent>>,
+
     #[prop_or_default]
     pub tree_store: Option<TreeStore<T>>,
+
+    /// Filter widget shown in the table filter row (see [filter](Self::filter)).
+    #[prop_or_default]
+    pub filter: Option<DataTableColumnFilter<T>>,
+
+    /// Plain text cell value used to copy or print rows (see [export_text](Self::export_text)).
+    #[prop_or_default]
+    pub export_text: Option<RenderFn<T, String>>,
 }
 
 impl<T: 'static> DataTableColumn<T> {
@@ -131,6 +248,18 @@ impl<T: 'static> DataTableColumn<T> {
             })
     }
 
+    /// Generates a fixed width checkbox column for multi-selection.
+    ///
+    /// Same as [Self::selection_indicator], but the column has a fixed
+    /// width and cannot be hidden. Rows are toggled by click or the
+    /// space key, the header toggles select all/none.
+    pub fn selection_column() -> Self {
+        Self::selection_indicator()
+            .key(Key::from("__selection__"))
+            .fixed(32)
+            .hideable(false)
+    }
+
     /// Genertates a column which shows the now number.
     pub fn row_number() -> Self {
         Self::new("Row")
@@ -138,6 +267,46 @@ impl<T: 'static> DataTableColumn<T> {
             .render_cell(super::render_row_number)
     }
 
+    /// Generates a column with a per-row action menu (kebab menu).
+    ///
+    /// The `menu_builder` is called with the row record when the user
+    /// opens the menu, either by clicking the icon, or by pressing
+    /// `Enter`/`Space` while the cell has focus. The menu closes when
+    /// it loses focus or when the table scrolls.
+    pub fn actions(menu_builder: impl 'static + Fn(&T) -> Menu) -> Self
+    where
+        T: Clone,
+    {
+        let menu_builder = Rc::new(menu_builder);
+        Self::new(tr!("Actions"))
+            .key(Key::from("__actions__"))
+            .fixed(40)
+            .resizable(false)
+            .justify("center")
+            .hideable(false)
+            .show_menu(false)
+            .render_header(|_: &mut DataTableHeaderRenderArgs<T>| html! {})
+            .render_cell(move |args: &mut DataTableCellRenderArgs<T>| {
+                let menu_builder = menu_builder.clone();
+                let record = args.record().clone();
+                DataTableRowActions::new(move || menu_builder(&record)).into()
+            })
+            .on_cell_keydown(|event: &mut DataTableKeyboardEvent| {
+                if !matches!(event.key().as_str(), "Enter" | " ") {
+                    return;
+                }
+                let button = event
+                    .target_dyn_into::<web_sys::Element>()
+                    .and_then(|cell| cell.query_selector(&format!(".{ROW_ACTIONS_CLASS}")).ok()?)
+                    .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+                if let Some(button) = button {
+                    event.stop_propagation();
+                    event.prevent_default();
+                    button.click();
+                }
+            })
+    }
+
     /// Builder style method to set the yew `key` property
     pub fn key(mut self, key: impl Into<Key>) -> Self {
         self.key = Some(key.into());
@@ -145,13 +314,13 @@ impl<T: 'static> DataTableColumn<T> {
     }
 
     /// Builder style method to set the column width.
-    pub fn width(mut self, width: impl Into<AttrValue>) -> Self {
+    pub fn width(mut self, width: impl Into<ColumnWidth>) -> Self {
         self.set_width(width);
         self
     }
 
     /// Method to set the column width.
-    pub fn set_width(&mut self, width: impl Into<AttrValue>) {
+    pub fn set_width(&mut self, width: impl Into<ColumnWidth>) {
         self.width = width.into();
     }
 
@@ -163,7 +332,7 @@ impl<T: 'static> DataTableColumn<T> {
 
     /// Method to set the column width as flex fraction.
     pub fn set_flex(&mut self, flex: usize) {
-        self.set_width(format!("{flex}fr"));
+        self.set_width(ColumnWidth::Flex(flex as f64));
     }
 
     /// Builder style method to set a fixed column width.
@@ -174,7 +343,7 @@ impl<T: 'static> DataTableColumn<T> {
 
     /// Method to set a fixed column width.
     pub fn set_fixed(&mut self, size: usize) {
-        self.set_width(format!("{size}px"));
+        self.set_width(ColumnWidth::Fixed(size as f64));
     }
 
     /// Builder style method to set the column width as percentage.
@@ -185,7 +354,7 @@ impl<T: 'static> DataTableColumn<T> {
 
     /// Method to set the column width as percentage.
     pub fn set_percentage(&mut self, percentage: usize) {
-        self.set_width(format!("{percentage}%"));
+        self.set_width(ColumnWidth::Percent(percentage as f64));
     }
 
     /// Builder style method to set the horizontal cell justification.
@@ -199,6 +368,47 @@ impl<T: 'static> DataTableColumn<T> {
         self.justify = justify.into();
     }
 
+    /// Builder style method to set the horizontal text alignment.
+    pub fn text_align(mut self, align: TextAlign) -> Self {
+        self.set_text_align(align);
+        self
+    }
+
+    /// Method to set the horizontal text alignment.
+    pub fn set_text_align(&mut self, align: TextAlign) {
+        let justify = match align {
+            TextAlign::Start => "start",
+            TextAlign::End => "end",
+            TextAlign::Left => "left",
+            TextAlign::Right => "right",
+            TextAlign::Center => "center",
+            TextAlign::Justify | TextAlign::JustifyAll => "justify",
+        };
+        self.set_justify(justify);
+    }
+
+    /// Builder style method to enable single-line mode with ellipsis.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.set_ellipsis(ellipsis);
+        self
+    }
+
+    /// Method to enable single-line mode with ellipsis.
+    pub fn set_ellipsis(&mut self, ellipsis: bool) {
+        self.ellipsis = ellipsis;
+    }
+
+    /// Builder style method to enable the change highlight.
+    pub fn flash_on_change(mut self, flash_on_change: bool) -> Self {
+        self.set_flash_on_change(flash_on_change);
+        self
+    }
+
+    /// Method to enable the change highlight.
+    pub fn set_flash_on_change(&mut self, flash_on_change: bool) {
+        self.flash_on_change = flash_on_change;
+    }
+
     /// Builder style method to set the vertical cell alignment.
     pub fn vertical_align(mut self, vertical_align: impl IntoPropValue<Option<AttrValue>>) -> Self {
         self.set_vertical_align(vertical_align);
@@ -234,6 +444,22 @@ impl<T: 'static> DataTableColumn<T> {
         self
     }
 
+    /// Builder style method to render and sort the column using a derived field.
+    ///
+    /// See [Store::derive](crate::state::Store::derive).
+    pub fn derived_field(self, fields: &DerivedFields<T>, name: impl Into<AttrValue>) -> Self {
+        let name = name.into();
+        let render_fields = fields.clone();
+        let render_name = name.clone();
+        self.sorter(fields.sorter(name)).render(move |record: &T| {
+            match render_fields.get(&render_name, record) {
+                Some(serde_json::Value::String(text)) => html! {text},
+                Some(serde_json::Value::Null) | None => html! {},
+                Some(value) => html! {value.to_string()},
+            }
+        })
+    }
+
     /// Builder style method to set the sort order
     pub fn sort_order(mut self, order: impl IntoPropValue<Option<bool>>) -> Self {
         self.sort_order = order.into_prop_value();
@@ -251,6 +477,17 @@ impl<T: 'static> DataTableColumn<T> {
         self.hidden = hidden;
     }
 
+    /// Builder style method to set the hideable flag.
+    pub fn hideable(mut self, hideable: bool) -> Self {
+        self.set_hideable(hideable);
+        self
+    }
+
+    /// Method to set the hideable flag.
+    pub fn set_hideable(&mut self, hideable: bool) {
+        self.hideable = hideable;
+    }
+
     /// Builder style method to set the resizable flag.
     pub fn resizable(mut self, hidden: bool) -> Self {
         self.set_resizable(hidden);
@@ -312,7 +549,115 @@ impl<T: 'static> DataTableColumn<T> {
         self
     }
 
-    /// Builder style method to set a get_property_fn for renderer and sorter
+    /// Builder style method to set the cell editor.
+    ///
+    /// The render function gets the record, and should return a single
+    /// named form field, using the current value as `default`. The editor
+    /// replaces the cell content on double click or `F2`. `Enter` or moving
+    /// the focus away commits the change, `Escape` cancels the edit. The
+    /// field validation is used, so invalid values cannot be committed.
+    ///
+    /// Changed values are reported with [on_cell_edit](Self::on_cell_edit).
+    /// The table does not modify the record, so the callback needs to
+    /// update the store.
+    ///
+    /// ```
+    /// # use pwt::prelude::*;
+    /// # use pwt::widget::data_table::{DataTableCellEditEvent, DataTableColumn};
+    /// # use pwt::widget::form::Field;
+    /// # struct Guest { name: String }
+    /// # fn create_column() -> DataTableColumn<Guest> {
+    ///     DataTableColumn::new("Name")
+    ///         .render(|guest: &Guest| html! {&guest.name})
+    ///         .editor(|guest: &Guest| {
+    ///             Field::new()
+    ///                 .name("name")
+    ///                 .required(true)
+    ///                 .default(guest.name.clone())
+    ///                 .into()
+    ///         })
+    ///         .on_cell_edit(|event: DataTableCellEditEvent| {
+    ///             log::info!("rename {} to {}", event.record_key, event.value);
+    ///         })
+    /// # }
+    /// ```
+    pub fn editor(mut self, editor: impl Into<RenderFn<T>>) -> Self {
+        self.set_editor(editor);
+        self
+    }
+
+    /// Method to set the cell editor.
+    pub fn set_editor(&mut self, editor: impl Into<RenderFn<T>>) {
+        self.editor = Some(editor.into());
+    }
+
+    /// Builder style method to set the column filter.
+    ///
+    /// Tables with column filters show a filter row below the headers.
+    /// Filter values are combined (all must match) with the filter set by
+    /// the application, and applied to the store with
+    /// [DataStore::set_filter](crate::state::DataStore::set_filter). Clearing
+    /// the column filters restores the application filter.
+    ///
+    /// Filter values are stored by column key (or column name, if there is
+    /// no key).
+    ///
+    /// ```
+    /// # use pwt::prelude::*;
+    /// # use pwt::widget::data_table::{DataTableColumn, DataTableColumnFilter};
+    /// # struct Guest { name: String }
+    /// # fn create_column() -> DataTableColumn<Guest> {
+    ///     DataTableColumn::new("Name")
+    ///         .render(|guest: &Guest| html! {&guest.name})
+    ///         .filter(DataTableColumnFilter::text(|guest: &Guest| guest.name.clone()))
+    /// # }
+    /// ```
+    pub fn filter(mut self, filter: impl Into<Option<DataTableColumnFilter<T>>>) -> Self {
+        self.set_filter(filter);
+        self
+    }
+
+    /// Method to set the column filter.
+    pub fn set_filter(&mut self, filter: impl Into<Option<DataTableColumnFilter<T>>>) {
+        self.filter = filter.into();
+    }
+
+    /// Builder style method to set the plain text cell value.
+    ///
+    /// Used by [DataTableExport](super::DataTableExport) to copy or print
+    /// rows. Columns without export text fall back to the text of a
+    /// text or select [filter](Self::filter), and are skipped otherwise.
+    pub fn export_text(mut self, export_text: impl Into<RenderFn<T, String>>) -> Self {
+        self.set_export_text(export_text);
+        self
+    }
+
+    /// Method to set the plain text cell value.
+    pub fn set_export_text(&mut self, export_text: impl Into<RenderFn<T, String>>) {
+        self.export_text = Some(export_text.into());
+    }
+
+    /// Returns true if the column provides a plain text cell value.
+    pub fn has_export_text(&self) -> bool {
+        self.export_text.is_some() || self.filter.as_ref().is_some_and(|filter| filter.has_text())
+    }
+
+    /// Returns the plain text cell value for `record`.
+    pub fn get_export_text(&self, record: &T) -> Option<String> {
+        match (&self.export_text, &self.filter) {
+            (Some(export_text), _) => Some(export_text.apply(record)),
+            (None, Some(filter)) => filter.text(record),
+            (None, None) => None,
+        }
+    }
+
+    /// Builder style method to set the cell edit callback.
+    pub fn on_cell_edit(mut self, cb: impl IntoEventCallback<DataTableCellEditEvent>) -> Self {
+        self.on_cell_edit = cb.into_event_callback();
+        self
+    }
+
+    /// Builder style method to set a get_property_fn for renderer, sorter and export text
     /// the given fn must return a reference
     pub fn get_property<E: Ord + std::fmt::Display + ?Sized>(
         self,
@@ -323,10 +668,14 @@ impl<T: 'static> DataTableColumn<T> {
             let get_property_fn = get_property_fn.clone();
             move |itema: &T, itemb: &T| get_property_fn(itema).cmp(get_property_fn(itemb))
         })
+        .export_text({
+            let get_property_fn = get_property_fn.clone();
+            move |item: &T| get_property_fn(item).to_string()
+        })
         .render(move |item: &T| html! {{get_property_fn(item)}})
     }
 
-    /// Builder style method to set a get_property_fn for renderer and sorter
+    /// Builder style method to set a get_property_fn for renderer, sorter and export text
     /// the given fn must return an owned type
     /// NOTE: on sort this will be called multiple times per entry, so use with caution for large
     /// lists
@@ -339,6 +688,10 @@ impl<T: 'static> DataTableColumn<T> {
             let get_property_fn = get_property_fn.clone();
             move |itema: &T, itemb: &T| get_property_fn(itema).cmp(&get_property_fn(itemb))
         })
+        .export_text({
+            let get_property_fn = get_property_fn.clone();
+            move |item: &T| get_property_fn(item).to_string()
+        })
         .render(move |item: &T| html! {{get_property_fn(item)}})
     }
 
@@ -384,3 +737,31 @@ impl<T: 'static> DataTableColumn<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ColumnWidth;
+
+    #[test]
+    fn test_column_width_parse() {
+        assert_eq!(ColumnWidth::from("100px"), ColumnWidth::Fixed(100.0));
+        assert_eq!(ColumnWidth::from(" 2.5fr "), ColumnWidth::Flex(2.5));
+        assert_eq!(ColumnWidth::from("30 %"), ColumnWidth::Percent(30.0));
+
+        // everything else is passed to CSS
+        assert_eq!(ColumnWidth::from("auto"), ColumnWidth::Css("auto".into()));
+        assert_eq!(
+            ColumnWidth::from("minmax(100px, 1fr)"),
+            ColumnWidth::Css("minmax(100px, 1fr)".into())
+        );
+        assert_eq!(ColumnWidth::from("xpx"), ColumnWidth::Css("xpx".into()));
+    }
+
+    #[test]
+    fn test_column_width_display() {
+        assert_eq!(ColumnWidth::Fixed(100.0).to_string(), "100px");
+        assert_eq!(ColumnWidth::Flex(2.0).to_string(), "minmax(50px, 2fr)");
+        assert_eq!(ColumnWidth::Percent(30.0).to_string(), "30%");
+        assert_eq!(ColumnWidth::default().to_string(), "auto");
+    }
+}
@@ -0,0 +1,498 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+use crate::css::FullWidth;
+use crate::dom::parse_float;
+use crate::props::{EventSubscriber, FilterFn, WidgetBuilder};
+use crate::tr;
+use crate::widget::{Input, Row};
+
+enum FilterKind<T> {
+    Text(Rc<dyn Fn(&T) -> String>),
+    Select(Rc<Vec<AttrValue>>, Rc<dyn Fn(&T) -> String>),
+    NumberRange(Rc<dyn Fn(&T) -> Option<f64>>),
+}
+
+impl<T> Clone for FilterKind<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Text(text_fn) => Self::Text(Rc::clone(text_fn)),
+            Self::Select(options, text_fn) => Self::Select(Rc::clone(options), Rc::clone(text_fn)),
+            Self::NumberRange(number_fn) => Self::NumberRange(Rc::clone(number_fn)),
+        }
+    }
+}
+
+impl<T> PartialEq for FilterKind<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(a), Self::Text(b)) => Rc::ptr_eq(a, b),
+            (Self::Select(options_a, a), Self::Select(options_b, b)) => {
+                options_a == options_b && Rc::ptr_eq(a, b)
+            }
+            (Self::NumberRange(a), Self::NumberRange(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Column filter widget for the DataTable filter row.
+///
+/// See [DataTableColumn::filter](super::DataTableColumn::filter).
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+pub struct DataTableColumnFilter<T> {
+    kind: FilterKind<T>,
+}
+
+impl<T> DataTableColumnFilter<T> {
+    /// Text filter (case insensitive substring match).
+    pub fn text(text_fn: impl 'static + Fn(&T) -> String) -> Self {
+        Self {
+            kind: FilterKind::Text(Rc::new(text_fn)),
+        }
+    }
+
+    /// Selection from a list of values (exact match).
+    pub fn select(
+        options: impl IntoIterator<Item = impl Into<AttrValue>>,
+        text_fn: impl 'static + Fn(&T) -> String,
+    ) -> Self {
+        let options = options.into_iter().map(|option| option.into()).collect();
+        Self {
+            kind: FilterKind::Select(Rc::new(options), Rc::new(text_fn)),
+        }
+    }
+
+    /// Number range (minimum and maximum, both optional).
+    ///
+    /// Records without value are filtered out if a limit is set.
+    pub fn number_range(number_fn: impl 'static + Fn(&T) -> Option<f64>) -> Self {
+        Self {
+            kind: FilterKind::NumberRange(Rc::new(number_fn)),
+        }
+    }
+
+    // Text and select filters provide a plain text record value.
+    pub(crate) fn has_text(&self) -> bool {
+        matches!(self.kind, FilterKind::Text(_) | FilterKind::Select(..))
+    }
+
+    pub(crate) fn text(&self, record: &T) -> Option<String> {
+        match &self.kind {
+            FilterKind::Text(text_fn) | FilterKind::Select(_, text_fn) => Some(text_fn(record)),
+            FilterKind::NumberRange(_) => None,
+        }
+    }
+
+    pub(crate) fn matches(&self, value: &ColumnFilterValue, record: &T) -> bool {
+        match &self.kind {
+            FilterKind::Text(text_fn) => {
+                let filter = value.text.trim().to_lowercase();
+                filter.is_empty() || text_fn(record).to_lowercase().contains(&filter)
+            }
+            FilterKind::Select(_, text_fn) => {
+                value.text.is_empty() || text_fn(record) == value.text
+            }
+            FilterKind::NumberRange(number_fn) => {
+                let min = parse_float(value.text.trim()).ok();
+                let max = parse_float(value.max.trim()).ok();
+                if min.is_none() && max.is_none() {
+                    return true;
+                }
+                match number_fn(record) {
+                    Some(number) => {
+                        min.map(|min| number >= min).unwrap_or(true)
+                            && max.map(|max| number <= max).unwrap_or(true)
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+
+    // Render the filter widget. The callback gets the new value, and a flag
+    // if the change should be applied immediately (no debounce).
+    pub(crate) fn render(
+        &self,
+        name: &AttrValue,
+        value: &ColumnFilterValue,
+        on_change: Callback<(ColumnFilterValue, bool)>,
+    ) -> Html {
+        let aria_label = tr!("Filter {0}", name);
+        match &self.kind {
+            FilterKind::Text(_) => {
+                let value = value.clone();
+                filter_input(value.text.clone(), tr!("Filter"), aria_label)
+                    .attribute("type", "search")
+                    .oninput(move |event: InputEvent| {
+                        let input: HtmlInputElement = event.target_unchecked_into();
+                        let text = input.value();
+                        on_change.emit((
+                            ColumnFilterValue {
+                                text,
+                                ..value.clone()
+                            },
+                            false,
+                        ));
+                    })
+                    .into()
+            }
+            FilterKind::Select(options, _) => {
+                let current = value.text.clone();
+                let onchange = {
+                    let value = value.clone();
+                    move |event: Event| {
+                        let select: HtmlSelectElement = event.target_unchecked_into();
+                        let text = select.value();
+                        on_change.emit((
+                            ColumnFilterValue {
+                                text,
+                                ..value.clone()
+                            },
+                            true,
+                        ));
+                    }
+                };
+                html! {
+                    <select class="pwt-input pwt-w-100" aria-label={aria_label} {onchange}>
+                        <option value="" selected={current.is_empty()}>{tr!("All")}</option>
+                        {for options.iter().map(|option| html!{
+                            <option value={option.clone()} selected={current == *option}>{option}</option>
+                        })}
+                    </select>
+                }
+            }
+            FilterKind::NumberRange(_) => {
+                let min_input = filter_input(value.text.clone(), tr!("Min"), tr!("Minimum"))
+                    .attribute("inputmode", "decimal")
+                    .oninput({
+                        let on_change = on_change.clone();
+                        let value = value.clone();
+                        move |event: InputEvent| {
+                            let input: HtmlInputElement = event.target_unchecked_into();
+                            let text = input.value();
+                            on_change.emit((
+                                ColumnFilterValue {
+                                    text,
+                                    ..value.clone()
+                                },
+                                false,
+                            ));
+                        }
+                    });
+                let max_input = filter_input(value.max.clone(), tr!("Max"), tr!("Maximum"))
+                    .attribute("inputmode", "decimal")
+                    .oninput({
+                        let value = value.clone();
+                        move |event: InputEvent| {
+                            let input: HtmlInputElement = event.target_unchecked_into();
+                            let max = input.value();
+                            on_change.emit((
+                                ColumnFilterValue {
+                                    max,
+                                    ..value.clone()
+                                },
+                                false,
+                            ));
+                        }
+                    });
+                Row::new()
+                    .attribute("role", "group")
+                    .attribute("aria-label", aria_label)
+                    .gap(1)
+                    .with_child(min_input)
+                    .with_child(max_input)
+                    .into()
+            }
+        }
+    }
+}
+
+fn filter_input(value: String, placeholder: String, aria_label: String) -> Input {
+    Input::new()
+        .class("pwt-input")
+        .class(FullWidth)
+        .attribute("size", "1") // make size minimal
+        .attribute("autocomplete", "off")
+        .attribute("value", value)
+        .attribute("placeholder", placeholder)
+        .attribute("aria-label", aria_label)
+}
+
+/// Current value of a column filter.
+///
+/// Uses the raw input text, so that invalid (partial) numbers are kept.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnFilterValue {
+    /// Text, selected value or range minimum.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    /// Range maximum.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub max: String,
+}
+
+impl ColumnFilterValue {
+    /// Create a text (or selection) filter value.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            max: String::new(),
+        }
+    }
+
+    /// Create a range filter value (empty strings mean no limit).
+    pub fn range(min: impl Into<String>, max: impl Into<String>) -> Self {
+        Self {
+            text: min.into(),
+            max: max.into(),
+        }
+    }
+
+    /// Returns true if the filter is not set.
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty() && self.max.trim().is_empty()
+    }
+}
+
+// Compute unique filter IDs for a column list.
+//
+// Uses the column key if set, else the column name. Duplicates get a `#n`
+// suffix, so that columns with the same name do not share the filter value.
+pub(crate) fn column_filter_ids<'a>(
+    columns: impl IntoIterator<Item = (Option<&'a Key>, &'a AttrValue)>,
+) -> Vec<AttrValue> {
+    let mut used = HashSet::new();
+    columns
+        .into_iter()
+        .map(|(key, name)| {
+            let base = match key {
+                Some(key) => key.to_string(),
+                None => name.to_string(),
+            };
+            let mut id = base.clone();
+            let mut n = 1;
+            while used.contains(&id) {
+                n += 1;
+                id = format!("{base}#{n}");
+            }
+            used.insert(id.clone());
+            AttrValue::from(id)
+        })
+        .collect()
+}
+
+// Column filter values, and the store filter installed by the table.
+//
+// The column filters are combined with the filter set by the application
+// (base filter), so that clearing the column filters restores the
+// application filter.
+pub(crate) struct ColumnFilterState<T> {
+    // Filter values (by filter ID, see column_filter_ids).
+    values: HashMap<AttrValue, ColumnFilterValue>,
+    // Filter set by the application.
+    base_filter: Option<FilterFn<T>>,
+    // Filter installed by the table.
+    table_filter: Option<FilterFn<T>>,
+}
+
+impl<T: 'static> ColumnFilterState<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            values: HashMap::new(),
+            base_filter: None,
+            table_filter: None,
+        }
+    }
+
+    pub(crate) fn values(&self) -> &HashMap<AttrValue, ColumnFilterValue> {
+        &self.values
+    }
+
+    pub(crate) fn get(&self, id: &AttrValue) -> Option<&ColumnFilterValue> {
+        self.values.get(id)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // Set a filter value (empty values remove the filter).
+    pub(crate) fn set(&mut self, id: AttrValue, value: ColumnFilterValue) {
+        if value.is_empty() {
+            self.values.remove(&id);
+        } else {
+            self.values.insert(id, value);
+        }
+    }
+
+    pub(crate) fn set_values(
+        &mut self,
+        values: impl IntoIterator<Item = (AttrValue, ColumnFilterValue)>,
+    ) {
+        self.values.clear();
+        for (id, value) in values {
+            self.set(id, value);
+        }
+    }
+
+    // Clear all values. Returns true if there were any.
+    pub(crate) fn clear(&mut self) -> bool {
+        let had_values = !self.values.is_empty();
+        self.values.clear();
+        had_values
+    }
+
+    // Returns true if the store filter was replaced since the last update
+    // while column filters are active (needs a new update).
+    pub(crate) fn needs_update(&self, current: &Option<FilterFn<T>>) -> bool {
+        !self.values.is_empty() && *current != self.table_filter
+    }
+
+    // Compute the store filter for the given column filters (by filter ID).
+    //
+    // `current` is the filter currently set on the store. Returns the new
+    // filter if the store filter needs an update.
+    pub(crate) fn update(
+        &mut self,
+        current: Option<FilterFn<T>>,
+        filters: impl IntoIterator<Item = (AttrValue, DataTableColumnFilter<T>)>,
+    ) -> Option<Option<FilterFn<T>>> {
+        if current != self.table_filter {
+            // changed by the application
+            self.base_filter = current.clone();
+        }
+
+        let filters: Vec<_> = filters
+            .into_iter()
+            .filter_map(|(id, filter)| Some((filter, self.values.get(&id)?.clone())))
+            .collect();
+
+        let filter = if filters.is_empty() {
+            self.base_filter.clone()
+        } else {
+            let base_filter = self.base_filter.clone();
+            Some(FilterFn::new(move |record: &T| {
+                base_filter
+                    .as_ref()
+                    .map(|filter| filter.apply(record))
+                    .unwrap_or(true)
+                    && filters
+                        .iter()
+                        .all(|(filter, value)| filter.matches(value, record))
+            }))
+        };
+
+        self.table_filter = filter.clone();
+        (filter != current).then_some(filter)
+    }
+
+    // Record the filter actually set on the store (stores without local
+    // filters ignore it).
+    pub(crate) fn set_installed(&mut self, filter: Option<FilterFn<T>>) {
+        self.table_filter = filter;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use yew::AttrValue;
+    use yew::virtual_dom::Key;
+
+    use super::{ColumnFilterState, ColumnFilterValue, DataTableColumnFilter, column_filter_ids};
+    use crate::props::FilterFn;
+
+    fn name_filter() -> (AttrValue, DataTableColumnFilter<String>) {
+        (
+            AttrValue::from("name"),
+            DataTableColumnFilter::text(|record: &String| record.clone()),
+        )
+    }
+
+    fn apply(filter: &Option<FilterFn<String>>, data: &[&str]) -> Vec<String> {
+        data.iter()
+            .map(|item| item.to_string())
+            .filter(|item| filter.as_ref().map(|f| f.apply(item)).unwrap_or(true))
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_ids() {
+        let name = AttrValue::from("Name");
+        let other = AttrValue::from("Other");
+        let key = Key::from("key");
+        let ids = column_filter_ids([
+            (None, &name),
+            (Some(&key), &name),
+            (None, &name),
+            (None, &other),
+            (None, &name),
+        ]);
+        let ids: Vec<&str> = ids.iter().map(|id| id.as_str()).collect();
+        assert_eq!(ids, ["Name", "key", "Name#2", "Other", "Name#3"]);
+    }
+
+    #[test]
+    fn test_combine_with_base_filter() {
+        let data = ["alpha", "beta", "gamma", "delta"];
+        let mut state = ColumnFilterState::new();
+
+        // no column filters and no store filter - nothing to do
+        assert!(state.update(None, [name_filter()]).is_none());
+
+        // application filter is kept as base filter
+        let base = Some(FilterFn::new(|record: &String| record.len() == 5));
+        assert!(state.update(base.clone(), [name_filter()]).is_none());
+
+        state.set("name".into(), ColumnFilterValue::text("ta"));
+        let combined = state.update(base.clone(), [name_filter()]).unwrap();
+        assert_eq!(apply(&combined, &data), ["delta"]);
+        assert!(!state.needs_update(&combined));
+
+        // values of unknown filters are ignored
+        state.set("name".into(), ColumnFilterValue::default());
+        state.set("other".into(), ColumnFilterValue::text("x"));
+        assert!(state.update(combined.clone(), [name_filter()]) == Some(base.clone()));
+
+        // clearing restores the application filter
+        state.set("name".into(), ColumnFilterValue::text("a"));
+        let combined = state.update(base.clone(), [name_filter()]).unwrap();
+        assert_eq!(apply(&combined, &data), ["alpha", "gamma", "delta"]);
+        assert!(state.clear());
+        assert!(!state.clear());
+        assert!(state.update(combined, [name_filter()]) == Some(base));
+    }
+
+    #[test]
+    fn test_application_filter_change() {
+        let data = ["alpha", "beta", "gamma", "delta"];
+        let mut state = ColumnFilterState::new();
+
+        state.set("name".into(), ColumnFilterValue::text("a"));
+        let combined = state.update(None, [name_filter()]).unwrap();
+        assert_eq!(apply(&combined, &data), data);
+
+        // the application replaces the store filter
+        let base = Some(FilterFn::new(|record: &String| record.starts_with('g')));
+        assert!(state.needs_update(&base));
+        let combined = state.update(base.clone(), [name_filter()]).unwrap();
+        assert_eq!(apply(&combined, &data), ["gamma"]);
+
+        // the application removes its filter
+        assert!(state.needs_update(&None));
+        let combined = state.update(None, [name_filter()]).unwrap();
+        assert_eq!(apply(&combined, &data), data);
+
+        // store without local filters
+        state.set_installed(None);
+        assert!(!state.needs_update(&None));
+    }
+}
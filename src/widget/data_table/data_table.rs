@@ -1,8 +1,10 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::rc::Rc;
 
 use derivative::Derivative;
+use serde::{Deserialize, Serialize};
 
 use gloo_timers::callback::Timeout;
 use wasm_bindgen::JsCast;
@@ -11,26 +13,35 @@ use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
+use crate::css::Layer;
+use crate::dom::dnd::{self, DragObserver, DragPayload, DropPosition};
 use crate::dom::focus::{focus_inside_el, focus_inside_input, focus_next_el};
-use crate::dom::{DomSizeObserver, IntoHtmlElement};
+use crate::dom::{DomDragScroll, DomSizeObserver, IntoHtmlElement};
 use crate::prelude::*;
 use crate::props::{
     AsClassesMut, AsCssStylesMut, CallbackMut, CssLength, CssStyles, IntoEventCallbackMut,
-    SorterFn, WidgetStyleBuilder,
+    IntoOptionalRenderFn, IntoStorageLocation, RenderFn, SorterFn, StorageLocation,
+    WidgetStyleBuilder,
 };
-use crate::state::{DataStore, Selection, SelectionObserver};
-use crate::widget::{Column, Container, get_unique_element_id};
+use crate::state::{DataStore, Selection, SelectionObserver, load_state, store_state};
+use crate::widget::form::{Form, FormContext};
+use crate::widget::menu::{ContextMenu, Menu};
+use crate::widget::{ActionIcon, Button, Column, Container, Row, get_unique_element_id};
 
+use super::column_filter::{ColumnFilterState, column_filter_ids};
 use super::{
-    CellConfiguration, DataTableColumn, DataTableHeader, DataTableKeyboardEvent,
-    DataTableMouseEvent, DataTableRow, DataTableRowRenderCallback, HeaderWidget, IndexedHeader,
-    IntoOptionalDataTableRowRenderCallback, create_indexed_header_list,
+    CellConfiguration, ColumnFilterValue, DataTableCellEditEvent, DataTableColumn,
+    DataTableDragEvent, DataTableHeader, DataTableKeyboardEvent, DataTableMouseEvent, DataTableRow,
+    DataTableRowRenderCallback, DataTableView, HeaderViewState, HeaderWidget, IndexedHeader,
+    IntoOptionalDataTableRowRenderCallback, ViewManager, ViewManagerObserver, apply_header_order,
+    create_indexed_header_list,
 };
 
 pub enum HeaderMsg<T: 'static> {
     ToggleSelectAll,
     ColumnWidthChange(Vec<f64>),
     ColumnHiddenChange(Vec<bool>),
+    ColumnReorder(Vec<String>),
     ChangeSort(SorterFn<T>),
 }
 
@@ -47,8 +58,35 @@ pub enum Msg<T: 'static> {
     CursorRight,
     ItemClick(Key, Option<usize>, MouseEvent, bool),
     ItemDblClick(Key, Option<usize>, MouseEvent),
+    EmptyClick,
     FocusChange(bool, bool),
     Header(HeaderMsg<T>),
+    ToggleGroup(AttrValue),
+    ClearFilter,
+    FilterChange(AttrValue, ColumnFilterValue, bool), // apply now?
+    ApplyFilter,
+    DragStateChange(Option<DragPayload>),
+    RowDragOver(DragPayload, Option<Key>, DropPosition),
+    RowDragLeave,
+    RowDrop(DragPayload),
+    CellEditCommit(bool), // restore focus?
+    CellEditCancel,
+    CloseContextMenu,
+    FlashEnd,
+    ViewChange,
+}
+
+// Delay (ms) before applying filter input changes.
+const FILTER_DEBOUNCE_MS: u32 = 300;
+
+// Duration (ms) of the change highlight (see `flash_changes`).
+const FLASH_DURATION_MS: u32 = 1000;
+
+// Drag payload kind used for table rows.
+const ROW_DRAG_KIND: &str = "datatable-row";
+
+fn accept_row_drag(accept: &[AttrValue], payload: &DragPayload) -> bool {
+    payload.kind == ROW_DRAG_KIND && (accept.is_empty() || accept.contains(&payload.source_id))
 }
 
 /// Row selction status
@@ -76,12 +114,23 @@ pub enum MultiSelectMode {
 ///
 /// # Features
 ///
-/// - Virtual scrolling.
+/// - Virtual scrolling (rows and columns).
 /// - Trees and Lists.
 /// - Selection/Cursor management
 /// - Nested header definitions.
+/// - Grouped rows with sticky, collapsible group headers.
+/// - Optional status bar with row/selection counts.
+/// - Optional filter row (see [DataTableColumn::filter]).
+/// - Optional scroll position and cursor persistence.
 /// - Header menus (hide, sort, ...).
+/// - Optional row context menu (see [row_context_menu](Self::row_context_menu)).
 /// - Resizable headers.
+/// - Optional column reordering using drag and drop.
+/// - Optional column width, order and visibility persistence.
+/// - Optional saved views (see [view_manager](Self::view_manager)).
+/// - Optional row drag and drop (also between tables).
+/// - Inline cell editing (see [DataTableColumn::editor]).
+/// - Optional highlight of changed rows (see [flash_changes](Self::flash_changes)).
 /// - ARIA support: <https://www.w3.org/WAI/ARIA/apg/patterns/grid/>.
 ///
 /// # Keyboard bindings
@@ -107,6 +156,25 @@ pub enum MultiSelectMode {
 /// * `Home`: moves focus to the first cell in the row that contains focus.
 ///
 /// * `End`: moves focus to the last cell in the row that contains focus.
+///
+/// * `F2`: Starts editing the focused cell (if the column has an editor), or moves focus
+///    inside the cell. `Enter` commits the edit, `Escape` cancels it.
+///
+/// The column resize handle can be reached with `Tab` from a focused
+/// header cell:
+///
+/// * `Right Arrow`/`Left Arrow`: Makes the column wider/narrower (use `Shift` for larger steps).
+///
+/// * `Enter`: Keeps the new width, `Escape` restores the width before resizing.
+///
+/// A double click on the resize handle resets the column to its configured width.
+///
+/// Row drag and drop (see [row_drag_id](Self::row_drag_id) and
+/// [on_row_drop](Self::on_row_drop)) also works with the keyboard:
+///
+/// * `Ctrl+X`: Picks up the cursor row. `Escape` puts it back.
+///
+/// * `Ctrl+V`: Drops the picked up row after the cursor row (`Ctrl+Shift+V` drops it before).
 #[derive(Properties, Derivative)]
 #[derivative(Clone(bound = ""), PartialEq(bound = ""))]
 pub struct DataTable<S: DataStore> {
@@ -162,9 +230,22 @@ pub struct DataTable<S: DataStore> {
     /// Virtual Scroll
     ///
     /// Virtual scroll is enabled by default for tables with more than 30 rows.
+    ///
+    /// Note: Setting a [group_extract](Self::group_extract) function sets
+    /// this to `Some(false)`, because virtual scrolling needs uniform row
+    /// heights.
     #[prop_or_default]
     pub virtual_scroll: Option<bool>,
 
+    /// Virtual column scroll
+    ///
+    /// Only render the visible columns (plus a few more). This is
+    /// enabled by default for tables with more than 30 columns, and
+    /// requires fixed column widths (i.e. a visible header or virtual
+    /// scroll).
+    #[prop_or_default]
+    pub virtual_columns: Option<bool>,
+
     /// Minimum row height (default 22)
     ///
     /// Sets the minmum height for table rows. This is also used by
@@ -188,6 +269,26 @@ pub struct DataTable<S: DataStore> {
     #[prop_or_default]
     pub allow_deselect: bool,
 
+    /// Activate rows with a single click (for touch-first apps).
+    ///
+    /// A plain click selects the row and calls
+    /// [on_row_dblclick](Self::on_row_dblclick). Double clicks no longer
+    /// activate the row, so the callback is not called twice.
+    #[prop_or_default]
+    pub activate_on_single_click: bool,
+
+    /// Clear the selection when clicking on the empty area below the last row.
+    #[prop_or_default]
+    pub deselect_on_empty_click: bool,
+
+    /// Keep at least one row selected.
+    ///
+    /// Ignores selection changes which would remove the last selected row,
+    /// and selects the first row if the selection is empty (i.e. after
+    /// loading data).
+    #[prop_or_default]
+    pub disallow_empty_selection: bool,
+
     /// Show the header.
     #[prop_or(true)]
     pub show_header: bool,
@@ -212,6 +313,15 @@ pub struct DataTable<S: DataStore> {
     #[prop_or_default]
     pub on_row_context_click: Option<CallbackMut<DataTableMouseEvent>>,
 
+    /// Row context menu builder.
+    ///
+    /// If set, a right click (or the `ContextMenu` key) opens the returned
+    /// [Menu] at the mouse position (see [ContextMenu]). This is done after
+    /// calling [on_row_context_click](Self::on_row_context_click), which can
+    /// stop propagation to suppress the menu.
+    #[prop_or_default]
+    pub row_context_menu: Option<RenderFn<S::Record, Menu>>,
+
     #[prop_or_default]
     pub row_render_callback: Option<DataTableRowRenderCallback<S::Record>>,
 
@@ -221,6 +331,115 @@ pub struct DataTable<S: DataStore> {
     /// Table scroll callback
     #[prop_or_default]
     pub on_table_scroll: Option<Callback<Event>>,
+
+    /// Group extract function.
+    ///
+    /// If set, a group header row is rendered whenever the group
+    /// changes, so the store should be sorted by group (else the same
+    /// group header is shown several times). Group headers stick to the
+    /// top while scrolling, and clicking them collapses or expands the
+    /// group. Keyboard navigation skips collapsed rows.
+    ///
+    /// Note: The builder methods also disable virtual scrolling (see
+    /// [virtual_scroll](Self::virtual_scroll)). Calling
+    /// [virtual_scroll](DataTable::virtual_scroll) afterwards overrides that.
+    #[prop_or_default]
+    pub group_extract: Option<RenderFn<S::Record, AttrValue>>,
+
+    /// Show a status bar below the table.
+    ///
+    /// The status bar shows the number of (filtered) rows and selected
+    /// rows. If column filters are set, it also shows a button to clear
+    /// them (filters set by the application are kept).
+    #[prop_or_default]
+    pub show_status_bar: bool,
+
+    /// Show the filter row below the headers.
+    ///
+    /// Enabled by default if any column has a
+    /// [filter](DataTableColumn::filter). The filter row is only visible
+    /// together with the header.
+    #[prop_or_default]
+    pub show_filter_row: Option<bool>,
+
+    /// Briefly highlight rows when their record changes.
+    ///
+    /// Useful for live updating tables. Records are compared with the
+    /// previous store data (by key), so only modified records are
+    /// highlighted (not new ones). Columns can opt out using
+    /// [DataTableColumn::flash_on_change]. If the user prefers reduced
+    /// motion, the cells are highlighted without animation.
+    #[prop_or_default]
+    pub flash_changes: bool,
+
+    /// Additional widgets displayed at the right side of the status bar.
+    #[prop_or_default]
+    pub status_bar_tools: Vec<VNode>,
+
+    /// Save and restore the view state.
+    ///
+    /// If set, the scroll position, the cursor and the expanded tree
+    /// nodes/collapsed groups are saved when the component is destroyed,
+    /// and restored when it gets created again (i.e. when navigating
+    /// away and back in a TabPanel or NavigationContainer). The state
+    /// is restored once the store has data.
+    #[prop_or_default]
+    pub scroll_state_id: Option<StorageLocation>,
+
+    /// Save and restore the column state.
+    ///
+    /// If set, user defined column widths, the column order and the
+    /// hidden flags are saved on change, and restored when the table is
+    /// created. Columns are identified by their key (or name).
+    #[prop_or_default]
+    pub state_id: Option<StorageLocation>,
+
+    /// Allow the user to reorder columns by dragging the header cells.
+    ///
+    /// Columns can only be moved inside their header group, and groups
+    /// move as a unit.
+    #[prop_or_default]
+    pub column_reorder: bool,
+
+    /// Called after the user reordered the columns.
+    ///
+    /// The callback gets the new order as list of column keys (or names
+    /// if the column has no key), including group headers.
+    #[prop_or_default]
+    pub on_column_reorder: Option<Callback<Vec<String>>>,
+
+    /// Saved views (column layout, sort order and filters).
+    ///
+    /// The table reports its state to the view manager, and applies views
+    /// selected with [ViewManager::apply_view]. The views are also listed
+    /// in the column header menu.
+    #[prop_or_default]
+    pub view_manager: Option<ViewManager>,
+
+    /// Enable kinetic drag-to-scroll with the mouse (see [DomDragScroll]).
+    #[prop_or_default]
+    pub drag_scroll: bool,
+
+    /// Allow the user to drag rows.
+    ///
+    /// The ID identifies this table as drag source (see
+    /// [DataTableDragEvent::source_id]). Please do not combine this with
+    /// [drag_scroll](Self::drag_scroll).
+    #[prop_or_default]
+    pub row_drag_id: Option<AttrValue>,
+
+    /// Only accept rows dragged from these tables.
+    ///
+    /// Empty means that rows from any table are accepted.
+    #[prop_or_default]
+    pub row_drop_accept: Vec<AttrValue>,
+
+    /// Called when the user drops a row on this table.
+    ///
+    /// Setting this callback makes the table a drop target. The table
+    /// itself does not move any data.
+    #[prop_or_default]
+    pub on_row_drop: Option<Callback<DataTableDragEvent>>,
 }
 
 impl<S: DataStore> AsClassesMut for DataTable<S> {
@@ -241,6 +460,8 @@ impl<S: DataStore> CssMarginBuilder for DataTable<S> {}
 impl<S: DataStore> WidgetStyleBuilder for DataTable<S> {}
 
 static VIRTUAL_SCROLL_TRIGGER: usize = 30;
+// Number of additional columns rendered on each side (virtual column scroll).
+static VIRTUAL_COLUMN_OVERSCAN: usize = 2;
 
 impl<S: DataStore> DataTable<S> {
     /// Create a new instance.
@@ -341,6 +562,17 @@ impl<S: DataStore> DataTable<S> {
         self.virtual_scroll = virtual_scroll.into_prop_value();
     }
 
+    /// Builder style method to set the virtual column scroll flag.
+    pub fn virtual_columns(mut self, virtual_columns: impl IntoPropValue<Option<bool>>) -> Self {
+        self.set_virtual_columns(virtual_columns);
+        self
+    }
+
+    /// Method to set the virtual column scroll flag.
+    pub fn set_virtual_columns(&mut self, virtual_columns: impl IntoPropValue<Option<bool>>) {
+        self.virtual_columns = virtual_columns.into_prop_value();
+    }
+
     /// Builder style method to set the minimum row height.
     pub fn min_row_height(mut self, min_row_height: usize) -> Self {
         self.set_min_row_height(min_row_height);
@@ -374,6 +606,57 @@ impl<S: DataStore> DataTable<S> {
         self.allow_deselect = allow_deselect.into_prop_value();
     }
 
+    /// Builder style method to set the activate_on_single_click flag.
+    pub fn activate_on_single_click(
+        mut self,
+        activate_on_single_click: impl IntoPropValue<bool>,
+    ) -> Self {
+        self.set_activate_on_single_click(activate_on_single_click);
+        self
+    }
+
+    /// Method to set the activate_on_single_click flag.
+    pub fn set_activate_on_single_click(
+        &mut self,
+        activate_on_single_click: impl IntoPropValue<bool>,
+    ) {
+        self.activate_on_single_click = activate_on_single_click.into_prop_value();
+    }
+
+    /// Builder style method to set the deselect_on_empty_click flag.
+    pub fn deselect_on_empty_click(
+        mut self,
+        deselect_on_empty_click: impl IntoPropValue<bool>,
+    ) -> Self {
+        self.set_deselect_on_empty_click(deselect_on_empty_click);
+        self
+    }
+
+    /// Method to set the deselect_on_empty_click flag.
+    pub fn set_deselect_on_empty_click(
+        &mut self,
+        deselect_on_empty_click: impl IntoPropValue<bool>,
+    ) {
+        self.deselect_on_empty_click = deselect_on_empty_click.into_prop_value();
+    }
+
+    /// Builder style method to set the disallow_empty_selection flag.
+    pub fn disallow_empty_selection(
+        mut self,
+        disallow_empty_selection: impl IntoPropValue<bool>,
+    ) -> Self {
+        self.set_disallow_empty_selection(disallow_empty_selection);
+        self
+    }
+
+    /// Method to set the disallow_empty_selection flag.
+    pub fn set_disallow_empty_selection(
+        &mut self,
+        disallow_empty_selection: impl IntoPropValue<bool>,
+    ) {
+        self.disallow_empty_selection = disallow_empty_selection.into_prop_value();
+    }
+
     /// Builder style method to set the show_header flag.
     pub fn show_header(mut self, show_header: impl IntoPropValue<bool>) -> Self {
         self.set_show_header(show_header);
@@ -417,6 +700,17 @@ impl<S: DataStore> DataTable<S> {
         self
     }
 
+    /// Builder style method to set the row context menu builder.
+    pub fn row_context_menu(mut self, f: impl IntoOptionalRenderFn<S::Record, Menu>) -> Self {
+        self.set_row_context_menu(f);
+        self
+    }
+
+    /// Method to set the row context menu builder.
+    pub fn set_row_context_menu(&mut self, f: impl IntoOptionalRenderFn<S::Record, Menu>) {
+        self.row_context_menu = f.into_optional_render_fn();
+    }
+
     /// Builder style method to set the row double click callback.
     pub fn on_row_dblclick(mut self, cb: impl IntoEventCallbackMut<DataTableMouseEvent>) -> Self {
         self.on_row_dblclick = cb.into_event_cb_mut();
@@ -438,6 +732,112 @@ impl<S: DataStore> DataTable<S> {
         self
     }
 
+    /// Builder style method to show the status bar.
+    pub fn show_status_bar(mut self, show_status_bar: bool) -> Self {
+        self.set_show_status_bar(show_status_bar);
+        self
+    }
+
+    /// Method to show the status bar.
+    pub fn set_show_status_bar(&mut self, show_status_bar: bool) {
+        self.show_status_bar = show_status_bar;
+    }
+
+    /// Builder style method to highlight changed rows.
+    pub fn flash_changes(mut self, flash_changes: bool) -> Self {
+        self.set_flash_changes(flash_changes);
+        self
+    }
+
+    /// Method to highlight changed rows.
+    pub fn set_flash_changes(&mut self, flash_changes: bool) {
+        self.flash_changes = flash_changes;
+    }
+
+    /// Builder style method to show the filter row.
+    pub fn show_filter_row(mut self, show_filter_row: impl IntoPropValue<Option<bool>>) -> Self {
+        self.set_show_filter_row(show_filter_row);
+        self
+    }
+
+    /// Method to show the filter row.
+    pub fn set_show_filter_row(&mut self, show_filter_row: impl IntoPropValue<Option<bool>>) {
+        self.show_filter_row = show_filter_row.into_prop_value();
+    }
+
+    /// Builder style method to add a widget to the status bar.
+    pub fn with_status_bar_tool(mut self, tool: impl Into<VNode>) -> Self {
+        self.add_status_bar_tool(tool);
+        self
+    }
+
+    /// Method to add a widget to the status bar.
+    pub fn add_status_bar_tool(&mut self, tool: impl Into<VNode>) {
+        self.status_bar_tools.push(tool.into());
+    }
+
+    /// Builder style method to set the scroll state storage location.
+    pub fn scroll_state_id(mut self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_scroll_state_id(state_id);
+        self
+    }
+
+    /// Method to set the scroll state storage location.
+    pub fn set_scroll_state_id(&mut self, state_id: impl IntoStorageLocation) {
+        self.scroll_state_id = state_id.into_storage_location();
+    }
+
+    /// Builder style method to set the column state storage location.
+    pub fn state_id(mut self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_state_id(state_id);
+        self
+    }
+
+    /// Method to set the column state storage location.
+    pub fn set_state_id(&mut self, state_id: impl IntoStorageLocation) {
+        self.state_id = state_id.into_storage_location();
+    }
+
+    /// Builder style method to set the view manager.
+    pub fn view_manager(mut self, view_manager: impl IntoPropValue<Option<ViewManager>>) -> Self {
+        self.set_view_manager(view_manager);
+        self
+    }
+
+    /// Method to set the view manager.
+    pub fn set_view_manager(&mut self, view_manager: impl IntoPropValue<Option<ViewManager>>) {
+        self.view_manager = view_manager.into_prop_value();
+    }
+
+    /// Builder style method to enable drag-to-scroll.
+    pub fn drag_scroll(mut self, drag_scroll: bool) -> Self {
+        self.set_drag_scroll(drag_scroll);
+        self
+    }
+
+    /// Method to enable drag-to-scroll.
+    pub fn set_drag_scroll(&mut self, drag_scroll: bool) {
+        self.drag_scroll = drag_scroll;
+    }
+
+    /// Builder style method to set the group extract function.
+    ///
+    /// Note: This disables virtual scrolling (sets `virtual_scroll` to `Some(false)`).
+    pub fn group_extract(mut self, f: impl IntoOptionalRenderFn<S::Record, AttrValue>) -> Self {
+        self.set_group_extract(f);
+        self
+    }
+
+    /// Method to set the group extract function.
+    ///
+    /// Note: This disables virtual scrolling (sets `virtual_scroll` to `Some(false)`).
+    pub fn set_group_extract(&mut self, f: impl IntoOptionalRenderFn<S::Record, AttrValue>) {
+        self.group_extract = f.into_optional_render_fn();
+        if self.group_extract.is_some() {
+            self.virtual_scroll = Some(false);
+        }
+    }
+
     /// Returns the [DataStore].
     pub fn get_store(&self) -> S {
         self.store.clone()
@@ -462,6 +862,51 @@ impl<S: DataStore> DataTable<S> {
         self.on_table_scroll = cb.into_event_callback();
         self
     }
+
+    /// Builder style method to enable column reordering.
+    pub fn column_reorder(mut self, column_reorder: bool) -> Self {
+        self.set_column_reorder(column_reorder);
+        self
+    }
+
+    /// Method to enable column reordering.
+    pub fn set_column_reorder(&mut self, column_reorder: bool) {
+        self.column_reorder = column_reorder;
+    }
+
+    /// Builder style method to set the column reorder callback.
+    pub fn on_column_reorder(mut self, cb: impl IntoEventCallback<Vec<String>>) -> Self {
+        self.on_column_reorder = cb.into_event_callback();
+        self
+    }
+
+    /// Builder style method to set the row drag source ID.
+    pub fn row_drag_id(mut self, id: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.set_row_drag_id(id);
+        self
+    }
+
+    /// Method to set the row drag source ID.
+    pub fn set_row_drag_id(&mut self, id: impl IntoPropValue<Option<AttrValue>>) {
+        self.row_drag_id = id.into_prop_value();
+    }
+
+    /// Builder style method to set the accepted drag source IDs.
+    pub fn row_drop_accept(mut self, ids: impl IntoIterator<Item = impl Into<AttrValue>>) -> Self {
+        self.set_row_drop_accept(ids);
+        self
+    }
+
+    /// Method to set the accepted drag source IDs.
+    pub fn set_row_drop_accept(&mut self, ids: impl IntoIterator<Item = impl Into<AttrValue>>) {
+        self.row_drop_accept = ids.into_iter().map(Into::into).collect();
+    }
+
+    /// Builder style method to set the row drop callback.
+    pub fn on_row_drop(mut self, cb: impl IntoEventCallback<DataTableDragEvent>) -> Self {
+        self.on_row_drop = cb.into_event_callback();
+        self
+    }
 }
 
 #[derive(Default)]
@@ -470,6 +915,8 @@ struct VirtualScrollInfo {
     end: usize,
     height: f64,
     offset: f64,
+    // Visible columns (None if virtual column scroll is disabled)
+    columns: Option<Range<usize>>,
 }
 
 impl VirtualScrollInfo {
@@ -482,12 +929,30 @@ impl VirtualScrollInfo {
     }
 }
 
+// Cell editor state.
+struct CellEdit {
+    record_key: Key,
+    column_num: usize,
+    form_ctx: FormContext,
+}
+
 #[derive(Debug)]
 struct Cursor {
     pos: usize,
     record_key: Key,
 }
 
+// View state saved by [DataTable::scroll_state_id].
+#[derive(Serialize, Deserialize)]
+struct ScrollState {
+    scroll_top: usize,
+    cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    expanded: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    collapsed_groups: Vec<String>,
+}
+
 #[doc(hidden)]
 pub struct PwtDataTable<S: DataStore> {
     unique_id: AttrValue,
@@ -515,7 +980,9 @@ pub struct PwtDataTable<S: DataStore> {
     header_scroll_ref: NodeRef,
     scroll_ref: NodeRef,
     scroll_top: usize,
+    scroll_left: f64,
     set_scroll_top: Option<usize>,
+    pending_scroll_state: Option<ScrollState>,
     viewport_height: f64,
     viewport_width: f64,
     table_height: f64,
@@ -531,6 +998,38 @@ pub struct PwtDataTable<S: DataStore> {
     keypress_timeout: Option<Timeout>,
 
     focus_table: bool,
+
+    collapsed_groups: HashSet<AttrValue>,
+
+    drag_scroll: Option<DomDragScroll>,
+
+    // Column order set by the user.
+    column_order: Option<Vec<String>>,
+
+    // Row picked up by a drag operation (this table is the source).
+    drag_key: Option<Key>,
+    // Drop marker (this table is the target).
+    drop_target: Option<(Option<Key>, DropPosition)>,
+    _drag_observer: Option<DragObserver>,
+
+    cell_edit: Option<CellEdit>,
+
+    // Column filter IDs (same order as columns).
+    filter_ids: Vec<AttrValue>,
+    // Column filter values and the installed store filter.
+    filter_state: ColumnFilterState<S::Record>,
+    filter_timeout: Option<Timeout>,
+
+    context_menu: Option<(Menu, (f64, f64))>,
+
+    // Record data used to detect changes (if `flash_changes` is set).
+    flash_snapshot: HashMap<Key, S::Record>,
+    flash_keys: HashSet<Key>,
+    flash_timeout: Option<Timeout>,
+
+    _view_manager_observer: Option<ViewManagerObserver>,
+    // Last applied view (with serial number, see HeaderWidget::view).
+    applied_view: Option<(usize, Rc<DataTableView>)>,
 }
 
 // Generate first table row using the width from the column definitions.
@@ -574,6 +1073,272 @@ fn render_empty_row_with_sizes(widths: &[f64], column_hidden: &[bool], bordered:
 }
 
 impl<S: DataStore> PwtDataTable<S> {
+    fn update_drag_observer(&mut self, ctx: &Context<Self>) {
+        let props = ctx.props();
+        self._drag_observer = props
+            .row_drag_id
+            .is_some()
+            .then(|| DragObserver::new(ctx.link().callback(Msg::DragStateChange)));
+        self.update_drag_key(props, dnd::drag_payload());
+    }
+
+    // Returns true if the picked up row changed.
+    fn update_drag_key(&mut self, props: &DataTable<S>, payload: Option<DragPayload>) -> bool {
+        let drag_key = payload
+            .filter(|payload| {
+                payload.kind == ROW_DRAG_KIND
+                    && Some(&payload.source_id) == props.row_drag_id.as_ref()
+            })
+            .and_then(|payload| payload.keys.first().cloned());
+        if drag_key == self.drag_key {
+            return false;
+        }
+        self.drag_key = drag_key;
+        true
+    }
+
+    fn is_self_drop(props: &DataTable<S>, payload: &DragPayload, target_key: &Option<Key>) -> bool {
+        Some(&payload.source_id) == props.row_drag_id.as_ref()
+            && target_key.is_some()
+            && payload.keys.first() == target_key.as_ref()
+    }
+
+    fn emit_row_drop(
+        &self,
+        props: &DataTable<S>,
+        payload: DragPayload,
+        target_key: Option<Key>,
+        position: DropPosition,
+    ) {
+        if Self::is_self_drop(props, &payload, &target_key) {
+            return;
+        }
+        let Some(record_key) = payload.keys.first().cloned() else {
+            return;
+        };
+        if let Some(on_row_drop) = &props.on_row_drop {
+            on_row_drop.emit(DataTableDragEvent {
+                source_id: payload.source_id,
+                record_key,
+                target_key,
+                position,
+            });
+        }
+    }
+
+    fn start_cell_edit(
+        &mut self,
+        props: &DataTable<S>,
+        record_key: Key,
+        column_num: usize,
+    ) -> bool {
+        let editable = self
+            .columns
+            .get(column_num)
+            .map(|column| column.editor.is_some())
+            .unwrap_or(false);
+        if !editable {
+            return false;
+        }
+        if let Some(edit) = &self.cell_edit {
+            if edit.record_key == record_key && edit.column_num == column_num {
+                return true; // already editing
+            }
+        }
+
+        let cursor = self.filtered_record_pos(props, &record_key);
+        self.set_cursor(props, cursor);
+        self.active_column = column_num;
+
+        self.cell_edit = Some(CellEdit {
+            record_key,
+            column_num,
+            form_ctx: FormContext::new(),
+        });
+        true
+    }
+
+    fn render_cell_editor(&self, ctx: &Context<Self>, edit: &CellEdit, field: Html) -> Html {
+        Form::new()
+            .form_context(edit.form_ctx.clone())
+            .autofocus(true)
+            .with_child(field)
+            .onsubmit(|event: SubmitEvent| event.prevent_default())
+            .onkeydown(ctx.link().batch_callback(|event: KeyboardEvent| {
+                // do not trigger the table key bindings
+                event.stop_propagation();
+                if event.default_prevented() {
+                    return None; // handled by the field
+                }
+                match event.key().as_str() {
+                    "Enter" => {
+                        event.prevent_default();
+                        Some(Msg::CellEditCommit(true))
+                    }
+                    "Escape" => {
+                        event.prevent_default();
+                        Some(Msg::CellEditCancel)
+                    }
+                    _ => None,
+                }
+            }))
+            .onfocusout(ctx.link().batch_callback(|event: FocusEvent| {
+                // ignore focus moves inside the editor
+                let related: Option<web_sys::Node> = event
+                    .related_target()
+                    .and_then(|target| target.dyn_into().ok());
+                let current: Option<web_sys::Node> = event
+                    .current_target()
+                    .and_then(|target| target.dyn_into().ok());
+                match current {
+                    Some(current) if current.contains(related.as_ref()) => None,
+                    _ => Some(Msg::CellEditCommit(false)),
+                }
+            }))
+            .into()
+    }
+
+    fn add_row_drag_listeners(&self, ctx: &Context<Self>, mut viewport: Container) -> Container {
+        let props = ctx.props();
+
+        if let Some(drag_id) = &props.row_drag_id {
+            let drag_id = drag_id.clone();
+            let unique_id = self.unique_id.clone();
+            viewport = viewport
+                .ondragstart(move |event: DragEvent| {
+                    if let Some((record_key, _)) = dom_find_row_el(&event, &unique_id) {
+                        let payload =
+                            DragPayload::new(ROW_DRAG_KIND, drag_id.clone(), [record_key]);
+                        dnd::start_drag(&event, payload);
+                    }
+                })
+                .ondragend(|_| dnd::end_drag());
+        }
+
+        if props.on_row_drop.is_some() {
+            viewport = viewport
+                .ondragover({
+                    let link = ctx.link().clone();
+                    let unique_id = self.unique_id.clone();
+                    let accept = props.row_drop_accept.clone();
+                    move |event: DragEvent| {
+                        let Some(payload) =
+                            dnd::accept_drag(&event, |payload| accept_row_drag(&accept, payload))
+                        else {
+                            return;
+                        };
+                        let (target_key, position) = match dom_find_row_el(&event, &unique_id) {
+                            Some((record_key, el)) => {
+                                (Some(record_key), dnd::drop_position(&event, &el, false))
+                            }
+                            // below the last row
+                            None => (None, DropPosition::After),
+                        };
+                        link.send_message(Msg::RowDragOver(payload, target_key, position));
+                    }
+                })
+                .ondragleave({
+                    let link = ctx.link().clone();
+                    move |event: DragEvent| {
+                        // ignore moves between child elements
+                        let related: Option<web_sys::Node> = event
+                            .related_target()
+                            .and_then(|target| target.dyn_into().ok());
+                        let current: Option<web_sys::Node> = event
+                            .current_target()
+                            .and_then(|target| target.dyn_into().ok());
+                        if let Some(current) = current {
+                            if current.contains(related.as_ref()) {
+                                return;
+                            }
+                        }
+                        link.send_message(Msg::RowDragLeave);
+                    }
+                })
+                .ondrop({
+                    let link = ctx.link().clone();
+                    let accept = props.row_drop_accept.clone();
+                    move |event: DragEvent| {
+                        if let Some(payload) =
+                            dnd::accept_drag(&event, |payload| accept_row_drag(&accept, payload))
+                        {
+                            link.send_message(Msg::RowDrop(payload));
+                        }
+                    }
+                });
+        }
+
+        viewport
+    }
+
+    fn load_scroll_state(&mut self, props: &DataTable<S>) {
+        self.pending_scroll_state = props.scroll_state_id.as_ref().and_then(load_state);
+        self.restore_scroll_state(props);
+    }
+
+    // Note: stores are usually loaded asynchronously, so we keep the saved
+    // state until the store has data, and restore it exactly once.
+    fn restore_scroll_state(&mut self, props: &DataTable<S>) {
+        if props.store.is_empty() {
+            return;
+        }
+        let state = match self.pending_scroll_state.take() {
+            Some(state) => state,
+            None => return,
+        };
+
+        if !state.expanded.is_empty() {
+            let expanded: Vec<Key> = state.expanded.into_iter().map(Key::from).collect();
+            props.store.apply_expanded_keys(&expanded);
+        }
+
+        self.collapsed_groups = state
+            .collapsed_groups
+            .into_iter()
+            .map(AttrValue::from)
+            .collect();
+
+        if let Some(record_key) = state.cursor.map(Key::from) {
+            self.cursor = props
+                .store
+                .filtered_record_pos(&record_key)
+                .map(|pos| Cursor { pos, record_key });
+        }
+
+        self.scroll_top = state.scroll_top;
+        self.set_scroll_top = Some(state.scroll_top);
+    }
+
+    fn save_scroll_state(&self, props: &DataTable<S>) {
+        let state_id = match &props.scroll_state_id {
+            Some(state_id) => state_id,
+            None => return,
+        };
+
+        // never restored, so keep the saved state
+        if self.pending_scroll_state.is_some() {
+            return;
+        }
+
+        let state = ScrollState {
+            scroll_top: self.scroll_top,
+            cursor: self.cursor.as_ref().map(|c| c.record_key.to_string()),
+            expanded: props
+                .store
+                .expanded_keys()
+                .iter()
+                .map(|key| key.to_string())
+                .collect(),
+            collapsed_groups: self
+                .collapsed_groups
+                .iter()
+                .map(|group| group.to_string())
+                .collect(),
+        };
+
+        store_state(&state, state_id);
+    }
+
     // avoid slow search by lookup up keys nearby cursor first
     fn filtered_record_pos(&self, props: &DataTable<S>, key: &Key) -> Option<usize> {
         if let Some(Cursor { pos, .. }) = &self.cursor {
@@ -620,40 +1385,79 @@ impl<S: DataStore> PwtDataTable<S> {
             self.set_cursor(props, None);
             return;
         }
-        self.set_cursor(
-            props,
-            match &self.cursor {
-                Some(Cursor { pos, .. }) => {
-                    if (pos + lines) < len {
-                        Some(pos + lines)
-                    } else {
-                        Some(len - 1)
-                    }
+        let pos = match &self.cursor {
+            Some(Cursor { pos, .. }) => {
+                if (pos + lines) < len {
+                    pos + lines
+                } else {
+                    len - 1
                 }
-                None => Some(0),
-            },
-        );
+            }
+            None => 0,
+        };
+        let pos = self
+            .visible_pos(props, pos, true)
+            .or_else(|| self.visible_pos(props, pos, false));
+        self.set_cursor(props, pos);
     }
 
     fn cursor_up(&mut self, lines: usize, props: &DataTable<S>) {
         let len = props.store.filtered_data_len();
-        if len == 0 {
-            self.set_cursor(props, None);
-            return;
+        if len == 0 {
+            self.set_cursor(props, None);
+            return;
+        }
+        let pos = match &self.cursor {
+            Some(Cursor { pos, .. }) => {
+                if *pos > lines {
+                    pos - lines
+                } else {
+                    0
+                }
+            }
+            None => len - 1,
+        };
+        let pos = self
+            .visible_pos(props, pos, false)
+            .or_else(|| self.visible_pos(props, pos, true));
+        self.set_cursor(props, pos);
+    }
+
+    fn is_collapsed_pos(&self, props: &DataTable<S>, pos: usize) -> bool {
+        if self.collapsed_groups.is_empty() {
+            return false;
+        }
+        let group_extract = match &props.group_extract {
+            Some(group_extract) => group_extract,
+            None => return false,
+        };
+        props
+            .store
+            .filtered_data_range(pos..(pos + 1))
+            .next()
+            .map(|(_, item)| {
+                self.collapsed_groups
+                    .contains(&group_extract.apply(&*item.record()))
+            })
+            .unwrap_or(false)
+    }
+
+    // Returns the nearest position (in the given direction) which is not inside a collapsed group.
+    fn visible_pos(&self, props: &DataTable<S>, mut pos: usize, down: bool) -> Option<usize> {
+        let len = props.store.filtered_data_len();
+        while pos < len {
+            if !self.is_collapsed_pos(props, pos) {
+                return Some(pos);
+            }
+            if down {
+                pos += 1;
+            } else if pos > 0 {
+                pos -= 1;
+            } else {
+                break;
+            }
         }
-        self.set_cursor(
-            props,
-            match &self.cursor {
-                Some(Cursor { pos, .. }) => {
-                    if *pos > lines {
-                        Some(pos - lines)
-                    } else {
-                        Some(0)
-                    }
-                }
-                None => Some(len - 1),
-            },
-        );
+        None
     }
 
     fn select_position(
@@ -744,6 +1548,41 @@ impl<S: DataStore> PwtDataTable<S> {
         if let Some(selection) = &props.selection {
             selection.clear();
         }
+        self.ensure_selection(props);
+    }
+
+    // select the cursor row (or the first row) if disallow_empty_selection is set
+    fn ensure_selection(&mut self, props: &DataTable<S>) {
+        if !props.disallow_empty_selection {
+            return;
+        }
+        let selection = match &props.selection {
+            Some(selection) => selection,
+            None => return,
+        };
+        if !selection.is_empty() {
+            return;
+        }
+        let record_key = match &self.cursor {
+            Some(Cursor { record_key, .. })
+                if props.store.filtered_record_pos(record_key).is_some() =>
+            {
+                Some(record_key.clone())
+            }
+            _ => props.store.lookup_filtered_record_key(0),
+        };
+        if let Some(record_key) = record_key {
+            selection.select(record_key);
+        }
+    }
+
+    // true if removing `key` would empty the selection (see disallow_empty_selection)
+    fn keep_last_selected(&self, props: &DataTable<S>, key: &Key) -> bool {
+        props.disallow_empty_selection
+            && props
+                .selection
+                .as_ref()
+                .is_some_and(|selection| selection.len() == 1 && selection.contains(key))
     }
 
     fn update_selection_status(&mut self, props: &DataTable<S>) {
@@ -816,6 +1655,8 @@ impl<S: DataStore> PwtDataTable<S> {
         if selection.is_multiselect() {
             if !(shift || ctrl) && props.multiselect_mode != MultiSelectMode::Simple {
                 selection.clear();
+            } else if self.keep_last_selected(props, record_key) {
+                return false;
             }
 
             selection.toggle(record_key.clone());
@@ -893,7 +1734,205 @@ impl<S: DataStore> PwtDataTable<S> {
         }
     }
 
-    fn render_table(&self, props: &DataTable<S>, offset: f64, start: usize, end: usize) -> Html {
+    fn render_group_header(&self, ctx: &Context<Self>, group: &AttrValue, key: Key) -> Html {
+        let collapsed = self.collapsed_groups.contains(group);
+        let colspan = self.column_hidden.iter().filter(|hidden| !**hidden).count();
+        let onclick = ctx.link().callback({
+            let group = group.clone();
+            move |_: MouseEvent| Msg::ToggleGroup(group.clone())
+        });
+
+        Container::from_tag("tr")
+            .key(key)
+            .attribute("role", "none")
+            .class("pwt-datatable-group-header")
+            .class("pwt-pointer")
+            .with_child(
+                Container::from_tag("td")
+                    .attribute("role", "none")
+                    .attribute("colspan", colspan.max(1).to_string())
+                    .style("position", "sticky")
+                    .style("top", "0")
+                    .style("z-index", Layer::Sticky)
+                    .onclick(onclick)
+                    .with_child(html! {
+                        <i class={classes!(
+                            "fa",
+                            "fa-fw",
+                            if collapsed { "fa-caret-right" } else { "fa-caret-down" }
+                        )}></i>
+                    })
+                    .with_child(group.clone()),
+            )
+            .into()
+    }
+
+    fn render_placeholder_row(&self, props: &DataTable<S>, pos: usize) -> Html {
+        let colspan = self.column_hidden.iter().filter(|hidden| !**hidden).count();
+
+        Container::from_tag("tr")
+            .key(Key::from(format!("placeholder-{pos}")))
+            .attribute("role", "none")
+            .class("pwt-datatable-placeholder-row")
+            .with_child(
+                Container::from_tag("td")
+                    .attribute("role", "none")
+                    .attribute("colspan", colspan.max(1).to_string())
+                    .style("height", format!("{}px", props.min_row_height)),
+            )
+            .into()
+    }
+
+    // Highlight records which changed since the last call.
+    fn update_flash(&mut self, ctx: &Context<Self>) {
+        let props = ctx.props();
+        if !props.flash_changes {
+            self.flash_snapshot.clear();
+            return;
+        }
+
+        let mut snapshot = HashMap::new();
+        let mut changed = false;
+        for (_, item) in props.store.filtered_data() {
+            let record = item.record();
+            let key = props.store.extract_key(&*record);
+            if let Some(old) = self.flash_snapshot.get(&key) {
+                if *old != *record {
+                    self.flash_keys.insert(key.clone());
+                    changed = true;
+                }
+            }
+            snapshot.insert(key, (*record).clone());
+        }
+        self.flash_snapshot = snapshot;
+
+        if changed {
+            let link = ctx.link().clone();
+            self.flash_timeout = Some(Timeout::new(FLASH_DURATION_MS, move || {
+                link.send_message(Msg::FlashEnd);
+            }));
+        }
+    }
+
+    fn show_filter_row(&self, props: &DataTable<S>) -> bool {
+        props.show_header
+            && props
+                .show_filter_row
+                .unwrap_or_else(|| self.columns.iter().any(|column| column.filter.is_some()))
+    }
+
+    // Combine all column filters with the application filter and set them on the store.
+    fn apply_filter(&mut self, props: &DataTable<S>) {
+        let filters = self
+            .columns
+            .iter()
+            .zip(self.filter_ids.iter())
+            .filter_map(|(column, id)| Some((id.clone(), column.filter.clone()?)));
+
+        if let Some(filter) = self.filter_state.update(props.store.get_filter(), filters) {
+            // Note: this triggers a Msg::DataChange
+            props.store.set_filter(filter);
+            self.filter_state.set_installed(props.store.get_filter());
+        }
+    }
+
+    fn render_filter_row(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let empty_value = ColumnFilterValue::default();
+
+        let cells = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(column_num, _)| {
+                !self
+                    .column_hidden
+                    .get(*column_num)
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .map(|(column_num, column)| {
+                let width = match self.column_widths.get(column_num) {
+                    Some(width) => format!("{width}px"),
+                    None => column.width.to_string(),
+                };
+                let content = column.filter.as_ref().map(|filter| {
+                    let id = self.filter_ids[column_num].clone();
+                    let value = self.filter_state.get(&id).unwrap_or(&empty_value);
+                    let on_change =
+                        link.callback(move |(value, apply_now): (ColumnFilterValue, bool)| {
+                            Msg::FilterChange(id.clone(), value, apply_now)
+                        });
+                    filter.render(&column.name, value, on_change)
+                });
+                Container::new()
+                    .class("pwt-datatable-filter-cell")
+                    .style("flex", "0 0 auto")
+                    .style("width", width)
+                    .padding_x(1)
+                    .with_optional_child(content)
+            });
+
+        let clear = ActionIcon::new("fa fa-fw fa-times")
+            .tabindex(0)
+            .disabled(self.filter_state.is_empty())
+            .aria_label(tr!("Clear all filters"))
+            .on_activate(link.callback(|_| Msg::ClearFilter));
+
+        Row::new()
+            .key(Key::from("filter-row"))
+            .attribute("role", "row")
+            .class("pwt-datatable-filter-row")
+            .class(crate::css::AlignItems::Center)
+            .padding_y(1)
+            .children(cells.map(|cell| cell.into()))
+            .with_child(clear)
+            .into()
+    }
+
+    fn render_status_bar(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let visible = props.store.filtered_data_len();
+        // Note: tree stores count collapsed nodes, so we can only show visible rows.
+        let rows_text = if !props.store.is_tree() && props.store.get_filter().is_some() {
+            tr!("{0} of {1} rows", visible, props.store.data_len())
+        } else {
+            tr!("{0} rows", visible)
+        };
+
+        // only offer to clear filters owned by the table
+        let show_clear = !self.filter_state.is_empty();
+
+        let selected = props
+            .selection
+            .as_ref()
+            .map(|selection| selection.len())
+            .unwrap_or(0);
+
+        Row::new()
+            .key(Key::from("status-bar"))
+            .class("pwt-datatable-status-bar")
+            .class(crate::css::AlignItems::Center)
+            .style("flex", "0 0 auto")
+            .gap(2)
+            .padding_x(2)
+            .padding_y(1)
+            .with_child(rows_text)
+            .with_optional_child((selected > 0).then(|| tr!("({0} selected)", selected)))
+            .with_optional_child(show_clear.then(|| {
+                Button::new(tr!("Clear filter"))
+                    .icon_class("fa fa-filter")
+                    .class("pwt-button-text")
+                    .on_activate(ctx.link().callback(|_: MouseEvent| Msg::ClearFilter))
+            }))
+            .with_flex_spacer()
+            .children(props.status_bar_tools.clone())
+            .into()
+    }
+
+    fn render_table(&self, ctx: &Context<Self>, offset: f64, start: usize, end: usize) -> Html {
+        let props = ctx.props();
         let virtual_scroll = props.virtual_scroll.unwrap_or(true);
         let fixed_mode = props.show_header || virtual_scroll;
 
@@ -930,7 +1969,36 @@ impl<S: DataStore> PwtDataTable<S> {
         // keep track of keys that are displayed, to avoid duplicates
         let mut key_set = HashSet::new();
 
+        let mut last_group: Option<AttrValue> = None;
+        let mut group_set = HashSet::new();
+
+        // rows not loaded yet (i.e. by a PagedStore) get placeholders
+        let mut next_pos = start;
+
         for (filtered_pos, item) in props.store.filtered_data_range(start..end) {
+            for pos in next_pos..filtered_pos {
+                table.add_child(self.render_placeholder_row(props, pos));
+            }
+            next_pos = filtered_pos + 1;
+
+            if let Some(group_extract) = &props.group_extract {
+                let group = group_extract.apply(&*item.record());
+                if last_group.as_ref() != Some(&group) {
+                    let key = if group_set.insert(group.clone()) {
+                        Key::from(format!("group-header-{group}"))
+                    } else {
+                        // store not sorted by group - avoid duplicate key
+                        Key::from(format!("group-header-{filtered_pos}-{group}"))
+                    };
+                    table.add_child(self.render_group_header(ctx, &group, key));
+                }
+                let collapsed = self.collapsed_groups.contains(&group);
+                last_group = Some(group);
+                if collapsed {
+                    continue;
+                }
+            }
+
             let mut record_key = props.store.extract_key(&*item.record());
 
             let mut selected = false;
@@ -952,6 +2020,22 @@ impl<S: DataStore> PwtDataTable<S> {
                 // if no cursor, mark first row active
                 .unwrap_or(filtered_pos == start);
 
+            let dragging = self.drag_key.as_ref() == Some(&record_key);
+            let drop_position = match &self.drop_target {
+                Some((Some(target_key), position)) if target_key == &record_key => Some(*position),
+                _ => None,
+            };
+
+            let cell_editor = self
+                .cell_edit
+                .as_ref()
+                .filter(|edit| edit.record_key == record_key)
+                .and_then(|edit| {
+                    let editor = self.columns.get(edit.column_num)?.editor.as_ref()?;
+                    let field = editor.apply(&*item.record());
+                    Some((edit.column_num, self.render_cell_editor(ctx, edit, field)))
+                });
+
             let row = DataTableRow {
                 selection: props.selection.clone(),
                 unique_table_id: self.unique_id.clone(),
@@ -970,17 +2054,27 @@ impl<S: DataStore> PwtDataTable<S> {
                 is_expanded: item.expanded(),
                 is_leaf: item.is_leaf(),
                 level: item.level(),
+                draggable: props.row_drag_id.is_some(),
+                dragging,
+                drop_position,
+                cell_editor,
+                column_range: self.scroll_info.columns.clone(),
+                flash: self.flash_keys.contains(&record_key),
             };
 
             table.add_child(row);
         }
 
+        for pos in next_pos..end.min(props.store.filtered_data_len()) {
+            table.add_child(self.render_placeholder_row(props, pos));
+        }
+
         table.into_html_with_ref(self.table_ref.clone())
     }
 
-    fn render_scroll_content(&self, props: &DataTable<S>) -> Html {
+    fn render_scroll_content(&self, ctx: &Context<Self>) -> Html {
         let table = self.render_table(
-            props,
+            ctx,
             self.scroll_info.offset,
             self.scroll_info.start,
             self.scroll_info.end,
@@ -1046,21 +2140,105 @@ impl<S: DataStore> PwtDataTable<S> {
             end,
             offset,
             height,
+            columns: self.visible_column_range(props),
         };
+
+        // allow stores to load missing records on demand
+        props.store.request_range(start..end);
+    }
+
+    // Compute the visible columns from the horizontal scroll position.
+    fn visible_column_range(&self, props: &DataTable<S>) -> Option<Range<usize>> {
+        let fixed_mode = props.show_header || props.virtual_scroll.unwrap_or(true);
+        if !fixed_mode || self.column_widths.is_empty() || self.viewport_width <= 0.0 {
+            return None;
+        }
+
+        let column_count = self.columns.len();
+        if !props
+            .virtual_columns
+            .unwrap_or(column_count >= VIRTUAL_SCROLL_TRIGGER)
+        {
+            return None;
+        }
+
+        let left = self.scroll_left;
+        let right = left + self.viewport_width;
+
+        let mut start = None;
+        let mut end = column_count;
+        let mut pos = 0.0;
+
+        for (column_num, width) in self.column_widths.iter().enumerate() {
+            if let Some(true) = self.column_hidden.get(column_num) {
+                continue;
+            }
+            if pos >= right {
+                end = column_num;
+                break;
+            }
+            pos += width;
+            if start.is_none() && pos > left {
+                start = Some(column_num);
+            }
+        }
+
+        let start = start.unwrap_or(end).saturating_sub(VIRTUAL_COLUMN_OVERSCAN);
+        let end = (end + VIRTUAL_COLUMN_OVERSCAN).min(column_count);
+
+        Some(start..end)
+    }
+
+    // Report the filter values to the view manager.
+    fn update_view_filters(&self, props: &DataTable<S>) {
+        if let Some(view_manager) = &props.view_manager {
+            view_manager.update_current(|view| {
+                view.filters = self
+                    .filter_state
+                    .values()
+                    .iter()
+                    .map(|(id, value)| (id.to_string(), value.clone()))
+                    .collect();
+            });
+        }
+    }
+
+    fn update_view_manager_observer(&mut self, ctx: &Context<Self>) {
+        let props = ctx.props();
+        self._view_manager_observer = props.view_manager.as_ref().map(|view_manager| {
+            view_manager.add_listener(ctx.link().callback(|_| Msg::ViewChange))
+        });
+        self.update_view_filters(props);
     }
 
     fn init_headers(&mut self, props: &DataTable<S>) {
-        let headers = create_indexed_header_list(&props.headers);
+        if self.column_order.is_none() {
+            let view_state: Option<HeaderViewState> = props.state_id.as_ref().and_then(load_state);
+            self.column_order = view_state
+                .map(|view_state| view_state.order)
+                .filter(|order| !order.is_empty());
+        }
+        let ordered_headers = match &self.column_order {
+            Some(order) => Rc::new(apply_header_order(&props.headers, order)),
+            None => Rc::clone(&props.headers),
+        };
+
+        let headers = create_indexed_header_list(&ordered_headers);
 
         // fixme: try to put column info into indexed headers so we have only one place for that
         let mut columns = Vec::new();
-        for header in props.headers.iter() {
+        for header in ordered_headers.iter() {
             header.extract_column_list(&mut columns);
         }
         let mut column_hidden = Vec::new();
         for column in columns.iter() {
             column_hidden.push(column.hidden);
         }
+        self.filter_ids = column_filter_ids(
+            columns
+                .iter()
+                .map(|column| (column.key.as_ref(), &column.name)),
+        );
         self.headers = Rc::new(headers);
         self.columns = Rc::new(columns);
         self.column_hidden = Rc::new(column_hidden);
@@ -1108,7 +2286,9 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
             scroll_info: VirtualScrollInfo::default(),
             cell_config: Rc::new(cell_config),
             scroll_top: 0,
+            scroll_left: 0.0,
             set_scroll_top: None,
+            pending_scroll_state: None,
             viewport_height: 0.0,
             viewport_width: 0.0,
             viewport_size_observer: None,
@@ -1124,12 +2304,40 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
             keypress_timeout: None,
 
             focus_table: false,
+
+            collapsed_groups: HashSet::new(),
+
+            drag_scroll: None,
+            column_order: None,
+
+            drag_key: None,
+            drop_target: None,
+            _drag_observer: None,
+
+            cell_edit: None,
+
+            filter_ids: Vec::new(),
+            filter_state: ColumnFilterState::new(),
+            filter_timeout: None,
+
+            context_menu: None,
+
+            flash_snapshot: HashMap::new(),
+            flash_keys: HashSet::new(),
+            flash_timeout: None,
+
+            _view_manager_observer: None,
+            applied_view: None,
         };
+        me.update_view_manager_observer(ctx);
+        me.update_drag_observer(ctx);
         me.init_headers(props);
+        me.load_scroll_state(props);
 
         me.update_scroll_info(props);
         // fixme: remove unknown keys from selection
         me.update_selection_status(props);
+        me.update_flash(ctx);
         me
     }
 
@@ -1141,6 +2349,13 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                 true
             }
             Msg::DataChange => {
+                // the application replaced the store filter
+                if self.filter_state.needs_update(&props.store.get_filter()) {
+                    self.apply_filter(props);
+                }
+
+                self.restore_scroll_state(props);
+
                 // try to keep cursor on the same record
                 if let Some(Cursor { record_key, .. }) = &self.cursor {
                     self.cursor = self
@@ -1152,22 +2367,62 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                 }
                 self.update_scroll_info(props);
 
+                if let Some(edit) = &self.cell_edit {
+                    if self.filtered_record_pos(props, &edit.record_key).is_none() {
+                        self.cell_edit = None;
+                    }
+                }
+
                 if self.selection_status == RowSelectionStatus::All {
                     self.select_all(props);
                 } else {
                     self.cleanup_selection(props);
+                    self.ensure_selection(props);
                 }
                 self.update_selection_status(props);
+                self.update_flash(ctx);
+
+                true
+            }
+            Msg::FlashEnd => {
+                self.flash_timeout = None;
+                self.flash_keys.clear();
+                true
+            }
+            Msg::ViewChange => {
+                let view = match props.view_manager.as_ref().and_then(|vm| vm.take_pending()) {
+                    Some(view) => view,
+                    None => return false,
+                };
 
+                // Note: an empty order restores the default column order
+                self.column_order = Some(view.columns.order.clone());
+                self.init_headers(props);
+
+                self.filter_timeout = None;
+                self.filter_state.set_values(
+                    view.filters
+                        .iter()
+                        .map(|(id, value)| (AttrValue::from(id.clone()), value.clone())),
+                );
+                self.apply_filter(props);
+                self.update_view_filters(props);
+
+                // widths, hidden flags and sorters are applied by the header
+                let serial = self.applied_view.as_ref().map(|(n, _)| n + 1).unwrap_or(0);
+                self.applied_view = Some((serial, Rc::new(view)));
                 true
             }
             Msg::ScrollTo(x, y) => {
                 self.scroll_top = y.max(0) as usize;
+                // Note: scroll_left is negative in RTL mode
+                self.scroll_left = x.unsigned_abs() as f64;
                 if let Some(el) = self.header_scroll_ref.cast::<web_sys::Element>() {
                     el.set_scroll_left(x);
                 }
+                let old_columns = self.scroll_info.columns.clone();
                 self.update_scroll_info(props);
-                props.virtual_scroll.unwrap_or(true)
+                props.virtual_scroll.unwrap_or(true) || self.scroll_info.columns != old_columns
             }
             Msg::ViewportResize(width, height, scrollbar_size) => {
                 self.viewport_height = height.max(0.0);
@@ -1281,6 +2536,42 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                 }
 
                 let msg = match key {
+                    "x" | "X" if ctrl && props.row_drag_id.is_some() => {
+                        event.prevent_default();
+                        if let (Some(drag_id), Some(Cursor { record_key, .. })) =
+                            (&props.row_drag_id, &self.cursor)
+                        {
+                            dnd::start_keyboard_drag(DragPayload::new(
+                                ROW_DRAG_KIND,
+                                drag_id.clone(),
+                                [record_key.clone()],
+                            ));
+                        }
+                        return false;
+                    }
+                    "v" | "V" if ctrl && props.on_row_drop.is_some() => {
+                        let payload = dnd::keyboard_drag_payload(ROW_DRAG_KIND)
+                            .filter(|payload| accept_row_drag(&props.row_drop_accept, payload));
+                        if let Some(payload) = payload {
+                            event.prevent_default();
+                            let target_key = self.cursor.as_ref().map(|c| c.record_key.clone());
+                            let position = if shift {
+                                DropPosition::Before
+                            } else {
+                                DropPosition::After
+                            };
+                            dnd::end_drag();
+                            self.emit_row_drop(props, payload, target_key, position);
+                        }
+                        return false;
+                    }
+                    "Escape" if self.drag_key.is_some() => {
+                        if dnd::keyboard_drag_payload(ROW_DRAG_KIND).is_some() {
+                            event.prevent_default();
+                            dnd::end_drag();
+                        }
+                        return false;
+                    }
                     "PageDown" => {
                         event.prevent_default();
                         let rows = self.rows_per_page(props);
@@ -1363,6 +2654,10 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                             None => return false, // nothing to do
                         };
 
+                        if self.start_cell_edit(props, record_key.clone(), self.active_column) {
+                            return true;
+                        }
+
                         self.cell_focus_next(&record_key, false);
 
                         return false;
@@ -1469,6 +2764,9 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
 
                 let shift = event.shift_key();
                 let ctrl = event.ctrl_key();
+                let context_position = context.then(|| ContextMenu::event_position(&event));
+                let activate = props.activate_on_single_click && !(context || shift || ctrl);
+                let activate_event = activate.then(|| event.clone());
 
                 self.set_cursor(props, new_cursor);
 
@@ -1512,6 +2810,20 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                     _ => {}
                 }
 
+                if let (Some(position), Some(row_context_menu), Some(pos)) =
+                    (context_position, &props.row_context_menu, new_cursor)
+                {
+                    let menu = props
+                        .store
+                        .filtered_data_range(pos..(pos + 1))
+                        .next()
+                        .map(|(_, item)| row_context_menu.apply(&*item.record()));
+                    if let Some(menu) = menu {
+                        self.context_menu = Some((menu, position));
+                        return true;
+                    }
+                }
+
                 if shift {
                     if let Some(selection) = &props.selection {
                         self.select_range(
@@ -1532,13 +2844,25 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                             self.cursor.as_ref().map(|c| (c.pos, c.record_key.clone()))
                         {
                             self.last_select_position = Some(pos);
-                            selection.toggle(record_key);
+                            if !self.keep_last_selected(props, &record_key) {
+                                selection.toggle(record_key);
+                            }
                         }
                     }
                 } else {
                     self.select_cursor(props, false, ctrl);
                 }
 
+                if let (Some(event), Some(callback)) = (activate_event, &props.on_row_dblclick) {
+                    let mut event = DataTableMouseEvent {
+                        record_key,
+                        inner: event,
+                        selection: props.selection.clone(),
+                        stop_propagation: false,
+                    };
+                    callback.emit(&mut event);
+                }
+
                 true
             }
             Msg::ItemDblClick(record_key, opt_col_num, event) => {
@@ -1557,6 +2881,14 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                             }
                         }
                     }
+                    if self.start_cell_edit(props, record_key.clone(), col_num) {
+                        return true;
+                    }
+                }
+
+                // rows are already activated by the first click
+                if props.activate_on_single_click {
+                    return false;
                 }
 
                 if let Some(callback) = &props.on_row_dblclick {
@@ -1578,6 +2910,13 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
 
                 true
             }
+            Msg::EmptyClick => {
+                if !props.deselect_on_empty_click {
+                    return false;
+                }
+                self.clear_selection(props);
+                true
+            }
             Msg::FocusChange(has_focus, has_related_target) => {
                 if has_focus {
                     if let Some((row, column)) = self.find_focused_cell() {
@@ -1614,6 +2953,7 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
             }
             Msg::Header(HeaderMsg::ColumnWidthChange(column_widths)) => {
                 self.column_widths = column_widths;
+                self.update_scroll_info(props);
                 true
             }
             Msg::Header(HeaderMsg::ChangeSort(sorter_fn)) => {
@@ -1621,8 +2961,17 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                 props.store.set_sorter(sorter_fn);
                 false
             }
+            Msg::Header(HeaderMsg::ColumnReorder(order)) => {
+                self.column_order = Some(order.clone());
+                self.init_headers(props);
+                if let Some(on_column_reorder) = &props.on_column_reorder {
+                    on_column_reorder.emit(order);
+                }
+                true
+            }
             Msg::Header(HeaderMsg::ColumnHiddenChange(column_hidden)) => {
                 self.column_hidden = Rc::new(column_hidden);
+                self.update_scroll_info(props);
                 true
             }
             Msg::Header(HeaderMsg::ToggleSelectAll) => {
@@ -1633,6 +2982,114 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                 }
                 false
             }
+            Msg::ToggleGroup(group) => {
+                if !self.collapsed_groups.remove(&group) {
+                    self.collapsed_groups.insert(group);
+                }
+                // move the cursor out of collapsed groups
+                if let Some(pos) = self.cursor.as_ref().map(|c| c.pos) {
+                    if self.is_collapsed_pos(props, pos) {
+                        let pos = self
+                            .visible_pos(props, pos, true)
+                            .or_else(|| self.visible_pos(props, pos, false));
+                        self.set_cursor(props, pos);
+                    }
+                }
+                self.update_scroll_info(props);
+                true
+            }
+            Msg::ClearFilter => {
+                self.filter_timeout = None;
+                let had_values = self.filter_state.clear();
+                self.update_view_filters(props);
+                // restores the application filter
+                self.apply_filter(props);
+                had_values
+            }
+            Msg::FilterChange(id, value, apply_now) => {
+                self.filter_state.set(id, value);
+                self.update_view_filters(props);
+                if apply_now {
+                    self.filter_timeout = None;
+                    self.apply_filter(props);
+                } else {
+                    let link = ctx.link().clone();
+                    self.filter_timeout = Some(Timeout::new(FILTER_DEBOUNCE_MS, move || {
+                        link.send_message(Msg::ApplyFilter);
+                    }));
+                }
+                true
+            }
+            Msg::ApplyFilter => {
+                self.filter_timeout = None;
+                self.apply_filter(props);
+                false
+            }
+            Msg::CloseContextMenu => {
+                self.context_menu = None;
+                true
+            }
+            Msg::DragStateChange(payload) => self.update_drag_key(props, payload),
+            Msg::RowDragOver(payload, target_key, position) => {
+                let drop_target = if Self::is_self_drop(props, &payload, &target_key) {
+                    None
+                } else {
+                    Some((target_key, position))
+                };
+                if self.drop_target == drop_target {
+                    return false;
+                }
+                self.drop_target = drop_target;
+                true
+            }
+            Msg::RowDragLeave => self.drop_target.take().is_some(),
+            Msg::CellEditCommit(restore_focus) => {
+                let Some(edit) = &self.cell_edit else {
+                    return false;
+                };
+                let field = edit.form_ctx.read().registered_fields().into_iter().next();
+                if let Some(field) = field {
+                    let value = match field.result {
+                        Ok(value) => value,
+                        Err(_) => {
+                            // the field displays the validation error
+                            edit.form_ctx.write().validate_field(field.name);
+                            return false;
+                        }
+                    };
+                    if field.dirty {
+                        if let Some(on_cell_edit) = self
+                            .columns
+                            .get(edit.column_num)
+                            .and_then(|column| column.on_cell_edit.as_ref())
+                        {
+                            on_cell_edit.emit(DataTableCellEditEvent {
+                                record_key: edit.record_key.clone(),
+                                value,
+                            });
+                        }
+                    }
+                }
+                self.cell_edit = None;
+                if restore_focus {
+                    self.take_focus = true;
+                }
+                true
+            }
+            Msg::CellEditCancel => {
+                if self.cell_edit.take().is_none() {
+                    return false;
+                }
+                self.take_focus = true;
+                true
+            }
+            Msg::RowDrop(payload) => {
+                if let Some((target_key, position)) = self.drop_target.take() {
+                    self.emit_row_drop(props, payload, target_key, position);
+                }
+                dnd::end_drag();
+                true
+            }
         }
     }
 
@@ -1661,7 +3118,7 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
         let viewport = Container::new()
             .onscroll(on_table_scroll)
             .key(Key::from("table-viewport"))
-            .class("pwt-flex-fill")
+            .class(crate::css::Flex::Fill)
             .style(
                 "overflow",
                 if self.table_height < 1.0 {
@@ -1678,7 +3135,7 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
             .attribute("tabindex", "-1")
             .attribute("role", "rowgroup")
             .attribute("aria-label", "table body")
-            .with_child(self.render_scroll_content(props))
+            .with_child(self.render_scroll_content(ctx))
             .onfocusin(ctx.link().callback(|_| Msg::FocusChange(true, false)))
             .onfocusout(ctx.link().callback(|event: FocusEvent| {
                 Msg::FocusChange(false, event.related_target().is_some())
@@ -1699,6 +3156,9 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                 move |event: MouseEvent| {
                     if let Some((row_num, col_num)) = dom_find_record_num(&event, &unique_id) {
                         link.send_message(Msg::ItemClick(row_num, col_num, event, false));
+                    } else if event.target() == event.current_target() {
+                        // click on the empty area below the last row
+                        link.send_message(Msg::EmptyClick);
                     }
                 }
             })
@@ -1714,12 +3174,19 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
             .oncontextmenu({
                 let link = ctx.link().clone();
                 let unique_id = self.unique_id.clone();
+                let has_context_menu = props.row_context_menu.is_some();
                 move |event: MouseEvent| {
                     if let Some((row_num, col_num)) = dom_find_record_num(&event, &unique_id) {
+                        if has_context_menu {
+                            event.prevent_default();
+                        }
                         link.send_message(Msg::ItemClick(row_num, col_num, event, true));
                     }
                 }
-            })
+            });
+
+        let viewport = self
+            .add_row_drag_listeners(ctx, viewport)
             .into_html_with_ref(self.scroll_ref.clone());
 
         let multiselect = props
@@ -1756,7 +3223,7 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                     } else {
                         CssLength::Px(0.0)
                     })
-                    .class("pwt-overflow-hidden")
+                    .class(crate::css::Overflow::Hidden)
                     .class("pwt-datatable-header")
                     .class((!props.show_header).then_some("pwt-datatable-header-hidden"))
                     .with_child(
@@ -1764,11 +3231,26 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                             .focusable(props.header_focusable && props.show_header)
                             .selection_status(self.selection_status)
                             .header_class(header_class)
-                            .reserve_scroll_space(self.scrollbar_size.unwrap_or_default()),
+                            .reserve_scroll_space(self.scrollbar_size.unwrap_or_default())
+                            .available_width(self.viewport_width)
+                            .column_reorder(props.column_reorder)
+                            .state_id(props.state_id.clone())
+                            .view_manager(props.view_manager.clone())
+                            .view(self.applied_view.clone()),
+                    )
+                    .with_optional_child(
+                        self.show_filter_row(props)
+                            .then(|| self.render_filter_row(ctx)),
                     )
                     .into_html_with_ref(self.header_scroll_ref.clone()),
             )
             .with_child(viewport)
+            .with_optional_child(props.show_status_bar.then(|| self.render_status_bar(ctx)))
+            .with_optional_child(self.context_menu.as_ref().map(|(menu, position)| {
+                ContextMenu::new(menu.clone(), *position)
+                    .key(Key::from("context-menu"))
+                    .on_close(ctx.link().callback(|_| Msg::CloseContextMenu))
+            }))
             .into()
     }
 
@@ -1784,9 +3266,31 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
             self.init_headers(props);
         }
 
+        if props.store != old_props.store || props.headers != old_props.headers {
+            self.apply_filter(props);
+        }
+
+        if props.row_drag_id != old_props.row_drag_id {
+            self.update_drag_observer(ctx);
+        }
+
+        if props.view_manager != old_props.view_manager {
+            self.update_view_manager_observer(ctx);
+        }
+
+        if props.store != old_props.store || props.flash_changes != old_props.flash_changes {
+            // do not compare with data from another store
+            self.flash_snapshot.clear();
+            self.update_flash(ctx);
+        }
+
         true
     }
 
+    fn destroy(&mut self, ctx: &Context<Self>) {
+        self.save_scroll_state(ctx.props());
+    }
+
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         if first_render {
             if let Some(el) = self.scroll_ref.cast::<web_sys::Element>() {
@@ -1806,6 +3310,13 @@ impl<S: DataStore + 'static> Component for PwtDataTable<S> {
                 self.table_size_observer = Some(size_observer);
             }
         }
+
+        if ctx.props().drag_scroll != self.drag_scroll.is_some() {
+            self.drag_scroll = match self.scroll_ref.cast::<web_sys::HtmlElement>() {
+                Some(el) if ctx.props().drag_scroll => Some(DomDragScroll::new(&el)),
+                _ => None,
+            };
+        }
         if let Some(top) = self.set_scroll_top.take() {
             // Note: we delay setting ScrollTop until we rendered the
             // viewport with correct height. Else, set_scroll_top can
@@ -1890,6 +3401,27 @@ fn dom_find_focus_pos(el: web_sys::Element, unique_id: &str) -> Option<(Key, Opt
     None
 }
 
+// Find the [DataTable] row element associated with an event.
+fn dom_find_row_el(event: &Event, unique_id: &str) -> Option<(Key, web_sys::Element)> {
+    let unique_row_prefix = format!("{}-item-", unique_id);
+
+    let mut cur_el: Option<web_sys::Element> = event.target_dyn_into();
+
+    while let Some(el) = cur_el {
+        if el.tag_name() == "TR" {
+            if let Some(key_str) = el.id().strip_prefix(&unique_row_prefix) {
+                if key_str.is_empty() {
+                    break;
+                }
+                return Some((Key::from(key_str), el));
+            }
+        }
+        cur_el = el.parent_element();
+    }
+
+    None
+}
+
 // Find the [DataTable] record associated with a [MouseEvent].
 fn dom_find_record_num(event: &MouseEvent, unique_id: &str) -> Option<(Key, Option<usize>)> {
     let unique_row_prefix = format!("{}-item-", unique_id);
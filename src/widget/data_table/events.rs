@@ -1,8 +1,11 @@
 use std::ops::Deref;
 
+use serde_json::Value;
+
 use yew::prelude::*;
 use yew::virtual_dom::Key;
 
+use crate::dom::dnd::DropPosition;
 use crate::state::Selection;
 
 use super::HeaderMsg;
@@ -65,6 +68,36 @@ impl Deref for DataTableMouseEvent {
     }
 }
 
+/// Row drop event.
+///
+/// Emitted by the drop target table (see
+/// [DataTable::on_row_drop](super::DataTable::on_row_drop)). The table does not
+/// move any data, so the handler needs to update the stores.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataTableDragEvent {
+    /// The ID of the source table (see
+    /// [DataTable::row_drag_id](super::DataTable::row_drag_id)).
+    pub source_id: AttrValue,
+    /// The key of the dragged record.
+    pub record_key: Key,
+    /// The key of the target row, or `None` if dropped below the last row.
+    pub target_key: Option<Key>,
+    /// The drop position relative to the target row.
+    pub position: DropPosition,
+}
+
+/// Cell edit event.
+///
+/// Emitted after the user edited a cell (see
+/// [DataTableColumn::editor](super::DataTableColumn::editor)).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataTableCellEditEvent {
+    /// The key of the edited record.
+    pub record_key: Key,
+    /// The new value (submit value of the editor field).
+    pub value: Value,
+}
+
 /// Like [web_sys::KeyboardEvent], but allows to send header commands.
 pub struct DataTableHeaderKeyboardEvent<T: 'static> {
     pub(crate) on_message: Callback<HeaderMsg<T>>,
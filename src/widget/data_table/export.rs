@@ -0,0 +1,263 @@
+use std::rc::Rc;
+
+use derivative::Derivative;
+
+use yew::html::IntoPropValue;
+use yew::prelude::*;
+
+use crate::state::{DataStore, Selection};
+use crate::tr;
+use crate::web_sys_ext::clipboard_write_text;
+use crate::widget::menu::{Menu, MenuButton, MenuEntry, MenuEvent, MenuItem};
+
+use super::{DataTableColumn, DataTableHeader};
+
+/// Text format used by [DataTableExport::to_text].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataTableExportFormat {
+    /// Tab separated values (pastes into spreadsheets).
+    Tsv,
+    /// Markdown table.
+    Markdown,
+}
+
+/// Copy or print [DataTable](super::DataTable) rows.
+///
+/// Exports the selected rows, or all filtered rows if nothing is
+/// selected, in store order. Only visible columns with a plain text value
+/// are included (see [DataTableColumn::export_text]).
+///
+/// [menu_items](Self::menu_items) and [menu_button](Self::menu_button)
+/// provide ready-made actions (copy as TSV or Markdown, print).
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use pwt::prelude::*;
+/// # use pwt::state::{Selection, Store};
+/// # use pwt::widget::Toolbar;
+/// # use pwt::widget::data_table::{DataTableExport, DataTableHeader};
+/// # #[derive(Clone, PartialEq)] struct Guest { name: String }
+/// # fn create_toolbar(
+/// #     store: Store<Guest>,
+/// #     headers: Rc<Vec<DataTableHeader<Guest>>>,
+/// #     selection: Selection,
+/// # ) -> Toolbar {
+/// let export = DataTableExport::new(store, headers)
+///     .selection(selection)
+///     .title("Guests");
+/// Toolbar::new().with_child(export.menu_button())
+/// # }
+/// ```
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+pub struct DataTableExport<S: DataStore> {
+    store: S,
+    headers: Rc<Vec<DataTableHeader<S::Record>>>,
+    selection: Option<Selection>,
+    title: Option<AttrValue>,
+}
+
+impl<S: DataStore + 'static> DataTableExport<S> {
+    /// Create a new instance (using the table store and headers).
+    pub fn new(store: S, headers: impl Into<Rc<Vec<DataTableHeader<S::Record>>>>) -> Self {
+        Self {
+            store,
+            headers: headers.into(),
+            selection: None,
+            title: None,
+        }
+    }
+
+    /// Builder style method to set the selection.
+    pub fn selection(mut self, selection: impl IntoPropValue<Option<Selection>>) -> Self {
+        self.selection = selection.into_prop_value();
+        self
+    }
+
+    /// Builder style method to set the title (used for printing).
+    pub fn title(mut self, title: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.title = title.into_prop_value();
+        self
+    }
+
+    fn columns(&self) -> Vec<DataTableColumn<S::Record>> {
+        let mut columns = Vec::new();
+        for header in self.headers.iter() {
+            header.extract_column_list(&mut columns);
+        }
+        columns.retain(|column| !column.hidden && column.has_export_text());
+        columns
+    }
+
+    /// Returns the exported rows (selected or all filtered records).
+    pub fn records(&self) -> Vec<S::Record> {
+        let selection = self.selection.as_ref().filter(|s| !s.is_empty());
+        self.store
+            .filtered_data()
+            .filter(|(_, node)| selection.is_none_or(|selection| selection.contains(&node.key())))
+            .map(|(_, node)| (*node.record()).clone())
+            .collect()
+    }
+
+    // Column names and cell text for all exported rows.
+    fn table_text(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let columns = self.columns();
+        let names = columns.iter().map(|c| c.name.to_string()).collect();
+        let rows = self
+            .records()
+            .iter()
+            .map(|record| {
+                columns
+                    .iter()
+                    .map(|column| column.get_export_text(record).unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+        (names, rows)
+    }
+
+    /// Returns the rows as text (including a header line).
+    pub fn to_text(&self, format: DataTableExportFormat) -> String {
+        let (names, rows) = self.table_text();
+        match format {
+            DataTableExportFormat::Tsv => {
+                let line = |cells: &[String]| {
+                    let cells: Vec<String> = cells.iter().map(|cell| tsv_escape(cell)).collect();
+                    cells.join("\t")
+                };
+                let mut text = line(&names);
+                for row in rows {
+                    text.push('\n');
+                    text.push_str(&line(&row));
+                }
+                text
+            }
+            DataTableExportFormat::Markdown => {
+                let line = |cells: &[String]| {
+                    let cells: Vec<String> =
+                        cells.iter().map(|cell| markdown_escape(cell)).collect();
+                    format!("| {} |", cells.join(" | "))
+                };
+                let mut text = line(&names);
+                text.push('\n');
+                text.push_str(&line(&vec![String::from("---"); names.len()]));
+                for row in rows {
+                    text.push('\n');
+                    text.push_str(&line(&row));
+                }
+                text
+            }
+        }
+    }
+
+    /// Copy the rows to the clipboard.
+    pub fn copy_to_clipboard(&self, format: DataTableExportFormat) {
+        let _ = clipboard_write_text(&self.to_text(format));
+    }
+
+    /// Returns the rows as HTML document (print-friendly).
+    pub fn to_html(&self) -> String {
+        format!("<!DOCTYPE html><html>{}</html>", self.html_content())
+    }
+
+    // Content of the html element (head and body).
+    fn html_content(&self) -> String {
+        let (names, rows) = self.table_text();
+        let title = self.title.as_deref().map(html_escape).unwrap_or_default();
+
+        let mut html = format!(
+            "<head><meta charset=\"utf-8\"><title>{title}</title>\
+             <style>body {{ font-family: sans-serif; }} \
+             table {{ border-collapse: collapse; width: 100%; }} \
+             th, td {{ border: 1px solid #888; padding: 2px 4px; text-align: left; }} \
+             thead {{ display: table-header-group; }} \
+             tr {{ break-inside: avoid; }}</style></head><body>"
+        );
+        if !title.is_empty() {
+            html.push_str(&format!("<h1>{title}</h1>"));
+        }
+        html.push_str("<table><thead><tr>");
+        for name in names {
+            html.push_str(&format!("<th>{}</th>", html_escape(&name)));
+        }
+        html.push_str("</tr></thead><tbody>");
+        for row in rows {
+            html.push_str("<tr>");
+            for cell in row {
+                html.push_str(&format!("<td>{}</td>", html_escape(&cell)));
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</tbody></table></body>");
+        html
+    }
+
+    /// Open the rows in a new window and show the print dialog.
+    pub fn print(&self) {
+        let window = match gloo_utils::window().open_with_url_and_target("", "_blank") {
+            Ok(Some(window)) => window,
+            _ => {
+                log::error!("unable to open print window (blocked?)");
+                return;
+            }
+        };
+        let Some(document) = window.document() else {
+            return;
+        };
+        if let Some(root) = document.document_element() {
+            root.set_inner_html(&self.html_content());
+        }
+        if let Some(title) = &self.title {
+            document.set_title(title);
+        }
+        let _ = window.focus();
+        let _ = window.print();
+    }
+
+    /// Returns menu items to copy (TSV, Markdown) and print the rows.
+    pub fn menu_items(&self) -> Vec<MenuEntry> {
+        let copy_item = |text: String, format| {
+            let export = self.clone();
+            MenuItem::new(text)
+                .icon_class("fa fa-clipboard")
+                .on_select(move |_: MenuEvent| export.copy_to_clipboard(format))
+                .into()
+        };
+        let print = {
+            let export = self.clone();
+            MenuItem::new(tr!("Print"))
+                .icon_class("fa fa-print")
+                .on_select(move |_: MenuEvent| export.print())
+        };
+        vec![
+            copy_item(tr!("Copy as Text"), DataTableExportFormat::Tsv),
+            copy_item(tr!("Copy as Markdown"), DataTableExportFormat::Markdown),
+            print.into(),
+        ]
+    }
+
+    /// Returns a menu button with the [menu items](Self::menu_items).
+    pub fn menu_button(&self) -> MenuButton {
+        let export = self.clone();
+        MenuButton::new(tr!("Export"))
+            .icon_class("fa fa-share-square-o")
+            .menu_builder(move || Menu::new().items(export.menu_items()))
+    }
+}
+
+fn tsv_escape(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+fn markdown_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace(['\n', '\r'], " ")
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
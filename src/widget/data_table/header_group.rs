@@ -38,6 +38,46 @@ impl<T: 'static> DataTableHeader<T> {
             DataTableHeader::Group(group) => group.extract_column_list(list),
         }
     }
+
+    /// Returns the ID used to store the column state (key or name).
+    pub(crate) fn state_key(&self) -> String {
+        let (key, name) = match self {
+            DataTableHeader::Single(column) => (&column.key, &column.name),
+            DataTableHeader::Group(group) => (&group.key, &group.name),
+        };
+        match key {
+            Some(key) => key.to_string(),
+            None => name.to_string(),
+        }
+    }
+}
+
+/// Reorder headers using a list of column state keys.
+///
+/// Headers are only reordered inside their group, so groups move as a
+/// unit. Headers not contained in `order` are placed at the end.
+pub(crate) fn apply_header_order<T: 'static>(
+    headers: &[DataTableHeader<T>],
+    order: &[String],
+) -> Vec<DataTableHeader<T>> {
+    let mut list: Vec<DataTableHeader<T>> = headers
+        .iter()
+        .map(|header| match header {
+            DataTableHeader::Single(_) => header.clone(),
+            DataTableHeader::Group(group) => {
+                let mut group = group.clone();
+                group.children = apply_header_order(&group.children, order);
+                DataTableHeader::Group(group)
+            }
+        })
+        .collect();
+
+    list.sort_by_key(|header| {
+        let key = header.state_key();
+        order.iter().position(|k| k == &key).unwrap_or(usize::MAX)
+    });
+
+    list
 }
 
 /// Group of [headers](DataTableHeader).
@@ -267,6 +307,18 @@ impl<T: 'static> IndexedHeader<T> {
         }
     }
 
+    /// Returns the ID used to store the column state (key or name).
+    pub fn state_key(&self) -> String {
+        let (key, name) = match self {
+            Self::Single(single) => (&single.column.key, &single.column.name),
+            Self::Group(group) => (&group.key, &group.name),
+        };
+        match key {
+            Some(key) => key.to_string(),
+            None => name.to_string(),
+        }
+    }
+
     pub fn cell_range(&self) -> Range<usize> {
         match self {
             Self::Single(single) => single.cell_idx..(single.cell_idx + 1),
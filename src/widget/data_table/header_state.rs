@@ -1,16 +1,36 @@
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::props::SorterFn;
 
 use super::{IndexedHeader, IndexedHeaderSingle};
 
+#[derive(Clone)]
 struct CellState {
     width: Option<f64>,
     hidden: bool,
     sort_order: Option<bool>,
 }
 
+/// Column state saved by [DataTable::state_id](super::DataTable::state_id).
+///
+/// Columns are identified by their key (or name if there is no key).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeaderViewState {
+    /// Column order (state keys of all cells, pre-order).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order: Vec<String>,
+    /// User defined column widths.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub widths: BTreeMap<String, f64>,
+    /// Column hidden flags.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hidden: BTreeMap<String, bool>,
+}
+
 /// Store for header state
 ///
 /// - column sort order
@@ -73,6 +93,78 @@ impl<T: 'static> HeaderState<T> {
         }
     }
 
+    /// Rebuild the state for new headers (i.e. after reordering columns).
+    ///
+    /// Width, hidden and sort state is kept for cells with the same
+    /// state key.
+    pub fn update_headers(&mut self, headers: Rc<Vec<IndexedHeader<T>>>) {
+        let mut state = Self::new(headers);
+
+        let old_keys = self.cell_keys();
+        let old_cells: HashMap<&String, &CellState> =
+            old_keys.iter().zip(self.cell_state.iter()).collect();
+
+        for (cell_idx, key) in state.cell_keys().iter().enumerate() {
+            if let Some(cell_state) = old_cells.get(key) {
+                state.cell_state[cell_idx] = (*cell_state).clone();
+            }
+        }
+
+        let new_keys = state.cell_keys();
+        state.sort_order = self
+            .sort_order
+            .iter()
+            .filter_map(|old_idx| new_keys.iter().position(|key| key == &old_keys[*old_idx]))
+            .collect();
+
+        *self = state;
+    }
+
+    /// Returns the state keys for all cells (pre-order).
+    pub fn cell_keys(&self) -> Vec<String> {
+        self.cell_map.iter().map(|cell| cell.state_key()).collect()
+    }
+
+    /// Returns the state keys for all columns.
+    pub fn column_keys(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|cell| self.cell_map[cell.cell_idx].state_key())
+            .collect()
+    }
+
+    /// Apply saved column widths and hidden flags.
+    pub fn apply_view_state(&mut self, view_state: &HeaderViewState) {
+        for col_num in 0..self.columns.len() {
+            let cell_idx = self.columns[col_num].cell_idx;
+            let key = self.cell_map[cell_idx].state_key();
+            if let Some(width) = view_state.widths.get(&key) {
+                self.set_width(col_num, Some(*width));
+            }
+            if let Some(hidden) = view_state.hidden.get(&key) {
+                self.set_hidden(cell_idx, *hidden);
+            }
+        }
+    }
+
+    /// Returns the current column order, widths and hidden flags.
+    pub fn view_state(&self) -> HeaderViewState {
+        let mut view_state = HeaderViewState {
+            order: self.cell_keys(),
+            ..Default::default()
+        };
+        for (col_num, cell) in self.columns.iter().enumerate() {
+            let key = self.cell_map[cell.cell_idx].state_key();
+            if let Some(width) = self.get_width(col_num) {
+                view_state.widths.insert(key.clone(), width);
+            }
+            view_state
+                .hidden
+                .insert(key, self.get_cell_hidden(cell.cell_idx));
+        }
+        view_state
+    }
+
     pub fn get_width(&self, col_num: usize) -> Option<f64> {
         let cell_idx = self.columns[col_num].cell_idx;
         self.cell_state[cell_idx].width
@@ -107,10 +199,64 @@ impl<T: 'static> HeaderState<T> {
             Some(order) => !order,
             None => true,
         });
-        self.sort_order.push(cell_idx);
+        if !self.sort_order.contains(&cell_idx) {
+            self.sort_order.push(cell_idx);
+        }
         self.cell_state[cell_idx].sort_order = Some(order);
     }
 
+    /// Remove all sorters.
+    pub fn clear_sorters(&mut self) {
+        for cell in self.cell_state.iter_mut() {
+            cell.sort_order = None;
+        }
+        self.sort_order.clear();
+    }
+
+    /// Returns the sorted columns (state key, ascending).
+    pub fn sort_state(&self) -> Vec<(String, bool)> {
+        self.sort_order
+            .iter()
+            .filter_map(|cell_idx| {
+                self.get_column_sorter(*cell_idx)
+                    .map(|order| (self.cell_map[*cell_idx].state_key(), order))
+            })
+            .collect()
+    }
+
+    /// Replace all sorters (see [sort_state](Self::sort_state)).
+    ///
+    /// Unknown keys are ignored.
+    pub fn apply_sort_state(&mut self, sort_state: &[(String, bool)]) {
+        self.clear_sorters();
+        let keys = self.cell_keys();
+        for (key, order) in sort_state {
+            if let Some(cell_idx) = keys.iter().position(|k| k == key) {
+                if matches!(self.cell_map[cell_idx], IndexedHeader::Single(_)) {
+                    self.add_column_sorter(cell_idx, Some(*order));
+                }
+            }
+        }
+    }
+
+    /// Returns the position (starting at 1) inside the sorter chain.
+    ///
+    /// Only returns a value if more than one column is sorted.
+    pub fn get_sort_position(&self, cell_idx: usize) -> Option<usize> {
+        if self.sort_order.len() < 2 {
+            return None;
+        }
+        self.sort_order
+            .iter()
+            .position(|idx| *idx == cell_idx)
+            .map(|pos| pos + 1)
+    }
+
+    /// Returns true if any column is sorted.
+    pub fn has_sorters(&self) -> bool {
+        !self.sort_order.is_empty()
+    }
+
     fn bubble_up_hidden(&mut self, cell_idx: Option<usize>) {
         let cell_idx = match cell_idx {
             Some(cell_idx) => cell_idx,
@@ -232,3 +378,96 @@ impl<T: 'static> HeaderState<T> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use super::HeaderState;
+    use crate::widget::data_table::{DataTableColumn, create_indexed_header_list};
+
+    type Record = (&'static str, &'static str);
+
+    fn create_state() -> HeaderState<Record> {
+        let headers = vec![
+            DataTableColumn::new("Node")
+                .sorter(|a: &Record, b: &Record| a.0.cmp(b.0))
+                .into(),
+            DataTableColumn::new("Name")
+                .sorter(|a: &Record, b: &Record| a.1.cmp(b.1))
+                .sort_order(true)
+                .into(),
+        ];
+        HeaderState::new(Rc::new(create_indexed_header_list(&headers)))
+    }
+
+    fn sort_state(state: &HeaderState<Record>) -> Vec<(&str, bool)> {
+        state
+            .sort_order
+            .iter()
+            .map(|idx| {
+                (
+                    ["Node", "Name"][*idx],
+                    state.get_column_sorter(*idx).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_state() {
+        let mut state = create_state();
+        assert_eq!(sort_state(&state), vec![("Name", true)]);
+        assert_eq!(state.get_sort_position(1), None);
+
+        state.set_column_sorter(0, None);
+        assert_eq!(sort_state(&state), vec![("Node", true)]);
+        assert_eq!(state.get_column_sorter(1), None);
+        state.set_column_sorter(0, None);
+        assert_eq!(sort_state(&state), vec![("Node", false)]);
+
+        state.add_column_sorter(1, None);
+        assert_eq!(sort_state(&state), vec![("Node", false), ("Name", true)]);
+        assert_eq!(state.get_sort_position(0), Some(1));
+        assert_eq!(state.get_sort_position(1), Some(2));
+
+        // toggles the direction, keeps the position
+        state.add_column_sorter(1, None);
+        assert_eq!(sort_state(&state), vec![("Node", false), ("Name", false)]);
+
+        state.clear_sorters();
+        assert!(!state.has_sorters());
+        assert_eq!(state.get_column_sorter(0), None);
+        assert!(state.sort_state().is_empty());
+    }
+
+    #[test]
+    fn test_apply_sort_state() {
+        let mut state = create_state();
+        state.apply_sort_state(&[
+            ("Name".into(), true),
+            ("unknown".into(), false),
+            ("Node".into(), false),
+        ]);
+        assert_eq!(
+            state.sort_state(),
+            vec![("Name".to_string(), true), ("Node".to_string(), false)]
+        );
+        assert_eq!(state.get_sort_position(1), Some(1));
+        assert_eq!(state.get_sort_position(0), Some(2));
+    }
+
+    #[test]
+    fn test_combined_sorter() {
+        let mut state = create_state();
+        state.apply_sort_state(&[("Node".into(), false), ("Name".into(), true)]);
+
+        let sorter = state.create_combined_sorter_fn();
+        let mut records = vec![("b", "x"), ("a", "y"), ("b", "a"), ("a", "b")];
+        records.sort_by(|a, b| sorter.cmp(a, b));
+        assert_eq!(
+            records,
+            vec![("b", "a"), ("b", "x"), ("a", "b"), ("a", "y")]
+        );
+    }
+}
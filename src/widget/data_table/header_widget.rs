@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use derivative::Derivative;
@@ -10,16 +11,72 @@ use yew::html::Scope;
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
+use crate::dom::element_direction_rtl;
+use crate::props::{IntoStorageLocation, StorageLocation};
+use crate::state::PersistentState;
 use crate::widget::menu::{Menu, MenuCheckbox, MenuEvent, MenuItem};
 use crate::widget::{Container, Fa, get_unique_element_id};
 use crate::{impl_yew_std_props_builder, prelude::*};
 
 use super::{
-    DataTableHeaderKeyboardEvent, DataTableHeaderRenderArgs, DataTableHeaderTableLink, HeaderMsg,
-    HeaderState, IndexedHeader, IndexedHeaderGroup, IndexedHeaderSingle, ResizableHeader,
-    RowSelectionStatus,
+    ColumnWidth, DataTableHeaderKeyboardEvent, DataTableHeaderRenderArgs, DataTableHeaderTableLink,
+    DataTableView, HeaderMsg, HeaderState, HeaderViewState, IndexedHeader, IndexedHeaderGroup,
+    IndexedHeaderSingle, ResizableHeader, RowSelectionStatus, ViewManager,
 };
 
+// Width settings of a visible column.
+struct TrackSize<'a> {
+    width: &'a ColumnWidth,
+    // width set by the user (resize)
+    user_width: Option<f64>,
+    // rendered width (used for CSS widths)
+    observed_width: Option<f64>,
+}
+
+// Compute the grid track sizes.
+//
+// User defined widths (resize) override the column settings. Fixed
+// and percentage widths are subtracted from the available width, and
+// the remaining space is distributed to flex columns by weight. CSS
+// widths are accounted with their observed size. If the available
+// width is unknown, we simply pass the CSS equivalent.
+fn grid_track_sizes(columns: &[TrackSize], available: f64) -> String {
+    let mut used = 0.0;
+    let mut total_weight = 0.0;
+    for column in columns {
+        if let Some(width) = column.user_width {
+            used += width;
+            continue;
+        }
+        match column.width {
+            ColumnWidth::Fixed(px) => used += px,
+            ColumnWidth::Percent(percent) => used += (available * percent / 100.0).floor(),
+            ColumnWidth::Flex(weight) => total_weight += weight.max(0.0),
+            ColumnWidth::Css(_) => used += column.observed_width.unwrap_or(0.0),
+        }
+    }
+    let remaining = (available - used).max(0.0);
+
+    let mut grid_style = String::new();
+    for column in columns {
+        match (column.user_width, column.width) {
+            (Some(width), _) => grid_style.push_str(&format!("{width}px")),
+            (None, ColumnWidth::Percent(percent)) if available > 0.0 => {
+                grid_style.push_str(&format!("{}px", (available * percent / 100.0).floor()))
+            }
+            (None, ColumnWidth::Flex(weight)) if available > 0.0 && total_weight > 0.0 => {
+                // round down to avoid overflow (and a horizontal scrollbar)
+                let width = (remaining * weight.max(0.0) / total_weight).floor();
+                grid_style.push_str(&format!("{}px", width.max(ColumnWidth::MIN_FLEX_WIDTH)))
+            }
+            (None, width) => grid_style.push_str(&width.to_string()),
+        }
+        grid_style.push(' ');
+    }
+
+    grid_style
+}
+
 #[derive(Properties, Derivative)]
 #[derivative(Clone(bound = ""), PartialEq(bound = ""))]
 #[doc(hidden)] // only used inside this crate
@@ -43,6 +100,25 @@ pub struct HeaderWidget<T: 'static> {
     pub focusable: bool,
 
     reserve_scroll_space: f64,
+
+    // Available width for the columns (0.0 if unknown).
+    available_width: f64,
+
+    /// Save and restore column widths, order and hidden state.
+    #[prop_or_default]
+    pub state_id: Option<StorageLocation>,
+
+    /// Allow to reorder columns using drag and drop.
+    #[prop_or_default]
+    pub column_reorder: bool,
+
+    /// Report the column state to this view manager, and show the views in the header menu.
+    #[prop_or_default]
+    pub view_manager: Option<ViewManager>,
+
+    // View to apply (with serial number, so that we can apply the same view again).
+    #[prop_or_default]
+    view: Option<(usize, Rc<DataTableView>)>,
 }
 
 impl<T: 'static> HeaderWidget<T> {
@@ -52,6 +128,7 @@ impl<T: 'static> HeaderWidget<T> {
             headers,
             on_message,
             reserve_scroll_space: 0.0,
+            available_width: 0.0,
         })
     }
 
@@ -95,16 +172,69 @@ impl<T: 'static> HeaderWidget<T> {
     pub fn set_reserve_scroll_space(&mut self, reserve_scroll_space: f64) {
         self.reserve_scroll_space = reserve_scroll_space;
     }
+
+    /// Builder style method to set the available width (used to layout flex columns).
+    pub fn available_width(mut self, available_width: f64) -> Self {
+        self.set_available_width(available_width);
+        self
+    }
+
+    /// Method to set the available width (used to layout flex columns).
+    pub fn set_available_width(&mut self, available_width: f64) {
+        self.available_width = available_width;
+    }
+
+    /// Builder style method to enable column reordering.
+    pub fn column_reorder(mut self, column_reorder: bool) -> Self {
+        self.set_column_reorder(column_reorder);
+        self
+    }
+
+    /// Method to enable column reordering.
+    pub fn set_column_reorder(&mut self, column_reorder: bool) {
+        self.column_reorder = column_reorder;
+    }
+
+    /// Builder style method to set the column state storage location.
+    pub fn state_id(mut self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_state_id(state_id);
+        self
+    }
+
+    /// Method to set the column state storage location.
+    pub fn set_state_id(&mut self, state_id: impl IntoStorageLocation) {
+        self.state_id = state_id.into_storage_location();
+    }
+
+    /// Builder style method to set the view manager.
+    pub fn view_manager(mut self, view_manager: Option<ViewManager>) -> Self {
+        self.view_manager = view_manager;
+        self
+    }
+
+    /// Builder style method to apply a view.
+    ///
+    /// The view is applied when the serial number changes. Column order is
+    /// not handled here (it is part of the headers).
+    pub fn view(mut self, view: Option<(usize, Rc<DataTableView>)>) -> Self {
+        self.view = view;
+        self
+    }
 }
 
 pub enum Msg {
     ResizeColumn(usize, f64),
     ColumnSizeReset(usize),
     ColumnSizeChange(usize, f64),
-    ColumnSortChange(usize, bool, Option<bool>),
+    ColumnSortChange(usize, bool, Option<bool>), // cell, add to sorter chain?, order
+    ClearSort,
     HideClick(usize, bool),
     MoveCursor(bool),
     FocusCell(usize),
+    DragStart(usize),
+    DragOver(usize, bool),
+    Drop,
+    DragEnd,
 }
 
 pub struct PwtHeaderWidget<T: 'static> {
@@ -121,24 +251,168 @@ pub struct PwtHeaderWidget<T: 'static> {
     observed_widths: Vec<Option<f64>>,
 
     timeout: Option<Timeout>,
+
+    view_state: Option<PersistentState<HeaderViewState>>,
+
+    // Column reorder: dragged cell and drop target (cell, insert before)
+    drag_cell: Option<usize>,
+    drop_target: Option<(usize, bool)>,
 }
 
 impl<T: 'static> PwtHeaderWidget<T> {
-    fn compute_grid_columns(&self, ctx: &Context<Self>) -> String {
-        let mut grid_style = String::new();
-        for (col_idx, cell) in self.state.columns().iter().enumerate() {
-            if self.state.get_column_hidden(col_idx) {
-                continue;
-            }
-            if let Some(width) = self.state.get_width(col_idx) {
-                grid_style.push_str(&format!("{}px", width));
-            } else {
-                grid_style.push_str(&cell.column.width);
+    fn save_view_state(&mut self, props: &HeaderWidget<T>) {
+        if let Some(view_state) = &mut self.view_state {
+            view_state.update(self.state.view_state());
+        }
+        self.update_view_manager(props);
+    }
+
+    // Report column and sort state to the view manager.
+    fn update_view_manager(&self, props: &HeaderWidget<T>) {
+        if let Some(view_manager) = &props.view_manager {
+            view_manager.update_current(|view| {
+                view.columns = self.state.view_state();
+                view.sort = self.state.sort_state();
+            });
+        }
+    }
+
+    // Hidden columns have no size, so we do not wait for them.
+    fn reset_observed_widths(&mut self, observed_widths: &HashMap<String, f64>) {
+        self.observed_widths = self
+            .state
+            .column_keys()
+            .iter()
+            .enumerate()
+            .map(|(col_idx, key)| {
+                if self.state.get_column_hidden(col_idx) {
+                    Some(0.0)
+                } else {
+                    observed_widths.get(key).copied()
+                }
+            })
+            .collect();
+    }
+
+    // Send observed column widths to the table (after we got all sizes)
+    fn notify_column_widths(&mut self, props: &HeaderWidget<T>) {
+        let observed_widths: Vec<f64> = self.observed_widths.iter().filter_map(|w| *w).collect();
+
+        if self.state.columns().len() == observed_widths.len() {
+            let on_message = props.on_message.clone();
+            // use timeout to reduce the number of on_size_change callbacks
+            self.timeout = Some(Timeout::new(1, move || {
+                on_message.emit(HeaderMsg::ColumnWidthChange(observed_widths));
+            }));
+        }
+    }
+
+    // Columns can only be moved inside their group.
+    fn can_drop(&self, props: &HeaderWidget<T>, cell_idx: usize) -> bool {
+        let drag_cell = match self.drag_cell {
+            Some(drag_cell) if drag_cell != cell_idx => drag_cell,
+            _ => return false,
+        };
+        let parent = |cell_idx| {
+            IndexedHeader::lookup_cell(&props.headers, cell_idx).map(|cell| cell.parent())
+        };
+        match (parent(drag_cell), parent(cell_idx)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    // Compute the new cell order (see [apply_header_order](super::apply_header_order)).
+    fn reordered_cell_keys(&self, drag_cell: usize, target: usize, before: bool) -> Vec<String> {
+        let mut order = self.state.cell_keys();
+        let drag_key = order[drag_cell].clone();
+        let target_key = order[target].clone();
+
+        order.retain(|key| key != &drag_key);
+        let mut pos = order
+            .iter()
+            .position(|key| key == &target_key)
+            .unwrap_or(order.len());
+        if !before {
+            pos += 1;
+        }
+        order.insert(pos.min(order.len()), drag_key);
+        order
+    }
+
+    fn add_drag_listeners(
+        &self,
+        props: &HeaderWidget<T>,
+        link: &Scope<PwtHeaderWidget<T>>,
+        cell_idx: usize,
+        mut cell: Container,
+    ) -> Container {
+        if !props.column_reorder {
+            return cell;
+        }
+
+        if let Some((target, before)) = self.drop_target {
+            if target == cell_idx {
+                let side = if before { "-2px" } else { "2px" };
+                cell.set_style(
+                    "box-shadow",
+                    format!("inset {side} 0 0 var(--pwt-color-primary)"),
+                );
             }
-            grid_style.push(' ');
         }
 
-        let scrollbar_size = ctx.props().reserve_scroll_space;
+        cell.attribute("draggable", "true")
+            .class((self.drag_cell == Some(cell_idx)).then_some("pwt-datatable-header-dragging"))
+            .ondragstart(link.callback(move |event: DragEvent| {
+                if let Some(data_transfer) = event.data_transfer() {
+                    data_transfer.set_effect_allowed("move");
+                    // Note: Firefox needs some data to start the drag operation
+                    let _ = data_transfer.set_data("text/plain", "");
+                }
+                Msg::DragStart(cell_idx)
+            }))
+            .ondragover({
+                let link = link.clone();
+                let rtl = element_direction_rtl(&self.node_ref).unwrap_or(false);
+                move |event: DragEvent| {
+                    let Some(el) = event
+                        .current_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                    else {
+                        return;
+                    };
+                    let rect = el.get_bounding_client_rect();
+                    let left_half = (event.client_x() as f64) < rect.x() + rect.width() / 2.0;
+                    link.send_message(Msg::DragOver(cell_idx, left_half != rtl));
+                    // Note: we need to prevent the default to allow the drop
+                    // (we check the drop position again in update())
+                    event.prevent_default();
+                }
+            })
+            .ondrop(link.callback(|event: DragEvent| {
+                event.prevent_default();
+                Msg::Drop
+            }))
+            .ondragend(link.callback(|_| Msg::DragEnd))
+    }
+
+    // Compute the grid track sizes (see [grid_track_sizes]).
+    fn compute_grid_columns(&self, ctx: &Context<Self>) -> String {
+        let props = ctx.props();
+        let scrollbar_size = props.reserve_scroll_space;
+        let available = (props.available_width - scrollbar_size).max(0.0);
+
+        let columns: Vec<TrackSize> = (0..self.state.columns().len())
+            .filter(|col_idx| !self.state.get_column_hidden(*col_idx))
+            .map(|col_idx| TrackSize {
+                width: &self.state.columns()[col_idx].column.width,
+                user_width: self.state.get_width(col_idx),
+                observed_width: self.observed_widths.get(col_idx).copied().flatten(),
+            })
+            .collect();
+
+        let mut grid_style = grid_track_sizes(&columns, available);
+
         if scrollbar_size > 0.0 {
             grid_style.push_str(&format!(" {scrollbar_size}px"));
         }
@@ -230,10 +504,21 @@ impl<T: 'static> PwtHeaderWidget<T> {
 
         let sort_icon = match sort_order {
             Some(ascending) => {
-                if ascending {
-                    Fa::new("long-arrow-up").padding_end(1).into()
+                let icon = Fa::new(if ascending {
+                    "long-arrow-up"
                 } else {
-                    Fa::new("long-arrow-down").padding_end(1).into()
+                    "long-arrow-down"
+                });
+                // show the position for multi-column sort
+                match self.state.get_sort_position(cell_idx) {
+                    Some(position) => html! {<>
+                        {icon}
+                        {Container::from_tag("sup")
+                            .class("pwt-datatable-sort-badge")
+                            .padding_end(1)
+                            .with_child(position.to_string())}
+                    </>},
+                    None => icon.padding_end(1).into(),
                 }
             }
             None => html! {},
@@ -277,9 +562,10 @@ impl<T: 'static> PwtHeaderWidget<T> {
         }
         attributes.insert(AttrValue::Static("aria-label"), cell.column.name.clone());
 
+        let th = self.add_drag_listeners(props, link, cell_idx, Container::from_tag("th"));
+
         header_row.push(
-            Container::from_tag("th")
-                .key(Key::from(cell_idx))
+            th.key(Key::from(cell_idx))
                 .attribute("role", "columnheader")
                 .attribute("aria-sort", aria_sort)
                 .attribute(
@@ -295,7 +581,8 @@ impl<T: 'static> PwtHeaderWidget<T> {
                         .id(unique_id)
                         .class(header_class.clone())
                         .class(sort_order.map(|_| "sorted"))
-                        .class("pwt-w-100 pwt-h-100")
+                        .class(crate::css::FullWidth)
+                        .class(crate::css::FullHeight)
                         .attributes(attributes)
                         .content(header_content)
                         .resizable(cell.column.resizable)
@@ -311,7 +598,18 @@ impl<T: 'static> PwtHeaderWidget<T> {
                             let headers = Rc::clone(&props.headers);
                             let link = link.clone();
                             let hidden_cells = self.state.hidden_cells();
-                            move || build_header_menu(&headers, &link, cell_idx, &hidden_cells)
+                            let has_sorters = self.state.has_sorters();
+                            let view_manager = props.view_manager.clone();
+                            move || {
+                                build_header_menu(
+                                    &headers,
+                                    &link,
+                                    cell_idx,
+                                    &hidden_cells,
+                                    has_sorters,
+                                    view_manager.as_ref(),
+                                )
+                            }
                         }),
                 )
                 .onfocusin(link.callback(move |_| Msg::FocusCell(cell_idx)))
@@ -321,7 +619,7 @@ impl<T: 'static> PwtHeaderWidget<T> {
                         if sortable {
                             link.send_message(Msg::ColumnSortChange(
                                 cell_idx,
-                                event.ctrl_key(),
+                                event.shift_key() || event.ctrl_key(),
                                 None,
                             ));
                         }
@@ -346,7 +644,7 @@ impl<T: 'static> PwtHeaderWidget<T> {
                         if sortable && event.key().as_str() == "Enter" {
                             link.send_message(Msg::ColumnSortChange(
                                 cell_idx,
-                                event.ctrl_key(),
+                                event.shift_key() || event.ctrl_key(),
                                 None,
                             ));
                             event.prevent_default();
@@ -390,9 +688,10 @@ impl<T: 'static> PwtHeaderWidget<T> {
         );
         let span = span.max(1); // at least one column for the group header
 
+        let th = self.add_drag_listeners(props, link, cell_idx, Container::from_tag("th"));
+
         header_row.push(
-            Container::from_tag("th")
-                .key(Key::from(cell_idx))
+            th.key(Key::from(cell_idx))
                 // Note: ARIA has no notation for group headers. We need
                 // to hide them to get correct column order.
                 .attribute("role", "none")
@@ -451,7 +750,19 @@ impl<T: 'static> Component for PwtHeaderWidget<T> {
     fn create(ctx: &Context<Self>) -> Self {
         let props = ctx.props();
 
-        let state = HeaderState::new(Rc::clone(&props.headers));
+        let mut state = HeaderState::new(Rc::clone(&props.headers));
+
+        let view_state = props
+            .state_id
+            .as_ref()
+            .map(|state_id| PersistentState::<HeaderViewState>::new(state_id.clone()));
+
+        if let Some(view_state) = &view_state {
+            state.apply_view_state(view_state);
+            props
+                .on_message
+                .emit(HeaderMsg::ColumnHiddenChange(state.hidden_columns()));
+        }
 
         let sorter = state.create_combined_sorter_fn();
         props.on_message.emit(HeaderMsg::ChangeSort(sorter));
@@ -465,14 +776,19 @@ impl<T: 'static> Component for PwtHeaderWidget<T> {
             });
         }
 
-        Self {
+        let me = Self {
             unique_id: get_unique_element_id(),
             node_ref: NodeRef::default(),
             state,
             cursor: None,
             observed_widths,
             timeout: None,
-        }
+            view_state,
+            drag_cell: None,
+            drop_target: None,
+        };
+        me.update_view_manager(props);
+        me
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -485,27 +801,19 @@ impl<T: 'static> Component for PwtHeaderWidget<T> {
                 self.state
                     .copy_observed_widths(col_idx, &self.observed_widths);
 
+                self.save_view_state(props);
                 true
             }
             Msg::ColumnSizeReset(col_idx) => {
                 self.state.set_width(col_idx, None);
+                self.save_view_state(props);
                 true
             }
             Msg::ColumnSizeChange(col_num, width) => {
                 self.observed_widths
                     .resize((col_num + 1).max(self.observed_widths.len()), None);
                 self.observed_widths[col_num] = Some(width);
-
-                let observed_widths: Vec<f64> =
-                    self.observed_widths.iter().filter_map(|w| *w).collect();
-
-                if self.state.columns().len() == observed_widths.len() {
-                    let on_message = props.on_message.clone();
-                    // use timeout to reduce the number of on_size_change callbacks
-                    self.timeout = Some(Timeout::new(1, move || {
-                        on_message.emit(HeaderMsg::ColumnWidthChange(observed_widths));
-                    }));
-                }
+                self.notify_column_widths(props);
                 true
             }
             Msg::ColumnSortChange(cell_idx, ctrl_key, opt_order) => {
@@ -516,10 +824,19 @@ impl<T: 'static> Component for PwtHeaderWidget<T> {
                 }
                 let sorter = self.state.create_combined_sorter_fn();
                 props.on_message.emit(HeaderMsg::ChangeSort(sorter));
+                self.update_view_manager(props);
+                true
+            }
+            Msg::ClearSort => {
+                self.state.clear_sorters();
+                let sorter = self.state.create_combined_sorter_fn();
+                props.on_message.emit(HeaderMsg::ChangeSort(sorter));
+                self.update_view_manager(props);
                 true
             }
             Msg::HideClick(cell_idx, visible) => {
                 self.state.set_hidden(cell_idx, !visible);
+                self.save_view_state(props);
                 props
                     .on_message
                     .emit(HeaderMsg::ColumnHiddenChange(self.state.hidden_columns()));
@@ -554,7 +871,80 @@ impl<T: 'static> Component for PwtHeaderWidget<T> {
                 self.focus_active_cell();
                 true
             }
+            Msg::DragStart(cell_idx) => {
+                self.drag_cell = Some(cell_idx);
+                true
+            }
+            Msg::DragOver(cell_idx, before) => {
+                let drop_target = self.can_drop(props, cell_idx).then_some((cell_idx, before));
+                if self.drop_target == drop_target {
+                    return false;
+                }
+                self.drop_target = drop_target;
+                true
+            }
+            Msg::Drop => {
+                if let (Some(drag_cell), Some((target, before))) =
+                    (self.drag_cell.take(), self.drop_target.take())
+                {
+                    let order = self.reordered_cell_keys(drag_cell, target, before);
+                    if order != self.state.cell_keys() {
+                        props.on_message.emit(HeaderMsg::ColumnReorder(order));
+                    }
+                }
+                true
+            }
+            Msg::DragEnd => {
+                self.drag_cell = None;
+                self.drop_target = None;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+
+        let headers_changed = props.headers != old_props.headers;
+        let serial = |view: &Option<(usize, Rc<DataTableView>)>| view.as_ref().map(|(s, _)| *s);
+        let view_changed = serial(&props.view) != serial(&old_props.view);
+
+        if headers_changed || view_changed {
+            // keep state and observed sizes of existing columns
+            let observed_widths: HashMap<String, f64> = self
+                .state
+                .column_keys()
+                .into_iter()
+                .zip(self.observed_widths.iter())
+                .filter_map(|(key, width)| width.map(|width| (key, width)))
+                .collect();
+
+            if headers_changed {
+                self.state.update_headers(Rc::clone(&props.headers));
+                self.cursor = None;
+            }
+
+            if let (true, Some((_, view))) = (view_changed, &props.view) {
+                // start with the column defaults
+                self.state = HeaderState::new(Rc::clone(&props.headers));
+                self.state.apply_view_state(&view.columns);
+                if !view.sort.is_empty() {
+                    self.state.apply_sort_state(&view.sort);
+                }
+                let sorter = self.state.create_combined_sorter_fn();
+                props.on_message.emit(HeaderMsg::ChangeSort(sorter));
+            }
+
+            self.reset_observed_widths(&observed_widths);
+
+            props
+                .on_message
+                .emit(HeaderMsg::ColumnHiddenChange(self.state.hidden_columns()));
+            self.notify_column_widths(props);
+            self.save_view_state(props);
         }
+
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -589,7 +979,7 @@ impl<T: 'static> Component for PwtHeaderWidget<T> {
 
         Container::from_tag("table")
             .attribute("role", "row")
-            .class("pwt-d-grid")
+            .class(crate::css::Display::Grid)
             .style("user-select", "none")
             .style("display", "grid")
             .style("grid-template-columns", self.compute_grid_columns(ctx))
@@ -614,6 +1004,8 @@ fn build_header_menu<T>(
     link: &Scope<PwtHeaderWidget<T>>,
     cell_idx: usize,
     hidden_cells: &[bool],
+    has_sorters: bool,
+    view_manager: Option<&ViewManager>,
 ) -> Menu {
     let mut columns_menu = Menu::new();
     headers_to_menu(&mut columns_menu, 0, headers, link, hidden_cells, true);
@@ -625,7 +1017,7 @@ fn build_header_menu<T>(
     };
     let sortable = column.sorter.is_some();
 
-    Menu::new()
+    let mut menu = Menu::new()
         .with_item(
             MenuItem::new(tr!("Sort Ascending"))
                 .icon_class("fa fa-long-arrow-up")
@@ -642,8 +1034,56 @@ fn build_header_menu<T>(
                     link.callback(move |_| Msg::ColumnSortChange(cell_idx, false, Some(false))),
                 ),
         )
+        .with_item(
+            MenuItem::new(tr!("Add Sort Ascending"))
+                .disabled(!sortable || !has_sorters)
+                .on_select(
+                    link.callback(move |_| Msg::ColumnSortChange(cell_idx, true, Some(true))),
+                ),
+        )
+        .with_item(
+            MenuItem::new(tr!("Add Sort Descending"))
+                .disabled(!sortable || !has_sorters)
+                .on_select(
+                    link.callback(move |_| Msg::ColumnSortChange(cell_idx, true, Some(false))),
+                ),
+        )
+        .with_item(
+            MenuItem::new(tr!("Clear Sort"))
+                .icon_class("fa fa-times")
+                .disabled(!has_sorters)
+                .on_select(link.callback(|_| Msg::ClearSort)),
+        )
         .with_separator()
-        .with_item(MenuItem::new(tr!("Columns")).menu(columns_menu))
+        .with_item(MenuItem::new(tr!("Columns")).menu(columns_menu));
+
+    if let Some(view_manager) = view_manager {
+        menu.add_item(build_views_menu(view_manager));
+    }
+
+    menu
+}
+
+fn build_views_menu(view_manager: &ViewManager) -> MenuItem {
+    let active = view_manager.active();
+    let views = view_manager.views();
+    let disabled = views.is_empty();
+
+    let mut menu = Menu::new();
+    for view in views {
+        let checked = active.as_deref() == Some(view.name.as_str());
+        let view_manager = view_manager.clone();
+        let name = view.name.clone();
+        menu.add_item(
+            MenuCheckbox::radio(view.name)
+                .checked(checked)
+                .on_click(move |_: MenuEvent| view_manager.apply_view(&name)),
+        );
+    }
+    MenuItem::new(tr!("Views"))
+        .icon_class("fa fa-columns")
+        .disabled(disabled)
+        .menu(menu)
 }
 
 fn headers_to_menu<T>(
@@ -682,6 +1122,9 @@ fn headers_to_menu<T>(
         let last = last && checked && num_active < 2;
         match header {
             IndexedHeader::Single(cell) => {
+                if !cell.column.hideable {
+                    continue;
+                }
                 let label = html! {<>{indent.clone()}{cell.column.name.clone()}</>};
                 menu.add_item(
                     MenuCheckbox::new(label)
@@ -718,3 +1161,67 @@ impl<T: 'static> From<HeaderWidget<T>> for VNode {
         VNode::from(comp)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{ColumnWidth, TrackSize, grid_track_sizes};
+
+    fn track(width: &ColumnWidth) -> TrackSize<'_> {
+        TrackSize {
+            width,
+            user_width: None,
+            observed_width: None,
+        }
+    }
+
+    #[test]
+    fn test_flex_distribution() {
+        let fixed = ColumnWidth::Fixed(100.0);
+        let percent = ColumnWidth::Percent(10.0);
+        let flex1 = ColumnWidth::Flex(1.0);
+        let flex2 = ColumnWidth::Flex(2.0);
+        let columns = [track(&fixed), track(&percent), track(&flex1), track(&flex2)];
+
+        // 1000 - 100 - 100 = 800 remaining, split 1:2 (rounded down)
+        assert_eq!(
+            grid_track_sizes(&columns, 1000.0),
+            "100px 100px 266px 533px "
+        );
+
+        // flex columns keep their minimum width
+        assert_eq!(grid_track_sizes(&columns, 200.0), "100px 20px 50px 53px ");
+    }
+
+    #[test]
+    fn test_flex_with_user_and_css_widths() {
+        let flex = ColumnWidth::Flex(1.0);
+        let css = ColumnWidth::Css("auto".into());
+        let columns = [
+            TrackSize {
+                user_width: Some(300.0),
+                ..track(&flex)
+            },
+            TrackSize {
+                observed_width: Some(100.0),
+                ..track(&css)
+            },
+            track(&flex),
+        ];
+
+        // user width overrides the flex setting, the observed css width is subtracted
+        assert_eq!(grid_track_sizes(&columns, 1000.0), "300px auto 600px ");
+    }
+
+    #[test]
+    fn test_unknown_available_width() {
+        let fixed = ColumnWidth::Fixed(100.0);
+        let percent = ColumnWidth::Percent(25.0);
+        let flex = ColumnWidth::Flex(2.0);
+        let columns = [track(&fixed), track(&percent), track(&flex)];
+
+        assert_eq!(
+            grid_track_sizes(&columns, 0.0),
+            "100px 25% minmax(50px, 2fr) "
+        );
+    }
+}
@@ -1,10 +1,14 @@
 //! Flexible data table/tree widget.
 
 mod events;
-pub use events::{DataTableHeaderKeyboardEvent, DataTableKeyboardEvent, DataTableMouseEvent};
+pub use events::{
+    DataTableCellEditEvent, DataTableDragEvent, DataTableHeaderKeyboardEvent,
+    DataTableKeyboardEvent, DataTableMouseEvent,
+};
 
 mod header_state;
 pub(crate) use header_state::HeaderState;
+pub use header_state::HeaderViewState;
 
 mod resizable_header;
 pub(crate) use resizable_header::ResizableHeader;
@@ -12,7 +16,8 @@ pub(crate) use resizable_header::ResizableHeader;
 mod header_group;
 pub use header_group::{DataTableHeader, DataTableHeaderGroup};
 pub(crate) use header_group::{
-    IndexedHeader, IndexedHeaderGroup, IndexedHeaderSingle, create_indexed_header_list,
+    IndexedHeader, IndexedHeaderGroup, IndexedHeaderSingle, apply_header_order,
+    create_indexed_header_list,
 };
 
 mod row_render_callback;
@@ -35,7 +40,27 @@ pub use header_render_callback::{
 };
 
 mod column;
-pub use column::DataTableColumn;
+pub use column::{ColumnWidth, DataTableColumn};
+
+mod column_filter;
+pub use column_filter::{ColumnFilterValue, DataTableColumnFilter};
+
+mod row_actions;
+pub use row_actions::DataTableRowActions;
+#[doc(hidden)]
+pub use row_actions::PwtDataTableRowActions;
+pub(crate) use row_actions::ROW_ACTIONS_CLASS;
+
+mod export;
+pub use export::{DataTableExport, DataTableExportFormat};
+
+mod view_manager;
+pub use view_manager::{DataTableView, ViewManager, ViewManagerObserver};
+
+mod view_selector;
+pub use view_selector::DataTableViewSelector;
+#[doc(hidden)]
+pub use view_selector::PwtDataTableViewSelector;
 
 mod header_widget;
 pub(crate) use header_widget::HeaderWidget;
@@ -155,7 +180,7 @@ pub fn render_selection_header<T>(args: &mut DataTableHeaderRenderArgs<T>) -> Ht
         "fa-fw",
         match status {
             RowSelectionStatus::Nothing => "fa-square-o",
-            RowSelectionStatus::Some => "fa-plus-square-o",
+            RowSelectionStatus::Some => "fa-minus-square-o", // indeterminate
             RowSelectionStatus::All => "fa-check-square-o",
         },
     );
@@ -148,8 +148,16 @@ pub enum Msg {
     FocusChange(bool),
     ShowPicker,
     HidePicker,
+    SizeChange(f64),
+    KeyResize(f64),
+    KeyResizeCommit,
+    KeyResizeCancel,
 }
 
+// Keyboard resize steps (pixels)
+const RESIZE_STEP: f64 = 10.0;
+const RESIZE_LARGE_STEP: f64 = 50.0;
+
 #[doc(hidden)]
 pub struct PwtResizableHeader {
     rtl: Option<bool>,
@@ -162,6 +170,8 @@ pub struct PwtResizableHeader {
     menu_controller: MenuController,
     show_picker: bool,
     focus_tracker: FocusTracker,
+    // Width before starting a keyboard resize (used by Escape to cancel)
+    key_resize_start: Option<f64>,
 }
 
 impl PwtResizableHeader {
@@ -192,6 +202,7 @@ impl Component for PwtResizableHeader {
             menu_controller: MenuController::new(),
             show_picker: false,
             focus_tracker,
+            key_resize_start: None,
         }
     }
 
@@ -251,6 +262,41 @@ impl Component for PwtResizableHeader {
                 self.has_focus = has_focus;
                 if has_focus {
                     self.rtl = element_direction_rtl(&self.node_ref);
+                } else {
+                    // commit on blur
+                    self.key_resize_start = None;
+                }
+                true
+            }
+            Msg::KeyResize(delta) => {
+                let width = match self.node_ref.cast::<web_sys::Element>() {
+                    Some(el) => el.get_bounding_client_rect().width(),
+                    None => return false,
+                };
+                if self.key_resize_start.is_none() {
+                    self.key_resize_start = Some(width);
+                }
+                let delta = if self.rtl.unwrap_or(false) {
+                    -delta
+                } else {
+                    delta
+                };
+                self.width = (width + delta).max(0.0);
+                if let Some(on_resize) = &props.on_resize {
+                    on_resize.emit(self.width);
+                }
+                true
+            }
+            Msg::KeyResizeCommit => {
+                self.key_resize_start = None;
+                false
+            }
+            Msg::KeyResizeCancel => {
+                if let Some(width) = self.key_resize_start.take() {
+                    self.width = width;
+                    if let Some(on_resize) = &props.on_resize {
+                        on_resize.emit(width);
+                    }
                 }
                 true
             }
@@ -267,6 +313,15 @@ impl Component for PwtResizableHeader {
                 self.menu_controller.open();
                 true
             }
+            Msg::SizeChange(width) => {
+                self.width = width;
+                // Note: use current props, because the column index can change
+                if let Some(on_size_change) = &props.on_size_change {
+                    on_size_change.emit(width);
+                }
+                // update aria-valuenow
+                self.has_focus
+            }
         }
     }
 
@@ -320,10 +375,40 @@ impl Component for PwtResizableHeader {
         }
 
         if props.resizable {
+            let onkeydown = ctx.link().batch_callback(|event: KeyboardEvent| {
+                let step = if event.shift_key() {
+                    RESIZE_LARGE_STEP
+                } else {
+                    RESIZE_STEP
+                };
+                let msg = match event.key().as_str() {
+                    "ArrowRight" => Msg::KeyResize(step),
+                    "ArrowLeft" => Msg::KeyResize(-step),
+                    "Enter" => Msg::KeyResizeCommit,
+                    "Escape" => Msg::KeyResizeCancel,
+                    _ => return None,
+                };
+                // do not move the header cursor or sort the column
+                event.stop_propagation();
+                event.prevent_default();
+                Some(msg)
+            });
+
             anchor.add_child(
                 Container::new()
-                    .attribute("role", "none")
+                    .attribute("role", "separator")
+                    .attribute("aria-orientation", "vertical")
+                    .attribute("aria-label", tr!("Resize column"))
+                    .attribute("aria-valuenow", format!("{}", self.width.round()))
+                    .attribute("tabindex", if self.has_focus { "0" } else { "-1" })
                     .class("pwt-datatable-header-resize-trigger")
+                    .onkeydown(onkeydown)
+                    // Note: avoid starting a column drag (reorder) operation
+                    .attribute("draggable", "true")
+                    .ondragstart(|event: DragEvent| {
+                        event.prevent_default();
+                        event.stop_propagation();
+                    })
                     .onpointerdown(ctx.link().callback(|_| Msg::StartResize))
                     .onclick(|event: MouseEvent| {
                         event.stop_propagation();
@@ -350,13 +435,10 @@ impl Component for PwtResizableHeader {
 
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         if first_render {
-            let props = ctx.props();
             if let Some(el) = self.node_ref.cast::<web_sys::HtmlElement>() {
-                let on_size_change = props.on_size_change.clone();
+                let link = ctx.link().clone();
                 self.size_observer = Some(DomSizeObserver::new(&el, move |(x, _y)| {
-                    if let Some(on_size_change) = &on_size_change {
-                        on_size_change.emit(x);
-                    }
+                    link.send_message(Msg::SizeChange(x));
                 }));
             }
         }
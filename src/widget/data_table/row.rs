@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::rc::Rc;
 
 use derivative::Derivative;
@@ -7,9 +8,11 @@ use indexmap::IndexMap;
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
+use crate::dom::dnd::DropPosition;
+use crate::dom::get_system_prefer_reduced_motion;
 use crate::props::{ContainerBuilder, WidgetBuilder, WidgetStyleBuilder};
 use crate::state::Selection;
-use crate::widget::Container;
+use crate::widget::{Container, Tooltip};
 
 use super::{
     CellConfiguration, DataTableCellRenderArgs, DataTableColumn, DataTableRowRenderArgs,
@@ -49,23 +52,67 @@ pub(crate) struct DataTableRow<T: Clone + PartialEq + 'static> {
     pub is_leaf: bool,
     #[prop_or(0)]
     pub level: usize,
+    #[prop_or_default]
+    pub draggable: bool,
+    // Row is picked up by a drag operation.
+    #[prop_or_default]
+    pub dragging: bool,
+    // Show a drop marker.
+    #[prop_or_default]
+    pub drop_position: Option<DropPosition>,
+    // Cell editor (column number and editor content).
+    #[prop_or_default]
+    pub cell_editor: Option<(usize, Html)>,
+    // Visible columns (virtual column scroll). Other columns are replaced
+    // by empty spacer cells.
+    #[prop_or_default]
+    pub column_range: Option<Range<usize>>,
+    // Highlight changed cells (see `DataTable::flash_changes`).
+    #[prop_or_default]
+    pub flash: bool,
 }
 
 #[doc(hidden)]
+pub(crate) enum Msg {
+    // Full text of a truncated cell (column number, text)
+    OverflowTip(usize, Option<AttrValue>),
+}
+
 pub(crate) struct PwtDataTableRow<T: Clone + PartialEq + 'static> {
     _phantom: PhantomData<T>,
+    overflow_tip: Option<(usize, AttrValue)>,
 }
 
 impl<T: Clone + PartialEq + 'static> Component for PwtDataTableRow<T> {
-    type Message = ();
+    type Message = Msg;
     type Properties = DataTableRow<T>;
 
     fn create(_ctx: &Context<Self>) -> Self {
         Self {
             _phantom: PhantomData::<T>,
+            overflow_tip: None,
         }
     }
 
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::OverflowTip(column_num, text) => {
+                let overflow_tip = text.map(|text| (column_num, text));
+                if self.overflow_tip == overflow_tip {
+                    return false;
+                }
+                self.overflow_tip = overflow_tip;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, _ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        // the cell content may have changed
+        self.overflow_tip = None;
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
@@ -91,7 +138,18 @@ impl<T: Clone + PartialEq + 'static> Component for PwtDataTableRow<T> {
             )
             .attribute("id", item_id)
             .class((props.active_cell.is_some() && props.has_focus).then_some("row-cursor"))
-            .class(props.selected.then_some("selected")); // fixme: remove
+            .class(props.selected.then_some("selected")) // fixme: remove
+            .class(props.dragging.then_some("pwt-datatable-row-dragging"))
+            .attribute("draggable", props.draggable.then_some("true"));
+
+        if let Some(position) = props.drop_position {
+            let shadow = match position {
+                DropPosition::Before => "inset 0 2px 0 var(--pwt-color-primary)",
+                DropPosition::After => "inset 0 -2px 0 var(--pwt-color-primary)",
+                DropPosition::Inside => "inset 0 0 0 2px var(--pwt-color-primary)",
+            };
+            row.set_style("box-shadow", shadow);
+        }
 
         if let Some(row_render_callback) = &props.row_render_callback {
             let mut args = DataTableRowRenderArgs {
@@ -119,8 +177,17 @@ impl<T: Clone + PartialEq + 'static> Component for PwtDataTableRow<T> {
         let minheight_cell_style =
             AttrValue::Rc(format!("vertical-align:top;height: {}px;", props.min_row_height).into());
 
+        let flash_class = props.flash.then(|| {
+            if get_system_prefer_reduced_motion() {
+                "pwt-datatable-cell-changed"
+            } else {
+                "pwt-datatable-cell-flash"
+            }
+        });
+
         let mut col_index = 0;
         let mut column_num = 0;
+        let mut skipped = 0;
 
         while let Some(column) = props.columns.get(column_num) {
             if let Some(true) = props.column_hidden.get(column_num) {
@@ -140,6 +207,29 @@ impl<T: Clone + PartialEq + 'static> Component for PwtDataTableRow<T> {
                 None => false,
             };
 
+            let cell_editor = match &props.cell_editor {
+                Some((editor_column, editor)) if *editor_column == column_num => Some(editor),
+                _ => None,
+            };
+
+            let in_range = match &props.column_range {
+                Some(range) => range.contains(&column_num),
+                None => true,
+            };
+
+            // always render the active cell and the editor to keep the focus
+            if !in_range && !cell_active && cell_editor.is_none() {
+                skipped += 1;
+                col_index += 1;
+                column_num += 1;
+                continue;
+            }
+
+            if skipped > 0 {
+                row.add_child(render_spacer_cell(skipped));
+                skipped = 0;
+            }
+
             let mut args = DataTableCellRenderArgs {
                 selection: props.selection.clone(),
                 record: &props.record,
@@ -153,7 +243,10 @@ impl<T: Clone + PartialEq + 'static> Component for PwtDataTableRow<T> {
                 level: props.level,
             };
 
-            let cell = column.apply_render(&mut args);
+            let cell = match cell_editor {
+                Some(editor) => editor.clone(),
+                None => column.apply_render(&mut args),
+            };
 
             let mut td = Container::from_tag("td")
                 .class(args.config.class)
@@ -164,7 +257,28 @@ impl<T: Clone + PartialEq + 'static> Component for PwtDataTableRow<T> {
                 .attribute("role", "gridcell")
                 .attribute("data-column-num", column_num.to_string())
                 .attribute("tabindex", if cell_active { "0" } else { "-1" })
-                .with_child(html! {<div role="none">{cell}</div>});
+                .class(cell_editor.is_some().then_some("pwt-datatable-cell-editor"))
+                .class(flash_class.filter(|_| column.flash_on_change))
+                .with_child(if column.ellipsis && cell_editor.is_none() {
+                    let onmouseenter = ctx.link().callback(move |event: MouseEvent| {
+                        Msg::OverflowTip(column_num, overflow_text(&event))
+                    });
+                    let tip = self
+                        .overflow_tip
+                        .as_ref()
+                        .filter(|(num, _)| *num == column_num)
+                        .map(|(_, text)| text.clone());
+                    Tooltip::new(html! {
+                        <div role="none" class="pwt-text-truncate" {onmouseenter}>
+                            {cell}
+                        </div>
+                    })
+                    .attribute("role", "none")
+                    .tip(tip)
+                    .into()
+                } else {
+                    html! {<div role="none">{cell}</div>}
+                });
 
             let mut colspan = 1;
 
@@ -186,11 +300,30 @@ impl<T: Clone + PartialEq + 'static> Component for PwtDataTableRow<T> {
             row.add_child(td);
         }
 
+        if skipped > 0 {
+            row.add_child(render_spacer_cell(skipped));
+        }
+
         row.add_child(html! {<td role="none" style={minheight_cell_style.clone()}/>});
         row.into()
     }
 }
 
+// Placeholder for columns outside the visible range.
+fn render_spacer_cell(colspan: usize) -> Html {
+    html! {<td role="none" colspan={colspan.to_string()}/>}
+}
+
+// Returns the full cell content (shown as tooltip) if it is truncated.
+fn overflow_text(event: &MouseEvent) -> Option<AttrValue> {
+    let el: web_sys::Element = event.target_unchecked_into();
+    if el.scroll_width() > el.client_width() {
+        el.text_content().map(AttrValue::from)
+    } else {
+        None
+    }
+}
+
 impl<T: Clone + PartialEq + 'static> From<DataTableRow<T>> for VNode {
     fn from(val: DataTableRow<T>) -> Self {
         let key = Some(val.record_key.clone());
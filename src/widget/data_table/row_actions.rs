@@ -0,0 +1,179 @@
+use std::rc::Rc;
+
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::dom::focus::FocusTracker;
+use crate::impl_to_html;
+use crate::prelude::*;
+use crate::props::BuilderFn;
+use crate::widget::menu::{Menu, MenuPopper};
+use crate::widget::{ActionIcon, Container};
+
+/// CSS class used to find the action button inside a table cell.
+pub(crate) const ROW_ACTIONS_CLASS: &str = "pwt-row-actions";
+
+/// Per-row action button (kebab menu), used by [DataTableColumn::actions](super::DataTableColumn::actions).
+///
+/// The menu is created on demand when the button gets activated. The
+/// menu follows the button when the table scrolls, and is closed when it
+/// loses focus, or when the button gets scrolled out of view.
+#[derive(Properties, Clone, PartialEq)]
+pub struct DataTableRowActions {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// Menu builder.
+    pub menu_builder: BuilderFn<Menu>,
+}
+
+impl DataTableRowActions {
+    /// Creates a new instance.
+    pub fn new(menu_builder: impl Into<BuilderFn<Menu>>) -> Self {
+        yew::props!(Self {
+            menu_builder: menu_builder.into(),
+        })
+    }
+}
+
+pub enum Msg {
+    Toggle,
+    CloseMenu,
+    AnchorHidden,
+    FocusChange(bool),
+}
+
+#[doc(hidden)]
+pub struct PwtDataTableRowActions {
+    align_ref: NodeRef,
+    submenu_ref: NodeRef,
+    popper: MenuPopper,
+    show_menu: bool,
+    focus_tracker: FocusTracker,
+}
+
+impl PwtDataTableRowActions {
+    fn restore_focus(&self) {
+        if let Some(el) = self.align_ref.cast::<web_sys::HtmlElement>() {
+            let _ = el.focus();
+        }
+    }
+}
+
+impl Component for PwtDataTableRowActions {
+    type Message = Msg;
+    type Properties = DataTableRowActions;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let align_ref = NodeRef::default();
+        let submenu_ref = NodeRef::default();
+        let popper = MenuPopper::new(align_ref.clone(), submenu_ref.clone(), true)
+            .on_anchor_hidden(ctx.link().callback(|_| Msg::AnchorHidden));
+
+        Self {
+            align_ref,
+            submenu_ref,
+            popper,
+            show_menu: false,
+            focus_tracker: FocusTracker::new(ctx.link().callback(Msg::FocusChange)),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Toggle => {
+                self.show_menu = !self.show_menu;
+                true
+            }
+            Msg::CloseMenu => {
+                if !self.show_menu {
+                    return false;
+                }
+                self.show_menu = false;
+                self.restore_focus();
+                true
+            }
+            Msg::AnchorHidden => {
+                // Note: do not restore focus, because this would scroll the row into view
+                let changed = self.show_menu;
+                self.show_menu = false;
+                changed
+            }
+            Msg::FocusChange(has_focus) => {
+                if !has_focus && self.show_menu {
+                    self.show_menu = false;
+                    return true;
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let submenu = self.show_menu.then(|| {
+            let menu = props
+                .menu_builder
+                .apply()
+                .autofocus(true)
+                .on_close(ctx.link().callback(|_| Msg::CloseMenu));
+
+            Container::new()
+                .attribute("role", "none")
+                .class("pwt-submenu")
+                .with_child(menu)
+                .into_html_with_ref(self.submenu_ref.clone())
+        });
+
+        let button = ActionIcon::new("fa fa-fw fa-ellipsis-v")
+            .class(ROW_ACTIONS_CLASS)
+            .aria_label(tr!("Actions"))
+            .attribute("aria-haspopup", "true")
+            .attribute("aria-expanded", self.show_menu.then_some("true"))
+            .on_activate(ctx.link().callback(|_| Msg::Toggle));
+
+        Container::new()
+            .style("display", "contents")
+            .attribute("role", "none")
+            .onfocusin(self.focus_tracker.get_focus_callback(true))
+            .onfocusout(self.focus_tracker.get_focus_callback(false))
+            .onkeydown({
+                let link = ctx.link().clone();
+                let show_menu = self.show_menu;
+                move |event: KeyboardEvent| {
+                    if !show_menu {
+                        return;
+                    }
+                    if event.key() == "Escape" {
+                        link.send_message(Msg::CloseMenu);
+                        event.prevent_default();
+                    }
+                    // do not let the table handle keys used for menu navigation
+                    event.stop_propagation();
+                }
+            })
+            .onclick(|event: MouseEvent| event.stop_propagation())
+            .with_child(button.into_html_with_ref(self.align_ref.clone()))
+            .with_optional_child(submenu)
+            .into()
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if self.show_menu {
+            self.popper.update();
+        } else {
+            self.popper.clear();
+        }
+    }
+}
+
+impl From<DataTableRowActions> for VNode {
+    fn from(val: DataTableRowActions) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtDataTableRowActions>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
+
+impl_to_html!(DataTableRowActions);
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use yew::prelude::*;
+
+use crate::props::{IntoStorageLocation, StorageLocation};
+use crate::state::{PersistentState, SharedState, SharedStateObserver};
+
+use super::{ColumnFilterValue, HeaderViewState};
+
+/// Saved [DataTable](super::DataTable) view.
+///
+/// Stores the column order, widths and visibility, the sort order and the
+/// column filter values. Columns are identified by their key (or name if
+/// there is no key). Empty values mean the defaults from the column
+/// definitions.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataTableView {
+    /// The view name.
+    pub name: String,
+    /// Column order, widths and hidden flags.
+    #[serde(default)]
+    pub columns: HeaderViewState,
+    /// Sorted columns (column key, ascending).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sort: Vec<(String, bool)>,
+    /// Column filter values (by column key, or column name if there is no key).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub filters: BTreeMap<String, ColumnFilterValue>,
+}
+
+impl DataTableView {
+    /// Create a new instance.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Builder style method to hide a column.
+    pub fn hidden_column(mut self, key: impl Into<String>) -> Self {
+        self.columns.hidden.insert(key.into(), true);
+        self
+    }
+
+    /// Builder style method to show a column (columns can be hidden by default).
+    pub fn visible_column(mut self, key: impl Into<String>) -> Self {
+        self.columns.hidden.insert(key.into(), false);
+        self
+    }
+
+    /// Builder style method to add a sorter.
+    pub fn sort(mut self, key: impl Into<String>, ascending: bool) -> Self {
+        self.sort.push((key.into(), ascending));
+        self
+    }
+
+    /// Builder style method to set a column filter value.
+    pub fn filter(mut self, name: impl Into<String>, value: ColumnFilterValue) -> Self {
+        self.filters.insert(name.into(), value);
+        self
+    }
+}
+
+#[derive(Default)]
+struct ViewManagerState {
+    predefined: Vec<DataTableView>,
+    saved: Vec<DataTableView>,
+    storage: Option<StorageLocation>,
+    active: Option<String>,
+    // Current table state (updated by the table).
+    current: DataTableView,
+    // View to apply (consumed by the table).
+    pending: Option<DataTableView>,
+}
+
+impl ViewManagerState {
+    fn store(&self) {
+        if let Some(storage) = &self.storage {
+            PersistentState::<Vec<DataTableView>>::new(storage.clone()).update(self.saved.clone());
+        }
+    }
+}
+
+/// Manages saved [DataTable](super::DataTable) views.
+///
+/// Users can save the current column layout, sort order and filters under
+/// a name, and switch between views later (see
+/// [DataTableViewSelector](super::DataTableViewSelector) and the column
+/// header menu). Applications can add predefined views. User defined views
+/// are persisted if there is a storage location.
+///
+/// A view manager should be attached to a single table (see
+/// [DataTable::view_manager](super::DataTable::view_manager)).
+///
+/// ```
+/// # use pwt::widget::data_table::{DataTableView, ViewManager};
+/// let view_manager = ViewManager::new()
+///     .state_id("my-table-views")
+///     .with_view(DataTableView::new("Running").sort("name", true).hidden_column("uptime"));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ViewManager {
+    state: SharedState<ViewManagerState>,
+}
+
+impl Default for ViewManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewManager {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        Self {
+            state: SharedState::new(ViewManagerState::default()),
+        }
+    }
+
+    /// Builder style method to set the storage location for user defined views.
+    ///
+    /// This loads the saved views.
+    pub fn state_id(self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_state_id(state_id);
+        self
+    }
+
+    /// Method to set the storage location for user defined views.
+    ///
+    /// This loads the saved views.
+    pub fn set_state_id(&self, state_id: impl IntoStorageLocation) {
+        let storage = state_id.into_storage_location();
+        let saved = match &storage {
+            Some(storage) => {
+                PersistentState::<Vec<DataTableView>>::new(storage.clone()).into_inner()
+            }
+            None => Vec::new(),
+        };
+        let mut state = self.state.write();
+        state.storage = storage;
+        state.saved = saved;
+    }
+
+    /// Builder style method to add a predefined view.
+    pub fn with_view(self, view: DataTableView) -> Self {
+        self.add_view(view);
+        self
+    }
+
+    /// Method to add a predefined view.
+    pub fn add_view(&self, view: DataTableView) {
+        self.state.write().predefined.push(view);
+    }
+
+    /// Returns all views (predefined views first).
+    pub fn views(&self) -> Vec<DataTableView> {
+        let state = self.state.read();
+        state
+            .predefined
+            .iter()
+            .chain(state.saved.iter())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns true if the view is user defined (saved).
+    pub fn is_saved_view(&self, name: &str) -> bool {
+        self.state.read().saved.iter().any(|view| view.name == name)
+    }
+
+    /// Returns the name of the last applied or saved view.
+    pub fn active(&self) -> Option<String> {
+        self.state.read().active.clone()
+    }
+
+    /// Returns the current table state.
+    pub fn current(&self) -> DataTableView {
+        self.state.read().current.clone()
+    }
+
+    /// Apply the view with the given name.
+    pub fn apply_view(&self, name: &str) {
+        let view = self.views().into_iter().find(|view| view.name == name);
+        if let Some(view) = view {
+            let mut state = self.state.write();
+            state.active = Some(view.name.clone());
+            state.pending = Some(view);
+        }
+    }
+
+    /// Save the current table state as user defined view.
+    ///
+    /// Replaces an existing user defined view with the same name.
+    pub fn save_view(&self, name: impl Into<String>) {
+        let name = name.into();
+        let mut state = self.state.write();
+        let view = DataTableView {
+            name: name.clone(),
+            ..state.current.clone()
+        };
+        match state.saved.iter_mut().find(|v| v.name == name) {
+            Some(saved) => *saved = view,
+            None => state.saved.push(view),
+        }
+        state.active = Some(name);
+        state.store();
+    }
+
+    /// Delete a user defined view.
+    pub fn delete_view(&self, name: &str) {
+        let mut state = self.state.write();
+        state.saved.retain(|view| view.name != name);
+        if state.active.as_deref() == Some(name) {
+            state.active = None;
+        }
+        state.store();
+    }
+
+    /// Method to add a change observer.
+    ///
+    /// The callback is called when views are added, saved, deleted or
+    /// applied. Changes of the current table state are not reported.
+    pub fn add_listener(&self, cb: impl Into<Callback<ViewManager>>) -> ViewManagerObserver {
+        let cb = cb.into();
+        let me = self.clone();
+        let _observer = self.state.add_listener(move |_| cb.emit(me.clone()));
+        ViewManagerObserver { _observer }
+    }
+
+    // Update the current table state (without notifications).
+    pub(crate) fn update_current(&self, update: impl FnOnce(&mut DataTableView)) {
+        let mut state = self.state.write();
+        state.notify = false;
+        update(&mut state.current);
+    }
+
+    // Returns the view to apply (if any).
+    pub(crate) fn take_pending(&self) -> Option<DataTableView> {
+        if self.state.read().pending.is_none() {
+            return None; // Note: avoid endless loop
+        }
+        let mut state = self.state.write();
+        state.notify = false;
+        state.pending.take()
+    }
+}
+
+/// Guard for [ViewManager::add_listener] (removes the listener on drop).
+pub struct ViewManagerObserver {
+    _observer: SharedStateObserver<ViewManagerState>,
+}
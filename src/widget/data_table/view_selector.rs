@@ -0,0 +1,242 @@
+use std::rc::Rc;
+
+use yew::html::Scope;
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::prelude::*;
+use crate::props::IntoOptionalKey;
+use crate::widget::form::Field;
+use crate::widget::menu::{Menu, MenuButton, MenuCheckbox, MenuEvent, MenuItem};
+use crate::widget::{Button, Column, Container, Dialog, Toolbar};
+
+use super::{ViewManager, ViewManagerObserver};
+
+/// Toolbar dropdown to select, save and delete [DataTable](super::DataTable) views.
+///
+/// Shows the name of the active view. Saving a view asks for the name
+/// (using the active view name as default), and only user defined views
+/// can be deleted.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Toolbar;
+/// # use pwt::widget::data_table::{DataTableViewSelector, ViewManager};
+/// # fn create_toolbar(view_manager: ViewManager) -> Toolbar {
+/// Toolbar::new()
+///     .with_flex_spacer()
+///     .with_child(DataTableViewSelector::new(view_manager))
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Properties)]
+pub struct DataTableViewSelector {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// The view manager (also attached to the table).
+    pub view_manager: ViewManager,
+}
+
+impl DataTableViewSelector {
+    /// Create a new instance.
+    pub fn new(view_manager: ViewManager) -> Self {
+        yew::props!(Self { view_manager })
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+}
+
+pub enum Msg {
+    ViewManagerChange,
+    ShowSaveDialog,
+    NameChange(String),
+    Save,
+    CloseDialog,
+}
+
+#[doc(hidden)]
+pub struct PwtDataTableViewSelector {
+    _observer: ViewManagerObserver,
+    // View name entered in the save dialog (dialog is visible if set).
+    save_name: Option<String>,
+}
+
+impl PwtDataTableViewSelector {
+    fn build_menu(view_manager: &ViewManager, link: &Scope<Self>) -> Menu {
+        let active = view_manager.active();
+        let mut menu = Menu::new();
+        let mut saved_views = Vec::new();
+
+        for view in view_manager.views() {
+            if view_manager.is_saved_view(&view.name) {
+                saved_views.push(view.name.clone());
+            }
+            let checked = active.as_deref() == Some(view.name.as_str());
+            let name = view.name.clone();
+            let view_manager = view_manager.clone();
+            menu.add_item(
+                MenuCheckbox::radio(view.name)
+                    .checked(checked)
+                    .on_click(move |_: MenuEvent| view_manager.apply_view(&name)),
+            );
+        }
+
+        if !saved_views.is_empty() {
+            menu.add_separator();
+        }
+
+        menu.add_item(
+            MenuItem::new(tr!("Save View..."))
+                .icon_class("fa fa-floppy-o")
+                .on_select(link.callback(|_| Msg::ShowSaveDialog)),
+        );
+
+        let mut delete_menu = Menu::new();
+        for name in saved_views.iter() {
+            let view_manager = view_manager.clone();
+            let name = name.clone();
+            delete_menu.add_item(
+                MenuItem::new(name.clone())
+                    .on_select(move |_: MenuEvent| view_manager.delete_view(&name)),
+            );
+        }
+        menu.add_item(
+            MenuItem::new(tr!("Delete View"))
+                .icon_class("fa fa-trash-o")
+                .disabled(saved_views.is_empty())
+                .menu(delete_menu),
+        );
+
+        menu
+    }
+
+    fn render_save_dialog(&self, ctx: &Context<Self>, name: &str) -> Html {
+        let link = ctx.link();
+        let valid = !name.trim().is_empty();
+
+        let input = Field::new()
+            .autofocus(true)
+            .default(name.to_string())
+            .on_input(link.callback(Msg::NameChange))
+            .onkeydown(link.batch_callback(move |event: KeyboardEvent| {
+                (valid && event.key() == "Enter").then_some(Msg::Save)
+            }));
+
+        let bbar = Toolbar::new()
+            .with_flex_spacer()
+            .with_child(Button::new(tr!("Cancel")).onclick(link.callback(|_| Msg::CloseDialog)))
+            .with_child(
+                Button::new(tr!("Save"))
+                    .disabled(!valid)
+                    .onclick(link.callback(|_| Msg::Save)),
+            );
+
+        Dialog::new(tr!("Save View"))
+            .min_width(300)
+            .on_close(link.callback(|_| Msg::CloseDialog))
+            .with_child(
+                Column::new()
+                    .padding(2)
+                    .gap(2)
+                    .with_child(tr!("Name"))
+                    .with_child(input),
+            )
+            .with_child(bbar)
+            .into()
+    }
+}
+
+impl Component for PwtDataTableViewSelector {
+    type Message = Msg;
+    type Properties = DataTableViewSelector;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let _observer = ctx
+            .props()
+            .view_manager
+            .add_listener(ctx.link().callback(|_| Msg::ViewManagerChange));
+        Self {
+            _observer,
+            save_name: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::ViewManagerChange => true,
+            Msg::ShowSaveDialog => {
+                self.save_name = Some(props.view_manager.active().unwrap_or_default());
+                true
+            }
+            Msg::NameChange(name) => {
+                let valid = !name.trim().is_empty();
+                let old_valid = self
+                    .save_name
+                    .as_ref()
+                    .is_some_and(|name| !name.trim().is_empty());
+                self.save_name = Some(name);
+                valid != old_valid
+            }
+            Msg::Save => {
+                if let Some(name) = self.save_name.take() {
+                    let name = name.trim();
+                    if !name.is_empty() {
+                        props.view_manager.save_view(name);
+                    }
+                }
+                true
+            }
+            Msg::CloseDialog => {
+                self.save_name = None;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.view_manager != old_props.view_manager {
+            self._observer = props
+                .view_manager
+                .add_listener(ctx.link().callback(|_| Msg::ViewManagerChange));
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let text = props.view_manager.active().unwrap_or_else(|| tr!("Views"));
+
+        let button = MenuButton::new(text)
+            .icon_class("fa fa-columns")
+            .menu_builder({
+                let view_manager = props.view_manager.clone();
+                let link = link.clone();
+                move || Self::build_menu(&view_manager, &link)
+            });
+
+        Container::new()
+            .with_child(button)
+            .with_optional_child(
+                self.save_name
+                    .as_deref()
+                    .map(|name| self.render_save_dialog(ctx, name)),
+            )
+            .into()
+    }
+}
+
+impl From<DataTableViewSelector> for VNode {
+    fn from(val: DataTableViewSelector) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtDataTableViewSelector>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
@@ -0,0 +1,233 @@
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::rc::Rc;
+
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+use yew::html::IntoPropValue;
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::props::IntoOptionalKey;
+use crate::web_sys_ext::{IdleDeadline, has_request_idle_callback, request_idle_callback};
+use crate::{impl_to_html, prelude::*};
+
+use pwt_macros::builder;
+
+// Time budget (ms) if the browser does not support requestIdleCallback.
+const FALLBACK_BUDGET_MS: f64 = 10.0;
+// Make sure deferred content gets rendered even if the browser is busy.
+const IDLE_TIMEOUT_MS: u32 = 1000;
+
+struct DeferredTask {
+    id: usize,
+    priority: i32,
+    on_render: Callback<()>,
+}
+
+#[derive(Default)]
+struct Scheduler {
+    tasks: Vec<DeferredTask>,
+    next_id: usize,
+    scheduled: bool,
+}
+
+// this `thread_local!` definition should be fine as this crate is essentially WASM only (see
+// `state::language`).
+thread_local! {
+    static SCHEDULER: RefCell<Scheduler> = RefCell::new(Scheduler::default());
+}
+
+fn schedule_task(priority: i32, on_render: Callback<()>) -> usize {
+    let id = SCHEDULER.with(|s| {
+        let mut s = s.borrow_mut();
+        let id = s.next_id;
+        s.next_id += 1;
+        s.tasks.push(DeferredTask {
+            id,
+            priority,
+            on_render,
+        });
+        id
+    });
+    request_run();
+    id
+}
+
+fn cancel_task(id: usize) {
+    SCHEDULER.with(|s| s.borrow_mut().tasks.retain(|task| task.id != id));
+}
+
+fn request_run() {
+    let need_run = SCHEDULER.with(|s| {
+        let mut s = s.borrow_mut();
+        if s.scheduled || s.tasks.is_empty() {
+            return false;
+        }
+        s.scheduled = true;
+        true
+    });
+    if !need_run {
+        return;
+    }
+
+    let callback = Closure::once_into_js(move |deadline: JsValue| run_tasks(deadline));
+    let callback: &js_sys::Function = callback.unchecked_ref();
+
+    if has_request_idle_callback() {
+        let options = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&options, &"timeout".into(), &IDLE_TIMEOUT_MS.into());
+        request_idle_callback(callback, &options);
+    } else {
+        let _ =
+            gloo_utils::window().set_timeout_with_callback_and_timeout_and_arguments_0(callback, 1);
+    }
+}
+
+// Render tasks (highest priority first) until the idle period ends.
+fn run_tasks(deadline: JsValue) {
+    let deadline = deadline.dyn_into::<IdleDeadline>().ok();
+    let start = js_sys::Date::now();
+    let time_remaining = || match &deadline {
+        Some(deadline) => deadline.time_remaining(),
+        None => FALLBACK_BUDGET_MS - (js_sys::Date::now() - start),
+    };
+
+    SCHEDULER.with(|s| s.borrow_mut().scheduled = false);
+
+    loop {
+        let task = SCHEDULER.with(|s| {
+            let mut s = s.borrow_mut();
+            let index = s
+                .tasks
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, task)| (task.priority, Reverse(task.id)))
+                .map(|(index, _)| index)?;
+            Some(s.tasks.remove(index))
+        });
+        let Some(task) = task else {
+            break;
+        };
+        task.on_render.emit(());
+        if time_remaining() <= 0.0 {
+            break;
+        }
+    }
+
+    request_run();
+}
+
+/// Render content when the browser is idle.
+///
+/// Shows a cheap placeholder on the first render, and renders the real
+/// content later, inside an idle callback (`requestIdleCallback`, with a
+/// timeout fallback for browsers without support). This helps large
+/// initial views (e.g. dashboards with many panels) to reach the first
+/// paint faster.
+///
+/// Deferred content is rendered in order of [priority](Self::priority)
+/// (highest first), and in creation order for equal priorities.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{Column, DeferredRender, Panel};
+/// # fn dashboard() -> Column {
+/// Column::new()
+///     .with_child(DeferredRender::new(Panel::new().title("Status")).priority(10))
+///     .with_child(DeferredRender::new(Panel::new().title("History")))
+/// # }
+/// ```
+#[derive(Properties, Clone, PartialEq)]
+#[builder]
+pub struct DeferredRender {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    content: Html,
+
+    /// Placeholder shown until the content is rendered.
+    ///
+    /// Should have about the same size as the content, to avoid layout
+    /// shifts. Default is an empty `div`.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub placeholder: Option<Html>,
+
+    /// Render priority (higher values are rendered first).
+    #[builder]
+    #[prop_or_default]
+    pub priority: i32,
+}
+
+impl DeferredRender {
+    /// Create a new instance.
+    pub fn new(content: impl Into<Html>) -> Self {
+        yew::props!(Self {
+            content: content.into()
+        })
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+}
+
+pub enum Msg {
+    Render,
+}
+
+#[doc(hidden)]
+pub struct PwtDeferredRender {
+    task_id: Option<usize>,
+}
+
+impl Component for PwtDeferredRender {
+    type Message = Msg;
+    type Properties = DeferredRender;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let task_id = schedule_task(ctx.props().priority, ctx.link().callback(|_| Msg::Render));
+        Self {
+            task_id: Some(task_id),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Render => {
+                self.task_id = None;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        if self.task_id.is_none() {
+            return props.content.clone();
+        }
+        match &props.placeholder {
+            Some(placeholder) => placeholder.clone(),
+            None => html! {<div class="pwt-deferred-placeholder"/>},
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if let Some(task_id) = self.task_id.take() {
+            cancel_task(task_id);
+        }
+    }
+}
+
+impl From<DeferredRender> for VNode {
+    fn from(val: DeferredRender) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtDeferredRender>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
+
+impl_to_html!(DeferredRender);
@@ -6,10 +6,11 @@ use yew_router::Router;
 
 use gloo_history::{AnyHistory, HashHistory};
 
+use crate::dom::{InputModality, InputModalityObserver, input_modality};
 use crate::prelude::*;
 use crate::props::{IntoOptionalRenderFn, RenderFn};
-use crate::state::NavigationContainer;
-use crate::widget::{CatalogLoader, ThemeLoader};
+use crate::state::{AppInfo, NavigationContainer};
+use crate::widget::{CatalogLoader, ThemeLoader, WidgetInspector, inspector_enabled};
 
 use pwt_macros::builder;
 
@@ -20,6 +21,10 @@ use pwt_macros::builder;
 /// - Provide a yew_router::HashRouter and [NavigationContainer]
 /// - uses [ThemeLoader] to load the material design theme (dark/light)
 /// - uses [CatalogLoader] to load the I18N tranlation catalog.
+/// - Provide the current [InputModality] using a [yew::ContextProvider], so
+///   that widgets can use `use_context::<InputModality>()`.
+/// - Provide the [AppInfo] (if set) using a [yew::ContextProvider].
+/// - Show the [WidgetInspector] (if [enabled](inspector_enabled)).
 //
 #[derive(Properties, Clone, PartialEq)]
 #[builder]
@@ -54,6 +59,13 @@ pub struct DesktopApp {
     #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String, String)]
     #[prop_or_default]
     pub theme_url_builder: Option<RenderFn<String, String>>,
+
+    /// Application metadata, provided as [AppInfo] context.
+    ///
+    /// The product name is also used as document title.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub app_info: Option<AppInfo>,
 }
 
 impl DesktopApp {
@@ -69,13 +81,19 @@ impl DesktopApp {
     }
 }
 
+pub enum Msg {
+    InputModalityChange(InputModality),
+}
+
 #[doc(hidden)]
 pub struct PwtDesktopApp {
     history: AnyHistory,
+    input_modality: InputModality,
+    _input_modality_observer: InputModalityObserver,
 }
 
 impl Component for PwtDesktopApp {
-    type Message = ();
+    type Message = Msg;
     type Properties = DesktopApp;
 
     fn create(ctx: &Context<Self>) -> Self {
@@ -85,7 +103,36 @@ impl Component for PwtDesktopApp {
             .clone()
             .unwrap_or(AnyHistory::from(HashHistory::new()));
 
-        Self { history }
+        let _input_modality_observer =
+            InputModalityObserver::new(ctx.link().callback(Msg::InputModalityChange));
+
+        set_document_title(props.app_info.as_ref());
+
+        Self {
+            history,
+            input_modality: input_modality(),
+            _input_modality_observer,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputModalityChange(input_modality) => {
+                if self.input_modality == input_modality {
+                    return false;
+                }
+                self.input_modality = input_modality;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.app_info != old_props.app_info {
+            set_document_title(props.app_info.as_ref());
+        }
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -97,12 +144,30 @@ impl Component for PwtDesktopApp {
             .default_lang(props.default_lang.clone())
             .url_builder(props.catalog_url_builder.clone());
         let body = NavigationContainer::new().with_child(body);
+        let body = match &props.app_info {
+            Some(app_info) => html! {
+                <ContextProvider<AppInfo> context={app_info.clone()}>{body}</ContextProvider<AppInfo>>
+            },
+            None => body.into(),
+        };
         html! {
-            <Router history={self.history.clone()} basename={props.basename.clone()}>{body}</Router>
+            <ContextProvider<InputModality> context={self.input_modality}>
+                <Router history={self.history.clone()} basename={props.basename.clone()}>
+                    {body}
+                    {for inspector_enabled().then(WidgetInspector::new)}
+                </Router>
+            </ContextProvider<InputModality>>
         }
     }
 }
 
+// Use the product name as document title.
+pub(crate) fn set_document_title(app_info: Option<&AppInfo>) {
+    if let Some(app_info) = app_info {
+        gloo_utils::document().set_title(&app_info.product_name);
+    }
+}
+
 impl From<DesktopApp> for VNode {
     fn from(val: DesktopApp) -> Self {
         let key = val.key.clone();
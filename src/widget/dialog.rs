@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -5,7 +6,7 @@ use gloo_events::EventListener;
 use gloo_timers::callback::Timeout;
 use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
-use web_sys::HtmlElement;
+use web_sys::{HtmlElement, MediaQueryList};
 
 use yew::html::{IntoEventCallback, IntoPropValue, Scope};
 use yew::prelude::*;
@@ -15,14 +16,105 @@ use crate::dom::IntoHtmlElement;
 use crate::dom::align::{Point, align_to_viewport, align_to_xy};
 use crate::prelude::*;
 use crate::props::{AsCssStylesMut, CssStyles};
-use crate::widget::{ActionIcon, Container, Panel};
+use crate::widget::{ActionIcon, Container, HelpButton, Panel};
 
 use pwt_macros::builder;
 
+/// Dialog size presets.
+///
+/// The sizes are defined by the theme (`pwt-dialog-small`,
+/// `pwt-dialog-medium`, `pwt-dialog-large` and `pwt-dialog-fullscreen`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DialogSize {
+    /// Small dialog (400px wide).
+    Small,
+    /// Medium dialog (600px wide).
+    Medium,
+    /// Large dialog (900px wide).
+    Large,
+    /// Cover the whole viewport.
+    Fullscreen,
+}
+
+impl DialogSize {
+    fn css_class(&self) -> &'static str {
+        match self {
+            DialogSize::Small => "pwt-dialog-small",
+            DialogSize::Medium => "pwt-dialog-medium",
+            DialogSize::Large => "pwt-dialog-large",
+            DialogSize::Fullscreen => "pwt-dialog-fullscreen",
+        }
+    }
+}
+
+/// The reason why a [Dialog] was closed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DialogCloseReason {
+    /// The close tool in the dialog header was activated.
+    CloseTool,
+    /// The user pressed `Escape`.
+    Escape,
+    /// The browser closed the dialog (e.g. a form with `method="dialog"`).
+    Other,
+}
+
+// Stack of open dialogs (dialog id, redraw callback), topmost last.
+#[derive(Default)]
+struct DialogStack {
+    next_id: usize,
+    dialogs: Vec<(usize, Callback<()>)>,
+}
+
+thread_local! {
+    static DIALOG_STACK: RefCell<DialogStack> = RefCell::new(DialogStack::default());
+}
+
+fn dialog_stack_new_id() -> usize {
+    DIALOG_STACK.with_borrow_mut(|stack| {
+        stack.next_id += 1;
+        stack.next_id
+    })
+}
+
+fn dialog_stack_push(id: usize, redraw: Callback<()>) {
+    DIALOG_STACK.with_borrow_mut(|stack| stack.dialogs.push((id, redraw)));
+    dialog_stack_notify();
+}
+
+fn dialog_stack_remove(id: usize) {
+    let removed = DIALOG_STACK.with_borrow_mut(|stack| {
+        let len = stack.dialogs.len();
+        stack.dialogs.retain(|(dialog_id, _)| *dialog_id != id);
+        len != stack.dialogs.len()
+    });
+    if removed {
+        dialog_stack_notify();
+    }
+}
+
+fn dialog_stack_is_top(id: usize) -> bool {
+    DIALOG_STACK.with_borrow(|stack| stack.dialogs.last().map(|(top, _)| *top) == Some(id))
+}
+
+fn dialog_stack_notify() {
+    // clone to avoid borrow() while the callbacks run
+    let callbacks: Vec<Callback<()>> =
+        DIALOG_STACK.with_borrow(|stack| stack.dialogs.iter().map(|(_, cb)| cb.clone()).collect());
+    for cb in callbacks {
+        cb.emit(());
+    }
+}
+
 /// Modal Dialog.
 ///
 /// This widget is implemented using the relatively new Html `<dialog>`
 /// tag in order to get correct focus handling.
+///
+/// # Stacking
+///
+/// Dialogs can open other dialogs. Only the topmost dialog is
+/// interactive, lower dialogs are dimmed, and `Escape` only closes
+/// the topmost dialog.
 #[derive(Properties, Clone, PartialEq)]
 #[builder]
 pub struct Dialog {
@@ -43,6 +135,50 @@ pub struct Dialog {
     #[prop_or_default]
     pub on_close: Option<Callback<()>>,
 
+    /// Dialog close callback, including the reason why the dialog was closed.
+    ///
+    /// Like [on_close](Self::on_close), this makes the dialog closable.
+    #[builder_cb(IntoEventCallback, into_event_callback, DialogCloseReason)]
+    #[prop_or_default]
+    pub on_close_reason: Option<Callback<DialogCloseReason>>,
+
+    /// Called when the dialog is shown.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_open: Option<Callback<()>>,
+
+    /// Controlled open state.
+    ///
+    /// By default, the dialog is shown when it gets created. If set, the
+    /// dialog visibility follows this property, and a close request (Escape
+    /// or close tool) only emits [on_open_change](Self::on_open_change)
+    /// (and the close callbacks).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub open: Option<bool>,
+
+    /// Called when the user wants to close the dialog (with `false`).
+    ///
+    /// Like [on_close](Self::on_close), this makes the dialog closable.
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    #[prop_or_default]
+    pub on_open_change: Option<Callback<bool>>,
+
+    /// Size preset.
+    ///
+    /// Sets the dialog width. [DialogSize::Fullscreen] covers the whole
+    /// viewport and disables dragging and resizing.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub size: Option<DialogSize>,
+
+    /// CSS media query for automatic fullscreen mode, e.g. `(max-width: 600px)`.
+    ///
+    /// The dialog is displayed fullscreen while the query matches.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub fullscreen_query: Option<AttrValue>,
+
     #[prop_or_default]
     pub children: Vec<VNode>,
 
@@ -50,6 +186,13 @@ pub struct Dialog {
     #[prop_or_default]
     pub tools: Vec<VNode>,
 
+    /// Help ID for the online documentation (adds a [HelpButton] tool).
+    ///
+    /// See [AppInfo::help_url](crate::state::AppInfo::help_url).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub help_id: Option<AttrValue>,
+
     /// CSS style for the dialog window
     #[prop_or_default]
     pub styles: CssStyles,
@@ -114,7 +257,10 @@ impl Dialog {
 
 pub enum Msg {
     Open,
-    Close,
+    Close(DialogCloseReason),
+    Cancel,
+    StackChange,
+    ViewportChanged(bool),
     PointerDown(PointerEvent),
     PointerMove(PointerEvent),
     PointerUp(i32),
@@ -133,7 +279,11 @@ enum DragState {
 
 #[doc(hidden)]
 pub struct PwtDialog {
+    id: usize,
     open: bool,
+    query_fullscreen: bool,
+    media_query: Option<MediaQueryList>,
+    media_query_listener: Option<Closure<dyn Fn()>>,
     dragging_state: DragState,
     last_active: Option<web_sys::HtmlElement>, // last focused element
     resizer_state: HashMap<Point, DragState>,
@@ -148,10 +298,68 @@ impl PwtDialog {
             let _ = el.focus();
         }
     }
+
+    fn closable(props: &Dialog) -> bool {
+        props.on_close.is_some()
+            || props.on_close_reason.is_some()
+            || props.on_open_change.is_some()
+    }
+
+    fn close(&mut self) {
+        if let Some(dialog_node) = self.node_ref.get() {
+            crate::close_dialog(dialog_node);
+        }
+        self.open = false;
+        dialog_stack_remove(self.id);
+
+        self.restore_focus();
+    }
+
+    fn fullscreen(&self, props: &Dialog) -> bool {
+        self.query_fullscreen || props.size == Some(DialogSize::Fullscreen)
+    }
+
+    fn install_query(&mut self, ctx: &Context<Self>) {
+        self.remove_query();
+        self.query_fullscreen = false;
+
+        let query = match &ctx.props().fullscreen_query {
+            Some(query) => query,
+            None => return,
+        };
+
+        let mql = match gloo_utils::window().match_media(query) {
+            Ok(Some(mql)) => mql,
+            _ => return,
+        };
+
+        self.query_fullscreen = mql.matches();
+
+        let link = ctx.link().clone();
+        let mql_clone = mql.clone();
+        let closure = Closure::wrap(Box::new(move || {
+            link.send_message(Msg::ViewportChanged(mql_clone.matches()));
+        }) as Box<dyn Fn()>);
+
+        let _ = mql.add_event_listener_with_callback("change", closure.as_ref().unchecked_ref());
+
+        self.media_query = Some(mql);
+        self.media_query_listener = Some(closure);
+    }
+
+    fn remove_query(&mut self) {
+        if let (Some(mql), Some(closure)) =
+            (self.media_query.take(), self.media_query_listener.take())
+        {
+            let _ =
+                mql.remove_event_listener_with_callback("change", closure.as_ref().unchecked_ref());
+        }
+    }
 }
 
 impl Drop for PwtDialog {
     fn drop(&mut self) {
+        self.remove_query();
         if let Some(center_function) = self.center_function.take() {
             gloo_utils::window()
                 .remove_event_listener_with_callback(
@@ -168,7 +376,9 @@ impl Component for PwtDialog {
     type Properties = Dialog;
 
     fn create(ctx: &Context<Self>) -> Self {
-        ctx.link().send_message(Msg::Open);
+        if ctx.props().open != Some(false) {
+            ctx.link().send_message(Msg::Open);
+        }
 
         let last_active = gloo_utils::document()
             .active_element()
@@ -190,15 +400,21 @@ impl Component for PwtDialog {
             center_function
         });
 
-        Self {
+        let mut me = Self {
+            id: dialog_stack_new_id(),
             open: false,
+            query_fullscreen: false,
+            media_query: None,
+            media_query_listener: None,
             dragging_state: DragState::Idle,
             resizer_state: HashMap::new(),
             last_active,
             center_function,
             node_ref: NodeRef::default(),
             inner_ref: NodeRef::default(),
-        }
+        };
+        me.install_query(ctx);
+        me
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -208,23 +424,55 @@ impl Component for PwtDialog {
             Msg::Open => {
                 if !self.open {
                     if let Some(dialog_node) = self.node_ref.get() {
+                        if self.last_active.is_none() {
+                            self.last_active = gloo_utils::document()
+                                .active_element()
+                                .and_then(|el| el.dyn_into::<HtmlElement>().ok());
+                        }
                         crate::show_modal_dialog(dialog_node);
                         self.open = true;
+                        dialog_stack_push(self.id, ctx.link().callback(|_| Msg::StackChange));
+                        if let Some(on_open) = &props.on_open {
+                            on_open.emit(());
+                        }
                     }
                 }
             }
-            Msg::Close => {
-                if self.open {
-                    if let Some(on_close) = &props.on_close {
-                        if let Some(dialog_node) = self.node_ref.get() {
-                            crate::close_dialog(dialog_node);
+            Msg::Cancel => {
+                // Escape only closes the topmost dialog
+                if dialog_stack_is_top(self.id) {
+                    ctx.link()
+                        .send_message(Msg::Close(DialogCloseReason::Escape));
+                }
+            }
+            Msg::Close(reason) => {
+                if self.open && Self::closable(props) {
+                    if props.open.is_none() {
+                        self.close();
+                    } else if let Some(dialog_el) = self.node_ref.cast::<web_sys::Element>() {
+                        // keep the dialog open until the parent changes the open property
+                        if !dialog_el.has_attribute("open") {
+                            crate::show_modal_dialog(dialog_el.into());
                         }
+                    }
 
+                    if let Some(on_close) = &props.on_close {
                         on_close.emit(());
-                        self.open = false;
-
-                        self.restore_focus();
                     }
+                    if let Some(on_close_reason) = &props.on_close_reason {
+                        on_close_reason.emit(reason);
+                    }
+                    if let Some(on_open_change) = &props.on_open_change {
+                        on_open_change.emit(false);
+                    }
+                }
+            }
+            Msg::StackChange => return true,
+            Msg::ViewportChanged(fullscreen) => {
+                if self.query_fullscreen != fullscreen {
+                    self.query_fullscreen = fullscreen;
+                    ctx.link().send_message(Msg::Center);
+                    return true;
                 }
             }
             Msg::PointerDown(event) => {
@@ -236,7 +484,7 @@ impl Component for PwtDialog {
                     }
                 }
 
-                if props.draggable && is_draggable {
+                if props.draggable && is_draggable && !self.fullscreen(props) {
                     if let Some(element) = self.node_ref.clone().into_html_element() {
                         let client = element.get_bounding_client_rect();
                         let x = event.client_x() as f64 - client.x();
@@ -432,9 +680,12 @@ impl Component for PwtDialog {
                 _ => {}
             },
             Msg::Center => {
-                if let Err(err) =
+                let result = if self.fullscreen(props) {
+                    align_to_xy(self.node_ref.clone(), (0.0, 0.0), Point::TopStart)
+                } else {
                     align_to_viewport(self.node_ref.clone(), Point::Center, Point::Center)
-                {
+                };
+                if let Err(err) = result {
                     log::error!("err: {}", err.to_string());
                 }
             }
@@ -442,7 +693,27 @@ impl Component for PwtDialog {
         false
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.fullscreen_query != old_props.fullscreen_query {
+            self.install_query(ctx);
+        }
+        if props.size != old_props.size || props.fullscreen_query != old_props.fullscreen_query {
+            ctx.link().send_message(Msg::Center);
+        }
+        match props.open {
+            Some(true) if !self.open => {
+                ctx.link().send_message(Msg::Open);
+                ctx.link().send_message(Msg::Center);
+            }
+            Some(false) if self.open => self.close(),
+            _ => {}
+        }
+        true
+    }
+
     fn destroy(&mut self, _ctx: &Context<Self>) {
+        dialog_stack_remove(self.id);
         // always close the dialog before restoring the focus
         if let Some(dialog_node) = self.node_ref.get() {
             crate::close_dialog(dialog_node);
@@ -457,28 +728,42 @@ impl Component for PwtDialog {
         let onclose = link.callback(|event: Event| {
             event.stop_propagation();
             event.prevent_default();
-            Msg::Close
+            Msg::Close(DialogCloseReason::Other)
         });
 
+        // handle Escape ourselves, so that we only close the topmost dialog
+        let oncancel = link.callback(|event: Event| {
+            event.stop_propagation();
+            event.prevent_default();
+            Msg::Cancel
+        });
+
+        let fullscreen = self.fullscreen(props);
+        let covered = self.open && !dialog_stack_is_top(self.id);
+
         let mut panel = Panel::new()
-            .class("pwt-overflow-auto")
-            .class("pwt-flex-fill")
+            .class(crate::css::Overflow::Auto)
+            .class(crate::css::Flex::Fill)
             .title(
                 props
                     .html_title
                     .clone()
                     .unwrap_or(html! {props.title.clone()}),
             )
-            .header_class(props.draggable.then_some("pwt-draggable"))
+            .header_class((props.draggable && !fullscreen).then_some("pwt-draggable"))
             .border(false);
 
         panel.tools.extend(props.tools.clone());
 
-        if props.on_close.is_some() {
+        if let Some(help_id) = &props.help_id {
+            panel.add_tool(HelpButton::new(help_id.clone()));
+        }
+
+        if Self::closable(props) {
             panel.add_tool(
                 ActionIcon::new("fa fa-close")
                     .aria_label("Close Dialog")
-                    .on_activate(link.callback(|_| Msg::Close)),
+                    .on_activate(link.callback(|_| Msg::Close(DialogCloseReason::CloseTool))),
             );
         };
 
@@ -491,15 +776,23 @@ impl Component for PwtDialog {
             Msg::PointerDown(event)
         });
 
-        let resizable = props.resizable;
+        let resizable = props.resizable && !fullscreen;
 
         let is_dragging = !matches!(self.dragging_state, DragState::Idle);
         let classes = classes!("pwt-dialog", is_dragging.then_some("pwt-user-select-none"));
 
         //let style = props.styles.compile_style_attribute(None);
 
+        let size = if fullscreen {
+            Some(DialogSize::Fullscreen)
+        } else {
+            props.size
+        };
+
         let mut inner = Container::new()
             .class(classes)
+            .class(size.map(|size| size.css_class()))
+            .class(covered.then_some("pwt-dialog-covered"))
             .styles(props.styles.clone())
             .with_child(panel);
 
@@ -534,6 +827,7 @@ impl Component for PwtDialog {
             .class("pwt-outer-dialog")
             .onpointerdown(onpointerdown)
             .onclose(onclose)
+            .oncancel(oncancel)
             .ontouchstart(cancel_event.clone())
             .ontouchend(cancel_event.clone())
             .ontouchmove(cancel_event.clone())
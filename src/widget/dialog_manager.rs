@@ -0,0 +1,552 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::channel::oneshot;
+
+use yew::html::IntoPropValue;
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::prelude::*;
+use crate::props::IntoOptionalKey;
+use crate::state::{SharedState, SharedStateObserver};
+use crate::widget::form::Field;
+use crate::widget::{AlertDialog, Button, Column, Dialog, MessageBox, MessageBoxButtons, Toolbar};
+
+use pwt_macros::builder;
+
+/// A queued dialog request.
+enum DialogRequest {
+    Alert {
+        title: Option<AttrValue>,
+        message: Html,
+        done: oneshot::Sender<()>,
+    },
+    Confirm {
+        title: Option<AttrValue>,
+        message: Html,
+        done: oneshot::Sender<bool>,
+    },
+    Prompt {
+        title: Option<AttrValue>,
+        message: Html,
+        value: String,
+        done: oneshot::Sender<Option<String>>,
+    },
+}
+
+// Queued dialog requests, and the currently displayed dialog.
+//
+// Each displayed request gets a unique key, so that consecutive dialogs
+// of the same type do not share component state.
+#[derive(Default)]
+struct DialogQueue {
+    queue: VecDeque<DialogRequest>,
+    current: Option<(Key, DialogRequest)>,
+    serial: usize,
+}
+
+impl DialogQueue {
+    fn extend(&mut self, list: impl IntoIterator<Item = DialogRequest>) {
+        self.queue.extend(list);
+        self.display_next();
+    }
+
+    fn display_next(&mut self) {
+        if self.current.is_some() {
+            return;
+        }
+        if let Some(request) = self.queue.pop_front() {
+            self.serial += 1;
+            self.current = Some((Key::from(self.serial), request));
+        }
+    }
+
+    fn close_current(&mut self, success: bool) {
+        match self.current.take().map(|(_, request)| request) {
+            Some(DialogRequest::Alert { done, .. }) => {
+                let _ = done.send(());
+            }
+            Some(DialogRequest::Confirm { done, .. }) => {
+                let _ = done.send(success);
+            }
+            Some(DialogRequest::Prompt { value, done, .. }) => {
+                let _ = done.send(success.then_some(value));
+            }
+            None => {}
+        }
+        self.display_next();
+    }
+}
+
+/// Dialog controller, used to show alert, confirm and prompt dialogs.
+///
+/// Each [DialogManager] provides a [DialogController] using a [yew::ContextProvider].
+/// All functions return a future which resolves when the dialog is closed.
+#[derive(Clone, PartialEq)]
+pub struct DialogController {
+    state: SharedState<Vec<DialogRequest>>,
+}
+
+impl Default for DialogController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DialogController {
+    pub fn new() -> Self {
+        Self {
+            state: SharedState::new(Vec::new()),
+        }
+    }
+
+    /// Show an alert dialog.
+    pub fn show_alert(
+        &self,
+        title: impl IntoPropValue<Option<AttrValue>>,
+        message: impl Into<Html>,
+    ) -> impl Future<Output = ()> + 'static {
+        let (done, receiver) = oneshot::channel();
+        self.state.write().push(DialogRequest::Alert {
+            title: title.into_prop_value(),
+            message: message.into(),
+            done,
+        });
+        async move {
+            let _ = receiver.await;
+        }
+    }
+
+    /// Show a confirm dialog with Yes/No buttons.
+    ///
+    /// Resolves to `true` if the user pressed "Yes".
+    pub fn show_confirm(
+        &self,
+        title: impl IntoPropValue<Option<AttrValue>>,
+        message: impl Into<Html>,
+    ) -> impl Future<Output = bool> + 'static {
+        let (done, receiver) = oneshot::channel();
+        self.state.write().push(DialogRequest::Confirm {
+            title: title.into_prop_value(),
+            message: message.into(),
+            done,
+        });
+        async move { receiver.await.unwrap_or(false) }
+    }
+
+    /// Show a prompt dialog with a text input, initialized with `value`.
+    ///
+    /// Resolves to the entered text, or `None` if the dialog was canceled.
+    pub fn show_prompt(
+        &self,
+        title: impl IntoPropValue<Option<AttrValue>>,
+        message: impl Into<Html>,
+        value: impl Into<String>,
+    ) -> impl Future<Output = Option<String>> + 'static {
+        let (done, receiver) = oneshot::channel();
+        self.state.write().push(DialogRequest::Prompt {
+            title: title.into_prop_value(),
+            message: message.into(),
+            value: value.into(),
+            done,
+        });
+        async move { receiver.await.unwrap_or(None) }
+    }
+}
+
+/// Display alert, confirm and prompt dialogs one after another.
+///
+/// Provides a [DialogController] to its children, which queues the
+/// dialog requests. So multiple messages do not stack modal on modal.
+/// Use [DialogContextExt] to access the controller.
+#[derive(Properties, Clone, PartialEq)]
+#[builder]
+pub struct DialogManager {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// Optional dialog controller.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub controller: Option<DialogController>,
+
+    #[prop_or_default]
+    pub children: Vec<VNode>,
+}
+
+impl Default for DialogManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContainerBuilder for DialogManager {
+    fn as_children_mut(&mut self) -> &mut Vec<VNode> {
+        &mut self.children
+    }
+}
+
+impl DialogManager {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+}
+
+pub enum Msg {
+    Controller, // Controller has new requests
+    PromptChange(String),
+    Close(bool),
+}
+
+#[doc(hidden)]
+pub struct PwtDialogManager {
+    controller: DialogController,
+    _state_observer: SharedStateObserver<Vec<DialogRequest>>,
+    dialogs: DialogQueue,
+}
+
+impl PwtDialogManager {
+    fn handle_controller_messages(&mut self) {
+        if self.controller.state.read().is_empty() {
+            return; // Note: avoid endless loop
+        }
+        let list = self.controller.state.write().split_off(0);
+        self.dialogs.extend(list);
+    }
+
+    fn render_prompt(
+        &self,
+        ctx: &Context<Self>,
+        key: &Key,
+        title: &Option<AttrValue>,
+        message: &Html,
+        value: &str,
+    ) -> Html {
+        let link = ctx.link();
+        let title = title.clone().unwrap_or_else(|| tr!("Prompt").into());
+
+        let input = Field::new()
+            .autofocus(true)
+            .default(value.to_string())
+            .on_change(link.callback(Msg::PromptChange))
+            .onkeydown(link.batch_callback(|event: KeyboardEvent| {
+                (event.key() == "Enter").then_some(Msg::Close(true))
+            }));
+
+        let bbar = Toolbar::new()
+            .with_flex_spacer()
+            .with_child(Button::new(tr!("Cancel")).onclick(link.callback(|_| Msg::Close(false))))
+            .with_child(Button::new(tr!("Ok")).onclick(link.callback(|_| Msg::Close(true))));
+
+        Dialog::new(title)
+            .key(key.clone())
+            .min_width(300)
+            .max_width(600)
+            .on_close(link.callback(|_| Msg::Close(false)))
+            .with_child(
+                Column::new()
+                    .padding(2)
+                    .gap(2)
+                    .with_child(html! {<p style={"overflow-wrap: anywhere;"}>{message.clone()}</p>})
+                    .with_child(input),
+            )
+            .with_child(bbar)
+            .into()
+    }
+}
+
+impl Component for PwtDialogManager {
+    type Message = Msg;
+    type Properties = DialogManager;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let controller = props.controller.clone().unwrap_or_default();
+
+        let _state_observer = controller
+            .state
+            .add_listener(ctx.link().callback(|_| Msg::Controller));
+
+        let mut me = Self {
+            controller,
+            _state_observer,
+            dialogs: DialogQueue::default(),
+        };
+
+        me.handle_controller_messages();
+        me
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Controller => {
+                self.handle_controller_messages();
+                true
+            }
+            Msg::PromptChange(text) => {
+                if let Some((_, DialogRequest::Prompt { value, .. })) = &mut self.dialogs.current {
+                    *value = text;
+                }
+                false
+            }
+            Msg::Close(success) => {
+                self.dialogs.close_current(success);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        let dialog: Option<Html> = match &self.dialogs.current {
+            None => None,
+            Some((key, DialogRequest::Alert { title, message, .. })) => Some(
+                AlertDialog::new(message.clone())
+                    .key(key.clone())
+                    .title(title.clone())
+                    .on_close(link.callback(|_| Msg::Close(true)))
+                    .into(),
+            ),
+            Some((key, DialogRequest::Confirm { title, message, .. })) => Some(
+                MessageBox::new(
+                    title.clone().unwrap_or_else(|| tr!("Confirm").into()),
+                    message.clone(),
+                )
+                .key(key.clone())
+                .icon_class("fa-question-circle")
+                .buttons(MessageBoxButtons::YesNo)
+                .on_close(link.callback(Msg::Close))
+                .into(),
+            ),
+            Some((
+                key,
+                DialogRequest::Prompt {
+                    title,
+                    message,
+                    value,
+                    ..
+                },
+            )) => Some(self.render_prompt(ctx, key, title, message, value)),
+        };
+
+        html! {
+            <ContextProvider<DialogController> context={self.controller.clone()}>
+                {for ctx.props().children.iter().cloned()}
+                {dialog}
+            </ContextProvider<DialogController>>
+        }
+    }
+}
+
+impl From<DialogManager> for VNode {
+    fn from(val: DialogManager) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtDialogManager>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
+
+/// Simplify access to the [DialogController] via [ContextProvider]
+///
+/// The [DialogManager] uses a [ContextProvider] to expose a [DialogController].
+/// This trait simplifies access to that controller.
+///
+/// ```
+/// use pwt::prelude::*;
+/// use pwt::widget::{Button, DialogContextExt};
+///
+/// # fn test(ctx: &Context<pwt::widget::PwtButton>) { // fake context for testing
+/// let button = Button::new("Delete").onclick({
+///     let link = ctx.link().clone();
+///     move |_| {
+///         let confirm = link.show_confirm("Delete", "Really delete?");
+///         wasm_bindgen_futures::spawn_local(async move {
+///             if confirm.await {
+///                 // delete ...
+///             }
+///         });
+///     }
+/// });
+/// # }
+/// ```
+pub trait DialogContextExt {
+    /// Access the [DialogController] from the [ContextProvider].
+    fn dialog_controller(&self) -> Option<DialogController>;
+
+    /// Show an alert dialog (see [DialogController::show_alert]).
+    ///
+    /// Resolves immediately if the context does not provide a [DialogController].
+    fn show_alert(
+        &self,
+        title: impl IntoPropValue<Option<AttrValue>>,
+        message: impl Into<Html>,
+    ) -> impl Future<Output = ()> + 'static {
+        let future = self
+            .dialog_controller()
+            .map(|controller| controller.show_alert(title, message));
+        if future.is_none() {
+            log::error!("unable to show alert: context does not provide a DialogController.");
+        }
+        async move {
+            if let Some(future) = future {
+                future.await
+            }
+        }
+    }
+
+    /// Show a confirm dialog (see [DialogController::show_confirm]).
+    ///
+    /// Resolves to `false` if the context does not provide a [DialogController].
+    fn show_confirm(
+        &self,
+        title: impl IntoPropValue<Option<AttrValue>>,
+        message: impl Into<Html>,
+    ) -> impl Future<Output = bool> + 'static {
+        let future = self
+            .dialog_controller()
+            .map(|controller| controller.show_confirm(title, message));
+        if future.is_none() {
+            log::error!("unable to show confirm: context does not provide a DialogController.");
+        }
+        async move {
+            match future {
+                Some(future) => future.await,
+                None => false,
+            }
+        }
+    }
+
+    /// Show a prompt dialog (see [DialogController::show_prompt]).
+    ///
+    /// Resolves to `None` if the context does not provide a [DialogController].
+    fn show_prompt(
+        &self,
+        title: impl IntoPropValue<Option<AttrValue>>,
+        message: impl Into<Html>,
+        value: impl Into<String>,
+    ) -> impl Future<Output = Option<String>> + 'static {
+        let future = self
+            .dialog_controller()
+            .map(|controller| controller.show_prompt(title, message, value));
+        if future.is_none() {
+            log::error!("unable to show prompt: context does not provide a DialogController.");
+        }
+        async move {
+            match future {
+                Some(future) => future.await,
+                None => None,
+            }
+        }
+    }
+}
+
+impl<COMP: Component> DialogContextExt for yew::html::Scope<COMP> {
+    fn dialog_controller(&self) -> Option<DialogController> {
+        self.context::<DialogController>(Callback::from(|_| {}))
+            .map(|(controller, _)| controller)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::channel::oneshot;
+    use yew::html;
+    use yew::virtual_dom::Key;
+
+    use super::{DialogQueue, DialogRequest};
+
+    fn confirm(message: &str) -> (DialogRequest, oneshot::Receiver<bool>) {
+        let (done, receiver) = oneshot::channel();
+        let request = DialogRequest::Confirm {
+            title: None,
+            message: html! {message},
+            done,
+        };
+        (request, receiver)
+    }
+
+    fn current_key(dialogs: &DialogQueue) -> Option<Key> {
+        dialogs.current.as_ref().map(|(key, _)| key.clone())
+    }
+
+    #[test]
+    fn test_queue_order() {
+        let mut dialogs = DialogQueue::default();
+        let (first, mut first_result) = confirm("first");
+        let (second, mut second_result) = confirm("second");
+        let (third, mut third_result) = confirm("third");
+
+        dialogs.extend([first]);
+        dialogs.extend([second, third]);
+        assert_eq!(dialogs.queue.len(), 2);
+        let first_key = current_key(&dialogs).unwrap();
+
+        dialogs.close_current(true);
+        assert_eq!(first_result.try_recv(), Ok(Some(true)));
+        assert_eq!(second_result.try_recv(), Ok(None));
+        let second_key = current_key(&dialogs).unwrap();
+
+        dialogs.close_current(false);
+        assert_eq!(second_result.try_recv(), Ok(Some(false)));
+        let third_key = current_key(&dialogs).unwrap();
+
+        dialogs.close_current(true);
+        assert_eq!(third_result.try_recv(), Ok(Some(true)));
+        assert!(dialogs.current.is_none());
+
+        // each request gets its own key
+        assert_ne!(first_key, second_key);
+        assert_ne!(second_key, third_key);
+
+        // closing without dialog is a no-op
+        dialogs.close_current(true);
+        assert!(dialogs.current.is_none());
+    }
+
+    #[test]
+    fn test_prompt_result() {
+        let mut dialogs = DialogQueue::default();
+        let (done, mut ok_result) = oneshot::channel();
+        dialogs.extend([DialogRequest::Prompt {
+            title: None,
+            message: html! {},
+            value: "initial".into(),
+            done,
+        }]);
+        let (done, mut cancel_result) = oneshot::channel();
+        dialogs.extend([DialogRequest::Prompt {
+            title: None,
+            message: html! {},
+            value: "other".into(),
+            done,
+        }]);
+
+        if let Some((_, DialogRequest::Prompt { value, .. })) = &mut dialogs.current {
+            *value = "changed".into();
+        }
+        dialogs.close_current(true);
+        assert_eq!(ok_result.try_recv(), Ok(Some(Some("changed".into()))));
+
+        dialogs.close_current(false);
+        assert_eq!(cancel_result.try_recv(), Ok(Some(None)));
+    }
+
+    #[test]
+    fn test_dropped_manager() {
+        let (request, mut result) = confirm("dropped");
+        let mut dialogs = DialogQueue::default();
+        dialogs.extend([request]);
+        drop(dialogs);
+        assert!(result.try_recv().is_err());
+    }
+}
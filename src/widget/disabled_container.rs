@@ -0,0 +1,117 @@
+use yew::html::Scope;
+
+use crate::prelude::*;
+use crate::widget::Container;
+
+use pwt_macros::{builder, widget};
+
+/// Disabled state propagated to all descendant fields and buttons.
+///
+/// This context is provided by the [DisabledContainer]. Nested
+/// containers can only add the disabled state, i.e. children of a
+/// disabled container are always disabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DisabledContext {
+    pub disabled: bool,
+}
+
+impl DisabledContext {
+    /// Subscribe to the context and return the current disabled state.
+    ///
+    /// Returns `false` and `None` if there is no [DisabledContainer] above
+    /// the component.
+    pub fn subscribe<COMP: Component>(
+        link: &Scope<COMP>,
+        callback: Callback<DisabledContext>,
+    ) -> (bool, Option<ContextHandle<DisabledContext>>) {
+        match link.context::<DisabledContext>(callback) {
+            Some((ctx, handle)) => (ctx.disabled, Some(handle)),
+            None => (false, None),
+        }
+    }
+}
+
+/// Container which can disable all descendant fields and buttons.
+///
+/// Useful to disable whole panels or forms at once, for example while
+/// submitting, or if the user lacks the required privileges. The
+/// container provides a [DisabledContext], which is observed by the
+/// form fields and the [Button](super::Button).
+///
+/// # Note
+///
+/// Disabling a field this way does not change the form submit behavior,
+/// i.e. fields are still submitted and validated.
+#[widget(pwt=crate, comp=PwtDisabledContainer, @element, @container)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct DisabledContainer {
+    /// Disable all descendant fields and buttons.
+    #[prop_or_default]
+    #[builder]
+    pub disabled: bool,
+}
+
+impl Default for DisabledContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisabledContainer {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+}
+
+pub enum Msg {
+    ParentUpdate(DisabledContext),
+}
+
+#[doc(hidden)]
+pub struct PwtDisabledContainer {
+    parent_disabled: bool,
+    _parent_handle: Option<ContextHandle<DisabledContext>>,
+}
+
+impl Component for PwtDisabledContainer {
+    type Message = Msg;
+    type Properties = DisabledContainer;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (parent_disabled, _parent_handle) =
+            DisabledContext::subscribe(ctx.link(), ctx.link().callback(Msg::ParentUpdate));
+        Self {
+            parent_disabled,
+            _parent_handle,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ParentUpdate(parent) => {
+                let changed = self.parent_disabled != parent.disabled;
+                self.parent_disabled = parent.disabled;
+                changed
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let disabled = props.disabled || self.parent_disabled;
+
+        let context = DisabledContext { disabled };
+
+        let content = Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .attribute("aria-disabled", disabled.then_some("true"))
+            .children(props.children.clone());
+
+        html! {
+            <ContextProvider<DisabledContext> {context}>{content}</ContextProvider<DisabledContext>>
+        }
+    }
+}
@@ -10,7 +10,7 @@ use yew::prelude::*;
 
 use crate::prelude::*;
 use crate::props::{IntoOptionalRenderFn, RenderFn};
-use crate::widget::{Container, Input, Tooltip, Trigger};
+use crate::widget::{Container, DisabledContext, Input, Tooltip, Trigger};
 
 use pwt_macros::{builder, widget};
 
@@ -119,6 +119,26 @@ pub struct Dropdown {
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or_default]
     pub align_options: Option<AlignOptions>,
+
+    /// Controlled open state.
+    ///
+    /// If set, the picker visibility follows this property, and user
+    /// interactions only emit [on_open_change](Self::on_open_change).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub open: Option<bool>,
+
+    /// Called when the user opens or closes the picker.
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    #[prop_or_default]
+    pub on_open_change: Option<Callback<bool>>,
+
+    /// Keep the picker open when a value gets selected.
+    ///
+    /// The change is reported immediately (i.e. for multi-select pickers).
+    #[prop_or_default]
+    #[builder]
+    pub keep_open: bool,
 }
 
 impl Dropdown {
@@ -148,6 +168,7 @@ pub enum Msg {
     MouseDownInput,
     FocusChange(bool),
     Dismiss,
+    DisabledContextUpdate(DisabledContext),
 }
 
 #[doc(hidden)]
@@ -172,9 +193,37 @@ pub struct PwtDropdown {
     dropup: bool,
     // dismiss-on-outside-interaction listeners, present only while the picker is open
     dismiss_listeners: Vec<EventListener>,
+    context_disabled: bool,
+    _disabled_context_handle: Option<ContextHandle<DisabledContext>>,
 }
 
 impl PwtDropdown {
+    // Change the picker visibility (only notify the parent in controlled mode).
+    fn set_show(&mut self, props: &Dropdown, show: bool) {
+        if self.show != show {
+            if let Some(on_open_change) = &props.on_open_change {
+                on_open_change.emit(show);
+            }
+        }
+        if props.open.is_none() {
+            self.show = show;
+        }
+    }
+
+    // Decide the side before the picker renders so the filter ordering is right on the
+    // first frame; only when we own the placement, as a custom align_options means the
+    // caller controls the side.
+    fn update_dropup(&mut self, props: &Dropdown) {
+        self.dropup = props.align_options.is_none()
+            && self
+                .dropdown_space()
+                .is_some_and(|(above, below)| below < MIN_SPACE_BELOW && above > below);
+    }
+
+    fn is_disabled(&self, props: &Dropdown) -> bool {
+        props.input_props.disabled || self.context_disabled
+    }
+
     // focus the input elelent (after closing the dropdown popover)
     fn restore_focus(&mut self) {
         if let Some(el) = self.input_ref.cast::<web_sys::HtmlElement>() {
@@ -237,8 +286,10 @@ impl Component for PwtDropdown {
 
     fn create(ctx: &Context<Self>) -> Self {
         let focus_tracker = FocusTracker::new(ctx.link().callback(Msg::FocusChange));
+        let (context_disabled, _disabled_context_handle) =
+            DisabledContext::subscribe(ctx.link(), ctx.link().callback(Msg::DisabledContextUpdate));
         Self {
-            show: false,
+            show: ctx.props().open.unwrap_or(false),
             last_show: false,
             pending_change: false,
             value: ctx.props().value.clone().unwrap_or_default(),
@@ -252,6 +303,8 @@ impl Component for PwtDropdown {
             focus_tracker,
             dropup: false,
             dismiss_listeners: Vec::new(),
+            context_disabled,
+            _disabled_context_handle,
         }
     }
 
@@ -259,7 +312,7 @@ impl Component for PwtDropdown {
         let props = ctx.props();
         match msg {
             Msg::TogglePicker => {
-                if props.input_props.disabled {
+                if self.is_disabled(props) {
                     return false;
                 }
                 //log::info!("TogglePicker");
@@ -274,10 +327,12 @@ impl Component for PwtDropdown {
                 )
             }
             Msg::HidePicker => {
-                if let Some(popover_node) = self.picker_ref.get() {
-                    crate::hide_popover(popover_node);
+                if props.open.is_none() {
+                    if let Some(popover_node) = self.picker_ref.get() {
+                        crate::hide_popover(popover_node);
+                    }
                 }
-                self.show = false;
+                self.set_show(props, false);
                 self.restore_focus();
                 if self.pending_change {
                     self.pending_change = false;
@@ -290,22 +345,18 @@ impl Component for PwtDropdown {
                 true
             }
             Msg::ShowPicker => {
-                if props.input_props.disabled {
+                if self.is_disabled(props) {
                     return false;
                 }
-                // decide the side before the picker renders so the filter ordering is right on the
-                // first frame; only when we own the placement, as a custom align_options means the
-                // caller controls the side.
-                self.dropup = props.align_options.is_none()
-                    && self
-                        .dropdown_space()
-                        .is_some_and(|(above, below)| below < MIN_SPACE_BELOW && above > below);
-                self.show = true;
+                if !self.show {
+                    self.update_dropup(props);
+                }
+                self.set_show(props, true);
                 true
             }
             Msg::ChangeValue(value) => {
                 self.value = value;
-                if self.show {
+                if self.show && !props.keep_open {
                     self.pending_change = true;
                     if !self.change_from_input {
                         yew::Component::update(self, ctx, Msg::HidePicker)
@@ -343,7 +394,7 @@ impl Component for PwtDropdown {
             }
             Msg::FocusChange(has_focus) => {
                 if !has_focus {
-                    self.show = false;
+                    self.set_show(props, false);
                 }
                 true
             }
@@ -351,7 +402,7 @@ impl Component for PwtDropdown {
                 if !self.show {
                     return false;
                 }
-                self.show = false;
+                self.set_show(props, false);
                 // closing by interaction elsewhere does not grab focus back to the input, unlike
                 // HidePicker; emit a pending change so a prior selection is not lost
                 if self.pending_change {
@@ -362,13 +413,34 @@ impl Component for PwtDropdown {
                 }
                 true
             }
+            Msg::DisabledContextUpdate(context) => {
+                let changed = self.context_disabled != context.disabled;
+                self.context_disabled = context.disabled;
+                if changed && context.disabled {
+                    self.set_show(props, false);
+                }
+                changed
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if let Some(open) = props.open {
+            if open != self.show {
+                if open {
+                    self.update_dropup(props);
+                }
+                self.show = open;
+            }
         }
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
-        let disabled = props.input_props.disabled;
+        let disabled = self.is_disabled(props);
         let editable = props.editable;
 
         let onclick = ctx.link().batch_callback(move |e: MouseEvent| {
@@ -438,7 +510,7 @@ impl Component for PwtDropdown {
 
             Container::new()
                 .listeners(&props.listeners)
-                .class("pwt-flex-fill")
+                .class(crate::css::Flex::Fill)
                 .class("pwt-input-content")
                 .class((!props.editable).then_some("non-editable"))
                 .attribute(
@@ -457,7 +529,7 @@ impl Component for PwtDropdown {
                 .with_child(
                     Input::new()
                         .name(props.input_props.name.clone())
-                        .disabled(props.input_props.disabled)
+                        .disabled(disabled)
                         .required(props.input_props.required)
                         .onpointerdown(ctx.link().callback(|_| Msg::MouseDownInput))
                         .attribute("value", value)
@@ -468,8 +540,9 @@ impl Component for PwtDropdown {
         } else {
             Input::new()
                 .with_input_props(&props.input_props)
+                .disabled(disabled)
                 .listeners(&props.listeners)
-                .class("pwt-flex-fill")
+                .class(crate::css::Flex::Fill)
                 .class((!props.editable).then_some("non-editable"))
                 .attribute("value", value)
                 .attribute("type", "text")
@@ -497,7 +570,7 @@ impl Component for PwtDropdown {
             .class("pwt-input-type-text")
             .class(self.show.then_some("picker-open"))
             .class(disabled.then_some("disabled"))
-            .class("pwt-w-100")
+            .class(crate::css::FullWidth)
             .class(if props.valid {
                 "is-valid"
             } else {
@@ -0,0 +1,323 @@
+use serde_json::Value;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+
+use crate::AsyncPool;
+use crate::dom::focus::FocusTracker;
+use crate::prelude::*;
+use crate::props::{IntoSubmitCallback, SubmitCallback};
+use crate::widget::form::{Field, IntoValidateFn, ValidateFn};
+use crate::widget::{Container, Fa};
+
+use pwt_macros::{builder, widget};
+
+/// Inline editable text, for example item names or titles.
+///
+/// The text is displayed like normal text until the user clicks on it
+/// (or presses `F2` or `Enter`). It is then replaced by a [Field].
+/// Pressing `Enter` or moving the focus away commits the change,
+/// `Escape` cancels the edit.
+///
+/// The optional [on_save](Self::on_save) callback is async. The editor
+/// stays open until the save operation succeeds, and displays the
+/// error if it fails.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::EditInPlace;
+/// # async fn rename(name: String) -> Result<(), anyhow::Error> { Ok(()) }
+/// fn title(name: AttrValue) -> Html {
+///     EditInPlace::new(name)
+///         .on_save(|name: String| async move { rename(name).await })
+///         .into()
+/// }
+/// ```
+#[widget(pwt=crate, comp=PwtEditInPlace, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct EditInPlace {
+    /// The displayed text.
+    pub value: AttrValue,
+
+    /// Text displayed when the value is empty.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+
+    /// Disable editing.
+    #[builder]
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Validation function.
+    #[prop_or_default]
+    pub validate: Option<ValidateFn<String>>,
+
+    /// Async save callback, called with the new value.
+    #[builder_cb(IntoSubmitCallback, into_submit_callback, String)]
+    #[prop_or_default]
+    pub on_save: Option<SubmitCallback<String>>,
+
+    /// Change callback.
+    ///
+    /// Emitted with the new value after a successful commit.
+    #[builder_cb(IntoEventCallback, into_event_callback, String)]
+    #[prop_or_default]
+    pub on_change: Option<Callback<String>>,
+}
+
+impl EditInPlace {
+    /// Create a new instance.
+    pub fn new(value: impl Into<AttrValue>) -> Self {
+        yew::props!(Self {
+            value: value.into()
+        })
+    }
+
+    /// Builder style method to set the validation function.
+    pub fn validate(mut self, validate: impl IntoValidateFn<String>) -> Self {
+        self.set_validate(validate);
+        self
+    }
+
+    /// Method to set the validation function.
+    pub fn set_validate(&mut self, validate: impl IntoValidateFn<String>) {
+        self.validate = validate.into_validate_fn();
+    }
+}
+
+pub enum Msg {
+    StartEdit,
+    Input(String),
+    Commit(bool), // restore focus?
+    Cancel,
+    SaveResult(Result<(), String>),
+    FocusChange(bool),
+}
+
+#[doc(hidden)]
+pub struct PwtEditInPlace {
+    value: AttrValue,
+    editing: bool,
+    edit_value: String,
+    saving: bool,
+    error: Option<String>,
+    restore_focus: bool,
+    focus_tracker: FocusTracker,
+    display_ref: NodeRef,
+    async_pool: AsyncPool,
+}
+
+impl PwtEditInPlace {
+    fn close_editor(&mut self, restore_focus: bool) {
+        self.editing = false;
+        self.saving = false;
+        self.error = None;
+        self.restore_focus = restore_focus;
+    }
+}
+
+impl Component for PwtEditInPlace {
+    type Message = Msg;
+    type Properties = EditInPlace;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            value: ctx.props().value.clone(),
+            editing: false,
+            edit_value: String::new(),
+            saving: false,
+            error: None,
+            restore_focus: false,
+            focus_tracker: FocusTracker::new(ctx.link().callback(Msg::FocusChange)),
+            display_ref: NodeRef::default(),
+            async_pool: AsyncPool::new(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::StartEdit => {
+                if props.disabled || self.editing {
+                    return false;
+                }
+                self.editing = true;
+                self.edit_value = self.value.to_string();
+                self.error = None;
+                true
+            }
+            Msg::Input(value) => {
+                self.edit_value = value;
+                self.error = None;
+                true
+            }
+            Msg::Commit(restore_focus) => {
+                if !self.editing || self.saving {
+                    return false;
+                }
+
+                if self.edit_value == self.value.as_str() {
+                    self.close_editor(restore_focus);
+                    return true;
+                }
+
+                if let Some(validate) = &props.validate {
+                    if validate.apply(&self.edit_value).is_err() {
+                        // the field displays the validation error
+                        return false;
+                    }
+                }
+
+                self.restore_focus = restore_focus;
+
+                match &props.on_save {
+                    Some(on_save) => {
+                        self.saving = true;
+                        let on_save = on_save.clone();
+                        let value = self.edit_value.clone();
+                        self.async_pool.send_future(ctx.link().clone(), async move {
+                            let result = on_save.apply(value).await.map_err(|err| err.to_string());
+                            Msg::SaveResult(result)
+                        });
+                        true
+                    }
+                    None => {
+                        ctx.link().send_message(Msg::SaveResult(Ok(())));
+                        false
+                    }
+                }
+            }
+            Msg::SaveResult(result) => {
+                self.saving = false;
+                match result {
+                    Ok(()) => {
+                        self.value = self.edit_value.clone().into();
+                        if let Some(on_change) = &props.on_change {
+                            on_change.emit(self.edit_value.clone());
+                        }
+                        self.close_editor(self.restore_focus);
+                    }
+                    Err(err) => {
+                        self.error = Some(err);
+                        self.restore_focus = false;
+                    }
+                }
+                true
+            }
+            Msg::Cancel => {
+                if !self.editing {
+                    return false;
+                }
+                self.close_editor(true);
+                true
+            }
+            Msg::FocusChange(has_focus) => {
+                if !has_focus && self.editing {
+                    if self.error.is_some() {
+                        // keep the failed value, so that the user can fix it
+                        return false;
+                    }
+                    ctx.link().send_message(Msg::Commit(false));
+                }
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value {
+            self.value = props.value.clone();
+        }
+        if props.disabled && self.editing && !self.saving {
+            self.close_editor(false);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        if self.editing {
+            let valid: Option<Result<Value, String>> = self.error.clone().map(Err);
+            let mut field = Field::new()
+                .autofocus(true)
+                .disabled(self.saving)
+                .value(self.edit_value.clone())
+                .validate(props.validate.clone())
+                .valid(valid)
+                .on_input(ctx.link().callback(Msg::Input));
+
+            if self.saving {
+                field.add_trigger(Fa::new("spinner").pulse(), true);
+            }
+
+            return Container::new()
+                .with_std_props(&props.std_props)
+                .listeners(&props.listeners)
+                .class("pwt-edit-in-place")
+                .class("editing")
+                .onfocusin(self.focus_tracker.get_focus_callback(true))
+                .onfocusout(self.focus_tracker.get_focus_callback(false))
+                .onkeydown({
+                    let link = ctx.link().clone();
+                    move |event: KeyboardEvent| match event.key().as_str() {
+                        "Enter" => {
+                            event.prevent_default();
+                            event.stop_propagation();
+                            link.send_message(Msg::Commit(true));
+                        }
+                        "Escape" => {
+                            event.prevent_default();
+                            event.stop_propagation();
+                            link.send_message(Msg::Cancel);
+                        }
+                        _ => {}
+                    }
+                })
+                .with_child(field)
+                .into();
+        }
+
+        let empty = self.value.is_empty();
+        let text = match (&props.placeholder, empty) {
+            (Some(placeholder), true) => placeholder.clone(),
+            _ => self.value.clone(),
+        };
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-edit-in-place")
+            .class(empty.then_some("pwt-edit-in-place-empty"))
+            .class(props.disabled.then_some("disabled"))
+            .attribute("role", "button")
+            .attribute("tabindex", (!props.disabled).then_some("0"))
+            .attribute("aria-disabled", props.disabled.then_some("true"))
+            .attribute("title", (!props.disabled).then(|| tr!("Click to edit")))
+            .onclick(ctx.link().callback(|_| Msg::StartEdit))
+            .onkeydown({
+                let link = ctx.link().clone();
+                move |event: KeyboardEvent| match event.key().as_str() {
+                    "F2" | "Enter" => {
+                        event.prevent_default();
+                        event.stop_propagation();
+                        link.send_message(Msg::StartEdit);
+                    }
+                    _ => {}
+                }
+            })
+            .with_child(text)
+            .into_html_with_ref(self.display_ref.clone())
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if !self.editing && self.restore_focus {
+            self.restore_focus = false;
+            if let Some(el) = self.display_ref.cast::<web_sys::HtmlElement>() {
+                let _ = el.focus();
+            }
+        }
+    }
+}
@@ -0,0 +1,455 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use derivative::Derivative;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::css::FontColor;
+use crate::prelude::*;
+use crate::props::IntoOptionalKey;
+use crate::state::{AppInfo, Language, SharedState, SharedStateObserver, session_storage};
+use crate::web_sys_ext::clipboard_write_text;
+use crate::widget::{Button, Column, Container, Dialog, DialogSize, Toolbar};
+
+// Session storage key used to keep panic reports across page reloads.
+const PANIC_REPORT_KEY: &str = "pwt-panic-report";
+
+thread_local! {
+    static PANIC_REPORTER: RefCell<Option<DiagnosticsReporter>> = const { RefCell::new(None) };
+}
+
+/// Error report with diagnostics data (see [DiagnosticsReporter]).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiagnosticsReport {
+    /// Error message.
+    pub message: String,
+    /// Error details (cause chain, panic location).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The report was generated by a panic (in a previous page load).
+    #[serde(default)]
+    pub panic: bool,
+    /// Product name and version (see [AppInfo::full_name]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+    /// Product build date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_date: Option<String>,
+    /// Report time (ISO 8601).
+    pub time: String,
+    /// Page URL (including the route).
+    pub url: String,
+    /// Browser user agent.
+    pub user_agent: String,
+    /// UI language.
+    pub language: String,
+    /// Recent log entries.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub log: Vec<String>,
+}
+
+impl DiagnosticsReport {
+    /// Returns the report as plain text.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("Error: {}\n", self.message);
+        if let Some(details) = &self.details {
+            text.push_str(&format!("{details}\n"));
+        }
+        text.push('\n');
+        if let Some(product) = &self.product {
+            text.push_str(&format!("Product: {product}\n"));
+        }
+        if let Some(build_date) = &self.build_date {
+            text.push_str(&format!("Build Date: {build_date}\n"));
+        }
+        text.push_str(&format!("Time: {}\n", self.time));
+        text.push_str(&format!("URL: {}\n", self.url));
+        text.push_str(&format!("User Agent: {}\n", self.user_agent));
+        text.push_str(&format!("Language: {}\n", self.language));
+        if !self.log.is_empty() {
+            text.push_str("\nLog:\n");
+            for line in &self.log {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+/// Collects [DiagnosticsReport]s for unexpected errors.
+///
+/// Reports are shown by the [ErrorReportDialog], where the user can copy
+/// or download them, which makes bug reports from the field actionable.
+/// Each report includes the [app_info](Self::app_info), the current URL
+/// (route), browser info and recent log entries (from the
+/// [pwt::log](crate::log) ring buffer, or see [log_source](Self::log_source)).
+///
+/// Panics abort the WebAssembly instance, so the dialog cannot show up
+/// immediately. Instead, [install_panic_hook](Self::install_panic_hook)
+/// saves the report in the session storage, and a reporter created after
+/// the next page load shows it.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::state::AppInfo;
+/// # use pwt::widget::{Column, DiagnosticsReporter, ErrorReportDialog};
+/// # fn create_app(body: Html) -> Html {
+/// let reporter = DiagnosticsReporter::new().app_info(AppInfo::new("My App").version("1.0"));
+/// reporter.install_panic_hook();
+///
+/// Column::new()
+///     .with_child(body)
+///     .with_child(ErrorReportDialog::new(reporter.clone()))
+///     .into()
+/// # }
+/// ```
+#[derive(Derivative)]
+#[derivative(Clone, PartialEq)]
+pub struct DiagnosticsReporter {
+    reports: SharedState<Vec<DiagnosticsReport>>,
+    app_info: Option<AppInfo>,
+    #[allow(clippy::type_complexity)]
+    #[derivative(PartialEq(compare_with = "opt_rc_ptr_eq"))]
+    log_source: Option<Rc<dyn Fn() -> Vec<String>>>,
+}
+
+fn opt_rc_ptr_eq<T: ?Sized>(a: &Option<Rc<T>>, b: &Option<Rc<T>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl Default for DiagnosticsReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiagnosticsReporter {
+    /// Create a new instance.
+    ///
+    /// Picks up the panic report saved during the previous page load (if any).
+    pub fn new() -> Self {
+        let mut reports = Vec::new();
+        if let Some(store) = session_storage() {
+            if let Ok(Some(data)) = store.get_item(PANIC_REPORT_KEY) {
+                let _ = store.remove_item(PANIC_REPORT_KEY);
+                match serde_json::from_str::<DiagnosticsReport>(&data) {
+                    Ok(report) => reports.push(report),
+                    Err(err) => log::error!("unable to parse panic report: {err}"),
+                }
+            }
+        }
+        Self {
+            reports: SharedState::new(reports),
+            app_info: None,
+            log_source: None,
+        }
+    }
+
+    /// Builder style method to set the application info.
+    pub fn app_info(mut self, app_info: impl Into<Option<AppInfo>>) -> Self {
+        self.app_info = app_info.into();
+        self
+    }
+
+    /// Builder style method to set the function returning recent log entries.
+    ///
+    /// Default is to use the last 100 entries from the [pwt::log](crate::log)
+    /// ring buffer.
+    pub fn log_source(mut self, log_source: impl 'static + Fn() -> Vec<String>) -> Self {
+        self.log_source = Some(Rc::new(log_source));
+        self
+    }
+
+    /// Collect the diagnostics data.
+    pub fn collect(
+        &self,
+        message: impl Into<String>,
+        details: Option<String>,
+    ) -> DiagnosticsReport {
+        let window = gloo_utils::window();
+        let language = Language::load();
+        DiagnosticsReport {
+            message: message.into(),
+            details,
+            panic: false,
+            product: self.app_info.as_ref().map(|info| info.full_name()),
+            build_date: self
+                .app_info
+                .as_ref()
+                .and_then(|info| info.build_date.as_ref().map(|date| date.to_string())),
+            time: String::from(js_sys::Date::new_0().to_iso_string()),
+            url: window.location().href().unwrap_or_default(),
+            user_agent: window.navigator().user_agent().unwrap_or_default(),
+            language: if language.is_empty() {
+                String::from("default")
+            } else {
+                language
+            },
+            log: self
+                .log_source
+                .as_ref()
+                .map(|log_source| log_source())
+                .unwrap_or_else(|| crate::log::recent_lines(100)),
+        }
+    }
+
+    /// Report an unexpected error (shows the [ErrorReportDialog]).
+    pub fn report(&self, message: impl Into<String>, details: Option<String>) {
+        let report = self.collect(message, details);
+        self.reports.write().push(report);
+    }
+
+    /// Report an unexpected error, including the cause chain.
+    pub fn report_error(&self, err: &anyhow::Error) {
+        let details = err
+            .chain()
+            .skip(1)
+            .map(|cause| format!("Caused by: {cause}"))
+            .collect::<Vec<_>>();
+        let details = (!details.is_empty()).then(|| details.join("\n"));
+        self.report(err.to_string(), details);
+    }
+
+    /// Install a panic hook which saves a report for the next page load.
+    ///
+    /// The previous hook (i.e. `console_error_panic_hook`) is still called.
+    pub fn install_panic_hook(&self) {
+        let first_install =
+            PANIC_REPORTER.with(|reporter| reporter.borrow_mut().replace(self.clone()).is_none());
+        if !first_install {
+            return;
+        }
+
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            previous_hook(info);
+
+            let message = match info.payload().downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => match info.payload().downcast_ref::<String>() {
+                    Some(message) => message.clone(),
+                    None => String::from("panic"),
+                },
+            };
+            let details = info
+                .location()
+                .map(|location| format!("Location: {location}"));
+
+            let report = PANIC_REPORTER.with(|reporter| {
+                reporter.try_borrow().ok().and_then(|reporter| {
+                    reporter.as_ref().map(|reporter| {
+                        let mut report = reporter.collect(message, details);
+                        report.panic = true;
+                        report
+                    })
+                })
+            });
+
+            if let (Some(report), Some(store)) = (report, session_storage()) {
+                if let Ok(data) = serde_json::to_string(&report) {
+                    let _ = store.set_item(PANIC_REPORT_KEY, &data);
+                }
+            }
+        }));
+    }
+
+    /// Returns the first pending report.
+    pub fn pending(&self) -> Option<DiagnosticsReport> {
+        self.reports.read().first().cloned()
+    }
+
+    /// Remove the first pending report.
+    pub fn dismiss(&self) {
+        let mut reports = self.reports.write();
+        if !reports.is_empty() {
+            reports.remove(0);
+        }
+    }
+
+    /// Register a listener, called when reports are added or dismissed.
+    pub fn add_listener(
+        &self,
+        cb: impl Into<Callback<SharedState<Vec<DiagnosticsReport>>>>,
+    ) -> SharedStateObserver<Vec<DiagnosticsReport>> {
+        self.reports.add_listener(cb)
+    }
+}
+
+/// Dialog showing pending [DiagnosticsReport]s.
+///
+/// Renders nothing unless the [DiagnosticsReporter] has a pending report.
+/// The dialog shows the full report text and allows the user to copy or
+/// download it.
+#[derive(Clone, PartialEq, Properties)]
+pub struct ErrorReportDialog {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// The reporter providing the reports.
+    pub reporter: DiagnosticsReporter,
+}
+
+impl ErrorReportDialog {
+    /// Create a new instance.
+    pub fn new(reporter: DiagnosticsReporter) -> Self {
+        yew::props!(Self { reporter })
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+}
+
+pub enum Msg {
+    ReporterChange,
+    Copy,
+    Download,
+    Close,
+}
+
+#[doc(hidden)]
+pub struct PwtErrorReportDialog {
+    _observer: SharedStateObserver<Vec<DiagnosticsReport>>,
+}
+
+fn download_text(filename: &str, text: &str) {
+    let url = format!(
+        "data:text/plain;charset=utf-8,{}",
+        utf8_percent_encode(text, NON_ALPHANUMERIC)
+    );
+    let document = gloo_utils::document();
+    let Ok(link) = document.create_element("a") else {
+        return;
+    };
+    let _ = link.set_attribute("href", &url);
+    let _ = link.set_attribute("download", filename);
+    if let Ok(link) = link.dyn_into::<web_sys::HtmlElement>() {
+        link.click();
+    }
+}
+
+impl Component for PwtErrorReportDialog {
+    type Message = Msg;
+    type Properties = ErrorReportDialog;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let _observer = ctx
+            .props()
+            .reporter
+            .add_listener(ctx.link().callback(|_| Msg::ReporterChange));
+        Self { _observer }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let reporter = &ctx.props().reporter;
+        match msg {
+            Msg::ReporterChange => true,
+            Msg::Copy => {
+                if let Some(report) = reporter.pending() {
+                    let _ = clipboard_write_text(&report.to_text());
+                }
+                false
+            }
+            Msg::Download => {
+                if let Some(report) = reporter.pending() {
+                    download_text("error-report.txt", &report.to_text());
+                }
+                false
+            }
+            Msg::Close => {
+                reporter.dismiss();
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.reporter != old_props.reporter {
+            self._observer = props
+                .reporter
+                .add_listener(ctx.link().callback(|_| Msg::ReporterChange));
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        let Some(report) = ctx.props().reporter.pending() else {
+            return html! {};
+        };
+
+        let intro = if report.panic {
+            tr!("The application crashed. Please include this report when you contact support.")
+        } else {
+            tr!(
+                "An unexpected error occurred. Please include this report when you contact support."
+            )
+        };
+
+        let text = Container::new()
+            // monospace, scrollable text (fixed height set by pwt-error-report)
+            .class("pwt-log-view")
+            .class("pwt-error-report")
+            .class("pwt-border")
+            .padding(1)
+            .with_child(report.to_text());
+
+        let toolbar = Toolbar::new()
+            .with_child(
+                Button::new(tr!("Copy"))
+                    .icon_class("fa fa-clipboard")
+                    .on_activate(link.callback(|_| Msg::Copy)),
+            )
+            .with_child(
+                Button::new(tr!("Download"))
+                    .icon_class("fa fa-download")
+                    .on_activate(link.callback(|_| Msg::Download)),
+            )
+            .with_flex_spacer()
+            .with_child(
+                Button::new(tr!("Close"))
+                    .autofocus(true)
+                    .on_activate(link.callback(|_| Msg::Close)),
+            );
+
+        Dialog::new(tr!("Error Report"))
+            .size(DialogSize::Medium)
+            .on_close(link.callback(|_| Msg::Close))
+            .with_child(
+                Column::new()
+                    .padding(2)
+                    .gap(2)
+                    .with_child(Container::new().with_child(intro))
+                    .with_child(
+                        Container::new()
+                            .class(FontColor::Error)
+                            .with_child(report.message.clone()),
+                    )
+                    .with_child(text),
+            )
+            .with_child(toolbar)
+            .into()
+    }
+}
+
+impl From<ErrorReportDialog> for VNode {
+    fn from(val: ErrorReportDialog) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtErrorReportDialog>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
@@ -1,15 +1,95 @@
 // Fontawesome icons
 
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use yew::virtual_dom::VTag;
 
 use crate::prelude::*;
 
 use pwt_macros::widget;
 
+/// Icon set used to resolve icon names.
+///
+/// Icon sets can be registered with [register_icon_set] and are then
+/// selected using the `<namespace>:<name>` syntax, i.e. `Fa::new("mdi:home")`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum IconSet {
+    /// Icon font using CSS classes (`<base_class> <prefix><name>`).
+    Font {
+        base_class: AttrValue,
+        prefix: AttrValue,
+    },
+    /// Inline SVG icons (maps icon names to SVG markup).
+    ///
+    /// The icons get the `pwt-svg-icon` class, which sizes the SVG like a
+    /// font icon (`1em`, using `currentColor`).
+    Svg(Rc<HashMap<String, AttrValue>>),
+}
+
+impl IconSet {
+    /// Create a new icon font description.
+    pub fn font(base_class: impl Into<AttrValue>, prefix: impl Into<AttrValue>) -> Self {
+        IconSet::Font {
+            base_class: base_class.into(),
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Create a new inline SVG icon set.
+    pub fn svg(icons: impl IntoIterator<Item = (impl Into<String>, impl Into<AttrValue>)>) -> Self {
+        let icons = icons
+            .into_iter()
+            .map(|(name, svg)| (name.into(), svg.into()))
+            .collect();
+        IconSet::Svg(Rc::new(icons))
+    }
+}
+
+thread_local! {
+    static DEFAULT_ICON_SET: RefCell<IconSet> = RefCell::new(IconSet::font("fa", "fa-"));
+    static ICON_SETS: RefCell<HashMap<String, IconSet>> = RefCell::new(HashMap::new());
+}
+
+/// Register an icon set using the specified namespace.
+///
+/// Icons from that set can be used with `Fa::new("<namespace>:<name>")`.
+pub fn register_icon_set(namespace: impl Into<String>, icon_set: IconSet) {
+    ICON_SETS.with(|sets| sets.borrow_mut().insert(namespace.into(), icon_set));
+}
+
+/// Set the icon set used for names without namespace.
+///
+/// Default is Font Awesome (`IconSet::font("fa", "fa-")`).
+pub fn set_default_icon_set(icon_set: IconSet) {
+    DEFAULT_ICON_SET.with(|set| *set.borrow_mut() = icon_set);
+}
+
+fn lookup_icon_set(name: &str) -> (Option<IconSet>, &str) {
+    match name.split_once(':') {
+        Some((namespace, icon_name)) => {
+            let set = ICON_SETS.with(|sets| sets.borrow().get(namespace).cloned());
+            (set, icon_name)
+        }
+        None => (
+            Some(DEFAULT_ICON_SET.with(|set| set.borrow().clone())),
+            name,
+        ),
+    }
+}
+
 /// Font Awesome icons.
 ///
 /// This is a helper to create Font Awesome icons from there name.
 ///
+/// Other icon fonts or inline SVG icon sets can be used after registering
+/// them with [register_icon_set] (or [set_default_icon_set]).
+///
+/// Rotation and flipping use the theme classes `pwt-icon-rotate-*` and
+/// `pwt-icon-flip-*`, so they work for all icon sets.
+///
 /// # Accessibility
 ///
 /// This widget hides the icon from the accessibility tree
@@ -22,14 +102,37 @@ use pwt_macros::widget;
 /// accessible to assistive technologies.
 #[widget(pwt=crate, @element)]
 #[derive(Properties, PartialEq, Clone)]
-pub struct Fa {}
+pub struct Fa {
+    /// Inline SVG markup (if the icon is from a SVG icon set).
+    #[prop_or_default]
+    svg: Option<AttrValue>,
+}
 
 impl Fa {
     /// Create a new instrtance from the icon name.
+    ///
+    /// Names can be prefixed with the namespace of a registered
+    /// [IconSet], i.e. `mdi:home`.
     pub fn new(name: impl AsRef<str>) -> Self {
-        yew::props! { Self {}}
-            .class("fa")
-            .class(format!("fa-{}", name.as_ref()))
+        let name = name.as_ref();
+        let mut me = yew::props! { Self {}};
+        match lookup_icon_set(name) {
+            (Some(IconSet::Font { base_class, prefix }), name) => {
+                me.add_class(base_class);
+                me.add_class(format!("{prefix}{name}"));
+            }
+            (Some(IconSet::Svg(icons)), name) => {
+                me.add_class("pwt-svg-icon");
+                me.svg = icons.get(name).cloned();
+                if me.svg.is_none() {
+                    log::error!("Fa: no such SVG icon '{name}'");
+                }
+            }
+            (None, _) => {
+                log::error!("Fa: no icon set registered for '{name}'");
+            }
+        }
+        me
     }
 
     /// Create a new instance using the passed CSS class name.
@@ -84,6 +187,54 @@ impl Fa {
         self.add_class("fa-5x");
         self
     }
+
+    /// Rotate icon by 90 degrees.
+    pub fn rotate_90(mut self) -> Self {
+        self.add_class("pwt-icon-rotate-90");
+        self
+    }
+
+    /// Rotate icon by 180 degrees.
+    pub fn rotate_180(mut self) -> Self {
+        self.add_class("pwt-icon-rotate-180");
+        self
+    }
+
+    /// Rotate icon by 270 degrees.
+    pub fn rotate_270(mut self) -> Self {
+        self.add_class("pwt-icon-rotate-270");
+        self
+    }
+
+    /// Mirror icon horizontally.
+    pub fn flip_horizontal(mut self) -> Self {
+        self.add_class("pwt-icon-flip-horizontal");
+        self
+    }
+
+    /// Mirror icon vertically.
+    pub fn flip_vertical(mut self) -> Self {
+        self.add_class("pwt-icon-flip-vertical");
+        self
+    }
+
+    /// Use normal icon size inside a [FaStack].
+    pub fn stack_1x(mut self) -> Self {
+        self.add_class("fa-stack-1x");
+        self
+    }
+
+    /// Use double icon size inside a [FaStack] (usually the background icon).
+    pub fn stack_2x(mut self) -> Self {
+        self.add_class("fa-stack-2x");
+        self
+    }
+
+    /// Use inverted color (useful for stacked icons).
+    pub fn inverse(mut self) -> Self {
+        self.add_class("fa-inverse");
+        self
+    }
 }
 
 impl IntoVTag for Fa {
@@ -94,7 +245,49 @@ impl IntoVTag for Fa {
             node_ref,
             None::<&str>,
             Some(self.listeners),
-            None,
+            self.svg.map(|svg| vec![Html::from_html_unchecked(svg)]),
+        )
+    }
+}
+
+/// Stack multiple [Fa] icons on top of each other.
+///
+/// Useful to create badge overlays or icons inside a circle/square.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{Fa, FaStack};
+/// FaStack::new()
+///     .with_child(Fa::new("circle").stack_2x())
+///     .with_child(Fa::new("flag").stack_1x().inverse())
+/// # ;
+/// ```
+#[widget(pwt=crate, @element, @container)]
+#[derive(Properties, PartialEq, Clone)]
+pub struct FaStack {}
+
+impl Default for FaStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FaStack {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props! { Self {}}.class("fa-stack")
+    }
+}
+
+impl IntoVTag for FaStack {
+    fn into_vtag_with_ref(mut self, node_ref: NodeRef) -> VTag {
+        self.set_attribute("role", "none");
+        self.std_props.into_vtag(
+            Cow::Borrowed("span"),
+            node_ref,
+            None::<&str>,
+            Some(self.listeners),
+            Some(self.children),
         )
     }
 }
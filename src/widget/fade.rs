@@ -0,0 +1,139 @@
+use yew::html::IntoEventCallback;
+
+use crate::dom::get_system_prefer_reduced_motion;
+use crate::prelude::*;
+use crate::widget::Container;
+
+use pwt_macros::{builder, widget};
+
+/// Show/hide children with an animated opacity.
+///
+/// Hidden content is `visibility: hidden` (still takes up space), so it
+/// is neither focusable nor visible to screen readers. If the user
+/// prefers reduced motion, the content is shown/hidden without animation.
+///
+/// Set [unmount_on_close](Self::unmount_on_close) to remove the children
+/// after the fade out finished (i.e. to dismiss a banner).
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Fade;
+/// # fn create_banner(visible: bool) -> Fade {
+/// Fade::new(visible)
+///     .unmount_on_close(true)
+///     .with_child("Update available")
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtFade, @element, @container)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct Fade {
+    /// Show the children.
+    #[builder]
+    pub open: bool,
+
+    /// Animation duration in milliseconds.
+    #[builder]
+    #[prop_or(200)]
+    pub duration: u32,
+
+    /// Unmount the children when closed.
+    #[builder]
+    #[prop_or_default]
+    pub unmount_on_close: bool,
+
+    /// Called with the [open](Self::open) state when the animation finished.
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    #[prop_or_default]
+    pub on_animation_end: Option<Callback<bool>>,
+}
+
+impl Fade {
+    /// Create a new instance.
+    pub fn new(open: bool) -> Self {
+        yew::props!(Self { open })
+    }
+}
+
+pub enum Msg {
+    TransitionEnd(TransitionEvent),
+}
+
+#[doc(hidden)]
+pub struct PwtFade {
+    animating: bool,
+}
+
+impl PwtFade {
+    fn animation_end(&mut self, props: &Fade) {
+        self.animating = false;
+        if let Some(on_animation_end) = &props.on_animation_end {
+            on_animation_end.emit(props.open);
+        }
+    }
+}
+
+impl Component for PwtFade {
+    type Message = Msg;
+    type Properties = Fade;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { animating: false }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::TransitionEnd(event) => {
+                // ignore bubbled events from children
+                if event.property_name() != "opacity" || event.target() != event.current_target() {
+                    return false;
+                }
+                self.animation_end(props);
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.open != old_props.open {
+            if get_system_prefer_reduced_motion() {
+                self.animation_end(props);
+            } else {
+                self.animating = true;
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        // visibility switches at the end of the fade out
+        let transition = (!get_system_prefer_reduced_motion()).then(|| {
+            format!(
+                "opacity {0}ms ease-in-out, visibility {0}ms",
+                props.duration
+            )
+        });
+
+        let show_children = props.open || self.animating || !props.unmount_on_close;
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-fade")
+            .class(props.open.then_some("open"))
+            .style("opacity", if props.open { "1" } else { "0" })
+            .style("visibility", (!props.open).then_some("hidden"))
+            .style("transition", transition)
+            .ontransitionend(ctx.link().callback(Msg::TransitionEnd))
+            .children(
+                show_children
+                    .then(|| props.children.clone())
+                    .unwrap_or_default(),
+            )
+            .into()
+    }
+}
@@ -0,0 +1,249 @@
+use yew::html::IntoEventCallback;
+
+use crate::dom::DomTextHighlighter;
+use crate::prelude::*;
+use crate::widget::{Button, Input, Row};
+
+use pwt_macros::{builder, widget};
+
+/// Find bar to search and highlight text inside a container.
+///
+/// The bar searches the rendered text below the `target` element (see
+/// [DomTextHighlighter]), displays the number of matches and allows to
+/// navigate between them (`Enter`, `Shift+Enter` or the arrow buttons).
+/// `Escape` closes the bar.
+///
+/// Please call [refresh](Self::refresh) with a new value whenever the
+/// target content changes, so that the highlights are computed again
+/// after the content got rendered. Highlighting does not modify the
+/// target DOM, so the content can be re-rendered at any time.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{Column, Container, FindBar};
+/// fn log_view(content: Html, node_ref: NodeRef) -> Html {
+///     Column::new()
+///         .with_child(FindBar::new(node_ref.clone()))
+///         .with_child(Container::new().with_child(content).into_html_with_ref(node_ref))
+///         .into()
+/// }
+/// ```
+#[widget(pwt=crate, comp=PwtFindBar, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct FindBar {
+    /// The container to search.
+    pub target: NodeRef,
+
+    /// Case sensitive search.
+    #[builder]
+    #[prop_or_default]
+    pub case_sensitive: bool,
+
+    /// Content version. Change this value to re-apply the search after
+    /// the target content changed.
+    #[builder]
+    #[prop_or_default]
+    pub refresh: usize,
+
+    /// Close callback.
+    ///
+    /// Called when the user presses `Escape` or the close button. The
+    /// close button is only visible if this callback is set.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_close: Option<Callback<()>>,
+}
+
+impl FindBar {
+    /// Create a new instance.
+    pub fn new(target: NodeRef) -> Self {
+        yew::props!(Self { target })
+    }
+}
+
+pub enum Msg {
+    Query(String),
+    Next,
+    Previous,
+    Close,
+    Refresh,
+}
+
+#[doc(hidden)]
+pub struct PwtFindBar {
+    query: String,
+    highlighter: Option<DomTextHighlighter>,
+    count: usize,
+    current: Option<usize>,
+    // search again after the target content got rendered
+    refresh_pending: bool,
+}
+
+impl PwtFindBar {
+    fn apply_search(&mut self, props: &FindBar) {
+        if self.highlighter.is_none() {
+            self.highlighter = props
+                .target
+                .cast::<web_sys::Element>()
+                .map(|el| DomTextHighlighter::new(&el));
+        }
+        match &mut self.highlighter {
+            Some(highlighter) => {
+                self.count = highlighter.highlight(&self.query, props.case_sensitive);
+                self.current = highlighter.next();
+            }
+            None => {
+                self.count = 0;
+                self.current = None;
+            }
+        }
+    }
+}
+
+impl Component for PwtFindBar {
+    type Message = Msg;
+    type Properties = FindBar;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            query: String::new(),
+            highlighter: None,
+            count: 0,
+            current: None,
+            refresh_pending: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Query(query) => {
+                self.query = query;
+                self.apply_search(props);
+            }
+            Msg::Next => {
+                if let Some(highlighter) = &mut self.highlighter {
+                    self.current = highlighter.next();
+                }
+            }
+            Msg::Previous => {
+                if let Some(highlighter) = &mut self.highlighter {
+                    self.current = highlighter.previous();
+                }
+            }
+            Msg::Refresh => {
+                self.apply_search(props);
+            }
+            Msg::Close => {
+                self.highlighter = None; // drop removes the highlights
+                self.count = 0;
+                self.current = None;
+                if let Some(on_close) = &props.on_close {
+                    on_close.emit(());
+                }
+            }
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.target != old_props.target {
+            self.highlighter = None;
+        }
+        if props.target != old_props.target
+            || props.case_sensitive != old_props.case_sensitive
+            || props.refresh != old_props.refresh
+        {
+            self.refresh_pending = true;
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let status = if self.query.is_empty() {
+            String::new()
+        } else if self.count == 0 {
+            tr!("No matches")
+        } else {
+            let current = self.current.map(|i| i + 1).unwrap_or(0);
+            tr!("{0} of {1}", current, self.count)
+        };
+
+        let onkeydown =
+            ctx.link()
+                .batch_callback(|event: KeyboardEvent| match event.key().as_str() {
+                    "Enter" => {
+                        event.prevent_default();
+                        Some(if event.shift_key() {
+                            Msg::Previous
+                        } else {
+                            Msg::Next
+                        })
+                    }
+                    "Escape" => {
+                        event.prevent_default();
+                        event.stop_propagation();
+                        Some(Msg::Close)
+                    }
+                    _ => None,
+                });
+
+        Row::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-find-bar")
+            .class(crate::css::AlignItems::Center)
+            .gap(1)
+            .padding(1)
+            .attribute("role", "search")
+            .with_child(
+                Input::new()
+                    .class("pwt-input")
+                    .class(crate::css::Flex::Fill)
+                    .attribute("type", "search")
+                    .attribute("value", self.query.clone())
+                    .placeholder(tr!("Find"))
+                    .aria_label(tr!("Find"))
+                    .oninput(ctx.link().callback(|event: InputEvent| {
+                        let input: web_sys::HtmlInputElement = event.target_unchecked_into();
+                        Msg::Query(input.value())
+                    }))
+                    .onkeydown(onkeydown),
+            )
+            .with_child(
+                html! {<span class="pwt-find-bar-status" aria-live="polite">{status}</span>},
+            )
+            .with_child(
+                Button::new_icon("fa fa-chevron-up")
+                    .class("pwt-button-text")
+                    .aria_label(tr!("Previous match"))
+                    .disabled(self.count == 0)
+                    .on_activate(ctx.link().callback(|_| Msg::Previous)),
+            )
+            .with_child(
+                Button::new_icon("fa fa-chevron-down")
+                    .class("pwt-button-text")
+                    .aria_label(tr!("Next match"))
+                    .disabled(self.count == 0)
+                    .on_activate(ctx.link().callback(|_| Msg::Next)),
+            )
+            .with_optional_child(props.on_close.is_some().then(|| {
+                Button::new_icon("fa fa-times")
+                    .class("pwt-button-text")
+                    .aria_label(tr!("Close"))
+                    .on_activate(ctx.link().callback(|_| Msg::Close))
+            }))
+            .into()
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if self.refresh_pending {
+            self.refresh_pending = false;
+            ctx.link().send_message(Msg::Refresh);
+        }
+    }
+}
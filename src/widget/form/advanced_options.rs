@@ -0,0 +1,325 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+
+use crate::prelude::*;
+use crate::props::{IntoStorageLocation, StorageLocation};
+use crate::state::PersistentState;
+use crate::widget::{Collapse, Container, Fa, Row};
+
+use super::{FormContext, FormContextObserver};
+
+use pwt_macros::{builder, widget};
+
+/// Expandable section for advanced options.
+///
+/// Shows a toggle row, and expands the children (usually an
+/// [InputPanel](crate::widget::InputPanel) with extra fields) with a
+/// [Collapse] animation. Collapsed children stay mounted, so their fields
+/// are still registered in the [FormContext] and submitted with the form.
+///
+/// Set a [state_id](Self::state_id) (e.g. derived from the dialog id) to
+/// remember the expanded state.
+///
+/// If the section is inside a [Form](super::Form), it automatically expands
+/// when one of the contained fields gets a validation error while the
+/// section is collapsed. Disabled fields, and pristine fields which are
+/// invalid from the start (i.e. empty required fields) are ignored.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::InputPanel;
+/// # use pwt::widget::form::{AdvancedOptions, Field};
+/// # fn create_section() -> AdvancedOptions {
+///     AdvancedOptions::new()
+///         .state_id("my-dialog-advanced")
+///         .with_child(InputPanel::new().with_field("MTU", Field::new().name("mtu")))
+/// # }
+/// ```
+#[widget(pwt=crate, comp=crate::widget::form::PwtAdvancedOptions, @element, @container)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct AdvancedOptions {
+    /// Toggle row text (default "Advanced").
+    #[prop_or_default]
+    #[builder(IntoPropValue, into_prop_value)]
+    pub title: Option<AttrValue>,
+
+    /// Initial expanded state (if there is no saved state).
+    #[prop_or_default]
+    #[builder]
+    pub default_expanded: bool,
+
+    /// Store the expanded state.
+    #[prop_or_default]
+    pub state_id: Option<StorageLocation>,
+
+    /// Called when the expanded state changes.
+    #[prop_or_default]
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    pub on_toggle: Option<Callback<bool>>,
+}
+
+impl Default for AdvancedOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdvancedOptions {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    /// Builder style method to set the persistent state ID.
+    pub fn state_id(mut self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_state_id(state_id);
+        self
+    }
+
+    /// Method to set the persistent state ID.
+    pub fn set_state_id(&mut self, state_id: impl IntoStorageLocation) {
+        self.state_id = state_id.into_storage_location();
+    }
+}
+
+/// Tracks the form fields rendered inside an [AdvancedOptions] section.
+///
+/// Provided as context to the section children. Managed fields announce
+/// themselves (with their [DisabledContainer](crate::widget::DisabledContainer)
+/// state) when they are registered in the form.
+#[derive(Clone, Default)]
+pub(crate) struct AdvancedFieldScope {
+    // field name => disabled by a parent DisabledContainer
+    fields: Rc<RefCell<HashMap<AttrValue, bool>>>,
+}
+
+impl PartialEq for AdvancedFieldScope {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.fields, &other.fields)
+    }
+}
+
+impl AdvancedFieldScope {
+    pub(crate) fn update(&self, name: &AttrValue, context_disabled: bool) {
+        self.fields
+            .borrow_mut()
+            .insert(name.clone(), context_disabled);
+    }
+
+    pub(crate) fn remove(&self, name: &AttrValue) {
+        self.fields.borrow_mut().remove(name);
+    }
+}
+
+pub enum Msg {
+    Toggle,
+    FormCtxUpdate(FormContext),
+    FormCtxDataChange,
+}
+
+#[doc(hidden)]
+pub struct PwtAdvancedOptions {
+    expanded: bool,
+    body_id: AttrValue,
+    expanded_state: Option<PersistentState<Option<bool>>>,
+    // Fields seen in a valid or modified state.
+    armed_fields: HashSet<AttrValue>,
+    field_scope: AdvancedFieldScope,
+    form_ctx: Option<FormContext>,
+    _form_ctx_handle: Option<ContextHandle<FormContext>>,
+    _form_ctx_observer: Option<FormContextObserver>,
+    on_form_data_change: Callback<FormContext>,
+}
+
+impl PwtAdvancedOptions {
+    fn set_expanded(&mut self, props: &AdvancedOptions, expanded: bool) {
+        if self.expanded == expanded {
+            return;
+        }
+        self.expanded = expanded;
+        if let Some(state) = &mut self.expanded_state {
+            state.update(Some(expanded));
+        }
+        if let Some(on_toggle) = &props.on_toggle {
+            on_toggle.emit(expanded);
+        }
+    }
+
+    // Returns true if a contained field got a validation error.
+    fn check_errors(&mut self) -> bool {
+        let Some(form_ctx) = &self.form_ctx else {
+            return false;
+        };
+        let fields = self.field_scope.fields.borrow();
+        let mut has_error = false;
+        for info in form_ctx.read().registered_fields() {
+            // skip fields outside this section, and disabled fields (by
+            // properties, field rules or a parent DisabledContainer)
+            if info.options.disabled || fields.get(&info.name) != Some(&false) {
+                continue;
+            }
+            match info.result {
+                Ok(_) => {
+                    self.armed_fields.insert(info.name);
+                }
+                Err(_) => {
+                    if info.dirty || self.armed_fields.contains(&info.name) {
+                        has_error = true;
+                    }
+                }
+            }
+        }
+        has_error
+    }
+}
+
+impl Component for PwtAdvancedOptions {
+    type Message = Msg;
+    type Properties = AdvancedOptions;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+
+        let expanded_state = props
+            .state_id
+            .as_ref()
+            .map(|state_id| PersistentState::<Option<bool>>::new(state_id.clone()));
+
+        let expanded = expanded_state
+            .as_ref()
+            .and_then(|state| **state)
+            .unwrap_or(props.default_expanded);
+
+        let on_form_ctx_change = Callback::from({
+            let link = ctx.link().clone();
+            move |form_ctx: FormContext| link.send_message(Msg::FormCtxUpdate(form_ctx))
+        });
+
+        let on_form_data_change = Callback::from({
+            let link = ctx.link().clone();
+            move |_form_ctx: FormContext| link.send_message(Msg::FormCtxDataChange)
+        });
+
+        let mut _form_ctx_handle = None;
+        let mut _form_ctx_observer = None;
+        let mut form_ctx = None;
+        if let Some((form, handle)) = ctx.link().context::<FormContext>(on_form_ctx_change) {
+            _form_ctx_handle = Some(handle);
+            _form_ctx_observer = Some(form.add_listener(on_form_data_change.clone()));
+            form_ctx = Some(form);
+        }
+
+        Self {
+            expanded,
+            body_id: AttrValue::from(crate::widget::get_unique_element_id()),
+            expanded_state,
+            armed_fields: HashSet::new(),
+            field_scope: AdvancedFieldScope::default(),
+            form_ctx,
+            _form_ctx_handle,
+            _form_ctx_observer,
+            on_form_data_change,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Toggle => {
+                self.set_expanded(props, !self.expanded);
+                true
+            }
+            Msg::FormCtxUpdate(form_ctx) => {
+                self._form_ctx_observer =
+                    Some(form_ctx.add_listener(self.on_form_data_change.clone()));
+                self.form_ctx = Some(form_ctx);
+                self.armed_fields.clear();
+                false
+            }
+            Msg::FormCtxDataChange => {
+                if self.check_errors() && !self.expanded {
+                    self.set_expanded(props, true);
+                    return true;
+                }
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.state_id != old_props.state_id {
+            self.expanded_state = props
+                .state_id
+                .as_ref()
+                .map(|state_id| PersistentState::<Option<bool>>::new(state_id.clone()));
+            if let Some(expanded) = self.expanded_state.as_ref().and_then(|state| **state) {
+                self.expanded = expanded;
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let title = props
+            .title
+            .clone()
+            .unwrap_or_else(|| AttrValue::from(tr!("Advanced")));
+
+        let onkeydown =
+            ctx.link()
+                .batch_callback(|event: KeyboardEvent| match event.key().as_str() {
+                    "Enter" | " " => {
+                        event.prevent_default();
+                        Some(Msg::Toggle)
+                    }
+                    _ => None,
+                });
+
+        let toggle = Row::new()
+            .class("pwt-advanced-options-toggle")
+            .class(crate::css::AlignItems::Center)
+            .gap(2)
+            .padding_y(1)
+            .class("pwt-pointer")
+            .attribute("role", "button")
+            .attribute("tabindex", "0")
+            .attribute("aria-expanded", self.expanded.to_string())
+            .attribute("aria-controls", self.body_id.clone())
+            .onclick(ctx.link().callback(|_| Msg::Toggle))
+            .onkeydown(onkeydown)
+            .with_child(
+                Fa::new(if self.expanded {
+                    "caret-down"
+                } else {
+                    "caret-right"
+                })
+                .fixed_width(),
+            )
+            .with_child(title);
+
+        let body = Collapse::new(self.expanded)
+            .id(self.body_id.clone())
+            .class("pwt-advanced-options-body")
+            .with_child(html! {
+                <ContextProvider<AdvancedFieldScope> context={self.field_scope.clone()}>
+                    {for props.children.iter().cloned()}
+                </ContextProvider<AdvancedFieldScope>>
+            });
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-advanced-options")
+            .class(self.expanded.then_some("expanded"))
+            .with_child(toggle)
+            .with_child(body)
+            .into()
+    }
+}
@@ -10,7 +10,7 @@ use super::{
     IntoValidateFn, ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt,
     ManagedFieldState, ValidateFn,
 };
-use crate::css::AlignItems;
+use crate::css::{AlignItems, Opacity};
 use crate::props::{ContainerBuilder, CssPaddingBuilder, EventSubscriber, IntoVTag, WidgetBuilder};
 use crate::tr;
 use crate::widget::{Container, Fa, FieldLabel, Row, Tooltip};
@@ -225,7 +225,7 @@ impl ManagedField for CheckboxField {
         let props = ctx.props();
         match msg {
             Msg::Toggle => {
-                if props.input_props.disabled {
+                if self.is_disabled(props) {
                     return true;
                 }
                 let checked = self.value == props.submit_on_value;
@@ -269,7 +269,7 @@ impl ManagedField for CheckboxField {
     fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
         let props = ctx.props();
         let link = ctx.link();
-        let disabled = props.input_props.disabled;
+        let disabled = self.is_disabled(props);
 
         let (value, validation_result) = (&self.value, &self.result);
         let checked = *value == props.submit_on_value;
@@ -319,7 +319,7 @@ impl ManagedField for CheckboxField {
         let box_label = props.box_label.clone().map(|label| {
             label
                 .padding_start(2)
-                .class(disabled.then_some("pwt-opacity-disabled"))
+                .class(disabled.then_some(Opacity::Disabled))
         });
 
         let checkbox = Row::new()
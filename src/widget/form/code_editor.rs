@@ -0,0 +1,594 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use anyhow::Error;
+use serde_json::Value;
+
+use wasm_bindgen::JsCast;
+use web_sys::HtmlTextAreaElement;
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::prelude::*;
+use yew::virtual_dom::{Listeners, VTag};
+
+use pwt_macros::{builder, widget};
+
+use super::{
+    IntoValidateFn, ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt,
+    ManagedFieldState, ValidateFn,
+};
+use crate::prelude::*;
+use crate::props::{IntoOptionalRenderFn, ListenersWrapper, RenderFn, WidgetStdProps};
+use crate::widget::Container;
+
+pub type PwtCodeEditor = ManagedFieldMaster<CodeEditorField>;
+
+/// Code editor for config file snippets and scripts.
+///
+/// This is a `<textarea>` with monospace font, optional line numbers and
+/// tab handling:
+///
+/// - `Tab` indents, `Shift+Tab` outdents the current line or all selected
+///   lines (see [tab_size](Self::tab_size) and [hard_tabs](Self::hard_tabs)).
+/// - Press `Escape` before `Tab` to move the focus out of the editor.
+///
+/// Lines are not wrapped. Use the standard
+/// [read_only](crate::props::FieldBuilder::read_only) property for a
+/// read-only view.
+///
+/// The [highlight](Self::highlight) hook can be used to integrate an
+/// external syntax highlighter. The returned markup is rendered behind a
+/// transparent textarea, so it must keep the text (and line breaks)
+/// unchanged.
+///
+/// The layout is defined by the theme (`pwt-code-editor-*` classes), which
+/// also provides the focus indicator for the textarea.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::CodeEditor;
+/// # fn create_editor() -> CodeEditor {
+/// CodeEditor::new()
+///     .name("user-data")
+///     .default("#cloud-config\n")
+///     .tab_size(2)
+///     .height(300)
+/// # }
+/// ```
+#[widget(pwt=crate, comp=ManagedFieldMaster<CodeEditorField>, @input, @element)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct CodeEditor {
+    /// Force value.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+
+    /// Force validation result.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    ///
+    /// This is only used if you also force a value, and overwrites
+    /// any result from the validation function (if any).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub valid: Option<Result<Value, String>>,
+
+    /// Default value.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub default: Option<AttrValue>,
+
+    /// Validation function.
+    #[prop_or_default]
+    pub validate: Option<ValidateFn<String>>,
+
+    /// Show line numbers (default true).
+    #[builder]
+    #[prop_or(true)]
+    pub line_numbers: bool,
+
+    /// Number of spaces inserted by the `Tab` key (default 4).
+    #[builder]
+    #[prop_or(4)]
+    pub tab_size: usize,
+
+    /// Insert a tab character instead of spaces.
+    #[builder]
+    #[prop_or_default]
+    pub hard_tabs: bool,
+
+    /// Number of visible text lines (textarea `rows` attribute).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub rows: Option<u32>,
+
+    /// Syntax highlighter hook.
+    ///
+    /// Gets the whole text and returns the highlighted markup.
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String)]
+    #[prop_or_default]
+    pub highlight: Option<RenderFn<String>>,
+
+    /// Change callback
+    ///
+    /// This callback is emited on any data change, i.e. if data
+    /// inside the [FormContext](super::FormContext) changed.
+    #[builder_cb(IntoEventCallback, into_event_callback, String)]
+    #[prop_or_default]
+    pub on_change: Option<Callback<String>>,
+
+    /// Input callback
+    ///
+    /// This callback is emited when the user types in new data.
+    #[builder_cb(IntoEventCallback, into_event_callback, String)]
+    #[prop_or_default]
+    pub on_input: Option<Callback<String>>,
+}
+
+impl Default for CodeEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeEditor {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    /// Builder style method to set the validate callback
+    pub fn validate(mut self, validate: impl IntoValidateFn<String>) -> Self {
+        self.set_validate(validate);
+        self
+    }
+
+    /// Method to set the validate callback
+    pub fn set_validate(&mut self, validate: impl IntoValidateFn<String>) {
+        self.validate = validate.into_validate_fn();
+    }
+}
+
+pub enum Msg {
+    Update(String),
+    // New text and selection (UTF-16 offsets) after indent/outdent.
+    Edit(String, u32, u32),
+}
+
+#[doc(hidden)]
+pub struct CodeEditorField {
+    state: ManagedFieldState,
+    textarea_ref: NodeRef,
+    gutter_ref: NodeRef,
+    highlight_ref: NodeRef,
+    // Set by the `Escape` key, so that the next `Tab` moves the focus.
+    escape_pressed: Rc<Cell<bool>>,
+    pending_selection: Option<(u32, u32)>,
+}
+
+crate::impl_deref_mut_property!(CodeEditorField, state, ManagedFieldState);
+
+// CodeEditor is type Value::String()
+fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_string(),
+        _ => String::new(),
+    }
+}
+
+// Convert a UTF-16 offset (used by the DOM selection API) into a byte offset.
+fn utf16_to_byte_offset(text: &str, offset: usize) -> usize {
+    let mut utf16_pos = 0;
+    for (byte_pos, c) in text.char_indices() {
+        if utf16_pos >= offset {
+            return byte_pos;
+        }
+        utf16_pos += c.len_utf16();
+    }
+    text.len()
+}
+
+fn byte_to_utf16_offset(text: &str, offset: usize) -> usize {
+    text[..offset].encode_utf16().count()
+}
+
+fn line_start(text: &str, pos: usize) -> usize {
+    text[..pos].rfind('\n').map(|p| p + 1).unwrap_or(0)
+}
+
+// Indent (or outdent) the text at the given selection (byte offsets).
+//
+// Without selection, indent inserts `unit` at the cursor position.
+// Otherwise all lines touched by the selection are indented. Returns
+// the new text and selection.
+fn indent_text(
+    text: &str,
+    start: usize,
+    end: usize,
+    unit: &str,
+    outdent: bool,
+) -> (String, usize, usize) {
+    if start == end && !outdent {
+        let mut new_text = String::with_capacity(text.len() + unit.len());
+        new_text.push_str(&text[..start]);
+        new_text.push_str(unit);
+        new_text.push_str(&text[start..]);
+        let pos = start + unit.len();
+        return (new_text, pos, pos);
+    }
+
+    let first_line = line_start(text, start);
+    // do not touch the line if the selection ends at its start
+    let last_pos = if end > start && text[..end].ends_with('\n') {
+        end - 1
+    } else {
+        end
+    };
+
+    let mut new_text = String::with_capacity(text.len());
+    new_text.push_str(&text[..first_line]);
+
+    let (mut new_start, mut new_end) = (start, end);
+    let mut line_pos = first_line;
+    for line in text[first_line..].split_inclusive('\n') {
+        if line_pos > last_pos {
+            new_text.push_str(&text[line_pos..]);
+            break;
+        }
+        if outdent {
+            let remove = if line.starts_with('\t') {
+                1
+            } else {
+                line.bytes()
+                    .take(unit.len().max(1))
+                    .take_while(|b| *b == b' ')
+                    .count()
+            };
+            new_text.push_str(&line[remove..]);
+            if start > line_pos {
+                new_start -= remove.min(start - line_pos);
+            }
+            new_end -= remove.min(end.saturating_sub(line_pos));
+        } else {
+            new_text.push_str(unit);
+            new_text.push_str(line);
+            if start > line_pos || (line_pos == first_line && start != end) {
+                new_start += unit.len();
+            }
+            new_end += unit.len();
+        }
+        line_pos += line.len();
+    }
+
+    (new_text, new_start, new_end)
+}
+
+#[derive(PartialEq)]
+pub struct ValidateClosure {
+    required: bool,
+    validate: Option<ValidateFn<String>>,
+}
+
+impl CodeEditorField {
+    fn onkeydown(&self, ctx: &ManagedFieldContext<Self>) -> Callback<KeyboardEvent> {
+        let props = ctx.props();
+        let escape_pressed = Rc::clone(&self.escape_pressed);
+        let trap_tab = !(props.input_props.read_only || self.is_disabled(props));
+        let unit = if props.hard_tabs {
+            String::from("\t")
+        } else {
+            " ".repeat(props.tab_size)
+        };
+        let link = ctx.link().clone();
+
+        Callback::from(move |event: KeyboardEvent| {
+            let key = event.key();
+            if key == "Escape" {
+                escape_pressed.set(true);
+                return;
+            }
+            let move_focus = escape_pressed.replace(false);
+
+            if key != "Tab" || move_focus || !trap_tab {
+                return;
+            }
+            if event.ctrl_key() || event.alt_key() || event.meta_key() {
+                return;
+            }
+            let Some(textarea) = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlTextAreaElement>().ok())
+            else {
+                return;
+            };
+            event.prevent_default();
+
+            let text = textarea.value();
+            let start = textarea.selection_start().ok().flatten().unwrap_or(0) as usize;
+            let end = textarea.selection_end().ok().flatten().unwrap_or(0) as usize;
+            let start = utf16_to_byte_offset(&text, start);
+            let end = utf16_to_byte_offset(&text, end);
+
+            let (text, start, end) = indent_text(&text, start, end, &unit, event.shift_key());
+
+            let start = byte_to_utf16_offset(&text, start) as u32;
+            let end = byte_to_utf16_offset(&text, end) as u32;
+            link.send_message(Msg::Edit(text, start, end));
+        })
+    }
+
+    // Keep line numbers and highlighted text in sync with the textarea.
+    fn onscroll(&self) -> Callback<Event> {
+        let gutter_ref = self.gutter_ref.clone();
+        let highlight_ref = self.highlight_ref.clone();
+        Callback::from(move |event: Event| {
+            let textarea: HtmlTextAreaElement = event.target_unchecked_into();
+            if let Some(gutter) = gutter_ref.cast::<web_sys::Element>() {
+                gutter.set_scroll_top(textarea.scroll_top());
+            }
+            if let Some(highlight) = highlight_ref.cast::<web_sys::Element>() {
+                highlight.set_scroll_top(textarea.scroll_top());
+                highlight.set_scroll_left(textarea.scroll_left());
+            }
+        })
+    }
+}
+
+impl ManagedField for CodeEditorField {
+    type Properties = CodeEditor;
+    type Message = Msg;
+    type ValidateClosure = ValidateClosure;
+
+    fn validation_args(props: &Self::Properties) -> Self::ValidateClosure {
+        ValidateClosure {
+            required: props.input_props.required,
+            validate: props.validate.clone(),
+        }
+    }
+
+    fn validator(props: &Self::ValidateClosure, value: &Value) -> Result<Value, Error> {
+        let value = match value {
+            Value::Null => String::new(),
+            Value::String(v) => v.clone(),
+            _ => {
+                // should not happen
+                log::error!("CodeEditor: got wrong data type in validate!");
+                String::new()
+            }
+        };
+
+        if value.is_empty() {
+            if props.required {
+                return Err(Error::msg(tr!("Field may not be empty.")));
+            } else {
+                return Ok(Value::String(String::new()));
+            }
+        }
+
+        if let Some(validate) = &props.validate {
+            validate.apply(&value)?;
+        }
+
+        Ok(Value::String(value))
+    }
+
+    fn create(ctx: &ManagedFieldContext<Self>) -> Self {
+        let props = ctx.props();
+
+        let value = props
+            .value
+            .as_ref()
+            .or(props.default.as_ref())
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+
+        let default: Value = props.default.as_deref().unwrap_or("").into();
+
+        Self {
+            state: ManagedFieldState::new(value.into(), default),
+            textarea_ref: NodeRef::default(),
+            gutter_ref: NodeRef::default(),
+            highlight_ref: NodeRef::default(),
+            escape_pressed: Rc::new(Cell::new(false)),
+            pending_selection: None,
+        }
+    }
+
+    fn value_changed(&mut self, ctx: &ManagedFieldContext<Self>) {
+        let props = ctx.props();
+        if let Some(on_change) = &props.on_change {
+            on_change.emit(value_to_text(&self.value));
+        }
+    }
+
+    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value || props.valid != old_props.valid {
+            ctx.link().force_value(
+                props.value.as_ref().map(|v| v.to_string()),
+                props.valid.clone(),
+            );
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &ManagedFieldContext<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        let input = match msg {
+            Msg::Update(input) => input,
+            Msg::Edit(input, start, end) => {
+                self.pending_selection = Some((start, end));
+                input
+            }
+        };
+        ctx.link().update_value(input.clone());
+        if let Some(on_input) = &props.on_input {
+            on_input.emit(input);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+
+        let value = value_to_text(&self.value);
+        let disabled = self.is_disabled(props);
+
+        let gutter = props.line_numbers.then(|| {
+            let line_count = value.split('\n').count();
+            let numbers: Vec<String> = (1..=line_count).map(|n| n.to_string()).collect();
+            Container::new()
+                .class("pwt-code-editor-gutter")
+                .attribute("aria-hidden", "true")
+                .padding_x(1)
+                .with_child(numbers.join("\n"))
+                .into_html_with_ref(self.gutter_ref.clone())
+        });
+
+        let highlight = props.highlight.as_ref().map(|highlight| {
+            Container::from_tag("pre")
+                .class("pwt-code-editor-highlight")
+                .attribute("aria-hidden", "true")
+                // the trailing newline needs some content to get rendered
+                .with_child(highlight.apply(&value))
+                .with_child("\n")
+                .into_html_with_ref(self.highlight_ref.clone())
+        });
+
+        // Build the textarea from a copy of our properties (like TextArea),
+        // but without the outer element properties and listeners.
+        let mut textarea = props.clone();
+        textarea.std_props = WidgetStdProps::default();
+        textarea.listeners = ListenersWrapper::default();
+        let mut textarea = textarea
+            .class("pwt-code-editor-input")
+            .attribute("spellcheck", "false")
+            .attribute("autocapitalize", "off")
+            .attribute("wrap", "off")
+            .attribute("rows", props.rows.map(|rows| rows.to_string()))
+            .style("tab-size", props.tab_size.to_string())
+            .onkeydown(self.onkeydown(ctx))
+            .onscroll(self.onscroll());
+
+        if !disabled {
+            textarea.add_oninput(ctx.link().callback(|event: InputEvent| {
+                let input: HtmlTextAreaElement = event.target_unchecked_into();
+                Msg::Update(input.value())
+            }));
+        }
+        textarea.input_props.disabled = disabled;
+
+        let mut attributes = textarea.std_props.cumulate_attributes(None::<&str>);
+        let attr_map = attributes.get_mut_index_map();
+        textarea.input_props.cumulate_attributes(attr_map);
+
+        let listeners = Listeners::Pending(textarea.listeners.listeners.into_boxed_slice());
+
+        let textarea = VTag::__new_textarea(
+            Some(value.clone().into()),
+            self.textarea_ref.clone(),
+            None,
+            attributes,
+            listeners,
+        );
+
+        let body = Container::new()
+            .class("pwt-code-editor-body")
+            .class("pwt-flex-fill")
+            .with_optional_child(highlight)
+            .with_child(textarea);
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-code-editor")
+            .class(crate::css::Display::Flex)
+            .class(if self.result.is_ok() {
+                "is-valid"
+            } else {
+                "is-invalid"
+            })
+            .class(disabled.then_some("disabled"))
+            .class(
+                props
+                    .highlight
+                    .is_some()
+                    .then_some("pwt-code-editor-highlighted"),
+            )
+            .with_optional_child(gutter)
+            .with_child(body)
+            .into()
+    }
+
+    fn rendered(&mut self, ctx: &ManagedFieldContext<Self>, first_render: bool) {
+        if first_render && ctx.props().input_props.autofocus {
+            if let Some(el) = self.textarea_ref.cast::<web_sys::HtmlElement>() {
+                let _ = el.focus();
+            }
+        }
+        if let Some((start, end)) = self.pending_selection.take() {
+            if let Some(el) = self.textarea_ref.cast::<HtmlTextAreaElement>() {
+                let _ = el.set_selection_range(start, end);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_indent_cursor() {
+        assert_eq!(
+            indent_text("ab\ncd", 1, 1, "  ", false),
+            ("a  b\ncd".into(), 3, 3)
+        );
+        // outdent without selection affects the current line
+        assert_eq!(
+            indent_text("a\n    bc", 7, 7, "  ", true),
+            ("a\n  bc".into(), 5, 5)
+        );
+        assert_eq!(indent_text("\tx", 2, 2, "  ", true), ("x".into(), 1, 1));
+    }
+
+    #[test]
+    fn test_indent_selection() {
+        let text = "a\nb\nc\n";
+        // select "b\nc"
+        assert_eq!(
+            indent_text(text, 2, 5, "\t", false),
+            ("a\n\tb\n\tc\n".into(), 3, 7)
+        );
+        // selection ends at the start of a line - that line is not indented
+        assert_eq!(
+            indent_text(text, 0, 4, "  ", false),
+            ("  a\n  b\nc\n".into(), 2, 8)
+        );
+        assert_eq!(
+            indent_text("  a\n  b\nc\n", 2, 8, "  ", true),
+            (text.into(), 0, 4)
+        );
+        // lines with less indentation
+        assert_eq!(
+            indent_text(" a\n    b", 0, 8, "  ", true),
+            ("a\n  b".into(), 0, 5)
+        );
+    }
+
+    #[test]
+    fn test_utf16_offsets() {
+        let text = "ä€😀x";
+        assert_eq!(utf16_to_byte_offset(text, 0), 0);
+        assert_eq!(utf16_to_byte_offset(text, 1), 2);
+        assert_eq!(utf16_to_byte_offset(text, 2), 5);
+        assert_eq!(utf16_to_byte_offset(text, 4), 9);
+        assert_eq!(utf16_to_byte_offset(text, 10), text.len());
+        assert_eq!(byte_to_utf16_offset(text, 9), 4);
+        assert_eq!(byte_to_utf16_offset(text, 5), 2);
+    }
+}
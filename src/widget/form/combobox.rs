@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::rc::Rc;
 
 use anyhow::format_err;
@@ -10,13 +11,19 @@ use yew::virtual_dom::Key;
 // #[cfg(feature = "proxmox-schema")]
 // use proxmox_schema::Schema;
 
+use crate::css::AlignItems;
 use crate::prelude::*;
-use crate::props::{IntoOptionalRenderFn, IntoTextFilterFn, RenderFn, TextFilterFn};
-use crate::state::Store;
+use crate::props::{
+    IntoOptionalRenderFn, IntoStorageLocation, IntoTextFilterFn, RenderFn, StorageLocation,
+    TextFilterFn,
+};
+use crate::state::{PersistentState, Store};
 use crate::widget::data_table::{DataTable, DataTableColumn, DataTableHeader};
-use crate::widget::{GridPicker, Trigger};
+use crate::widget::{Button, Column, Fa, GridPicker, Row, Trigger};
 
-use super::{IntoValidateFn, Selector, SelectorRenderArgs, ValidateFn};
+use super::{
+    FormContext, FormContextObserver, IntoValidateFn, Selector, SelectorRenderArgs, ValidateFn,
+};
 
 use pwt_macros::{builder, widget};
 
@@ -24,14 +31,48 @@ use pwt_macros::{builder, widget};
 ///
 /// Allows to select text options.
 ///
-/// # Note: No multiselect
+/// # Multiselect
 ///
-/// Comboboxes with multi-select suffers from severe usability problems, because
-/// there is not enough room to display the selected values when the combobox
-/// is collapsed. Therefore, we have not implemented the multiselect mode.
+/// Set [multiple](Self::multiple) to select several values. Selected
+/// values are shown as removable chips inside the field, and the picker
+/// stays open while selecting. The value is stored as array in the
+/// [FormContext].
 ///
-/// Please consider using a flat grid instead. If there are few choices,
-/// a segmented button may also be an option.
+/// Multi-select comboboxes get crowded with many selected values, because
+/// there is not enough room to display them all. Please consider using a
+/// flat grid instead in that case. If there are few choices, a segmented
+/// button may also be an option.
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::Combobox;
+/// # fn create_field() -> Combobox {
+/// Combobox::new()
+///     .name("tags")
+///     .multiple(true)
+///     .items(Rc::new(vec!["red".into(), "green".into(), "blue".into()]))
+/// # }
+/// ```
+///
+/// # Recent values
+///
+/// Set [recent_state_id](Self::recent_state_id) to remember the last
+/// submitted values (see [SubmitButton](super::SubmitButton)). They are
+/// listed first in the picker (marked with a history icon), and the picker
+/// shows a button to clear the history. This requires a field name, and
+/// only stores the value when the surrounding form gets submitted.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::Combobox;
+/// # fn create_field() -> Combobox {
+/// Combobox::new()
+///     .name("host")
+///     .editable(true)
+///     .recent_state_id("my-dialog-recent-hosts")
+/// # }
+/// ```
 #[widget(pwt=crate, comp=PwtCombobox, @input)]
 #[derive(Clone, PartialEq, Properties)]
 #[builder]
@@ -104,6 +145,38 @@ pub struct Combobox {
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or_default]
     pub autoselect_filter: Option<bool>,
+
+    /// Group options using the returned group name.
+    ///
+    /// Items should be sorted by group. See [DataTable::group_extract].
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, AttrValue, AttrValue)]
+    #[prop_or_default]
+    pub group_extract: Option<RenderFn<AttrValue, AttrValue>>,
+
+    /// Text displayed in the picker if there are no items.
+    ///
+    /// See [Selector::empty_text].
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub empty_text: Option<AttrValue>,
+
+    /// Remember the last submitted values using this storage location.
+    #[prop_or_default]
+    pub recent_state_id: Option<StorageLocation>,
+
+    /// The number of remembered values (default 5).
+    #[prop_or(5)]
+    #[builder]
+    pub recent_limit: usize,
+
+    /// Allow to select multiple values.
+    ///
+    /// The `value` and `default` properties, and the value passed to
+    /// [on_change](Self::on_change) use comma separated lists. Recent values
+    /// are not stored in this mode.
+    #[prop_or_default]
+    #[builder]
+    pub multiple: bool,
 }
 
 impl Default for Combobox {
@@ -203,10 +276,42 @@ impl Combobox {
     pub fn add_trigger(&mut self, trigger: impl Into<Trigger>, right: bool) {
         self.trigger.push((trigger.into(), right));
     }
+
+    /// Builder style method to set the storage location for recent values.
+    pub fn recent_state_id(mut self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_recent_state_id(state_id);
+        self
+    }
+
+    /// Method to set the storage location for recent values.
+    pub fn set_recent_state_id(&mut self, state_id: impl IntoStorageLocation) {
+        self.recent_state_id = state_id.into_storage_location();
+    }
+}
+
+fn load_recent_values(state_id: &Option<StorageLocation>) -> Rc<Vec<AttrValue>> {
+    let values = match state_id {
+        Some(state_id) => PersistentState::<Vec<String>>::new(state_id.clone()).into_inner(),
+        None => Vec::new(),
+    };
+    Rc::new(values.into_iter().map(AttrValue::from).collect())
+}
+
+// Move the value to the front of the list (and drop old entries).
+fn remember_recent_value(state_id: &StorageLocation, value: String, limit: usize) {
+    let mut state = PersistentState::<Vec<String>>::new(state_id.clone());
+    let mut values = (*state).clone();
+    values.retain(|v| *v != value);
+    values.insert(0, value);
+    values.truncate(limit);
+    state.update(values);
 }
 
 pub enum Msg {
     Reposition,
+    FormCtxUpdate(FormContext),
+    RecentChange,
+    ClearRecent,
 }
 
 #[doc(hidden)]
@@ -214,9 +319,106 @@ pub struct PwtCombobox {
     store: Store<AttrValue>,
     columns: Rc<Vec<DataTableHeader<AttrValue>>>,
     validate: Option<ValidateFn<(String, Store<AttrValue>)>>,
+    recent: Rc<Vec<AttrValue>>,
+    form_ctx: Option<FormContext>,
+    _form_ctx_handle: Option<ContextHandle<FormContext>>,
+    _submit_observer: Option<FormContextObserver>,
 }
 
 impl PwtCombobox {
+    // Store the field value when the form gets submitted.
+    fn observe_submit(&mut self, ctx: &Context<Self>) {
+        let props = ctx.props();
+        self._submit_observer = None;
+        if props.multiple {
+            return;
+        }
+        let (Some(form_ctx), Some(state_id), Some(name)) = (
+            &self.form_ctx,
+            &props.recent_state_id,
+            &props.input_props.name,
+        ) else {
+            return;
+        };
+
+        let state_id = state_id.clone();
+        let name = name.clone();
+        let limit = props.recent_limit;
+        let last_submit_count = Cell::new(form_ctx.read().submit_count());
+        let link = ctx.link().clone();
+
+        self._submit_observer = Some(form_ctx.add_listener(move |form_ctx: FormContext| {
+            let submit_count = form_ctx.read().submit_count();
+            if last_submit_count.replace(submit_count) == submit_count {
+                return;
+            }
+            let value = form_ctx.read().get_field_text(name.clone());
+            if !value.is_empty() {
+                remember_recent_value(&state_id, value, limit);
+                link.send_message(Msg::RecentChange);
+            }
+        }));
+    }
+
+    // Recent values are listed first.
+    fn update_store(&self, props: &Combobox) {
+        let recent: Vec<AttrValue> = self
+            .recent
+            .iter()
+            .filter(|value| !props.force_selection || props.items.contains(value))
+            .cloned()
+            .collect();
+
+        if recent.is_empty() && props.items.is_empty() {
+            self.store.clear();
+            return;
+        }
+
+        let mut data = recent.clone();
+        data.extend(
+            props
+                .items
+                .iter()
+                .filter(|item| !recent.contains(item))
+                .cloned(),
+        );
+        self.store.set_data(data);
+    }
+
+    fn create_columns(
+        props: &Combobox,
+        recent: &Rc<Vec<AttrValue>>,
+    ) -> Rc<Vec<DataTableHeader<AttrValue>>> {
+        let render_value = props
+            .render_value
+            .clone()
+            .unwrap_or_else(|| RenderFn::new(|value: &AttrValue| html! {value}));
+
+        let render_value = if recent.is_empty() {
+            render_value
+        } else {
+            let recent = Rc::clone(recent);
+            RenderFn::new(move |value: &AttrValue| {
+                if recent.contains(value) {
+                    Row::new()
+                        .gap(2)
+                        .class(AlignItems::Baseline)
+                        .with_child(Fa::new("history").fixed_width())
+                        .with_child(render_value.apply(value))
+                        .into()
+                } else {
+                    render_value.apply(value)
+                }
+            })
+        };
+
+        Rc::new(vec![
+            DataTableColumn::new("Value")
+                .show_menu(false)
+                .render(render_value)
+                .into(),
+        ])
+    }
     fn create_validate(props: &Combobox) -> Option<ValidateFn<(String, Store<AttrValue>)>> {
         if props.force_selection {
             let validate = props.validate.clone();
@@ -253,55 +455,87 @@ impl Component for PwtCombobox {
         let props = ctx.props();
 
         let store = Store::with_extract_key(|item: &AttrValue| Key::from(item.as_str()));
-        if !props.items.is_empty() {
-            store.set_data(props.items.as_ref().clone());
-        }
-
-        let render_value = props
-            .render_value
-            .clone()
-            .unwrap_or_else(|| RenderFn::new(|value: &AttrValue| html! {value}));
-        let columns = Rc::new(vec![
-            DataTableColumn::new("Value")
-                .show_menu(false)
-                .render(render_value)
-                .into(),
-        ]);
+        let recent = load_recent_values(&props.recent_state_id);
+        let columns = Self::create_columns(props, &recent);
+
+        let (form_ctx, _form_ctx_handle) = match props.recent_state_id {
+            Some(_) => ctx
+                .link()
+                .context::<FormContext>(ctx.link().callback(Msg::FormCtxUpdate))
+                .unzip(),
+            None => (None, None),
+        };
 
-        Self {
+        let mut me = Self {
             store,
             columns,
             validate: Self::create_validate(props),
-        }
+            recent,
+            form_ctx,
+            _form_ctx_handle,
+            _submit_observer: None,
+        };
+        me.update_store(props);
+        me.observe_submit(ctx);
+        me
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
         match msg {
             Msg::Reposition => true, // just trigger a redraw
+            Msg::FormCtxUpdate(form_ctx) => {
+                self.form_ctx = Some(form_ctx);
+                self.observe_submit(ctx);
+                false
+            }
+            Msg::RecentChange => {
+                self.recent = load_recent_values(&props.recent_state_id);
+                self.columns = Self::create_columns(props, &self.recent);
+                self.update_store(props);
+                true
+            }
+            Msg::ClearRecent => {
+                if let Some(state_id) = &props.recent_state_id {
+                    PersistentState::<Vec<String>>::new(state_id.clone()).update(Vec::new());
+                }
+                self.recent = Rc::new(Vec::new());
+                self.columns = Self::create_columns(props, &self.recent);
+                self.update_store(props);
+                true
+            }
         }
     }
 
     fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
         let props = ctx.props();
-        if props.items != old_props.items {
-            if !props.items.is_empty() {
-                self.store.set_data(props.items.as_ref().clone());
-            } else {
-                self.store.clear();
+        if props.recent_state_id != old_props.recent_state_id {
+            self.recent = load_recent_values(&props.recent_state_id);
+            if self.form_ctx.is_none() && props.recent_state_id.is_some() {
+                let (form_ctx, handle) = ctx
+                    .link()
+                    .context::<FormContext>(ctx.link().callback(Msg::FormCtxUpdate))
+                    .unzip();
+                self.form_ctx = form_ctx;
+                self._form_ctx_handle = handle;
             }
         }
+        if props.recent_state_id != old_props.recent_state_id
+            || props.input_props.name != old_props.input_props.name
+            || props.recent_limit != old_props.recent_limit
+            || props.multiple != old_props.multiple
+        {
+            self.observe_submit(ctx);
+        }
+        if props.items != old_props.items
+            || props.recent_state_id != old_props.recent_state_id
+            || props.force_selection != old_props.force_selection
+        {
+            self.update_store(props);
+        }
         // Rebuild the column so options whose labels load asynchronously render their names; else
         // the create()-time render_value sticks and shows raw keys.
-        let render_value = props
-            .render_value
-            .clone()
-            .unwrap_or_else(|| RenderFn::new(|value: &AttrValue| html! {value}));
-        self.columns = Rc::new(vec![
-            DataTableColumn::new("Value")
-                .show_menu(false)
-                .render(render_value)
-                .into(),
-        ]);
+        self.columns = Self::create_columns(props, &self.recent);
         self.validate = Self::create_validate(props);
         true
     }
@@ -317,6 +551,22 @@ impl Component for PwtCombobox {
         let auto_select_filter = ctx.props().autoselect_filter;
 
         let filter = props.filter.clone();
+        let group_extract = match (&props.group_extract, self.recent.is_empty()) {
+            (Some(group_extract), false) => {
+                // keep recent values in their own group
+                let group_extract = group_extract.clone();
+                let recent = Rc::clone(&self.recent);
+                Some(RenderFn::new(move |value: &AttrValue| {
+                    if recent.contains(value) {
+                        AttrValue::from(tr!("Recent"))
+                    } else {
+                        group_extract.apply(value)
+                    }
+                }))
+            }
+            (group_extract, _) => group_extract.clone(),
+        };
+        let show_clear_recent = !self.recent.is_empty();
 
         let columns = Rc::clone(&self.columns);
         let picker = move |args: &SelectorRenderArgs<Store<AttrValue>>| {
@@ -326,7 +576,8 @@ impl Component for PwtCombobox {
                 .striped(false)
                 .borderless(true)
                 .bordered(false)
-                .show_header(false);
+                .show_header(false)
+                .group_extract(group_extract.clone());
 
             let mut picker = GridPicker::new(table)
                 .selection(args.selection.clone())
@@ -345,7 +596,26 @@ impl Component for PwtCombobox {
                 });
             }
 
-            picker.into()
+            if !show_clear_recent {
+                return picker.into();
+            }
+
+            Column::new()
+                .class("pwt-flex-fill pwt-overflow-auto")
+                .with_child(picker)
+                .with_child(
+                    Row::new()
+                        .padding(1)
+                        .border_top(true)
+                        .with_flex_spacer()
+                        .with_child(
+                            Button::new(tr!("Clear History"))
+                                .class("pwt-button-text")
+                                .icon_class("fa fa-history")
+                                .on_activate(link.callback(|_| Msg::ClearRecent)),
+                        ),
+                )
+                .into()
         };
 
         Selector::new(self.store.clone(), picker)
@@ -357,6 +627,8 @@ impl Component for PwtCombobox {
             .validate(self.validate.clone())
             .render_value(props.render_value.clone())
             .trigger(props.trigger.clone())
+            .empty_text(props.empty_text.clone())
+            .multiple(props.multiple)
             .on_change({
                 let on_change = props.on_change.clone();
                 move |key: Key| {
@@ -14,14 +14,15 @@ use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
 
 use crate::state::optional_rc_ptr_eq;
+use crate::tr;
 
-use super::SubmitValidateFn;
+use super::{FieldRule, FieldRuleState, SubmitValidateFn};
 
 /// Basic field options used inside [FormContext].
 ///
 /// This basically mirrors the (non-display) options from
 /// [FieldStdProps](crate::props::FieldStdProps).
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct FieldOptions {
     /// Include the field data in the submit request.
     pub submit: bool,
@@ -32,6 +33,25 @@ pub struct FieldOptions {
     pub required: bool,
     /// Field disabled flag.
     pub disabled: bool,
+    /// Explicit focus order (see [FormContext::focus_first_field]).
+    pub tab_order: Option<i32>,
+}
+
+/// Information about a registered field (see [FormContextState::registered_fields]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldInfo {
+    /// Field name.
+    pub name: AttrValue,
+    /// Field options.
+    pub options: FieldOptions,
+    /// Field value.
+    pub value: Value,
+    /// Validation result (contains the submit value).
+    pub result: Result<Value, String>,
+    /// Set if the field value differs from the default.
+    pub dirty: bool,
+    /// Radio group flag.
+    pub radio_group: bool,
 }
 
 #[derive(Debug, PartialEq)]
@@ -56,11 +76,19 @@ struct FieldRegistration {
     pub default: Value,
     /// Validation result (contains the submit value)
     pub result: Result<Value, String>,
+    /// Callback to focus the field (see [FieldHandle::set_focus_callback]).
+    pub focus: Option<Callback<()>>,
+    /// State computed from the [FieldRule]s.
+    pub rule_state: FieldRuleState,
 }
 
 impl FieldRegistration {
+    fn is_disabled(&self) -> bool {
+        self.options.disabled || self.rule_state.disabled
+    }
+
     fn is_dirty(&self) -> bool {
-        if self.options.disabled {
+        if self.is_disabled() {
             return false;
         }
         // we need to compare the value that will be submitted
@@ -70,12 +98,19 @@ impl FieldRegistration {
         }
     }
 
-    fn apply_value(&mut self, value: Value) {
-        let result = if let Some(validate) = &self.validate {
-            validate.apply(&value).map_err(|e| e.to_string())
+    fn validate_value(&self, value: &Value) -> Result<Value, String> {
+        if self.rule_state.required && value_is_empty(value) {
+            return Err(tr!("Field may not be empty."));
+        }
+        if let Some(validate) = &self.validate {
+            validate.apply(value).map_err(|e| e.to_string())
         } else {
             Ok(value.clone())
-        };
+        }
+    }
+
+    fn apply_value(&mut self, value: Value) {
+        let result = self.validate_value(&value);
         self.value = value;
         if let Ok(submit_value) = &result {
             self.last_valid = Some(submit_value.clone());
@@ -200,6 +235,27 @@ impl FieldHandle {
         let key = self.key;
         self.write().update_field_options_by_slab_key(key, options);
     }
+
+    /// Set the callback used to focus the field.
+    ///
+    /// This does not notify the form listeners.
+    pub fn set_focus_callback(&mut self, cb: impl IntoEventCallback<()>) {
+        if let Some(field) = self.form_ctx.inner.borrow_mut().fields.get_mut(self.key) {
+            field.focus = cb.into_event_callback();
+        }
+    }
+
+    /// Focus the field.
+    pub fn focus(&self) {
+        let focus = self
+            .read()
+            .fields
+            .get(self.key)
+            .and_then(|f| f.focus.clone());
+        if let Some(focus) = focus {
+            focus.emit(());
+        }
+    }
 }
 
 impl Drop for FieldHandle {
@@ -306,6 +362,7 @@ impl FormContext {
             options,
             unique,
         );
+        self.inner.borrow_mut().apply_field_rules();
 
         self.notify_listeners();
 
@@ -315,6 +372,86 @@ impl FormContext {
         }
     }
 
+    /// Focus the field with the specified name.
+    ///
+    /// Returns false if there is no such field, or if the field does not
+    /// support focusing.
+    pub fn focus_field(&self, name: impl IntoPropValue<AttrValue>) -> bool {
+        let name = name.into_prop_value();
+        let focus = {
+            let state = self.inner.borrow();
+            state
+                .find_field_slab_id(&name)
+                .and_then(|key| state.fields[key].focus.clone())
+        };
+        match focus {
+            Some(focus) => {
+                focus.emit(());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Focus the first focusable (not disabled) field.
+    ///
+    /// Fields with a positive [tab_order](crate::props::FieldBuilder::tab_order)
+    /// come first (ordered by that value), followed by all other fields
+    /// in registration order. Returns false if there is no such field.
+    pub fn focus_first_field(&self) -> bool {
+        let focus = {
+            let state = self.inner.borrow();
+            state
+                .fields
+                .iter()
+                .filter(|(_, field)| !field.is_disabled() && field.focus.is_some())
+                .min_by_key(|(key, field)| {
+                    let order = field.options.tab_order.filter(|o| *o > 0);
+                    (order.unwrap_or(i32::MAX), *key)
+                })
+                .and_then(|(_, field)| field.focus.clone())
+        };
+        match focus {
+            Some(focus) => {
+                focus.emit(());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Add a listener which gets notified when the value or validation
+    /// result of field `name` changes.
+    ///
+    /// This is a filtered version of [Self::add_listener].
+    pub fn add_field_listener(
+        &self,
+        name: impl IntoPropValue<AttrValue>,
+        cb: impl Into<Callback<FormContext>>,
+    ) -> FormContextObserver {
+        let name = name.into_prop_value();
+        let cb = cb.into();
+        let last = RefCell::new(self.read().get_field_data(&name).map(|(v, r, _)| (v, r)));
+        self.add_listener(move |form_ctx: FormContext| {
+            let data = form_ctx
+                .read()
+                .get_field_data(&name)
+                .map(|(v, r, _)| (v, r));
+            if *last.borrow() != data {
+                *last.borrow_mut() = data;
+                cb.emit(form_ctx);
+            }
+        })
+    }
+
+    /// Add a field dependency rule (see [FieldRule]).
+    ///
+    /// Rules are evaluated whenever the form data changes, so fields get
+    /// enabled/disabled or required automatically.
+    pub fn add_field_rule(&self, rule: FieldRule) {
+        self.write().add_field_rule(rule);
+    }
+
     /// Returns the show_advanced flag
     pub fn get_show_advanced(&self) -> bool {
         self.inner.borrow().show_advanced
@@ -383,6 +520,9 @@ impl DerefMut for FormContextWriteGuard<'_> {
 impl Drop for FormContextWriteGuard<'_> {
     fn drop(&mut self) {
         let changed = self.state.version != self.initial_version;
+        if changed {
+            self.state.apply_field_rules();
+        }
         unsafe {
             ManuallyDrop::drop(&mut self.state);
         } // drop ref before calling notify listeners
@@ -424,6 +564,9 @@ pub struct FormContextState {
     groups: HashMap<AttrValue, GroupState>,
     show_advanced: bool,
     has_advanced: bool,
+    submit_count: usize,
+    rules: Vec<FieldRule>,
+    rules_state: HashMap<AttrValue, FieldRuleState>,
 }
 
 impl FormContextState {
@@ -435,6 +578,9 @@ impl FormContextState {
             groups: HashMap::new(),
             show_advanced: false,
             has_advanced: false,
+            submit_count: 0,
+            rules: Vec::new(),
+            rules_state: HashMap::new(),
         }
     }
 
@@ -475,6 +621,8 @@ impl FormContextState {
             last_valid: None,
             default: default.clone(),
             result: Ok(default.clone()), // set by apply_value below
+            focus: None,
+            rule_state: self.rules_state.get(&name).copied().unwrap_or_default(),
         };
 
         if !radio_group {
@@ -554,6 +702,83 @@ impl FormContextState {
         }
     }
 
+    /// Record that the form data was submitted.
+    ///
+    /// This is called by the [SubmitButton](super::SubmitButton), so that
+    /// fields can react on submits (i.e. to remember the submitted value).
+    pub fn mark_submitted(&mut self) {
+        self.submit_count += 1;
+        self.version += 1;
+    }
+
+    /// Returns how often the form data was submitted.
+    pub fn submit_count(&self) -> usize {
+        self.submit_count
+    }
+
+    /// Add a field dependency rule.
+    ///
+    /// Rules are evaluated whenever the form data changes.
+    pub fn add_field_rule(&mut self, rule: FieldRule) {
+        self.rules.push(rule);
+        self.version += 1;
+    }
+
+    /// Remove a field dependency rule.
+    pub fn remove_field_rule(&mut self, rule: &FieldRule) {
+        let len = self.rules.len();
+        self.rules.retain(|r| r != rule);
+        if self.rules.len() != len {
+            self.version += 1;
+        }
+    }
+
+    /// Returns the field state computed from the [FieldRule]s.
+    pub fn get_field_rule_state(&self, name: impl IntoPropValue<AttrValue>) -> FieldRuleState {
+        let name = name.into_prop_value();
+        self.rules_state.get(&name).copied().unwrap_or_default()
+    }
+
+    // Evaluate all rules and update the field state.
+    //
+    // Conditions may depend on fields controlled by other rules, so we
+    // repeat until nothing changes (limited to avoid endless loops).
+    fn apply_field_rules(&mut self) {
+        if self.rules.is_empty() {
+            return;
+        }
+        for _ in 0..=self.rules.len() {
+            let mut new_state: HashMap<AttrValue, FieldRuleState> = HashMap::new();
+            for rule in self.rules.iter() {
+                let state = new_state.entry(rule.name.clone()).or_default();
+                if let Some(enabled) = &rule.enabled {
+                    state.disabled |= !enabled.apply(self);
+                }
+                if let Some(required) = &rule.required {
+                    state.required |= required.apply(self);
+                }
+            }
+
+            if new_state == self.rules_state {
+                return;
+            }
+
+            let keys: Vec<usize> = self.fields.iter().map(|(key, _)| key).collect();
+            for key in keys {
+                let field = &mut self.fields[key];
+                let state = new_state.get(&field.name).copied().unwrap_or_default();
+                if field.rule_state != state {
+                    field.rule_state = state;
+                    self.validate_field_by_slab_key(key);
+                }
+            }
+
+            self.rules_state = new_state;
+            self.version += 1;
+        }
+        log::error!("FormContext: field rules do not converge");
+    }
+
     fn find_field_slab_id(&self, name: &AttrValue) -> Option<usize> {
         self.fields
             .iter()
@@ -654,6 +879,69 @@ impl FormContextState {
         }
     }
 
+    /// Returns information about all registered fields, in registration order.
+    ///
+    /// Fields registered multiple times (radio groups, unique fields) are
+    /// only listed once.
+    pub fn registered_fields(&self) -> Vec<FieldInfo> {
+        let mut list: Vec<FieldInfo> = Vec::new();
+        for (key, field) in self.fields.iter() {
+            if list.iter().any(|info| info.name == field.name) {
+                continue;
+            }
+            let (value, result, _) = self.get_field_data_by_slab_key(key);
+            let dirty = match self.groups.get(&field.name) {
+                Some(group) if field.radio_group => group.default != group.value,
+                _ => field.is_dirty(),
+            };
+            let mut options = field.options.clone();
+            options.disabled |= field.rule_state.disabled;
+            options.required |= field.rule_state.required;
+            list.push(FieldInfo {
+                name: field.name.clone(),
+                options,
+                value,
+                result,
+                dirty,
+                radio_group: field.radio_group,
+            });
+        }
+        list
+    }
+
+    /// Returns the names of all fields with validation errors.
+    pub fn invalid_fields(&self) -> Vec<AttrValue> {
+        self.registered_fields()
+            .into_iter()
+            .filter(|info| !info.options.disabled && info.result.is_err())
+            .map(|info| info.name)
+            .collect()
+    }
+
+    /// Set a validation error on the field (e.g. an error reported by the server).
+    ///
+    /// The error is kept until the field value changes or the field gets
+    /// re-validated (see [Self::clear_field_error]).
+    pub fn set_field_error(
+        &mut self,
+        name: impl IntoPropValue<AttrValue>,
+        error: impl Into<String>,
+    ) {
+        let name = name.into_prop_value();
+        if let Some(slab_key) = self.find_field_slab_id(&name) {
+            self.fields[slab_key].result = Err(error.into());
+            self.version += 1;
+        }
+    }
+
+    /// Clear an error set with [Self::set_field_error] by re-validating the field.
+    pub fn clear_field_error(&mut self, name: impl IntoPropValue<AttrValue>) {
+        let name = name.into_prop_value();
+        if let Some(slab_key) = self.find_field_slab_id(&name) {
+            self.validate_field_by_slab_key(slab_key);
+        }
+    }
+
     /// Returns the field validation result.
     pub fn get_field_valid(
         &self,
@@ -818,7 +1106,7 @@ impl FormContextState {
 
     pub fn is_valid(&self) -> bool {
         for (_key, field) in self.fields.iter() {
-            if !field.options.disabled && field.result.is_err() {
+            if !field.is_disabled() && field.result.is_err() {
                 return false;
             }
         }
@@ -836,11 +1124,7 @@ impl FormContextState {
         if field.radio_group {
             // fixme: do something ?
         } else {
-            let result = if let Some(validate) = &field.validate {
-                validate.apply(&field.value).map_err(|err| err.to_string())
-            } else {
-                Ok(field.value.clone())
-            };
+            let result = field.validate_value(&field.value);
 
             if result != field.result {
                 self.version += 1;
@@ -946,6 +1230,14 @@ impl FormContextState {
         self.groups.contains_key(name)
     }
 
+    /// Returns the names of all registered fields.
+    pub fn field_names(&self) -> impl Iterator<Item = &AttrValue> {
+        self.groups
+            .iter()
+            .filter(|(_name, group)| !group.members.is_empty())
+            .map(|(name, _group)| name)
+    }
+
     /// Get form submit data.
     pub fn get_submit_data(&self) -> Value {
         let mut data = json!({});
@@ -998,7 +1290,7 @@ impl FormContextState {
             if field_keys.len() == 1 {
                 let key = field_keys[0];
                 let field = &self.fields[key];
-                if !field.options.disabled && field.options.submit {
+                if !field.is_disabled() && field.options.submit {
                     match &field.result {
                         Ok(value) => {
                             if !field.options.submit_empty & value_is_empty(value) {
@@ -1052,3 +1344,83 @@ fn value_is_empty(value: &Value) -> bool {
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+
+    use super::{FieldOptions, FormContextState};
+    use crate::widget::form::{FieldCondition, FieldRule};
+
+    fn register(state: &mut FormContextState, name: &'static str, value: &str) {
+        let options = FieldOptions {
+            submit: true,
+            submit_empty: false,
+            required: false,
+            disabled: false,
+            tab_order: None,
+        };
+        let value = Value::from(value);
+        state.register_field(name, value.clone(), value, false, None, options, false);
+    }
+
+    #[test]
+    fn test_apply_field_rules() {
+        let mut state = FormContextState::new();
+        register(&mut state, "mode", "auto");
+        register(&mut state, "port", "");
+        register(&mut state, "comment", "");
+
+        state.add_field_rule(
+            FieldRule::new("port").enabled_if(FieldCondition::field_equals("mode", "custom")),
+        );
+        // depends on the state computed by the first rule
+        state.add_field_rule(
+            FieldRule::new("comment")
+                .required_if(|form: &FormContextState| !form.get_field_rule_state("port").disabled),
+        );
+
+        state.apply_field_rules();
+        assert!(state.get_field_rule_state("port").disabled);
+        assert!(!state.get_field_rule_state("comment").required);
+
+        // converged, nothing changes
+        let version = state.version;
+        state.apply_field_rules();
+        assert_eq!(state.version, version);
+
+        state.set_field_value("mode", "custom".into());
+        state.apply_field_rules();
+        assert!(!state.get_field_rule_state("port").disabled);
+        assert!(state.get_field_rule_state("comment").required);
+    }
+
+    #[test]
+    fn test_field_rules_no_convergence() {
+        let mut state = FormContextState::new();
+        register(&mut state, "a", "");
+
+        // toggles on each evaluation
+        state.add_field_rule(
+            FieldRule::new("a")
+                .enabled_if(|form: &FormContextState| form.get_field_rule_state("a").disabled),
+        );
+
+        // terminates
+        state.apply_field_rules();
+    }
+
+    #[test]
+    fn test_rule_identity() {
+        let rule = || FieldRule::new("port").enabled_if(FieldCondition::field_not_empty("mode"));
+        assert!(rule() == rule());
+        assert!(rule() != rule().key("other"));
+        assert!(rule().key("other") == rule().key("other"));
+
+        let mut state = FormContextState::new();
+        state.add_field_rule(rule());
+        state.add_field_rule(rule().key("other"));
+        state.remove_field_rule(&rule());
+        assert_eq!(state.rules.len(), 1);
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::Error;
 use serde_json::Value;
 
+use crate::dom::LocaleInfo;
 use crate::dom::align::{AlignOptions, GrowDirection, Point};
 use crate::prelude::*;
 use crate::props::FieldBuilder;
@@ -16,6 +17,14 @@ use super::plain_date::PlainDate;
 
 use pwt_macros::{builder, widget};
 
+/// Date input field with calendar dropdown.
+///
+/// The field stores the date as string (using
+/// [submit_format](Self::submit_format) or [format](Self::format)), and
+/// validates it against the min/max constraints and disabled days.
+///
+/// Set [locale_format](Self::locale_format) to display the date using the
+/// browser locale date format (see [LocaleInfo](crate::dom::LocaleInfo)).
 #[widget(pwt=crate, comp=ManagedFieldMaster<DateFieldComp>, @input, @element)]
 #[builder]
 #[derive(Clone, PartialEq, Properties)]
@@ -38,6 +47,15 @@ pub struct DateField {
     #[prop_or(AttrValue::from("Y-m-d"))]
     pub format: AttrValue,
 
+    /// Display the date using the browser locale date format.
+    ///
+    /// The [format](Self::format) is then only used for the submitted value
+    /// (unless [submit_format](Self::submit_format) is set), and is still
+    /// accepted as input.
+    #[builder]
+    #[prop_or_default]
+    pub locale_format: bool,
+
     /// Format to use for the submitted value.
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or_default]
@@ -107,10 +125,25 @@ pub struct DateField {
     pub show_today: bool,
 }
 
+/// Date picker field (same as [DateField]).
+///
+/// See [DateTimePicker](super::DateTimePicker) to also select the time.
+pub type DatePicker = DateField;
+
 impl DateField {
+    /// Create a new instance.
     pub fn new() -> Self {
         yew::props!(Self {})
     }
+
+    // The format used to display (and parse) the value.
+    fn display_format(&self) -> AttrValue {
+        if self.locale_format {
+            AttrValue::from(LocaleInfo::new().date_format().to_string())
+        } else {
+            self.format.clone()
+        }
+    }
 }
 
 pub enum Msg {
@@ -124,10 +157,20 @@ pub struct DateFieldComp {
 crate::impl_deref_mut_property!(DateFieldComp, state, ManagedFieldState);
 
 impl DateFieldComp {
-    fn try_parse(value: &str, format: &str, alt_formats: &str) -> Option<PlainDate> {
-        if let Ok(date) = PlainDate::from_format(value, format) {
+    fn try_parse(
+        value: &str,
+        display_format: &str,
+        format: &str,
+        alt_formats: &str,
+    ) -> Option<PlainDate> {
+        if let Ok(date) = PlainDate::from_format(value, display_format) {
             return Some(date);
         }
+        if display_format != format {
+            if let Ok(date) = PlainDate::from_format(value, format) {
+                return Some(date);
+            }
+        }
         for fmt in alt_formats.split('|') {
             if let Ok(date) = PlainDate::from_format(value, fmt) {
                 return Some(date);
@@ -141,6 +184,7 @@ impl DateFieldComp {
 pub struct DateFieldValidationArgs {
     pub min_value: Option<PlainDate>,
     pub max_value: Option<PlainDate>,
+    pub display_format: AttrValue,
     pub format: AttrValue,
     pub submit_format: Option<AttrValue>,
     pub alt_formats: AttrValue,
@@ -157,6 +201,7 @@ impl ManagedField for DateFieldComp {
         DateFieldValidationArgs {
             min_value: props.min_value,
             max_value: props.max_value,
+            display_format: props.display_format(),
             format: props.format.clone(),
             submit_format: props.submit_format.clone(),
             alt_formats: props.alt_formats.clone(),
@@ -172,7 +217,9 @@ impl ManagedField for DateFieldComp {
                 if s.is_empty() {
                     return Ok(Value::String(String::new()));
                 }
-                if let Some(date) = Self::try_parse(s, &args.format, &args.alt_formats) {
+                if let Some(date) =
+                    Self::try_parse(s, &args.display_format, &args.format, &args.alt_formats)
+                {
                     if let Some(min) = &args.min_value {
                         if date < *min {
                             return Err(anyhow::anyhow!(tr!(
@@ -243,7 +290,12 @@ impl ManagedField for DateFieldComp {
             Value::String(s) => s.clone(),
             _ => "".to_string(),
         };
-        let date = Self::try_parse(&value, &props.format, &props.alt_formats);
+        let date = Self::try_parse(
+            &value,
+            &props.display_format(),
+            &props.format,
+            &props.alt_formats,
+        );
         if let Some(on_change) = &props.on_change {
             on_change.emit(date);
         }
@@ -266,11 +318,17 @@ impl ManagedField for DateFieldComp {
         };
         let validation_result = &self.result;
 
-        let current_value = Self::try_parse(&value_str, &props.format, &props.alt_formats);
+        let display_format = props.display_format();
+        let current_value = Self::try_parse(
+            &value_str,
+            &display_format,
+            &props.format,
+            &props.alt_formats,
+        );
 
         // Show the value in display `format`, else a preloaded value renders raw until edited.
         let display_value = match &current_value {
-            Some(date) => date.format(&props.format),
+            Some(date) => date.format(&display_format),
             None => value_str,
         };
 
@@ -16,6 +16,11 @@ pub struct DatePanel {
     #[prop_or_default]
     pub value: Option<PlainDate>,
 
+    /// Highlight a date range (used by range pickers).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub range: Option<(PlainDate, PlainDate)>,
+
     /// Callback triggered when a date is selected.
     #[builder_cb(IntoEventCallback, into_event_callback, PlainDate)]
     #[prop_or_default]
@@ -215,6 +220,7 @@ impl DatePanelComp {
 
     fn render_days(&self, ctx: &Context<Self>) -> Html {
         let selected = ctx.props().value;
+        let range = ctx.props().range;
         let view_year = self.view_date.year();
         let view_month = self.view_date.month(); // 0-11
         // `start_day` (deprecated) overrides `week_start` when a caller still sets it.
@@ -342,6 +348,10 @@ impl DatePanelComp {
                     cell = cell.class("pwt-today");
                 }
 
+                if !is_disabled && range.is_some_and(|(start, end)| d >= start && d <= end) {
+                    cell = cell.class("pwt-date-panel-in-range");
+                }
+
                 if !is_disabled {
                     cell = cell.onclick(ctx.link().callback(move |_| Msg::SelectDate(d_clone)));
                 }
@@ -552,7 +562,7 @@ impl Component for DatePanelComp {
             }
             return true;
         }
-        false
+        ctx.props().range != _old_props.range
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -0,0 +1,670 @@
+use anyhow::Error;
+use serde_json::Value;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::VNode;
+
+use crate::dom::LocaleInfo;
+use crate::dom::align::{AlignOptions, GrowDirection, Point};
+use crate::prelude::*;
+use crate::props::FieldBuilder;
+use crate::widget::form::{
+    FormContext, Hidden, ManagedField, ManagedFieldContext, ManagedFieldMaster,
+    ManagedFieldScopeExt, ManagedFieldState,
+};
+use crate::widget::{Button, Column, Container, Dropdown, DropdownController, Row, WeekStart};
+
+use super::date_panel::DatePanel;
+use super::plain_date::PlainDate;
+
+use pwt_macros::{builder, widget};
+
+// Separator used for the submitted value (ISO 8601 time interval).
+const SUBMIT_SEPARATOR: &str = "/";
+// Separator used to display the range.
+const DISPLAY_SEPARATOR: &str = " – ";
+
+/// Predefined date ranges for the [DateRangePicker].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateRangePreset {
+    /// Today only.
+    Today,
+    /// The last `n` days (including today).
+    LastDays(u32),
+    /// From the first day of the month to today.
+    ThisMonth,
+}
+
+impl DateRangePreset {
+    /// The text displayed in the picker.
+    pub fn text(&self) -> String {
+        match self {
+            Self::Today => tr!("Today"),
+            Self::LastDays(n) => tr!("Last day" | "Last {n} days" % *n),
+            Self::ThisMonth => tr!("This month"),
+        }
+    }
+
+    /// Compute the range relative to `today`.
+    pub fn range(&self, today: PlainDate) -> (PlainDate, PlainDate) {
+        match self {
+            Self::Today => (today, today),
+            Self::LastDays(n) => (today.add_days(1 - (*n).max(1) as i32), today),
+            Self::ThisMonth => (PlainDate::new(today.year(), today.month(), 1), today),
+        }
+    }
+}
+
+/// Date range input field (start and end date) with calendar dropdown.
+///
+/// The dropdown shows a calendar for the start and the end date, and a
+/// list of [presets](Self::presets). The field validates that the end
+/// date is not before the start date.
+///
+/// The value is stored as single string, using the
+/// [submit_format](Self::submit_format) (or [format](Self::format)) for
+/// both dates, separated by a slash (e.g. `2024-01-01/2024-01-31`).
+///
+/// Alternatively, set [start_name](Self::start_name) and
+/// [end_name](Self::end_name) (and no `name`) to store the dates as two
+/// separate values in the [FormContext]:
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::DateRangePicker;
+/// # fn create_field() -> DateRangePicker {
+/// DateRangePicker::new()
+///     .start_name("since")
+///     .end_name("until")
+/// # }
+/// ```
+#[widget(pwt=crate, comp=ManagedFieldMaster<DateRangePickerComp>, @input, @element)]
+#[builder]
+#[derive(Clone, PartialEq, Properties)]
+pub struct DateRangePicker {
+    /// Force value.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+
+    /// Default value.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub default: Option<AttrValue>,
+
+    /// Store the start date as separate value with this name.
+    ///
+    /// Requires [end_name](Self::end_name).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub start_name: Option<AttrValue>,
+
+    /// Store the end date as separate value with this name.
+    ///
+    /// Requires [start_name](Self::start_name).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub end_name: Option<AttrValue>,
+
+    /// The date format string (e.g. "Y-m-d").
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::from("Y-m-d"))]
+    pub format: AttrValue,
+
+    /// Display the dates using the browser locale date format.
+    #[builder]
+    #[prop_or_default]
+    pub locale_format: bool,
+
+    /// Format to use for the submitted value.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub submit_format: Option<AttrValue>,
+
+    /// Callback triggered when the value changes (either via input or picker).
+    #[builder_cb(IntoEventCallback, into_event_callback, Option<(PlainDate, PlainDate)>)]
+    #[prop_or_default]
+    pub on_change: Option<Callback<Option<(PlainDate, PlainDate)>>>,
+
+    /// Allow manual entry of the range. Defaults to true.
+    #[builder]
+    #[prop_or(true)]
+    pub editable: bool,
+
+    /// The minimum allowed date.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub min_value: Option<PlainDate>,
+
+    /// The maximum allowed date.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub max_value: Option<PlainDate>,
+
+    /// Alternative date formats to try if the primary format fails.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::from("Y-m-d|m/d/Y|n/j/Y|d.m.Y|j.n.Y"))]
+    pub alt_formats: AttrValue,
+
+    /// Quick selection presets shown in the dropdown.
+    ///
+    /// Defaults to today, last 7 days and this month. Use an empty list
+    /// to hide the presets.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(vec![
+        DateRangePreset::Today,
+        DateRangePreset::LastDays(7),
+        DateRangePreset::ThisMonth,
+    ])]
+    pub presets: Vec<DateRangePreset>,
+
+    /// Show the week numbers.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(true)]
+    pub show_week_numbers: bool,
+
+    /// The day the calendar week starts on. Defaults to [`WeekStart::Monday`] (ISO 8601).
+    #[builder]
+    #[prop_or_default]
+    pub week_start: WeekStart,
+}
+
+impl Default for DateRangePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DateRangePicker {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    // The format used to display (and parse) the value.
+    fn display_format(&self) -> AttrValue {
+        if self.locale_format {
+            AttrValue::from(LocaleInfo::new().date_format().to_string())
+        } else {
+            self.format.clone()
+        }
+    }
+
+    fn submit_format(&self) -> &AttrValue {
+        self.submit_format.as_ref().unwrap_or(&self.format)
+    }
+}
+
+// Split the range text into start and end text.
+fn split_range(text: &str) -> Option<(&str, &str)> {
+    for separator in [DISPLAY_SEPARATOR.trim(), " - ", SUBMIT_SEPARATOR] {
+        if let Some((start, end)) = text.split_once(separator) {
+            if !end.contains(separator) {
+                return Some((start.trim(), end.trim()));
+            }
+        }
+    }
+    None
+}
+
+fn parse_date(text: &str, formats: &[&str]) -> Option<PlainDate> {
+    formats
+        .iter()
+        .flat_map(|formats| formats.split('|'))
+        .find_map(|format| PlainDate::from_format(text, format).ok())
+}
+
+fn parse_range(text: &str, formats: &[&str]) -> Result<Option<(PlainDate, PlainDate)>, Error> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let (start, end) =
+        split_range(text).ok_or_else(|| anyhow::anyhow!(tr!("Invalid date range")))?;
+    let start =
+        parse_date(start, formats).ok_or_else(|| anyhow::anyhow!(tr!("Invalid date format")))?;
+    let end =
+        parse_date(end, formats).ok_or_else(|| anyhow::anyhow!(tr!("Invalid date format")))?;
+    Ok(Some((start, end)))
+}
+
+fn format_range((start, end): (PlainDate, PlainDate), format: &str, separator: &str) -> String {
+    format!("{}{separator}{}", start.format(format), end.format(format))
+}
+
+pub enum Msg {
+    ValueChange(String),
+    // A separate start/end value changed in the form context.
+    PartChange(bool, Value), // is_end, value
+}
+
+#[doc(hidden)]
+pub struct DateRangePickerComp {
+    state: ManagedFieldState,
+    // Separate start/end values (see `start_name` and `end_name`).
+    parts: (Value, Value),
+}
+
+crate::impl_deref_mut_property!(DateRangePickerComp, state, ManagedFieldState);
+
+impl DateRangePickerComp {
+    fn current_range(&self, props: &DateRangePicker) -> Option<(PlainDate, PlainDate)> {
+        let text = self.value.as_str().unwrap_or("");
+        let display_format = props.display_format();
+        let formats = [&display_format, &props.format, &props.alt_formats];
+        parse_range(text, &formats.map(|f| f.as_str()))
+            .ok()
+            .flatten()
+    }
+
+    // Write the separate start/end values to the form context.
+    fn update_parts(&mut self, ctx: &ManagedFieldContext<Self>) {
+        let props = ctx.props();
+        let (Some(start_name), Some(end_name)) = (&props.start_name, &props.end_name) else {
+            return;
+        };
+        let Some((form_ctx, _handle)) = ctx.link().context::<FormContext>(Callback::noop()) else {
+            return;
+        };
+
+        let format = props.submit_format();
+        let parts = match (&self.result, self.current_range(props)) {
+            (Ok(_), Some((start, end))) => (start.format(format).into(), end.format(format).into()),
+            _ => (Value::Null, Value::Null),
+        };
+        self.parts = parts.clone();
+
+        let mut form_ctx = form_ctx.write();
+        form_ctx.set_field_value(start_name.clone(), parts.0);
+        form_ctx.set_field_value(end_name.clone(), parts.1);
+        if let Err(err) = &self.result {
+            // make sure the form cannot be submitted
+            form_ctx.set_field_error(end_name.clone(), err.clone());
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct DateRangePickerValidationArgs {
+    min_value: Option<PlainDate>,
+    max_value: Option<PlainDate>,
+    display_format: AttrValue,
+    format: AttrValue,
+    submit_format: AttrValue,
+    alt_formats: AttrValue,
+}
+
+impl ManagedField for DateRangePickerComp {
+    type Properties = DateRangePicker;
+    type Message = Msg;
+    type ValidateClosure = DateRangePickerValidationArgs;
+
+    fn validation_args(props: &Self::Properties) -> Self::ValidateClosure {
+        DateRangePickerValidationArgs {
+            min_value: props.min_value,
+            max_value: props.max_value,
+            display_format: props.display_format(),
+            format: props.format.clone(),
+            submit_format: props.submit_format().clone(),
+            alt_formats: props.alt_formats.clone(),
+        }
+    }
+
+    fn validator(args: &Self::ValidateClosure, value: &Value) -> Result<Value, Error> {
+        let text = match value {
+            Value::Null => return Ok(value.clone()),
+            Value::String(text) => text,
+            _ => return Err(anyhow::anyhow!("Invalid value type")),
+        };
+
+        let formats = [&args.display_format, &args.format, &args.alt_formats];
+        let Some((start, end)) = parse_range(text, &formats.map(|f| f.as_str()))? else {
+            return Ok(Value::String(String::new()));
+        };
+
+        if end < start {
+            return Err(anyhow::anyhow!(tr!("End date is before start date")));
+        }
+        if let Some(min) = &args.min_value {
+            if start < *min {
+                return Err(anyhow::anyhow!(tr!("Date is before minimum allowed date")));
+            }
+        }
+        if let Some(max) = &args.max_value {
+            if end > *max {
+                return Err(anyhow::anyhow!(tr!("Date is after maximum allowed date")));
+            }
+        }
+
+        Ok(Value::String(format_range(
+            (start, end),
+            &args.submit_format,
+            SUBMIT_SEPARATOR,
+        )))
+    }
+
+    fn create(ctx: &ManagedFieldContext<Self>) -> Self {
+        let props = ctx.props();
+        let mut value = String::new();
+
+        if let Some(default) = &props.default {
+            value = default.to_string();
+        }
+        if let Some(force_value) = &props.value {
+            value = force_value.to_string();
+        }
+
+        let default: Value = props.default.as_deref().unwrap_or("").into();
+
+        Self {
+            state: ManagedFieldState::new(value.into(), default),
+            parts: (Value::Null, Value::Null),
+        }
+    }
+
+    fn update(&mut self, ctx: &ManagedFieldContext<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ValueChange(value) => {
+                ctx.link().update_value(value);
+                true
+            }
+            Msg::PartChange(is_end, value) => {
+                if is_end {
+                    self.parts.1 = value;
+                } else {
+                    self.parts.0 = value;
+                }
+                let value = match &self.parts {
+                    (Value::String(start), Value::String(end)) => {
+                        format!("{start}{SUBMIT_SEPARATOR}{end}")
+                    }
+                    _ => String::new(),
+                };
+                let current = self.value.as_str().unwrap_or("");
+                let props = ctx.props();
+                let formats = [&props.format, props.submit_format(), &props.alt_formats];
+                let new_range = parse_range(&value, &formats.map(|f| f.as_str()))
+                    .ok()
+                    .flatten();
+                let changed = if value.is_empty() {
+                    !current.is_empty() && self.result.is_ok()
+                } else {
+                    new_range != self.current_range(props)
+                };
+                if changed {
+                    ctx.link().update_value(value);
+                }
+                false
+            }
+        }
+    }
+
+    fn value_changed(&mut self, ctx: &ManagedFieldContext<Self>) {
+        self.update_parts(ctx);
+        let props = ctx.props();
+        if let Some(on_change) = &props.on_change {
+            let range = self.current_range(props).filter(|_| self.result.is_ok());
+            on_change.emit(range);
+        }
+    }
+
+    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value {
+            ctx.link()
+                .force_value(props.value.as_ref().map(|v| v.to_string()), None);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+        let value_text = self.value.as_str().unwrap_or("").to_string();
+
+        let display_format = props.display_format();
+        let current_range = self.current_range(props);
+
+        // Show the value in display format, so that loaded values look the same as picked ones.
+        let display_value = match current_range {
+            Some(range) => format_range(range, &display_format, DISPLAY_SEPARATOR),
+            None => value_text,
+        };
+
+        let picker = {
+            let props = props.clone();
+            move |controller: &DropdownController| {
+                let on_select = controller.on_select_callback();
+                let display_format = props.display_format();
+                DateRangePanel {
+                    value: current_range,
+                    presets: props.presets.clone(),
+                    min_value: props.min_value,
+                    max_value: props.max_value,
+                    show_week_numbers: props.show_week_numbers,
+                    week_start: props.week_start,
+                    on_select: Callback::from(move |range| {
+                        on_select.emit(format_range(range, &display_format, DISPLAY_SEPARATOR))
+                    }),
+                }
+                .into()
+            }
+        };
+
+        let align_options = AlignOptions::new(
+            Point::BottomStart,
+            Point::TopStart,
+            GrowDirection::TopBottom,
+        )
+        .viewport_padding(5.0)
+        .align_width(false);
+
+        let tip = self.result.as_ref().err().map(|err| err.to_string());
+
+        let dropdown = Dropdown::new(picker)
+            .with_std_props(&props.std_props)
+            .with_input_props(&props.input_props)
+            .align_options(align_options)
+            .value(display_value)
+            .valid(self.result.is_ok())
+            .tip(tip)
+            .editable(props.editable)
+            .on_change(ctx.link().callback(Msg::ValueChange))
+            .with_trigger("fa fa-calendar", true);
+
+        let (Some(start_name), Some(end_name)) = (&props.start_name, &props.end_name) else {
+            return dropdown.into();
+        };
+
+        html! {<>
+            {dropdown}
+            {Hidden::new()
+                .name(start_name.clone())
+                .on_change(ctx.link().callback(|value| Msg::PartChange(false, value)))}
+            {Hidden::new()
+                .name(end_name.clone())
+                .on_change(ctx.link().callback(|value| Msg::PartChange(true, value)))}
+        </>}
+    }
+}
+
+// The picker panel with presets and a start and end calendar.
+#[derive(Properties, PartialEq, Clone)]
+struct DateRangePanel {
+    value: Option<(PlainDate, PlainDate)>,
+    presets: Vec<DateRangePreset>,
+    min_value: Option<PlainDate>,
+    max_value: Option<PlainDate>,
+    show_week_numbers: bool,
+    week_start: WeekStart,
+    on_select: Callback<(PlainDate, PlainDate)>,
+}
+
+impl From<DateRangePanel> for VNode {
+    fn from(props: DateRangePanel) -> Self {
+        html! { <DateRangePanelComp ..props /> }
+    }
+}
+
+enum PanelMsg {
+    SelectStart(PlainDate),
+    SelectEnd(PlainDate),
+    Preset(DateRangePreset),
+    Apply,
+}
+
+struct DateRangePanelComp {
+    start: Option<PlainDate>,
+    end: Option<PlainDate>,
+}
+
+impl Component for DateRangePanelComp {
+    type Message = PanelMsg;
+    type Properties = DateRangePanel;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let value = ctx.props().value;
+        Self {
+            start: value.map(|(start, _)| start),
+            end: value.map(|(_, end)| end),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            PanelMsg::SelectStart(start) => {
+                self.start = Some(start);
+                if self.end.is_some_and(|end| end < start) {
+                    self.end = None;
+                }
+                true
+            }
+            PanelMsg::SelectEnd(end) => {
+                let start = self.start.filter(|start| *start <= end).unwrap_or(end);
+                props.on_select.emit((start, end));
+                false
+            }
+            PanelMsg::Preset(preset) => {
+                props.on_select.emit(preset.range(PlainDate::today()));
+                false
+            }
+            PanelMsg::Apply => {
+                if let (Some(start), Some(end)) = (self.start, self.end) {
+                    props.on_select.emit((start, end));
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let range = self.start.zip(self.end);
+
+        let presets = (!props.presets.is_empty()).then(|| {
+            Column::new()
+                .class("pwt-date-range-presets")
+                .padding(1)
+                .gap(1)
+                .border_end(true)
+                .children(props.presets.iter().map(|preset| {
+                    let preset = *preset;
+                    Button::new(preset.text())
+                        .class("pwt-button-text")
+                        .on_activate(link.callback(move |_| PanelMsg::Preset(preset)))
+                        .into()
+                }))
+        });
+
+        let calendar = |label: String, panel: DatePanel| {
+            Column::new()
+                .with_child(
+                    Container::new()
+                        .class("pwt-font-label-large")
+                        .padding_x(2)
+                        .padding_top(1)
+                        .with_child(label),
+                )
+                .with_child(panel)
+        };
+
+        let start_panel = DatePanel::new()
+            .value(self.start)
+            .range(range)
+            .min_value(props.min_value)
+            .max_value(props.max_value)
+            .show_week_numbers(props.show_week_numbers)
+            .week_start(props.week_start)
+            .show_today(false)
+            .on_select(link.callback(PanelMsg::SelectStart));
+
+        // the end date cannot be before the start date
+        let end_min = match (self.start, props.min_value) {
+            (Some(start), Some(min)) => Some(start.max(min)),
+            (start, min) => start.or(min),
+        };
+        let end_panel = DatePanel::new()
+            .value(self.end.or(self.start))
+            .range(range)
+            .min_value(end_min)
+            .max_value(props.max_value)
+            .show_week_numbers(props.show_week_numbers)
+            .week_start(props.week_start)
+            .show_today(false)
+            .on_select(link.callback(PanelMsg::SelectEnd));
+
+        let footer = Row::new()
+            .padding(1)
+            .border_top(true)
+            .with_flex_spacer()
+            .with_child(
+                Button::new(tr!("Apply"))
+                    .disabled(range.is_none())
+                    .on_activate(link.callback(|_| PanelMsg::Apply)),
+            );
+
+        Column::new()
+            .class("pwt-date-range-panel")
+            .with_child(
+                Row::new()
+                    .with_optional_child(presets)
+                    .with_child(calendar(tr!("Start"), start_panel))
+                    .with_child(calendar(tr!("End"), end_panel)),
+            )
+            .with_child(footer)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        let formats = ["Y-m-d", "d.m.Y"];
+        let range = (PlainDate::new(2024, 0, 1), PlainDate::new(2024, 0, 31));
+
+        assert_eq!(parse_range("", &formats).unwrap(), None);
+        assert_eq!(
+            parse_range("2024-01-01/2024-01-31", &formats).unwrap(),
+            Some(range)
+        );
+        assert_eq!(
+            parse_range("2024-01-01 – 2024-01-31", &formats).unwrap(),
+            Some(range)
+        );
+        assert_eq!(
+            parse_range("01.01.2024 - 31.01.2024", &formats).unwrap(),
+            Some(range)
+        );
+        assert!(parse_range("2024-01-01", &formats).is_err());
+        assert!(parse_range("2024-01-01/2024-13-45", &formats).is_err());
+
+        assert_eq!(format_range(range, "Y-m-d", "/"), "2024-01-01/2024-01-31");
+    }
+}
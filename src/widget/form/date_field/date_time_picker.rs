@@ -0,0 +1,536 @@
+use std::fmt;
+
+use anyhow::Error;
+use serde_json::Value;
+use web_sys::HtmlInputElement;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::VNode;
+
+use crate::dom::LocaleInfo;
+use crate::dom::align::{AlignOptions, GrowDirection, Point};
+use crate::prelude::*;
+use crate::props::FieldBuilder;
+use crate::widget::form::{
+    ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt, ManagedFieldState,
+};
+use crate::widget::{Button, Column, Dropdown, DropdownController, Input, Row, WeekStart};
+
+use super::date_panel::DatePanel;
+use super::plain_date::PlainDate;
+
+use pwt_macros::{builder, widget};
+
+/// A time of day without date and time zone (hour and minute).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PlainTime {
+    hour: u32,
+    minute: u32,
+}
+
+impl PlainTime {
+    /// Create a new instance.
+    ///
+    /// Returns `None` if the hour or minute is out of range.
+    pub fn new(hour: u32, minute: u32) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// The hour (0-23).
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// The minute (0-59).
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// Parse `H:i` (e.g. "9:05" or "09:05").
+    pub fn parse(text: &str) -> Option<Self> {
+        let (hour, minute) = text.trim().split_once(':')?;
+        if hour.is_empty() || hour.len() > 2 || minute.len() != 2 {
+            return None;
+        }
+        if !(hour.chars().chain(minute.chars())).all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        Self::new(hour.parse().ok()?, minute.parse().ok()?)
+    }
+}
+
+impl fmt::Display for PlainTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// Date and time input field with calendar dropdown.
+///
+/// The dropdown shows a calendar and a time input. The value is stored
+/// as single string, using the [submit_format](Self::submit_format) (or
+/// [format](Self::format)) for the date, followed by the time (e.g.
+/// `2024-01-31 13:45`).
+///
+/// The [min_value](Self::min_value) and [max_value](Self::max_value)
+/// constraints apply to the date.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::{DateTimePicker, PlainDate};
+/// # fn create_field() -> DateTimePicker {
+/// DateTimePicker::new()
+///     .name("start")
+///     .locale_format(true)
+///     .min_value(PlainDate::new(2024, 0, 1))
+/// # }
+/// ```
+#[widget(pwt=crate, comp=ManagedFieldMaster<DateTimePickerComp>, @input, @element)]
+#[builder]
+#[derive(Clone, PartialEq, Properties)]
+pub struct DateTimePicker {
+    /// Force value.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+
+    /// Default value.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub default: Option<AttrValue>,
+
+    /// The date format string (e.g. "Y-m-d").
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::from("Y-m-d"))]
+    pub format: AttrValue,
+
+    /// Display the date using the browser locale date format.
+    #[builder]
+    #[prop_or_default]
+    pub locale_format: bool,
+
+    /// Date format to use for the submitted value.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub submit_format: Option<AttrValue>,
+
+    /// Callback triggered when the value changes (either via input or picker).
+    #[builder_cb(IntoEventCallback, into_event_callback, Option<(PlainDate, PlainTime)>)]
+    #[prop_or_default]
+    pub on_change: Option<Callback<Option<(PlainDate, PlainTime)>>>,
+
+    /// Allow manual entry of the date and time. Defaults to true.
+    #[builder]
+    #[prop_or(true)]
+    pub editable: bool,
+
+    /// The minimum allowed date.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub min_value: Option<PlainDate>,
+
+    /// The maximum allowed date.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub max_value: Option<PlainDate>,
+
+    /// Alternative date formats to try if the primary format fails.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::from("Y-m-d|m/d/Y|n/j/Y|d.m.Y|j.n.Y"))]
+    pub alt_formats: AttrValue,
+
+    /// Show the week numbers.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(true)]
+    pub show_week_numbers: bool,
+
+    /// The day the calendar week starts on. Defaults to [`WeekStart::Monday`] (ISO 8601).
+    #[builder]
+    #[prop_or_default]
+    pub week_start: WeekStart,
+}
+
+impl Default for DateTimePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DateTimePicker {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    // The format used to display (and parse) the date.
+    fn display_format(&self) -> AttrValue {
+        if self.locale_format {
+            AttrValue::from(LocaleInfo::new().date_format().to_string())
+        } else {
+            self.format.clone()
+        }
+    }
+
+    fn submit_format(&self) -> &AttrValue {
+        self.submit_format.as_ref().unwrap_or(&self.format)
+    }
+}
+
+fn parse_date(text: &str, formats: &[&str]) -> Option<PlainDate> {
+    formats
+        .iter()
+        .flat_map(|formats| formats.split('|'))
+        .find_map(|format| PlainDate::from_format(text, format).ok())
+}
+
+// Parse "<date> <time>" (the time is separated by the last space).
+fn parse_date_time(text: &str, formats: &[&str]) -> Result<Option<(PlainDate, PlainTime)>, Error> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let (date, time) = text
+        .rsplit_once(' ')
+        .ok_or_else(|| anyhow::anyhow!(tr!("Invalid time format")))?;
+    let date = parse_date(date.trim(), formats)
+        .ok_or_else(|| anyhow::anyhow!(tr!("Invalid date format")))?;
+    let time = PlainTime::parse(time).ok_or_else(|| anyhow::anyhow!(tr!("Invalid time format")))?;
+    Ok(Some((date, time)))
+}
+
+fn format_date_time((date, time): (PlainDate, PlainTime), format: &str) -> String {
+    format!("{} {time}", date.format(format))
+}
+
+pub enum Msg {
+    ValueChange(String),
+}
+
+#[doc(hidden)]
+pub struct DateTimePickerComp {
+    state: ManagedFieldState,
+}
+
+crate::impl_deref_mut_property!(DateTimePickerComp, state, ManagedFieldState);
+
+impl DateTimePickerComp {
+    fn current_value(&self, props: &DateTimePicker) -> Option<(PlainDate, PlainTime)> {
+        let text = self.value.as_str().unwrap_or("");
+        let display_format = props.display_format();
+        let formats = [&display_format, &props.format, &props.alt_formats];
+        parse_date_time(text, &formats.map(|f| f.as_str()))
+            .ok()
+            .flatten()
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct DateTimePickerValidationArgs {
+    min_value: Option<PlainDate>,
+    max_value: Option<PlainDate>,
+    display_format: AttrValue,
+    format: AttrValue,
+    submit_format: AttrValue,
+    alt_formats: AttrValue,
+}
+
+impl ManagedField for DateTimePickerComp {
+    type Properties = DateTimePicker;
+    type Message = Msg;
+    type ValidateClosure = DateTimePickerValidationArgs;
+
+    fn validation_args(props: &Self::Properties) -> Self::ValidateClosure {
+        DateTimePickerValidationArgs {
+            min_value: props.min_value,
+            max_value: props.max_value,
+            display_format: props.display_format(),
+            format: props.format.clone(),
+            submit_format: props.submit_format().clone(),
+            alt_formats: props.alt_formats.clone(),
+        }
+    }
+
+    fn validator(args: &Self::ValidateClosure, value: &Value) -> Result<Value, Error> {
+        let text = match value {
+            Value::Null => return Ok(value.clone()),
+            Value::String(text) => text,
+            _ => return Err(anyhow::anyhow!("Invalid value type")),
+        };
+
+        let formats = [&args.display_format, &args.format, &args.alt_formats];
+        let Some((date, time)) = parse_date_time(text, &formats.map(|f| f.as_str()))? else {
+            return Ok(Value::String(String::new()));
+        };
+
+        if let Some(min) = &args.min_value {
+            if date < *min {
+                return Err(anyhow::anyhow!(tr!("Date is before minimum allowed date")));
+            }
+        }
+        if let Some(max) = &args.max_value {
+            if date > *max {
+                return Err(anyhow::anyhow!(tr!("Date is after maximum allowed date")));
+            }
+        }
+
+        Ok(Value::String(format_date_time(
+            (date, time),
+            &args.submit_format,
+        )))
+    }
+
+    fn create(ctx: &ManagedFieldContext<Self>) -> Self {
+        let props = ctx.props();
+        let mut value = String::new();
+
+        if let Some(default) = &props.default {
+            value = default.to_string();
+        }
+        if let Some(force_value) = &props.value {
+            value = force_value.to_string();
+        }
+
+        let default: Value = props.default.as_deref().unwrap_or("").into();
+
+        Self {
+            state: ManagedFieldState::new(value.into(), default),
+        }
+    }
+
+    fn update(&mut self, ctx: &ManagedFieldContext<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ValueChange(value) => {
+                ctx.link().update_value(value);
+                true
+            }
+        }
+    }
+
+    fn value_changed(&mut self, ctx: &ManagedFieldContext<Self>) {
+        let props = ctx.props();
+        if let Some(on_change) = &props.on_change {
+            let value = self.current_value(props).filter(|_| self.result.is_ok());
+            on_change.emit(value);
+        }
+    }
+
+    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value {
+            ctx.link()
+                .force_value(props.value.as_ref().map(|v| v.to_string()), None);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+        let value_text = self.value.as_str().unwrap_or("").to_string();
+
+        let display_format = props.display_format();
+        let current_value = self.current_value(props);
+
+        // Show the value in display format, so that loaded values look the same as picked ones.
+        let display_value = match current_value {
+            Some(value) => format_date_time(value, &display_format),
+            None => value_text,
+        };
+
+        let picker = {
+            let props = props.clone();
+            move |controller: &DropdownController| {
+                let on_select = controller.on_select_callback();
+                let display_format = props.display_format();
+                DateTimePanel {
+                    value: current_value,
+                    min_value: props.min_value,
+                    max_value: props.max_value,
+                    show_week_numbers: props.show_week_numbers,
+                    week_start: props.week_start,
+                    on_select: Callback::from(move |value| {
+                        on_select.emit(format_date_time(value, &display_format))
+                    }),
+                }
+                .into()
+            }
+        };
+
+        let align_options = AlignOptions::new(
+            Point::BottomStart,
+            Point::TopStart,
+            GrowDirection::TopBottom,
+        )
+        .viewport_padding(5.0)
+        .align_width(false);
+
+        let tip = self.result.as_ref().err().map(|err| err.to_string());
+
+        Dropdown::new(picker)
+            .with_std_props(&props.std_props)
+            .with_input_props(&props.input_props)
+            .align_options(align_options)
+            .value(display_value)
+            .valid(self.result.is_ok())
+            .tip(tip)
+            .editable(props.editable)
+            .on_change(ctx.link().callback(Msg::ValueChange))
+            .with_trigger("fa fa-calendar", true)
+            .into()
+    }
+}
+
+// The picker panel with a calendar and a time input.
+#[derive(Properties, PartialEq, Clone)]
+struct DateTimePanel {
+    value: Option<(PlainDate, PlainTime)>,
+    min_value: Option<PlainDate>,
+    max_value: Option<PlainDate>,
+    show_week_numbers: bool,
+    week_start: WeekStart,
+    on_select: Callback<(PlainDate, PlainTime)>,
+}
+
+impl From<DateTimePanel> for VNode {
+    fn from(props: DateTimePanel) -> Self {
+        html! { <DateTimePanelComp ..props /> }
+    }
+}
+
+enum PanelMsg {
+    SelectDate(PlainDate),
+    TimeInput(String),
+    Apply,
+}
+
+struct DateTimePanelComp {
+    date: Option<PlainDate>,
+    // Note: keep the raw text, so that partial input is not lost.
+    time: String,
+}
+
+impl DateTimePanelComp {
+    fn selection(&self) -> Option<(PlainDate, PlainTime)> {
+        self.date.zip(PlainTime::parse(&self.time))
+    }
+}
+
+impl Component for DateTimePanelComp {
+    type Message = PanelMsg;
+    type Properties = DateTimePanel;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let value = ctx.props().value;
+        Self {
+            date: value.map(|(date, _)| date),
+            time: value.map(|(_, time)| time).unwrap_or_default().to_string(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            PanelMsg::SelectDate(date) => {
+                self.date = Some(date);
+                true
+            }
+            PanelMsg::TimeInput(time) => {
+                self.time = time;
+                true
+            }
+            PanelMsg::Apply => {
+                if let Some(value) = self.selection() {
+                    ctx.props().on_select.emit(value);
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let calendar = DatePanel::new()
+            .value(self.date)
+            .min_value(props.min_value)
+            .max_value(props.max_value)
+            .show_week_numbers(props.show_week_numbers)
+            .week_start(props.week_start)
+            .show_today(false)
+            .on_select(link.callback(PanelMsg::SelectDate));
+
+        let time_input = Input::new()
+            .class("pwt-input")
+            .attribute("type", "time")
+            .attribute("value", self.time.clone())
+            .attribute("aria-label", tr!("Time"))
+            .oninput(link.callback(|event: InputEvent| {
+                let input: HtmlInputElement = event.target_unchecked_into();
+                PanelMsg::TimeInput(input.value())
+            }))
+            .onkeydown(link.batch_callback(|event: KeyboardEvent| {
+                (event.key() == "Enter").then_some(PanelMsg::Apply)
+            }));
+
+        let footer = Row::new()
+            .class(crate::css::AlignItems::Center)
+            .padding(1)
+            .gap(2)
+            .border_top(true)
+            .with_child(time_input)
+            .with_flex_spacer()
+            .with_child(
+                Button::new(tr!("Apply"))
+                    .disabled(self.selection().is_none())
+                    .on_activate(link.callback(|_| PanelMsg::Apply)),
+            );
+
+        Column::new()
+            .class("pwt-date-time-panel")
+            .with_child(calendar)
+            .with_child(footer)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_plain_time() {
+        assert_eq!(PlainTime::parse("09:05"), PlainTime::new(9, 5));
+        assert_eq!(PlainTime::parse(" 9:05 "), PlainTime::new(9, 5));
+        assert_eq!(PlainTime::parse("23:59"), PlainTime::new(23, 59));
+        assert_eq!(PlainTime::parse("24:00"), None);
+        assert_eq!(PlainTime::parse("12:60"), None);
+        assert_eq!(PlainTime::parse("12:5"), None);
+        assert_eq!(PlainTime::parse("+1:05"), None);
+        assert_eq!(PlainTime::parse("12"), None);
+        assert_eq!(PlainTime::new(7, 3).unwrap().to_string(), "07:03");
+    }
+
+    #[test]
+    fn test_parse_date_time() {
+        let formats = ["Y-m-d", "d. m. Y"];
+        let value = (PlainDate::new(2024, 0, 31), PlainTime::new(13, 45).unwrap());
+
+        assert_eq!(parse_date_time("", &formats).unwrap(), None);
+        assert_eq!(
+            parse_date_time("2024-01-31 13:45", &formats).unwrap(),
+            Some(value)
+        );
+        // date formats may contain spaces
+        assert_eq!(
+            parse_date_time("31. 01. 2024 13:45", &formats).unwrap(),
+            Some(value)
+        );
+        assert!(parse_date_time("2024-01-31", &formats).is_err());
+        assert!(parse_date_time("2024-01-31 25:00", &formats).is_err());
+        assert!(parse_date_time("2024-13-31 13:45", &formats).is_err());
+
+        assert_eq!(format_date_time(value, "d.m.Y"), "31.01.2024 13:45");
+    }
+}
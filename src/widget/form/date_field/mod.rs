@@ -1,7 +1,13 @@
 mod date_field;
-pub use date_field::{DateField, DateFieldComp};
+pub use date_field::{DateField, DateFieldComp, DatePicker};
 
 mod date_panel;
 
+mod date_range_picker;
+pub use date_range_picker::{DateRangePicker, DateRangePickerComp, DateRangePreset};
+
+mod date_time_picker;
+pub use date_time_picker::{DateTimePicker, DateTimePickerComp, PlainTime};
+
 mod plain_date;
 pub use plain_date::PlainDate;
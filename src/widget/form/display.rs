@@ -1,14 +1,20 @@
 use serde_json::Value;
 
 use yew::html::IntoPropValue;
-use yew::{AttrValue, Properties};
+use yew::{AttrValue, Callback, Html, MouseEvent, Properties, html};
+use yew_router::AnyRoute;
+use yew_router::scope_ext::RouterScopeExt;
 
 use pwt_macros::{builder, widget};
 
 use crate::{
+    css::AlignItems,
+    props::{IntoOptionalRenderFn, RenderFn},
     touch::prelude::{ContainerBuilder, WidgetBuilder},
+    tr,
+    web_sys_ext::clipboard_write_text,
     widget::{
-        Container, Tooltip,
+        ActionIcon, Container, Row, SecretText, Tooltip,
         form::{ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldState},
     },
 };
@@ -16,6 +22,12 @@ use crate::{
 pub type PwtDisplayField = ManagedFieldMaster<DisplayFieldImpl>;
 
 /// A display only text field which is not validated
+///
+/// The stored value can be formatted using a `renderer` (e.g. to show
+/// byte sizes or timestamps). Optionally, the field shows a button to
+/// copy the value to the clipboard, or renders the value as router link.
+///
+/// Set [secret](Self::secret) to mask the value (see [SecretText]).
 #[widget(pwt=crate, comp=ManagedFieldMaster<DisplayFieldImpl>, @input, @element)]
 #[derive(Clone, PartialEq, Properties)]
 #[builder]
@@ -37,6 +49,34 @@ pub struct DisplayField {
     #[prop_or_default]
     #[builder]
     pub tip: Option<AttrValue>,
+
+    /// Render function to format the value.
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, Value)]
+    #[prop_or_default]
+    pub renderer: Option<RenderFn<Value>>,
+
+    /// Show a button to copy the (unformatted) value to the clipboard.
+    #[builder]
+    #[prop_or_default]
+    pub copy_button: bool,
+
+    /// Render the value as router link.
+    ///
+    /// The function returns the route path for the value. This uses the
+    /// [Navigator](yew_router::navigator::Navigator) if available, else
+    /// it renders a normal link.
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, Value, String)]
+    #[prop_or_default]
+    pub link_route: Option<RenderFn<Value, String>>,
+
+    /// Mask the value (API tokens, keys, ...).
+    ///
+    /// The value is displayed as bullets, with an icon to reveal it. The
+    /// [renderer](Self::renderer) and [link_route](Self::link_route) are
+    /// ignored, and the copy button copies the value without revealing it.
+    #[builder]
+    #[prop_or_default]
+    pub secret: bool,
 }
 
 impl DisplayField {
@@ -77,14 +117,63 @@ impl ManagedField for DisplayFieldImpl {
         let props = ctx.props();
         let input_props = &props.input_props;
 
-        let value = self
-            .value
-            .as_str()
-            .unwrap_or(props.default.as_deref().unwrap_or(""));
+        let value = match &self.value {
+            Value::Null => props.default.as_deref().unwrap_or("").into(),
+            value => value.clone(),
+        };
+
+        let text = match &value {
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        };
+
+        let content: Html = match &props.renderer {
+            Some(renderer) => renderer.apply(&value),
+            None => html! {text.clone()},
+        };
+
+        let content: Html = match &props.link_route {
+            Some(link_route) => {
+                let route = link_route.apply(&value);
+                let navigator = ctx.link().navigator();
+                let onclick = Callback::from({
+                    let route = route.clone();
+                    move |event: MouseEvent| {
+                        if let Some(navigator) = &navigator {
+                            event.prevent_default();
+                            navigator.push(&AnyRoute::new(route.clone()));
+                        }
+                    }
+                });
+                html! {<a class="pwt-display-link" href={route} {onclick}>{content}</a>}
+            }
+            None => content,
+        };
 
         let tabindex = input_props.tabindex.unwrap_or(0).to_string();
 
-        Tooltip::new(Container::from_tag("span").with_child(value))
+        let display = Container::from_tag("span").with_child(content);
+
+        let display: Html = if props.secret {
+            SecretText::new(text).copy_button(props.copy_button).into()
+        } else if props.copy_button {
+            Row::new()
+                .class(AlignItems::Center)
+                .gap(1)
+                .with_child(display)
+                .with_child(
+                    ActionIcon::new("fa fa-clipboard")
+                        .aria_label(tr!("Copy to clipboard"))
+                        .on_activate(move |_| {
+                            let _ = clipboard_write_text(&text);
+                        }),
+                )
+                .into()
+        } else {
+            display.into()
+        };
+
+        Tooltip::new(display)
             .with_std_props(&props.std_props)
             .class("pwt-input-display")
             .tip(&props.tip)
@@ -11,11 +11,12 @@ use yew::prelude::*;
 use pwt_macros::{builder, widget};
 
 use super::{
-    IntoValidateFn, ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt,
-    ManagedFieldState, ValidateFn,
+    InputMask, IntoValidateFn, ManagedField, ManagedFieldContext, ManagedFieldMaster,
+    ManagedFieldScopeExt, ManagedFieldState, ValidateFn,
 };
 use crate::props::{
-    ContainerBuilder, EventSubscriber, IntoVTag, WidgetBuilder, WidgetStyleBuilder,
+    ContainerBuilder, EventSubscriber, IntoOptionalRenderFn, IntoVTag, RenderFn, WidgetBuilder,
+    WidgetStyleBuilder,
 };
 use crate::tr;
 use crate::widget::{Container, Input, Tooltip, Trigger};
@@ -192,6 +193,61 @@ pub struct Field {
     /// The tooltip.
     #[prop_or_default]
     pub tip: Option<AttrValue>,
+
+    /// Input filter, applied on every input event.
+    ///
+    /// Useful to fix trivial input errors while typing, i.e. strip
+    /// spaces or force lowercase (see [InputFilter] for common filters).
+    /// The cursor position is kept stable.
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String, String)]
+    #[prop_or_default]
+    pub input_filter: Option<RenderFn<String, String>>,
+
+    /// Normalize function, applied when the field loses focus.
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String, String)]
+    #[prop_or_default]
+    pub normalize_on_blur: Option<RenderFn<String, String>>,
+
+    /// Input mask (i.e. `"XX:XX:XX:XX:XX:XX"` for MAC addresses).
+    ///
+    /// Applied on every input event (after the
+    /// [input_filter](Self::input_filter)), so literals are inserted
+    /// automatically and pasted text is normalized. Non-empty values
+    /// must completely match the mask.
+    #[prop_or_default]
+    pub mask: Option<InputMask>,
+}
+
+/// Common input filters for [Field::input_filter] and [Field::normalize_on_blur].
+pub struct InputFilter;
+
+// Note: filters take `&String` to match the [RenderFn] signature.
+#[allow(clippy::ptr_arg)]
+impl InputFilter {
+    /// Convert text to uppercase.
+    pub fn uppercase(text: &String) -> String {
+        text.to_uppercase()
+    }
+
+    /// Convert text to lowercase.
+    pub fn lowercase(text: &String) -> String {
+        text.to_lowercase()
+    }
+
+    /// Remove leading and trailing whitespace.
+    pub fn trim(text: &String) -> String {
+        text.trim().to_string()
+    }
+
+    /// Remove all whitespace.
+    pub fn strip_whitespace(text: &String) -> String {
+        text.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Only allow ASCII digits.
+    pub fn digits_only(text: &String) -> String {
+        text.chars().filter(|c| c.is_ascii_digit()).collect()
+    }
 }
 
 impl Default for Field {
@@ -266,10 +322,33 @@ impl Field {
     pub fn set_tip(&mut self, tip: impl IntoPropValue<Option<AttrValue>>) {
         self.tip = tip.into_prop_value();
     }
+
+    /// Builder style method to set the input mask
+    pub fn mask(mut self, mask: impl Into<InputMask>) -> Self {
+        self.set_mask(mask);
+        self
+    }
+
+    /// Method to set the input mask
+    pub fn set_mask(&mut self, mask: impl Into<InputMask>) {
+        self.mask = Some(mask.into());
+    }
+
+    // apply input_filter and mask
+    fn filter_input(&self, input: &str) -> String {
+        let mut text = input.to_string();
+        if let Some(input_filter) = &self.input_filter {
+            text = input_filter.apply(&text);
+        }
+        if let Some(mask) = &self.mask {
+            text = mask.apply(&text);
+        }
+        text
+    }
 }
 
 pub enum Msg {
-    Update(String),
+    Update(String, Option<u32>),
     RevealPassword,
     HidePassword,
 }
@@ -305,6 +384,7 @@ pub struct ValidateClosure {
     input_type: InputType,
     min: Option<f64>,
     max: Option<f64>,
+    mask: Option<InputMask>,
     validate: Option<ValidateFn<String>>,
 }
 
@@ -319,6 +399,7 @@ impl ManagedField for StandardField {
             input_type: props.input_type,
             min: props.min,
             max: props.max,
+            mask: props.mask.clone(),
             validate: props.validate.clone(),
         }
     }
@@ -362,6 +443,15 @@ impl ManagedField for StandardField {
             }
         }
 
+        if let Some(mask) = &props.mask {
+            if !mask.matches(&value) {
+                return Err(Error::msg(tr!(
+                    "value does not match the format '{}'",
+                    mask
+                )));
+            }
+        }
+
         if let Some(validate) = &props.validate {
             validate.apply(&value)?;
         }
@@ -419,7 +509,24 @@ impl ManagedField for StandardField {
     fn update(&mut self, ctx: &ManagedFieldContext<Self>, msg: Self::Message) -> bool {
         let props = ctx.props();
         match msg {
-            Msg::Update(input) => {
+            Msg::Update(mut input, cursor) => {
+                if props.input_filter.is_some() || props.mask.is_some() {
+                    let filtered = props.filter_input(&input);
+                    if filtered != input {
+                        // compute new cursor position from the filtered prefix
+                        let cursor = cursor.map(|pos| {
+                            let prefix: String = input.chars().take(pos as usize).collect();
+                            props.filter_input(&prefix).chars().count() as u32
+                        });
+                        if let Some(el) = self.input_ref.cast::<HtmlInputElement>() {
+                            el.set_value(&filtered);
+                            if let Some(pos) = cursor {
+                                let _ = el.set_selection_range(pos, pos);
+                            }
+                        }
+                        input = filtered;
+                    }
+                }
                 ctx.link().update_value(input.clone());
                 if let Some(on_input) = &props.on_input {
                     on_input.emit(input);
@@ -450,19 +557,22 @@ impl ManagedField for StandardField {
         };
         let oninput = ctx.link().callback(move |event: InputEvent| {
             let input: HtmlInputElement = event.target_unchecked_into();
-            Msg::Update(input.value())
+            let cursor = input.selection_start().ok().flatten();
+            Msg::Update(input.value(), cursor)
         });
-
-        let disabled = props.input_props.disabled;
+        let disabled = self.is_disabled(props);
         let input: Html = Input::new()
             .with_input_props(&props.input_props)
-            .class("pwt-flex-fill")
+            .disabled(disabled)
+            .class(crate::css::Flex::Fill)
             .attribute("type", Some(input_type.to_string()))
             .attribute("value", value)
             .attribute("min", props.min.map(|v| v.to_string()))
             .attribute("max", props.max.map(|v| v.to_string()))
             .attribute("step", props.step.map(|v| v.to_string()))
             .oninput((!disabled).then_some(oninput))
+            .onfocus(ctx.link().focus_callback(true))
+            .onblur(ctx.link().focus_callback(false))
             .into_html_with_ref(self.input_ref.clone());
 
         let peek_icon =
@@ -496,7 +606,7 @@ impl ManagedField for StandardField {
             .class("pwt-input")
             .class(format!("pwt-input-type-{}", props.input_type))
             .class(disabled.then_some("disabled"))
-            .class("pwt-w-100")
+            .class(crate::css::FullWidth)
             .class(if validation_result.is_ok() {
                 "is-valid"
             } else {
@@ -544,6 +654,24 @@ impl ManagedField for StandardField {
         input_container.into()
     }
 
+    fn on_blur(&mut self, ctx: &ManagedFieldContext<Self>) -> bool {
+        let props = ctx.props();
+        if let Some(normalize) = &props.normalize_on_blur {
+            let text = value_to_text(&self.value);
+            let normalized = normalize.apply(&text);
+            if normalized != text {
+                ctx.link().update_value(normalized);
+            }
+        }
+        false
+    }
+
+    fn focus(&mut self, _ctx: &ManagedFieldContext<Self>) {
+        if let Some(el) = self.input_ref.cast::<web_sys::HtmlElement>() {
+            let _ = el.focus();
+        }
+    }
+
     fn rendered(&mut self, ctx: &ManagedFieldContext<Self>, first_render: bool) {
         if first_render {
             let props = ctx.props();
@@ -0,0 +1,338 @@
+use serde_json::Value;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::prelude::*;
+
+use pwt_macros::{builder, widget};
+
+use super::{FormContext, FormContextObserver, FormContextState};
+use crate::css::{AlignItems, FlexFit};
+use crate::props::{ContainerBuilder, CssPaddingBuilder, EventSubscriber, RenderFn, WidgetBuilder};
+use crate::tr;
+use crate::widget::{ActionIcon, Button, Column, Row};
+
+/// Item description passed to the [FieldArray] render function.
+#[derive(Clone, PartialEq)]
+pub struct FieldArrayItem {
+    /// The array name.
+    pub name: AttrValue,
+    /// The item index.
+    pub index: usize,
+}
+
+impl FieldArrayItem {
+    /// Returns the prefix for sub-field names, i.e. `net[0]`.
+    pub fn prefix(&self) -> String {
+        format!("{}[{}]", self.name, self.index)
+    }
+
+    /// Returns the form context name for a sub-field, i.e. `net[0].model`.
+    pub fn field_name(&self, field: &str) -> String {
+        format!("{}.{field}", self.prefix())
+    }
+}
+
+/// Repeatable group of form fields.
+///
+/// Manages an indexed set of sub-fields (e.g. multiple network
+/// interfaces) inside a [FormContext]. Sub-fields are named using
+/// [FieldArrayItem::field_name], i.e. `net[0].model`, so that the
+/// values are part of the normal form data.
+///
+/// Items can be added, removed and reordered. When removing or moving
+/// items, the field values are moved inside the [FormContext] accordingly.
+///
+/// The widget gets the `is-invalid` class if any sub-field is invalid.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::{Field, FieldArray, FieldArrayItem};
+/// FieldArray::new("net", |item: &FieldArrayItem| {
+///     Field::new().name(item.field_name("model")).into()
+/// })
+/// .min_items(1)
+/// .max_items(8)
+/// # ;
+/// ```
+#[widget(pwt=crate, comp=PwtFieldArray, @element)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct FieldArray {
+    /// The array name (prefix for sub-field names).
+    pub name: AttrValue,
+
+    /// Item render function.
+    pub render_item: RenderFn<FieldArrayItem>,
+
+    /// Initial number of items.
+    #[builder]
+    #[prop_or(1)]
+    pub default_count: usize,
+
+    /// Minimum number of items.
+    #[builder]
+    #[prop_or_default]
+    pub min_items: usize,
+
+    /// Maximum number of items.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub max_items: Option<usize>,
+
+    /// Show controls to move items up/down.
+    #[builder]
+    #[prop_or(true)]
+    pub reorderable: bool,
+
+    /// Text for the add button (default "Add").
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub add_text: Option<AttrValue>,
+
+    /// Item count change callback.
+    #[builder_cb(IntoEventCallback, into_event_callback, usize)]
+    #[prop_or_default]
+    pub on_count_change: Option<Callback<usize>>,
+}
+
+impl FieldArray {
+    /// Create a new instance.
+    pub fn new(
+        name: impl IntoPropValue<AttrValue>,
+        render_item: impl Into<RenderFn<FieldArrayItem>>,
+    ) -> Self {
+        yew::props!(Self {
+            name: name.into_prop_value(),
+            render_item: render_item.into(),
+        })
+    }
+}
+
+pub enum Msg {
+    Add,
+    Remove(usize),
+    Move(usize, usize),
+    FormCtxUpdate(FormContext),
+    FormCtxDataChange,
+}
+
+#[doc(hidden)]
+pub struct PwtFieldArray {
+    count: usize,
+    form_ctx: Option<FormContext>,
+    _form_ctx_handle: Option<ContextHandle<FormContext>>,
+    _form_ctx_observer: Option<FormContextObserver>,
+    on_form_data_change: Callback<FormContext>,
+}
+
+// Returns the sub-field names (without prefix) of an item.
+fn item_fields(state: &FormContextState, prefix: &str) -> Vec<String> {
+    let prefix = format!("{prefix}.");
+    state
+        .field_names()
+        .filter_map(|name| name.strip_prefix(&prefix).map(String::from))
+        .collect()
+}
+
+impl PwtFieldArray {
+    fn item(props: &FieldArray, index: usize) -> FieldArrayItem {
+        FieldArrayItem {
+            name: props.name.clone(),
+            index,
+        }
+    }
+
+    fn set_count(&mut self, props: &FieldArray, count: usize) {
+        self.count = count;
+        if let Some(on_count_change) = &props.on_count_change {
+            on_count_change.emit(count);
+        }
+    }
+
+    fn swap_values(&self, props: &FieldArray, a: usize, b: usize) {
+        let form_ctx = match &self.form_ctx {
+            Some(form_ctx) => form_ctx,
+            None => return,
+        };
+        let item_a = Self::item(props, a);
+        let item_b = Self::item(props, b);
+
+        let mut state = form_ctx.write();
+        for field in item_fields(&state, &item_a.prefix()) {
+            let name_a = item_a.field_name(&field);
+            let name_b = item_b.field_name(&field);
+            let value_a = state.get_field_value(name_a.clone()).unwrap_or(Value::Null);
+            let value_b = state.get_field_value(name_b.clone()).unwrap_or(Value::Null);
+            state.set_field_value(name_a, value_b);
+            state.set_field_value(name_b, value_a);
+        }
+    }
+
+    // Shift values of all items after `index` one position down.
+    fn shift_values(&self, props: &FieldArray, index: usize) {
+        let form_ctx = match &self.form_ctx {
+            Some(form_ctx) => form_ctx,
+            None => return,
+        };
+
+        let mut state = form_ctx.write();
+        for i in index..self.count.saturating_sub(1) {
+            let item = Self::item(props, i);
+            let next = Self::item(props, i + 1);
+            for field in item_fields(&state, &next.prefix()) {
+                let value = state
+                    .get_field_value(next.field_name(&field))
+                    .unwrap_or(Value::Null);
+                state.set_field_value(item.field_name(&field), value);
+            }
+        }
+    }
+
+    fn is_valid(&self, props: &FieldArray) -> bool {
+        let form_ctx = match &self.form_ctx {
+            Some(form_ctx) => form_ctx,
+            None => return true,
+        };
+        let prefix = format!("{}[", props.name);
+        let state = form_ctx.read();
+        state
+            .field_names()
+            .filter(|name| name.starts_with(&prefix))
+            .all(|name| !matches!(state.get_field_valid(name.clone()), Some(Err(_))))
+    }
+}
+
+impl Component for PwtFieldArray {
+    type Message = Msg;
+    type Properties = FieldArray;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+
+        let on_form_ctx_change = ctx.link().callback(Msg::FormCtxUpdate);
+        let on_form_data_change = ctx.link().callback(|_: FormContext| Msg::FormCtxDataChange);
+
+        let mut _form_ctx_handle = None;
+        let mut _form_ctx_observer = None;
+        let mut form_ctx = None;
+        if let Some((form, handle)) = ctx.link().context::<FormContext>(on_form_ctx_change) {
+            _form_ctx_handle = Some(handle);
+            _form_ctx_observer = Some(form.add_listener(on_form_data_change.clone()));
+            form_ctx = Some(form);
+        }
+
+        let mut count = props.default_count.max(props.min_items);
+        if let Some(max_items) = props.max_items {
+            count = count.min(max_items);
+        }
+
+        Self {
+            count,
+            form_ctx,
+            _form_ctx_handle,
+            _form_ctx_observer,
+            on_form_data_change,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Add => {
+                if props.max_items.map(|max| self.count < max).unwrap_or(true) {
+                    self.set_count(props, self.count + 1);
+                }
+                true
+            }
+            Msg::Remove(index) => {
+                if self.count > props.min_items && index < self.count {
+                    self.shift_values(props, index);
+                    self.set_count(props, self.count - 1);
+                }
+                true
+            }
+            Msg::Move(from, to) => {
+                if from < self.count && to < self.count && from != to {
+                    self.swap_values(props, from, to);
+                }
+                true
+            }
+            Msg::FormCtxUpdate(form_ctx) => {
+                self._form_ctx_observer =
+                    Some(form_ctx.add_listener(self.on_form_data_change.clone()));
+                self.form_ctx = Some(form_ctx);
+                true
+            }
+            Msg::FormCtxDataChange => true,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let can_remove = self.count > props.min_items;
+        let can_add = props.max_items.map(|max| self.count < max).unwrap_or(true);
+
+        let mut column = Column::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-field-array")
+            .class((!self.is_valid(props)).then_some("is-invalid"))
+            .gap(2);
+
+        for index in 0..self.count {
+            let item = Self::item(props, index);
+
+            let mut row = Row::new()
+                .key(item.prefix())
+                .class("pwt-field-array-item")
+                .class(AlignItems::Center)
+                .gap(1)
+                .with_child(
+                    Row::new()
+                        .class(FlexFit)
+                        .with_child(props.render_item.apply(&item)),
+                );
+
+            if props.reorderable {
+                row.add_child(
+                    ActionIcon::new("fa fa-arrow-up")
+                        .aria_label(tr!("Move up"))
+                        .disabled(index == 0)
+                        .on_activate(
+                            link.callback(move |_| Msg::Move(index, index.saturating_sub(1))),
+                        ),
+                );
+                row.add_child(
+                    ActionIcon::new("fa fa-arrow-down")
+                        .aria_label(tr!("Move down"))
+                        .disabled(index + 1 >= self.count)
+                        .on_activate(link.callback(move |_| Msg::Move(index, index + 1))),
+                );
+            }
+
+            row.add_child(
+                ActionIcon::new("fa fa-trash-o")
+                    .aria_label(tr!("Remove"))
+                    .disabled(!can_remove)
+                    .on_activate(link.callback(move |_| Msg::Remove(index))),
+            );
+
+            column.add_child(row);
+        }
+
+        let add_text = props.add_text.clone().unwrap_or(tr!("Add").into());
+
+        column.add_child(
+            Row::new().padding_top(1).with_child(
+                Button::new(add_text)
+                    .icon_class("fa fa-plus")
+                    .disabled(!can_add)
+                    .on_activate(link.callback(|_| Msg::Add)),
+            ),
+        );
+
+        column.into()
+    }
+}
@@ -0,0 +1,143 @@
+use std::rc::Rc;
+
+use derivative::Derivative;
+use serde_json::Value;
+
+use yew::html::IntoPropValue;
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+use crate::props::IntoOptionalKey;
+
+use super::FormContextState;
+
+/// Condition evaluated on the form data (see [FieldRule]).
+#[derive(Derivative)]
+#[derivative(Clone, PartialEq)]
+pub struct FieldCondition(
+    #[derivative(PartialEq(compare_with = "Rc::ptr_eq"))] Rc<dyn Fn(&FormContextState) -> bool>,
+);
+
+impl FieldCondition {
+    /// Create a new instance.
+    pub fn new(condition: impl 'static + Fn(&FormContextState) -> bool) -> Self {
+        Self(Rc::new(condition))
+    }
+
+    /// Evaluate the condition.
+    pub fn apply(&self, form_state: &FormContextState) -> bool {
+        (self.0)(form_state)
+    }
+
+    /// True if the value of field `name` is equal to `value`.
+    pub fn field_equals(name: impl IntoPropValue<AttrValue>, value: impl Into<Value>) -> Self {
+        let name = name.into_prop_value();
+        let value = value.into();
+        Self::new(move |form_state| form_state.get_field_value(&name).as_ref() == Some(&value))
+    }
+
+    /// True if field `name` has a non-empty value (checked for checkboxes).
+    pub fn field_not_empty(name: impl IntoPropValue<AttrValue>) -> Self {
+        let name = name.into_prop_value();
+        Self::new(move |form_state| match form_state.get_field_value(&name) {
+            None | Some(Value::Null) => false,
+            Some(Value::Bool(checked)) => checked,
+            Some(Value::String(text)) => !text.trim().is_empty(),
+            Some(Value::Array(list)) => !list.is_empty(),
+            Some(_) => true,
+        })
+    }
+
+    /// Negate the condition.
+    pub fn not(self) -> Self {
+        Self::new(move |form_state| !self.apply(form_state))
+    }
+}
+
+impl<F: 'static + Fn(&FormContextState) -> bool> From<F> for FieldCondition {
+    fn from(condition: F) -> Self {
+        Self::new(condition)
+    }
+}
+
+/// Declarative field dependency, re-evaluated on each form change.
+///
+/// Enables the target field or makes it required, depending on other
+/// field values. Rules are added with [FormContext::add_field_rule](super::FormContext::add_field_rule)
+/// (or [Form::field_rule](super::Form::field_rule)), and apply to all
+/// fields with the target name, in addition to the field properties.
+/// Fields disabled by a rule are not submitted and not validated, and
+/// required fields are invalid if empty.
+///
+/// Rules are compared by field name and [key](Self::key), not by their
+/// conditions, so it is safe to create them inside `view()`. Use a
+/// different key if you need to replace the conditions of a rule.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::{FieldCondition, FieldRule, Form, FormContextState};
+/// Form::new()
+///     .field_rule(
+///         FieldRule::new("port").enabled_if(FieldCondition::field_equals("mode", "custom")),
+///     )
+///     .field_rule(
+///         FieldRule::new("comment")
+///             .required_if(|form: &FormContextState| form.get_field_checked("notify")),
+///     );
+/// ```
+#[derive(Clone)]
+pub struct FieldRule {
+    /// Target field name.
+    pub name: AttrValue,
+    /// Optional key, to distinguish rules for the same field.
+    pub key: Option<Key>,
+    /// The field is disabled if this condition is false.
+    pub enabled: Option<FieldCondition>,
+    /// The field is required if this condition is true.
+    pub required: Option<FieldCondition>,
+}
+
+impl FieldRule {
+    /// Create a new instance for field `name`.
+    pub fn new(name: impl IntoPropValue<AttrValue>) -> Self {
+        Self {
+            name: name.into_prop_value(),
+            key: None,
+            enabled: None,
+            required: None,
+        }
+    }
+
+    /// Builder style method to set the rule key.
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+
+    /// Builder style method to set the enabled condition.
+    pub fn enabled_if(mut self, condition: impl Into<FieldCondition>) -> Self {
+        self.enabled = Some(condition.into());
+        self
+    }
+
+    /// Builder style method to set the required condition.
+    pub fn required_if(mut self, condition: impl Into<FieldCondition>) -> Self {
+        self.required = Some(condition.into());
+        self
+    }
+}
+
+impl PartialEq for FieldRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.key == other.key
+    }
+}
+
+/// Field state computed from [FieldRule]s.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FieldRuleState {
+    /// Disabled by a rule.
+    pub disabled: bool,
+    /// Required by a rule.
+    pub required: bool,
+}
@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 
+use gloo_timers::callback::Timeout;
 use yew::prelude::*;
 use yew::virtual_dom::ApplyAttributeAs;
 use yew::virtual_dom::{Listeners, VList, VNode, VTag};
 
-use super::FormContext;
+use super::{FieldRule, FormContext, FormContextObserver};
 
 use pwt_macros::widget;
 
@@ -14,11 +15,26 @@ use pwt_macros::widget;
 /// fields do validation themselves.
 ///
 /// The form creates an empty [FormContext] if you do not provide one.
+///
+/// With [autofocus](Self::autofocus) set, the form focuses its first field
+/// (see [FormContext::focus_first_field]) when it gets rendered. If there is
+/// no focusable field yet (i.e. while the data gets loaded asynchronously),
+/// this is retried whenever the form context changes.
+///
+/// Field dependencies can be declared with [field_rule](Self::field_rule).
 #[widget(pwt=crate, comp=PwtForm, @element, @container)]
 #[derive(Clone, PartialEq, Properties)]
 pub struct Form {
     #[prop_or_default]
     pub form_context: Option<FormContext>,
+
+    /// Focus the first field when the form gets rendered.
+    #[prop_or_default]
+    pub autofocus: bool,
+
+    /// Field dependency rules (added to the form context).
+    #[prop_or_default]
+    pub field_rules: Vec<FieldRule>,
 }
 
 impl Default for Form {
@@ -41,16 +57,53 @@ impl Form {
     pub fn set_form_context(&mut self, form_context: FormContext) {
         self.form_context = Some(form_context);
     }
+
+    /// Builder style method to set the autofocus flag.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.set_autofocus(autofocus);
+        self
+    }
+
+    /// Method to set the autofocus flag.
+    pub fn set_autofocus(&mut self, autofocus: bool) {
+        self.autofocus = autofocus;
+    }
+
+    /// Builder style method to add a field dependency rule.
+    pub fn field_rule(mut self, rule: FieldRule) -> Self {
+        self.add_field_rule(rule);
+        self
+    }
+
+    /// Method to add a field dependency rule.
+    pub fn add_field_rule(&mut self, rule: FieldRule) {
+        self.field_rules.push(rule);
+    }
 }
 
 #[doc(hidden)]
 pub enum Msg {
     Update,
+    Autofocus,
 }
 
 #[doc(hidden)]
 pub struct PwtForm {
     form_ctx: FormContext,
+    autofocus_pending: bool,
+    autofocus_timeout: Option<Timeout>,
+    _autofocus_observer: Option<FormContextObserver>,
+}
+
+impl PwtForm {
+    fn schedule_autofocus(&mut self, ctx: &Context<Self>) {
+        // Delay, so that we run after the focus handling of a surrounding
+        // dialog (showModal() focuses the first focusable element).
+        let link = ctx.link().clone();
+        self.autofocus_timeout = Some(Timeout::new(1, move || {
+            link.send_message(Msg::Autofocus);
+        }));
+    }
 }
 
 impl Component for PwtForm {
@@ -65,16 +118,61 @@ impl Component for PwtForm {
             None => FormContext::new(),
         };
 
-        Self { form_ctx }
+        for rule in props.field_rules.iter() {
+            form_ctx.add_field_rule(rule.clone());
+        }
+
+        Self {
+            form_ctx,
+            autofocus_pending: props.autofocus,
+            autofocus_timeout: None,
+            _autofocus_observer: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Update => true,
+            Msg::Autofocus => {
+                if self.autofocus_pending && self.form_ctx.focus_first_field() {
+                    self.autofocus_pending = false;
+                    self._autofocus_observer = None;
+                } else if self.autofocus_pending && self._autofocus_observer.is_none() {
+                    // retry when fields get registered or enabled
+                    let link = ctx.link().clone();
+                    self._autofocus_observer = Some(self.form_ctx.add_listener(move |_| {
+                        link.send_message(Msg::Autofocus);
+                    }));
+                }
+                false
+            }
+        }
     }
 
     fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
         let props = ctx.props();
+        let old_form_ctx = self.form_ctx.clone();
         if props.form_context != old_props.form_context {
             if let Some(form_ctx) = &props.form_context {
                 self.form_ctx = form_ctx.clone();
+                self._autofocus_observer = None;
+            }
+        }
+        if self.form_ctx != old_form_ctx || props.field_rules != old_props.field_rules {
+            let mut state = old_form_ctx.write();
+            for rule in old_props.field_rules.iter() {
+                state.remove_field_rule(rule);
+            }
+            drop(state);
+            let mut state = self.form_ctx.write();
+            for rule in props.field_rules.iter() {
+                state.add_field_rule(rule.clone());
             }
         }
+        if props.autofocus && !old_props.autofocus {
+            self.autofocus_pending = true;
+            self.autofocus_timeout = None;
+        }
         true
     }
 
@@ -107,4 +205,10 @@ impl Component for PwtForm {
             <ContextProvider<FormContext> context={form_ctx}>{form}</ContextProvider<FormContext>>
         }
     }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if self.autofocus_pending && self.autofocus_timeout.is_none() {
+            self.schedule_autofocus(ctx);
+        }
+    }
 }
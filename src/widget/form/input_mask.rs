@@ -0,0 +1,201 @@
+use std::fmt;
+
+use yew::AttrValue;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MaskToken {
+    Digit,
+    Letter,
+    Alphanumeric,
+    HexDigit,
+    Literal(char),
+}
+
+impl MaskToken {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            MaskToken::Digit => c.is_ascii_digit(),
+            MaskToken::Letter => c.is_alphabetic(),
+            MaskToken::Alphanumeric => c.is_alphanumeric(),
+            MaskToken::HexDigit => c.is_ascii_hexdigit(),
+            MaskToken::Literal(literal) => *literal == c,
+        }
+    }
+}
+
+/// Input mask for [Field::mask](super::Field::mask).
+///
+/// The mask pattern uses the following placeholders:
+///
+/// - `9`: a digit (`0-9`)
+/// - `a`: a letter
+/// - `*`: a letter or digit
+/// - `X`: a hexadecimal digit (`0-9`, `a-f`, `A-F`)
+///
+/// All other characters are literals, which are inserted automatically.
+/// Use `\` to escape placeholder characters (i.e. `\9`).
+///
+/// ```
+/// # use pwt::widget::form::InputMask;
+/// let mask = InputMask::new("XX:XX:XX:XX:XX:XX");
+/// assert_eq!(mask.apply("aabbccddeeff"), "aa:bb:cc:dd:ee:ff");
+/// assert_eq!(mask.apply("AA-BB-CC-DD-EE-FF"), "AA:BB:CC:DD:EE:FF");
+/// assert!(mask.matches("AA:BB:CC:DD:EE:FF"));
+/// assert!(!mask.matches("AA:BB:CC"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputMask {
+    pattern: AttrValue,
+    tokens: Vec<MaskToken>,
+}
+
+impl InputMask {
+    /// Create a new instance from the mask pattern.
+    pub fn new(pattern: impl Into<AttrValue>) -> Self {
+        let pattern = pattern.into();
+        let mut tokens = Vec::new();
+
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '9' => MaskToken::Digit,
+                'a' => MaskToken::Letter,
+                '*' => MaskToken::Alphanumeric,
+                'X' => MaskToken::HexDigit,
+                '\\' => match chars.next() {
+                    Some(c) => MaskToken::Literal(c),
+                    None => MaskToken::Literal('\\'),
+                },
+                c => MaskToken::Literal(c),
+            };
+            tokens.push(token);
+        }
+
+        Self { pattern, tokens }
+    }
+
+    /// Returns the mask pattern.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Fit the input text into the mask.
+    ///
+    /// Inserts missing literals before the next accepted character and
+    /// drops characters which do not fit (i.e. other separators in
+    /// pasted text). Input beyond the end of the mask is ignored.
+    ///
+    /// Literals are only inserted in front of following input, so a prefix
+    /// of the input maps to a prefix of the result (used to keep the
+    /// cursor position stable).
+    pub fn apply(&self, input: &str) -> String {
+        let mut result = String::new();
+        let mut pos = 0;
+
+        for c in input.chars() {
+            // find the first literal matching `c`, or the next placeholder
+            let mut next = pos;
+            while let Some(MaskToken::Literal(literal)) = self.tokens.get(next) {
+                if *literal == c {
+                    break;
+                }
+                next += 1;
+            }
+
+            match self.tokens.get(next) {
+                Some(token) if token.accepts(c) => {
+                    for token in &self.tokens[pos..next] {
+                        if let MaskToken::Literal(literal) = token {
+                            result.push(*literal);
+                        }
+                    }
+                    result.push(c);
+                    pos = next + 1;
+                }
+                _ => { /* drop characters which do not fit */ }
+            }
+        }
+
+        result
+    }
+
+    /// Returns true if the text completely matches the mask.
+    pub fn matches(&self, text: &str) -> bool {
+        text.chars().count() == self.tokens.len()
+            && text
+                .chars()
+                .zip(self.tokens.iter())
+                .all(|(c, token)| token.accepts(c))
+    }
+}
+
+impl fmt::Display for InputMask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl From<&str> for InputMask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern.to_string())
+    }
+}
+
+impl From<String> for InputMask {
+    fn from(pattern: String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<AttrValue> for InputMask {
+    fn from(pattern: AttrValue) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InputMask;
+
+    #[test]
+    fn test_apply() {
+        let mask = InputMask::new("XX:XX:XX:XX:XX:XX");
+
+        assert_eq!(mask.apply(""), "");
+        assert_eq!(mask.apply("a"), "a");
+        assert_eq!(mask.apply("aa"), "aa");
+        assert_eq!(mask.apply("aab"), "aa:b");
+        assert_eq!(mask.apply("aa:"), "aa:");
+        assert_eq!(mask.apply("aa::b"), "aa:b");
+        assert_eq!(mask.apply("aag"), "aa");
+        assert_eq!(mask.apply("aabbccddeeff00"), "aa:bb:cc:dd:ee:ff");
+        assert_eq!(mask.apply(" AA-BB-CC-DD-EE-FF "), "AA:BB:CC:DD:EE:FF");
+        assert_eq!(mask.apply("AA:BB:CC:DD:EE:FF"), "AA:BB:CC:DD:EE:FF");
+
+        let mask = InputMask::new("(999) 999-9999");
+        assert_eq!(mask.apply("1"), "(1");
+        assert_eq!(mask.apply("(1"), "(1");
+        assert_eq!(mask.apply("1234"), "(123) 4");
+        assert_eq!(mask.apply("123 4"), "(123) 4");
+        assert_eq!(mask.apply("+1 (234) 567-8901"), "(123) 456-7890");
+
+        let mask = InputMask::new(r"SN-\9aa-9999");
+        assert_eq!(mask.apply("xy1234"), "SN-9xy-1234");
+        assert_eq!(mask.apply("12"), "");
+    }
+
+    #[test]
+    fn test_matches() {
+        let mask = InputMask::new("XX:XX:XX:XX:XX:XX");
+        assert!(mask.matches("aa:bb:cc:dd:ee:ff"));
+        assert!(!mask.matches("aa:bb:cc:dd:ee:f"));
+        assert!(!mask.matches("aa:bb:cc:dd:ee:fff"));
+        assert!(!mask.matches("aa-bb-cc-dd-ee-ff"));
+        assert!(!mask.matches("gg:bb:cc:dd:ee:ff"));
+
+        let mask = InputMask::new(r"SN-\9aa-9999");
+        assert!(mask.matches("SN-9xy-1234"));
+        assert!(!mask.matches("SN-1xy-1234"));
+        assert_eq!(mask.pattern(), r"SN-\9aa-9999");
+    }
+}
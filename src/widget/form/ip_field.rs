@@ -0,0 +1,519 @@
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use anyhow::{Error, bail};
+use serde_json::Value;
+
+use web_sys::HtmlInputElement;
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::prelude::*;
+
+use pwt_macros::{builder, widget};
+
+use super::{
+    IntoValidateFn, ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt,
+    ManagedFieldState, ValidateFn,
+};
+use crate::css::{FontColor, FontStyle};
+use crate::props::{ContainerBuilder, EventSubscriber, WidgetBuilder};
+use crate::widget::{Column, Container, Input, Tooltip};
+
+use crate::tr;
+
+pub type PwtIpField = ManagedFieldMaster<IpFieldComp>;
+
+/// IP address with optional CIDR prefix length (i.e. `"192.168.0.1/24"`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IpCidr {
+    /// The IP address.
+    pub address: IpAddr,
+    /// The prefix length (network mask).
+    pub prefix: Option<u8>,
+}
+
+impl IpCidr {
+    /// Returns the maximum prefix length (32 for IPv4, 128 for IPv6).
+    pub fn max_prefix(&self) -> u8 {
+        match self.address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+
+    /// Returns the network address (`None` without prefix).
+    pub fn network(&self) -> Option<IpAddr> {
+        let prefix = self.prefix?;
+        Some(match self.address {
+            IpAddr::V4(address) => {
+                let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(u32::from(address) & mask))
+            }
+            IpAddr::V6(address) => {
+                let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(u128::from(address) & mask))
+            }
+        })
+    }
+
+    /// Returns the IPv4 network mask (i.e. `255.255.255.0`).
+    pub fn netmask(&self) -> Option<Ipv4Addr> {
+        match (self.address, self.prefix) {
+            (IpAddr::V4(_), Some(prefix)) => Some(Ipv4Addr::from(
+                u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns the IPv4 broadcast address.
+    ///
+    /// Not available for IPv6, and for `/31` and `/32` networks.
+    pub fn broadcast(&self) -> Option<Ipv4Addr> {
+        match (self.address, self.netmask()) {
+            (IpAddr::V4(address), Some(mask)) if self.prefix < Some(31) => {
+                Some(Ipv4Addr::from(u32::from(address) | !u32::from(mask)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for IpCidr {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Error> {
+        let text = text.trim();
+        let (address, prefix) = match text.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (text, None),
+        };
+
+        let address: IpAddr = match address.parse() {
+            Ok(address) => address,
+            Err(_) => bail!(tr!("invalid IP address")),
+        };
+
+        let mut cidr = IpCidr {
+            address,
+            prefix: None,
+        };
+
+        if let Some(prefix) = prefix {
+            match prefix.parse::<u8>() {
+                Ok(prefix) if prefix <= cidr.max_prefix() => cidr.prefix = Some(prefix),
+                _ => bail!(tr!(
+                    "invalid prefix length (expected 0 - {0})",
+                    cidr.max_prefix()
+                )),
+            }
+        }
+
+        Ok(cidr)
+    }
+}
+
+impl fmt::Display for IpCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.prefix {
+            Some(prefix) => write!(f, "{}/{prefix}", self.address),
+            None => write!(f, "{}", self.address),
+        }
+    }
+}
+
+/// Accepted IP address versions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IpVersion {
+    /// IPv4 and IPv6 (default).
+    #[default]
+    Any,
+    /// IPv4 only.
+    V4,
+    /// IPv6 only.
+    V6,
+}
+
+/// CIDR prefix length handling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CidrMask {
+    /// Plain addresses, without prefix length (default).
+    #[default]
+    None,
+    /// The prefix length is optional.
+    Optional,
+    /// The prefix length is required.
+    Required,
+}
+
+/// IPv4/IPv6 address input with optional CIDR prefix length.
+///
+/// The input is validated while typing. If `show_subnet_info` is set,
+/// the network and broadcast address (IPv4 only) are displayed below
+/// the input.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::{CidrMask, IpField, IpVersion};
+/// # fn test() {
+/// let cidr = IpField::new()
+///     .name("cidr")
+///     .version(IpVersion::V4)
+///     .mask(CidrMask::Required)
+///     .show_subnet_info(true);
+/// let gateway = IpField::new().name("gateway").version(IpVersion::V4);
+/// # }
+/// ```
+#[widget(pwt=crate, comp=ManagedFieldMaster<IpFieldComp>, @input, @element)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct IpField {
+    /// Accepted IP versions.
+    #[builder]
+    #[prop_or_default]
+    pub version: IpVersion,
+
+    /// CIDR prefix length handling.
+    #[builder]
+    #[prop_or_default]
+    pub mask: CidrMask,
+
+    /// Display network and broadcast address.
+    #[builder]
+    #[prop_or_default]
+    pub show_subnet_info: bool,
+
+    /// Force value.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+
+    /// Force validation result.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub valid: Option<Result<Value, String>>,
+
+    /// Default value.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub default: Option<AttrValue>,
+
+    /// Validation function.
+    #[prop_or_default]
+    pub validate: Option<ValidateFn<IpCidr>>,
+
+    /// Change callback
+    ///
+    /// This callback is emited on any data change, i.e. if data
+    /// inside the [FormContext](super::FormContext) changed.
+    #[builder_cb(IntoEventCallback, into_event_callback, Option<Result<IpCidr, String>>)]
+    #[prop_or_default]
+    pub on_change: Option<Callback<Option<Result<IpCidr, String>>>>,
+}
+
+impl Default for IpField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IpField {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    /// Builder style method to set the validate callback
+    pub fn validate(mut self, validate: impl IntoValidateFn<IpCidr>) -> Self {
+        self.set_validate(validate);
+        self
+    }
+
+    /// Method to set the validate callback
+    pub fn set_validate(&mut self, validate: impl IntoValidateFn<IpCidr>) {
+        self.validate = validate.into_validate_fn();
+    }
+}
+
+pub enum Msg {
+    Update(String),
+}
+
+#[doc(hidden)]
+pub struct IpFieldComp {
+    state: ManagedFieldState,
+}
+
+impl std::ops::Deref for IpFieldComp {
+    type Target = ManagedFieldState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl std::ops::DerefMut for IpFieldComp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.state
+    }
+}
+
+#[derive(PartialEq)]
+pub struct ValidateClosure {
+    required: bool,
+    version: IpVersion,
+    mask: CidrMask,
+    validate: Option<ValidateFn<IpCidr>>,
+}
+
+fn parse_ip(props: &ValidateClosure, text: &str) -> Result<IpCidr, Error> {
+    let cidr: IpCidr = text.parse()?;
+
+    match (props.version, cidr.address) {
+        (IpVersion::V4, IpAddr::V6(_)) => bail!(tr!("expected an IPv4 address")),
+        (IpVersion::V6, IpAddr::V4(_)) => bail!(tr!("expected an IPv6 address")),
+        _ => {}
+    }
+
+    match (props.mask, cidr.prefix) {
+        (CidrMask::None, Some(_)) => bail!(tr!("prefix length not allowed")),
+        (CidrMask::Required, None) => bail!(tr!("missing prefix length")),
+        _ => {}
+    }
+
+    Ok(cidr)
+}
+
+fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        _ => String::new(),
+    }
+}
+
+impl ManagedField for IpFieldComp {
+    type Properties = IpField;
+    type Message = Msg;
+    type ValidateClosure = ValidateClosure;
+
+    fn validation_args(props: &Self::Properties) -> Self::ValidateClosure {
+        ValidateClosure {
+            required: props.input_props.required,
+            version: props.version,
+            mask: props.mask,
+            validate: props.validate.clone(),
+        }
+    }
+
+    fn validator(props: &Self::ValidateClosure, value: &Value) -> Result<Value, Error> {
+        let text = match value {
+            Value::Null => "",
+            Value::String(text) => text.trim(),
+            _ => return Err(Error::msg(tr!("Got wrong data type!"))),
+        };
+
+        if text.is_empty() {
+            if props.required {
+                return Err(Error::msg(tr!("Field may not be empty.")));
+            }
+            return Ok(Value::String(String::new()));
+        }
+
+        let cidr = parse_ip(props, text)?;
+        if let Some(validate) = &props.validate {
+            validate.apply(&cidr)?;
+        }
+        Ok(Value::String(text.to_string()))
+    }
+
+    fn create(ctx: &ManagedFieldContext<Self>) -> Self {
+        let props = ctx.props();
+
+        let default: Value = props.default.as_deref().unwrap_or("").into();
+        let value = match &props.value {
+            Some(value) => value.to_string().into(),
+            None => default.clone(),
+        };
+
+        Self {
+            state: ManagedFieldState::new(value, default),
+        }
+    }
+
+    fn value_changed(&mut self, ctx: &ManagedFieldContext<Self>) {
+        let props = ctx.props();
+        let data = match &self.result {
+            Ok(Value::String(text)) if text.is_empty() => None,
+            Ok(value) => Some(
+                value_to_text(value)
+                    .parse::<IpCidr>()
+                    .map_err(|err| err.to_string()),
+            ),
+            Err(err) => Some(Err(err.clone())),
+        };
+        if let Some(on_change) = &props.on_change {
+            on_change.emit(data);
+        }
+    }
+
+    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value || props.valid != old_props.valid {
+            ctx.link().force_value(
+                props.value.as_ref().map(|v| v.to_string()),
+                props.valid.clone(),
+            );
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &ManagedFieldContext<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Update(input) => {
+                ctx.link().update_value(input);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+        let value_text = value_to_text(&self.value);
+
+        let oninput = ctx.link().callback(|input: InputEvent| {
+            let input: HtmlInputElement = input.target_unchecked_into();
+            Msg::Update(input.value())
+        });
+
+        let disabled = self.is_disabled(props);
+        let input: Html = Input::new()
+            .with_input_props(&props.input_props)
+            .disabled(disabled)
+            .class(crate::css::Flex::Fill)
+            .attribute("type", "text")
+            .attribute("value", value_text.clone())
+            .attribute("autocomplete", "off")
+            .attribute("spellcheck", "false")
+            .oninput((!disabled).then_some(oninput))
+            .onfocus(ctx.link().focus_callback(true))
+            .onblur(ctx.link().focus_callback(false))
+            .into();
+
+        let mut input_container = Tooltip::empty()
+            .class("pwt-input")
+            .class("pwt-input-type-ip")
+            .class(crate::css::FullWidth)
+            .class(disabled.then_some("disabled"))
+            .class(if self.result.is_ok() {
+                "is-valid"
+            } else {
+                "is-invalid"
+            })
+            .with_child(input);
+
+        if let Err(msg) = &self.result {
+            input_container.set_tip(msg.clone())
+        }
+
+        let subnet_info = match value_text.parse::<IpCidr>() {
+            Ok(cidr) if props.show_subnet_info && self.result.is_ok() => {
+                cidr.network().map(|network| {
+                    let mut text = tr!("Network: {0}", network);
+                    if let Some(broadcast) = cidr.broadcast() {
+                        text += &format!(", {}", tr!("Broadcast: {0}", broadcast));
+                    }
+                    Container::new()
+                        .class("pwt-ip-field-subnet-info")
+                        .class(FontStyle::BodySmall)
+                        .class(FontColor::NeutralAlt)
+                        .with_child(text)
+                })
+            }
+            _ => None,
+        };
+
+        Column::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-ip-field")
+            .gap(1)
+            .with_child(input_container)
+            .with_optional_child(subnet_info)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::{CidrMask, IpCidr, IpVersion, ValidateClosure, parse_ip};
+
+    #[test]
+    fn test_parse() {
+        let cidr: IpCidr = "192.168.1.10/24".parse().unwrap();
+        assert_eq!(cidr.address, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)));
+        assert_eq!(cidr.prefix, Some(24));
+        assert_eq!(cidr.to_string(), "192.168.1.10/24");
+
+        let cidr: IpCidr = " fd00::1/64 ".parse().unwrap();
+        assert_eq!(cidr.prefix, Some(64));
+        assert_eq!(cidr.to_string(), "fd00::1/64");
+
+        assert!("192.168.1.10".parse::<IpCidr>().unwrap().prefix.is_none());
+        assert!("192.168.1.256".parse::<IpCidr>().is_err());
+        assert!("192.168.1.1/33".parse::<IpCidr>().is_err());
+        assert!("192.168.1.1/".parse::<IpCidr>().is_err());
+        assert!("fd00::1/129".parse::<IpCidr>().is_err());
+    }
+
+    #[test]
+    fn test_subnet() {
+        let cidr: IpCidr = "192.168.1.10/24".parse().unwrap();
+        assert_eq!(cidr.network().unwrap().to_string(), "192.168.1.0");
+        assert_eq!(cidr.netmask().unwrap().to_string(), "255.255.255.0");
+        assert_eq!(cidr.broadcast().unwrap().to_string(), "192.168.1.255");
+
+        let cidr: IpCidr = "10.1.2.3/0".parse().unwrap();
+        assert_eq!(cidr.network().unwrap().to_string(), "0.0.0.0");
+        assert_eq!(cidr.broadcast().unwrap().to_string(), "255.255.255.255");
+
+        let cidr: IpCidr = "10.1.2.3/31".parse().unwrap();
+        assert_eq!(cidr.network().unwrap().to_string(), "10.1.2.2");
+        assert!(cidr.broadcast().is_none());
+
+        let cidr: IpCidr = "fd00:1:2:3::1/48".parse().unwrap();
+        assert_eq!(cidr.network().unwrap().to_string(), "fd00:1:2::");
+        assert!(cidr.broadcast().is_none());
+
+        let cidr: IpCidr = "10.1.2.3".parse().unwrap();
+        assert!(cidr.network().is_none());
+    }
+
+    #[test]
+    fn test_field_constraints() {
+        let props = ValidateClosure {
+            required: false,
+            version: IpVersion::V4,
+            mask: CidrMask::Required,
+            validate: None,
+        };
+        assert!(parse_ip(&props, "10.0.0.0/8").is_ok());
+        assert!(parse_ip(&props, "10.0.0.1").is_err());
+        assert!(parse_ip(&props, "fd00::/8").is_err());
+
+        let props = ValidateClosure {
+            mask: CidrMask::None,
+            version: IpVersion::Any,
+            ..props
+        };
+        assert!(parse_ip(&props, "fd00::1").is_ok());
+        assert!(parse_ip(&props, "10.0.0.0/8").is_err());
+    }
+}
@@ -1,14 +1,17 @@
 use std::ops::DerefMut;
 
 use anyhow::Error;
+use gloo_timers::callback::Timeout;
 use serde_json::Value;
 use wasm_bindgen::{JsCast, closure::Closure};
 
 use yew::html::Scope;
 use yew::prelude::*;
 
+use super::advanced_options::AdvancedFieldScope;
 use super::{FieldHandle, FieldOptions, FormContext, FormContextObserver, SubmitValidateFn};
 use crate::props::FieldBuilder;
+use crate::widget::DisabledContext;
 
 pub type ManagedFieldContext<MF> = Context<ManagedFieldMaster<MF>>;
 pub type ManagedFieldLink<MF> = Scope<ManagedFieldMaster<MF>>;
@@ -52,12 +55,28 @@ pub struct ManagedFieldState {
     /// This is mutually exclusive with `radio_group`.
     pub unique: bool,
 
+    /// Set when the field is disabled by a parent
+    /// [DisabledContainer](crate::widget::DisabledContainer).
+    pub context_disabled: bool,
+
+    /// Set when the field is disabled by a [FieldRule](super::FieldRule).
+    pub rule_disabled: bool,
+
+    /// Set while the field has the focus (see [ManagedFieldScopeExt::focus_callback]).
+    pub has_focus: bool,
+
     form_ctx: Option<FormContext>,
     field_handle: Option<FieldHandle>,
     _form_ctx_handle: Option<ContextHandle<FormContext>>,
     _form_ctx_observer: Option<FormContextObserver>,
+    _disabled_ctx_handle: Option<ContextHandle<DisabledContext>>,
+    advanced_scope: Option<AdvancedFieldScope>,
     label_clicked_closure: Option<Closure<dyn Fn()>>,
 
+    // Focus the field as soon as it is rendered and enabled (autofocus).
+    autofocus_pending: bool,
+    autofocus_timeout: Option<Timeout>,
+
     /// The validation function
     validate: SubmitValidateFn<Value>,
 }
@@ -72,14 +91,28 @@ impl ManagedFieldState {
             default,
             value,
 
+            context_disabled: false,
+            rule_disabled: false,
+            has_focus: false,
             form_ctx: None,
             field_handle: None,
             _form_ctx_handle: None,
             _form_ctx_observer: None,
+            _disabled_ctx_handle: None,
+            advanced_scope: None,
             label_clicked_closure: None,
+            autofocus_pending: false,
+            autofocus_timeout: None,
             validate: SubmitValidateFn::new(|v: &Value| Ok(v.clone())),
         }
     }
+
+    /// Returns true if the field is disabled, either by its properties,
+    /// by a parent [DisabledContainer](crate::widget::DisabledContainer)
+    /// or by a [FieldRule](super::FieldRule).
+    pub fn is_disabled(&self, props: &impl FieldBuilder) -> bool {
+        props.is_disabled() || self.context_disabled || self.rule_disabled
+    }
 }
 
 pub trait ManagedFieldScopeExt<M: ManagedField> {
@@ -109,6 +142,14 @@ pub trait ManagedFieldScopeExt<M: ManagedField> {
 
     /// Trigger re-validation
     fn validate(&self);
+
+    /// Returns a callback to notify the field about focus changes.
+    ///
+    /// Fields should attach this to the `onfocus` (`has_focus = true`) and
+    /// `onblur` (`has_focus = false`) listeners of their input element. This
+    /// updates [ManagedFieldState::has_focus] and calls the
+    /// [on_focus](ManagedField::on_focus) and [on_blur](ManagedField::on_blur) hooks.
+    fn focus_callback(&self, has_focus: bool) -> Callback<FocusEvent>;
 }
 
 impl<M: ManagedField> ManagedFieldScopeExt<M> for Scope<ManagedFieldMaster<M>> {
@@ -131,6 +172,9 @@ impl<M: ManagedField> ManagedFieldScopeExt<M> for Scope<ManagedFieldMaster<M>> {
     fn validate(&self) {
         self.send_message(Msg::Validate);
     }
+    fn focus_callback(&self, has_focus: bool) -> Callback<FocusEvent> {
+        self.callback(move |_| Msg::FocusChange(has_focus))
+    }
 }
 
 /// Trait to simplify implementing managed fields.
@@ -181,6 +225,32 @@ pub trait ManagedField: Sized + DerefMut<Target = ManagedFieldState> + 'static {
     /// This is called whenever the managed value (or validity) changes.
     fn value_changed(&mut self, _ctx: &ManagedFieldContext<Self>) {}
 
+    /// This is called when the value was modified by the [FormContext] (i.e. on form
+    /// load or reset), after [value_changed](Self::value_changed).
+    ///
+    /// Fields can use this to reformat their display text.
+    fn value_changed_externally(&mut self, _ctx: &ManagedFieldContext<Self>) {}
+
+    /// This is called when the field gets the focus.
+    ///
+    /// Requires that the field uses [ManagedFieldScopeExt::focus_callback].
+    fn on_focus(&mut self, _ctx: &ManagedFieldContext<Self>) -> bool {
+        false
+    }
+
+    /// This is called when the field loses the focus.
+    ///
+    /// Requires that the field uses [ManagedFieldScopeExt::focus_callback].
+    fn on_blur(&mut self, _ctx: &ManagedFieldContext<Self>) -> bool {
+        false
+    }
+
+    /// Focus the field input element.
+    ///
+    /// This is called by [FieldHandle::focus] and [FormContext::focus_field]. The
+    /// default implementation does nothing.
+    fn focus(&mut self, _ctx: &ManagedFieldContext<Self>) {}
+
     /// This is called when the associated label is clicked.
     fn label_clicked(&mut self, _ctx: &ManagedFieldContext<Self>) -> bool {
         false
@@ -204,9 +274,12 @@ pub enum Msg<M> {
     ForceValue(Option<Value>, Option<Result<Value, String>>),
     ChildMessage(M),
     Validate,
-    LabelClicked,               // Associated label was clicked
-    FormCtxUpdate(FormContext), // FormContext object changed
-    FormCtxDataChange,          // Data inside FormContext changed
+    LabelClicked,                       // Associated label was clicked
+    Focus,                              // Imperative focus request (FieldHandle)
+    FocusChange(bool),                  // Field got/lost the focus
+    FormCtxUpdate(FormContext),         // FormContext object changed
+    FormCtxDataChange,                  // Data inside FormContext changed
+    DisabledCtxUpdate(DisabledContext), // DisabledContext changed
 }
 
 impl<CM> From<CM> for Msg<CM> {
@@ -285,9 +358,10 @@ impl<MF: ManagedField + 'static> ManagedFieldMaster<MF> {
             submit_empty: input_props.submit_empty,
             disabled: input_props.disabled,
             required: input_props.required,
+            tab_order: input_props.tab_order,
         };
 
-        let field_handle = form_ctx.register_field(
+        let mut field_handle = form_ctx.register_field(
             name,
             self.state.value.clone(),
             self.state.default.clone(),
@@ -304,7 +378,37 @@ impl<MF: ManagedField + 'static> ManagedFieldMaster<MF> {
         self.state.result = result;
         self.state.last_valid = last_valid;
 
+        field_handle.set_focus_callback(ctx.link().callback(|_| Msg::Focus));
+
         self.state.field_handle = Some(field_handle);
+        self.update_rule_disabled(ctx);
+        self.update_advanced_scope(ctx);
+    }
+
+    // Announce the field to a parent AdvancedOptions section.
+    fn update_advanced_scope(&self, ctx: &Context<Self>) {
+        if self.state.field_handle.is_none() {
+            return;
+        }
+        if let (Some(scope), Some(name)) = (
+            &self.state.advanced_scope,
+            &ctx.props().as_input_props().name,
+        ) {
+            scope.update(name, self.state.context_disabled);
+        }
+    }
+
+    // Returns true if the flag changed.
+    fn update_rule_disabled(&mut self, ctx: &Context<Self>) -> bool {
+        let rule_disabled = match (&self.state.form_ctx, &ctx.props().as_input_props().name) {
+            (Some(form_ctx), Some(name)) if self.state.field_handle.is_some() => {
+                form_ctx.read().get_field_rule_state(name).disabled
+            }
+            _ => false,
+        };
+        let changed = self.state.rule_disabled != rule_disabled;
+        self.state.rule_disabled = rule_disabled;
+        changed
     }
 }
 
@@ -330,6 +434,8 @@ impl<MF: ManagedField + 'static> Component for ManagedFieldMaster<MF> {
 
         let input_props = props.as_input_props();
 
+        state.autofocus_pending = input_props.autofocus;
+
         let on_form_ctx_change = ctx.link().callback(Msg::FormCtxUpdate);
 
         let on_form_data_change = ctx.link().callback(|_| Msg::FormCtxDataChange);
@@ -341,6 +447,18 @@ impl<MF: ManagedField + 'static> Component for ManagedFieldMaster<MF> {
                 state.form_ctx = Some(form);
             }
         }
+
+        let (context_disabled, disabled_ctx_handle) =
+            DisabledContext::subscribe(ctx.link(), ctx.link().callback(Msg::DisabledCtxUpdate));
+        state.context_disabled = context_disabled;
+        state._disabled_ctx_handle = disabled_ctx_handle;
+
+        // the scope is never replaced, so we do not need to keep the handle
+        state.advanced_scope = ctx
+            .link()
+            .context::<AdvancedFieldScope>(Callback::from(|_| {}))
+            .map(|(scope, _handle)| scope);
+
         let mut me = Self { state };
 
         me.register_field(ctx);
@@ -413,11 +531,21 @@ impl<MF: ManagedField + 'static> Component for ManagedFieldMaster<MF> {
                 self.register_field(ctx);
                 true
             }
+            Msg::DisabledCtxUpdate(disabled_ctx) => {
+                let changed = self.state.context_disabled != disabled_ctx.disabled;
+                self.state.context_disabled = disabled_ctx.disabled;
+                if changed {
+                    self.update_advanced_scope(ctx);
+                }
+                changed
+            }
             Msg::FormCtxDataChange => {
                 if self.state.field_handle.is_none() {
                     return false;
                 }
 
+                let rule_changed = self.update_rule_disabled(ctx);
+
                 let (value, result, last_valid) = self.get_field_data();
                 let value_changed = value != self.state.value;
                 let valid_changed = result != self.state.result;
@@ -427,11 +555,29 @@ impl<MF: ManagedField + 'static> Component for ManagedFieldMaster<MF> {
                     self.state.result = result;
                     self.state.last_valid = last_valid;
                     self.state.value_changed(ctx);
+                    if value_changed {
+                        self.state.value_changed_externally(ctx);
+                    }
                 }
 
-                value_changed || valid_changed
+                value_changed || valid_changed || rule_changed
             }
             Msg::LabelClicked => self.state.label_clicked(ctx),
+            Msg::Focus => {
+                MF::focus(&mut self.state, ctx);
+                false
+            }
+            Msg::FocusChange(has_focus) => {
+                if self.state.has_focus == has_focus {
+                    return false;
+                }
+                self.state.has_focus = has_focus;
+                if has_focus {
+                    self.state.on_focus(ctx)
+                } else {
+                    self.state.on_blur(ctx)
+                }
+            }
         }
     }
 
@@ -446,12 +592,14 @@ impl<MF: ManagedField + 'static> Component for ManagedFieldMaster<MF> {
                 || input_props.submit_empty != old_input_props.submit_empty
                 || input_props.disabled != old_input_props.disabled
                 || input_props.required != old_input_props.required
+                || input_props.tab_order != old_input_props.tab_order
             {
                 let options = FieldOptions {
                     submit: input_props.submit,
                     submit_empty: input_props.submit_empty,
                     disabled: input_props.disabled,
                     required: input_props.required,
+                    tab_order: input_props.tab_order,
                 };
                 field_handle.update_field_options(options);
                 refresh1 = true;
@@ -473,6 +621,10 @@ impl<MF: ManagedField + 'static> Component for ManagedFieldMaster<MF> {
             }
         }
 
+        if props.as_input_props().autofocus && !old_props.as_input_props().autofocus {
+            self.state.autofocus_pending = true;
+        }
+
         let refresh2 = self.state.changed(ctx, old_props);
 
         refresh1 || refresh2
@@ -505,6 +657,25 @@ impl<MF: ManagedField + 'static> Component for ManagedFieldMaster<MF> {
             }
         }
 
+        if self.state.autofocus_pending && !self.state.is_disabled(ctx.props()) {
+            self.state.autofocus_pending = false;
+            // Delay, so that we run after the focus handling of a surrounding
+            // dialog (showModal() focuses the first focusable element).
+            let link = ctx.link().clone();
+            self.state.autofocus_timeout = Some(Timeout::new(1, move || {
+                link.send_message(Msg::Focus);
+            }));
+        }
+
         self.state.rendered(ctx, first_render);
     }
+
+    fn destroy(&mut self, ctx: &Context<Self>) {
+        if let (Some(scope), Some(name)) = (
+            &self.state.advanced_scope,
+            &ctx.props().as_input_props().name,
+        ) {
+            scope.remove(name);
+        }
+    }
 }
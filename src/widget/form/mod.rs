@@ -2,14 +2,22 @@
 
 mod context;
 pub use context::{
-    FieldHandle, FieldOptions, FormContext, FormContextObserver, FormContextReadGuard,
+    FieldHandle, FieldInfo, FieldOptions, FormContext, FormContextObserver, FormContextReadGuard,
     FormContextState, FormContextWriteGuard,
 };
 
+mod field_rule;
+pub use field_rule::{FieldCondition, FieldRule, FieldRuleState};
+
+mod advanced_options;
+pub use advanced_options::AdvancedOptions;
+#[doc(hidden)]
+pub use advanced_options::PwtAdvancedOptions;
+
 mod tristate_boolean;
 #[doc(hidden)]
 pub use tristate_boolean::PwtTristateBoolean;
-pub use tristate_boolean::{Tristate, TristateBoolean};
+pub use tristate_boolean::{Tristate, TristateBoolean, TristateDisplay};
 
 mod checkbox;
 pub use checkbox::Checkbox;
@@ -21,6 +29,11 @@ mod radio_button;
 pub use radio_button::PwtRadioButton;
 pub use radio_button::RadioButton;
 
+mod code_editor;
+pub use code_editor::CodeEditor;
+#[doc(hidden)]
+pub use code_editor::{CodeEditorField, PwtCodeEditor};
+
 mod combobox;
 pub use combobox::Combobox;
 #[doc(hidden)]
@@ -31,11 +44,24 @@ pub use display::DisplayField;
 #[doc(hidden)]
 pub use display::PwtDisplayField;
 
+mod field_array;
+#[doc(hidden)]
+pub use field_array::PwtFieldArray;
+pub use field_array::{FieldArray, FieldArrayItem};
+
 mod hidden;
 pub use hidden::Hidden;
 #[doc(hidden)]
 pub use hidden::PwtHidden;
 
+mod input_mask;
+pub use input_mask::InputMask;
+
+mod ip_field;
+#[doc(hidden)]
+pub use ip_field::PwtIpField;
+pub use ip_field::{CidrMask, IpCidr, IpField, IpVersion};
+
 mod managed_field;
 pub use managed_field::{
     ManagedField, ManagedFieldContext, ManagedFieldLink, ManagedFieldMaster, ManagedFieldScopeExt,
@@ -45,7 +71,7 @@ pub use managed_field::{
 mod field;
 #[doc(hidden)]
 pub use field::PwtField;
-pub use field::{Field, InputType};
+pub use field::{Field, InputFilter, InputType};
 
 #[allow(clippy::module_inception)]
 mod form;
@@ -56,7 +82,7 @@ pub use form::PwtForm;
 mod number;
 #[doc(hidden)]
 pub use number::PwtNumber;
-pub use number::{Number, NumberTypeInfo};
+pub use number::{Number, NumberLocale, NumberTypeInfo};
 
 mod reset_button;
 #[doc(hidden)]
@@ -64,7 +90,9 @@ pub use reset_button::PwtResetButton;
 pub use reset_button::ResetButton;
 
 mod selector;
-pub use selector::{PwtSelector, Selector, SelectorRenderArgs};
+pub use selector::{
+    PwtSelector, Selector, SelectorPage, SelectorQueryFn, SelectorRenderArgs, SelectorResolveFn,
+};
 
 mod submit_button;
 #[doc(hidden)]
@@ -77,11 +105,24 @@ pub use textarea::{PwtTextArea, TextArea};
 mod submit_validate;
 pub use submit_validate::{IntoSubmitValidateFn, SubmitValidateFn};
 
+mod unit_number;
+#[doc(hidden)]
+pub use unit_number::PwtUnitNumber;
+pub use unit_number::{NumberUnit, NumberUnits, UnitNumber};
+
 mod validate;
 pub use validate::{IntoValidateFn, ValidateFn};
 
 mod date_field;
-pub use date_field::DateField;
 #[doc(hidden)]
 pub use date_field::DateFieldComp;
 pub use date_field::PlainDate;
+pub use date_field::{DateField, DatePicker};
+
+#[doc(hidden)]
+pub use date_field::DateRangePickerComp;
+pub use date_field::{DateRangePicker, DateRangePreset};
+
+#[doc(hidden)]
+pub use date_field::DateTimePickerComp;
+pub use date_field::{DateTimePicker, PlainTime};
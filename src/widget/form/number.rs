@@ -16,6 +16,7 @@ use super::{
     IntoValidateFn, ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt,
     ManagedFieldState, ValidateFn,
 };
+use crate::dom::LocaleInfo;
 use crate::props::{ContainerBuilder, EventSubscriber, IntoVTag, WidgetBuilder};
 use crate::widget::{Column, Container, Input, Tooltip};
 
@@ -43,6 +44,11 @@ pub trait NumberTypeInfo:
     fn clamp_value(&self, min: Option<Self>, max: Option<Self>) -> Self;
 
     fn is_decimal() -> bool;
+
+    /// Parse the canonical string representation (as returned by `to_string()`).
+    fn parse_canonical(text: &str) -> Result<Self, Error> {
+        Self::value_to_number(&Value::String(text.to_string()))
+    }
 }
 
 impl NumberTypeInfo for f64 {
@@ -79,6 +85,12 @@ impl NumberTypeInfo for f64 {
     fn is_decimal() -> bool {
         true
     }
+    fn parse_canonical(text: &str) -> Result<Self, Error> {
+        match text.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(number),
+            _ => Err(Error::msg(tr!("invalid float literal"))),
+        }
+    }
 }
 
 // Note: Error message from rust parse() are not gettext translated, so try to do all
@@ -252,6 +264,57 @@ unsigned_number_impl!(u32);
 unsigned_number_impl!(u16);
 unsigned_number_impl!(u8);
 
+/// Locale used by [Number] to parse and format numbers.
+#[derive(Clone, PartialEq, Default)]
+pub enum NumberLocale {
+    /// Use the browser locale settings (default).
+    #[default]
+    Browser,
+    /// Use the specified locale.
+    Custom(LocaleInfo),
+    /// Raw mode - only accept canonical number format ('.' as decimal separator, no grouping).
+    Raw,
+}
+
+impl NumberLocale {
+    /// Returns the [LocaleInfo], or `None` in raw mode.
+    pub fn info(&self) -> Option<LocaleInfo> {
+        match self {
+            NumberLocale::Browser => Some(LocaleInfo::new()),
+            NumberLocale::Custom(info) => Some(info.clone()),
+            NumberLocale::Raw => None,
+        }
+    }
+}
+
+impl From<LocaleInfo> for NumberLocale {
+    fn from(info: LocaleInfo) -> Self {
+        NumberLocale::Custom(info)
+    }
+}
+
+// Parse number from a field value, using the locale to interpret strings.
+fn parse_value<T: NumberTypeInfo>(
+    locale: Option<&LocaleInfo>,
+    grouping: bool,
+    value: &Value,
+) -> Result<T, Error> {
+    match (value, locale) {
+        (Value::String(text), Some(locale)) => {
+            T::parse_canonical(&locale.canonicalize(text, grouping).map_err(Error::msg)?)
+        }
+        (Value::String(text), None) => T::parse_canonical(text.trim()),
+        _ => T::value_to_number(value),
+    }
+}
+
+fn format_number<T: NumberTypeInfo>(locale: Option<&LocaleInfo>, grouping: bool, n: &T) -> String {
+    match locale {
+        Some(locale) => locale.format_canonical(&n.to_string(), grouping),
+        None => n.to_string(),
+    }
+}
+
 // Note: We need to store numbers as strings while editing, because
 // the conversion String/Number is not bijective, and would lead to strange effects
 // especially for floating point numbers.
@@ -262,12 +325,16 @@ unsigned_number_impl!(u8);
 /// Number input element for common Rust types (f64, u8, u16, u32, u64, i8, i16, i32, i64)
 ///
 /// When used inside a [FormContext](crate::widget::form::FormContext), values are submitted as
-/// json numbers (not strings).
+/// json numbers (not strings), so the submitted data does not depend on the locale.
+///
+/// Input is parsed according to the configured [NumberLocale] (browser
+/// locale by default), so users can type the locale decimal separator.
+/// Set `grouping` to display (and accept) digit group separators.
 ///
 /// Accepted floating point number format (f64) is:
 ///
 /// ```BNF
-/// DecimalPoint ::= 'read from current locale settings'
+/// DecimalPoint ::= 'read from locale settings'
 /// Number       ::= ( Digit+ |
 ///                    Digit+ DecimalPoint Digit* |
 ///                    Digit* DecimalPoint Digit+ ) Exp?
@@ -322,7 +389,7 @@ pub struct Number<T: NumberTypeInfo> {
     /// To implement controlled components (for use without a FormContext).
     /// This is ignored if the field has a name.
     ///
-    /// Note: for f64, value must be formated using the browser locale!
+    /// Note: for f64, value must be formated using the configured locale!
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or_default]
     pub value: Option<AttrValue>,
@@ -343,6 +410,20 @@ pub struct Number<T: NumberTypeInfo> {
     #[prop_or_default]
     pub default: Option<T>,
 
+    /// Locale used to parse and format numbers.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub locale: NumberLocale,
+
+    /// Display digit group separators.
+    ///
+    /// The text is reformatted when the field loses focus. Group separators
+    /// in the input are only accepted if this is set (and only at valid
+    /// positions).
+    #[builder]
+    #[prop_or_default]
+    pub grouping: bool,
+
     /// Validation function.
     ///
     /// # Note
@@ -398,6 +479,7 @@ impl<T: NumberTypeInfo> Number<T> {
 
 pub enum Msg {
     Update(String),
+    Blur,
     Up,
     Down,
     SpinnerStart(bool),
@@ -434,6 +516,8 @@ pub struct ValidateClosure<T> {
     required: bool,
     min: Option<T>,
     max: Option<T>,
+    locale: Option<LocaleInfo>,
+    grouping: bool,
     validate: Option<ValidateFn<T>>,
 }
 
@@ -447,6 +531,8 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
             required: props.input_props.required,
             min: props.min,
             max: props.max,
+            locale: props.locale.info(),
+            grouping: props.grouping,
             validate: props.validate.clone(),
         }
     }
@@ -467,7 +553,7 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
             }
         }
 
-        let number = match T::value_to_number(value) {
+        let number = match parse_value::<T>(props.locale.as_ref(), props.grouping, value) {
             Ok(number) => number,
             Err(err) => return Err(Error::msg(tr!("Input invalid: {}", err.to_string()))),
         };
@@ -501,7 +587,7 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
         let mut value = Value::Null;
 
         if let Some(default) = props.default {
-            value = T::format(&default).into();
+            value = format_number(props.locale.info().as_ref(), props.grouping, &default).into();
         }
         if let Some(force_value) = &props.value {
             value = force_value.to_string().into();
@@ -552,13 +638,32 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
             Msg::Update(input) => {
                 ctx.link().update_value(input.clone());
                 if let Some(on_input) = &props.on_input {
-                    let value = T::value_to_number(&input.clone().into()).ok();
+                    let value = parse_value(
+                        props.locale.info().as_ref(),
+                        props.grouping,
+                        &input.clone().into(),
+                    )
+                    .ok();
                     on_input.emit((input, value));
                 }
                 true
             }
+            Msg::Blur => {
+                if !props.grouping || !self.value.is_string() {
+                    return false;
+                }
+                let locale = props.locale.info();
+                if let Ok(n) = parse_value::<T>(locale.as_ref(), props.grouping, &self.value) {
+                    let text = format_number(locale.as_ref(), true, &n);
+                    if self.value.as_str() != Some(text.as_str()) {
+                        ctx.link().update_value(text);
+                    }
+                }
+                false
+            }
             Msg::Up => {
-                let n = T::value_to_number(&self.value).ok();
+                let n = parse_value::<T>(props.locale.info().as_ref(), props.grouping, &self.value)
+                    .ok();
                 let n = match (n, self.result.is_ok()) {
                     (None, true) => Some(T::default().clamp_value(props.min, props.max)),
                     (Some(n), _) => {
@@ -578,7 +683,8 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
                 true
             }
             Msg::Down => {
-                let n = T::value_to_number(&self.value).ok();
+                let n = parse_value::<T>(props.locale.info().as_ref(), props.grouping, &self.value)
+                    .ok();
                 let n = match (n, self.result.is_ok()) {
                     (None, true) => Some(T::default().clamp_value(props.min, props.max)),
                     (Some(n), _) => {
@@ -634,7 +740,7 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
         let value_text = match value {
             Value::Null => String::new(),
             Value::Number(number) => match T::value_to_number(value) {
-                Ok(n) => T::format(&n),
+                Ok(n) => format_number(props.locale.info().as_ref(), props.grouping, &n),
                 Err(_) => number.to_string(),
             },
             Value::String(s) => s.to_string(),
@@ -669,10 +775,11 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
             "numeric"
         };
 
-        let disabled = props.input_props.disabled;
+        let disabled = self.is_disabled(props);
         let input: Html = Input::new()
             .with_input_props(&input_props)
-            .class("pwt-flex-fill")
+            .disabled(disabled)
+            .class(crate::css::Flex::Fill)
             .attribute("type", "text") // important (text, not number)
             .attribute("role", "spinbutton")
             .attribute("value", value_text)
@@ -680,18 +787,22 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
             .attribute("aria-valuemax", props.max.map(|v| v.to_string()))
             .attribute("inputmode", inputmode)
             .oninput(oninput)
+            .onblur(ctx.link().callback(|_| Msg::Blur))
             .onkeydown(on_key_down)
-            .onwheel({
-                let link = ctx.link().clone();
-                move |event: WheelEvent| {
-                    match event.delta_y() {
-                        delta if delta < 0.0 => link.send_message(Msg::Up),
-                        delta if delta > 0.0 => link.send_message(Msg::Down),
-                        _ => {}
+            .onwheel_with_passive(
+                {
+                    let link = ctx.link().clone();
+                    move |event: WheelEvent| {
+                        match event.delta_y() {
+                            delta if delta < 0.0 => link.send_message(Msg::Up),
+                            delta if delta > 0.0 => link.send_message(Msg::Down),
+                            _ => {}
+                        }
+                        event.prevent_default();
                     }
-                    event.prevent_default();
-                }
-            })
+                },
+                false,
+            )
             .into_html_with_ref(self.input_ref.clone());
 
         let mut input_container = Tooltip::empty()
@@ -699,7 +810,7 @@ impl<T: NumberTypeInfo> ManagedField for NumberField<T> {
             .listeners(&props.listeners)
             .class("pwt-input")
             .class("pwt-input-type-number")
-            .class("pwt-w-100")
+            .class(crate::css::FullWidth)
             .class(disabled.then_some("disabled"))
             .class(if validation_result.is_ok() {
                 "is-valid"
@@ -205,7 +205,7 @@ impl ManagedField for RadioButtonField {
 
         match msg {
             Msg::Toggle => {
-                if props.input_props.disabled {
+                if self.is_disabled(props) {
                     return true;
                 }
                 let on_value = props.value.to_string();
@@ -240,7 +240,7 @@ impl ManagedField for RadioButtonField {
     fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
         let props = ctx.props();
 
-        let disabled = props.input_props.disabled;
+        let disabled = self.is_disabled(props);
 
         let on_value = props.value.to_string();
         let (value, validation_result) = (&self.value, &self.result);
@@ -1,6 +1,13 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
 use anyhow::{Error, bail};
 use derivative::Derivative;
+use gloo_timers::callback::Timeout;
 use serde_json::Value;
+use web_sys::HtmlInputElement;
 
 use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
@@ -14,9 +21,11 @@ use crate::AsyncAbortGuard;
 use crate::css;
 use crate::prelude::*;
 use crate::props::{IntoLoadCallback, IntoOptionalRenderFn, LoadCallback, RenderFn};
-use crate::state::DataStore;
 use crate::state::Selection;
-use crate::widget::{Container, Dropdown, DropdownController, Mask, Trigger, error_message};
+use crate::state::{DataStore, PageRequest};
+use crate::widget::{
+    Button, Column, Container, Dropdown, DropdownController, Fa, Input, Row, Trigger, error_message,
+};
 
 use pwt_macros::{builder, widget};
 
@@ -30,6 +39,96 @@ pub struct SelectorRenderArgs<S: DataStore> {
     pub controller: DropdownController,
 }
 
+/// A page returned by the [Selector::query] callback.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectorPage<C> {
+    /// Total number of matching records (all pages).
+    pub total: usize,
+    /// The records, starting at [PageRequest::offset].
+    pub data: C,
+}
+
+/// Server side query callback for the [Selector].
+///
+/// We use an [Rc] to store the callback, and [Rc::ptr_eq] to implement
+/// [PartialEq].
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+pub struct SelectorQueryFn<C> {
+    #[allow(clippy::type_complexity)]
+    #[derivative(PartialEq(compare_with = "Rc::ptr_eq"))]
+    callback:
+        Rc<dyn Fn(PageRequest) -> Pin<Box<dyn Future<Output = Result<SelectorPage<C>, Error>>>>>,
+}
+
+impl<C> SelectorQueryFn<C> {
+    /// Create a new instance.
+    pub fn new<F, R>(callback: F) -> Self
+    where
+        F: 'static + Fn(PageRequest) -> R,
+        R: 'static + Future<Output = Result<SelectorPage<C>, Error>>,
+    {
+        Self {
+            callback: Rc::new(move |request| Box::pin(callback(request))),
+        }
+    }
+
+    /// Query a page.
+    pub async fn apply(&self, request: PageRequest) -> Result<SelectorPage<C>, Error> {
+        (self.callback)(request).await
+    }
+}
+
+impl<C, F, R> From<F> for SelectorQueryFn<C>
+where
+    F: 'static + Fn(PageRequest) -> R,
+    R: 'static + Future<Output = Result<SelectorPage<C>, Error>>,
+{
+    fn from(callback: F) -> Self {
+        SelectorQueryFn::new(callback)
+    }
+}
+
+/// Callback to resolve the display text for a [Selector] value.
+///
+/// We use an [Rc] to store the callback, and [Rc::ptr_eq] to implement
+/// [PartialEq].
+#[derive(Derivative)]
+#[derivative(Clone, PartialEq)]
+pub struct SelectorResolveFn {
+    #[allow(clippy::type_complexity)]
+    #[derivative(PartialEq(compare_with = "Rc::ptr_eq"))]
+    callback: Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<AttrValue, Error>>>>>,
+}
+
+impl SelectorResolveFn {
+    /// Create a new instance.
+    pub fn new<F, R>(callback: F) -> Self
+    where
+        F: 'static + Fn(String) -> R,
+        R: 'static + Future<Output = Result<AttrValue, Error>>,
+    {
+        Self {
+            callback: Rc::new(move |value| Box::pin(callback(value))),
+        }
+    }
+
+    /// Resolve the display text for `value`.
+    pub async fn apply(&self, value: String) -> Result<AttrValue, Error> {
+        (self.callback)(value).await
+    }
+}
+
+impl<F, R> From<F> for SelectorResolveFn
+where
+    F: 'static + Fn(String) -> R,
+    R: 'static + Future<Output = Result<AttrValue, Error>>,
+{
+    fn from(callback: F) -> Self {
+        SelectorResolveFn::new(callback)
+    }
+}
+
 pub type PwtSelector<S> = ManagedFieldMaster<SelectorField<S>>;
 
 /// Helper widget to implement [Combobox](super::Combobox) like selectors.
@@ -44,10 +143,15 @@ pub type PwtSelector<S> = ManagedFieldMaster<SelectorField<S>>;
 /// [TreeStore](crate::state::TreeStore)).
 ///
 /// - Ability to load data using a [LoadCallback] (with reasonable
-/// error handling).
+/// error handling). The picker shows a loading indicator while the
+/// loader is running, and the error with a retry button if it failed.
 ///
 /// - Handles [FormContext](super::FormContext) interaction.
 ///
+/// - Optional multi-select mode (see [multiple](Self::multiple)).
+///
+/// - Optional server side filtering and paging (see [query](Self::query)).
+///
 /// # Note
 ///
 /// Please use a trackable [LoadCallback] to avoid unnecessary
@@ -109,6 +213,69 @@ pub struct Selector<S: DataStore + 'static> {
     #[prop_or_default]
     #[builder]
     pub trigger: Vec<(Trigger, bool)>,
+
+    /// Text displayed in the picker while the loader is running.
+    ///
+    /// Default is "Loading...".
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub loading_text: Option<AttrValue>,
+
+    /// Text displayed in the picker if the store is empty.
+    ///
+    /// Default is "List does not contain any items.".
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub empty_text: Option<AttrValue>,
+
+    /// Show a retry button if the loader failed (default true).
+    #[prop_or(true)]
+    #[builder]
+    pub show_retry: bool,
+
+    /// Allow to select multiple values.
+    ///
+    /// Selected values are displayed as removable chips, and the picker
+    /// stays open while selecting. The value is stored as array in the
+    /// [FormContext](super::FormContext). The `value` and `default`
+    /// properties, and the [on_change](Self::on_change) key use comma
+    /// separated lists.
+    #[prop_or_default]
+    #[builder]
+    pub multiple: bool,
+
+    /// Server side filtering and paging.
+    ///
+    /// If set, the [loader](Self::loader) is not used. Instead, the
+    /// picker shows a search field and a paging toolbar, and the query
+    /// callback is called to load the requested page (debounced while
+    /// the user types, see [query_delay](Self::query_delay)). The search
+    /// text is passed as [PageRequest::filter].
+    ///
+    /// Values which are not part of the loaded page are displayed using
+    /// the [resolve_text](Self::resolve_text) callback.
+    #[prop_or_default]
+    pub query: Option<SelectorQueryFn<S::Collection>>,
+
+    /// Number of records requested by the [query](Self::query) callback (default 50).
+    #[prop_or(50)]
+    #[builder]
+    pub page_size: usize,
+
+    /// Delay in milliseconds before a changed search text triggers a query (default 300).
+    #[prop_or(300)]
+    #[builder]
+    pub query_delay: u32,
+
+    /// Resolve the display text for values not contained in the store.
+    ///
+    /// Used together with the [query](Self::query) callback, i.e. for a
+    /// preset value which is not part of the loaded page.
+    ///
+    /// Note: selectors using this feature are not editable, because the
+    /// text is displayed with a [render_value](Self::render_value) function.
+    #[prop_or_default]
+    pub resolve_text: Option<SelectorResolveFn>,
 }
 
 impl<S: DataStore> Selector<S> {
@@ -142,6 +309,28 @@ impl<S: DataStore> Selector<S> {
         self.loader = callback.into_load_callback();
     }
 
+    /// Builder style method to set the server side query callback.
+    pub fn query(mut self, callback: impl Into<SelectorQueryFn<S::Collection>>) -> Self {
+        self.set_query(callback);
+        self
+    }
+
+    /// Method to set the server side query callback.
+    pub fn set_query(&mut self, callback: impl Into<SelectorQueryFn<S::Collection>>) {
+        self.query = Some(callback.into());
+    }
+
+    /// Builder style method to set the resolve text callback.
+    pub fn resolve_text(mut self, callback: impl Into<SelectorResolveFn>) -> Self {
+        self.set_resolve_text(callback);
+        self
+    }
+
+    /// Method to set the resolve text callback.
+    pub fn set_resolve_text(&mut self, callback: impl Into<SelectorResolveFn>) {
+        self.resolve_text = Some(callback.into());
+    }
+
     /// Builder style method to add an trigger
     pub fn with_trigger(mut self, trigger: impl Into<Trigger>, right: bool) -> Self {
         self.add_trigger(trigger, right);
@@ -156,8 +345,15 @@ impl<S: DataStore> Selector<S> {
 
 pub enum Msg<S: DataStore> {
     Select(String),
+    Remove(String),
+    Reload,
     DataChange,
     LoadResult(Result<S::Collection, Error>),
+    QueryResult(Result<SelectorPage<S::Collection>, Error>),
+    QueryInput(String),
+    Query,
+    Page(usize),
+    Resolved(String, AttrValue),
     DeleteKey,
 }
 
@@ -169,6 +365,17 @@ pub struct SelectorField<S: DataStore> {
     load_error: Option<String>,
     _store_observer: S::Observer,
     abort_load_guard: Option<AsyncAbortGuard>,
+    // server side query state
+    query_filter: String,
+    query_offset: usize,
+    query_total: Option<usize>,
+    query_timeout: Option<Timeout>,
+    // display texts from the resolve_text callback
+    resolved_texts: HashMap<String, AttrValue>,
+    resolving: Vec<String>,
+    resolve_guard: Option<AsyncAbortGuard>,
+    // disabled state at the last check, used to reload when enabled
+    disabled: bool,
 }
 
 impl<S: DataStore> std::ops::Deref for SelectorField<S> {
@@ -186,12 +393,269 @@ impl<S: DataStore> std::ops::DerefMut for SelectorField<S> {
 }
 
 impl<S: DataStore + 'static> SelectorField<S> {
+    fn sync_selection(selection: &Selection, props: &Selector<S>, value: &Value) {
+        if props.multiple {
+            selection.clear();
+            for item in value_to_list(value) {
+                selection.toggle(item);
+            }
+        } else if let Some(s) = value.as_str() {
+            if !s.is_empty() {
+                selection.select(s.to_string());
+            }
+        }
+    }
+
+    // Returns the value as comma separated list (multi-select mode).
+    fn value_text(&self, props: &Selector<S>) -> String {
+        if props.multiple {
+            value_to_list(&self.value).join(",")
+        } else {
+            self.value.as_str().unwrap_or("").to_owned()
+        }
+    }
+
+    // Returns the list of selected values.
+    fn value_list(&self, props: &Selector<S>) -> Vec<String> {
+        if props.multiple {
+            value_to_list(&self.value)
+        } else {
+            match self.value.as_str() {
+                Some(value) if !value.is_empty() => vec![value.to_owned()],
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    // Returns the render function for values, using the resolved texts.
+    fn value_render_fn(&self, props: &Selector<S>) -> Option<RenderFn<AttrValue>> {
+        if self.resolved_texts.is_empty() {
+            return props.render_value.clone();
+        }
+        let texts = self.resolved_texts.clone();
+        let render_value = props.render_value.clone();
+        Some(RenderFn::new(move |value: &AttrValue| {
+            match (texts.get(value.as_str()), &render_value) {
+                (Some(text), _) => html! {text},
+                (None, Some(render_value)) => render_value.apply(value),
+                (None, None) => html! {value},
+            }
+        }))
+    }
+
+    // Resolve the display text for values not contained in the store.
+    fn resolve_missing(&mut self, ctx: &ManagedFieldContext<Self>) {
+        let props = ctx.props();
+        let Some(resolve_text) = props.resolve_text.clone() else {
+            return;
+        };
+
+        let missing: Vec<String> = self
+            .value_list(props)
+            .into_iter()
+            .filter(|value| {
+                !self.resolved_texts.contains_key(value)
+                    && props
+                        .store
+                        .filtered_record_pos(&Key::from(value.clone()))
+                        .is_none()
+            })
+            .collect();
+
+        if missing == self.resolving {
+            return;
+        }
+        self.resolving = missing.clone();
+        self.resolve_guard = None;
+
+        if missing.is_empty() {
+            return;
+        }
+
+        let link = ctx.link().clone();
+        self.resolve_guard = Some(AsyncAbortGuard::spawn(async move {
+            for value in missing {
+                let text = match resolve_text.apply(value.clone()).await {
+                    Ok(text) => text,
+                    Err(err) => {
+                        log::warn!("Selector: unable to resolve text for '{value}' - {err}");
+                        AttrValue::from(value.clone())
+                    }
+                };
+                link.send_message(Msg::Resolved(value, text));
+            }
+        }));
+    }
+
+    fn set_load_result(&mut self, props: &Selector<S>, res: Result<S::Collection, Error>) {
+        match res {
+            Ok(data) => {
+                self.load_error = None;
+                props.store.set_data(data);
+            }
+            Err(err) => {
+                props.store.clear();
+                self.load_error = Some(err.to_string());
+            }
+        }
+        self.abort_load_guard = None;
+    }
+
+    fn render_query_bar(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        Row::new()
+            .padding(2)
+            .class(css::AlignItems::Center)
+            .with_child(
+                Input::new()
+                    .autofocus(true)
+                    .class("pwt-input")
+                    .class(css::FullWidth)
+                    .attribute("autocomplete", "off")
+                    .attribute("size", "1") // make size minimal
+                    .attribute("placeholder", tr!("Search"))
+                    .attribute("aria-label", tr!("Search"))
+                    .attribute("value", self.query_filter.clone())
+                    .oninput(ctx.link().callback(|event: InputEvent| {
+                        let input: HtmlInputElement = event.target_unchecked_into();
+                        Msg::QueryInput(input.value())
+                    })),
+            )
+            .into()
+    }
+
+    fn render_paging_bar(&self, ctx: &ManagedFieldContext<Self>) -> Option<Html> {
+        let props = ctx.props();
+        let total = self.query_total?;
+        let page_size = props.page_size.max(1);
+        if total <= page_size && self.query_offset == 0 {
+            return None;
+        }
+
+        let offset = self.query_offset;
+        let last = (offset + page_size).min(total);
+        let loading = self.abort_load_guard.is_some();
+
+        Some(
+            Row::new()
+                .padding(1)
+                .gap(1)
+                .class(css::AlignItems::Center)
+                .class("pwt-selector-paging")
+                .with_child(
+                    Button::new_icon("fa fa-angle-left")
+                        .aria_label(tr!("Previous Page"))
+                        .disabled(loading || offset == 0)
+                        .on_activate(
+                            ctx.link()
+                                .callback(move |_| Msg::Page(offset.saturating_sub(page_size))),
+                        ),
+                )
+                .with_child(
+                    Container::new()
+                        .class(css::Flex::Fill)
+                        .style("text-align", "center")
+                        .with_child(tr!("{0} - {1} of {2}", offset + 1, last, total)),
+                )
+                .with_child(
+                    Button::new_icon("fa fa-angle-right")
+                        .aria_label(tr!("Next Page"))
+                        .disabled(loading || last >= total)
+                        .on_activate(ctx.link().callback(move |_| Msg::Page(offset + page_size))),
+                )
+                .into(),
+        )
+    }
+
+    // Returns true if the field got enabled since the last check (by its
+    // properties, a DisabledContainer or a FieldRule).
+    fn update_disabled(&mut self, props: &Selector<S>) -> bool {
+        let disabled = self.is_disabled(props);
+        let enabled = self.disabled && !disabled;
+        self.disabled = disabled;
+        enabled
+    }
+
+    fn update_list(&self, ctx: &ManagedFieldContext<Self>, update: impl FnOnce(&mut Vec<String>)) {
+        let mut list = value_to_list(&self.value);
+        update(&mut list);
+        ctx.link().update_value(list_to_value(list));
+    }
+
+    fn render_chips(&self, ctx: &ManagedFieldContext<Self>) -> RenderFn<AttrValue> {
+        let props = ctx.props();
+        let list = value_to_list(&self.value);
+        let render_value = self.value_render_fn(props);
+        let placeholder = props.input_props.placeholder.clone();
+        let disabled = self.is_disabled(props);
+        let link = ctx.link().clone();
+
+        RenderFn::new(move |_: &AttrValue| {
+            if list.is_empty() {
+                return Container::new()
+                    .class("pwt-opacity-50")
+                    .with_optional_child(placeholder.clone())
+                    .into();
+            }
+            Container::new()
+                .class("pwt-selector-chips")
+                .class(css::Display::Flex)
+                .style("flex-wrap", "wrap")
+                .style("gap", "0.25em")
+                .children(list.iter().map(|item| {
+                    let text = match &render_value {
+                        Some(render_value) => render_value.apply(&AttrValue::from(item.clone())),
+                        None => html! {item},
+                    };
+                    let item = item.clone();
+                    let link = link.clone();
+                    Container::new()
+                        .class("pwt-selector-chip")
+                        .class(css::Display::Flex)
+                        .class(css::AlignItems::Center)
+                        .border(true)
+                        .padding_x(1)
+                        .style("border-radius", "1em")
+                        .style("gap", "0.25em")
+                        .with_child(text)
+                        .with_optional_child((!disabled).then(|| {
+                            Container::from_tag("i")
+                                .class("fa fa-times pwt-pointer")
+                                .attribute("role", "button")
+                                .attribute("aria-label", tr!("Remove"))
+                                .onclick(move |event: MouseEvent| {
+                                    // do not toggle the picker
+                                    event.stop_propagation();
+                                    link.send_message(Msg::Remove(item.clone()));
+                                })
+                        }))
+                        .into()
+                }))
+                .into()
+        })
+    }
+
     fn load(&mut self, ctx: &ManagedFieldContext<Self>) {
         let props = ctx.props();
         let link = ctx.link().clone();
         self.abort_load_guard = None; // abort any previous load
-        if let Some(loader) = &props.loader {
-            if !props.is_disabled() {
+        self.query_timeout = None;
+        if let Some(query) = &props.query {
+            if !self.is_disabled(props) {
+                let query = query.clone();
+                let request = PageRequest {
+                    offset: self.query_offset,
+                    limit: props.page_size.max(1),
+                    sort: None,
+                    filter: (!self.query_filter.is_empty())
+                        .then(|| AttrValue::from(self.query_filter.clone())),
+                };
+                self.abort_load_guard = Some(AsyncAbortGuard::spawn(async move {
+                    let res = query.apply(request).await;
+                    link.send_message(Msg::QueryResult(res));
+                }));
+            }
+        } else if let Some(loader) = &props.loader {
+            if !self.is_disabled(props) {
                 let loader = loader.clone();
                 self.abort_load_guard = Some(AsyncAbortGuard::spawn(async move {
                     let res = loader.apply().await;
@@ -205,8 +669,41 @@ impl<S: DataStore + 'static> SelectorField<S> {
     }
 }
 
+// Returns the list of values (multi-select mode).
+//
+// Strings are interpreted as comma separated lists.
+fn value_to_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(list) => list
+            .iter()
+            .filter_map(|item| item.as_str())
+            .map(String::from)
+            .collect(),
+        Value::String(text) => text
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(String::from)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn list_to_value(list: Vec<String>) -> Value {
+    Value::Array(list.into_iter().map(Value::String).collect())
+}
+
+fn value_is_empty(value: &Value) -> bool {
+    match value {
+        Value::String(text) => text.is_empty(),
+        Value::Array(list) => list.is_empty(),
+        _ => true,
+    }
+}
+
 #[derive(PartialEq)]
 pub struct ValidateClosure<S: DataStore> {
+    multiple: bool,
     required: bool,
     store: S,
     validate: Option<ValidateFn<(String, S)>>,
@@ -219,6 +716,7 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
 
     fn validation_args(props: &Self::Properties) -> Self::ValidateClosure {
         ValidateClosure {
+            multiple: props.multiple,
             required: props.input_props.required,
             store: props.store.clone(),
             validate: props.validate.clone(),
@@ -226,6 +724,24 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
     }
 
     fn validator(props: &Self::ValidateClosure, value: &Value) -> Result<Value, Error> {
+        if props.multiple {
+            if !matches!(value, Value::Null | Value::String(_) | Value::Array(_)) {
+                return Err(Error::msg(tr!("got wrong data type.")));
+            }
+            let list = value_to_list(value);
+            if list.is_empty() && props.required {
+                bail!("Field may not be empty.");
+            }
+            if !props.store.is_empty() {
+                if let Some(validate) = &props.validate {
+                    for item in &list {
+                        validate.apply(&(item.clone(), props.store.clone()))?;
+                    }
+                }
+            }
+            return Ok(list_to_value(list));
+        }
+
         let value = match value {
             Value::Null => String::new(),
             Value::String(v) => v.clone(),
@@ -255,23 +771,24 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
     fn create(ctx: &ManagedFieldContext<Self>) -> Self {
         let props = ctx.props();
 
-        let value: Value = match &props.value {
+        let mut value: Value = match &props.value {
             Some(value) => value.to_string().into(),
             None => Value::Null,
         };
 
-        let default = match &props.default {
+        let mut default = match &props.default {
             Some(default) => default.to_string().into(),
             None => Value::Null,
         };
 
-        let selection = Selection::new();
-        if let Some(s) = value.as_str() {
-            if !s.is_empty() {
-                selection.select(s.to_string());
-            }
+        if props.multiple {
+            value = list_to_value(value_to_list(&value));
+            default = list_to_value(value_to_list(&default));
         }
 
+        let selection = Selection::new().multiselect(props.multiple);
+        Self::sync_selection(&selection, props, &value);
+
         let _store_observer = props
             .store
             .add_listener(ctx.link().callback(|_| Msg::DataChange));
@@ -283,55 +800,107 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
             load_error: None,
             _store_observer,
             abort_load_guard: None,
+            query_filter: String::new(),
+            query_offset: 0,
+            query_total: None,
+            query_timeout: None,
+            resolved_texts: HashMap::new(),
+            resolving: Vec::new(),
+            resolve_guard: None,
+            disabled: false,
         };
 
-        me.load(ctx);
+        me.resolve_missing(ctx);
 
         me
     }
 
     fn value_changed(&mut self, ctx: &super::ManagedFieldContext<Self>) {
         let props = ctx.props();
-        let key = self
-            .value
-            .as_str()
-            .map(|s| Key::from(s))
-            .unwrap_or(Key::from(""));
+        let key = Key::from(self.value_text(props));
 
-        self.selection.select(key.clone());
+        if props.multiple {
+            Self::sync_selection(&self.selection, props, &self.value);
+        } else {
+            self.selection.select(key.clone());
+        }
 
         if let Some(on_change) = &props.on_change {
             on_change.emit(key);
         }
+
+        self.resolve_missing(ctx);
     }
 
     fn update(&mut self, ctx: &ManagedFieldContext<Self>, msg: Self::Message) -> bool {
         let props = ctx.props();
         match msg {
+            Msg::Reload => {
+                self.load_error = None;
+                self.load(ctx);
+                true
+            }
             Msg::DeleteKey => {
-                if !props.editable {
+                if props.multiple {
+                    self.update_list(ctx, |list| {
+                        list.pop();
+                    });
+                } else if !props.editable {
                     ctx.link().update_value(String::new());
                 }
                 false
             }
+            Msg::Remove(item) => {
+                if !self.is_disabled(props) {
+                    self.update_list(ctx, |list| list.retain(|v| *v != item));
+                }
+                false
+            }
             Msg::LoadResult(res) => {
-                match res {
-                    Ok(data) => {
-                        self.load_error = None;
-                        props.store.set_data(data);
-                    }
-                    Err(err) => {
-                        props.store.clear();
-                        self.load_error = Some(err.to_string());
-                    }
+                self.set_load_result(props, res);
+                true
+            }
+            Msg::QueryResult(res) => {
+                let res = res.map(|page| {
+                    self.query_total = Some(page.total);
+                    page.data
+                });
+                self.set_load_result(props, res);
+                true
+            }
+            Msg::QueryInput(filter) => {
+                if filter == self.query_filter {
+                    return false;
+                }
+                self.query_filter = filter;
+                self.query_offset = 0;
+                let link = ctx.link().clone();
+                self.query_timeout = Some(Timeout::new(props.query_delay, move || {
+                    link.send_message(Msg::Query)
+                }));
+                true
+            }
+            Msg::Query => {
+                self.load_error = None;
+                self.load(ctx);
+                true
+            }
+            Msg::Page(offset) => {
+                self.query_offset = offset;
+                self.load_error = None;
+                self.load(ctx);
+                true
+            }
+            Msg::Resolved(value, text) => {
+                self.resolving.retain(|v| *v != value);
+                if self.resolving.is_empty() {
+                    self.resolve_guard = None;
                 }
-                self.abort_load_guard = None;
+                self.resolved_texts.insert(value, text);
                 true
             }
             Msg::DataChange => {
-                let value = self.value.as_str().unwrap_or("").to_owned();
-
-                if self.load_error.is_none() && value.is_empty() && !self.value_set {
+                if self.load_error.is_none() && value_is_empty(&self.value) && !self.value_set {
                     let mut default = props.default.clone();
 
                     if default.is_none() && props.autoselect {
@@ -341,16 +910,32 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
                     }
 
                     if let Some(default) = default {
-                        ctx.link().update_value(default.to_string());
-                        ctx.link().update_default(default.to_string());
+                        let default: Value = if props.multiple {
+                            list_to_value(value_to_list(&default.to_string().into()))
+                        } else {
+                            default.to_string().into()
+                        };
+                        ctx.link().update_value(default.clone());
+                        ctx.link().update_default(default);
                         self.value_set = true;
                     }
                 }
                 ctx.link().validate(); // re-evaluate
+                self.resolve_missing(ctx);
                 true
             }
             Msg::Select(value) => {
-                if !props.input_props.disabled {
+                if self.is_disabled(props) {
+                    return false;
+                }
+                if props.multiple {
+                    self.update_list(ctx, |list| match list.iter().position(|v| *v == value) {
+                        Some(pos) => {
+                            list.remove(pos);
+                        }
+                        None => list.push(value),
+                    });
+                } else {
                     ctx.link().update_value(value);
                 }
                 false
@@ -363,6 +948,11 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
 
         let mut reload = false;
 
+        if props.multiple != old_props.multiple {
+            self.selection = Selection::new().multiselect(props.multiple);
+            Self::sync_selection(&self.selection, props, &self.value);
+        }
+
         if props.store != old_props.store {
             self._store_observer = props
                 .store
@@ -370,18 +960,25 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
             reload = true;
         }
 
-        if props.loader != old_props.loader {
+        if props.loader != old_props.loader || props.query != old_props.query {
             reload = true;
         }
 
-        if old_props.is_disabled() && !props.is_disabled() {
+        if props.resolve_text != old_props.resolve_text {
+            self.resolved_texts.clear();
+            self.resolving.clear();
+            self.resolve_guard = None;
+            self.resolve_missing(ctx);
+        }
+
+        if self.update_disabled(props) {
             reload = true;
         }
 
         if props.value != old_props.value {
             ctx.link()
                 .force_value(props.value.as_ref().map(|v| v.to_string()), None);
-        } else if !props.editable && props.input_props.name.is_none() {
+        } else if !props.editable && !props.multiple && props.input_props.name.is_none() {
             // A controlled non-editable field cleared in place stays blank if the parent maps the
             // clear back to the same value, so re-assert. Limited to nameless fields, matching
             // the `value` prop's documented controlled use. Relies on changed() running, which
@@ -403,7 +1000,7 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
     fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
         let props = ctx.props();
 
-        let value = self.value.as_str().unwrap_or("").to_owned();
+        let value = self.value_text(props);
         let validation_result = &self.result;
 
         let picker = {
@@ -414,10 +1011,25 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
             let load_error = self.load_error.clone();
             let is_loading = self.abort_load_guard.is_some();
 
-            move |controller: &DropdownController| {
+            let loading_text = props
+                .loading_text
+                .clone()
+                .unwrap_or_else(|| tr!("Loading...").into());
+            let empty_text = props
+                .empty_text
+                .clone()
+                .unwrap_or_else(|| tr!("List does not contain any items.").into());
+            let on_retry = (props.show_retry && (props.loader.is_some() || props.query.is_some()))
+                .then(|| ctx.link().callback(|_| Msg::Reload));
+
+            let render_content = move |controller: &DropdownController| -> Html {
                 if is_loading {
-                    return Mask::new(Container::new().min_height(100))
-                        .visible(true)
+                    return Container::new()
+                        .padding(2)
+                        .class(css::AlignItems::Center)
+                        .class(css::Display::Flex)
+                        .with_child(Fa::new("spinner").margin_end(1).pulse())
+                        .with_child(loading_text.clone())
                         .into();
                 }
                 if let Some(load_error) = &load_error {
@@ -426,19 +1038,26 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
                     return Container::new()
                         .class(css::Flex::Fill)
                         .class(css::Display::Flex)
+                        .class(css::AlignItems::Center)
                         .with_child(
                             error_message(&format!("Error: {}", load_error))
                                 .padding(2)
                                 .class(css::Flex::Fill)
                                 .width(400),
                         )
+                        .with_optional_child(on_retry.clone().map(|on_retry| {
+                            Button::new(tr!("Retry"))
+                                .icon_class("fa fa-refresh")
+                                .margin_end(2)
+                                .on_activate(on_retry)
+                        }))
                         .into();
                 }
 
                 if store.is_empty() {
                     return Container::new()
                         .padding(2)
-                        .with_child(tr!("List does not contain any items."))
+                        .with_child(empty_text.clone())
                         .into();
                 }
 
@@ -448,6 +1067,22 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
                     controller: controller.clone(),
                 };
                 picker.apply(&render_picker_args)
+            };
+
+            let query_bar = props.query.is_some().then(|| self.render_query_bar(ctx));
+            let paging_bar = self.render_paging_bar(ctx);
+
+            move |controller: &DropdownController| {
+                let content = render_content(controller);
+                match &query_bar {
+                    Some(query_bar) => Column::new()
+                        .class(css::Flex::Fill)
+                        .with_child(query_bar.clone())
+                        .with_child(content)
+                        .with_optional_child(paging_bar.clone())
+                        .into(),
+                    None => content,
+                }
             }
         };
 
@@ -467,7 +1102,8 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
         let mut trigger = props.trigger.clone();
         if !value.is_empty()
             && !props.editable
-            && !props.is_disabled()
+            && !props.multiple
+            && !self.is_disabled(props)
             && !props.input_props.required
         {
             trigger.push((
@@ -478,17 +1114,143 @@ impl<S: DataStore + 'static> ManagedField for SelectorField<S> {
             ));
         }
 
+        let render_value = if props.multiple {
+            Some(self.render_chips(ctx))
+        } else {
+            self.value_render_fn(props)
+        };
+
         Dropdown::new(picker)
             .with_std_props(&props.std_props)
             .with_input_props(&props.input_props)
-            .editable(props.editable)
+            .disabled(self.is_disabled(props))
+            .editable(props.editable && !props.multiple)
+            .keep_open(props.multiple)
             .valid(validation_result.is_ok())
             .onkeydown(onkeydown)
             .on_change(ctx.link().callback(|key: String| Msg::Select(key)))
             .value(value)
-            .render_value(props.render_value.clone())
+            .render_value(render_value)
             .tip(tip)
             .trigger(trigger)
             .into()
     }
+
+    fn rendered(&mut self, ctx: &ManagedFieldContext<Self>, first_render: bool) {
+        // Note: the disabled context is not known in create(), so we
+        // load after the first render.
+        if self.update_disabled(ctx.props()) || first_render {
+            self.load(ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::bail;
+    use serde_json::{Value, json};
+    use yew::virtual_dom::Key;
+
+    use super::{SelectorField, ValidateClosure, list_to_value, value_to_list};
+    use crate::state::Store;
+    use crate::widget::form::{ManagedField, ValidateFn};
+
+    fn validate(args: &ValidateClosure<Store<String>>, value: Value) -> Option<Value> {
+        SelectorField::<Store<String>>::validator(args, &value).ok()
+    }
+
+    #[test]
+    fn test_value_list() {
+        assert_eq!(value_to_list(&json!("a, b,,c ")), vec!["a", "b", "c"]);
+        assert_eq!(value_to_list(&json!(["a", 1, "b"])), vec!["a", "b"]);
+        assert!(value_to_list(&json!("")).is_empty());
+        assert!(value_to_list(&Value::Null).is_empty());
+
+        assert_eq!(
+            list_to_value(vec!["a".into(), "b".into()]),
+            json!(["a", "b"])
+        );
+        assert_eq!(list_to_value(Vec::new()), json!([]));
+    }
+
+    #[test]
+    fn test_multi_validator() {
+        let store = Store::with_extract_key(|item: &String| Key::from(item.as_str()));
+        let mut args = ValidateClosure {
+            multiple: true,
+            required: false,
+            store: store.clone(),
+            validate: Some(ValidateFn::new(
+                |(item, store): &(String, Store<String>)| {
+                    if store
+                        .read()
+                        .lookup_record(&Key::from(item.as_str()))
+                        .is_none()
+                    {
+                        bail!("unknown item");
+                    }
+                    Ok(())
+                },
+            )),
+        };
+
+        assert_eq!(validate(&args, Value::Null), Some(json!([])));
+        assert_eq!(validate(&args, json!("a,b")), Some(json!(["a", "b"])));
+        assert_eq!(validate(&args, json!(42)), None);
+
+        // no data loaded, so items are not validated
+        assert_eq!(validate(&args, json!(["x"])), Some(json!(["x"])));
+
+        store.set_data(vec!["a".into(), "b".into()]);
+        assert_eq!(validate(&args, json!(["a", "b"])), Some(json!(["a", "b"])));
+        assert_eq!(validate(&args, json!(["a", "x"])), None);
+
+        args.required = true;
+        assert_eq!(validate(&args, json!([])), None);
+        assert_eq!(validate(&args, json!("b")), Some(json!(["b"])));
+    }
+    #[cfg(feature = "gallery")]
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_disabled_container() {
+        use super::{Selector, SelectorRenderArgs};
+        use crate::prelude::*;
+        use crate::widget::{Container, DisabledContainer};
+
+        #[derive(Properties, PartialEq)]
+        struct Props {
+            disabled: bool,
+        }
+
+        #[function_component]
+        fn App(props: &Props) -> Html {
+            let store = Store::with_extract_key(|item: &String| Key::from(item.as_str()));
+            let selector = Selector::new(store, |_: &SelectorRenderArgs<Store<String>>| {
+                Container::new().into()
+            })
+            .multiple(true)
+            .value("a,b");
+
+            DisabledContainer::new()
+                .disabled(props.disabled)
+                .with_child(selector)
+                .into()
+        }
+
+        let render = |disabled| async move {
+            yew::LocalServerRenderer::<App>::with_props(Props { disabled })
+                .hydratable(false)
+                .render()
+                .await
+        };
+
+        let local = tokio::task::LocalSet::new();
+        let (enabled, disabled) = local
+            .run_until(async { (render(false).await, render(true).await) })
+            .await;
+
+        // chips can only be removed if enabled
+        assert!(enabled.contains(r#"aria-label="Remove""#));
+        assert!(!disabled.contains(r#"aria-label="Remove""#));
+        assert!(disabled.contains(r#"aria-disabled="true""#));
+    }
 }
@@ -126,8 +126,9 @@ impl Component for PwtSubmitButton {
             }
             Msg::FormCtxDataChange => true,
             Msg::Submit => {
-                if let Some(on_submit) = &props.on_submit {
-                    if let Some(form_ctx) = self.form_ctx.clone() {
+                if let Some(form_ctx) = self.form_ctx.clone() {
+                    form_ctx.write().mark_submitted();
+                    if let Some(on_submit) = &props.on_submit {
                         on_submit.emit(form_ctx);
                     }
                 }
@@ -219,8 +219,11 @@ impl ManagedField for TextAreaField {
             Msg::Update(input.value())
         });
 
-        let disabled = props.input_props.disabled;
-        let props = props.clone().oninput((!disabled).then_some(oninput));
+        let disabled = self.is_disabled(props);
+        let props = props
+            .clone()
+            .disabled(disabled)
+            .oninput((!disabled).then_some(oninput));
 
         let classes = classes!(
             "pwt-textarea",
@@ -6,11 +6,14 @@ use serde_json::Value;
 use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::virtual_dom::Key;
 
+use crate::css::AlignItems;
 use crate::prelude::*;
 use crate::props::RenderFn;
 use crate::state::{Selection, Store};
 use crate::widget::data_table::{DataTable, DataTableColumn, DataTableHeader};
-use crate::widget::{Dropdown, DropdownController, GridPicker};
+use crate::widget::{
+    Button, Container, Dropdown, DropdownController, Fa, GridPicker, Row, SegmentedButton, Tooltip,
+};
 
 use super::{
     ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt, ManagedFieldState,
@@ -25,7 +28,26 @@ pub enum Tristate {
     No,
 }
 
+/// How to render a [TristateBoolean].
+#[derive(Copy, Clone, PartialEq, Default)]
+pub enum TristateDisplay {
+    /// Dropdown list (default).
+    #[default]
+    Dropdown,
+    /// Segmented control with one button per state.
+    Segmented,
+    /// Checkbox, showing a dash for [Tristate::Null].
+    Checkbox,
+}
+
 /// Tristate Boolean widget (yes, no, null)
+///
+/// The [Tristate::Null] state usually means "use the default". If you
+/// set the `inherited` value, it is shown as part of the null text,
+/// i.e. "Default (Yes)".
+///
+/// In checkbox mode, clicking (or pressing space) cycles through the
+/// states using the `cycle_order`.
 #[widget(pwt=crate, comp=ManagedFieldMaster<PwtTristateBoolean>, @input)]
 #[derive(Clone, PartialEq, Properties)]
 #[builder]
@@ -50,6 +72,40 @@ pub struct TristateBoolean {
     #[prop_or_default]
     pub null_text: Option<AttrValue>,
 
+    /// Icon class for [Tristate::Yes].
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub yes_icon: Option<Classes>,
+
+    /// Icon class for [Tristate::No].
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub no_icon: Option<Classes>,
+
+    /// Icon class for [Tristate::Null].
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub null_icon: Option<Classes>,
+
+    /// The inherited value (used when the value is [Tristate::Null]).
+    ///
+    /// This is only used for display.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub inherited: Option<bool>,
+
+    /// State order, used for the list items and for cycling.
+    ///
+    /// Default is `[Null, Yes, No]`.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub cycle_order: Option<Vec<Tristate>>,
+
+    /// Display mode.
+    #[builder]
+    #[prop_or_default]
+    pub display: TristateDisplay,
+
     /// Change callback
     #[builder_cb(IntoEventCallback, into_event_callback, Tristate)]
     #[prop_or_default]
@@ -67,10 +123,64 @@ impl TristateBoolean {
     pub fn new() -> Self {
         yew::props!(Self {})
     }
+
+    fn state_order(&self) -> Vec<Tristate> {
+        match &self.cycle_order {
+            Some(order) if !order.is_empty() => order.clone(),
+            _ => vec![Tristate::Null, Tristate::Yes, Tristate::No],
+        }
+    }
+
+    fn state_text(&self, tristate: Tristate) -> String {
+        let yes_text = || {
+            self.yes_text
+                .as_ref()
+                .map(|s| s.to_string())
+                .unwrap_or(tr!("Yes"))
+        };
+        let no_text = || {
+            self.no_text
+                .as_ref()
+                .map(|s| s.to_string())
+                .unwrap_or(tr!("No"))
+        };
+        match tristate {
+            Tristate::Yes => yes_text(),
+            Tristate::No => no_text(),
+            Tristate::Null => match (&self.null_text, self.inherited) {
+                (Some(text), _) => text.to_string(),
+                (None, Some(true)) => tr!("Default ({})", yes_text()),
+                (None, Some(false)) => tr!("Default ({})", no_text()),
+                (None, None) => tr!("Default"),
+            },
+        }
+    }
+
+    fn state_icon(&self, tristate: Tristate) -> Option<Classes> {
+        match tristate {
+            Tristate::Yes => self.yes_icon.clone(),
+            Tristate::No => self.no_icon.clone(),
+            Tristate::Null => self.null_icon.clone(),
+        }
+    }
+
+    fn render_state(&self, tristate: Tristate) -> Html {
+        let text = self.state_text(tristate);
+        match self.state_icon(tristate) {
+            Some(icon) => Row::new()
+                .gap(1)
+                .class(AlignItems::Center)
+                .with_child(Fa::from_class(icon))
+                .with_child(text)
+                .into(),
+            None => html! {text},
+        }
+    }
 }
 
 pub enum Msg {
     Select(String),
+    Cycle,
 }
 
 #[doc(hidden)]
@@ -92,6 +202,14 @@ fn tristate_to_text(tristate: Tristate) -> String {
     }
 }
 
+fn text_to_tristate(text: &str) -> Tristate {
+    match text {
+        "yes" => Tristate::Yes,
+        "no" => Tristate::No,
+        _ => Tristate::Null,
+    }
+}
+
 fn value_to_tristate(value: &Value) -> Option<Tristate> {
     match value {
         Value::Bool(true) => Some(Tristate::Yes),
@@ -118,60 +236,42 @@ impl ManagedField for PwtTristateBoolean {
     fn create(ctx: &ManagedFieldContext<Self>) -> Self {
         let props = ctx.props();
 
-        let items = vec![
-            AttrValue::Static(""),
-            AttrValue::Static("yes"),
-            AttrValue::Static("no"),
-        ];
-
         let store = Store::with_extract_key(|item: &AttrValue| Key::from(item.as_str()));
-        store.set_data(items);
-
-        let yes_text = props
-            .yes_text
-            .clone()
-            .map(|s| s.to_string())
-            .unwrap_or(tr!("Yes"));
-        let no_text = props
-            .no_text
-            .clone()
-            .map(|s| s.to_string())
-            .unwrap_or(tr!("No"));
-        let null_text = props
-            .null_text
-            .clone()
-            .map(|s| s.to_string())
-            .unwrap_or(tr!("Default"));
-
-        let render_value = RenderFn::new(move |value: &AttrValue| {
-            let text = match value.as_str() {
-                "" => null_text.clone(),
-                "yes" => yes_text.clone(),
-                "no" => no_text.clone(),
-                unknown => unknown.to_string(), // should never happen
-            };
-            html! {text}
-        });
-
-        let columns = Rc::new(vec![
-            DataTableColumn::new("Value")
-                .show_menu(false)
-                .render(render_value.clone())
-                .into(),
-        ]);
 
         let selection = Selection::new();
         selection.select(Key::from(tristate_to_text(
             props.default.unwrap_or(Tristate::Null),
         )));
 
-        Self {
+        let (render_value, columns) = Self::create_renderer(props);
+
+        let me = Self {
             state: ManagedFieldState::new(Value::Null, Value::Null),
             store,
             selection,
             columns,
             render_value,
+        };
+        me.update_store(props);
+        me
+    }
+
+    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.cycle_order != old_props.cycle_order {
+            self.update_store(props);
+        }
+        if props.yes_text != old_props.yes_text
+            || props.no_text != old_props.no_text
+            || props.null_text != old_props.null_text
+            || props.yes_icon != old_props.yes_icon
+            || props.no_icon != old_props.no_icon
+            || props.null_icon != old_props.null_icon
+            || props.inherited != old_props.inherited
+        {
+            (self.render_value, self.columns) = Self::create_renderer(props);
         }
+        true
     }
 
     fn value_changed(&mut self, ctx: &super::ManagedFieldContext<Self>) {
@@ -194,22 +294,173 @@ impl ManagedField for PwtTristateBoolean {
         let props = ctx.props();
         match msg {
             Msg::Select(value) => {
-                if props.input_props.disabled {
+                if self.is_disabled(props) {
                     return false;
                 }
 
-                let value = match value.as_str() {
-                    "yes" => Value::Bool(true),
-                    "no" => Value::Bool(false),
-                    "" => Value::Null,
-                    _ => Value::Null, // should never happen
+                ctx.link()
+                    .update_value(tristate_to_value(text_to_tristate(&value)));
+                false
+            }
+            Msg::Cycle => {
+                if self.is_disabled(props) {
+                    return false;
+                }
+                let order = props.state_order();
+                let current = value_to_tristate(&self.value).unwrap_or(Tristate::Null);
+                let next = match order.iter().position(|state| *state == current) {
+                    Some(pos) => order[(pos + 1) % order.len()],
+                    None => order[0],
                 };
-                ctx.link().update_value(value);
+                ctx.link().update_value(tristate_to_value(next));
                 false
             }
         }
     }
+
     fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+        match props.display {
+            TristateDisplay::Dropdown => self.view_dropdown(ctx),
+            TristateDisplay::Segmented => self.view_segmented(ctx),
+            TristateDisplay::Checkbox => self.view_checkbox(ctx),
+        }
+    }
+}
+
+fn tristate_to_value(tristate: Tristate) -> Value {
+    match tristate {
+        Tristate::Yes => Value::Bool(true),
+        Tristate::No => Value::Bool(false),
+        Tristate::Null => Value::Null,
+    }
+}
+
+impl PwtTristateBoolean {
+    fn create_renderer(
+        props: &TristateBoolean,
+    ) -> (RenderFn<AttrValue>, Rc<Vec<DataTableHeader<AttrValue>>>) {
+        let render_props = props.clone();
+        let render_value = RenderFn::new(move |value: &AttrValue| {
+            render_props.render_state(text_to_tristate(value))
+        });
+
+        let columns = Rc::new(vec![
+            DataTableColumn::new("Value")
+                .show_menu(false)
+                .render(render_value.clone())
+                .into(),
+        ]);
+
+        (render_value, columns)
+    }
+
+    fn update_store(&self, props: &TristateBoolean) {
+        let items = props
+            .state_order()
+            .into_iter()
+            .map(|state| AttrValue::from(tristate_to_text(state)))
+            .collect();
+        self.store.set_data(items);
+    }
+
+    fn validation_class(&self) -> &'static str {
+        if self.result.is_ok() {
+            "is-valid"
+        } else {
+            "is-invalid"
+        }
+    }
+
+    fn view_segmented(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+        let disabled = self.is_disabled(props);
+        let current = value_to_tristate(&self.value);
+
+        let mut segmented = SegmentedButton::new()
+            .with_std_props(&props.std_props)
+            .class("pwt-tristate-segmented")
+            .class(self.validation_class());
+
+        for state in props.state_order() {
+            let mut button = Button::new(props.state_text(state))
+                .disabled(disabled)
+                .pressed(current == Some(state))
+                .on_activate(
+                    ctx.link()
+                        .callback(move |_: MouseEvent| Msg::Select(tristate_to_text(state))),
+                );
+            if let Some(icon) = props.state_icon(state) {
+                button.set_icon_class(icon);
+            }
+            segmented.add_button(button);
+        }
+
+        let mut tooltip = Tooltip::new(segmented);
+        if let Err(msg) = &self.result {
+            tooltip.set_tip(msg.clone());
+        }
+        tooltip.into()
+    }
+
+    fn view_checkbox(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+        let disabled = self.is_disabled(props);
+        let current = value_to_tristate(&self.value).unwrap_or(Tristate::Null);
+
+        let onkeyup = Callback::from({
+            let link = ctx.link().clone();
+            move |event: KeyboardEvent| {
+                if event.key() == " " {
+                    link.send_message(Msg::Cycle);
+                }
+            }
+        });
+
+        let (icon, aria_checked) = match current {
+            Tristate::Yes => ("check", "true"),
+            Tristate::No => ("times", "false"),
+            Tristate::Null => ("minus", "mixed"),
+        };
+
+        let checkbox = Container::new().class("pwt-checkbox-state").with_child(
+            Container::new()
+                .class("pwt-checkbox")
+                .class("checked")
+                .class(format!("pwt-tristate-{}", tristate_to_text(current)))
+                .class(disabled.then_some("disabled"))
+                .class(self.validation_class())
+                .attribute(
+                    "tabindex",
+                    props.input_props.tabindex.unwrap_or(0).to_string(),
+                )
+                .attribute("role", "checkbox")
+                .attribute("aria-checked", aria_checked)
+                .onkeyup(onkeyup)
+                .with_child(
+                    Container::from_tag("span")
+                        .class("pwt-checkbox-icon")
+                        .with_child(Fa::new(icon)),
+                ),
+        );
+
+        let mut tooltip = Tooltip::new(
+            Row::new()
+                .gap(2)
+                .class(AlignItems::Center)
+                .with_child(checkbox)
+                .with_child(props.render_state(current)),
+        )
+        .with_std_props(&props.std_props)
+        .onclick(ctx.link().callback(|_: MouseEvent| Msg::Cycle));
+
+        if let Err(msg) = &self.result {
+            tooltip.set_tip(msg.clone());
+        }
+        tooltip.into()
+    }
+
+    fn view_dropdown(&self, ctx: &ManagedFieldContext<Self>) -> Html {
         let props = ctx.props();
         let (value, validation_result) = (&self.value, &self.result);
         let value_text = match value_to_tristate(value) {
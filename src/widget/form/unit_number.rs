@@ -0,0 +1,659 @@
+use anyhow::{Error, bail};
+use serde_json::Value;
+
+use web_sys::HtmlInputElement;
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::prelude::*;
+
+use pwt_macros::{builder, widget};
+
+use super::{
+    IntoValidateFn, ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt,
+    ManagedFieldState, ValidateFn,
+};
+use crate::props::{ContainerBuilder, EventSubscriber, WidgetBuilder};
+use crate::widget::menu::{Menu, MenuButton, MenuCheckbox, MenuEvent};
+use crate::widget::{Input, Tooltip};
+
+use crate::tr;
+
+pub type PwtUnitNumber = ManagedFieldMaster<UnitNumberField>;
+
+/// Measurement unit used by [NumberUnits].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberUnit {
+    /// The unit symbol (i.e. `"m"` or `"MiB/s"`).
+    pub symbol: AttrValue,
+    /// Size of the unit as multiple of the base unit.
+    pub factor: f64,
+}
+
+impl NumberUnit {
+    /// Create a new instance.
+    pub fn new(symbol: impl Into<AttrValue>, factor: f64) -> Self {
+        Self {
+            symbol: symbol.into(),
+            factor,
+        }
+    }
+}
+
+/// Set of units to parse and format values with units.
+///
+/// Values are numbers followed by a unit symbol (i.e. `"30m"` or
+/// `"1.5GiB/s"`), using `.` as decimal separator. If `compound` is set,
+/// multiple values are summed up (i.e. `"1h 30m"`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberUnits {
+    // sorted by factor
+    units: Vec<NumberUnit>,
+    compound: bool,
+}
+
+impl NumberUnits {
+    /// Create a new instance.
+    ///
+    /// The first unit (by factor) is the base unit.
+    pub fn new(units: impl IntoIterator<Item = NumberUnit>) -> Self {
+        let mut units: Vec<NumberUnit> = units.into_iter().collect();
+        units.sort_by(|a, b| a.factor.total_cmp(&b.factor));
+        Self {
+            units,
+            compound: false,
+        }
+    }
+
+    /// Builder style method to allow compound values (i.e. `"1h 30m"`).
+    pub fn compound(mut self, compound: bool) -> Self {
+        self.compound = compound;
+        self
+    }
+
+    /// Time spans in seconds (`s`, `m`, `h`, `d`, `w`), with compound values.
+    pub fn duration() -> Self {
+        Self::new([
+            NumberUnit::new("s", 1.0),
+            NumberUnit::new("m", 60.0),
+            NumberUnit::new("h", 3600.0),
+            NumberUnit::new("d", 86400.0),
+            NumberUnit::new("w", 604800.0),
+        ])
+        .compound(true)
+    }
+
+    /// Bandwidth in bytes per second (`B/s` up to `TiB/s`).
+    pub fn bandwidth() -> Self {
+        Self::new([
+            NumberUnit::new("B/s", 1.0),
+            NumberUnit::new("KiB/s", 1024.0),
+            NumberUnit::new("MiB/s", 1024.0 * 1024.0),
+            NumberUnit::new("GiB/s", 1024.0 * 1024.0 * 1024.0),
+            NumberUnit::new("TiB/s", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ])
+    }
+
+    /// Returns the units (sorted by factor).
+    pub fn units(&self) -> &[NumberUnit] {
+        &self.units
+    }
+
+    /// Lookup a unit by symbol.
+    ///
+    /// Falls back to a case insensitive match (i.e. `"gib/s"`).
+    pub fn find(&self, symbol: &str) -> Option<&NumberUnit> {
+        self.units
+            .iter()
+            .find(|unit| unit.symbol == symbol)
+            .or_else(|| {
+                self.units
+                    .iter()
+                    .find(|unit| unit.symbol.eq_ignore_ascii_case(symbol))
+            })
+    }
+
+    // Split into (number, unit) pairs.
+    fn split<'a>(&self, text: &'a str) -> Result<Vec<(f64, &'a str)>, Error> {
+        let mut parts = Vec::new();
+        let mut rest = text.trim();
+        if rest.is_empty() {
+            bail!(tr!("missing number"));
+        }
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(rest.len());
+            let number = match rest[..end].parse::<f64>() {
+                Ok(number) if number.is_finite() => number,
+                _ => bail!(tr!("expected number at '{0}'", rest)),
+            };
+            rest = rest[end..].trim_start();
+            let end = rest
+                .find(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace())
+                .unwrap_or(rest.len());
+            parts.push((number, &rest[..end]));
+            rest = rest[end..].trim_start();
+        }
+        Ok(parts)
+    }
+
+    /// Parse a value, returns the number in base units.
+    ///
+    /// Numbers without unit use the `default_unit` (base unit if not set).
+    pub fn parse(&self, text: &str, default_unit: Option<&NumberUnit>) -> Result<f64, Error> {
+        let parts = self.split(text)?;
+        if parts.len() > 1 && !self.compound {
+            bail!(tr!("expected a single value"));
+        }
+
+        let mut value = 0.0;
+        for (number, symbol) in &parts {
+            let factor = if symbol.is_empty() {
+                if parts.len() > 1 {
+                    bail!(tr!("missing unit"));
+                }
+                default_unit.or(self.units.first()).map(|u| u.factor)
+            } else {
+                match self.find(symbol) {
+                    Some(unit) => Some(unit.factor),
+                    None => bail!(tr!("unknown unit '{0}'", symbol)),
+                }
+            };
+            value += number * factor.unwrap_or(1.0);
+        }
+        Ok(value)
+    }
+
+    /// Returns the unit used in `text`.
+    ///
+    /// Only set for single values with unit symbol.
+    pub fn unit_of(&self, text: &str) -> Option<&NumberUnit> {
+        match self.split(text).ok()?.as_slice() {
+            [(_, symbol)] if !symbol.is_empty() => self.find(symbol),
+            _ => None,
+        }
+    }
+
+    /// Format a value (in base units) using the given unit.
+    pub fn format_with_unit(value: f64, unit: &NumberUnit) -> String {
+        format!("{}{}", value / unit.factor, unit.symbol)
+    }
+
+    /// Format a value (in base units).
+    ///
+    /// Uses the largest unit which represents the value with at most two
+    /// decimal places, so that the text can be parsed without loss. Compound
+    /// values are split into all units (i.e. `"1h 30m"`).
+    pub fn format(&self, value: f64) -> String {
+        let Some(base) = self.units.first() else {
+            return value.to_string();
+        };
+
+        if self.compound && value > 0.0 {
+            let mut parts = Vec::new();
+            let mut rest = value;
+            for unit in self.units.iter().skip(1).rev() {
+                let count = (rest / unit.factor).floor();
+                if count >= 1.0 {
+                    parts.push(format!("{count}{}", unit.symbol));
+                    rest -= count * unit.factor;
+                }
+            }
+            if rest > 0.0 {
+                parts.push(Self::format_with_unit(rest, base));
+            }
+            return parts.join(" ");
+        }
+
+        let unit = self
+            .units
+            .iter()
+            .rev()
+            .find(|unit| {
+                let n = value / unit.factor;
+                n.abs() >= 1.0 && (n * 100.0).round() == n * 100.0
+            })
+            .unwrap_or(base);
+        Self::format_with_unit(value, unit)
+    }
+}
+
+// Submit integer values as json integers.
+fn number_to_value(number: f64) -> Value {
+    if number.fract() == 0.0 && number.abs() < (1u64 << 53) as f64 {
+        Value::from(number as i64)
+    } else {
+        Value::from(number)
+    }
+}
+
+/// Number input with units, i.e. for durations (`"30m"`) or bandwidth
+/// limits (`"1.5GiB/s"`).
+///
+/// The text is parsed with [NumberUnits], and the value is submitted as
+/// json number in base units (seconds or bytes per second for the
+/// predefined [duration](Self::duration) and [bandwidth](Self::bandwidth)
+/// fields). A trigger shows the current unit, and allows to convert the
+/// value to another unit.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::form::UnitNumber;
+/// # fn test() {
+/// let timeout = UnitNumber::duration().name("timeout").default(1800.0);
+/// let limit = UnitNumber::bandwidth().name("rate").unit("MiB/s");
+/// # }
+/// ```
+#[widget(pwt=crate, comp=ManagedFieldMaster<UnitNumberField>, @input, @element)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct UnitNumber {
+    /// The units.
+    pub units: NumberUnits,
+
+    /// Unit used for numbers without unit symbol (defaults to the base unit).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub unit: Option<AttrValue>,
+
+    /// Minimum value (in base units).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub min: Option<f64>,
+
+    /// Maximum value (in base units).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub max: Option<f64>,
+
+    /// Force value.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+
+    /// Force validation result.
+    ///
+    /// To implement controlled components (for use without a FormContext).
+    /// This is ignored if the field has a name.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub valid: Option<Result<Value, String>>,
+
+    /// Default value (in base units).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub default: Option<f64>,
+
+    /// Validation function (called with the value in base units).
+    #[prop_or_default]
+    pub validate: Option<ValidateFn<f64>>,
+
+    /// Change callback (value in base units).
+    #[builder_cb(IntoEventCallback, into_event_callback, Option<Result<f64, String>>)]
+    #[prop_or_default]
+    pub on_change: Option<Callback<Option<Result<f64, String>>>>,
+}
+
+impl UnitNumber {
+    /// Create a new instance.
+    pub fn new(units: NumberUnits) -> Self {
+        yew::props!(Self { units })
+    }
+
+    /// Create a duration field (see [NumberUnits::duration]).
+    pub fn duration() -> Self {
+        Self::new(NumberUnits::duration())
+    }
+
+    /// Create a bandwidth field (see [NumberUnits::bandwidth]).
+    pub fn bandwidth() -> Self {
+        Self::new(NumberUnits::bandwidth())
+    }
+
+    /// Builder style method to set the validate callback
+    pub fn validate(mut self, validate: impl IntoValidateFn<f64>) -> Self {
+        self.set_validate(validate);
+        self
+    }
+
+    /// Method to set the validate callback
+    pub fn set_validate(&mut self, validate: impl IntoValidateFn<f64>) {
+        self.validate = validate.into_validate_fn();
+    }
+
+    fn default_unit(&self) -> Option<&NumberUnit> {
+        self.unit
+            .as_deref()
+            .and_then(|symbol| self.units.find(symbol))
+    }
+}
+
+pub enum Msg {
+    Update(String),
+    SelectUnit(NumberUnit),
+}
+
+#[doc(hidden)]
+pub struct UnitNumberField {
+    state: ManagedFieldState,
+}
+
+impl std::ops::Deref for UnitNumberField {
+    type Target = ManagedFieldState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl std::ops::DerefMut for UnitNumberField {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.state
+    }
+}
+
+#[derive(PartialEq)]
+pub struct ValidateClosure {
+    required: bool,
+    units: NumberUnits,
+    unit: Option<NumberUnit>,
+    min: Option<f64>,
+    max: Option<f64>,
+    validate: Option<ValidateFn<f64>>,
+}
+
+impl UnitNumberField {
+    fn value_text(&self, units: &NumberUnits) -> String {
+        match &self.value {
+            Value::Number(number) => number.as_f64().map(|n| units.format(n)).unwrap_or_default(),
+            Value::String(text) => text.clone(),
+            _ => String::new(),
+        }
+    }
+}
+
+impl ManagedField for UnitNumberField {
+    type Properties = UnitNumber;
+    type Message = Msg;
+    type ValidateClosure = ValidateClosure;
+
+    fn validation_args(props: &Self::Properties) -> Self::ValidateClosure {
+        ValidateClosure {
+            required: props.input_props.required,
+            units: props.units.clone(),
+            unit: props.default_unit().cloned(),
+            min: props.min,
+            max: props.max,
+            validate: props.validate.clone(),
+        }
+    }
+
+    fn validator(props: &Self::ValidateClosure, value: &Value) -> Result<Value, Error> {
+        let number = match value {
+            Value::Null => None,
+            Value::Number(number) => number.as_f64(),
+            Value::String(text) if text.trim().is_empty() => None,
+            Value::String(text) => match props.units.parse(text, props.unit.as_ref()) {
+                Ok(number) => Some(number),
+                Err(err) => return Err(Error::msg(tr!("Input invalid: {}", err.to_string()))),
+            },
+            _ => return Err(Error::msg(tr!("Got wrong data type!"))),
+        };
+
+        let Some(number) = number else {
+            if props.required {
+                return Err(Error::msg(tr!("Field may not be empty.")));
+            }
+            return Ok(Value::Null);
+        };
+
+        if let Some(min) = props.min {
+            if number < min {
+                return Err(Error::msg(tr!(
+                    "value must be greater than or equal to '{0}'",
+                    props.units.format(min)
+                )));
+            }
+        }
+        if let Some(max) = props.max {
+            if number > max {
+                return Err(Error::msg(tr!(
+                    "value must be less than or equal to '{0}'",
+                    props.units.format(max)
+                )));
+            }
+        }
+
+        if let Some(validate) = &props.validate {
+            validate.apply(&number)?;
+        }
+        Ok(number_to_value(number))
+    }
+
+    fn create(ctx: &ManagedFieldContext<Self>) -> Self {
+        let props = ctx.props();
+
+        let mut value = Value::Null;
+        if let Some(default) = props.default {
+            value = props.units.format(default).into();
+        }
+        if let Some(force_value) = &props.value {
+            value = force_value.to_string().into();
+        }
+
+        let default = match props.default {
+            Some(default) => number_to_value(default),
+            None => Value::Null,
+        };
+
+        Self {
+            state: ManagedFieldState::new(value, default),
+        }
+    }
+
+    fn value_changed(&mut self, ctx: &ManagedFieldContext<Self>) {
+        let props = ctx.props();
+        let data = match &self.result {
+            Ok(Value::Null) => None,
+            Ok(value) => value.as_f64().map(Ok),
+            Err(err) => Some(Err(err.clone())),
+        };
+        if let Some(on_change) = &props.on_change {
+            on_change.emit(data);
+        }
+    }
+
+    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.value != old_props.value || props.valid != old_props.valid {
+            ctx.link().force_value(
+                props.value.as_ref().map(|v| v.to_string()),
+                props.valid.clone(),
+            );
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &ManagedFieldContext<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Update(input) => {
+                ctx.link().update_value(input);
+                true
+            }
+            Msg::SelectUnit(unit) => {
+                let text = self.value_text(&props.units);
+                let text = text.trim();
+                if text.is_empty() {
+                    return false;
+                }
+                let has_unit = props
+                    .units
+                    .split(text)
+                    .is_ok_and(|parts| parts.iter().any(|(_, symbol)| !symbol.is_empty()));
+                let text = if has_unit {
+                    // convert to the selected unit
+                    match props.units.parse(text, None) {
+                        Ok(number) => NumberUnits::format_with_unit(number, &unit),
+                        Err(_) => return false,
+                    }
+                } else {
+                    // plain number - use the selected unit
+                    format!("{text}{}", unit.symbol)
+                };
+                ctx.link().update_value(text);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
+        let props = ctx.props();
+        let value_text = self.value_text(&props.units);
+
+        let oninput = ctx.link().callback(|input: InputEvent| {
+            let input: HtmlInputElement = input.target_unchecked_into();
+            Msg::Update(input.value())
+        });
+
+        let disabled = self.is_disabled(props);
+        let input: Html = Input::new()
+            .with_input_props(&props.input_props)
+            .disabled(disabled)
+            .class(crate::css::Flex::Fill)
+            .attribute("type", "text")
+            .attribute("value", value_text.clone())
+            .attribute("inputmode", "decimal")
+            .oninput((!disabled).then_some(oninput))
+            .onfocus(ctx.link().focus_callback(true))
+            .onblur(ctx.link().focus_callback(false))
+            .into();
+
+        let current_unit = props
+            .units
+            .unit_of(&value_text)
+            .or(props.default_unit())
+            .or(props.units.units().first());
+
+        let unit_menu = {
+            let units = props.units.clone();
+            let current = current_unit.map(|unit| unit.symbol.clone());
+            let link = ctx.link().clone();
+            move || {
+                let mut menu = Menu::new();
+                for unit in units.units() {
+                    let checked = current.as_ref() == Some(&unit.symbol);
+                    let unit = unit.clone();
+                    let link = link.clone();
+                    menu.add_item(
+                        MenuCheckbox::radio(unit.symbol.clone())
+                            .checked(checked)
+                            .on_click(move |_: MenuEvent| {
+                                link.send_message(Msg::SelectUnit(unit.clone()))
+                            }),
+                    );
+                }
+                menu
+            }
+        };
+
+        let unit_trigger = MenuButton::new(
+            current_unit
+                .map(|unit| unit.symbol.clone())
+                .unwrap_or_default(),
+        )
+        .class("pwt-button-text")
+        .disabled(disabled)
+        .show_arrow(true)
+        .tabindex(-1)
+        .menu_builder(unit_menu);
+
+        let mut input_container = Tooltip::empty()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-input")
+            .class("pwt-input-type-unit-number")
+            .class(crate::css::FullWidth)
+            .class(disabled.then_some("disabled"))
+            .class(if self.result.is_ok() {
+                "is-valid"
+            } else {
+                "is-invalid"
+            })
+            .with_child(input)
+            .with_child(html! {
+                <div class="pwt-flex-fill-first-child pwt-d-flex pwt-align-self-center">
+                    {unit_trigger}
+                </div>
+            });
+
+        if let Err(msg) = &self.result {
+            input_container.set_tip(msg.clone())
+        }
+
+        input_container.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NumberUnit, NumberUnits, number_to_value};
+
+    #[test]
+    fn test_parse_duration() {
+        let units = NumberUnits::duration();
+        assert_eq!(units.parse("30", None).unwrap(), 30.0);
+        assert_eq!(units.parse("30m", None).unwrap(), 1800.0);
+        assert_eq!(units.parse(" 1h 30m ", None).unwrap(), 5400.0);
+        assert_eq!(units.parse("1h30m", None).unwrap(), 5400.0);
+        assert_eq!(units.parse("1.5 H", None).unwrap(), 5400.0);
+
+        let minute = NumberUnit::new("m", 60.0);
+        assert_eq!(units.parse("2", Some(&minute)).unwrap(), 120.0);
+
+        assert!(units.parse("", None).is_err());
+        assert!(units.parse("m", None).is_err());
+        assert!(units.parse("5x", None).is_err());
+        assert!(units.parse("1h 30", None).is_err());
+        assert!(units.parse("-5s", None).is_err());
+    }
+
+    #[test]
+    fn test_parse_bandwidth() {
+        let units = NumberUnits::bandwidth();
+        assert_eq!(units.parse("1.5GiB/s", None).unwrap(), 1610612736.0);
+        assert_eq!(units.parse("100 kib/s", None).unwrap(), 102400.0);
+        assert!(units.parse("1MiB/s 1KiB/s", None).is_err());
+        assert_eq!(units.unit_of("10 MiB/s").unwrap().factor, 1048576.0);
+        assert!(units.unit_of("10").is_none());
+    }
+
+    #[test]
+    fn test_format() {
+        let duration = NumberUnits::duration();
+        assert_eq!(duration.format(0.0), "0s");
+        assert_eq!(duration.format(1800.0), "30m");
+        assert_eq!(duration.format(5400.0), "1h 30m");
+        assert_eq!(duration.format(90.5), "1m 30.5s");
+
+        let bandwidth = NumberUnits::bandwidth();
+        assert_eq!(bandwidth.format(1610612736.0), "1.5GiB/s");
+        assert_eq!(bandwidth.format(1000.0), "1000B/s");
+        assert_eq!(bandwidth.format(1500.0), "1500B/s");
+
+        for value in [0.0, 1.0, 1500.0, 1610612736.0, 123456789.0] {
+            assert_eq!(
+                bandwidth.parse(&bandwidth.format(value), None).unwrap(),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_to_value() {
+        assert_eq!(number_to_value(1800.0), serde_json::json!(1800));
+        assert_eq!(number_to_value(1.5), serde_json::json!(1.5));
+    }
+}
@@ -8,7 +8,7 @@ use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
-use crate::css::{Display, FlexDirection};
+use crate::css::{AlignItems, Display, FlexDirection, FullWidth};
 use crate::props::{FilterFn, IntoTextFilterFn, TextFilterFn};
 use crate::state::{DataStore, Selection};
 use crate::widget::data_table::DataTable;
@@ -24,6 +24,11 @@ use super::data_table::CellConfiguration;
 /// Allows you to select one or more items from a table. This is usually used
 /// to implement [Dropdown](crate::widget::Dropdown) pickers.
 ///
+/// Options can be grouped by setting a group extract function on the
+/// table (see [DataTable::group_extract]). Group headers stick to the top
+/// while scrolling and can be collapsed. Keyboard navigation skips group
+/// headers and collapsed items.
+///
 /// # Note
 ///
 /// This widget overwrites the store filter, so the passed store can not
@@ -87,6 +92,11 @@ pub struct GridPicker<S: DataStore> {
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or_default]
     pub filter_below: Option<bool>,
+
+    /// Enable kinetic drag-to-scroll with the mouse (see [DataTable::drag_scroll]).
+    #[builder]
+    #[prop_or_default]
+    pub drag_scroll: bool,
 }
 
 impl<S: DataStore> GridPicker<S> {
@@ -190,6 +200,7 @@ impl<S: DataStore + 'static> Component for PwtGridPicker<S> {
             .cell_configuration(CellConfiguration::new().class("pwt-datatable-cell pwt-pointer"))
             .hover(true)
             .header_focusable(false)
+            .drag_scroll(props.drag_scroll || props.table.drag_scroll)
             .selection(self.selection.clone())
             .into();
 
@@ -213,7 +224,8 @@ impl<S: DataStore + 'static> Component for PwtGridPicker<S> {
                 .key(Key::from("picker-filter"))
                 .gap(2)
                 .padding(2)
-                .class("pwt-w-100 pwt-align-items-center")
+                .class(FullWidth)
+                .class(AlignItems::Center)
                 .with_child(html! {<label for="testinput">{"Filter"}</label>})
                 .with_child(
                     Input::new()
@@ -221,7 +233,7 @@ impl<S: DataStore + 'static> Component for PwtGridPicker<S> {
                         .attribute("autocomplete", "off")
                         .attribute("size", "1") // make size minimal
                         .class("pwt-input")
-                        .class("pwt-w-100")
+                        .class(FullWidth)
                         .class(if filter_invalid {
                             "is-invalid"
                         } else {
@@ -0,0 +1,119 @@
+use yew::html::IntoPropValue;
+
+use crate::prelude::*;
+use crate::state::AppInfo;
+use crate::widget::ActionIcon;
+
+use pwt_macros::{builder, widget};
+
+/// Help button opening the online documentation.
+///
+/// The documentation URL is generated from the [AppInfo] context (see
+/// [AppInfo::help_url]), and opened in a new tab. Nothing is rendered if
+/// there is no [AppInfo] context or no documentation URL.
+///
+/// [Panel](super::Panel) and [Dialog](super::Dialog) add this button
+/// automatically if you set a `help_id`.
+#[widget(pwt=crate, comp=PwtHelpButton, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct HelpButton {
+    /// The help ID (documentation page and anchor).
+    pub help_id: AttrValue,
+
+    /// Aria label (default "Help").
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+}
+
+impl HelpButton {
+    /// Create a new instance.
+    pub fn new(help_id: impl Into<AttrValue>) -> Self {
+        yew::props!(Self {
+            help_id: help_id.into()
+        })
+    }
+}
+
+pub enum Msg {
+    AppInfoUpdate(AppInfo),
+    Open,
+}
+
+#[doc(hidden)]
+pub struct PwtHelpButton {
+    app_info: Option<AppInfo>,
+    _app_info_handle: Option<ContextHandle<AppInfo>>,
+}
+
+impl Component for PwtHelpButton {
+    type Message = Msg;
+    type Properties = HelpButton;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (app_info, _app_info_handle) = match ctx
+            .link()
+            .context::<AppInfo>(ctx.link().callback(Msg::AppInfoUpdate))
+        {
+            Some((app_info, handle)) => (Some(app_info), Some(handle)),
+            None => (None, None),
+        };
+
+        Self {
+            app_info,
+            _app_info_handle,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::AppInfoUpdate(app_info) => {
+                self.app_info = Some(app_info);
+                true
+            }
+            Msg::Open => {
+                let url = self
+                    .app_info
+                    .as_ref()
+                    .and_then(|info| info.help_url(&props.help_id));
+                if let Some(url) = url {
+                    if let Err(err) = gloo_utils::window().open_with_url_and_target(&url, "_blank")
+                    {
+                        log::error!("unable to open help: {}", crate::convert_js_error(err));
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let has_url = self
+            .app_info
+            .as_ref()
+            .map(|info| info.docs_url.is_some())
+            .unwrap_or(false);
+
+        if !has_url {
+            return html! {};
+        }
+
+        ActionIcon::new("fa fa-question-circle")
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-help-button")
+            .tabindex(0)
+            .aria_label(
+                props
+                    .aria_label
+                    .clone()
+                    .unwrap_or_else(|| tr!("Help").into()),
+            )
+            .on_activate(ctx.link().callback(|_| Msg::Open))
+            .into()
+    }
+}
@@ -6,16 +6,10 @@ use pwt_macros::widget;
 use crate::props::{FieldStdProps, IntoVTag, WidgetStdProps};
 
 /// Html Input element.
-#[widget(pwt=crate, @input, @element)]
+#[widget(pwt=crate, @input, @element, @default)]
 #[derive(Clone, PartialEq, Properties)]
 pub struct Input {}
 
-impl Default for Input {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl Input {
     /// Creates a new instance.
     pub fn new() -> Self {
@@ -6,10 +6,22 @@ use yew::virtual_dom::{Key, Listeners, VList, VTag};
 
 use pwt_macros::{builder, widget};
 
+use crate::dom::DomSizeObserver;
 use crate::prelude::*;
 use crate::props::{PwtSpace, WidgetStyleBuilder};
 use crate::widget::{Container, FieldLabel, Row};
 
+// CSS variables used to override the grid placement of all children
+// when the panel is collapsed to a single column. The theme resets them
+// for each `pwt-input-panel` (so that nested panels do not inherit them),
+// and sets them for `pwt-input-panel-collapsed`.
+const ROW_OVERRIDE: &str = "--pwt-input-panel-row";
+const COLUMN_OVERRIDE: &str = "--pwt-input-panel-column";
+
+fn grid_placement(row: impl std::fmt::Display, column: impl std::fmt::Display) -> String {
+    format!("grid-row: var({ROW_OVERRIDE}, {row}); grid-column: var({COLUMN_OVERRIDE}, {column});")
+}
+
 pub enum FieldPosition {
     Left,
     Right,
@@ -47,6 +59,23 @@ impl<T: FieldBuilder> Labelable for T {
 ///
 /// This container show input fields with labels at different regions
 /// (left, right, advanced).
+///
+/// Set [collapse_width](Self::collapse_width) to automatically switch to
+/// a single column layout (labels above fields) when the panel gets
+/// narrower than the specified width.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::InputPanel;
+/// # use pwt::widget::form::Field;
+/// # fn create_panel() -> InputPanel {
+///     InputPanel::new()
+///         .collapse_width(600)
+///         .with_field("Name", Field::new().name("name"))
+///         .with_right_field("Email", Field::new().name("email"))
+///         .with_full_width_field("Comment", Field::new().name("comment"))
+/// # }
+/// ```
 #[widget(pwt=crate, @element, @container)]
 #[builder]
 #[derive(Properties, PartialEq, Clone)]
@@ -85,6 +114,14 @@ pub struct InputPanel {
     #[prop_or_default]
     #[builder]
     pub mobile: bool,
+
+    /// Collapse to a single column if the panel is narrower than this width (in pixels).
+    ///
+    /// Labels are then positioned above the fields. This is ignored by the
+    /// mobile view (which always uses a single column).
+    #[prop_or_default]
+    #[builder(IntoPropValue, into_prop_value)]
+    pub collapse_width: Option<u32>,
 }
 
 impl InputPanel {
@@ -136,7 +173,10 @@ impl InputPanel {
             FieldPosition::Large,
             advanced,
             false,
-            Container::from_tag("hr").key(key).class("pwt-w-100").into(),
+            Container::from_tag("hr")
+                .key(key)
+                .class(crate::css::FullWidth)
+                .into(),
         );
         // Note: do not change two_column when adding a spacer!
         self.two_column = two_column;
@@ -242,7 +282,7 @@ impl InputPanel {
         };
 
         let style = if visible {
-            format!("grid-row: {}; grid-column: {}/{};", row, start, span)
+            grid_placement(row, format!("{}/{}", start, span))
         } else {
             "display: none;".to_string()
         };
@@ -267,7 +307,7 @@ impl InputPanel {
             self.add_child(
                 Container::new()
                     .key(key)
-                    .class("pwt-align-self-center")
+                    .class(crate::css::AlignSelf::Center)
                     .attribute("style", style)
                     .with_child(child),
             );
@@ -291,20 +331,20 @@ impl InputPanel {
             visible = false;
         }
 
-        let (label_column, row, field_class) = if visible {
+        let (label_column, row, field_class, field_column) = if visible {
             if self.mobile {
                 self.left_count += 1; // ignore position
-                (1, self.left_count, "pwt-single-grid-row")
+                (1, self.left_count, "pwt-single-grid-row", "1/-1")
             } else {
                 match column {
                     FieldPosition::Left => {
                         self.left_count += 1;
-                        (1, self.left_count, "pwt-grid-column-2")
+                        (1, self.left_count, "pwt-grid-column-2", "2")
                     }
                     FieldPosition::Right => {
                         self.two_column = true;
                         self.right_count += 1;
-                        (3, self.right_count, "pwt-grid-column-4")
+                        (3, self.right_count, "pwt-grid-column-4", "4")
                     }
                     FieldPosition::Large => {
                         self.two_column = true;
@@ -313,18 +353,21 @@ impl InputPanel {
                         self.left_count = max + 1;
                         self.right_count = max + 1;
 
-                        (1, self.left_count, "pwt-fill-grid-row")
+                        (1, self.left_count, "pwt-fill-grid-row", "2/-1")
                     }
                 }
             }
         } else {
-            (1, 10000, "pwt-grid-column-2")
+            (1, 10000, "pwt-grid-column-2", "2")
         };
 
-        let style = if visible {
-            format!("grid-row: {};", row)
+        let (label_style, style) = if visible {
+            (
+                grid_placement(row, label_column),
+                grid_placement(row, field_column),
+            )
         } else {
-            "display: none;".to_string()
+            ("display: none;".to_string(), "display: none;".to_string())
         };
 
         let label_id = crate::widget::get_unique_element_id();
@@ -363,16 +406,16 @@ impl InputPanel {
         } else {
             self.add_child(
                 label
-                    .class("pwt-align-self-center")
+                    .class(crate::css::AlignSelf::Center)
                     .class(format!("pwt-grid-column-{}", label_column))
                     .class(is_disabled.then_some("pwt-label-disabled"))
-                    .attribute("style", style.clone()),
+                    .attribute("style", label_style),
             );
 
             self.add_child(
                 Container::new()
                     .key(key)
-                    .class("pwt-align-self-center")
+                    .class(crate::css::AlignSelf::Center)
                     .class(field_class)
                     .attribute("style", style)
                     .with_child(field),
@@ -473,6 +516,60 @@ impl InputPanel {
         self.add_field_impl(FieldPosition::Large, advanced, hidden, label, field)
     }
 
+    /// Builder style method to add a field spanning the full width, with the label above.
+    pub fn with_full_width_field(
+        mut self,
+        label: impl Into<FieldLabel>,
+        field: impl Labelable,
+    ) -> Self {
+        self.add_full_width_field(false, false, label, field);
+        self
+    }
+
+    /// Method to add a field spanning the full width, with the label above.
+    ///
+    /// Such rows keep their layout when the panel collapses (see
+    /// [collapse_width](Self::collapse_width)), and look the same in the
+    /// mobile layout.
+    pub fn add_full_width_field(
+        &mut self,
+        advanced: bool,
+        hidden: bool,
+        label: impl Into<FieldLabel>,
+        mut field: impl Labelable,
+    ) {
+        let label_id = crate::widget::get_unique_element_id();
+
+        let is_disabled = field.disabled();
+        let mut label: FieldLabel = label
+            .into()
+            .id(label_id.clone())
+            .class(is_disabled.then_some("pwt-label-disabled"))
+            .padding_bottom(PwtSpace::Em(0.3));
+        if label.std_props.key.is_none() {
+            label.set_key(format!("label_{}", label.label));
+        }
+
+        field.set_label_id(label_id.into());
+
+        let name = field.name().map(|name| Key::from(name.to_string()));
+        let field = field.into();
+        let key = field.key().cloned().or(name);
+
+        let column = Container::new()
+            .key(key)
+            .class(crate::css::Display::Flex)
+            .class(crate::css::FlexDirection::Column)
+            .class(crate::css::Overflow::Hidden) // pass size constraints down
+            .with_child(label)
+            .with_child(field);
+
+        let two_column = self.two_column;
+        self.add_custom_child_impl(FieldPosition::Large, advanced, hidden, column.into());
+        // Note: a full width row should not enable the two column layout
+        self.two_column = two_column;
+    }
+
     /// Builder style method to add a single line field.
     pub fn with_single_line_field(
         mut self,
@@ -538,8 +635,8 @@ impl Default for InputPanel {
 impl IntoVTag for InputPanel {
     fn into_vtag_with_ref(mut self, node_ref: NodeRef) -> VTag {
         if self.mobile {
-            self.add_class("pwt-d-grid");
-            self.add_class("pwt-gap-2");
+            self.add_class(crate::css::Display::Grid);
+            self.add_class(crate::css::Gap(2));
 
             self.set_style(
                 "grid-template-columns",
@@ -572,6 +669,14 @@ impl IntoVTag for InputPanel {
             }
         }
         self.set_style("grid-auto-rows", "min-content");
+        self.add_class("pwt-input-panel");
+
+        if let (Some(collapse_width), false) = (self.collapse_width, self.mobile) {
+            let panel_class = self.std_props.class.clone();
+            self.add_child(html! {
+                <InputPanelCollapse key="__collapse__" {panel_class} {collapse_width}/>
+            });
+        }
 
         let attributes = self.std_props.cumulate_attributes(None::<&str>);
 
@@ -590,6 +695,86 @@ impl IntoVTag for InputPanel {
     }
 }
 
+#[derive(Properties, PartialEq, Clone)]
+struct InputPanelCollapseProps {
+    // The panel classes, so that we get re-rendered (and can restore our
+    // class) whenever the panel class attribute changes.
+    panel_class: Classes,
+    collapse_width: u32,
+}
+
+enum CollapseMsg {
+    Resize(f64),
+}
+
+// Observes the size of the parent InputPanel, and toggles the
+// `pwt-input-panel-collapsed` class, which overrides the grid placement
+// of all panel children.
+struct InputPanelCollapse {
+    collapsed: bool,
+    node_ref: NodeRef,
+    panel: Option<web_sys::Element>,
+    size_observer: Option<DomSizeObserver>,
+}
+
+impl Component for InputPanelCollapse {
+    type Message = CollapseMsg;
+    type Properties = InputPanelCollapseProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            collapsed: false,
+            node_ref: NodeRef::default(),
+            panel: None,
+            size_observer: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            CollapseMsg::Resize(width) => {
+                if width <= 0.0 {
+                    return false; // not visible
+                }
+                let collapsed = width < ctx.props().collapse_width as f64;
+                if collapsed == self.collapsed {
+                    return false;
+                }
+                self.collapsed = collapsed;
+                true
+            }
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        // a template element is never rendered
+        html! {<template ref={self.node_ref.clone()}/>}
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            self.panel = self
+                .node_ref
+                .cast::<web_sys::Element>()
+                .and_then(|el| el.parent_element());
+            if let Some(panel) = &self.panel {
+                let link = ctx.link().clone();
+                self.size_observer = Some(DomSizeObserver::new(
+                    panel,
+                    move |(width, _height): (f64, f64)| {
+                        link.send_message(CollapseMsg::Resize(width));
+                    },
+                ));
+            }
+        }
+        if let Some(panel) = &self.panel {
+            let _ = panel
+                .class_list()
+                .toggle_with_force("pwt-input-panel-collapsed", self.collapsed);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -621,4 +806,21 @@ mod tests {
         // and a trailing advanced spacer must not clear that
         assert!(advanced_child().with_advanced_spacer().has_advanced());
     }
+
+    #[test]
+    fn full_width_field_keeps_single_column() {
+        let panel = InputPanel::new()
+            .with_field("A", crate::widget::form::Field::new().name("a"))
+            .with_full_width_field("B", crate::widget::form::Field::new().name("b"));
+        assert!(!panel.two_column);
+        assert_eq!(panel.left_count, 2);
+    }
+
+    #[test]
+    fn grid_placement_uses_override_variables() {
+        assert_eq!(
+            grid_placement(3, "2/-1"),
+            "grid-row: var(--pwt-input-panel-row, 3); grid-column: var(--pwt-input-panel-column, 2/-1);"
+        );
+    }
 }
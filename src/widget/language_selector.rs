@@ -1,14 +1,18 @@
 use std::rc::Rc;
 
+use gettext::Catalog;
+
+use crate::AsyncAbortGuard;
+use crate::props::{IntoOptionalRenderFn, RenderFn};
 use crate::state::{Selection, Store, get_language_info};
 use crate::widget::rtl_switcher::set_text_direction;
-use yew::html::IntoEventCallback;
+use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::virtual_dom::{Key, VComp, VNode};
 
 use crate::prelude::*;
 use crate::state::{Language, LanguageInfo, get_available_languages};
 use crate::widget::data_table::{DataTable, DataTableColumn, DataTableHeader};
-use crate::widget::{Dropdown, GridPicker};
+use crate::widget::{Dropdown, GridPicker, Row, Trigger};
 
 #[cfg(doc)]
 use crate::widget::CatalogLoader;
@@ -37,6 +41,12 @@ use super::DropdownController;
 /// If you do not specilfy an `on_change` callback, the selected language is directly
 /// stored using the global [Language] state, so that the [CatalogLoader] automatically
 /// loads the new catalog and redraw the whole page (you loose the page state).
+///
+/// With `preload_catalog` enabled, the catalog for the selected language is
+/// downloaded first (showing a progress indicator), and the language is only
+/// switched after the catalog is ready. If the download fails, the current
+/// language is kept. The [CatalogLoader] reuses the preloaded catalog
+/// instead of downloading it again.
 #[derive(Clone, PartialEq, Properties)]
 #[builder]
 pub struct LanguageSelector {
@@ -45,6 +55,43 @@ pub struct LanguageSelector {
     #[prop_or_default]
     on_change: Option<Callback<String>>,
 
+    /// Language change callback.
+    ///
+    /// Emitted after the language is switched (after the catalog is
+    /// loaded if `preload_catalog` is enabled).
+    #[builder_cb(IntoEventCallback, into_event_callback, String)]
+    #[prop_or_default]
+    on_language_change: Option<Callback<String>>,
+
+    /// Show filter
+    ///
+    /// Default behavior is to show the filter for lists with more than 10 languages.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub show_filter: Option<bool>,
+
+    /// Show the language code (initials) in front of the native language name.
+    #[builder]
+    #[prop_or_default]
+    pub show_initials: bool,
+
+    /// Load the catalog of the selected language before switching.
+    #[builder]
+    #[prop_or_default]
+    pub preload_catalog: bool,
+
+    /// Convert ISO 639-1 language code to server side URL (used by `preload_catalog`).
+    ///
+    /// Default is the same as used by [CatalogLoader] (`catalog-{lang}.mo`).
+    #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String, String)]
+    #[prop_or_default]
+    pub url_builder: Option<RenderFn<String, String>>,
+
+    /// Default language (skip catalog preload for this language)
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::Static("en"))]
+    pub default_lang: AttrValue,
+
     /// CSS class.
     #[prop_or_default]
     pub class: Classes,
@@ -71,6 +118,14 @@ impl LanguageSelector {
     pub fn add_class(&mut self, class: impl Into<Classes>) {
         self.class.push(class);
     }
+
+    fn lang_to_url(&self, lang: &str) -> String {
+        if let Some(url_builder) = &self.url_builder {
+            url_builder.apply(&lang.to_string())
+        } else {
+            format!("catalog-{}.mo", lang)
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -78,17 +133,51 @@ pub struct PwtLanguageSelector {
     store: Store<LanguageInfo>,
     selection: Selection,
     lang: String,
+    columns: Rc<Vec<DataTableHeader<LanguageInfo>>>,
+    // language with pending catalog download
+    loading: Option<String>,
+    // aborts the pending download when replaced
+    load_guard: Option<AsyncAbortGuard>,
 }
 
 pub enum Msg {
     Select(String),
+    CatalogLoaded(String, String, Result<Catalog, String>),
+}
+
+impl PwtLanguageSelector {
+    fn columns(show_initials: bool) -> Rc<Vec<DataTableHeader<LanguageInfo>>> {
+        if show_initials {
+            COLUMNS_WITH_INITIALS.with(Rc::clone)
+        } else {
+            COLUMNS.with(Rc::clone)
+        }
+    }
+
+    fn switch_language(&mut self, ctx: &Context<Self>, lang: String) {
+        let props = ctx.props();
+        self.lang = lang.clone();
+        if let Some(on_change) = &props.on_change {
+            on_change.emit(lang.clone());
+        } else {
+            Language::store(lang.clone());
+        }
+        if let Some(info) = get_language_info(&self.lang) {
+            if let Err(err) = set_text_direction(info.direction) {
+                log::error!("could not set text direction: {err}");
+            }
+        }
+        if let Some(on_language_change) = &props.on_language_change {
+            on_language_change.emit(lang);
+        }
+    }
 }
 
 impl Component for PwtLanguageSelector {
     type Message = Msg;
     type Properties = LanguageSelector;
 
-    fn create(_ctx: &Context<Self>) -> Self {
+    fn create(ctx: &Context<Self>) -> Self {
         let store = Store::new();
         let languages = get_available_languages();
 
@@ -110,6 +199,9 @@ impl Component for PwtLanguageSelector {
             store,
             selection,
             lang,
+            columns: Self::columns(ctx.props().show_initials),
+            loading: None,
+            load_guard: None,
         }
     }
 
@@ -117,15 +209,43 @@ impl Component for PwtLanguageSelector {
         let props = ctx.props();
         match msg {
             Msg::Select(lang) => {
-                self.lang = lang.clone();
-                if let Some(on_change) = &props.on_change {
-                    on_change.emit(lang);
+                if props.preload_catalog && lang != props.default_lang {
+                    // Note: the catalog is only installed after the download
+                    // finished, so outdated requests cannot overwrite it
+                    let url = props.lang_to_url(&lang);
+                    self.loading = Some(lang.clone());
+                    let link = ctx.link().clone();
+                    self.load_guard = Some(AsyncAbortGuard::spawn(async move {
+                        let result = crate::load_catalog(&url).await;
+                        link.send_message(Msg::CatalogLoaded(lang, url, result));
+                    }));
                 } else {
-                    Language::store(lang);
+                    self.loading = None;
+                    self.load_guard = None;
+                    if props.preload_catalog {
+                        crate::init_i18n(Catalog::empty());
+                    }
+                    self.switch_language(ctx, lang);
+                }
+                true
+            }
+            Msg::CatalogLoaded(lang, url, result) => {
+                // ignore outdated requests
+                if self.loading.as_ref() != Some(&lang) {
+                    return false;
                 }
-                if let Some(info) = get_language_info(&self.lang) {
-                    if let Err(err) = set_text_direction(info.direction) {
-                        log::error!("could not set text direction: {err}");
+                self.loading = None;
+                self.load_guard = None;
+                match result {
+                    Ok(catalog) => {
+                        // Note: the CatalogLoader does not download it again
+                        crate::gettext_wrapper::init_i18n_preloaded(&url, catalog);
+                        self.switch_language(ctx, lang);
+                    }
+                    Err(err) => {
+                        // keep the current language
+                        log::error!("Catalog load error: {err}");
+                        self.selection.select(Key::from(self.lang.clone()));
                     }
                 }
                 true
@@ -133,28 +253,38 @@ impl Component for PwtLanguageSelector {
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.show_initials != old_props.show_initials {
+            self.columns = Self::columns(props.show_initials);
+        }
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
         let picker = {
             let store = self.store.clone();
-            let columns = COLUMNS.with(Rc::clone);
+            let columns = Rc::clone(&self.columns);
             let selection = self.selection.clone();
+            let show_filter = props.show_filter;
 
             move |controller: &DropdownController| {
                 let table = DataTable::new(columns.clone(), store.clone());
 
                 GridPicker::new(table)
                     .selection(selection.clone())
-                    .show_filter(false)
+                    .show_filter(show_filter)
                     .on_select(controller.on_select_callback())
                     .into()
             }
         };
 
         let store = self.store.clone();
+        let show_initials = props.show_initials;
 
-        Dropdown::new(picker)
+        let mut dropdown = Dropdown::new(picker)
             .required(true)
             .class(props.class.clone())
             .value(self.lang.clone())
@@ -162,12 +292,20 @@ impl Component for PwtLanguageSelector {
             .render_value(move |id: &AttrValue| {
                 let key = Key::from(id.to_string());
                 if let Some(info) = store.read().lookup_record(&key) {
-                    html! {&info.text}
+                    render_language(&info, show_initials)
                 } else {
                     html! {id}
                 }
-            })
-            .into()
+            });
+
+        if self.loading.is_some() {
+            dropdown.add_trigger(
+                Trigger::new("fa fa-spinner fa-pulse").tip(tr!("Loading...")),
+                true,
+            );
+        }
+
+        dropdown.into()
     }
 
     fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
@@ -188,28 +326,42 @@ impl From<LanguageSelector> for VNode {
     }
 }
 
-thread_local! {
-    static COLUMNS: Rc<Vec<DataTableHeader<LanguageInfo>>> = Rc::new(vec![
+fn render_language(info: &LanguageInfo, show_initials: bool) -> Html {
+    if show_initials {
+        Row::new()
+            .gap(1)
+            .class(crate::css::AlignItems::Center)
+            .with_child(html! {
+                <span class="pwt-language-initials">{info.lang.to_uppercase()}</span>
+            })
+            .with_child(html! {&info.text})
+            .into()
+    } else {
+        html! {&info.text}
+    }
+}
+
+fn language_columns(show_initials: bool) -> Rc<Vec<DataTableHeader<LanguageInfo>>> {
+    Rc::new(vec![
         DataTableColumn::new(tr!("Language"))
             .width("200px")
             .show_menu(false)
-            .render(|info: &LanguageInfo| {
-                html!{&info.text}
-            })
-            .sorter(|a: &LanguageInfo, b: &LanguageInfo| {
-                a.text.cmp(&b.text)
-            })
+            .render(move |info: &LanguageInfo| render_language(info, show_initials))
+            .sorter(|a: &LanguageInfo, b: &LanguageInfo| a.text.cmp(&b.text))
             .sort_order(true)
             .into(),
         DataTableColumn::new(tr!("Translated"))
             .width("200px")
             .show_menu(false)
             .render(|info: &LanguageInfo| {
-                html!{&info.translated_text}
+                html! {&info.translated_text}
             })
-            .sorter(|a: &LanguageInfo, b: &LanguageInfo| {
-                a.translated_text.cmp(&b.translated_text)
-            })
-           .into(),
-    ]);
+            .sorter(|a: &LanguageInfo, b: &LanguageInfo| a.translated_text.cmp(&b.translated_text))
+            .into(),
+    ])
+}
+
+thread_local! {
+    static COLUMNS: Rc<Vec<DataTableHeader<LanguageInfo>>> = language_columns(false);
+    static COLUMNS_WITH_INITIALS: Rc<Vec<DataTableHeader<LanguageInfo>>> = language_columns(true);
 }
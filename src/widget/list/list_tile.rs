@@ -1,18 +1,68 @@
 use std::borrow::Cow;
 
+use gloo_timers::callback::Timeout;
 use wasm_bindgen::JsCast;
 use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
 use yew::virtual_dom::VTag;
 
-use crate::props::{EventSubscriber, IntoVTag, ListenersWrapper, WidgetBuilder, WidgetStdProps};
+use crate::props::{
+    EventSubscriber, IntoOptionalInlineHtml, IntoVTag, ListenersWrapper, WidgetBuilder,
+    WidgetStdProps, WidgetStyleBuilder,
+};
 
 use pwt_macros::{builder, widget};
 
+/// List tile density presets (following the Material list specification).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum ListTileDensity {
+    /// Single line of text (56px).
+    #[default]
+    OneLine,
+    /// Title and one line of secondary text (72px).
+    TwoLine,
+    /// Title and up to two lines of secondary text (88px).
+    ThreeLine,
+}
+
+impl ListTileDensity {
+    fn class(&self) -> &'static str {
+        match self {
+            ListTileDensity::OneLine => "pwt-list-tile-one-line",
+            ListTileDensity::TwoLine => "pwt-list-tile-two-line",
+            ListTileDensity::ThreeLine => "pwt-list-tile-three-line",
+        }
+    }
+}
+
+// Long press delay in milliseconds.
+const LONG_PRESS_DELAY_MS: u32 = 500;
+
 /// List tile. A container with grid/subgrid layout.
 ///
 /// This is meant to be used inside [List](crate::widget::List).
 ///
+/// Instead of adding children, you can use the structured slots (`leading`,
+/// `title`, `subtitle` and `trailing`). The tile then always renders three
+/// grid columns, so the list should use `grid_template_columns("auto 1fr auto")`.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{List, ListTile, ListTileDensity};
+/// # fn create_list() -> List {
+///     List::new(100, |pos| {
+///         ListTile::new()
+///             .leading(html!{<i class="fa fa-user"/>})
+///             .title(format!("User {pos}"))
+///             .subtitle("user@example.com")
+///             .trailing(html!{<span>{"Admin"}</span>})
+///             .density(ListTileDensity::TwoLine)
+///             .on_tap(|_| log::info!("tap"))
+///     })
+///     .grid_template_columns("auto 1fr auto")
+/// # }
+/// ```
+///
 /// ```
 /// # use pwt::prelude::*;
 /// # use pwt::widget::{List, ListTile};
@@ -29,7 +79,11 @@ use pwt_macros::{builder, widget};
 ///     .grid_template_columns("auto 1fr auto")
 /// # }
 /// ```
-#[widget(pwt=crate, @element, @container)]
+///
+/// Note: The long press state is managed by the component, so
+/// [on_long_press](Self::on_long_press) is ignored when the tile is
+/// converted with [IntoVTag].
+#[widget(pwt=crate, comp=PwtListTile, @element, @container)]
 #[derive(Default, Debug, Clone, PartialEq, Properties)]
 #[builder]
 pub struct ListTile {
@@ -51,6 +105,52 @@ pub struct ListTile {
     #[builder_cb(IntoEventCallback, into_event_callback, Event)]
     #[prop_or_default]
     on_activate: Option<Callback<Event>>,
+
+    /// Leading slot (icon or avatar).
+    #[prop_or_default]
+    leading: Option<Html>,
+
+    /// Title slot.
+    #[prop_or_default]
+    title: Option<Html>,
+
+    /// Secondary text, displayed below the title.
+    #[prop_or_default]
+    subtitle: Option<Html>,
+
+    /// Trailing slot (widget or metadata).
+    #[prop_or_default]
+    trailing: Option<Html>,
+
+    /// Density preset, sets the minimum height (CSS class
+    /// `pwt-list-tile-one-line`, `pwt-list-tile-two-line` or
+    /// `pwt-list-tile-three-line`).
+    #[prop_or_default]
+    #[builder(IntoPropValue, into_prop_value)]
+    density: Option<ListTileDensity>,
+
+    /// Selected state.
+    #[prop_or_default]
+    #[builder]
+    selected: bool,
+
+    /// Activated state (i.e. the currently opened item).
+    #[prop_or_default]
+    #[builder]
+    activated: bool,
+
+    /// Tap callback (click).
+    #[builder_cb(IntoEventCallback, into_event_callback, MouseEvent)]
+    #[prop_or_default]
+    on_tap: Option<Callback<MouseEvent>>,
+
+    /// Long press callback.
+    ///
+    /// Called if the pointer is pressed for 500ms. The following
+    /// click does not trigger `on_tap` or `on_activate`.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    on_long_press: Option<Callback<()>>,
 }
 
 impl ListTile {
@@ -66,6 +166,57 @@ impl ListTile {
     ) -> Self {
         yew::props! { Self { std_props, listeners: listeners.unwrap_or_default() } }
     }
+
+    /// Builder style method to set the leading slot.
+    pub fn leading(mut self, leading: impl IntoOptionalInlineHtml) -> Self {
+        self.set_leading(leading);
+        self
+    }
+
+    /// Method to set the leading slot.
+    pub fn set_leading(&mut self, leading: impl IntoOptionalInlineHtml) {
+        self.leading = leading.into_optional_inline_html();
+    }
+
+    /// Builder style method to set the title.
+    pub fn title(mut self, title: impl IntoOptionalInlineHtml) -> Self {
+        self.set_title(title);
+        self
+    }
+
+    /// Method to set the title.
+    pub fn set_title(&mut self, title: impl IntoOptionalInlineHtml) {
+        self.title = title.into_optional_inline_html();
+    }
+
+    /// Builder style method to set the secondary text.
+    pub fn subtitle(mut self, subtitle: impl IntoOptionalInlineHtml) -> Self {
+        self.set_subtitle(subtitle);
+        self
+    }
+
+    /// Method to set the secondary text.
+    pub fn set_subtitle(&mut self, subtitle: impl IntoOptionalInlineHtml) {
+        self.subtitle = subtitle.into_optional_inline_html();
+    }
+
+    /// Builder style method to set the trailing slot.
+    pub fn trailing(mut self, trailing: impl IntoOptionalInlineHtml) -> Self {
+        self.set_trailing(trailing);
+        self
+    }
+
+    /// Method to set the trailing slot.
+    pub fn set_trailing(&mut self, trailing: impl IntoOptionalInlineHtml) {
+        self.trailing = trailing.into_optional_inline_html();
+    }
+
+    fn has_slots(&self) -> bool {
+        self.leading.is_some()
+            || self.title.is_some()
+            || self.subtitle.is_some()
+            || self.trailing.is_some()
+    }
 }
 
 impl IntoVTag for ListTile {
@@ -73,19 +224,50 @@ impl IntoVTag for ListTile {
         let classes = classes!(
             "pwt-list-tile",
             self.interactive.then_some("pwt-interactive"),
-            self.disabled.then_some("disabled")
+            self.disabled.then_some("disabled"),
+            self.selected.then_some("selected"),
+            self.activated.then_some("activated"),
+            self.density.map(|density| density.class()),
         );
 
+        if self.selected {
+            self.set_attribute("aria-selected", "true");
+        }
+
+        if self.has_slots() {
+            let leading = self.leading.take().unwrap_or_default();
+            let trailing = self.trailing.take().unwrap_or_default();
+            let title = self.title.take().map(|title| {
+                html! {<div class="pwt-list-tile-title">{title}</div>}
+            });
+            let subtitle = self.subtitle.take().map(|subtitle| {
+                html! {<div class="pwt-list-tile-subtitle">{subtitle}</div>}
+            });
+            self.children = vec![
+                html! {<div class="pwt-list-tile-leading">{leading}</div>},
+                html! {<div class="pwt-list-tile-content">{title}{subtitle}</div>},
+                html! {<div class="pwt-list-tile-trailing">{trailing}</div>},
+            ];
+        }
+
         if !self.disabled {
-            if let Some(on_activate) = self.on_activate.clone() {
-                self.set_tabindex(0);
-                self.add_onclick({
-                    let on_activate = on_activate.clone();
-                    move |event: MouseEvent| {
+            let on_tap = self.on_tap.clone();
+            let on_activate = self.on_activate.clone();
+
+            if on_tap.is_some() || on_activate.is_some() {
+                self.add_onclick(move |event: MouseEvent| {
+                    if let Some(on_tap) = &on_tap {
+                        on_tap.emit(event.clone());
+                    }
+                    if let Some(on_activate) = &on_activate {
                         event.stop_propagation();
                         on_activate.emit(event.unchecked_into());
                     }
                 });
+            }
+
+            if let Some(on_activate) = self.on_activate.clone() {
+                self.set_tabindex(0);
                 self.add_onkeydown({
                     let on_activate = on_activate.clone();
                     move |event: KeyboardEvent| match event.key().as_str() {
@@ -108,3 +290,114 @@ impl IntoVTag for ListTile {
         )
     }
 }
+
+pub enum Msg {
+    PressStart,
+    PressEnd,
+    LongPress,
+    Tap(MouseEvent),
+    Activate(Event),
+}
+
+#[doc(hidden)]
+pub struct PwtListTile {
+    long_press_timeout: Option<Timeout>,
+    // The pointer was pressed long enough (reset on the next press).
+    long_pressed: bool,
+}
+
+impl Component for PwtListTile {
+    type Message = Msg;
+    type Properties = ListTile;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            long_press_timeout: None,
+            long_pressed: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+
+        match msg {
+            Msg::PressStart => {
+                let link = ctx.link().clone();
+                self.long_press_timeout = Some(Timeout::new(LONG_PRESS_DELAY_MS, move || {
+                    link.send_message(Msg::LongPress)
+                }));
+                std::mem::replace(&mut self.long_pressed, false)
+            }
+            Msg::PressEnd => {
+                self.long_press_timeout = None;
+                false
+            }
+            Msg::LongPress => {
+                self.long_press_timeout = None;
+                self.long_pressed = true;
+                if let Some(on_long_press) = &props.on_long_press {
+                    on_long_press.emit(());
+                }
+                true
+            }
+            Msg::Tap(event) => {
+                // the click following a long press does not trigger on_tap
+                if !self.long_pressed {
+                    if let Some(on_tap) = &props.on_tap {
+                        on_tap.emit(event);
+                    }
+                }
+                false
+            }
+            Msg::Activate(event) => {
+                if !(self.long_pressed && event.type_() == "click") {
+                    if let Some(on_activate) = &props.on_activate {
+                        on_activate.emit(event);
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.disabled || props.on_long_press.is_none() {
+            self.long_press_timeout = None;
+            self.long_pressed = false;
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let mut tile = props.clone();
+
+        if props.disabled || props.on_long_press.is_none() {
+            return tile.into_vtag().into();
+        }
+
+        let link = ctx.link();
+        if props.on_tap.is_some() {
+            tile.on_tap = Some(link.callback(Msg::Tap));
+        }
+        if props.on_activate.is_some() {
+            tile.on_activate = Some(link.callback(Msg::Activate));
+        }
+
+        tile.add_onpointerdown(link.callback(|_: PointerEvent| Msg::PressStart));
+        tile.add_onpointerup(link.callback(|_: PointerEvent| Msg::PressEnd));
+        tile.add_onpointercancel(link.callback(|_: PointerEvent| Msg::PressEnd));
+        tile.add_onpointerleave(link.callback(|_: PointerEvent| Msg::PressEnd));
+
+        // avoid the browser context menu on touch devices
+        let long_pressed = self.long_pressed;
+        tile.add_oncontextmenu(move |event: MouseEvent| {
+            if long_pressed {
+                event.prevent_default();
+            }
+        });
+
+        tile.into_vtag().into()
+    }
+}
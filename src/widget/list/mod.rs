@@ -1,20 +1,25 @@
 use gloo_timers::callback::Timeout;
-use html::IntoPropValue;
+use html::{IntoEventCallback, IntoPropValue};
 
 use crate::prelude::*;
 
-use crate::props::{EventSubscriber, PwtSpace, WidgetBuilder};
+use crate::props::{
+    EventSubscriber, IntoStorageLocation, PwtSpace, StorageLocation, WidgetBuilder,
+};
+use crate::state::{load_state, store_state};
 
-use crate::widget::Container;
+use crate::widget::{Container, Fa, Row};
 
-use crate::dom::DomSizeObserver;
+use crate::dom::{DomDragScroll, DomSizeObserver};
 
 use pwt_macros::{builder, widget};
 
 static VIRTUAL_SCROLL_TRIGGER: u64 = 30;
 
 mod list_tile;
-pub use list_tile::ListTile;
+#[doc(hidden)]
+pub use list_tile::PwtListTile;
+pub use list_tile::{ListTile, ListTileDensity};
 
 mod list_tile_observer;
 pub use list_tile_observer::ListTileObserver;
@@ -41,6 +46,30 @@ use super::SizeObserver;
 ///
 /// The virtual scrolling algorithm can handle different tile
 /// sizes even if the tile size changes dynamically.
+///
+/// # Open-ended lists
+///
+/// Set [has_more](Self::has_more) if more items can be loaded (e.g. paged
+/// API calls). The list then shows a placeholder tile after the last item,
+/// and calls [on_reach_end](Self::on_reach_end) (with the current item count)
+/// as soon as the placeholder gets rendered. Simply update the item count
+/// after loading the next page. With
+/// [load_more_button](Self::load_more_button), the placeholder is a button,
+/// and the list waits for the user to press it.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{List, ListTile};
+/// # fn create_list(items: &[String], loading: bool, link: Callback<u64>) -> List {
+///     let items = items.to_vec();
+///     List::new(items.len() as u64, move |pos| {
+///         ListTile::new().with_child(items[pos as usize].clone())
+///     })
+///     .has_more(true)
+///     .loading(loading)
+///     .on_reach_end(link) // load the next page
+/// # }
+/// ```
 #[widget(pwt=crate, comp=crate::widget::PwtList, @element)]
 #[derive(Properties, Clone, PartialEq)]
 #[builder]
@@ -143,6 +172,44 @@ pub struct List {
     #[prop_or(2)]
     #[builder]
     pub prefetch_count: u8,
+
+    /// Save and restore the scroll position.
+    ///
+    /// If set, the scroll position is saved when the component is
+    /// destroyed, and restored when it gets created again (as soon
+    /// as the list has items).
+    #[prop_or_default]
+    pub scroll_state_id: Option<StorageLocation>,
+
+    /// Enable kinetic drag-to-scroll with the mouse (see [DomDragScroll]).
+    #[prop_or_default]
+    #[builder]
+    pub drag_scroll: bool,
+
+    /// More items are available (open-ended list).
+    ///
+    /// Adds a placeholder tile after the last item.
+    #[prop_or_default]
+    #[builder]
+    pub has_more: bool,
+
+    /// Items are being loaded (shows a spinner in the placeholder tile).
+    #[prop_or_default]
+    #[builder]
+    pub loading: bool,
+
+    /// Show a "Load more" button instead of loading automatically.
+    #[prop_or_default]
+    #[builder]
+    pub load_more_button: bool,
+
+    /// Called when the user reaches the end of an open-ended list.
+    ///
+    /// The callback gets the current item count, and is called only once
+    /// per item count (unless the user presses the "Load more" button).
+    #[prop_or_default]
+    #[builder_cb(IntoEventCallback, into_event_callback, u64)]
+    pub on_reach_end: Option<Callback<u64>>,
 }
 
 impl List {
@@ -162,6 +229,17 @@ impl List {
             tiles[pos as usize].clone().into()
         })
     }
+
+    /// Builder style method to set the scroll state storage location.
+    pub fn scroll_state_id(mut self, state_id: impl IntoStorageLocation) -> Self {
+        self.set_scroll_state_id(state_id);
+        self
+    }
+
+    /// Method to set the scroll state storage location.
+    pub fn set_scroll_state_id(&mut self, state_id: impl IntoStorageLocation) {
+        self.scroll_state_id = state_id.into_storage_location();
+    }
 }
 
 #[derive(Default)]
@@ -178,6 +256,7 @@ pub enum Msg {
     ViewportResize(f64, f64, f64),
     TileResize(u64, f64, f64),
     DelayedTileResize,
+    LoadMore,
 }
 
 #[derive(Default)]
@@ -263,15 +342,85 @@ pub struct PwtList {
     row_heights: SizeAccumulator,
 
     set_scroll_top: Option<usize>,
+    // Saved scroll position, restored once the list has items.
+    pending_scroll_top: Option<usize>,
 
     tile_resize_callback: Callback<(u64, f64, f64)>,
     tile_resize_timeout: Option<Timeout>,
     scroll_diff: i64,
+
+    drag_scroll: Option<DomDragScroll>,
+
+    // Item count for which we already called on_reach_end.
+    reach_end_count: Option<u64>,
 }
 
 impl PwtList {
+    // Note: list items are usually loaded asynchronously, so we wait
+    // until we have items, and restore the position exactly once.
+    fn restore_scroll_top(&mut self, props: &List) {
+        if props.item_count == 0 {
+            return;
+        }
+        if let Some(top) = self.pending_scroll_top.take() {
+            self.viewport_scroll_top = top;
+            self.set_scroll_top = Some(top);
+        }
+    }
+
+    // Item count including the placeholder tile.
+    fn row_count(props: &List) -> u64 {
+        props.item_count + props.has_more as u64
+    }
+
+    fn check_reach_end(&mut self, props: &List) {
+        if !props.has_more || props.loading || props.load_more_button {
+            return;
+        }
+        // placeholder tile rendered?
+        if self.scroll_info.end <= props.item_count {
+            return;
+        }
+        if self.reach_end_count == Some(props.item_count) {
+            return;
+        }
+        self.reach_end_count = Some(props.item_count);
+        if let Some(on_reach_end) = &props.on_reach_end {
+            on_reach_end.emit(props.item_count);
+        }
+    }
+
+    fn render_placeholder_tile(&self, ctx: &Context<Self>, props: &List) -> ListTile {
+        let content = if props.loading {
+            Row::new()
+                .gap(2)
+                .class(crate::css::AlignItems::Center)
+                .with_child(Fa::new("spinner").pulse())
+                .with_child(tr!("Loading..."))
+        } else if props.load_more_button {
+            Row::new().with_child(tr!("Load more"))
+        } else {
+            Row::new()
+        };
+
+        let mut tile = ListTile::new()
+            .class("pwt-list-placeholder-tile")
+            .attribute("aria-busy", props.loading.then_some("true"))
+            .with_child(
+                content
+                    .class(crate::css::JustifyContent::Center)
+                    .style("grid-column", "1 / -1"),
+            );
+
+        if props.load_more_button && !props.loading {
+            tile.set_interactive(true);
+            tile.set_on_activate(ctx.link().callback(|_| Msg::LoadMore));
+        }
+        tile
+    }
+
     fn update_scroll_info(&mut self, props: &List) {
-        let item_count = props.item_count;
+        let item_count = Self::row_count(props);
 
         let virtual_scroll = props
             .virtual_scroll
@@ -296,7 +445,7 @@ impl PwtList {
         let offset_end = offset as f64 + self.table_height;
 
         let tail_height = self.row_heights.compute_tail_height(
-            props.item_count as usize,
+            item_count as usize,
             end as usize,
             props.min_row_height,
         );
@@ -358,9 +507,14 @@ impl PwtList {
         }
 
         for pos in self.scroll_info.start..self.scroll_info.end {
+            let tile = if pos < props.item_count {
+                props.renderer.emit(pos)
+            } else {
+                self.render_placeholder_tile(ctx, props)
+            };
             // if we have keys, we need overflow-anchor none on the scroll container
             // see: https://github.com/facebook/react/issues/27044
-            let row = ListTileObserver::new(props.renderer.emit(pos))
+            let row = ListTileObserver::new(tile)
                 .key(format!("row-{pos}"))
                 .tile_pos(pos)
                 .separator(props.separator)
@@ -387,7 +541,9 @@ impl Component for PwtList {
     type Properties = List;
 
     fn create(ctx: &Context<Self>) -> Self {
-        Self {
+        let scroll_top: Option<usize> = ctx.props().scroll_state_id.as_ref().and_then(load_state);
+
+        let mut me = Self {
             viewport_height: 0.0,
             viewport_width: 0.0,
             viewport_size_observer: None,
@@ -401,20 +557,33 @@ impl Component for PwtList {
             row_heights: SizeAccumulator::default(),
 
             set_scroll_top: None,
+            pending_scroll_top: scroll_top,
 
             tile_resize_callback: ctx
                 .link()
                 .callback(|(pos, w, h)| Msg::TileResize(pos, w, h)),
             tile_resize_timeout: None,
             scroll_diff: 0,
-        }
+            drag_scroll: None,
+            reach_end_count: None,
+        };
+        me.restore_scroll_top(ctx.props());
+        me
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         let props = ctx.props();
         match msg {
+            Msg::LoadMore => {
+                self.reach_end_count = Some(props.item_count);
+                if let Some(on_reach_end) = &props.on_reach_end {
+                    on_reach_end.emit(props.item_count);
+                }
+                false
+            }
             Msg::DelayedTileResize => {
                 self.update_scroll_info(props);
+                self.check_reach_end(props);
                 self.tile_resize_timeout = None;
                 if self.scroll_diff != 0 && self.viewport_scroll_top != 0 {
                     self.set_scroll_top =
@@ -450,6 +619,7 @@ impl Component for PwtList {
             Msg::ScrollTo(_x, y) => {
                 self.viewport_scroll_top = y.max(0) as usize;
                 self.update_scroll_info(props);
+                self.check_reach_end(props);
                 props.virtual_scroll.unwrap_or(true)
             }
             Msg::ViewportResize(width, height, scrollbar_size) => {
@@ -464,11 +634,13 @@ impl Component for PwtList {
                 };
 
                 self.update_scroll_info(props);
+                self.check_reach_end(props);
                 true
             }
             Msg::TableResize(_width, height) => {
                 self.table_height = height.max(0.0);
                 self.update_scroll_info(props);
+                self.check_reach_end(props);
                 true
             }
         }
@@ -477,9 +649,14 @@ impl Component for PwtList {
     fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
         let props = ctx.props();
 
-        if props.item_count != old_props.item_count {
+        if props.item_count != old_props.item_count || props.has_more != old_props.has_more {
+            self.restore_scroll_top(props);
             self.update_scroll_info(props);
         }
+        if props.has_more && !old_props.has_more {
+            self.reach_end_count = None;
+        }
+        self.check_reach_end(props);
         true
     }
 
@@ -500,6 +677,13 @@ impl Component for PwtList {
             .into_html_with_ref(self.viewport_ref.clone())
     }
 
+    fn destroy(&mut self, ctx: &Context<Self>) {
+        if let Some(state_id) = &ctx.props().scroll_state_id {
+            let scroll_top = self.pending_scroll_top.unwrap_or(self.viewport_scroll_top);
+            store_state(&scroll_top, state_id);
+        }
+    }
+
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         let viewport_el = self.viewport_ref.cast::<web_sys::Element>();
 
@@ -514,6 +698,13 @@ impl Component for PwtList {
             }
         }
 
+        if ctx.props().drag_scroll != self.drag_scroll.is_some() {
+            self.drag_scroll = match self.viewport_ref.cast::<web_sys::HtmlElement>() {
+                Some(el) if ctx.props().drag_scroll => Some(DomDragScroll::new(&el)),
+                _ => None,
+            };
+        }
+
         if let Some(el) = &viewport_el {
             if let Some(top) = self.set_scroll_top.take() {
                 // Note: we delay setting ScrollTop until we rendered the
@@ -0,0 +1,185 @@
+use crate::css::{FontColor, Overflow};
+use crate::log::{Level, LevelFilter, LogEntry, LogObserver};
+use crate::prelude::*;
+use crate::web_sys_ext::clipboard_write_text;
+use crate::widget::menu::{Menu, MenuButton, MenuCheckbox, MenuEvent};
+use crate::widget::{Button, Column, Container, Toolbar};
+
+use pwt_macros::{builder, widget};
+
+const LEVELS: [LevelFilter; 6] = [
+    LevelFilter::Off,
+    LevelFilter::Error,
+    LevelFilter::Warn,
+    LevelFilter::Info,
+    LevelFilter::Debug,
+    LevelFilter::Trace,
+];
+
+/// Debug panel showing the [pwt::log](crate::log) ring buffer.
+///
+/// Shows recent log entries (follows new entries while scrolled to the
+/// end), and allows to change the default log level, copy and clear the
+/// entries.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{Dialog, LogViewer};
+/// # fn create_dialog() -> Dialog {
+/// Dialog::new("Log").with_child(LogViewer::new().style("height", "400px"))
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtLogViewer, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct LogViewer {}
+
+impl Default for LogViewer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogViewer {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+}
+
+pub enum Msg {
+    LogChange,
+    Copy,
+    Clear,
+}
+
+#[doc(hidden)]
+pub struct PwtLogViewer {
+    entries: Vec<LogEntry>,
+    _observer: LogObserver,
+    log_ref: NodeRef,
+    // keep the log scrolled to the end
+    follow_log: bool,
+}
+
+impl PwtLogViewer {
+    fn log_at_end(&self) -> bool {
+        match self.log_ref.cast::<web_sys::Element>() {
+            Some(el) => el.scroll_top() + el.client_height() + 2 >= el.scroll_height(),
+            None => true,
+        }
+    }
+
+    fn level_menu() -> Menu {
+        let current = crate::log::level_config().map(|config| config.default);
+        let mut menu = Menu::new();
+        for level in LEVELS {
+            menu.add_item(
+                MenuCheckbox::radio(level.to_string())
+                    .checked(current == Some(level))
+                    .on_click(move |_: MenuEvent| crate::log::set_level("", Some(level))),
+            );
+        }
+        menu
+    }
+
+    fn render_entry(entry: &LogEntry) -> Html {
+        let color = match entry.level {
+            Level::Error => Some(FontColor::Error),
+            Level::Warn => Some(FontColor::Warning),
+            Level::Info => None,
+            Level::Debug | Level::Trace => Some(FontColor::NeutralAlt),
+        };
+        Container::new()
+            .class(color)
+            .with_child(entry.to_text())
+            .into()
+    }
+}
+
+impl Component for PwtLogViewer {
+    type Message = Msg;
+    type Properties = LogViewer;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            entries: crate::log::recent_entries(),
+            _observer: LogObserver::new(ctx.link().callback(|_| Msg::LogChange)),
+            log_ref: NodeRef::default(),
+            follow_log: true,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::LogChange => {
+                self.follow_log = self.log_at_end();
+                self.entries = crate::log::recent_entries();
+                true
+            }
+            Msg::Copy => {
+                let text: Vec<String> = self.entries.iter().map(LogEntry::to_text).collect();
+                let _ = clipboard_write_text(&text.join("\n"));
+                false
+            }
+            Msg::Clear => {
+                crate::log::clear();
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let level = match crate::log::level_config() {
+            Some(config) => tr!("Level: {0}", config.default),
+            None => tr!("Logger not installed"),
+        };
+
+        let toolbar = Toolbar::new()
+            .with_child(
+                MenuButton::new(level)
+                    .disabled(crate::log::level_config().is_none())
+                    .menu_builder(Self::level_menu),
+            )
+            .with_flex_spacer()
+            .with_child(
+                Button::new(tr!("Copy"))
+                    .icon_class("fa fa-clipboard")
+                    .on_activate(link.callback(|_| Msg::Copy)),
+            )
+            .with_child(
+                Button::new(tr!("Clear"))
+                    .icon_class("fa fa-trash-o")
+                    .on_activate(link.callback(|_| Msg::Clear)),
+            );
+
+        let log = Container::new()
+            .class("pwt-log-view")
+            .class("pwt-log-viewer-entries")
+            .class("pwt-flex-fill")
+            .padding(1)
+            .attribute("role", "log")
+            .children(self.entries.iter().map(Self::render_entry))
+            .into_html_with_ref(self.log_ref.clone());
+
+        Column::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-log-viewer")
+            .class(Overflow::Hidden)
+            .with_child(toolbar)
+            .with_child(log)
+            .into()
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if self.follow_log {
+            if let Some(el) = self.log_ref.cast::<web_sys::Element>() {
+                el.set_scroll_top(el.scroll_height());
+            }
+        }
+    }
+}
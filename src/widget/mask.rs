@@ -4,6 +4,7 @@ use web_sys::HtmlElement;
 use yew::html::IntoPropValue;
 use yew::virtual_dom::VNode;
 
+use crate::css::Layer;
 use crate::prelude::*;
 use crate::widget::Container;
 
@@ -103,7 +104,8 @@ impl Component for PwtMask {
 
         let mut mask = Container::new()
             .class("pwt-load-mask")
-            .class(props.visible.then_some("visible"));
+            .class(props.visible.then_some("visible"))
+            .style("z-index", Layer::Overlay);
 
         if props.visible {
             mask.add_child(
@@ -120,7 +122,7 @@ impl Component for PwtMask {
         })
         .class("pwt-flex-fill-first-child")
         .class("pwt-d-flex")
-        .class("pwt-position-relative")
+        .class(crate::css::Position::Relative)
         .with_child(props.content.clone())
         .with_child(mask)
         .into()
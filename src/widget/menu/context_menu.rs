@@ -0,0 +1,339 @@
+use std::rc::Rc;
+
+use gloo_events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
+
+use yew::html::IntoEventCallback;
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::dom::focus::FocusTracker;
+use crate::prelude::*;
+use crate::props::IntoOptionalKey;
+use crate::state::SharedStateObserver;
+use crate::widget::Container;
+
+use super::{Menu, MenuController, MenuControllerMsg};
+
+use pwt_macros::builder;
+
+// Minimal distance (px) to the viewport edges.
+const VIEWPORT_PADDING: f64 = 5.0;
+
+/// Context menu, displayed at the given viewport coordinates.
+///
+/// The menu is rendered as popover (top layer), so it is not clipped by
+/// scroll containers. If there is not enough space, the menu opens to the
+/// left/top of the position instead, and it is always kept inside the
+/// viewport.
+///
+/// The menu gets the focus when opened, and [on_close](Self::on_close) is
+/// called when the user selects an item, presses `Escape`, clicks outside
+/// the menu or moves the focus away. The focus is restored to the
+/// previously focused element.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::Container;
+/// # use pwt::widget::menu::{ContextMenu, Menu, MenuItem};
+/// #[function_component]
+/// fn Example() -> Html {
+///     let position = use_state(|| None);
+///
+///     let context_menu = (*position).map(|(x, y)| {
+///         let menu = Menu::new()
+///             .with_item(MenuItem::new("Edit"))
+///             .with_item(MenuItem::new("Remove"));
+///         let position = position.clone();
+///         ContextMenu::new(menu, (x, y)).on_close(move |_| position.set(None))
+///     });
+///
+///     Container::new()
+///         .oncontextmenu({
+///             let position = position.clone();
+///             move |event: MouseEvent| {
+///                 event.prevent_default();
+///                 position.set(Some(ContextMenu::event_position(&event)));
+///             }
+///         })
+///         .with_child("Right click me")
+///         .with_optional_child(context_menu)
+///         .into()
+/// }
+/// ```
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct ContextMenu {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// The menu.
+    pub menu: Menu,
+
+    /// Viewport coordinates (usually the mouse position).
+    pub position: (f64, f64),
+
+    /// Called when the menu should be closed.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_close: Option<Callback<()>>,
+}
+
+impl ContextMenu {
+    /// Create a new instance.
+    pub fn new(menu: Menu, position: (f64, f64)) -> Self {
+        yew::props!(Self { menu, position })
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+
+    /// Returns the menu position for a `contextmenu` event.
+    ///
+    /// This is the mouse position, or the bottom left corner of the target
+    /// element if the event was triggered with the keyboard (`ContextMenu`
+    /// key or `Shift+F10`).
+    pub fn event_position(event: &MouseEvent) -> (f64, f64) {
+        let (x, y) = (event.client_x() as f64, event.client_y() as f64);
+        if x != 0.0 || y != 0.0 {
+            return (x, y);
+        }
+        match event.target_dyn_into::<web_sys::Element>() {
+            Some(el) => {
+                let rect = el.get_bounding_client_rect();
+                (rect.left(), rect.bottom())
+            }
+            None => (x, y),
+        }
+    }
+}
+
+// Place the menu at (x, y), flip it if it does not fit, and clamp it to the viewport.
+fn menu_position(
+    (x, y): (f64, f64),
+    (width, height): (f64, f64),
+    (viewport_width, viewport_height): (f64, f64),
+) -> (f64, f64) {
+    let place = |pos: f64, size: f64, available: f64| {
+        let pos = if pos + size + VIEWPORT_PADDING > available && pos - size >= VIEWPORT_PADDING {
+            pos - size
+        } else {
+            pos
+        };
+        pos.min(available - size - VIEWPORT_PADDING)
+            .max(VIEWPORT_PADDING)
+    };
+    (
+        place(x, width, viewport_width),
+        place(y, height, viewport_height),
+    )
+}
+
+pub enum Msg {
+    Close,
+    FocusChange(bool),
+    Controller, // MenuController has new messages
+}
+
+#[doc(hidden)]
+pub struct PwtContextMenu {
+    menu_ref: NodeRef,
+    menu_controller: MenuController,
+    _menu_controller_observer: SharedStateObserver<Vec<MenuControllerMsg>>,
+    focus_tracker: FocusTracker,
+    // element to focus when the menu is closed
+    last_focus: Option<web_sys::HtmlElement>,
+    closed: bool,
+    _listeners: (EventListener, EventListener), // pointerdown, resize
+}
+
+impl PwtContextMenu {
+    fn close(&mut self, ctx: &Context<Self>) {
+        if self.closed {
+            return;
+        }
+        self.closed = true;
+        if let Some(on_close) = &ctx.props().on_close {
+            on_close.emit(());
+        }
+    }
+
+    fn update_position(&self, ctx: &Context<Self>) {
+        let Some(el) = self.menu_ref.cast::<web_sys::HtmlElement>() else {
+            return;
+        };
+        let window = gloo_utils::window();
+        let viewport_size = |size: Result<wasm_bindgen::JsValue, _>| {
+            size.ok().and_then(|v| v.as_f64()).unwrap_or(0.0)
+        };
+        let viewport = (
+            viewport_size(window.inner_width()),
+            viewport_size(window.inner_height()),
+        );
+        let rect = el.get_bounding_client_rect();
+        let (x, y) = menu_position(
+            ctx.props().position,
+            (rect.width(), rect.height()),
+            viewport,
+        );
+
+        let style = el.style();
+        let _ = style.set_property("inset", "auto");
+        let _ = style.set_property("margin", "0");
+        let _ = style.set_property("left", &format!("{x}px"));
+        let _ = style.set_property("top", &format!("{y}px"));
+    }
+}
+
+impl Component for PwtContextMenu {
+    type Message = Msg;
+    type Properties = ContextMenu;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let menu_ref = NodeRef::default();
+        let menu_controller = MenuController::new();
+        let _menu_controller_observer = menu_controller
+            .state
+            .add_listener(ctx.link().callback(|_| Msg::Controller));
+
+        let last_focus = gloo_utils::document()
+            .active_element()
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+
+        let pointerdown = EventListener::new_with_options(
+            &gloo_utils::document(),
+            "pointerdown",
+            EventListenerOptions::run_in_capture_phase(),
+            {
+                let menu_ref = menu_ref.clone();
+                let link = ctx.link().clone();
+                move |event| {
+                    let target = event
+                        .target()
+                        .and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                    if let (Some(menu), Some(target)) = (menu_ref.get(), &target) {
+                        if menu.contains(Some(target)) {
+                            return;
+                        }
+                    }
+                    link.send_message(Msg::Close);
+                }
+            },
+        );
+        let resize = EventListener::new(&gloo_utils::window(), "resize", {
+            let link = ctx.link().clone();
+            move |_| link.send_message(Msg::Close)
+        });
+
+        Self {
+            menu_ref,
+            menu_controller,
+            _menu_controller_observer,
+            focus_tracker: FocusTracker::new(ctx.link().callback(Msg::FocusChange)),
+            last_focus,
+            closed: false,
+            _listeners: (pointerdown, resize),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Controller => {
+                if self.menu_controller.state.read().is_empty() {
+                    return false; // Note: avoid endless loop
+                }
+                let list = self.menu_controller.state.write().split_off(0);
+                if list
+                    .iter()
+                    .any(|msg| matches!(msg, MenuControllerMsg::Collapse))
+                {
+                    self.close(ctx);
+                }
+                false
+            }
+            Msg::Close => {
+                self.close(ctx);
+                false
+            }
+            Msg::FocusChange(has_focus) => {
+                if !has_focus {
+                    self.close(ctx);
+                }
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, _ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        // the parent may re-use the component for a new position
+        self.closed = false;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let menu = props
+            .menu
+            .clone()
+            .autofocus(true)
+            .menu_controller(self.menu_controller.clone())
+            .on_close(link.callback(|_| Msg::Close));
+
+        Container::new()
+            .attribute("popover", "manual")
+            .attribute("role", "none")
+            .class("pwt-submenu")
+            .class("pwt-context-menu")
+            .style("position", "fixed")
+            .onfocusin(self.focus_tracker.get_focus_callback(true))
+            .onfocusout(self.focus_tracker.get_focus_callback(false))
+            .onkeydown(link.batch_callback(|event: KeyboardEvent| {
+                if event.key() == "Escape" {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    Some(Msg::Close)
+                } else {
+                    None
+                }
+            }))
+            .oncontextmenu(|event: MouseEvent| {
+                // no browser context menu on top of our own
+                event.prevent_default();
+            })
+            .with_child(menu)
+            .into_html_with_ref(self.menu_ref.clone())
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(node) = self.menu_ref.get() {
+                crate::show_popover(node);
+            }
+        }
+        self.update_position(ctx);
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if let Some(node) = self.menu_ref.get() {
+            crate::hide_popover(node);
+        }
+        if let Some(el) = &self.last_focus {
+            if el.is_connected() {
+                let _ = el.focus();
+            }
+        }
+    }
+}
+
+impl From<ContextMenu> for VNode {
+    fn from(val: ContextMenu) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtContextMenu>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
@@ -65,6 +65,19 @@ pub struct MenuButton {
     #[builder_cb(IntoEventCallback, into_event_callback, ())]
     pub on_close: Option<Callback<()>>,
 
+    /// Controlled open state.
+    ///
+    /// If set, the menu visibility follows this property, and user
+    /// interactions only emit [on_open_change](Self::on_open_change).
+    #[prop_or_default]
+    #[builder(IntoPropValue, into_prop_value)]
+    pub open: Option<bool>,
+
+    /// Called when the user opens or closes the menu.
+    #[prop_or_default]
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    pub on_open_change: Option<Callback<bool>>,
+
     #[builder_cb(IntoPropValue, into_prop_value, Option<MenuController>)]
     #[prop_or_default]
     pub(crate) menu_controller: Option<MenuController>,
@@ -103,6 +116,7 @@ impl MenuButton {
 pub enum Msg {
     ShowMenu,
     CloseMenu,
+    AnchorHidden,
     FocusChange(bool),
     Controller, // MenuController has new messages
 }
@@ -119,6 +133,18 @@ pub struct PwtMenuButton {
 }
 
 impl PwtMenuButton {
+    // Change the menu visibility (only notify the parent in controlled mode).
+    fn set_show_submenu(&mut self, props: &MenuButton, show: bool) {
+        if self.show_submenu != show {
+            if let Some(on_open_change) = &props.on_open_change {
+                on_open_change.emit(show);
+            }
+        }
+        if props.open.is_none() {
+            self.show_submenu = show;
+        }
+    }
+
     fn restore_focus(&mut self, _props: &MenuButton) {
         if let Some(node) = self.align_ref.get() {
             if let Ok(el) = node.dyn_into::<web_sys::HtmlElement>() {
@@ -160,7 +186,8 @@ impl Component for PwtMenuButton {
 
         let align_ref = NodeRef::default();
 
-        let popper = MenuPopper::new(align_ref.clone(), submenu_ref.clone(), true);
+        let popper = MenuPopper::new(align_ref.clone(), submenu_ref.clone(), true)
+            .on_anchor_hidden(ctx.link().callback(|_| Msg::AnchorHidden));
 
         let menu_controller = props
             .menu_controller
@@ -179,7 +206,7 @@ impl Component for PwtMenuButton {
             popper,
             menu_controller,
             _menu_controller_observer,
-            show_submenu: false,
+            show_submenu: props.open.unwrap_or(false),
             focus_tracker,
         }
     }
@@ -192,30 +219,45 @@ impl Component for PwtMenuButton {
                 true
             }
             Msg::ShowMenu => {
-                self.show_submenu = true;
+                self.set_show_submenu(props, true);
                 true
             }
             Msg::CloseMenu => {
-                self.show_submenu = false;
+                self.set_show_submenu(props, false);
                 self.restore_focus(props);
                 if let Some(on_close) = &props.on_close {
                     on_close.emit(());
                 }
                 true
             }
+            Msg::AnchorHidden => {
+                // Note: do not restore focus, because this would scroll the button into view
+                self.set_show_submenu(props, false);
+                if let Some(on_close) = &props.on_close {
+                    on_close.emit(());
+                }
+                true
+            }
             Msg::FocusChange(has_focus) => {
                 if has_focus {
                     if props.autoshow_menu {
-                        self.show_submenu = true;
+                        self.set_show_submenu(props, true);
                     }
                 } else {
-                    self.show_submenu = false;
+                    self.set_show_submenu(props, false);
                 }
                 true
             }
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        if let Some(open) = ctx.props().open {
+            self.show_submenu = open;
+        }
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
@@ -292,6 +334,8 @@ impl Component for PwtMenuButton {
     fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
         if self.show_submenu {
             self.popper.update();
+        } else {
+            self.popper.clear();
         }
     }
 }
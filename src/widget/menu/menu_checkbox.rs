@@ -1,11 +1,15 @@
+use std::any::Any;
 use std::rc::Rc;
 
+use serde::{Serialize, de::DeserializeOwned};
+
 use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
 use yew::virtual_dom::{VComp, VNode};
 
 use crate::prelude::*;
 use crate::props::FieldStdProps;
+use crate::state::{PersistentState, SharedState};
 use crate::widget::Container;
 use crate::widget::form::{
     ManagedField, ManagedFieldContext, ManagedFieldMaster, ManagedFieldScopeExt, ManagedFieldState,
@@ -18,6 +22,88 @@ use pwt_macros::builder;
 
 pub type PwtMenuCheckbox = ManagedFieldMaster<MenuCheckboxField>;
 
+// Type erased access to a bound state value.
+trait CheckedBinding {
+    fn checked(&self) -> bool;
+    fn set_checked(&self, checked: bool);
+    fn add_listener(&self, cb: Callback<()>) -> Box<dyn Any>;
+    fn as_any(&self) -> &dyn Any;
+    fn same_binding(&self, other: &dyn Any) -> bool;
+}
+
+struct StateBinding<S: 'static, T> {
+    state: SharedState<S>,
+    get: fn(&S) -> T,
+    set: fn(&mut S, T),
+    on_value: T,
+    off_value: Option<T>, // None for radio groups
+}
+
+impl<S: 'static, T: 'static + Clone + PartialEq> CheckedBinding for StateBinding<S, T> {
+    fn checked(&self) -> bool {
+        let guard = self.state.read();
+        (self.get)(&**guard) == self.on_value
+    }
+
+    fn set_checked(&self, checked: bool) {
+        let value = match (checked, &self.off_value) {
+            (true, _) => self.on_value.clone(),
+            (false, Some(off_value)) => off_value.clone(),
+            // unchecking a radio button does not change the group value
+            (false, None) => return,
+        };
+        let mut guard = self.state.write();
+        (self.set)(&mut **guard, value);
+    }
+
+    fn add_listener(&self, cb: Callback<()>) -> Box<dyn Any> {
+        Box::new(self.state.add_listener(move |_| cb.emit(())))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn same_binding(&self, other: &dyn Any) -> bool {
+        match other.downcast_ref::<Self>() {
+            Some(other) => {
+                self.state == other.state
+                    && self.on_value == other.on_value
+                    && self.off_value == other.off_value
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[doc(hidden)]
+pub struct MenuCheckboxBinding(Rc<dyn CheckedBinding>);
+
+impl MenuCheckboxBinding {
+    fn new<S: 'static, T: 'static + Clone + PartialEq>(
+        state: SharedState<S>,
+        get: fn(&S) -> T,
+        set: fn(&mut S, T),
+        on_value: T,
+        off_value: Option<T>,
+    ) -> Self {
+        Self(Rc::new(StateBinding {
+            state,
+            get,
+            set,
+            on_value,
+            off_value,
+        }))
+    }
+}
+
+impl PartialEq for MenuCheckboxBinding {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.same_binding(other.0.as_any())
+    }
+}
+
 /// Checkbox/RadioGroup widget for [Menu](super::Menu)s.
 #[derive(Clone, PartialEq, Properties)]
 #[builder]
@@ -70,6 +156,10 @@ pub struct MenuCheckbox {
     #[builder_cb(IntoPropValue, into_prop_value, Option<MenuController>)]
     #[prop_or_default]
     pub(crate) menu_controller: Option<MenuController>,
+
+    /// Two-way binding of the checked state (see [Self::bind]).
+    #[prop_or_default]
+    binding: Option<MenuCheckboxBinding>,
 }
 
 impl FieldBuilder for MenuCheckbox {
@@ -94,15 +184,139 @@ impl MenuCheckbox {
             radio_group: true,
         })
     }
+
+    /// Builder style method to bind the checked state to a [SharedState].
+    ///
+    /// The binding works in both directions, i.e. toggling the checkbox
+    /// updates the state, and state changes update the checkbox. This
+    /// keeps all widgets using the same state in sync.
+    pub fn bind(mut self, state: SharedState<bool>) -> Self {
+        self.set_bind(state);
+        self
+    }
+
+    /// Method to bind the checked state to a [SharedState].
+    pub fn set_bind(&mut self, state: SharedState<bool>) {
+        self.binding = Some(MenuCheckboxBinding::new(
+            state,
+            |data| *data,
+            |data, value| *data = value,
+            true,
+            Some(false),
+        ));
+    }
+
+    /// Builder style method to bind the checked state to a shared [PersistentState].
+    ///
+    /// Same as [Self::bind], but the value is also stored persistently.
+    pub fn bind_persistent(mut self, state: SharedState<PersistentState<bool>>) -> Self {
+        self.set_bind_persistent(state);
+        self
+    }
+
+    /// Method to bind the checked state to a shared [PersistentState].
+    pub fn set_bind_persistent(&mut self, state: SharedState<PersistentState<bool>>) {
+        self.binding = Some(MenuCheckboxBinding::new(
+            state,
+            |data| **data,
+            |data, value| data.update(value),
+            true,
+            Some(false),
+        ));
+    }
+
+    /// Builder style method to bind a radio button to a [SharedState].
+    ///
+    /// The radio button is checked if the state is equal to `value`, and
+    /// selecting the radio button sets the state to `value`. This is
+    /// usually used with an `enum`, one radio button for each variant.
+    ///
+    /// ```
+    /// # use pwt::prelude::*;
+    /// # use pwt::state::SharedState;
+    /// # use pwt::widget::menu::{Menu, MenuCheckbox};
+    /// #[derive(Clone, Copy, PartialEq)]
+    /// enum GroupBy { None, Type }
+    ///
+    /// fn view_menu(group_by: SharedState<GroupBy>) -> Menu {
+    ///     Menu::new()
+    ///         .with_item(MenuCheckbox::radio("No grouping").bind_radio(group_by.clone(), GroupBy::None))
+    ///         .with_item(MenuCheckbox::radio("Group by type").bind_radio(group_by, GroupBy::Type))
+    /// }
+    /// ```
+    pub fn bind_radio<T: 'static + Clone + PartialEq>(
+        mut self,
+        state: SharedState<T>,
+        value: T,
+    ) -> Self {
+        self.set_bind_radio(state, value);
+        self
+    }
+
+    /// Method to bind a radio button to a [SharedState].
+    pub fn set_bind_radio<T: 'static + Clone + PartialEq>(
+        &mut self,
+        state: SharedState<T>,
+        value: T,
+    ) {
+        self.binding = Some(MenuCheckboxBinding::new(
+            state,
+            |data| data.clone(),
+            |data, value| *data = value,
+            value,
+            None,
+        ));
+    }
+
+    /// Builder style method to bind a radio button to a shared [PersistentState].
+    ///
+    /// Same as [Self::bind_radio], but the value is also stored persistently.
+    pub fn bind_radio_persistent<T>(
+        mut self,
+        state: SharedState<PersistentState<T>>,
+        value: T,
+    ) -> Self
+    where
+        T: 'static + Clone + PartialEq + Default + Serialize + DeserializeOwned,
+    {
+        self.set_bind_radio_persistent(state, value);
+        self
+    }
+
+    /// Method to bind a radio button to a shared [PersistentState].
+    pub fn set_bind_radio_persistent<T>(&mut self, state: SharedState<PersistentState<T>>, value: T)
+    where
+        T: 'static + Clone + PartialEq + Default + Serialize + DeserializeOwned,
+    {
+        self.binding = Some(MenuCheckboxBinding::new(
+            state,
+            |data| (**data).clone(),
+            |data, value| data.update(value),
+            value,
+            None,
+        ));
+    }
 }
 
 pub enum Msg {
     Toggle,
+    BindingChanged,
 }
 
 #[doc(hidden)]
 pub struct MenuCheckboxField {
     state: ManagedFieldState,
+    _binding_observer: Option<Box<dyn Any>>,
+}
+
+impl MenuCheckboxField {
+    fn observe_binding(ctx: &ManagedFieldContext<Self>) -> Option<Box<dyn Any>> {
+        ctx.props().binding.as_ref().map(|binding| {
+            binding
+                .0
+                .add_listener(ctx.link().callback(|()| Msg::BindingChanged))
+        })
+    }
 }
 
 crate::impl_deref_mut_property!(MenuCheckboxField, state, ManagedFieldState);
@@ -123,7 +337,12 @@ impl ManagedField for MenuCheckboxField {
             _ => String::new(),
         };
 
-        let value = match props.checked {
+        let checked = match &props.binding {
+            Some(binding) => Some(binding.0.checked()),
+            None => props.checked,
+        };
+
+        let value = match checked {
             Some(true) => on_value.clone(),
             Some(false) => String::new(),
             None => default.clone(),
@@ -133,7 +352,10 @@ impl ManagedField for MenuCheckboxField {
         state.radio_group = props.radio_group;
         state.unique = true;
 
-        Self { state }
+        Self {
+            state,
+            _binding_observer: Self::observe_binding(ctx),
+        }
     }
 
     fn value_changed(&mut self, ctx: &ManagedFieldContext<Self>) {
@@ -142,6 +364,12 @@ impl ManagedField for MenuCheckboxField {
         let on_value = props.value.as_deref().unwrap_or("on").to_string();
         let checked = value == on_value;
 
+        if let Some(binding) = &props.binding {
+            if binding.0.checked() != checked {
+                binding.0.set_checked(checked);
+            }
+        }
+
         let mut event = MenuEvent::new();
         event.checked = checked;
         if let Some(on_change) = &props.on_change {
@@ -153,7 +381,7 @@ impl ManagedField for MenuCheckboxField {
         let props = ctx.props();
         match msg {
             Msg::Toggle => {
-                if props.input_props.disabled {
+                if self.is_disabled(props) {
                     return false;
                 }
 
@@ -187,12 +415,41 @@ impl ManagedField for MenuCheckboxField {
 
                 true
             }
+            Msg::BindingChanged => {
+                let binding = match &props.binding {
+                    Some(binding) => binding,
+                    None => return false,
+                };
+
+                let on_value = props.value.as_deref().unwrap_or("on").to_string();
+                let checked = binding.0.checked();
+                if checked == (self.value == on_value) {
+                    return false;
+                }
+
+                if props.input_props.name.is_none() {
+                    let value = if checked { on_value } else { String::new() };
+                    ctx.link().force_value(Some(value), None);
+                } else if checked {
+                    ctx.link().update_value(on_value);
+                } else if !props.radio_group {
+                    // radio group values are changed by the checked radio button
+                    ctx.link().update_value(String::new());
+                }
+
+                false
+            }
         }
     }
 
-    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, _old_props: &Self::Properties) -> bool {
+    fn changed(&mut self, ctx: &ManagedFieldContext<Self>, old_props: &Self::Properties) -> bool {
         let props = ctx.props();
 
+        if props.binding != old_props.binding {
+            self._binding_observer = Self::observe_binding(ctx);
+            ctx.link().send_message(Msg::BindingChanged);
+        }
+
         if let Some(checked) = props.checked {
             let on_value = props.value.as_deref().unwrap_or("on").to_string();
             let value = if checked { on_value } else { String::new() };
@@ -205,7 +462,7 @@ impl ManagedField for MenuCheckboxField {
     fn view(&self, ctx: &ManagedFieldContext<Self>) -> Html {
         let props = ctx.props();
 
-        let disabled = props.input_props.disabled;
+        let disabled = self.is_disabled(props);
 
         let on_value = props.value.as_deref().unwrap_or("on").to_string();
         let value = self.value.clone();
@@ -5,7 +5,8 @@ use yew::prelude::*;
 use yew::virtual_dom::{VComp, VNode};
 
 use crate::prelude::*;
-use crate::widget::Container;
+use crate::state::{Capabilities, MissingCapPolicy, RequiredCaps};
+use crate::widget::{Container, Tooltip};
 
 use pwt_macros::builder;
 
@@ -34,6 +35,10 @@ pub struct MenuItem {
     #[builder]
     pub disabled: bool,
 
+    /// Required capabilities (see [Capabilities]).
+    #[prop_or_default]
+    pub required_caps: RequiredCaps,
+
     /// Indicates that the `text` contains a focusable element.
     ///
     /// If set, the menu item does not add `tabindex: -1` to the
@@ -104,6 +109,31 @@ impl MenuItem {
         self.menu = menu.into_prop_value();
     }
 
+    /// Builder style method to add a required capability.
+    ///
+    /// The item is disabled (or hidden, see [Self::missing_cap_policy])
+    /// if the capability is not granted by the [Capabilities] context.
+    pub fn require_cap(mut self, cap: impl Into<AttrValue>) -> Self {
+        self.add_required_cap(cap);
+        self
+    }
+
+    /// Method to add a required capability.
+    pub fn add_required_cap(&mut self, cap: impl Into<AttrValue>) {
+        self.required_caps.add(cap);
+    }
+
+    /// Builder style method to set the missing capability policy.
+    pub fn missing_cap_policy(mut self, policy: MissingCapPolicy) -> Self {
+        self.set_missing_cap_policy(policy);
+        self
+    }
+
+    /// Method to set the missing capability policy.
+    pub fn set_missing_cap_policy(&mut self, policy: MissingCapPolicy) {
+        self.required_caps.set_policy(policy);
+    }
+
     // Methods below are used internally.
 
     pub(crate) fn has_menu(&self) -> bool {
@@ -113,6 +143,7 @@ impl MenuItem {
 
 pub enum Msg {
     Select,
+    CapabilitiesUpdate(Capabilities),
 }
 
 #[doc(hidden)]
@@ -120,6 +151,8 @@ pub struct PwtMenuItem {
     content_ref: NodeRef,
     submenu_ref: NodeRef,
     popper: MenuPopper,
+    capabilities: Option<Capabilities>,
+    _capabilities_handle: Option<ContextHandle<Capabilities>>,
 }
 
 impl Component for PwtMenuItem {
@@ -137,10 +170,17 @@ impl Component for PwtMenuItem {
             props.inside_menubar,
         );
 
+        let (capabilities, _capabilities_handle) = ctx
+            .link()
+            .context::<Capabilities>(ctx.link().callback(Msg::CapabilitiesUpdate))
+            .unzip();
+
         Self {
             content_ref,
             submenu_ref,
             popper,
+            capabilities,
+            _capabilities_handle,
         }
     }
 
@@ -165,12 +205,21 @@ impl Component for PwtMenuItem {
                 }
                 false
             }
+            Msg::CapabilitiesUpdate(capabilities) => {
+                self.capabilities = Some(capabilities);
+                true
+            }
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
+        let cap_check = props.required_caps.check(self.capabilities.as_ref());
+        if cap_check.hidden() {
+            return html! {};
+        }
+
         let show_submenu = props.active && props.show_submenu;
 
         let mut submenu: Option<Html> = None;
@@ -220,15 +269,15 @@ impl Component for PwtMenuItem {
             html! {<i role="none" class={arrow_class}/>}
         });
 
-        let disabled = props.disabled;
-        Container::new()
+        let disabled = props.disabled || !cap_check.granted();
+        let item = Container::new()
             .class(if props.inside_menubar {
                 "pwt-menubar-item"
             } else {
                 "pwt-menu-item"
             })
             .attribute("tabindex", (!props.focusable).then_some("-1"))
-            .attribute("aria-disabled", props.disabled.then_some("true"))
+            .attribute("aria-disabled", disabled.then_some("true"))
             .attribute("role", "menuitem")
             .attribute("aria-haspopup", has_submenu.then_some("true"))
             .attribute(
@@ -260,7 +309,12 @@ impl Component for PwtMenuItem {
                     };
                 }
             }))
-            .into_html_with_ref(self.content_ref.clone())
+            .into_html_with_ref(self.content_ref.clone());
+
+        match cap_check.tip() {
+            Some(tip) => Tooltip::new(item).attribute("role", "none").tip(tip).into(),
+            None => item,
+        }
     }
 
     fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
@@ -270,6 +324,8 @@ impl Component for PwtMenuItem {
         }
         if props.active && props.show_submenu {
             self.popper.update();
+        } else {
+            self.popper.clear();
         }
     }
 }
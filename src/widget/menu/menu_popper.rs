@@ -1,3 +1,6 @@
+use gloo_events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
+
 use yew::prelude::*;
 
 use crate::dom::align::{AlignOptions, GrowDirection, Point, align_to};
@@ -7,6 +10,8 @@ pub struct MenuPopper {
     submenu_ref: NodeRef,
     align_options: Option<AlignOptions>,
     prefer_bottom: bool,
+    on_anchor_hidden: Option<Callback<()>>,
+    listeners: Option<(EventListener, EventListener)>, // scroll, resize
 }
 
 impl MenuPopper {
@@ -16,50 +21,177 @@ impl MenuPopper {
             submenu_ref,
             prefer_bottom,
             align_options: None,
+            on_anchor_hidden: None,
+            listeners: None,
         }
     }
 
+    /// Builder style method to set a callback, called when the anchor
+    /// element gets scrolled out of view.
+    pub fn on_anchor_hidden(mut self, cb: impl Into<Callback<()>>) -> Self {
+        self.on_anchor_hidden = Some(cb.into());
+        self
+    }
+
     pub fn update_refs(&mut self, base: NodeRef, submenu: NodeRef) {
         self.content_ref = base;
         self.submenu_ref = submenu;
     }
 
+    fn align_options(&mut self) -> AlignOptions {
+        self.align_options
+            .get_or_insert_with(|| {
+                if self.prefer_bottom {
+                    AlignOptions::new(Point::BottomStart, Point::TopStart, GrowDirection::StartEnd)
+                        .align_width(true)
+                        .offset(0.0, 1.0)
+                        .viewport_padding(5.0)
+                        .shift(true)
+                        .with_fallback_placement(
+                            Point::TopStart,
+                            Point::BottomStart,
+                            GrowDirection::StartEnd,
+                        )
+                        .with_fallback_placement(
+                            Point::TopEnd,
+                            Point::TopStart,
+                            GrowDirection::TopBottom,
+                        )
+                        .with_fallback_placement(
+                            Point::TopStart,
+                            Point::TopEnd,
+                            GrowDirection::TopBottom,
+                        )
+                } else {
+                    AlignOptions::new(Point::TopEnd, Point::TopStart, GrowDirection::TopBottom)
+                        .viewport_padding(5.0)
+                        .offset(1.0, 0.0)
+                        .align_width(true)
+                        .shift(true)
+                        .with_fallback_placement(
+                            Point::TopStart,
+                            Point::TopEnd,
+                            GrowDirection::TopBottom,
+                        )
+                }
+            })
+            .clone()
+    }
+
+    /// Position the menu and start tracking scroll and resize events.
     pub fn update(&mut self) {
-        if self.align_options.is_none() {
-            self.align_options = Some(if self.prefer_bottom {
-                AlignOptions::new(Point::BottomStart, Point::TopStart, GrowDirection::StartEnd)
-                    .align_width(true)
-                    .offset(0.0, 1.0)
-                    .viewport_padding(5.0)
-                    .with_fallback_placement(
-                        Point::TopEnd,
-                        Point::TopStart,
-                        GrowDirection::TopBottom,
-                    )
-                    .with_fallback_placement(
-                        Point::TopStart,
-                        Point::TopEnd,
-                        GrowDirection::TopBottom,
-                    )
-            } else {
-                AlignOptions::new(Point::TopEnd, Point::TopStart, GrowDirection::TopBottom)
-                    .viewport_padding(5.0)
-                    .offset(1.0, 0.0)
-                    .align_width(true)
-                    .with_fallback_placement(
-                        Point::TopStart,
-                        Point::TopEnd,
-                        GrowDirection::TopBottom,
-                    )
-            });
-        }
+        let align_options = self.align_options();
 
         if let Err(err) = align_to(
             &self.content_ref,
             &self.submenu_ref,
-            self.align_options.clone(),
+            Some(align_options.clone()),
         ) {
             log::error!("could not position menu: {}", err.to_string());
         }
+
+        if self.listeners.is_none() {
+            self.listeners = Some(self.create_listeners(align_options));
+        }
+    }
+
+    /// Stop tracking scroll and resize events (call when the menu is hidden).
+    pub fn clear(&mut self) {
+        self.listeners = None;
+    }
+
+    fn create_listeners(&self, align_options: AlignOptions) -> (EventListener, EventListener) {
+        let reposition = {
+            let content_ref = self.content_ref.clone();
+            let submenu_ref = self.submenu_ref.clone();
+            let on_anchor_hidden = self.on_anchor_hidden.clone();
+            move || {
+                let anchor = match content_ref.cast::<web_sys::Element>() {
+                    Some(anchor) => anchor,
+                    None => return,
+                };
+                if submenu_ref.get().is_none() {
+                    return;
+                }
+                if let Some(on_anchor_hidden) = &on_anchor_hidden {
+                    if !element_in_view(&anchor) {
+                        on_anchor_hidden.emit(());
+                        return;
+                    }
+                }
+                if let Err(err) = align_to(&content_ref, &submenu_ref, Some(align_options.clone()))
+                {
+                    log::error!("could not position menu: {}", err.to_string());
+                }
+            }
+        };
+
+        let scroll_listener = EventListener::new_with_options(
+            &gloo_utils::document(),
+            "scroll",
+            EventListenerOptions::run_in_capture_phase(),
+            {
+                let submenu_ref = self.submenu_ref.clone();
+                let reposition = reposition.clone();
+                move |event| {
+                    // ignore scrolling inside the menu itself
+                    let target = event
+                        .target()
+                        .and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                    if let (Some(menu), Some(target)) = (submenu_ref.get(), &target) {
+                        if menu.contains(Some(target)) {
+                            return;
+                        }
+                    }
+                    reposition();
+                }
+            },
+        );
+
+        let resize_listener =
+            EventListener::new(&gloo_utils::window(), "resize", move |_| reposition());
+
+        (scroll_listener, resize_listener)
+    }
+}
+
+// Test if the element is visible inside the viewport and all clipping (scroll) ancestors.
+fn element_in_view(el: &web_sys::Element) -> bool {
+    let rect = el.get_bounding_client_rect();
+    let window = gloo_utils::window();
+
+    let mut top = 0f64;
+    let mut left = 0f64;
+    let mut bottom = window
+        .inner_height()
+        .ok()
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0);
+    let mut right = window
+        .inner_width()
+        .ok()
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0);
+
+    let mut parent = el.parent_element();
+    while let Some(current) = parent {
+        if let Ok(Some(style)) = window.get_computed_style(&current) {
+            let clips = |property: &str| {
+                !matches!(
+                    style.get_property_value(property).as_deref(),
+                    Ok("visible") | Ok("")
+                )
+            };
+            if clips("overflow-x") || clips("overflow-y") {
+                let clip = current.get_bounding_client_rect();
+                top = top.max(clip.top());
+                left = left.max(clip.left());
+                bottom = bottom.min(clip.bottom());
+                right = right.min(clip.right());
+            }
+        }
+        parent = current.parent_element();
     }
+
+    rect.bottom() > top && rect.top() < bottom && rect.right() > left && rect.left() < right
 }
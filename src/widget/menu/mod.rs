@@ -9,7 +9,8 @@ use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::virtual_dom::{Key, VComp, VNode};
 
 use crate::dom::focus::{FocusTracker, get_first_focusable};
-use crate::state::{SharedState, SharedStateObserver};
+use crate::dom::pointer_moves_towards;
+use crate::state::{Capabilities, SharedState, SharedStateObserver};
 use crate::widget::{Container, get_unique_element_id};
 use crate::{impl_class_prop_builder, impl_yew_std_props_builder, prelude::*};
 
@@ -29,6 +30,11 @@ pub use menu_checkbox::MenuCheckbox;
 #[doc(hidden)]
 pub use menu_checkbox::PwtMenuCheckbox;
 
+mod context_menu;
+pub use context_menu::ContextMenu;
+#[doc(hidden)]
+pub use context_menu::PwtContextMenu;
+
 mod menu_button;
 pub use menu_button::MenuButton;
 #[doc(hidden)]
@@ -219,6 +225,10 @@ impl Menu {
     }
 }
 
+// Minimum delay before switching submenus while the pointer moves towards
+// the open submenu.
+const SAFE_TRIANGLE_TIMEOUT_MS: u32 = 300;
+
 #[doc(hidden)]
 pub enum Msg {
     Collapse,
@@ -228,12 +238,14 @@ pub enum Msg {
     DelayedNext,
     DelayedPrevious,
     ActivateItem(usize, bool),
-    OnMouseOver(usize),
+    OnMouseOver(usize, bool), // index, pointer moves towards open submenu
+    HoverIntent(usize),
     SetActiveSubmenu(usize),
     ShowSubmenu(bool, bool),
     SubmenuClose,
     Redraw,
     Controller, // MenuController has new messages
+    CapabilitiesUpdate(Capabilities),
 }
 
 #[doc(hidden)]
@@ -251,6 +263,8 @@ pub struct PwtMenu {
     move_timeout: Option<Timeout>, // for Next/Prev
     active_submenu: Option<usize>,
     submenu_timer: Option<Timeout>,
+    capabilities: Option<Capabilities>,
+    _capabilities_handle: Option<ContextHandle<Capabilities>>,
 }
 
 impl PwtMenu {
@@ -399,6 +413,11 @@ impl Component for PwtMenu {
 
         let focus_tracker = FocusTracker::new(ctx.link().callback(Msg::FocusChange));
 
+        let (capabilities, _capabilities_handle) = ctx
+            .link()
+            .context::<Capabilities>(ctx.link().callback(Msg::CapabilitiesUpdate))
+            .unzip();
+
         let mut me = Self {
             cursor: None,
             unique_id: get_unique_element_id(),
@@ -413,6 +432,8 @@ impl Component for PwtMenu {
             move_timeout: None,
             active_submenu: None,
             submenu_timer: None,
+            capabilities,
+            _capabilities_handle,
         };
 
         me.handle_controller_messages(ctx);
@@ -452,6 +473,10 @@ impl Component for PwtMenu {
                 true
             }
             Msg::Redraw => true,
+            Msg::CapabilitiesUpdate(capabilities) => {
+                self.capabilities = Some(capabilities);
+                true
+            }
             Msg::Next => {
                 let link = ctx.link().clone();
                 self.move_timeout = Some(Timeout::new(1, move || {
@@ -598,7 +623,23 @@ impl Component for PwtMenu {
                 }
                 true
             }
-            Msg::OnMouseOver(index) => {
+            Msg::OnMouseOver(index, true) => {
+                // pointer is on the way to the open submenu (safe triangle),
+                // so delay the switch to the hovered item.
+                let link = ctx.link().clone();
+                let timeout = props.submenu_timeout_ms.max(SAFE_TRIANGLE_TIMEOUT_MS);
+                self.submenu_timer = Some(Timeout::new(timeout, move || {
+                    link.send_message(Msg::HoverIntent(index))
+                }));
+                false
+            }
+            Msg::HoverIntent(index) => {
+                dom_focus_submenu(&self.get_unique_item_id(index));
+                self.active_submenu = Some(index);
+                self.show_submenu = true;
+                true
+            }
+            Msg::OnMouseOver(index, false) => {
                 let link = ctx.link().clone();
                 if props.menubar || props.submenu_timeout_ms == 0 {
                     self.active_submenu = Some(index);
@@ -623,6 +664,12 @@ impl Component for PwtMenu {
 
         let focus_on_over = !props.menubar || self.has_focus;
 
+        // used to test if the pointer moves towards the open submenu
+        let open_submenu = match (props.menubar, self.show_submenu, self.active_submenu) {
+            (false, true, Some(active)) => Some((active, self.get_unique_item_id(active))),
+            _ => None,
+        };
+
         let menu = Container::from_tag("ul")
             .attribute("role", if props.menubar { "menubar" } else { "menu" })
             .attribute("id", self.unique_id.clone())
@@ -633,6 +680,15 @@ impl Component for PwtMenu {
             })
             .class(props.class.clone())
             .children(props.children.iter().enumerate().map(|(i, entry)| {
+                if let MenuEntry::MenuItem(item) = entry {
+                    if item
+                        .required_caps
+                        .check(self.capabilities.as_ref())
+                        .hidden()
+                    {
+                        return html! {};
+                    }
+                }
                 let mut has_submenu = false;
                 let active = self.cursor == Some(i);
                 let submenu_active = self.active_submenu == Some(i);
@@ -720,11 +776,22 @@ impl Component for PwtMenu {
                     })
                     .onmouseover({
                         let item_id = item_id.clone();
+                        let open_submenu = open_submenu.clone();
                         move |event: MouseEvent| {
                             event.stop_propagation();
                             if focus_on_over {
-                                dom_focus_submenu(&item_id);
-                                link.send_message(Msg::OnMouseOver(i))
+                                let towards_submenu = match &open_submenu {
+                                    Some((active, active_id)) if *active != i => {
+                                        dom_find_submenu(active_id).is_some_and(|submenu| {
+                                            pointer_moves_towards(&event, &submenu)
+                                        })
+                                    }
+                                    _ => false,
+                                };
+                                if !towards_submenu {
+                                    dom_focus_submenu(&item_id);
+                                }
+                                link.send_message(Msg::OnMouseOver(i, towards_submenu))
                             }
                         }
                     })
@@ -810,6 +877,11 @@ fn dom_focus_inside_submenu(event: &FocusEvent, item_id: &str) -> bool {
     false
 }
 
+fn dom_find_submenu(item_id: &str) -> Option<web_sys::Element> {
+    let el = gloo_utils::document().get_element_by_id(item_id)?;
+    el.query_selector(".pwt-submenu").ok().flatten()
+}
+
 fn dom_focus_submenu(item_id: &str) {
     let el = match gloo_utils::document().get_element_by_id(item_id) {
         Some(el) => el,
@@ -98,6 +98,7 @@ pub enum Msg {
     Activate(MouseEvent),
     ShowMenu,
     CloseMenu,
+    AnchorHidden,
     FocusChange(bool),
     Controller, // MenuController has new messages
 }
@@ -157,7 +158,8 @@ impl Component for PwtSplitButton {
         let submenu_ref = NodeRef::default();
         let trigger_ref = NodeRef::default();
 
-        let popper = MenuPopper::new(trigger_ref.clone(), submenu_ref.clone(), true);
+        let popper = MenuPopper::new(trigger_ref.clone(), submenu_ref.clone(), true)
+            .on_anchor_hidden(ctx.link().callback(|_| Msg::AnchorHidden));
 
         let menu_controller = props
             .menu_controller
@@ -211,6 +213,14 @@ impl Component for PwtSplitButton {
                 }
                 true
             }
+            Msg::AnchorHidden => {
+                // Note: do not restore focus, because this would scroll the button into view
+                self.show_submenu = false;
+                if let Some(on_close) = &props.on_close {
+                    on_close.emit(());
+                }
+                true
+            }
             Msg::FocusChange(has_focus) => {
                 if !has_focus {
                     self.show_submenu = false;
@@ -306,6 +316,8 @@ impl Component for PwtSplitButton {
     fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
         if self.show_submenu {
             self.popper.update();
+        } else {
+            self.popper.clear();
         }
     }
 }
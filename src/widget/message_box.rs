@@ -81,7 +81,7 @@ pub(crate) fn message(text: impl Into<Html>, icon_class: impl Into<Classes>) ->
 
     Row::new()
         .padding(2)
-        .class("pwt-align-items-center")
+        .class(crate::css::AlignItems::Center)
         .with_child(
             html! {<span class={"pwt-message-sign"} role="none"><i class={icon_class}/></span>},
         )
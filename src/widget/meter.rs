@@ -12,7 +12,13 @@ use crate::props::{
 };
 use crate::widget::Container;
 
+use super::progress::{BarSegment, render_segments};
+
 /// Wrapper for Html `<meter>`.
+///
+/// Set [segments](Self::segments) to show multiple stacked values (e.g.
+/// used/reserved memory), and [buffer](Self::buffer) to show a secondary
+/// value behind the main bar.
 #[widget(pwt=crate, @element)]
 #[builder]
 #[derive(Default, Clone, PartialEq, Properties)]
@@ -73,6 +79,18 @@ pub struct Meter {
     #[builder]
     #[prop_or_default]
     pub animated: bool,
+
+    /// Secondary value, displayed as a lighter bar behind the value bar.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub buffer: Option<f32>,
+
+    /// Stacked segments (replaces the value bar if not empty).
+    ///
+    /// The [value](Self::value) is still used for the text and the
+    /// optimum range coloring.
+    #[prop_or_default]
+    pub segments: Vec<BarSegment>,
 }
 
 impl Meter {
@@ -81,6 +99,28 @@ impl Meter {
         yew::props! { Self {}}
     }
 
+    /// Builder style method to add a segment.
+    pub fn with_segment(mut self, segment: BarSegment) -> Self {
+        self.add_segment(segment);
+        self
+    }
+
+    /// Method to add a segment.
+    pub fn add_segment(&mut self, segment: BarSegment) {
+        self.segments.push(segment);
+    }
+
+    /// Builder style method to set the segments.
+    pub fn segments(mut self, segments: impl IntoIterator<Item = BarSegment>) -> Self {
+        self.set_segments(segments);
+        self
+    }
+
+    /// Method to set the segments.
+    pub fn set_segments(&mut self, segments: impl IntoIterator<Item = BarSegment>) {
+        self.segments = segments.into_iter().collect();
+    }
+
     fn get_range_index(&self, value: f32) -> usize {
         if value < self.low.unwrap_or(self.min) {
             0
@@ -125,13 +165,36 @@ impl IntoVTag for Meter {
             class.push("pwt-animated");
         }
 
-        children.push(
-            Container::new()
-                .class("pwt-meter-bar")
-                .class(format!("pwt-meter-distance-{}", distance_to_optimum))
-                .width(CssLength::Fraction(percentage))
-                .into(),
-        );
+        if let Some(buffer) = self.buffer {
+            let buffer_percentage =
+                ((buffer - self.min).max(0.0) / (self.max - self.min)).clamp(0.0, 1.0);
+            children.push(
+                Container::new()
+                    .class("pwt-meter-buffer")
+                    .width(CssLength::Fraction(buffer_percentage))
+                    .into(),
+            );
+        }
+
+        if self.segments.is_empty() {
+            children.push(
+                Container::new()
+                    .class("pwt-meter-bar")
+                    .class(format!("pwt-meter-distance-{}", distance_to_optimum))
+                    .width(CssLength::Fraction(percentage))
+                    .into(),
+            );
+        } else {
+            children.push(
+                Container::new()
+                    .class("pwt-meter-bar")
+                    .class(format!("pwt-meter-distance-{}", distance_to_optimum))
+                    .width(CssLength::Fraction(1.0))
+                    .style("background", "none")
+                    .with_child(render_segments(&self.segments, self.min, self.max))
+                    .into(),
+            );
+        }
 
         self.std_props.into_vtag(
             Cow::Borrowed("div"),
@@ -2,7 +2,7 @@ use yew::prelude::*;
 
 use gloo_timers::callback::Timeout;
 
-use crate::dom::DomSizeObserver;
+use crate::dom::{DomDragScroll, DomSizeObserver};
 use crate::props::{ContainerBuilder, EventSubscriber, IntoVTag, WidgetBuilder};
 use crate::widget::Container;
 
@@ -31,6 +31,10 @@ pub struct MiniScroll {
     /// Scroll mode. User native scroll bars (default) or custom arrow buttons.
     #[prop_or(MiniScrollMode::Native)]
     scroll_mode: MiniScrollMode,
+
+    /// Enable kinetic drag-to-scroll with the mouse (see [DomDragScroll]).
+    #[prop_or_default]
+    drag_scroll: bool,
 }
 
 impl MiniScroll {
@@ -51,6 +55,17 @@ impl MiniScroll {
     pub fn set_scroll_mode(&mut self, scroll_mode: MiniScrollMode) {
         self.scroll_mode = scroll_mode;
     }
+
+    /// Builder style method to enable drag-to-scroll.
+    pub fn drag_scroll(mut self, drag_scroll: bool) -> Self {
+        self.set_drag_scroll(drag_scroll);
+        self
+    }
+
+    /// Method to enable drag-to-scroll.
+    pub fn set_drag_scroll(&mut self, drag_scroll: bool) {
+        self.drag_scroll = drag_scroll;
+    }
 }
 
 #[doc(hidden)]
@@ -59,6 +74,7 @@ pub enum Msg {
     ContentResize(f64, f64),
     HandleResize(f64, f64),
     Wheel(f64),
+    ScrollChange,
     Scroll,
     ScrollStop,
     ScrollLeft,
@@ -86,6 +102,7 @@ pub struct PwtMiniScroll {
     pos: f64,
     scroll_mode: ScrollMode,
     scroll_timeout: Option<Timeout>,
+    drag_scroll: Option<DomDragScroll>,
 }
 
 impl PwtMiniScroll {
@@ -119,6 +136,7 @@ impl Component for PwtMiniScroll {
             pos: 0f64,
             scroll_mode: ScrollMode::None,
             scroll_timeout: None,
+            drag_scroll: None,
         }
     }
 
@@ -147,6 +165,27 @@ impl Component for PwtMiniScroll {
                 self.handle_width = width;
                 true
             }
+            Msg::ScrollChange => {
+                // sync position after native or drag scrolling
+                if !matches!(self.scroll_mode, ScrollMode::None) {
+                    return false;
+                }
+                let el = match self.scroll_ref.cast::<web_sys::Element>() {
+                    None => return false,
+                    Some(el) => el,
+                };
+                let diff = self.content_width - self.width;
+                let pos = if diff > 0.0 {
+                    (el.scroll_left() as f64 / diff).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                if self.pos == pos {
+                    return false;
+                }
+                self.pos = pos;
+                true
+            }
             Msg::Scroll => {
                 self.scroll_timeout = None;
 
@@ -229,13 +268,17 @@ impl Component for PwtMiniScroll {
                 "pwt-mini-scroll-content-native"
             })
             .with_child(content)
-            .onwheel({
-                let link = ctx.link().clone();
-                move |event: WheelEvent| {
-                    event.prevent_default();
-                    link.send_message(Msg::Wheel(event.delta_y()))
-                }
-            })
+            .onwheel_with_passive(
+                {
+                    let link = ctx.link().clone();
+                    move |event: WheelEvent| {
+                        event.prevent_default();
+                        link.send_message(Msg::Wheel(event.delta_y()))
+                    }
+                },
+                false,
+            )
+            .onscroll(ctx.link().callback(|_| Msg::ScrollChange))
             .into_html_with_ref(self.scroll_ref.clone());
 
         let arrow_visible = if arrow_mode {
@@ -298,5 +341,12 @@ impl Component for PwtMiniScroll {
                 self.handle_size_observer = Some(size_observer);
             }
         }
+
+        if ctx.props().drag_scroll != self.drag_scroll.is_some() {
+            self.drag_scroll = match self.scroll_ref.cast::<web_sys::HtmlElement>() {
+                Some(el) if ctx.props().drag_scroll => Some(DomDragScroll::new(&el)),
+                _ => None,
+            };
+        }
     }
 }
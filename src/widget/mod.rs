@@ -1,7 +1,14 @@
 //! # Basic widgets
 
+mod about_panel;
+pub use about_panel::AboutPanel;
+#[doc(hidden)]
+pub use about_panel::PwtAboutPanel;
+
 mod action_icon;
 pub use action_icon::ActionIcon;
+#[doc(hidden)]
+pub use action_icon::PwtActionIcon;
 
 mod alert_dialog;
 pub use alert_dialog::AlertDialog;
@@ -13,6 +20,11 @@ mod message_box;
 pub use message_box::PwtMessageBox;
 pub use message_box::{MessageBox, MessageBoxButtons};
 
+mod bootstrap;
+pub use bootstrap::Bootstrap;
+#[doc(hidden)]
+pub use bootstrap::PwtBootstrap;
+
 mod button;
 #[doc(hidden)]
 pub use button::PwtButton;
@@ -30,6 +42,11 @@ pub use catalog_loader::PwtCatalogLoader;
 
 pub mod charts;
 
+mod collapse;
+pub use collapse::Collapse;
+#[doc(hidden)]
+pub use collapse::PwtCollapse;
+
 mod column;
 pub use column::Column;
 
@@ -39,10 +56,21 @@ pub use container::Container;
 mod confirm_dialog;
 pub use confirm_dialog::ConfirmDialog;
 
+mod deferred_render;
+pub use deferred_render::DeferredRender;
+#[doc(hidden)]
+pub use deferred_render::PwtDeferredRender;
+
 mod desktop_app;
 pub use desktop_app::DesktopApp;
 #[doc(hidden)]
 pub use desktop_app::PwtDesktopApp;
+pub(crate) use desktop_app::set_document_title;
+
+mod disabled_container;
+#[doc(hidden)]
+pub use disabled_container::PwtDisabledContainer;
+pub use disabled_container::{DisabledContainer, DisabledContext};
 
 mod trigger;
 pub use trigger::Trigger;
@@ -52,14 +80,34 @@ mod dropdown;
 pub use dropdown::PwtDropdown;
 pub use dropdown::{Dropdown, DropdownController};
 
+mod edit_in_place;
+pub use edit_in_place::EditInPlace;
+#[doc(hidden)]
+pub use edit_in_place::PwtEditInPlace;
+
+mod error_report_dialog;
+#[doc(hidden)]
+pub use error_report_dialog::PwtErrorReportDialog;
+pub use error_report_dialog::{DiagnosticsReport, DiagnosticsReporter, ErrorReportDialog};
+
 mod fa;
-pub use fa::Fa;
+pub use fa::{Fa, FaStack, IconSet, register_icon_set, set_default_icon_set};
+
+mod fade;
+pub use fade::Fade;
+#[doc(hidden)]
+pub use fade::PwtFade;
 
 mod file_button;
 pub use file_button::FileButton;
 #[doc(hidden)]
 pub use file_button::PwtFileButton;
 
+mod find_bar;
+pub use find_bar::FindBar;
+#[doc(hidden)]
+pub use find_bar::PwtFindBar;
+
 pub mod form;
 
 mod field_label;
@@ -67,6 +115,11 @@ pub use field_label::FieldLabel;
 #[doc(hidden)]
 pub use field_label::PwtFieldLabel;
 
+mod help_button;
+pub use help_button::HelpButton;
+#[doc(hidden)]
+pub use help_button::PwtHelpButton;
+
 mod image;
 pub use image::Image;
 
@@ -82,10 +135,15 @@ pub use language_selector::LanguageSelector;
 pub use language_selector::PwtLanguageSelector;
 
 mod list;
-pub use list::{List, ListTile, ListTileObserver};
+pub use list::{List, ListTile, ListTileDensity, ListTileObserver};
+
+#[doc(hidden)]
+pub use list::{PwtList, PwtListTile, PwtListTileObserver};
 
+mod log_viewer;
+pub use log_viewer::LogViewer;
 #[doc(hidden)]
-pub use list::{PwtList, PwtListTileObserver};
+pub use log_viewer::PwtLogViewer;
 
 mod mask;
 pub use mask::Mask;
@@ -107,6 +165,11 @@ pub use meter::Meter;
 mod week_start;
 pub use week_start::WeekStart;
 
+mod secret_text;
+#[doc(hidden)]
+pub use secret_text::PwtSecretText;
+pub use secret_text::SecretText;
+
 mod segmented_bar;
 pub use segmented_bar::{Segment, SegmentedBar};
 
@@ -121,9 +184,14 @@ pub use split_pane::{Pane, SplitPane};
 pub mod data_table;
 
 mod dialog;
-pub use dialog::Dialog;
 #[doc(hidden)]
 pub use dialog::PwtDialog;
+pub use dialog::{Dialog, DialogCloseReason, DialogSize};
+
+mod dialog_manager;
+#[doc(hidden)]
+pub use dialog_manager::PwtDialogManager;
+pub use dialog_manager::{DialogContextExt, DialogController, DialogManager};
 
 mod panel;
 pub use panel::Panel;
@@ -134,7 +202,12 @@ pub use grid_picker::GridPicker;
 pub use grid_picker::PwtGridPicker;
 
 mod progress;
-pub use progress::Progress;
+pub use progress::{BarSegment, Progress};
+
+mod refresh_control;
+#[doc(hidden)]
+pub use refresh_control::PwtRefreshControl;
+pub use refresh_control::RefreshControl;
 
 mod row;
 pub use row::Row;
@@ -142,10 +215,27 @@ pub use row::Row;
 mod rtl_switcher;
 pub use rtl_switcher::RtlSwitcher;
 
+mod safe_destroy_dialog;
+#[doc(hidden)]
+pub use safe_destroy_dialog::PwtSafeDestroyDialog;
+pub use safe_destroy_dialog::{
+    SafeDestroyCallback, SafeDestroyDialog, SafeDestroyOption, SafeDestroyRequest,
+};
+
+mod scroll_box;
+#[doc(hidden)]
+pub use scroll_box::PwtScrollBox;
+pub use scroll_box::{ScrollBox, ScrollBoxController, ScrollBoxControllerMsg, ScrollDirection};
+
 mod search_dropdown;
 #[doc(hidden)]
+pub use search_dropdown::PwtFederatedSearchDropdown;
+#[doc(hidden)]
 pub use search_dropdown::PwtSearchDropdown;
-pub use search_dropdown::{FilteredLoadCallback, SearchDropdown, SearchDropdownRenderArgs};
+pub use search_dropdown::{
+    FederatedSearchDropdown, FilteredLoadCallback, SearchDropdown, SearchDropdownRenderArgs,
+    SearchSource, SearchSourceSelect,
+};
 
 mod selection_view;
 #[doc(hidden)]
@@ -162,15 +252,25 @@ mod size_observer;
 pub use size_observer::PwtSizeObserver;
 pub use size_observer::SizeObserver;
 
+mod sticky;
+#[doc(hidden)]
+pub use sticky::PwtSticky;
+pub use sticky::{Sticky, StickyPosition};
+
 mod tab;
 #[doc(hidden)]
 pub use tab::{PwtTabBar, PwtTabPanel};
 pub use tab::{TabBar, TabBarItem, TabBarStyle, TabPanel};
 
+mod task_progress_dialog;
+#[doc(hidden)]
+pub use task_progress_dialog::PwtTaskProgressDialog;
+pub use task_progress_dialog::{TaskProgressDialog, TaskStatus};
+
 mod theme_loader;
 #[doc(hidden)]
 pub use theme_loader::PwtThemeLoader;
-pub use theme_loader::ThemeLoader;
+pub use theme_loader::{CssBundle, ThemeLoader};
 
 mod theme_density_selector;
 #[doc(hidden)]
@@ -187,6 +287,16 @@ mod theme_name_selector;
 pub use theme_name_selector::PwtThemeNameSelector;
 pub use theme_name_selector::ThemeNameSelector;
 
+mod time_ago;
+#[doc(hidden)]
+pub use time_ago::PwtTimeAgo;
+pub use time_ago::TimeAgo;
+
+mod timeline;
+#[doc(hidden)]
+pub use timeline::PwtTimeline;
+pub use timeline::{Timeline, TimelineEntry};
+
 mod toolbar;
 #[doc(hidden)]
 pub use toolbar::PwtToolbar;
@@ -200,6 +310,11 @@ pub use tooltip::Tooltip;
 mod visibility_observer;
 pub use visibility_observer::VisibilityObserver;
 
+mod widget_inspector;
+#[doc(hidden)]
+pub use widget_inspector::PwtWidgetInspector;
+pub use widget_inspector::{WidgetInspector, inspector_enabled};
+
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 static UNIQUE_ELEMENT_ID: AtomicUsize = AtomicUsize::new(0);
@@ -12,6 +12,7 @@ use yew::virtual_dom::{Key, VNode};
 use pwt_macros::builder;
 
 use crate::props::{CssPaddingBuilder, IntoOptionalKey};
+use crate::state::{MissingCapPolicy, RequiredCaps};
 use crate::widget::Container;
 
 /// Navigation Menu item widget with optional icon and optional submenu.
@@ -60,6 +61,10 @@ pub struct MenuItem {
     #[prop_or_default]
     #[builder(IntoPropValue, into_prop_value)]
     pub trailing: Option<Html>,
+
+    /// Required capabilities (see [Capabilities](crate::state::Capabilities)).
+    #[prop_or_default]
+    pub required_caps: RequiredCaps,
 }
 
 impl MenuItem {
@@ -80,6 +85,32 @@ impl MenuItem {
     pub fn set_key(&mut self, key: impl IntoOptionalKey) {
         self.key = key.into_optional_key();
     }
+
+    /// Builder style method to add a required capability.
+    ///
+    /// The item is disabled (or hidden, see [Self::missing_cap_policy])
+    /// if the capability is not granted by the [Capabilities](crate::state::Capabilities) context.
+    /// Hidden items are removed together with their submenu.
+    pub fn require_cap(mut self, cap: impl Into<AttrValue>) -> Self {
+        self.add_required_cap(cap);
+        self
+    }
+
+    /// Method to add a required capability.
+    pub fn add_required_cap(&mut self, cap: impl Into<AttrValue>) {
+        self.required_caps.add(cap);
+    }
+
+    /// Builder style method to set the missing capability policy.
+    pub fn missing_cap_policy(mut self, policy: MissingCapPolicy) -> Self {
+        self.set_missing_cap_policy(policy);
+        self
+    }
+
+    /// Method to set the missing capability policy.
+    pub fn set_missing_cap_policy(&mut self, policy: MissingCapPolicy) {
+        self.required_caps.set_policy(policy);
+    }
 }
 
 /// Navigation Menu entries.
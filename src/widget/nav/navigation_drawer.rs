@@ -12,11 +12,11 @@ use crate::props::{
     AsClassesMut, AsCssStylesMut, ContainerBuilder, CssBorderBuilder, CssPaddingBuilder, CssStyles,
     EventSubscriber, IntoOptionalKey, IntoVTag, WidgetBuilder, WidgetStyleBuilder,
 };
-use crate::state::{NavigationContext, NavigationContextExt, Selection};
+use crate::state::{Capabilities, NavigationContext, NavigationContextExt, Selection};
 use crate::{impl_class_prop_builder, impl_yew_std_props_builder};
 
 use crate::dom::focus::roving_tabindex_next_recursive;
-use crate::widget::{Column, Container, Fa};
+use crate::widget::{Column, Container, Fa, Tooltip};
 
 use super::{Menu, MenuEntry, MenuItem};
 
@@ -152,6 +152,7 @@ pub enum Msg {
     MenuToggle(Key),
     MenuClose(Key),
     MenuOpen(Key),
+    CapabilitiesUpdate(Capabilities),
 }
 
 #[doc(hidden)]
@@ -161,6 +162,8 @@ pub struct PwtNavigationDrawer {
     selection: Selection,
     menu_states: HashMap<Key, bool>, // true = open
     _nav_ctx_handle: Option<ContextHandle<NavigationContext>>,
+    capabilities: Option<Capabilities>,
+    _capabilities_handle: Option<ContextHandle<Capabilities>>,
 }
 
 impl PwtNavigationDrawer {
@@ -172,11 +175,13 @@ impl PwtNavigationDrawer {
         indent_level: usize,
         open: bool, // submenu open ?
         hidden: bool,
+        disabled_tip: Option<AttrValue>, // disabled because of missing capabilities
     ) -> Html {
         let is_active = Some(active) == item.key.as_deref();
         let is_menu = item.submenu.is_some();
+        let disabled = disabled_tip.is_some();
 
-        let onclick = Callback::from({
+        let onclick = (!disabled).then_some({
             let link = ctx.link().clone();
             let key = item.key.clone();
             move |_event: MouseEvent| {
@@ -205,7 +210,7 @@ impl PwtNavigationDrawer {
                     None => return None,
                 };
                 match event.key().as_str() {
-                    " " => Some(Msg::Select(Some(key.clone()), true, true)),
+                    " " if !disabled => Some(Msg::Select(Some(key.clone()), true, true)),
                     "ArrowRight" if is_menu => Some(Msg::MenuOpen(key.clone())),
                     "ArrowLeft" if is_menu => Some(Msg::MenuClose(key.clone())),
                     _ => None,
@@ -219,8 +224,7 @@ impl PwtNavigationDrawer {
             None
         };
 
-        Container::from_tag("a")
-            .key(item.key.clone())
+        let nav_link = Container::from_tag("a")
             .attribute("role", "link")
             .attribute("aria-expanded", aria_expanded)
             .attribute(
@@ -230,6 +234,8 @@ impl PwtNavigationDrawer {
             .class("pwt-nav-link")
             .class(crate::css::AlignItems::Baseline)
             .class(is_active.then_some("active"))
+            .class(disabled.then_some("disabled"))
+            .attribute("aria-disabled", disabled.then_some("true"))
             .onclick(onclick)
             .onkeydown(onkeydown)
             // add indentation
@@ -254,8 +260,16 @@ impl PwtNavigationDrawer {
                     .class("pwt-nav-menu-item-arrow")
                     .class(open.then_some("expanded"))
                     .onclick(ontoggle)
-            }))
-            .into()
+            }));
+
+        match disabled_tip {
+            Some(tip) => Tooltip::new(nav_link)
+                .key(item.key.clone())
+                .attribute("role", "none")
+                .tip(tip)
+                .into(),
+            None => nav_link.key(item.key.clone()).into(),
+        }
     }
 
     fn render_menu_entry(
@@ -269,6 +283,11 @@ impl PwtNavigationDrawer {
     ) {
         match item {
             MenuEntry::Item(child) => {
+                let cap_check = child.required_caps.check(self.capabilities.as_ref());
+                if cap_check.hidden() {
+                    return;
+                }
+
                 // An absent state means the user has not toggled this submenu yet, so fall back
                 // to its default: open, unless the item opted into starting collapsed. A submenu
                 // on the path to the active item is force-opened by open_ancestors, so a
@@ -282,7 +301,15 @@ impl PwtNavigationDrawer {
                     None => false,
                 };
 
-                menu.add_child(self.render_single_item(ctx, child, active, level, open, hidden));
+                menu.add_child(self.render_single_item(
+                    ctx,
+                    child,
+                    active,
+                    level,
+                    open,
+                    hidden,
+                    cap_check.tip(),
+                ));
 
                 if let Some(submenu) = &child.submenu {
                     let mut items = Column::new().min_height(0);
@@ -325,15 +352,20 @@ impl PwtNavigationDrawer {
         desired: &Key,
     ) -> Option<&'a MenuEntry> {
         let props = ctx.props();
+        let caps = self.capabilities.clone();
 
-        fn find_first_key_recursive(menu: &[MenuEntry]) -> Option<&MenuEntry> {
+        fn find_first_key_recursive<'a>(
+            menu: &'a [MenuEntry],
+            caps: Option<&Capabilities>,
+        ) -> Option<&'a MenuEntry> {
             for menu in menu.iter() {
                 let res = match menu {
+                    MenuEntry::Item(item) if !item.required_caps.check(caps).granted() => None,
                     MenuEntry::Item(item) => match &item.submenu {
                         None => Some(menu),
                         Some(submenu) => {
                             if item.key.is_none() || !item.selectable {
-                                find_first_key_recursive(&submenu.children[..])
+                                find_first_key_recursive(&submenu.children[..], caps)
                             } else {
                                 Some(menu)
                             }
@@ -367,17 +399,20 @@ impl PwtNavigationDrawer {
         }
 
         match find_item_recursive(&props.menu.children, desired) {
-            Some(entry @ MenuEntry::Item(item)) => match &item.submenu {
-                None => item.selectable.then_some(entry),
-                Some(submenu) => {
-                    if item.selectable {
-                        Some(entry)
-                    } else {
-                        self.menu_states.insert(desired.clone(), true);
-                        find_first_key_recursive(&submenu.children)
+            Some(entry @ MenuEntry::Item(item)) => {
+                let granted = item.required_caps.check(caps.as_ref()).granted();
+                match &item.submenu {
+                    None => (item.selectable && granted).then_some(entry),
+                    Some(submenu) => {
+                        if item.selectable && granted {
+                            Some(entry)
+                        } else {
+                            self.menu_states.insert(desired.clone(), true);
+                            find_first_key_recursive(&submenu.children, caps.as_ref())
+                        }
                     }
                 }
-            },
+            }
             _ => None,
         }
     }
@@ -482,6 +517,11 @@ impl Component for PwtNavigationDrawer {
 
         let selection = Self::init_selection(ctx, props.selection.clone(), &active);
 
+        let (capabilities, _capabilities_handle) = ctx
+            .link()
+            .context::<Capabilities>(ctx.link().callback(Msg::CapabilitiesUpdate))
+            .unzip();
+
         if let Some(on_select) = &props.on_select {
             on_select.emit(active.clone());
         }
@@ -492,6 +532,8 @@ impl Component for PwtNavigationDrawer {
             selection,
             menu_states: HashMap::new(),
             _nav_ctx_handle,
+            capabilities,
+            _capabilities_handle,
         };
 
         // expand the path to the initially active item, so a deep-linked entry is visible
@@ -604,6 +646,10 @@ impl Component for PwtNavigationDrawer {
                 self.menu_states.insert(key, true);
                 true
             }
+            Msg::CapabilitiesUpdate(capabilities) => {
+                self.capabilities = Some(capabilities);
+                true
+            }
         }
     }
 
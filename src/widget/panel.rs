@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use yew::html::IntoPropValue;
 use yew::prelude::*;
 use yew::virtual_dom::{Listeners, VList, VNode, VTag};
 
@@ -6,7 +7,7 @@ use pwt_macros::widget;
 
 use crate::prelude::*;
 use crate::props::IntoOptionalInlineHtml;
-use crate::widget::Row;
+use crate::widget::{HelpButton, Row, Sticky};
 
 /// Container with header and body.
 ///
@@ -24,6 +25,9 @@ pub struct Panel {
     /// Optional header CSS class.
     #[prop_or_default]
     pub header_class: Classes,
+    /// Help ID for the online documentation (adds a [HelpButton] tool).
+    #[prop_or_default]
+    pub help_id: Option<AttrValue>,
 }
 
 impl Default for Panel {
@@ -49,6 +53,20 @@ impl Panel {
         self.title = title.into_optional_inline_html();
     }
 
+    /// Builder style method to set the help ID.
+    ///
+    /// Adds a [HelpButton] to the header tools, which opens the online
+    /// documentation (see [AppInfo::help_url](crate::state::AppInfo::help_url)).
+    pub fn help_id(mut self, help_id: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.set_help_id(help_id);
+        self
+    }
+
+    /// Method to set the help ID.
+    pub fn set_help_id(&mut self, help_id: impl IntoPropValue<Option<AttrValue>>) {
+        self.help_id = help_id.into_prop_value();
+    }
+
     /// Builder style method to add a tool.
     pub fn with_tool(mut self, tool: impl Into<VNode>) -> Self {
         self.add_tool(tool);
@@ -70,12 +88,42 @@ impl Panel {
     pub fn add_header_class(&mut self, class: impl Into<Classes>) {
         self.header_class.push(class);
     }
+
+    /// Builder style method to add a child pinned to the top of the scrolling ancestor.
+    ///
+    /// The child is wrapped into a [Sticky] widget.
+    pub fn with_sticky_child(mut self, child: impl Into<Html>) -> Self {
+        self.add_sticky_child(child);
+        self
+    }
+
+    /// Method to add a child pinned to the top of the scrolling ancestor.
+    pub fn add_sticky_child(&mut self, child: impl Into<Html>) {
+        self.add_child(Sticky::top(child));
+    }
+
+    /// Builder style method to add a child pinned to the bottom of the scrolling ancestor.
+    ///
+    /// The child is wrapped into a [Sticky] widget.
+    pub fn with_sticky_footer(mut self, child: impl Into<Html>) -> Self {
+        self.add_sticky_footer(child);
+        self
+    }
+
+    /// Method to add a child pinned to the bottom of the scrolling ancestor.
+    pub fn add_sticky_footer(&mut self, child: impl Into<Html>) {
+        self.add_child(Sticky::bottom(child));
+    }
 }
 
 impl IntoVTag for Panel {
     fn into_vtag_with_ref(mut self, node_ref: NodeRef) -> VTag {
         self.add_class("pwt-panel");
 
+        if let Some(help_id) = self.help_id.take() {
+            self.tools.push(HelpButton::new(help_id).into());
+        }
+
         if self.title.is_some() || !self.tools.is_empty() {
             let header = create_panel_title(self.title, self.tools)
                 .class("pwt-panel-header")
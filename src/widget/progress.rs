@@ -6,10 +6,88 @@ use yew::virtual_dom::VTag;
 
 use pwt_macros::{builder, widget};
 
-use crate::props::{CssLength, IntoVTag, WidgetBuilder, WidgetStyleBuilder};
+use crate::props::{ContainerBuilder, CssLength, IntoVTag, WidgetBuilder, WidgetStyleBuilder};
 use crate::widget::Container;
 
+/// A segment of a stacked [Progress] or [Meter](super::Meter) bar.
+///
+/// Segments are rendered one after another (e.g. used/reserved/free).
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct BarSegment {
+    /// The segment value (same unit as the bar value).
+    pub value: f32,
+    /// Segment color (any CSS color).
+    pub color: Option<AttrValue>,
+    /// Additional CSS class.
+    pub class: Classes,
+    /// Tooltip text.
+    pub tip: Option<AttrValue>,
+}
+
+impl BarSegment {
+    /// Create a new instance.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Builder style method to set the segment color.
+    pub fn color(mut self, color: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.color = color.into_prop_value();
+        self
+    }
+
+    /// Builder style method to add a CSS class.
+    pub fn class(mut self, class: impl Into<Classes>) -> Self {
+        self.class.push(class);
+        self
+    }
+
+    /// Builder style method to set the tooltip text.
+    pub fn tip(mut self, tip: impl IntoPropValue<Option<AttrValue>>) -> Self {
+        self.tip = tip.into_prop_value();
+        self
+    }
+}
+
+// Render stacked segments (range `min`..`max`).
+pub(crate) fn render_segments(segments: &[BarSegment], min: f32, max: f32) -> Html {
+    let range = max - min;
+    let mut remaining = 1.0;
+
+    let children = segments.iter().map(|segment| {
+        let fraction = if range > 0.0 {
+            (segment.value / range).clamp(0.0, remaining)
+        } else {
+            0.0
+        };
+        remaining -= fraction;
+
+        Container::new()
+            .class("pwt-bar-segment")
+            .class(segment.class.clone())
+            .style("height", "100%")
+            .style("background-color", segment.color.clone())
+            .width(CssLength::Fraction(fraction))
+            .attribute("title", segment.tip.clone())
+            .into()
+    });
+
+    Container::new()
+        .class("pwt-bar-segments")
+        .style("display", "flex")
+        .style("height", "100%")
+        .children(children)
+        .into()
+}
+
 /// Wrapper for Html `<progress>`.
+///
+/// Besides the simple bar, this supports multiple stacked
+/// [segments](Self::segments), a [buffer](Self::buffer) value (i.e. for
+/// buffered downloads) and a [striped](Self::striped) animation.
 #[widget(pwt=crate, @element)]
 #[derive(Default, Debug, Clone, PartialEq, Properties)]
 #[builder]
@@ -33,6 +111,24 @@ pub struct Progress {
     #[builder(IntoPropValue, into_prop_value)]
     #[prop_or_default]
     pub value: Option<f32>,
+
+    /// Secondary (buffered) value.
+    ///
+    /// Displayed as a lighter bar behind the value bar.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub buffer: Option<f32>,
+
+    /// Stacked segments (replaces the value bar if not empty).
+    #[prop_or_default]
+    pub segments: Vec<BarSegment>,
+
+    /// Use a striped animation.
+    ///
+    /// This is mostly useful for the indeterminate state.
+    #[builder]
+    #[prop_or_default]
+    pub striped: bool,
 }
 
 impl Progress {
@@ -40,29 +136,73 @@ impl Progress {
     pub fn new() -> Self {
         yew::props! { Self {}}
     }
+
+    /// Builder style method to add a segment.
+    pub fn with_segment(mut self, segment: BarSegment) -> Self {
+        self.add_segment(segment);
+        self
+    }
+
+    /// Method to add a segment.
+    pub fn add_segment(&mut self, segment: BarSegment) {
+        self.segments.push(segment);
+    }
+
+    /// Builder style method to set the segments.
+    pub fn segments(mut self, segments: impl IntoIterator<Item = BarSegment>) -> Self {
+        self.set_segments(segments);
+        self
+    }
+
+    /// Method to set the segments.
+    pub fn set_segments(&mut self, segments: impl IntoIterator<Item = BarSegment>) {
+        self.segments = segments.into_iter().collect();
+    }
 }
 
 impl IntoVTag for Progress {
     fn into_vtag_with_ref(self, node_ref: NodeRef) -> VTag {
         let max = self.max.unwrap_or(1.0);
 
-        let bar = match self.value {
-            Some(value) => {
-                let percentage = (value / max).clamp(0.0, 1.0);
+        let mut children = Vec::new();
+
+        if let Some(buffer) = self.buffer {
+            let percentage = (buffer / max).clamp(0.0, 1.0);
+            children.push(
                 Container::new()
-                    .class("pwt-progress-bar")
+                    .class("pwt-progress-buffer")
                     .width(CssLength::Fraction(percentage))
-                    .into()
+                    .into(),
+            );
+        }
+
+        let bar = if !self.segments.is_empty() {
+            render_segments(&self.segments, 0.0, max)
+        } else {
+            match self.value {
+                Some(value) => {
+                    let percentage = (value / max).clamp(0.0, 1.0);
+                    Container::new()
+                        .class("pwt-progress-bar")
+                        .width(CssLength::Fraction(percentage))
+                        .into()
+                }
+                None => Container::new().class("pwt-progress-infinite").into(),
             }
-            None => Container::new().class("pwt-progress-infinite").into(),
         };
+        children.push(bar);
+
+        let class = classes!(
+            "pwt-progress",
+            self.striped.then_some("pwt-progress-striped")
+        );
 
         self.std_props.into_vtag(
             Cow::Borrowed("div"),
             node_ref,
-            Some("pwt-progress"),
+            Some(class),
             None,
-            Some(vec![bar]),
+            Some(children),
         )
     }
 }
@@ -0,0 +1,215 @@
+use derivative::Derivative;
+use gloo_timers::callback::Timeout;
+use serde::{Serialize, de::DeserializeOwned};
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+
+use crate::css::{AlignItems, FontColor};
+use crate::prelude::*;
+use crate::state::{Loader, LoaderState, SharedStateObserver};
+use crate::widget::{Button, Fa, Row, TimeAgo, Tooltip};
+
+use pwt_macros::{builder, widget};
+
+/// Refresh controls for a [Loader].
+///
+/// Shows the time of the last successful load, a refresh button (spinning
+/// while loading) and an error badge if the last load failed (the error
+/// message is shown as tooltip).
+///
+/// If a [poll_interval](Self::poll_interval) is set, the loader is
+/// reloaded periodically (the interval starts when the previous load
+/// finished), and a pause/resume button is added.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::state::Loader;
+/// # use pwt::widget::{RefreshControl, Toolbar};
+/// # fn create_toolbar(loader: Loader<Vec<String>>) -> Toolbar {
+/// Toolbar::new()
+///     .with_flex_spacer()
+///     .with_child(RefreshControl::new(loader).poll_interval(5000))
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtRefreshControl<T>, @element)]
+#[derive(Properties, Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+#[builder]
+pub struct RefreshControl<T: 'static + DeserializeOwned + Serialize> {
+    /// The loader.
+    pub loader: Loader<T>,
+
+    /// Poll interval in milliseconds (no polling if not set).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub poll_interval: Option<u32>,
+
+    /// Start with paused polling.
+    #[builder]
+    #[prop_or_default]
+    pub paused: bool,
+
+    /// Called when polling gets paused (`true`) or resumed (`false`).
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    #[prop_or_default]
+    pub on_pause_change: Option<Callback<bool>>,
+}
+
+impl<T: 'static + DeserializeOwned + Serialize> RefreshControl<T> {
+    /// Create a new instance.
+    pub fn new(loader: Loader<T>) -> Self {
+        yew::props!(Self { loader })
+    }
+}
+
+pub enum Msg {
+    LoaderChange,
+    Poll,
+    Refresh,
+    TogglePause,
+}
+
+#[doc(hidden)]
+pub struct PwtRefreshControl<T: 'static> {
+    paused: bool,
+    poll_timeout: Option<Timeout>,
+    _loader_observer: SharedStateObserver<LoaderState<T>>,
+}
+
+impl<T: 'static + DeserializeOwned + Serialize> PwtRefreshControl<T> {
+    fn schedule_poll(&mut self, ctx: &Context<Self>) {
+        self.poll_timeout = None;
+
+        let props = ctx.props();
+        let Some(interval) = props.poll_interval else {
+            return;
+        };
+        // rescheduled after the running load finished
+        if self.paused || props.loader.loading() {
+            return;
+        }
+
+        let link = ctx.link().clone();
+        self.poll_timeout = Some(Timeout::new(interval, move || link.send_message(Msg::Poll)));
+    }
+}
+
+impl<T: 'static + DeserializeOwned + Serialize> Component for PwtRefreshControl<T> {
+    type Message = Msg;
+    type Properties = RefreshControl<T>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let _loader_observer = props
+            .loader
+            .add_listener(ctx.link().callback(|_| Msg::LoaderChange));
+
+        let mut me = Self {
+            paused: props.paused,
+            poll_timeout: None,
+            _loader_observer,
+        };
+        me.schedule_poll(ctx);
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::LoaderChange => {
+                self.schedule_poll(ctx);
+                true
+            }
+            Msg::Poll => {
+                self.poll_timeout = None;
+                if self.paused || props.loader.loading() {
+                    return false;
+                }
+                props.loader.load();
+                true
+            }
+            Msg::Refresh => {
+                self.poll_timeout = None;
+                props.loader.load();
+                true
+            }
+            Msg::TogglePause => {
+                self.paused = !self.paused;
+                if let Some(on_pause_change) = &props.on_pause_change {
+                    on_pause_change.emit(self.paused);
+                }
+                self.schedule_poll(ctx);
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.loader != old_props.loader {
+            self._loader_observer = props
+                .loader
+                .add_listener(ctx.link().callback(|_| Msg::LoaderChange));
+        }
+        if props.paused != old_props.paused {
+            self.paused = props.paused;
+        }
+        if props.loader != old_props.loader
+            || props.poll_interval != old_props.poll_interval
+            || props.paused != old_props.paused
+        {
+            self.schedule_poll(ctx);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let loading = props.loader.loading();
+
+        let error = match &props.loader.read().data {
+            Some(Err(err)) => Some(err.to_string()),
+            _ => None,
+        };
+
+        let error_badge = error.map(|err| {
+            Tooltip::new(
+                Fa::new("exclamation-triangle")
+                    .class(FontColor::Error)
+                    .attribute("aria-label", tr!("Load failed")),
+            )
+            .tip(err)
+        });
+
+        let last_update = props.loader.last_update().map(|timestamp| {
+            Row::new()
+                .gap(1)
+                .with_child(tr!("Last update:"))
+                .with_child(TimeAgo::new(timestamp))
+        });
+
+        let pause_button = props.poll_interval.is_some().then(|| {
+            let (icon_class, label) = if self.paused {
+                ("fa fa-fw fa-play", tr!("Resume"))
+            } else {
+                ("fa fa-fw fa-pause", tr!("Pause"))
+            };
+            Button::new_icon(icon_class)
+                .aria_label(label)
+                .on_activate(link.callback(|_| Msg::TogglePause))
+        });
+
+        Row::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-refresh-control")
+            .class(AlignItems::Center)
+            .gap(1)
+            .with_optional_child(error_badge)
+            .with_optional_child(last_update)
+            .with_optional_child(pause_button)
+            .with_child(Button::refresh(loading).on_activate(link.callback(|_| Msg::Refresh)))
+            .into()
+    }
+}
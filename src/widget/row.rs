@@ -1,11 +1,8 @@
-use std::borrow::Cow;
-
 use yew::prelude::*;
-use yew::virtual_dom::{Listeners, VList, VTag};
 
 use pwt_macros::widget;
 
-use crate::css::Display;
+use crate::css::{Display, Gap};
 use crate::prelude::*;
 use crate::props::{AsClassesMut, AsCssStylesMut, PwtSpace};
 
@@ -27,7 +24,7 @@ use crate::props::{AsClassesMut, AsCssStylesMut, PwtSpace};
 ///   .with_child(Button::new("Button3"))
 /// # ;
 /// ```
-#[widget(pwt=crate, @element, @container)]
+#[widget(pwt=crate, tag="div", @element, @container)]
 #[derive(Debug, Clone, PartialEq, Properties)]
 pub struct Row {}
 
@@ -56,7 +53,7 @@ impl Row {
         match gap.into() {
             PwtSpace::None => {}
             PwtSpace::Pwt(factor) if factor <= 4 => {
-                self.as_classes_mut().push(format!("pwt-gap-{}", factor));
+                self.as_classes_mut().push(Gap(factor));
             }
             space => self.as_css_styles_mut().set_style("gap", space.to_string()),
         }
@@ -75,22 +72,3 @@ impl Row {
         self.add_child(html! {<div class="pwt-flex-fill"/>});
     }
 }
-
-impl IntoVTag for Row {
-    fn into_vtag_with_ref(self, node_ref: NodeRef) -> VTag {
-        let attributes = self.std_props.cumulate_attributes(None::<&str>);
-
-        let listeners = Listeners::Pending(self.listeners.listeners.into_boxed_slice());
-
-        let children = VList::with_children(self.children, None);
-
-        VTag::__new_other(
-            Cow::Borrowed("div"),
-            node_ref,
-            self.std_props.key,
-            attributes,
-            listeners,
-            children.into(),
-        )
-    }
-}
@@ -0,0 +1,374 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use anyhow::Error;
+use derivative::Derivative;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::AsyncAbortGuard;
+use crate::css::{AlignItems, ColorScheme, FontColor};
+use crate::prelude::*;
+use crate::props::IntoOptionalKey;
+use crate::widget::form::{Checkbox, Field};
+use crate::widget::{Button, Column, Container, Dialog, Progress, Row, Toolbar, error_message};
+
+use pwt_macros::builder;
+
+/// Related resource checkbox for the [SafeDestroyDialog] (i.e. "Remove
+/// from backup jobs").
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafeDestroyOption {
+    /// Option name (key in [SafeDestroyRequest::options]).
+    pub name: AttrValue,
+    /// Checkbox label.
+    pub label: AttrValue,
+    /// Initial checkbox state.
+    pub checked: bool,
+}
+
+impl SafeDestroyOption {
+    /// Create a new instance (unchecked).
+    pub fn new(name: impl Into<AttrValue>, label: impl Into<AttrValue>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            checked: false,
+        }
+    }
+
+    /// Builder style method to set the initial checkbox state.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+}
+
+/// Parameters passed to the [SafeDestroyDialog] destroy callback.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SafeDestroyRequest {
+    /// Checkbox state of all [SafeDestroyOption]s.
+    pub options: BTreeMap<String, bool>,
+}
+
+impl SafeDestroyRequest {
+    /// Returns true if the option is checked.
+    pub fn option(&self, name: &str) -> bool {
+        self.options.get(name).copied().unwrap_or(false)
+    }
+}
+
+/// Async destroy callback, returns an optional task ID.
+#[derive(Derivative)]
+#[derivative(Clone, PartialEq)]
+pub struct SafeDestroyCallback(
+    #[allow(clippy::type_complexity)]
+    #[derivative(PartialEq(compare_with = "Rc::ptr_eq"))]
+    Rc<dyn Fn(SafeDestroyRequest) -> Pin<Box<dyn Future<Output = Result<Option<String>, Error>>>>>,
+);
+
+impl SafeDestroyCallback {
+    pub fn new<F, R>(callback: F) -> Self
+    where
+        F: 'static + Fn(SafeDestroyRequest) -> R,
+        R: 'static + Future<Output = Result<Option<String>, Error>>,
+    {
+        Self(Rc::new(move |request| Box::pin(callback(request))))
+    }
+
+    pub async fn apply(&self, request: SafeDestroyRequest) -> Result<Option<String>, Error> {
+        (self.0)(request).await
+    }
+}
+
+impl<F, R> From<F> for SafeDestroyCallback
+where
+    F: 'static + Fn(SafeDestroyRequest) -> R,
+    R: 'static + Future<Output = Result<Option<String>, Error>>,
+{
+    fn from(callback: F) -> Self {
+        Self::new(callback)
+    }
+}
+
+/// Confirmation dialog for destructive actions.
+///
+/// The user needs to type the resource name (i.e. the VM ID) to enable
+/// the "Remove" button. Optional checkboxes allow to remove related
+/// resources. The [destroy](Self::destroy) callback runs asynchronously
+/// (errors are shown inside the dialog), and may return a task ID, which is
+/// passed to [on_task](Self::on_task) to show the task progress.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{SafeDestroyDialog, SafeDestroyOption, SafeDestroyRequest};
+/// # async fn destroy_vm(vmid: u32, purge: bool) -> Result<String, anyhow::Error> { todo!() }
+/// # fn create_dialog(vmid: u32) -> SafeDestroyDialog {
+/// SafeDestroyDialog::new(vmid.to_string(), move |request: SafeDestroyRequest| async move {
+///     let upid = destroy_vm(vmid, request.option("purge")).await?;
+///     Ok(Some(upid))
+/// })
+/// .with_option(SafeDestroyOption::new("purge", tr!("Purge from job configurations")).checked(true))
+/// .on_task(|upid: String| { /* show a TaskProgressDialog */ })
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct SafeDestroyDialog {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// Dialog title.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::from(tr!("Confirm")))]
+    pub title: AttrValue,
+
+    /// The resource name the user needs to type.
+    pub name: AttrValue,
+
+    /// Warning message (default: generic "cannot be undone" message).
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub message: Option<Html>,
+
+    /// Related resource checkboxes.
+    #[prop_or_default]
+    pub options: Vec<SafeDestroyOption>,
+
+    /// The destroy callback.
+    pub destroy: SafeDestroyCallback,
+
+    /// Called with the task ID returned by the destroy callback.
+    #[builder_cb(IntoEventCallback, into_event_callback, String)]
+    #[prop_or_default]
+    pub on_task: Option<Callback<String>>,
+
+    /// Called when the destroy callback was successful.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_success: Option<Callback<()>>,
+
+    /// Called when the dialog gets closed (also after success).
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_close: Option<Callback<()>>,
+}
+
+impl SafeDestroyDialog {
+    /// Create a new instance.
+    pub fn new(name: impl Into<AttrValue>, destroy: impl Into<SafeDestroyCallback>) -> Self {
+        yew::props!(Self {
+            name: name.into(),
+            destroy: destroy.into(),
+        })
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+
+    /// Builder style method to add a related resource checkbox.
+    pub fn with_option(mut self, option: SafeDestroyOption) -> Self {
+        self.add_option(option);
+        self
+    }
+
+    /// Method to add a related resource checkbox.
+    pub fn add_option(&mut self, option: SafeDestroyOption) {
+        self.options.push(option);
+    }
+}
+
+pub enum Msg {
+    Input(String),
+    Option(AttrValue, bool),
+    Destroy,
+    DestroyResult(Result<Option<String>, Error>),
+    Close,
+}
+
+#[doc(hidden)]
+pub struct PwtSafeDestroyDialog {
+    confirm_text: String,
+    options: BTreeMap<String, bool>,
+    running: bool,
+    error: Option<String>,
+    destroy_guard: Option<AsyncAbortGuard>,
+}
+
+impl PwtSafeDestroyDialog {
+    fn confirmed(&self, props: &SafeDestroyDialog) -> bool {
+        self.confirm_text.trim() == props.name.as_str()
+    }
+}
+
+impl Component for PwtSafeDestroyDialog {
+    type Message = Msg;
+    type Properties = SafeDestroyDialog;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let options = ctx
+            .props()
+            .options
+            .iter()
+            .map(|option| (option.name.to_string(), option.checked))
+            .collect();
+
+        Self {
+            confirm_text: String::new(),
+            options,
+            running: false,
+            error: None,
+            destroy_guard: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Input(text) => {
+                self.confirm_text = text;
+                true
+            }
+            Msg::Option(name, checked) => {
+                self.options.insert(name.to_string(), checked);
+                true
+            }
+            Msg::Destroy => {
+                if self.running || !self.confirmed(props) {
+                    return false;
+                }
+                self.running = true;
+                self.error = None;
+
+                let request = SafeDestroyRequest {
+                    options: self.options.clone(),
+                };
+                let destroy = props.destroy.clone();
+                let link = ctx.link().clone();
+                self.destroy_guard = Some(AsyncAbortGuard::spawn(async move {
+                    let result = destroy.apply(request).await;
+                    link.send_message(Msg::DestroyResult(result));
+                }));
+                true
+            }
+            Msg::DestroyResult(result) => {
+                self.running = false;
+                self.destroy_guard = None;
+                match result {
+                    Ok(task_id) => {
+                        if let Some(on_success) = &props.on_success {
+                            on_success.emit(());
+                        }
+                        if let (Some(task_id), Some(on_task)) = (task_id, &props.on_task) {
+                            on_task.emit(task_id);
+                        }
+                        ctx.link().send_message(Msg::Close);
+                    }
+                    Err(err) => self.error = Some(err.to_string()),
+                }
+                true
+            }
+            Msg::Close => {
+                // closing aborts a running request
+                self.destroy_guard = None;
+                if let Some(on_close) = &props.on_close {
+                    on_close.emit(());
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let message = props.message.clone().unwrap_or_else(|| {
+            html! {tr!("This will permanently delete '{0}'. This action cannot be undone.", props.name)}
+        });
+
+        let warning = Row::new()
+            .gap(2)
+            .class(AlignItems::Center)
+            .with_child(
+                Container::from_tag("i")
+                    .class("fa fa-2x fa-exclamation-triangle")
+                    .class(FontColor::Error)
+                    .attribute("aria-hidden", "true"),
+            )
+            .with_child(Container::new().class("pwt-flex-fill").with_child(message));
+
+        let confirm_field = Column::new()
+            .gap(1)
+            .with_child(tr!("Please enter '{0}' to confirm", props.name))
+            .with_child(
+                Field::new()
+                    .value(self.confirm_text.clone())
+                    .autofocus(true)
+                    .disabled(self.running)
+                    .aria_label(tr!("Confirmation"))
+                    .on_input(link.callback(Msg::Input))
+                    .onkeydown(link.batch_callback(|event: KeyboardEvent| {
+                        (event.key() == "Enter").then_some(Msg::Destroy)
+                    })),
+            );
+
+        let options = props.options.iter().map(|option| {
+            let name = option.name.clone();
+            let checked = self
+                .options
+                .get(option.name.as_str())
+                .copied()
+                .unwrap_or(option.checked);
+            Checkbox::new()
+                .box_label(option.label.clone())
+                .checked(checked)
+                .disabled(self.running)
+                .on_input(link.callback(move |checked| Msg::Option(name.clone(), checked)))
+                .into()
+        });
+
+        let content = Column::new()
+            .class("pwt-safe-destroy-dialog")
+            .padding(2)
+            .gap(2)
+            .with_child(warning)
+            .with_child(confirm_field)
+            .children(options)
+            .with_optional_child(self.running.then(|| Progress::new().striped(true)))
+            .with_optional_child(self.error.as_deref().map(error_message));
+
+        let toolbar = Toolbar::new()
+            .with_flex_spacer()
+            .with_child(Button::new(tr!("Cancel")).on_activate(link.callback(|_| Msg::Close)))
+            .with_child(
+                Button::new(tr!("Remove"))
+                    .class("pwt-button-filled")
+                    .class(ColorScheme::Error)
+                    .icon_class("fa fa-trash-o")
+                    .disabled(self.running || !self.confirmed(props))
+                    .on_activate(link.callback(|_| Msg::Destroy)),
+            );
+
+        Dialog::new(props.title.clone())
+            .on_close(link.callback(|_| Msg::Close))
+            .with_child(content)
+            .with_child(toolbar)
+            .into()
+    }
+}
+
+impl From<SafeDestroyDialog> for VNode {
+    fn from(val: SafeDestroyDialog) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtSafeDestroyDialog>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
@@ -0,0 +1,312 @@
+use yew::html::IntoEventCallback;
+
+use crate::css::{OverflowX, OverflowY};
+use crate::dom::DomSizeObserver;
+use crate::prelude::*;
+use crate::state::{SharedState, SharedStateObserver};
+use crate::widget::Container;
+
+use pwt_macros::{builder, widget};
+
+/// Scroll direction for [ScrollBox].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum ScrollDirection {
+    /// Vertical scrolling (default).
+    #[default]
+    Vertical,
+    /// Horizontal scrolling.
+    Horizontal,
+    /// Scroll in both directions.
+    Both,
+}
+
+/// Messages sent from the [ScrollBoxController] to the [ScrollBox].
+pub enum ScrollBoxControllerMsg {
+    ScrollTo(f64),
+    ScrollBy(f64),
+    ScrollToTop,
+    ScrollToBottom,
+}
+
+/// Scroll box controller, used to scroll the content programmatically.
+#[derive(Clone, PartialEq)]
+pub struct ScrollBoxController {
+    state: SharedState<Vec<ScrollBoxControllerMsg>>,
+}
+
+impl Default for ScrollBoxController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScrollBoxController {
+    pub fn new() -> Self {
+        Self {
+            state: SharedState::new(Vec::new()),
+        }
+    }
+
+    /// Scroll to the vertical position `top` (in pixels).
+    pub fn scroll_to(&self, top: f64) {
+        self.state
+            .write()
+            .push(ScrollBoxControllerMsg::ScrollTo(top));
+    }
+
+    /// Scroll vertically by `delta` pixels.
+    pub fn scroll_by(&self, delta: f64) {
+        self.state
+            .write()
+            .push(ScrollBoxControllerMsg::ScrollBy(delta));
+    }
+
+    /// Scroll to the top.
+    pub fn scroll_to_top(&self) {
+        self.state.write().push(ScrollBoxControllerMsg::ScrollToTop);
+    }
+
+    /// Scroll to the bottom.
+    pub fn scroll_to_bottom(&self) {
+        self.state
+            .write()
+            .push(ScrollBoxControllerMsg::ScrollToBottom);
+    }
+}
+
+/// Scroll container with consistent overflow handling.
+///
+/// - optional thin, themed scrollbars.
+/// - top/bottom scroll shadows to indicate hidden content.
+/// - `on_scroll_end` event when the user scrolls to the bottom.
+/// - programmatic scrolling using a [ScrollBoxController].
+#[widget(pwt=crate, comp=PwtScrollBox, @element, @container)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct ScrollBox {
+    /// Scroll direction.
+    #[prop_or_default]
+    #[builder]
+    pub direction: ScrollDirection,
+
+    /// Use thin, themed scrollbars.
+    #[prop_or_default]
+    #[builder]
+    pub thin_scrollbars: bool,
+
+    /// Show top/bottom shadows if the content overflows (default `true`).
+    #[prop_or(true)]
+    #[builder]
+    pub scroll_shadows: bool,
+
+    /// Distance from the bottom (in pixels) which triggers `on_scroll_end`.
+    #[prop_or(1.0)]
+    #[builder]
+    pub scroll_end_threshold: f64,
+
+    /// Called when the user scrolls to the bottom of the content.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_scroll_end: Option<Callback<()>>,
+
+    /// Optional scroll box controller.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or_default]
+    pub controller: Option<ScrollBoxController>,
+}
+
+impl Default for ScrollBox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScrollBox {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+}
+
+pub enum Msg {
+    Controller,
+    Scroll,
+    Resize,
+}
+
+#[doc(hidden)]
+pub struct PwtScrollBox {
+    scroll_ref: NodeRef,
+    content_ref: NodeRef,
+    scroll_size_observer: Option<DomSizeObserver>,
+    content_size_observer: Option<DomSizeObserver>,
+    controller_observer: Option<SharedStateObserver<Vec<ScrollBoxControllerMsg>>>,
+    shadow_top: bool,
+    shadow_bottom: bool,
+    at_end: bool,
+}
+
+impl PwtScrollBox {
+    fn observe_controller(&mut self, ctx: &Context<Self>) {
+        self.controller_observer = ctx.props().controller.as_ref().map(|controller| {
+            controller
+                .state
+                .add_listener(ctx.link().callback(|_| Msg::Controller))
+        });
+    }
+
+    fn handle_controller_messages(&mut self, ctx: &Context<Self>) {
+        let controller = match &ctx.props().controller {
+            Some(controller) => controller,
+            None => return,
+        };
+        if controller.state.read().is_empty() {
+            return; // Note: avoid endless loop
+        }
+        let list = controller.state.write().split_off(0);
+
+        let el = match self.scroll_ref.cast::<web_sys::Element>() {
+            Some(el) => el,
+            None => return,
+        };
+
+        for msg in list {
+            let top = match msg {
+                ScrollBoxControllerMsg::ScrollTo(top) => top,
+                ScrollBoxControllerMsg::ScrollBy(delta) => el.scroll_top() as f64 + delta,
+                ScrollBoxControllerMsg::ScrollToTop => 0.0,
+                ScrollBoxControllerMsg::ScrollToBottom => el.scroll_height() as f64,
+            };
+            el.set_scroll_top(top.round() as i32);
+        }
+    }
+
+    // update shadow state, returns true if something changed
+    fn update_scroll_state(&mut self, ctx: &Context<Self>) -> bool {
+        let props = ctx.props();
+        let el = match self.scroll_ref.cast::<web_sys::Element>() {
+            Some(el) => el,
+            None => return false,
+        };
+
+        let scroll_top = el.scroll_top() as f64;
+        let hidden_bottom = el.scroll_height() as f64 - el.client_height() as f64 - scroll_top;
+
+        let at_end = hidden_bottom <= props.scroll_end_threshold;
+        if at_end && !self.at_end && scroll_top > 0.0 {
+            if let Some(on_scroll_end) = &props.on_scroll_end {
+                on_scroll_end.emit(());
+            }
+        }
+        self.at_end = at_end;
+
+        let shadow_top = scroll_top > 0.0;
+        let shadow_bottom = hidden_bottom > 0.0;
+
+        let changed = shadow_top != self.shadow_top || shadow_bottom != self.shadow_bottom;
+        self.shadow_top = shadow_top;
+        self.shadow_bottom = shadow_bottom;
+        changed
+    }
+}
+
+impl Component for PwtScrollBox {
+    type Message = Msg;
+    type Properties = ScrollBox;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut me = Self {
+            scroll_ref: NodeRef::default(),
+            content_ref: NodeRef::default(),
+            scroll_size_observer: None,
+            content_size_observer: None,
+            controller_observer: None,
+            shadow_top: false,
+            shadow_bottom: false,
+            at_end: false,
+        };
+        me.observe_controller(ctx);
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Controller => {
+                self.handle_controller_messages(ctx);
+                false
+            }
+            Msg::Scroll | Msg::Resize => self.update_scroll_state(ctx),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().controller != old_props.controller {
+            self.observe_controller(ctx);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let (overflow_x, overflow_y) = match props.direction {
+            ScrollDirection::Vertical => (OverflowX::Hidden, OverflowY::Auto),
+            ScrollDirection::Horizontal => (OverflowX::Auto, OverflowY::Hidden),
+            ScrollDirection::Both => (OverflowX::Auto, OverflowY::Auto),
+        };
+
+        let content = Container::new()
+            .class("pwt-scroll-box-content")
+            .children(props.children.clone())
+            .into_html_with_ref(self.content_ref.clone());
+
+        let scroll = Container::new()
+            .class("pwt-scroll-box-viewport")
+            .class(props.thin_scrollbars.then_some("pwt-thin-scrollbars"))
+            .class(overflow_x)
+            .class(overflow_y)
+            .with_child(content)
+            .onscroll(ctx.link().callback(|_| Msg::Scroll))
+            .into_html_with_ref(self.scroll_ref.clone());
+
+        let shadows = props.scroll_shadows;
+
+        Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-scroll-box")
+            .with_child(scroll)
+            .with_optional_child(shadows.then(|| {
+                Container::new()
+                    .class("pwt-scroll-box-shadow top")
+                    .class(self.shadow_top.then_some("visible"))
+            }))
+            .with_optional_child(shadows.then(|| {
+                Container::new()
+                    .class("pwt-scroll-box-shadow bottom")
+                    .class(self.shadow_bottom.then_some("visible"))
+            }))
+            .into()
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(el) = self.scroll_ref.cast::<web_sys::Element>() {
+                let link = ctx.link().clone();
+                let size_observer = DomSizeObserver::new(&el, move |(_, _)| {
+                    link.send_message(Msg::Resize);
+                });
+                self.scroll_size_observer = Some(size_observer);
+            }
+            if let Some(el) = self.content_ref.cast::<web_sys::Element>() {
+                let link = ctx.link().clone();
+                let size_observer = DomSizeObserver::new(&el, move |(_, _)| {
+                    link.send_message(Msg::Resize);
+                });
+                self.content_size_observer = Some(size_observer);
+            }
+            // process messages sent before the first render
+            self.handle_controller_messages(ctx);
+        }
+    }
+}
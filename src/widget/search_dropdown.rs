@@ -6,15 +6,16 @@ use anyhow::Error;
 use derivative::Derivative;
 
 use gloo_timers::callback::Timeout;
-use yew::html::{IntoEventCallback, Scope};
+use wasm_bindgen::JsCast;
+use yew::html::{IntoEventCallback, IntoPropValue, Scope};
 use yew::virtual_dom::Key;
 
-use crate::props::{CssLength, FieldBuilder, RenderFn, WidgetBuilder};
+use crate::props::{CssLength, ExtractPrimaryKey, FieldBuilder, RenderFn, WidgetBuilder};
 use crate::state::DataStore;
 use crate::widget::data_table::{
     DataTable, DataTableColumn, DataTableHeader, DataTableKeyboardEvent, DataTableMouseEvent,
 };
-use crate::widget::{Dropdown, DropdownController};
+use crate::widget::{Button, Column, Container, Dropdown, DropdownController, Row};
 use crate::{AsyncAbortGuard, prelude::*};
 
 use pwt_macros::{builder, widget};
@@ -314,3 +315,431 @@ impl<S: DataStore + 'static> Component for PwtSearchDropdown<S> {
         .into()
     }
 }
+
+/// A single search result, as displayed by the [FederatedSearchDropdown].
+#[derive(Clone, PartialEq)]
+pub struct SearchResult {
+    key: Key,
+    html: Html,
+}
+
+/// Search source for the [FederatedSearchDropdown].
+///
+/// Each source has its own async loader, section title and record renderer.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), PartialEq(bound = ""))]
+pub struct SearchSource {
+    title: AttrValue,
+    #[derivative(PartialEq = "ignore")] // never trigger reload
+    #[allow(clippy::type_complexity)]
+    loader: Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<Vec<SearchResult>, Error>>>>>,
+    limit: Option<usize>,
+}
+
+impl SearchSource {
+    /// Create a new instance.
+    ///
+    /// The `loader` gets called with the current filter text, and
+    /// `render` is used to display the returned records.
+    pub fn new<T, Fut, F>(
+        title: impl Into<AttrValue>,
+        loader: F,
+        render: impl Into<RenderFn<T>>,
+    ) -> Self
+    where
+        T: ExtractPrimaryKey + 'static,
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Result<Vec<T>, Error>> + 'static,
+    {
+        let render = render.into();
+        let loader = Rc::new(loader);
+        Self {
+            title: title.into(),
+            loader: Rc::new(move |filter| {
+                let loader = Rc::clone(&loader);
+                let render = render.clone();
+                Box::pin(async move {
+                    let list = loader(filter).await?;
+                    Ok(list
+                        .iter()
+                        .map(|record| SearchResult {
+                            key: record.extract_key(),
+                            html: render.apply(record),
+                        })
+                        .collect())
+                })
+            }),
+            limit: None,
+        }
+    }
+
+    /// Builder style method to set the result limit.
+    ///
+    /// Overwrites the [FederatedSearchDropdown::source_limit] for this source.
+    pub fn limit(mut self, limit: impl IntoPropValue<Option<usize>>) -> Self {
+        self.set_limit(limit);
+        self
+    }
+
+    /// Method to set the result limit.
+    pub fn set_limit(&mut self, limit: impl IntoPropValue<Option<usize>>) {
+        self.limit = limit.into_prop_value();
+    }
+}
+
+/// Selection event emitted by the [FederatedSearchDropdown].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchSourceSelect {
+    /// Source index.
+    pub source: usize,
+    /// The record key.
+    pub key: Key,
+}
+
+/// Text box which presents search results from multiple sources in the dropdown.
+///
+/// Like [SearchDropdown], but queries several [SearchSource]s in
+/// parallel. The results are shown in separate sections, each with its
+/// own loading and error state. Only the first `limit` results of a
+/// source are shown until the user clicks "Show more".
+///
+/// # Note
+///
+/// This widget does not interact with a form context.
+#[widget(pwt=crate, comp=PwtFederatedSearchDropdown, @input)]
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct FederatedSearchDropdown {
+    /// Select callback, emitted when the user selects something from the picker.
+    #[builder_cb(IntoEventCallback, into_event_callback, SearchSourceSelect)]
+    #[prop_or_default]
+    pub on_select: Option<Callback<SearchSourceSelect>>,
+
+    /// The search sources.
+    sources: Vec<SearchSource>,
+
+    /// Default number of results shown per source (default 5).
+    #[builder]
+    #[prop_or(5)]
+    pub source_limit: usize,
+}
+
+impl FederatedSearchDropdown {
+    /// Create a new instance.
+    pub fn new(sources: impl IntoIterator<Item = SearchSource>) -> Self {
+        yew::props!(Self {
+            sources: sources.into_iter().collect::<Vec<_>>(),
+        })
+    }
+}
+
+#[derive(Clone, Default, PartialEq)]
+struct SourceState {
+    loading: bool,
+    error: Option<String>,
+    results: Vec<SearchResult>,
+    show_all: bool,
+}
+
+pub enum FederatedMsg {
+    UpdateFilter(String),
+    Load,
+    LoadResult(usize, Result<Vec<SearchResult>, Error>),
+    ShowMore(usize),
+    Select(usize, Key),
+}
+
+#[doc(hidden)]
+pub struct PwtFederatedSearchDropdown {
+    filter: String,
+    load_timeout: Option<Timeout>,
+    load_abort_guards: Vec<AsyncAbortGuard>,
+    states: Vec<SourceState>,
+}
+
+// Number of results to show (limited, unless the user requested all).
+fn shown_results(total: usize, limit: usize, show_all: bool) -> usize {
+    if show_all { total } else { total.min(limit) }
+}
+
+fn render_source_section(
+    link: &Scope<PwtFederatedSearchDropdown>,
+    controller: &DropdownController,
+    index: usize,
+    source: &SearchSource,
+    state: &SourceState,
+    source_limit: usize,
+) -> Html {
+    let limit = source.limit.unwrap_or(source_limit);
+    let shown = shown_results(state.results.len(), limit, state.show_all);
+
+    let loading_icon = state
+        .loading
+        .then(|| html! {<i class="fa fa-spinner fa-pulse" aria-label={tr!("Loading...")}/>});
+
+    let header = Row::new()
+        .class("pwt-search-source-title")
+        .class(crate::css::AlignItems::Center)
+        .gap(2)
+        .padding(2)
+        .with_child(html! {<b>{source.title.clone()}</b>})
+        .with_optional_child(loading_icon);
+
+    let items = state.results[..shown].iter().map(|result| {
+        let link = link.clone();
+        let controller = controller.clone();
+        let key = result.key.clone();
+        let select = move || {
+            link.send_message(FederatedMsg::Select(index, key.clone()));
+            controller.change_value(String::new()); // close dropdown, clear filter
+        };
+        Container::new()
+            .key(result.key.clone())
+            .class("pwt-search-result")
+            .class("pwt-pointer")
+            .padding_x(2)
+            .padding_y(1)
+            .attribute("role", "option")
+            .attribute("tabindex", "-1")
+            .onclick({
+                let select = select.clone();
+                move |_| select()
+            })
+            .onkeydown(move |event: KeyboardEvent| {
+                if let " " | "Enter" = event.key().as_str() {
+                    event.prevent_default();
+                    select();
+                }
+            })
+            .with_child(result.html.clone())
+            .into()
+    });
+
+    let more = (shown < state.results.len()).then(|| {
+        let remaining = state.results.len() - shown;
+        Button::new(tr!("Show more ({0})", remaining))
+            .class("pwt-search-show-more")
+            .margin_x(2)
+            .on_activate(link.callback(move |_| FederatedMsg::ShowMore(index)))
+    });
+
+    let empty = (!state.loading && state.error.is_none() && state.results.is_empty())
+        .then(|| Container::new().padding(2).with_child(tr!("No results")));
+
+    let error = state
+        .error
+        .as_ref()
+        .map(|err| crate::widget::error_message(&format!("Error: {err}")).padding(2));
+
+    Column::new()
+        .class("pwt-search-source")
+        .attribute("role", "group")
+        .attribute("aria-label", source.title.clone())
+        .with_child(header)
+        .with_optional_child(error)
+        .children(items)
+        .with_optional_child(more)
+        .with_optional_child(empty)
+        .into()
+}
+
+/// Moves the focus to the next/previous search result.
+fn focus_next_result(event: &KeyboardEvent, forward: bool) {
+    let picker = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+    let Some(picker) = picker else {
+        return;
+    };
+    let Ok(list) = picker.query_selector_all(".pwt-search-result") else {
+        return;
+    };
+    let items: Vec<web_sys::HtmlElement> = (0..list.length())
+        .filter_map(|i| list.get(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect();
+    if items.is_empty() {
+        return;
+    }
+
+    let active = gloo_utils::document().active_element();
+    let pos = items.iter().position(|item| {
+        let item: &web_sys::Element = item;
+        active.as_ref() == Some(item)
+    });
+
+    let next = next_result_index(items.len(), pos, forward);
+    let _ = items[next].focus();
+}
+
+// Index of the next/previous result (stops at the first and last result).
+//
+// Starts at the first (or last) result if no result has focus. `len` must
+// not be zero.
+fn next_result_index(len: usize, pos: Option<usize>, forward: bool) -> usize {
+    match (pos, forward) {
+        (None, true) => 0,
+        (None, false) => len - 1,
+        (Some(pos), true) => (pos + 1).min(len - 1),
+        (Some(pos), false) => pos.saturating_sub(1),
+    }
+}
+
+impl Component for PwtFederatedSearchDropdown {
+    type Message = FederatedMsg;
+    type Properties = FederatedSearchDropdown;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.link().send_message(FederatedMsg::Load);
+        Self {
+            filter: String::new(),
+            load_timeout: None,
+            load_abort_guards: Vec::new(),
+            states: vec![SourceState::default(); ctx.props().sources.len()],
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            FederatedMsg::UpdateFilter(filter) => {
+                self.filter = filter;
+                let link = ctx.link().clone();
+                self.load_timeout = Some(Timeout::new(200, move || {
+                    link.send_message(FederatedMsg::Load);
+                }));
+                true
+            }
+            FederatedMsg::Load => {
+                // dropping the old guards aborts pending loads
+                self.load_abort_guards = props
+                    .sources
+                    .iter()
+                    .enumerate()
+                    .map(|(index, source)| {
+                        let loader = Rc::clone(&source.loader);
+                        let filter = self.filter.clone();
+                        let link = ctx.link().clone();
+                        AsyncAbortGuard::spawn(async move {
+                            let res = loader(filter).await;
+                            link.send_message(FederatedMsg::LoadResult(index, res));
+                        })
+                    })
+                    .collect();
+                self.states
+                    .resize_with(props.sources.len(), Default::default);
+                for state in self.states.iter_mut() {
+                    state.loading = true;
+                    state.show_all = false;
+                }
+                true
+            }
+            FederatedMsg::LoadResult(index, result) => {
+                let Some(state) = self.states.get_mut(index) else {
+                    return false;
+                };
+                state.loading = false;
+                match result {
+                    Ok(results) => {
+                        state.results = results;
+                        state.error = None;
+                    }
+                    Err(err) => {
+                        state.results = Vec::new();
+                        state.error = Some(err.to_string());
+                    }
+                }
+                true
+            }
+            FederatedMsg::ShowMore(index) => {
+                if let Some(state) = self.states.get_mut(index) {
+                    state.show_all = true;
+                }
+                true
+            }
+            FederatedMsg::Select(source, key) => {
+                if let Some(on_select) = &props.on_select {
+                    on_select.emit(SearchSourceSelect { source, key });
+                }
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().sources != old_props.sources {
+            ctx.link().send_message(FederatedMsg::Load);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let sources = props.sources.clone();
+        let states = self.states.clone();
+        let source_limit = props.source_limit;
+        let link = ctx.link().clone();
+
+        Dropdown::new(move |controller: &DropdownController| -> Html {
+            let sections = sources.iter().enumerate().map(|(index, source)| {
+                let state = states.get(index).cloned().unwrap_or_default();
+                render_source_section(&link, controller, index, source, &state, source_limit)
+            });
+            Column::new()
+                .class("pwt-federated-search")
+                .attribute("role", "listbox")
+                .max_height(CssLength::Em(30.0))
+                .style("overflow", "auto")
+                .onkeydown(|event: KeyboardEvent| match event.key().as_str() {
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        focus_next_result(&event, true);
+                    }
+                    "ArrowUp" => {
+                        event.prevent_default();
+                        focus_next_result(&event, false);
+                    }
+                    _ => {}
+                })
+                .children(sections)
+                .into()
+        })
+        .with_std_props(&props.std_props)
+        .with_input_props(&props.input_props)
+        .value(self.filter.clone())
+        .editable(true)
+        .on_change(ctx.link().callback(FederatedMsg::UpdateFilter))
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{next_result_index, shown_results};
+
+    #[test]
+    fn test_shown_results() {
+        assert_eq!(shown_results(0, 5, false), 0);
+        assert_eq!(shown_results(3, 5, false), 3);
+        assert_eq!(shown_results(5, 5, false), 5);
+        assert_eq!(shown_results(8, 5, false), 5);
+        assert_eq!(shown_results(8, 5, true), 8);
+        assert_eq!(shown_results(8, 0, false), 0);
+    }
+
+    #[test]
+    fn test_next_result_index() {
+        // nothing focused
+        assert_eq!(next_result_index(4, None, true), 0);
+        assert_eq!(next_result_index(4, None, false), 3);
+
+        assert_eq!(next_result_index(4, Some(0), true), 1);
+        assert_eq!(next_result_index(4, Some(2), false), 1);
+
+        // stop at the first and last result
+        assert_eq!(next_result_index(4, Some(3), true), 3);
+        assert_eq!(next_result_index(4, Some(0), false), 0);
+        assert_eq!(next_result_index(1, Some(0), true), 0);
+    }
+}
@@ -0,0 +1,232 @@
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+
+use yew::html::IntoPropValue;
+
+use crate::AsyncAbortGuard;
+use crate::css::{AlignItems, UserSelect};
+use crate::prelude::*;
+use crate::web_sys_ext::clipboard_write_text;
+use crate::widget::{ActionIcon, Container, Row};
+
+use pwt_macros::{builder, widget};
+
+// We always display the same number of bullets, so that the length of the
+// secret is not revealed.
+const MASK: &str = "••••••••••••";
+
+/// Masked display of secrets (API tokens, keys, passwords).
+///
+/// Shows bullets instead of the value, with an icon to reveal the value
+/// (toggle, or only while pressed with [reveal_on_hold](Self::reveal_on_hold)),
+/// and an optional button to copy the value to the clipboard without
+/// revealing it.
+///
+/// The icons stop click propagation, so this can be used inside
+/// [DataTable](crate::widget::data_table::DataTable) cells:
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::SecretText;
+/// # use pwt::widget::data_table::DataTableColumn;
+/// # struct Token { secret: String }
+/// # fn create_column() -> DataTableColumn<Token> {
+///     DataTableColumn::new("Secret")
+///         .render(|token: &Token| SecretText::new(token.secret.clone()).into())
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtSecretText, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct SecretText {
+    /// The secret value.
+    pub value: AttrValue,
+
+    /// Only reveal the value while the reveal icon is pressed.
+    #[builder]
+    #[prop_or_default]
+    pub reveal_on_hold: bool,
+
+    /// Show a button to copy the value to the clipboard (default `true`).
+    #[builder]
+    #[prop_or(true)]
+    pub copy_button: bool,
+
+    /// Hide the reveal icon (i.e. only allow copying).
+    #[builder]
+    #[prop_or_default]
+    pub hide_reveal: bool,
+}
+
+impl SecretText {
+    /// Create a new instance.
+    pub fn new(value: impl IntoPropValue<AttrValue>) -> Self {
+        yew::props!(Self {
+            value: value.into_prop_value()
+        })
+    }
+}
+
+pub enum Msg {
+    Toggle(bool), // keyboard?
+    Reveal(bool),
+    Copy,
+    CopyResult(Result<(), String>),
+    CopyDone,
+}
+
+#[doc(hidden)]
+pub struct PwtSecretText {
+    revealed: bool,
+    // result of the last copy, shown until the timeout
+    copy_result: Option<Result<(), String>>,
+    copy_timeout: Option<Timeout>,
+    copy_guard: Option<AsyncAbortGuard>,
+}
+
+impl Component for PwtSecretText {
+    type Message = Msg;
+    type Properties = SecretText;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            revealed: false,
+            copy_result: None,
+            copy_timeout: None,
+            copy_guard: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Toggle(keyboard) => {
+                if props.reveal_on_hold {
+                    // pointer events are handled separately
+                    if keyboard && !self.revealed {
+                        self.revealed = true;
+                        return true;
+                    }
+                    return false;
+                }
+                self.revealed = !self.revealed;
+                true
+            }
+            Msg::Reveal(revealed) => {
+                if self.revealed == revealed {
+                    return false;
+                }
+                self.revealed = revealed;
+                true
+            }
+            Msg::Copy => {
+                let link = ctx.link().clone();
+                let promise = clipboard_write_text(&props.value);
+                self.copy_guard = Some(AsyncAbortGuard::spawn(async move {
+                    let result = match promise {
+                        Ok(promise) => wasm_bindgen_futures::JsFuture::from(promise)
+                            .await
+                            .map(|_| ()),
+                        Err(err) => Err(err),
+                    };
+                    link.send_message(Msg::CopyResult(result.map_err(|err| format!("{err:?}"))));
+                }));
+                false
+            }
+            Msg::CopyResult(result) => {
+                if let Err(err) = &result {
+                    log::error!("copy to clipboard failed: {err}");
+                }
+                self.copy_result = Some(result);
+                self.copy_guard = None;
+                let link = ctx.link().clone();
+                self.copy_timeout = Some(Timeout::new(1500, move || {
+                    link.send_message(Msg::CopyDone);
+                }));
+                true
+            }
+            Msg::CopyDone => {
+                self.copy_result = None;
+                self.copy_timeout = None;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().value != old_props.value {
+            // never reveal a new secret without user interaction
+            self.revealed = false;
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let text = Container::from_tag("span")
+            .class("pwt-secret-text-value")
+            .class((!self.revealed).then_some(UserSelect::None))
+            .with_child(if self.revealed {
+                html! {props.value.clone()}
+            } else {
+                html! {MASK}
+            });
+
+        let reveal = (!props.hide_reveal).then(|| {
+            let mut icon =
+                ActionIcon::new(if self.revealed {
+                    "fa fa-eye-slash"
+                } else {
+                    "fa fa-eye"
+                })
+                .tabindex(0)
+                .attribute("aria-pressed", self.revealed.to_string())
+                .aria_label(if props.reveal_on_hold {
+                    tr!("Hold to show")
+                } else if self.revealed {
+                    tr!("Hide")
+                } else {
+                    tr!("Show")
+                })
+                .on_activate(link.callback(|event: Event| {
+                    Msg::Toggle(event.dyn_ref::<KeyboardEvent>().is_some())
+                }));
+
+            if props.reveal_on_hold {
+                icon = icon
+                    .onkeyup(link.callback(|_| Msg::Reveal(false)))
+                    .onpointerdown(link.callback(|_| Msg::Reveal(true)))
+                    .onpointerup(link.callback(|_| Msg::Reveal(false)))
+                    .onpointerleave(link.callback(|_| Msg::Reveal(false)))
+                    .onpointercancel(link.callback(|_| Msg::Reveal(false)))
+                    .onblur(link.callback(|_| Msg::Reveal(false)));
+            }
+            icon
+        });
+
+        let copy = props.copy_button.then(|| {
+            let (icon, label) = match &self.copy_result {
+                None => ("fa fa-clipboard", tr!("Copy to clipboard")),
+                Some(Ok(())) => ("fa fa-check", tr!("Copied")),
+                Some(Err(_)) => ("fa fa-exclamation-triangle", tr!("Copy failed")),
+            };
+            ActionIcon::new(icon)
+                .tabindex(0)
+                .aria_label(label)
+                .on_activate(link.callback(|_| Msg::Copy))
+        });
+
+        Row::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-secret-text")
+            .class(AlignItems::Center)
+            .gap(1)
+            .with_child(text)
+            .with_optional_child(reveal)
+            .with_optional_child(copy)
+            .into()
+    }
+}
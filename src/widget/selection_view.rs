@@ -228,9 +228,9 @@ impl Component for PwtSelectionView {
             };
             let class = if active {
                 // Note: use "pwt-d-flex pwt-flex-fill" to make it work with min-height, min-width, ...
-                classes!("pwt-d-flex", "pwt-flex-fill", "pwt-overflow-auto")
+                classes!(crate::css::Display::Flex, crate::css::Flex::Fill, crate::css::Overflow::Auto)
             } else {
-                classes!("pwt-d-none")
+                classes!(crate::css::Display::None)
             };
 
             html! {
@@ -244,7 +244,8 @@ impl Component for PwtSelectionView {
             std_props: props.std_props.clone(),
             listeners: props.listeners.clone(),
         })
-        .class("pwt-d-flex pwt-flex-direction-column")
+        .class(crate::css::Display::Flex)
+        .class(crate::css::FlexDirection::Column)
         .children(content)
         .into()
     }
@@ -191,7 +191,7 @@ impl<T: Into<VNode>> From<T> for Pane {
 }
 
 /// Container where children are separated by a draggable sparator.
-#[widget(pwt=crate, comp=PwtSplitPane, @element)]
+#[widget(pwt=crate, comp=PwtSplitPane, @element, @default)]
 #[derive(Clone, PartialEq, Properties)]
 pub struct SplitPane {
     /// Container children.
@@ -207,12 +207,6 @@ pub struct SplitPane {
     pub handle_size: usize,
 }
 
-impl Default for SplitPane {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl SplitPane {
     /// Creates a new instance
     pub fn new() -> Self {
@@ -0,0 +1,164 @@
+use yew::html::IntoEventCallback;
+
+use crate::css::{Layer, Position};
+use crate::dom::DomVisibilityObserver;
+use crate::prelude::*;
+use crate::widget::Container;
+
+use pwt_macros::{builder, widget};
+
+/// Position of a [Sticky] region.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum StickyPosition {
+    /// Pin to the top of the scrolling ancestor (header).
+    #[default]
+    Top,
+    /// Pin to the bottom of the scrolling ancestor (footer).
+    Bottom,
+}
+
+/// Keep content pinned within a scrolling ancestor.
+///
+/// This uses CSS `position: sticky`, so the content stays visible while
+/// the surrounding container scrolls. An invisible sentinel element is used
+/// to detect if the content is stuck. Stuck regions get the `stuck` CSS class,
+/// which the theme uses to display a shadow.
+///
+/// # Note
+///
+/// Virtualized containers like [DataTable](crate::widget::data_table::DataTable)
+/// or [List](crate::widget::List) only render visible rows, using absolute
+/// positioning. Sticky regions inside those rows cannot work, so put them
+/// beside the virtualized container (i.e. inside the surrounding [Column]).
+///
+/// [Column]: crate::widget::Column
+#[widget(pwt=crate, comp=PwtSticky, @element, @container)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct Sticky {
+    /// Sticky position.
+    #[prop_or_default]
+    #[builder]
+    pub position: StickyPosition,
+
+    /// Distance (in pixels) from the scrolling ancestor edge.
+    #[prop_or_default]
+    #[builder]
+    pub offset: f64,
+
+    /// Called when the stuck state changes.
+    #[builder_cb(IntoEventCallback, into_event_callback, bool)]
+    #[prop_or_default]
+    pub on_stuck_change: Option<Callback<bool>>,
+}
+
+impl Default for Sticky {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sticky {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    /// Create a new instance pinned to the top.
+    pub fn top(child: impl Into<Html>) -> Self {
+        Self::new().with_child(child)
+    }
+
+    /// Create a new instance pinned to the bottom.
+    pub fn bottom(child: impl Into<Html>) -> Self {
+        Self::new()
+            .position(StickyPosition::Bottom)
+            .with_child(child)
+    }
+}
+
+pub enum Msg {
+    SentinelVisible(bool),
+}
+
+#[doc(hidden)]
+pub struct PwtSticky {
+    sentinel_ref: NodeRef,
+    observer: Option<DomVisibilityObserver>,
+    observed_position: Option<StickyPosition>,
+    stuck: bool,
+}
+
+impl Component for PwtSticky {
+    type Message = Msg;
+    type Properties = Sticky;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            sentinel_ref: NodeRef::default(),
+            observer: None,
+            observed_position: None,
+            stuck: false,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SentinelVisible(visible) => {
+                let stuck = !visible;
+                if self.stuck == stuck {
+                    return false;
+                }
+                self.stuck = stuck;
+                if let Some(on_stuck_change) = &ctx.props().on_stuck_change {
+                    on_stuck_change.emit(stuck);
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let sentinel = Container::new()
+            .attribute("aria-hidden", "true")
+            .class("pwt-sticky-sentinel")
+            .into_html_with_ref(self.sentinel_ref.clone());
+
+        let edge = match props.position {
+            StickyPosition::Top => "top",
+            StickyPosition::Bottom => "bottom",
+        };
+
+        let content = Container::new()
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-sticky")
+            .class(self.stuck.then_some("stuck"))
+            .class(Position::Sticky)
+            .style("z-index", Layer::Sticky)
+            .style(edge, format!("{}px", props.offset))
+            .children(props.children.clone());
+
+        match props.position {
+            StickyPosition::Top => html! {<>{sentinel}{content}</>},
+            StickyPosition::Bottom => html! {<>{content}{sentinel}</>},
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        let position = ctx.props().position;
+        if self.observed_position == Some(position) {
+            return;
+        }
+        // the sentinel moves if the position changes, so we need a new observer
+        if let Some(el) = self.sentinel_ref.cast::<web_sys::Element>() {
+            let link = ctx.link().clone();
+            self.observer = Some(DomVisibilityObserver::new(&el, move |visible| {
+                link.send_message(Msg::SentinelVisible(visible));
+            }));
+            self.observed_position = Some(position);
+        }
+    }
+}
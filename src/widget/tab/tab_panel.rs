@@ -245,7 +245,12 @@ impl Component for PwtTabPanel {
                     .class(material_style.then_some("pwt-panel-header"));
             (Some(title), None)
         } else {
-            class = classes!("pwt-d-flex", "pwt-align-items-center", "pwt-gap-2", class);
+            class = classes!(
+                crate::css::Display::Flex,
+                crate::css::AlignItems::Center,
+                crate::css::Gap(2),
+                class
+            );
             let tools =
                 (!props.tools.is_empty()).then(|| VList::with_children(props.tools.clone(), None));
             (None, tools)
@@ -0,0 +1,331 @@
+use std::rc::Rc;
+
+use anyhow::Error;
+use gloo_timers::callback::Timeout;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::AsyncAbortGuard;
+use crate::css::{AlignItems, FontColor};
+use crate::prelude::*;
+use crate::props::{IntoOptionalKey, LoadCallback};
+use crate::widget::{Button, Column, Container, Dialog, DialogSize, Progress, Row, Toolbar};
+
+use pwt_macros::builder;
+
+/// Task status returned by the [TaskProgressDialog] loader.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TaskStatus {
+    /// The task is still running.
+    pub running: bool,
+    /// Progress value (0 to 1), if known.
+    pub progress: Option<f32>,
+    /// Error message (only used when the task is finished).
+    pub error: Option<String>,
+    /// The task log (all lines).
+    pub log: Vec<String>,
+}
+
+impl TaskStatus {
+    /// Returns true if the task finished without errors.
+    pub fn is_ok(&self) -> bool {
+        !self.running && self.error.is_none()
+    }
+}
+
+/// Dialog showing the progress and log of a running task.
+///
+/// The dialog calls the [loader](Self::loader) until the returned
+/// [TaskStatus] is no longer running (see
+/// [poll_interval](Self::poll_interval)). Load errors are displayed, but do
+/// not stop polling.
+///
+/// Closing the dialog does not stop the task. Set
+/// [on_abort](Self::on_abort) to show a "Stop" button.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{TaskProgressDialog, TaskStatus};
+/// # fn create_dialog(upid: String) -> TaskProgressDialog {
+/// TaskProgressDialog::new(tr!("Backup"), move || {
+///     let upid = upid.clone();
+///     async move {
+///         // query the task status and log for `upid`
+///         Ok::<_, anyhow::Error>(TaskStatus {
+///             running: false,
+///             log: vec![format!("{upid}: TASK OK")],
+///             ..Default::default()
+///         })
+///     }
+/// })
+/// .on_abort(|_| { /* send the stop request */ })
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Properties)]
+#[builder]
+pub struct TaskProgressDialog {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+
+    /// Dialog title.
+    #[builder(IntoPropValue, into_prop_value)]
+    pub title: AttrValue,
+
+    /// Returns the current task status.
+    pub loader: LoadCallback<TaskStatus>,
+
+    /// Delay between status requests in milliseconds (default 1000).
+    #[builder]
+    #[prop_or(1000)]
+    pub poll_interval: u32,
+
+    /// Called once when the task is finished.
+    #[builder_cb(IntoEventCallback, into_event_callback, TaskStatus)]
+    #[prop_or_default]
+    pub on_finish: Option<Callback<TaskStatus>>,
+
+    /// Called when the user presses the "Stop" button.
+    ///
+    /// The dialog continues polling until the task is finished.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_abort: Option<Callback<()>>,
+
+    /// Called when the dialog gets closed.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_close: Option<Callback<()>>,
+}
+
+impl TaskProgressDialog {
+    /// Create a new instance.
+    pub fn new(title: impl Into<AttrValue>, loader: impl Into<LoadCallback<TaskStatus>>) -> Self {
+        yew::props!(Self {
+            title: title.into(),
+            loader: loader.into(),
+        })
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+}
+
+pub enum Msg {
+    Poll,
+    PollResult(Result<TaskStatus, Error>),
+    Abort,
+    Close,
+}
+
+#[doc(hidden)]
+pub struct PwtTaskProgressDialog {
+    status: TaskStatus,
+    finished: bool,
+    aborting: bool,
+    poll_error: Option<String>,
+    poll_timeout: Option<Timeout>,
+    load_guard: Option<AsyncAbortGuard>,
+    log_ref: NodeRef,
+    // keep the log scrolled to the end
+    follow_log: bool,
+}
+
+impl PwtTaskProgressDialog {
+    fn poll(&mut self, ctx: &Context<Self>) {
+        let loader = ctx.props().loader.clone();
+        let link = ctx.link().clone();
+        self.load_guard = Some(AsyncAbortGuard::spawn(async move {
+            let result = loader.apply().await;
+            link.send_message(Msg::PollResult(result));
+        }));
+    }
+
+    fn schedule_poll(&mut self, ctx: &Context<Self>) {
+        let link = ctx.link().clone();
+        self.poll_timeout = Some(Timeout::new(ctx.props().poll_interval, move || {
+            link.send_message(Msg::Poll);
+        }));
+    }
+
+    fn log_at_end(&self) -> bool {
+        match self.log_ref.cast::<web_sys::Element>() {
+            Some(el) => el.scroll_top() + el.client_height() + 2 >= el.scroll_height(),
+            None => true,
+        }
+    }
+
+    fn status_text(&self) -> String {
+        if !self.finished {
+            if self.aborting {
+                tr!("Stopping...")
+            } else {
+                tr!("Running...")
+            }
+        } else if let Some(err) = &self.status.error {
+            tr!("Error: {0}", err)
+        } else {
+            tr!("Task OK")
+        }
+    }
+}
+
+impl Component for PwtTaskProgressDialog {
+    type Message = Msg;
+    type Properties = TaskProgressDialog;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut me = Self {
+            status: TaskStatus {
+                running: true,
+                ..Default::default()
+            },
+            finished: false,
+            aborting: false,
+            poll_error: None,
+            poll_timeout: None,
+            load_guard: None,
+            log_ref: NodeRef::default(),
+            follow_log: true,
+        };
+        me.poll(ctx);
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Poll => {
+                self.poll(ctx);
+                false
+            }
+            Msg::PollResult(result) => {
+                if self.finished {
+                    return false;
+                }
+                match result {
+                    Ok(status) => {
+                        self.poll_error = None;
+                        self.follow_log = self.log_at_end();
+                        self.status = status;
+                        if self.status.running {
+                            self.schedule_poll(ctx);
+                        } else {
+                            self.finished = true;
+                            if let Some(on_finish) = &props.on_finish {
+                                on_finish.emit(self.status.clone());
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        self.poll_error = Some(err.to_string());
+                        self.schedule_poll(ctx);
+                    }
+                }
+                true
+            }
+            Msg::Abort => {
+                self.aborting = true;
+                if let Some(on_abort) = &props.on_abort {
+                    on_abort.emit(());
+                }
+                true
+            }
+            Msg::Close => {
+                if let Some(on_close) = &props.on_close {
+                    on_close.emit(());
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let progress = if self.finished {
+            Some(1.0)
+        } else {
+            self.status.progress
+        };
+
+        let status = Row::new()
+            .gap(2)
+            .class(AlignItems::Center)
+            .with_child(
+                Container::new()
+                    .attribute("role", "status")
+                    .class("pwt-flex-fill")
+                    .class(
+                        (self.finished && self.status.error.is_some()).then_some(FontColor::Error),
+                    )
+                    .with_child(self.status_text()),
+            )
+            .with_optional_child(
+                self.poll_error
+                    .as_ref()
+                    .map(|err| crate::widget::error_message(err)),
+            );
+
+        let log = Container::new()
+            // monospace, scrollable text (fixed height set by pwt-task-log)
+            .class("pwt-log-view")
+            .class("pwt-task-log")
+            .class("pwt-border")
+            .padding(1)
+            .attribute("role", "log")
+            .attribute("aria-label", tr!("Task log"))
+            .children(self.status.log.iter().map(|line| html! {<div>{line}</div>}))
+            .into_html_with_ref(self.log_ref.clone());
+
+        let mut toolbar = Toolbar::new().with_flex_spacer();
+        if props.on_abort.is_some() {
+            toolbar.add_child(
+                Button::new(tr!("Stop"))
+                    .icon_class("fa fa-stop")
+                    .disabled(self.finished || self.aborting)
+                    .on_activate(link.callback(|_| Msg::Abort)),
+            );
+        }
+        toolbar.add_child(
+            Button::new(tr!("Close"))
+                .autofocus(true)
+                .on_activate(link.callback(|_| Msg::Close)),
+        );
+
+        Dialog::new(props.title.clone())
+            .size(DialogSize::Medium)
+            .on_close(link.callback(|_| Msg::Close))
+            .with_child(
+                Column::new()
+                    .padding(2)
+                    .gap(2)
+                    .with_child(status)
+                    .with_child(Progress::new().value(progress).striped(progress.is_none()))
+                    .with_child(log),
+            )
+            .with_child(toolbar)
+            .into()
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if self.follow_log {
+            if let Some(el) = self.log_ref.cast::<web_sys::Element>() {
+                el.set_scroll_top(el.scroll_height());
+            }
+        }
+    }
+}
+
+impl From<TaskProgressDialog> for VNode {
+    fn from(val: TaskProgressDialog) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtTaskProgressDialog>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}
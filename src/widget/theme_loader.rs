@@ -1,6 +1,8 @@
+use std::collections::HashSet;
 use std::rc::Rc;
 
 use pwt_macros::builder;
+use yew::html::{IntoEventCallback, IntoPropValue};
 use yew::prelude::*;
 use yew::virtual_dom::{Key, VComp, VNode};
 
@@ -8,6 +10,52 @@ use crate::impl_to_html;
 use crate::props::{IntoOptionalRenderFn, RenderFn};
 use crate::state::{Theme, ThemeDensity, ThemeObserver};
 
+/// Minimal CSS used if the theme stylesheet fails to load.
+const DEFAULT_FALLBACK_CSS: &str = "body { font-family: sans-serif; margin: 0; }";
+
+/// Additional CSS bundle loaded by the [ThemeLoader].
+#[derive(Clone, PartialEq, Debug)]
+pub struct CssBundle {
+    /// The stylesheet URL.
+    pub href: AttrValue,
+    /// Optional subresource integrity hash (`integrity` attribute).
+    pub integrity: Option<AttrValue>,
+}
+
+impl CssBundle {
+    /// Create a new instance.
+    pub fn new(href: impl Into<AttrValue>) -> Self {
+        Self {
+            href: href.into(),
+            integrity: None,
+        }
+    }
+
+    /// Builder style method to set the integrity hash.
+    pub fn integrity(mut self, integrity: impl Into<AttrValue>) -> Self {
+        self.integrity = Some(integrity.into());
+        self
+    }
+}
+
+impl From<&'static str> for CssBundle {
+    fn from(href: &'static str) -> Self {
+        CssBundle::new(href)
+    }
+}
+
+impl From<String> for CssBundle {
+    fn from(href: String) -> Self {
+        CssBundle::new(href)
+    }
+}
+
+impl From<AttrValue> for CssBundle {
+    fn from(href: AttrValue) -> Self {
+        CssBundle::new(href)
+    }
+}
+
 /// Dynamic theme loader component.
 ///
 /// This widget serves as the root boundary for theming in the application. It manages:
@@ -20,6 +68,11 @@ use crate::state::{Theme, ThemeDensity, ThemeObserver};
 /// It uses [ThemeObserver] internally to react to system and application-level theme changes, and
 /// displays an empty page until the first theme is successfully loaded.
 ///
+/// Additional [CssBundle]s (product overrides, user custom CSS) are loaded in order after
+/// the theme CSS. The content is shown after all bundles are loaded (or failed). If the theme
+/// stylesheet fails to load, the `fallback_css` is applied instead (the bundles still take
+/// precedence).
+///
 /// This is typically the root of the widget tree, and is used by scaffold widgets
 /// like [DesktopApp][crate::widget::DesktopApp] and
 /// [MaterialApp][crate::touch::MaterialApp].
@@ -52,6 +105,20 @@ pub struct ThemeLoader {
     #[builder_cb(IntoOptionalRenderFn, into_optional_render_fn, String, String)]
     #[prop_or_default]
     pub theme_url_builder: Option<RenderFn<String, String>>,
+
+    /// Additional CSS bundles, loaded in order after the theme CSS.
+    #[prop_or_default]
+    pub bundles: Vec<CssBundle>,
+
+    /// Minimal CSS applied if the theme stylesheet fails to load.
+    #[builder(IntoPropValue, into_prop_value)]
+    #[prop_or(AttrValue::Static(DEFAULT_FALLBACK_CSS))]
+    pub fallback_css: AttrValue,
+
+    /// Ready callback, called once after the initial theme and all bundles are loaded.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_ready: Option<Callback<()>>,
 }
 
 impl ThemeLoader {
@@ -60,6 +127,17 @@ impl ThemeLoader {
         yew::props!(Self { body: body.into() })
     }
 
+    /// Builder style method to add a CSS bundle.
+    pub fn with_bundle(mut self, bundle: impl Into<CssBundle>) -> Self {
+        self.add_bundle(bundle);
+        self
+    }
+
+    /// Method to add a CSS bundle.
+    pub fn add_bundle(&mut self, bundle: impl Into<CssBundle>) {
+        self.bundles.push(bundle.into());
+    }
+
     /// Set the dark/light mode class on the document root (`<html>`).
     ///
     /// This removes any existing `pwt-dark-mode` or `pwt-light-mode` classes and adds
@@ -99,6 +177,11 @@ pub struct PwtThemeLoader {
     theme_css: String,
     new_theme_css: Option<String>,
     theme_observer: ThemeObserver,
+    // bundles which finished loading (successfully or not)
+    settled_bundles: HashSet<AttrValue>,
+    // the theme stylesheet failed to load
+    theme_failed: bool,
+    ready: bool,
 }
 
 fn get_document_root() -> Option<web_sys::Element> {
@@ -169,10 +252,30 @@ impl PwtThemeLoader {
             false
         }
     }
+
+    fn is_ready(&self, props: &ThemeLoader) -> bool {
+        !matches!(self.loadstate, LoadState::Initial)
+            && props
+                .bundles
+                .iter()
+                .all(|bundle| self.settled_bundles.contains(&bundle.href))
+    }
+
+    fn check_ready(&mut self, props: &ThemeLoader) {
+        if !self.ready && self.is_ready(props) {
+            self.ready = true;
+            if let Some(on_ready) = &props.on_ready {
+                on_ready.emit(());
+            }
+        }
+    }
 }
 
 pub enum Msg {
     Loaded,
+    LoadFailed(String),
+    BundleLoaded(AttrValue),
+    BundleFailed(AttrValue),
     ThemeChanged((Theme, bool)),
 }
 
@@ -192,41 +295,110 @@ impl Component for PwtThemeLoader {
             loadstate: LoadState::Initial,
             theme_css: Self::get_css_filename(props, &theme),
             new_theme_css: None,
+            settled_bundles: HashSet::new(),
+            theme_failed: false,
+            ready: false,
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         let props = ctx.props();
         let theme = self.theme_observer.theme();
-        match msg {
-            Msg::Loaded => self.update_theme(props, &theme, self.theme_observer.dark_mode(), true),
+        let changed = match msg {
+            Msg::Loaded => {
+                let was_failed = std::mem::take(&mut self.theme_failed);
+                self.update_theme(props, &theme, self.theme_observer.dark_mode(), true)
+                    || was_failed
+            }
+            Msg::LoadFailed(href) => {
+                log::error!("failed to load theme stylesheet '{href}'");
+                self.theme_failed = true;
+                self.update_theme(props, &theme, self.theme_observer.dark_mode(), true);
+                true
+            }
+            Msg::BundleLoaded(href) => {
+                self.settled_bundles.insert(href);
+                true
+            }
+            Msg::BundleFailed(href) => {
+                log::error!("failed to load CSS bundle '{href}'");
+                self.settled_bundles.insert(href);
+                true
+            }
             Msg::ThemeChanged((theme, dark_mode)) => {
                 self.update_theme(props, &theme, dark_mode, false)
             }
-        }
+        };
+        self.check_ready(props);
+        changed
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.check_ready(ctx.props());
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
         let onload = ctx.link().callback(|_| Msg::Loaded);
+        let onerror = {
+            let href = self
+                .new_theme_css
+                .clone()
+                .unwrap_or_else(|| self.theme_css.clone());
+            ctx.link().callback(move |_| Msg::LoadFailed(href.clone()))
+        };
 
         // Note: Try to keep the VDOM, so just set display on the content
-        let style = match &self.loadstate {
-            LoadState::Loading | LoadState::Loaded => "display: contents;",
-            LoadState::Initial => "display: none;",
+        let style = if self.is_ready(props) || self.ready {
+            "display: contents;"
+        } else {
+            "display: none;"
         };
 
+        let bundles: Html = props
+            .bundles
+            .iter()
+            .map(|bundle| {
+                let href = bundle.href.clone();
+                let onload = ctx
+                    .link()
+                    .callback(move |_| Msg::BundleLoaded(href.clone()));
+                let href = bundle.href.clone();
+                let onerror = ctx
+                    .link()
+                    .callback(move |_| Msg::BundleFailed(href.clone()));
+                html! {
+                    <link
+                        key={Key::from(bundle.href.to_string())}
+                        href={bundle.href.clone()}
+                        integrity={bundle.integrity.clone()}
+                        crossorigin={bundle.integrity.as_ref().map(|_| "anonymous")}
+                        rel="stylesheet"
+                        {onload}
+                        {onerror}
+                    />
+                }
+            })
+            .collect();
+
         html! {
             <>
+                // Note: insert the fallback first, so that the stylesheets
+                // take precedence.
+                if self.theme_failed {
+                    <style key="__theme-loader-fallback__">{props.fallback_css.clone()}</style>
+                }
                 // Important: use href as Key, to create a new DOM
                 // element for each href, and thus get an load event
                 // for each href.
                 if let Some(theme) = &self.new_theme_css {
                     <link key={Key::from(self.theme_css.clone())} href={self.theme_css.clone()} rel="stylesheet"/>
-                    <link key={Key::from(theme.clone())} {onload} href={theme.clone()} rel="stylesheet"/>
+                    <link key={Key::from(theme.clone())} {onload} {onerror} href={theme.clone()} rel="stylesheet"/>
                 } else {
-                    <link key={Key::from(self.theme_css.clone())} {onload} href={self.theme_css.clone()} rel="stylesheet"/>
+                    <link key={Key::from(self.theme_css.clone())} {onload} {onerror} href={self.theme_css.clone()} rel="stylesheet"/>
                 }
+                {bundles}
                 <div key="__theme-loader-content__" {style}>{props.body.clone()}</div>
             </>
         }
@@ -0,0 +1,174 @@
+use gloo_timers::callback::Timeout;
+use js_sys::Date;
+use wasm_bindgen::JsValue;
+
+use crate::prelude::*;
+use crate::state::{Language, LanguageObserver};
+use crate::widget::{Container, Tooltip};
+
+use pwt_macros::{builder, widget};
+
+/// Relative timestamp ("5 minutes ago").
+///
+/// The text is updated automatically, every second for recent
+/// timestamps, then every minute, hour and day. The absolute timestamp
+/// (formatted with the active language) is shown as tooltip. Timestamps
+/// in the future (clock skew) are displayed as "just now".
+///
+/// Useful as renderer for task lists and log tables:
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::TimeAgo;
+/// # use pwt::widget::data_table::DataTableColumn;
+/// # struct Task { starttime: i64 }
+/// # fn create_column() -> DataTableColumn<Task> {
+///     DataTableColumn::new("Start Time")
+///         .render(|task: &Task| TimeAgo::new(task.starttime).into())
+/// # }
+/// ```
+#[widget(pwt=crate, comp=PwtTimeAgo, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct TimeAgo {
+    /// The timestamp (unix epoch, seconds).
+    pub timestamp: i64,
+
+    /// Show the absolute timestamp as tooltip (default `true`).
+    #[builder]
+    #[prop_or(true)]
+    pub tooltip: bool,
+}
+
+impl TimeAgo {
+    /// Create a new instance.
+    pub fn new(timestamp: i64) -> Self {
+        yew::props!(Self { timestamp })
+    }
+}
+
+// Returns the text and the number of seconds until the text changes.
+fn format_time_ago(diff: i64) -> (String, i64) {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let diff = diff.max(0);
+    let next = |unit: i64| unit - diff % unit;
+
+    if diff < 10 {
+        (tr!("just now"), 10 - diff)
+    } else if diff < MINUTE {
+        (tr!("one second ago" | "{n} seconds ago" % diff), 1)
+    } else if diff < HOUR {
+        let n = diff / MINUTE;
+        (tr!("one minute ago" | "{n} minutes ago" % n), next(MINUTE))
+    } else if diff < DAY {
+        let n = diff / HOUR;
+        (tr!("one hour ago" | "{n} hours ago" % n), next(HOUR))
+    } else {
+        let n = diff / DAY;
+        (tr!("one day ago" | "{n} days ago" % n), next(DAY))
+    }
+}
+
+fn epoch_to_date(timestamp: i64) -> Date {
+    Date::new(&JsValue::from_f64(timestamp as f64 * 1000.0))
+}
+
+pub enum Msg {
+    Tick,
+    LanguageChange,
+}
+
+#[doc(hidden)]
+pub struct PwtTimeAgo {
+    text: String,
+    timeout: Option<Timeout>,
+    _language_observer: LanguageObserver,
+}
+
+impl PwtTimeAgo {
+    fn update_text(&mut self, ctx: &Context<Self>) {
+        let now = (Date::now() / 1000.0) as i64;
+        let (text, next) = format_time_ago(now - ctx.props().timestamp);
+        self.text = text;
+
+        let link = ctx.link().clone();
+        self.timeout = Some(Timeout::new((next * 1000) as u32, move || {
+            link.send_message(Msg::Tick)
+        }));
+    }
+}
+
+impl Component for PwtTimeAgo {
+    type Message = Msg;
+    type Properties = TimeAgo;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let _language_observer =
+            LanguageObserver::new(ctx.link().callback(|_| Msg::LanguageChange));
+        let mut me = Self {
+            text: String::new(),
+            timeout: None,
+            _language_observer,
+        };
+        me.update_text(ctx);
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Tick | Msg::LanguageChange => {
+                let old_text = std::mem::take(&mut self.text);
+                self.update_text(ctx);
+                self.text != old_text || matches!(msg, Msg::LanguageChange)
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().timestamp != old_props.timestamp {
+            self.update_text(ctx);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let date = epoch_to_date(props.timestamp);
+
+        let time = Container::from_tag("time")
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-time-ago")
+            .attribute("datetime", String::from(date.to_iso_string()))
+            .with_child(self.text.clone());
+
+        if !props.tooltip {
+            return time.into();
+        }
+
+        let lang = Language::load();
+        let lang = if lang.is_empty() { "default" } else { &lang };
+        let full_time: String = date.to_locale_string(lang, &JsValue::UNDEFINED).into();
+
+        Tooltip::new(time).tip(full_time).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::format_time_ago;
+
+    #[test]
+    fn test_format_time_ago() {
+        assert_eq!(format_time_ago(-5), ("just now".into(), 10));
+        assert_eq!(format_time_ago(3), ("just now".into(), 7));
+        assert_eq!(format_time_ago(42), ("42 seconds ago".into(), 1));
+        assert_eq!(format_time_ago(60), ("one minute ago".into(), 60));
+        assert_eq!(format_time_ago(5 * 60 + 20), ("5 minutes ago".into(), 40));
+        assert_eq!(format_time_ago(2 * 3600 + 10), ("2 hours ago".into(), 3590));
+        assert_eq!(format_time_ago(3 * 86400), ("3 days ago".into(), 86400));
+    }
+}
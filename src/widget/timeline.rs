@@ -0,0 +1,410 @@
+use std::rc::Rc;
+
+use gloo_timers::callback::Interval;
+use js_sys::Date;
+use wasm_bindgen::JsValue;
+
+use yew::html::{IntoEventCallback, IntoPropValue};
+use yew::virtual_dom::Key;
+
+use crate::prelude::*;
+use crate::widget::form::PlainDate;
+use crate::widget::{Button, Column, Container, Fa, List, ListTile, VisibilityObserver};
+
+use pwt_macros::{builder, widget};
+
+// Update relative times once a minute.
+const TICK_INTERVAL_MS: u32 = 60_000;
+
+/// Timeline entry.
+#[derive(Clone, PartialEq)]
+pub struct TimelineEntry {
+    key: Key,
+    timestamp: i64,
+    title: Html,
+    icon_class: Option<Classes>,
+    detail: Option<Html>,
+}
+
+impl TimelineEntry {
+    /// Creates a new instance.
+    ///
+    /// The `timestamp` is the unix epoch (seconds).
+    pub fn new(key: impl Into<Key>, timestamp: i64, title: impl Into<Html>) -> Self {
+        Self {
+            key: key.into(),
+            timestamp,
+            title: title.into(),
+            icon_class: None,
+            detail: None,
+        }
+    }
+
+    /// Builder style method to set the icon class.
+    pub fn icon_class(mut self, icon_class: impl Into<Classes>) -> Self {
+        self.set_icon_class(icon_class);
+        self
+    }
+
+    /// Method to set the icon class.
+    pub fn set_icon_class(&mut self, icon_class: impl Into<Classes>) {
+        self.icon_class = Some(icon_class.into());
+    }
+
+    /// Builder style method to set the detail body.
+    pub fn detail(mut self, detail: impl IntoPropValue<Option<Html>>) -> Self {
+        self.set_detail(detail);
+        self
+    }
+
+    /// Method to set the detail body.
+    pub fn set_detail(&mut self, detail: impl IntoPropValue<Option<Html>>) {
+        self.detail = detail.into_prop_value();
+    }
+}
+
+/// Time ordered list of entries (activity feed).
+///
+/// Useful for task histories and audit logs. Entries are grouped by
+/// day, and show relative times ("5 minutes ago") for recent entries,
+/// which get updated automatically. The list uses virtual scrolling,
+/// so long histories are no problem.
+///
+/// Entries must be sorted by time, newest first. Older entries can be
+/// loaded on demand: if [has_more](Self::has_more) is set, the timeline
+/// emits [on_load_more](Self::on_load_more) when the user scrolls to the
+/// end of the list.
+///
+/// ```
+/// # use pwt::prelude::*;
+/// # use pwt::widget::{Timeline, TimelineEntry};
+/// fn task_history(loading: bool, on_load_more: Callback<()>) -> Timeline {
+///     Timeline::new(vec![
+///         TimelineEntry::new("2", 1700003600, "Backup finished")
+///             .icon_class("fa fa-check"),
+///         TimelineEntry::new("1", 1700000000, "Backup started")
+///             .icon_class("fa fa-play")
+///             .detail(html! {"Job: daily"}),
+///     ])
+///     .has_more(true)
+///     .loading(loading)
+///     .on_load_more(on_load_more)
+/// }
+/// ```
+#[widget(pwt=crate, comp=PwtTimeline, @element)]
+#[derive(Properties, PartialEq, Clone)]
+#[builder]
+pub struct Timeline {
+    /// The entries (newest first).
+    #[prop_or_default]
+    pub entries: Rc<Vec<TimelineEntry>>,
+
+    /// Older entries are available.
+    #[prop_or_default]
+    #[builder]
+    pub has_more: bool,
+
+    /// Older entries are currently loading.
+    #[prop_or_default]
+    #[builder]
+    pub loading: bool,
+
+    /// Load more callback.
+    ///
+    /// Emitted when the user scrolls to the end of the list, or
+    /// presses the "Load more" button.
+    #[builder_cb(IntoEventCallback, into_event_callback, ())]
+    #[prop_or_default]
+    pub on_load_more: Option<Callback<()>>,
+}
+
+impl Timeline {
+    /// Create a new instance.
+    pub fn new(entries: impl Into<Rc<Vec<TimelineEntry>>>) -> Self {
+        yew::props!(Self {
+            entries: entries.into(),
+        })
+    }
+
+    /// Builder style method to set the entries.
+    pub fn entries(mut self, entries: impl Into<Rc<Vec<TimelineEntry>>>) -> Self {
+        self.set_entries(entries);
+        self
+    }
+
+    /// Method to set the entries.
+    pub fn set_entries(&mut self, entries: impl Into<Rc<Vec<TimelineEntry>>>) {
+        self.entries = entries.into();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TimelineRow {
+    Separator(PlainDate),
+    Entry(usize),
+    Loader,
+}
+
+// Insert day separators, and append the loader row.
+fn build_rows(
+    entries: &[TimelineEntry],
+    day_of: impl Fn(i64) -> PlainDate,
+    loader: bool,
+) -> Vec<TimelineRow> {
+    let mut rows = Vec::new();
+    let mut last_day = None;
+
+    for (i, entry) in entries.iter().enumerate() {
+        let day = day_of(entry.timestamp);
+        if last_day != Some(day) {
+            rows.push(TimelineRow::Separator(day));
+            last_day = Some(day);
+        }
+        rows.push(TimelineRow::Entry(i));
+    }
+
+    if loader {
+        rows.push(TimelineRow::Loader);
+    }
+
+    rows
+}
+
+// Returns None if the time difference is more than a day.
+fn format_relative_time(diff: i64) -> Option<String> {
+    let diff = diff.max(0);
+    if diff < 60 {
+        Some(tr!("just now"))
+    } else if diff < 3600 {
+        Some(tr!("one minute ago" | "{n} minutes ago" % diff / 60))
+    } else if diff < 86400 {
+        Some(tr!("one hour ago" | "{n} hours ago" % diff / 3600))
+    } else {
+        None
+    }
+}
+
+fn epoch_to_date(timestamp: i64) -> Date {
+    Date::new(&JsValue::from_f64(timestamp as f64 * 1000.0))
+}
+
+fn format_day(day: PlainDate, today: PlainDate) -> String {
+    if day == today {
+        tr!("Today")
+    } else if day == today.add_days(-1) {
+        tr!("Yesterday")
+    } else {
+        day.format("Y-m-d")
+    }
+}
+
+pub enum Msg {
+    Tick,
+    LoaderVisible(bool),
+}
+
+#[doc(hidden)]
+pub struct PwtTimeline {
+    now: i64,
+    rows: Rc<Vec<TimelineRow>>,
+    _tick: Interval,
+}
+
+impl PwtTimeline {
+    fn update_rows(&mut self, props: &Timeline) {
+        let loader = props.has_more || props.loading;
+        let rows = build_rows(
+            &props.entries,
+            |ts| PlainDate::from_timestamp(ts as f64 * 1000.0),
+            loader,
+        );
+        self.rows = Rc::new(rows);
+    }
+
+    fn load_more(props: &Timeline) {
+        if props.loading || !props.has_more {
+            return;
+        }
+        if let Some(on_load_more) = &props.on_load_more {
+            on_load_more.emit(());
+        }
+    }
+}
+
+fn render_entry(entry: &TimelineEntry, now: i64) -> ListTile {
+    let date = epoch_to_date(entry.timestamp);
+    let time_text = format_relative_time(now - entry.timestamp)
+        .unwrap_or_else(|| format!("{:02}:{:02}", date.get_hours(), date.get_minutes()));
+    let full_time: String = date.to_locale_string("default", &JsValue::UNDEFINED).into();
+
+    let icon = Container::new()
+        .class("pwt-timeline-icon")
+        .with_optional_child(
+            entry
+                .icon_class
+                .clone()
+                .map(|icon_class| html! {<i role="none" class={icon_class}/>}),
+        );
+
+    let content = Column::new()
+        .class("pwt-timeline-content")
+        .with_child(html! {<div class="pwt-timeline-title">{entry.title.clone()}</div>})
+        .with_optional_child(
+            entry
+                .detail
+                .clone()
+                .map(|detail| html! {<div class="pwt-timeline-detail">{detail}</div>}),
+        );
+
+    let time = Container::from_tag("time")
+        .class("pwt-timeline-time")
+        .attribute("title", full_time)
+        .with_child(time_text);
+
+    ListTile::new()
+        .key(entry.key.clone())
+        .class("pwt-timeline-entry")
+        .with_child(icon)
+        .with_child(content)
+        .with_child(time)
+}
+
+impl Component for PwtTimeline {
+    type Message = Msg;
+    type Properties = Timeline;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let link = ctx.link().clone();
+        let mut me = Self {
+            now: (Date::now() / 1000.0) as i64,
+            rows: Rc::new(Vec::new()),
+            _tick: Interval::new(TICK_INTERVAL_MS, move || link.send_message(Msg::Tick)),
+        };
+        me.update_rows(ctx.props());
+        me
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Tick => {
+                self.now = (Date::now() / 1000.0) as i64;
+                true
+            }
+            Msg::LoaderVisible(visible) => {
+                if visible {
+                    Self::load_more(ctx.props());
+                }
+                false
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.entries != old_props.entries
+            || props.has_more != old_props.has_more
+            || props.loading != old_props.loading
+        {
+            self.update_rows(props);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props().clone();
+        let rows = self.rows.clone();
+        let now = self.now;
+        let today = PlainDate::from_timestamp(now as f64 * 1000.0);
+        let link = ctx.link().clone();
+
+        let renderer = move |pos: u64| -> ListTile {
+            match rows[pos as usize] {
+                TimelineRow::Separator(day) => ListTile::new()
+                    .key(format!("__day_{}__", day.format("Y-m-d")))
+                    .class("pwt-timeline-separator")
+                    .attribute("role", "separator")
+                    .with_child(
+                        Container::new()
+                            .style("grid-column", "1 / -1")
+                            .with_child(format_day(day, today)),
+                    ),
+                TimelineRow::Entry(index) => render_entry(&props.entries[index], now),
+                TimelineRow::Loader => {
+                    let content = if props.loading {
+                        Container::new()
+                            .with_child(Fa::new("spinner").pulse())
+                            .with_child(" ")
+                            .with_child(tr!("Loading..."))
+                    } else {
+                        Container::new().with_child(Button::new(tr!("Load more")).on_activate({
+                            let props = props.clone();
+                            move |_| Self::load_more(&props)
+                        }))
+                    };
+                    let content = content
+                        .class("pwt-timeline-loader")
+                        .style("grid-column", "1 / -1");
+                    ListTile::new()
+                        .key("__loader__")
+                        .with_child(VisibilityObserver::new(
+                            content,
+                            link.callback(Msg::LoaderVisible),
+                        ))
+                }
+            }
+        };
+
+        List::new(self.rows.len() as u64, renderer)
+            .with_std_props(&props.std_props)
+            .listeners(&props.listeners)
+            .class("pwt-timeline")
+            .grid_template_columns("auto 1fr auto")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_build_rows() {
+        // one "day" has 100 seconds
+        let day_of = |ts: i64| PlainDate::new(2024, 0, (ts / 100) as u32);
+
+        let entries = vec![
+            TimelineEntry::new("d", 350, "d"),
+            TimelineEntry::new("c", 320, "c"),
+            TimelineEntry::new("b", 210, "b"),
+            TimelineEntry::new("a", 110, "a"),
+        ];
+
+        let rows = build_rows(&entries, day_of, true);
+        assert_eq!(
+            rows,
+            vec![
+                TimelineRow::Separator(PlainDate::new(2024, 0, 3)),
+                TimelineRow::Entry(0),
+                TimelineRow::Entry(1),
+                TimelineRow::Separator(PlainDate::new(2024, 0, 2)),
+                TimelineRow::Entry(2),
+                TimelineRow::Separator(PlainDate::new(2024, 0, 1)),
+                TimelineRow::Entry(3),
+                TimelineRow::Loader,
+            ]
+        );
+
+        assert!(build_rows(&[], day_of, false).is_empty());
+        assert_eq!(build_rows(&[], day_of, true), vec![TimelineRow::Loader]);
+    }
+
+    #[test]
+    fn test_relative_time() {
+        assert_eq!(format_relative_time(-5).unwrap(), "just now");
+        assert_eq!(format_relative_time(59).unwrap(), "just now");
+        assert_eq!(format_relative_time(60).unwrap(), "one minute ago");
+        assert_eq!(format_relative_time(125).unwrap(), "2 minutes ago");
+        assert_eq!(format_relative_time(7300).unwrap(), "2 hours ago");
+        assert_eq!(format_relative_time(86400), None);
+    }
+}
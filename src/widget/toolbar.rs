@@ -146,13 +146,16 @@ impl Component for PwtToolbar {
             .clone()
             .onfocusin(self.focus_tracker.get_focus_callback(true))
             .onfocusout(self.focus_tracker.get_focus_callback(false))
-            .onwheel({
-                let link = ctx.link().clone();
-                move |event: WheelEvent| {
-                    event.prevent_default();
-                    link.send_message(Msg::Scroll(event.delta_y() > 0.0))
-                }
-            })
+            .onwheel_with_passive(
+                {
+                    let link = ctx.link().clone();
+                    move |event: WheelEvent| {
+                        event.prevent_default();
+                        link.send_message(Msg::Scroll(event.delta_y() > 0.0))
+                    }
+                },
+                false,
+            )
             .onkeydown(move |event: KeyboardEvent| {
                 // Leave caret-movement keys to a focused text input instead of using them to
                 // move between toolbar items; mirrors the same guard in DataTable.
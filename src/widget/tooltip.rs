@@ -4,6 +4,7 @@ use yew::html::IntoPropValue;
 use yew::virtual_dom::VNode;
 
 use crate::dom::align::{AlignOptions, GrowDirection, Point, align_to};
+use crate::dom::pointer_moves_towards;
 use crate::prelude::*;
 use crate::widget::Container;
 
@@ -19,8 +20,19 @@ pub struct Tooltip {
 
     #[prop_or_default]
     rich: bool,
+
+    /// Show the tooltip on long-press (touch devices).
+    #[prop_or_default]
+    pub long_press: bool,
 }
 
+// Delay before showing the tooltip on long-press.
+const LONG_PRESS_DELAY_MS: u32 = 500;
+// Delay before hiding a tooltip shown by long-press.
+const LONG_PRESS_HIDE_MS: u32 = 1500;
+// Delay before hiding while the pointer moves towards the tooltip.
+const SAFE_TRIANGLE_HIDE_MS: u32 = 500;
+
 impl Tooltip {
     /// Creates a new tooltip for the provided content.
     pub fn new(content: impl Into<VNode>) -> Self {
@@ -57,6 +69,17 @@ impl Tooltip {
         self.rich = true;
         self.tip = Some(tip.into());
     }
+
+    /// Builder style method to enable the long-press trigger for touch devices.
+    pub fn long_press(mut self, long_press: bool) -> Self {
+        self.set_long_press(long_press);
+        self
+    }
+
+    /// Method to enable the long-press trigger for touch devices.
+    pub fn set_long_press(&mut self, long_press: bool) {
+        self.long_press = long_press;
+    }
 }
 
 pub enum Msg {
@@ -64,8 +87,12 @@ pub enum Msg {
     RealHide,
     Show,
     Hide,
+    HideDelayed(u32),
     Enter,
     Leave,
+    PressStart,
+    PressEnd,
+    LongPress,
 }
 
 #[doc(hidden)]
@@ -76,6 +103,8 @@ pub struct PwtTooltip {
     show: bool,
     hover_tooltip: bool,
     timeout: Option<Timeout>,
+    long_press_timeout: Option<Timeout>,
+    long_press_active: bool,
 }
 
 impl Component for PwtTooltip {
@@ -108,6 +137,8 @@ impl Component for PwtTooltip {
             show: false,
             hover_tooltip: false,
             timeout: None,
+            long_press_timeout: None,
+            long_press_active: false,
             align_options,
         }
     }
@@ -136,11 +167,36 @@ impl Component for PwtTooltip {
                 }));
             }
             Msg::Hide => {
+                ctx.link().send_message(Msg::HideDelayed(200));
+            }
+            Msg::HideDelayed(delay) => {
                 let link = ctx.link().clone();
-                self.timeout = Some(Timeout::new(200, move || {
+                self.timeout = Some(Timeout::new(delay, move || {
                     link.send_message(Msg::RealHide);
                 }));
             }
+            Msg::PressStart => {
+                let link = ctx.link().clone();
+                self.long_press_timeout = Some(Timeout::new(LONG_PRESS_DELAY_MS, move || {
+                    link.send_message(Msg::LongPress);
+                }));
+            }
+            Msg::LongPress => {
+                self.long_press_timeout = None;
+                self.long_press_active = true;
+                self.timeout = None;
+                self.show = true;
+                // haptic feedback, if supported
+                let _ = gloo_utils::window().navigator().vibrate_with_duration(10);
+            }
+            Msg::PressEnd => {
+                self.long_press_timeout = None;
+                if self.long_press_active {
+                    self.long_press_active = false;
+                    ctx.link()
+                        .send_message(Msg::HideDelayed(LONG_PRESS_HIDE_MS));
+                }
+            }
         }
         true
     }
@@ -163,12 +219,24 @@ impl Component for PwtTooltip {
                 .into_html_with_ref(self.tooltip_ref.clone()),
         );
 
-        Container::new()
+        let mut container = Container::new()
             .with_std_props(&props.std_props)
             .listeners(&props.listeners)
             .children(props.children.clone())
             .onmouseenter(ctx.link().callback(|_| Msg::Show))
-            .onmouseleave(ctx.link().callback(|_| Msg::Hide))
+            .onmouseleave(ctx.link().callback({
+                let tooltip_ref = self.tooltip_ref.clone();
+                move |event: MouseEvent| {
+                    let towards_tip = tooltip_ref
+                        .cast::<web_sys::Element>()
+                        .is_some_and(|tip| pointer_moves_towards(&event, &tip));
+                    if towards_tip {
+                        Msg::HideDelayed(SAFE_TRIANGLE_HIDE_MS)
+                    } else {
+                        Msg::Hide
+                    }
+                }
+            }))
             .onfocus(ctx.link().callback(|_| Msg::Show))
             .onblur(ctx.link().callback(|_| Msg::Hide))
             .onkeydown(Callback::from({
@@ -180,8 +248,25 @@ impl Component for PwtTooltip {
                     }
                 }
             }))
-            .with_optional_child(tip)
-            .into_html_with_ref(self.node_ref.clone())
+            .with_optional_child(tip);
+
+        if props.long_press {
+            let long_press_active = self.long_press_active;
+            container = container
+                .onpointerdown(ctx.link().batch_callback(|event: PointerEvent| {
+                    (event.pointer_type() == "touch").then_some(Msg::PressStart)
+                }))
+                .onpointerup(ctx.link().callback(|_| Msg::PressEnd))
+                .onpointercancel(ctx.link().callback(|_| Msg::PressEnd))
+                .oncontextmenu(move |event: MouseEvent| {
+                    // avoid the browser context menu on long-press
+                    if long_press_active {
+                        event.prevent_default();
+                    }
+                });
+        }
+
+        container.into_html_with_ref(self.node_ref.clone())
     }
 
     fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
@@ -0,0 +1,333 @@
+use std::rc::Rc;
+
+use gloo_events::{EventListener, EventListenerOptions, EventListenerPhase};
+use wasm_bindgen::JsCast;
+
+use yew::virtual_dom::{Key, VComp, VNode};
+
+use crate::css::{Layer, Shadow};
+use crate::prelude::*;
+use crate::props::IntoOptionalKey;
+use crate::state::shared_state_observer_count;
+use crate::widget::{ActionIcon, Button, Container};
+
+const INSPECTOR_CLASS: &str = "pwt-widget-inspector";
+
+thread_local! {
+    static INSPECTOR_ENABLED: bool = detect_inspector();
+}
+
+#[cfg(target_arch = "wasm32")]
+fn detect_inspector() -> bool {
+    if cfg!(feature = "inspector") {
+        return true;
+    }
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .is_some_and(|search| {
+            search
+                .trim_start_matches('?')
+                .split('&')
+                .any(|param| param == "pwt-inspector" || param.starts_with("pwt-inspector="))
+        })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn detect_inspector() -> bool {
+    cfg!(feature = "inspector")
+}
+
+/// Returns true if the [WidgetInspector] is enabled.
+///
+/// The inspector is enabled by the `inspector` crate feature, or by
+/// adding the `pwt-inspector` query parameter to the page URL (i.e.
+/// `https://host/?pwt-inspector`).
+pub fn inspector_enabled() -> bool {
+    INSPECTOR_ENABLED.with(|enabled| *enabled)
+}
+
+/// Developer overlay to inspect widgets.
+///
+/// Shows a small button in the bottom left corner to start inspection.
+/// While inspecting, the widget below the mouse pointer gets highlighted,
+/// and a panel shows the widget type, key, element, classes and styles.
+/// Click to pin the widget, `Escape` to stop.
+///
+/// The panel also shows the number of active
+/// [SharedStateObserver](crate::state::SharedStateObserver)s, which helps
+/// to detect leaking observers.
+///
+/// Widget types and keys are recorded as data attributes, but only if the
+/// inspector is enabled (see [inspector_enabled]). [DesktopApp](super::DesktopApp)
+/// and [MaterialApp](crate::touch::MaterialApp) add the overlay
+/// automatically in that case.
+#[derive(Clone, PartialEq, Properties)]
+pub struct WidgetInspector {
+    /// The yew component key.
+    #[prop_or_default]
+    pub key: Option<Key>,
+}
+
+impl Default for WidgetInspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WidgetInspector {
+    /// Create a new instance.
+    pub fn new() -> Self {
+        yew::props!(Self {})
+    }
+
+    /// Builder style method to set the yew `key` property
+    pub fn key(mut self, key: impl IntoOptionalKey) -> Self {
+        self.key = key.into_optional_key();
+        self
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, PartialEq)]
+pub struct InspectInfo {
+    // viewport position and size (left, top, width, height)
+    rect: (f64, f64, f64, f64),
+    widget: Option<String>,
+    key: Option<String>,
+    tag: String,
+    class: String,
+    style: Option<String>,
+}
+
+impl InspectInfo {
+    fn new(element: &web_sys::Element) -> Self {
+        let rect = element.get_bounding_client_rect();
+        Self {
+            rect: (rect.left(), rect.top(), rect.width(), rect.height()),
+            widget: element.get_attribute("data-pwt-widget"),
+            key: element.get_attribute("data-pwt-key"),
+            tag: element.tag_name().to_lowercase(),
+            class: element.class_name(),
+            style: element.get_attribute("style"),
+        }
+    }
+}
+
+pub enum Msg {
+    Toggle,
+    Hover(Option<InspectInfo>),
+    Pin,
+    Stop,
+    ClosePanel,
+}
+
+#[doc(hidden)]
+pub struct PwtWidgetInspector {
+    inspecting: bool,
+    info: Option<InspectInfo>,
+    _listeners: Vec<EventListener>,
+}
+
+// Returns the nearest annotated widget element (ignoring the inspector itself).
+fn inspect_target(event: &Event) -> Option<InspectInfo> {
+    let element: web_sys::Element = event.target()?.dyn_into().ok()?;
+    if element
+        .closest(&format!(".{INSPECTOR_CLASS}"))
+        .ok()?
+        .is_some()
+    {
+        return None;
+    }
+    let widget = element
+        .closest("[data-pwt-widget]")
+        .ok()
+        .flatten()
+        .unwrap_or(element);
+    Some(InspectInfo::new(&widget))
+}
+
+impl PwtWidgetInspector {
+    fn install_listeners(&mut self, ctx: &Context<Self>) {
+        let document = gloo_utils::document();
+        let options = EventListenerOptions::run_in_capture_phase();
+
+        let link = ctx.link().clone();
+        let mousemove =
+            EventListener::new_with_options(&document, "mousemove", options, move |event| {
+                link.send_message(Msg::Hover(inspect_target(event)));
+            });
+
+        let link = ctx.link().clone();
+        let click = EventListener::new_with_options(
+            &document,
+            "click",
+            EventListenerOptions {
+                phase: EventListenerPhase::Capture,
+                passive: false,
+            },
+            move |event| {
+                if inspect_target(event).is_some() {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    link.send_message(Msg::Pin);
+                }
+            },
+        );
+
+        let link = ctx.link().clone();
+        let keydown =
+            EventListener::new_with_options(&document, "keydown", options, move |event| {
+                if let Some(event) = event.dyn_ref::<KeyboardEvent>() {
+                    if event.key() == "Escape" {
+                        link.send_message(Msg::Stop);
+                    }
+                }
+            });
+
+        self._listeners = vec![mousemove, click, keydown];
+    }
+
+    fn render_panel(&self, ctx: &Context<Self>, info: &InspectInfo) -> Html {
+        let row = |label: &str, value: String| {
+            html! {
+                <div>
+                    <span style="opacity: 0.7;">{label}{": "}</span>
+                    {value}
+                </div>
+            }
+        };
+
+        let (_, _, width, height) = info.rect;
+
+        Container::new()
+            .class(INSPECTOR_CLASS)
+            .class(Shadow(2))
+            .padding(2)
+            .style("position", "fixed")
+            .style("left", "8px")
+            .style("bottom", "48px")
+            .style("max-width", "480px")
+            .style("z-index", Layer::Inspector.above(1))
+            .style("background", "var(--pwt-color-surface)")
+            .style("color", "var(--pwt-color-on-surface)")
+            .style("font-family", "monospace")
+            .style("font-size", "12px")
+            .style("overflow-wrap", "anywhere")
+            .with_child(
+                ActionIcon::new("fa fa-times")
+                    .style("float", "right")
+                    .on_activate(ctx.link().callback(|_| Msg::ClosePanel)),
+            )
+            .with_child(row(
+                "Widget",
+                info.widget.clone().unwrap_or_else(|| String::from("-")),
+            ))
+            .with_child(row(
+                "Key",
+                info.key.clone().unwrap_or_else(|| String::from("-")),
+            ))
+            .with_child(row("Element", info.tag.clone()))
+            .with_child(row("Size", format!("{width:.0} x {height:.0}")))
+            .with_child(row("Classes", info.class.clone()))
+            .with_child(row(
+                "Style",
+                info.style.clone().unwrap_or_else(|| String::from("-")),
+            ))
+            .with_child(row(
+                "SharedState observers",
+                shared_state_observer_count().to_string(),
+            ))
+            .into()
+    }
+}
+
+impl Component for PwtWidgetInspector {
+    type Message = Msg;
+    type Properties = WidgetInspector;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            inspecting: false,
+            info: None,
+            _listeners: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Toggle => {
+                if self.inspecting {
+                    return self.update(ctx, Msg::Stop);
+                }
+                self.inspecting = true;
+                self.install_listeners(ctx);
+                true
+            }
+            Msg::Hover(info) => {
+                if !self.inspecting || info.is_none() || self.info == info {
+                    return false;
+                }
+                self.info = info;
+                true
+            }
+            Msg::Pin => {
+                self.inspecting = false;
+                self._listeners.clear();
+                true
+            }
+            Msg::Stop => {
+                self.inspecting = false;
+                self._listeners.clear();
+                self.info = None;
+                true
+            }
+            Msg::ClosePanel => {
+                self.info = None;
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let toggle = Button::new_icon("fa fa-crosshairs")
+            .class(INSPECTOR_CLASS)
+            .class(self.inspecting.then_some("pwt-button-filled"))
+            .aria_label("Inspect widgets")
+            .style("position", "fixed")
+            .style("left", "8px")
+            .style("bottom", "8px")
+            .style("z-index", Layer::Inspector.above(1))
+            .on_activate(ctx.link().callback(|_| Msg::Toggle));
+
+        let highlight = self.info.as_ref().map(|info| {
+            let (left, top, width, height) = info.rect;
+            Container::new()
+                .class(INSPECTOR_CLASS)
+                .style("position", "fixed")
+                .style("left", format!("{left}px"))
+                .style("top", format!("{top}px"))
+                .style("width", format!("{width}px"))
+                .style("height", format!("{height}px"))
+                .style("pointer-events", "none")
+                .style("z-index", Layer::Inspector)
+                .style("outline", "2px solid var(--pwt-color-primary)")
+                .style("background", "rgba(100, 150, 255, 0.15)")
+        });
+
+        html! {
+            <>
+                {toggle}
+                {for highlight}
+                {for self.info.as_ref().map(|info| self.render_panel(ctx, info))}
+            </>
+        }
+    }
+}
+
+impl From<WidgetInspector> for VNode {
+    fn from(val: WidgetInspector) -> Self {
+        let key = val.key.clone();
+        let comp = VComp::new::<PwtWidgetInspector>(Rc::new(val), key);
+        VNode::from(comp)
+    }
+}